@@ -20,6 +20,7 @@ use std::{
     collections::HashMap,
     path::PathBuf,
     sync::{Arc, atomic::AtomicBool},
+    time::Duration,
 };
 
 pub mod access;
@@ -259,6 +260,26 @@ impl HeadlessApp {
         }
     }
 
+    /// Advance the app time by `delta` and does updates.
+    ///
+    /// The first call enables manual time, see [`APP.start_manual_time`] for more details. After this call all timers and
+    /// animations that elapse up to the new time have updated and windows that need it have rendered a new frame, so a
+    /// sequence of steps can be used to capture frames at exact timestamps, independent of the system clock.
+    ///
+    /// [`APP.start_manual_time`]: APP::start_manual_time
+    pub fn step(&mut self, delta: Duration) -> AppControlFlow {
+        if !matches!(INSTANT.mode(), InstantMode::Manual) {
+            APP.start_manual_time();
+        }
+        APP.advance_manual_time(delta);
+        let mut flow = self.update(false);
+        // wait for the frames requested by the update
+        while flow != AppControlFlow::Exit && VIEW_PROCESS.is_available() && VIEW_PROCESS.has_pending_frames() {
+            flow = self.update(true);
+        }
+        flow
+    }
+
     /// Does updates and calls `on_pre_update` on the first update.
     pub fn update_observe(&mut self, on_pre_update: impl FnOnce() + Send + 'static) -> bool {
         let u = Arc::new(AtomicBool::new(false));
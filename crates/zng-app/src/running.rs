@@ -162,6 +162,9 @@ impl RunningApp {
                 RAW_MOUSE_LEFT_EVENT.notify(args);
             }
             Event::WindowChanged(c) => {
+                if let Some(s) = &c.state {
+                    VIEW_PROCESS.on_window_state(window_id(c.window), s.state);
+                }
                 let monitor_id = c.monitor.map(|id| VIEW_PROCESS.monitor_id(id));
                 let args = RawWindowChangedArgs::now(
                     window_id(c.window),
@@ -232,6 +235,10 @@ impl RunningApp {
                 );
                 RAW_KEY_INPUT_EVENT.notify(args);
             }
+            Event::GlobalHotkey { id, state } => {
+                let args = RawGlobalHotkeyArgs::now(id, state);
+                RAW_GLOBAL_HOTKEY_EVENT.notify(args);
+            }
             Event::Ime { window: w_id, ime } => {
                 let args = RawImeArgs::now(window_id(w_id), ime);
                 RAW_IME_EVENT.notify(args);
@@ -442,7 +449,8 @@ impl RunningApp {
             }
 
             Event::MenuCommand { id } => {
-                let _ = id;
+                let args = RawMenuCommandArgs::now(id);
+                RAW_MENU_COMMAND_EVENT.notify(args);
             }
 
             // custom
@@ -450,6 +458,14 @@ impl RunningApp {
                 let args = RawExtensionEventArgs::now(id, payload);
                 RAW_EXTENSION_EVENT.notify(args);
             }
+            Event::ExtensionResponse {
+                extension_id,
+                request_id,
+                response,
+            } => {
+                let args = RawExtensionResponseArgs::now(extension_id, request_id, response);
+                RAW_EXTENSION_RESPONSE_EVENT.notify(args);
+            }
 
             // config events
             Event::FontsChanged => {
@@ -596,6 +612,11 @@ impl RunningApp {
 
                     VIEW_PROCESS.handle_inited(&inited);
 
+                    // snapshot of devices connected before the first change event
+                    if let Ok(devices) = VIEW_PROCESS.connected_devices() {
+                        crate::view_process::raw_device_events::INPUT_DEVICES.update(devices);
+                    }
+
                     let args = crate::view_process::ViewProcessInitedArgs::now(inited);
                     VIEW_PROCESS_INITED_EVENT.notify(args);
                 }
@@ -609,6 +630,10 @@ impl RunningApp {
                     // update ViewProcess immediately.
                     VIEW_PROCESS.handle_disconnect(vp_gen);
                 }
+                zng_view_api::Event::ViewProcessUnresponsive(vp_gen) => {
+                    let args = crate::view_process::ViewProcessUnresponsiveArgs::now(vp_gen);
+                    VIEW_PROCESS_UNRESPONSIVE_EVENT.notify(args);
+                }
                 ev => {
                     if let Some(last) = self.pending_view_events.last_mut() {
                         match last.coalesce(ev) {
@@ -734,6 +759,12 @@ impl RunningApp {
         {
             AppControlFlow::Poll
         } else {
+            UPDATES.on_app_idle();
+            if self.has_pending_updates() || UPDATES.has_pending_layout_or_render() {
+                // idle handler requested update
+                return AppControlFlow::Poll;
+            }
+
             UPDATES.on_app_sleep();
             AppControlFlow::Wait
         }
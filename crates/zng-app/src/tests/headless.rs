@@ -0,0 +1,36 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use crate::{APP, INSTANT, InstantMode, hn_once, timer::TIMERS};
+
+#[test]
+pub fn step_manual_time() {
+    let mut app = APP.minimal().run_headless(false);
+
+    let fired = Arc::new(AtomicBool::new(false));
+    app.step(Duration::ZERO);
+    assert!(matches!(INSTANT.mode(), InstantMode::Manual));
+
+    let start = INSTANT.now();
+    TIMERS
+        .on_deadline(
+            Duration::from_secs(10),
+            hn_once!(fired, |_| {
+                fired.store(true, Ordering::Relaxed);
+            }),
+        )
+        .perm();
+
+    app.step(Duration::from_secs(5));
+    assert_eq!(INSTANT.now() - start, Duration::from_secs(5));
+    assert!(!fired.load(Ordering::Relaxed));
+
+    app.step(Duration::from_secs(5));
+    assert_eq!(INSTANT.now() - start, Duration::from_secs(10));
+    assert!(fired.load(Ordering::Relaxed));
+}
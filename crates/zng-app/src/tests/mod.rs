@@ -4,6 +4,7 @@ mod widget;
 
 mod a;
 mod b;
+mod headless;
 mod ui_node_list;
 
 pub use a::Foo as FooA;
@@ -1081,6 +1081,105 @@ pub fn name_collision_wgt_when() {
     });
 }
 
+/*
+* Measure node outside of the window.
+*/
+#[test]
+pub fn measure_detached() {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    };
+    use zng_clone_move::clmv;
+    use zng_layout::{
+        context::{LAYOUT, LayoutMetrics},
+        unit::{Factor, Px, PxConstraints2d, PxSize},
+    };
+
+    use crate::widget::node::{UiNodeOp, match_node_leaf};
+
+    let _app = APP.minimal().run_headless(false);
+
+    let inited = Arc::new(AtomicBool::new(false));
+    let mut node = match_node_leaf(clmv!(inited, |op| match op {
+        UiNodeOp::Init => inited.store(true, Ordering::Relaxed),
+        UiNodeOp::Deinit => inited.store(false, Ordering::Relaxed),
+        UiNodeOp::Measure { desired_size, .. } => {
+            assert!(inited.load(Ordering::Relaxed));
+            *desired_size = LAYOUT.constraints().fill_size();
+        }
+        _ => {}
+    }));
+
+    let size = WINDOW.with_test_context(WidgetUpdateMode::Bubble, || {
+        let metrics =
+            LayoutMetrics::new(Factor(1.0), PxSize::splat(Px(100)), Px(14)).with_constraints(PxConstraints2d::new_exact(Px(20), Px(30)));
+        node.measure_detached(metrics)
+    });
+
+    assert_eq!(size, PxSize::new(Px(20), Px(30)));
+    assert!(!inited.load(Ordering::Relaxed));
+}
+
+#[test]
+pub fn spawn_cancelled_on_deinit() {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicBool, Ordering},
+        },
+        time::{Duration, Instant},
+    };
+    use zng_clone_move::clmv;
+
+    use crate::widget::{
+        WIDGET,
+        node::{UiNodeOp, match_node_leaf},
+    };
+
+    struct DropFlag(Arc<AtomicBool>);
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::Relaxed);
+        }
+    }
+    fn wait_flag(flag: &AtomicBool) -> bool {
+        let timeout = Instant::now() + Duration::from_secs(10);
+        while !flag.load(Ordering::Relaxed) && Instant::now() < timeout {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        flag.load(Ordering::Relaxed)
+    }
+
+    let _app = APP.minimal().run_headless(false);
+
+    let started = Arc::new(AtomicBool::new(false));
+    let completed = Arc::new(AtomicBool::new(false));
+    let dropped = Arc::new(AtomicBool::new(false));
+    let mut wgt = match_node_leaf(clmv!(started, completed, dropped, |op| {
+        if let UiNodeOp::Init = op {
+            let flag = DropFlag(dropped.clone());
+            WIDGET.spawn(clmv!(started, completed, async move {
+                let _flag = flag;
+                started.store(true, Ordering::Relaxed);
+                std::future::pending::<()>().await;
+                completed.store(true, Ordering::Relaxed);
+            }));
+        }
+    }))
+    .into_widget();
+
+    WINDOW.with_test_context(WidgetUpdateMode::Bubble, || {
+        let _ = WINDOW.test_init(&mut wgt);
+        assert!(wait_flag(&started), "task did not start");
+        assert!(!dropped.load(Ordering::Relaxed));
+
+        let _ = WINDOW.test_deinit(&mut wgt);
+        assert!(wait_flag(&dropped), "task was not dropped after deinit");
+    });
+    assert!(!completed.load(Ordering::Relaxed));
+}
+
 /*
 * macro_rules! generated widget
 */
@@ -4,22 +4,27 @@ use std::{
     borrow::Cow,
     collections::{HashMap, hash_map},
     fmt, mem,
-    sync::Arc,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
     task::Waker,
+    time::{Duration, Instant},
 };
 
 use parking_lot::Mutex;
 use zng_app_context::app_local;
 use zng_handle::{Handle, HandleOwner, WeakHandle};
 use zng_task::channel::ChannelError;
+use zng_time::{DInstant, INSTANT, InstantMode};
 use zng_unique_id::IdSet;
 use zng_var::{VARS, VARS_APP, VarUpdateId};
 
 use crate::{
     AppEventSender, LoopTimer, async_hn_once,
-    handler::{AppWeakHandle, Handler, HandlerExt as _},
-    hn_once,
-    timer::TIMERS_SV,
+    handler::{APP_HANDLER, AppWeakHandle, Handler, HandlerExt as _},
+    hn, hn_once,
+    timer::{TIMERS, TIMERS_SV},
     widget::{
         WIDGET, WidgetId,
         info::{InteractionPath, WidgetInfo, WidgetInfoTree, WidgetPath},
@@ -1294,6 +1299,97 @@ impl UPDATES {
         Self::push_handler(&mut u.pos_handlers.lock(), false, handler, false)
     }
 
+    /// Create an idle handler.
+    ///
+    /// The `handler` is called every time the app finishes an update cycle with no pending updates, layout or render and
+    /// is about to wait for the next event or timer. Use this for non-urgent background work, like trimming caches, so that
+    /// it does not compete with interactive updates. The [`UpdateArgs::count`] is the number of times the app idled
+    /// since the handler was created.
+    ///
+    /// If the handler requests an update the app does not wait, it applies the update and the handler is called again
+    /// in the next idle, so you must avoid requesting updates every call. It can be any of the non-async [`Handler`],
+    /// use the [`hn!`] or [`hn_once!`] macros to declare the closure.
+    ///
+    /// Returns an [`OnUpdateHandle`] that can be used to unsubscribe, you can also unsubscribe from inside the handler by calling
+    /// [`APP_HANDLER.unsubscribe`].
+    ///
+    /// [`hn_once!`]: macro@crate::handler::hn_once
+    /// [`hn!`]: macro@crate::handler::hn
+    /// [`APP_HANDLER.unsubscribe`]: crate::handler::APP_HANDLER::unsubscribe
+    pub fn on_idle(&self, handler: Handler<UpdateArgs>) -> OnUpdateHandle {
+        let u = UPDATES_SV.read();
+        Self::push_handler(&mut u.idle_handlers.lock(), false, handler, false)
+    }
+
+    /// Schedule an incremental `work` that runs across multiple app updates, limited to a time `budget` per update.
+    ///
+    /// The `work` closure is called repeatedly in the *preview* track of updates, each call must do a small unit of work and
+    /// return `true` if there is more work to do. When the `budget` is exceeded the closure yields back to the app loop so
+    /// that layout and render can happen, the work continues in an update requested after the frame. This can be used to build
+    /// a very large number of widgets without blocking the app in a single long frame.
+    ///
+    /// The budget is measured using [`INSTANT.now`] if the app time is [`InstantMode::Manual`], otherwise it is measured using
+    /// the system clock, as the time is paused during updates.
+    ///
+    /// Returns a handle that can be dropped to cancel the work, the handle is also dropped when `work` returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use zng_app::update::UPDATES;
+    /// # use std::time::Duration;
+    /// # fn demo() {
+    /// let mut items = (0..100_000).collect::<Vec<u32>>();
+    /// UPDATES
+    ///     .run_incremental(Duration::from_millis(4), move || {
+    ///         if let Some(item) = items.pop() {
+    ///             // process item
+    ///             let _ = item;
+    ///         }
+    ///         !items.is_empty()
+    ///     })
+    ///     .perm();
+    /// # }
+    /// ```
+    ///
+    /// [`INSTANT.now`]: INSTANT::now
+    pub fn run_incremental(&self, budget: Duration, mut work: impl FnMut() -> bool + Send + 'static) -> OnUpdateHandle {
+        fn now() -> DInstant {
+            match INSTANT.mode() {
+                InstantMode::Manual => INSTANT.now(),
+                _ => DInstant::from(Instant::now()),
+            }
+        }
+
+        let resume = Arc::new(AtomicBool::new(true));
+        self.update_app();
+        self.on_pre_update(hn!(|_| {
+            if !resume.swap(false, Ordering::Relaxed) {
+                return;
+            }
+            let start = now();
+            loop {
+                if !work() {
+                    APP_HANDLER.unsubscribe();
+                    return;
+                }
+                if now() - start >= budget {
+                    break;
+                }
+            }
+            // timers only elapse after the pending layout and render, so the next slice runs after the frame
+            TIMERS
+                .on_deadline(
+                    Duration::ZERO,
+                    hn_once!(resume, |_| {
+                        resume.store(true, Ordering::Relaxed);
+                        UPDATES.update_app();
+                    }),
+                )
+                .perm();
+        }))
+    }
+
     /// Id of the current update pass.
     ///
     /// This is an alias for [`VARS::update_id`]
@@ -1358,6 +1454,16 @@ impl UPDATES {
         *u.pos_handlers.get_mut() = handlers;
     }
 
+    pub(crate) fn on_app_idle(&self) {
+        let _s = tracing::trace_span!("UPDATES.on_app_idle");
+        let mut handlers = mem::take(UPDATES_SV.write().idle_handlers.get_mut());
+        Self::retain_updates(&mut handlers);
+
+        let mut u = UPDATES_SV.write();
+        handlers.append(u.idle_handlers.get_mut());
+        *u.idle_handlers.get_mut() = handlers;
+    }
+
     fn retain_updates(handlers: &mut Vec<UpdateHandler>) {
         handlers.retain_mut(|e| {
             !e.handle.is_dropped() && {
@@ -1447,6 +1553,7 @@ struct UpdatesService {
 
     pre_handlers: Mutex<Vec<UpdateHandler>>,
     pos_handlers: Mutex<Vec<UpdateHandler>>,
+    idle_handlers: Mutex<Vec<UpdateHandler>>,
 
     app_is_awake: bool,
     awake_pending: bool,
@@ -1464,6 +1571,7 @@ impl UpdatesService {
 
             pre_handlers: Mutex::new(vec![]),
             pos_handlers: Mutex::new(vec![]),
+            idle_handlers: Mutex::new(vec![]),
 
             app_is_awake: false,
             awake_pending: false,
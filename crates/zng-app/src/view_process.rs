@@ -5,6 +5,7 @@ use std::{
     fmt,
     path::PathBuf,
     sync::{self, Arc},
+    time::Instant,
 };
 
 pub mod raw_device_events;
@@ -12,15 +13,16 @@ pub mod raw_events;
 
 use crate::{
     event::{event, event_args},
+    shortcut::ModifiersState,
     window::{MonitorId, WindowId},
 };
 
 use parking_lot::{MappedRwLockReadGuard, MappedRwLockWriteGuard};
 use zng_app_context::app_local;
-use zng_layout::unit::{DipPoint, DipRect, DipSideOffsets, DipSize, Factor, Frequency, Px, PxPoint, PxRect};
+use zng_layout::unit::{DipPoint, DipRect, DipSideOffsets, DipSize, Factor, Frequency, Px, PxPoint, PxRect, PxSize, PxTransform};
 use zng_task::channel::{self, ChannelError, IpcBytes, IpcReadHandle, IpcReceiver, Receiver};
 use zng_txt::Txt;
-use zng_unique_id::IdMap;
+use zng_unique_id::{IdMap, IdSet};
 use zng_var::{ArcEq, ResponderVar, Var, VarHandle, WeakEq};
 use zng_view_api::{
     self, DeviceEventsFilter, DragDropId, Event, FocusResult, ViewProcessGen, ViewProcessInfo,
@@ -33,9 +35,11 @@ use zng_view_api::{
     drag_drop::{DragDropData, DragDropEffect, DragDropError},
     font::{FontOptions, IpcFontBytes},
     image::{ImageDecoded, ImageEncodeId, ImageEncodeRequest, ImageMaskMode, ImageMetadata, ImageRequest, ImageTextureId},
+    keyboard::{GlobalHotkey, GlobalHotkeyError, GlobalHotkeyId},
+    menu::TrayIcon,
     window::{
-        CursorIcon, FocusIndicator, FrameRequest, FrameUpdateRequest, HeadlessOpenData, HeadlessRequest, RenderMode, ResizeDirection,
-        VideoMode, WindowButton, WindowRequest, WindowStateAll,
+        CenterTarget, CursorIcon, FocusIndicator, FrameRequest, FrameUpdateRequest, HeadlessOpenData, HeadlessRequest, RenderMode,
+        ResizeDirection, SnapZone, VideoMode, WindowButton, WindowRequest, WindowShape, WindowState, WindowStateAll,
     },
 };
 
@@ -68,12 +72,17 @@ struct ViewProcessService {
     loading_audios: Vec<WeakEq<ViewAudioHandleData>>,
 
     pending_frames: IdMap<WindowId, usize>,
+    // windows that may not present frames, excluded from the unresponsive check.
+    hidden_windows: IdSet<WindowId>,
+    minimized_windows: IdSet<WindowId>,
 
     message_dialogs: Vec<(zng_view_api::dialog::DialogId, ResponderVar<MsgDialogResponse>)>,
     file_dialogs: Vec<(zng_view_api::dialog::DialogId, ResponderVar<FileDialogResponse>)>,
     notifications: Vec<(zng_view_api::dialog::DialogId, VarHandle, ResponderVar<NotificationResponse>)>,
 
     ping_count: u16,
+    // first ping that observed a pending frame, reset on frame rendered.
+    frame_pending_since: Option<Instant>,
 }
 app_local! {
     static VIEW_PROCESS_SV: Option<ViewProcessService> = None;
@@ -151,6 +160,17 @@ impl VIEW_PROCESS {
         self.write().process.set_device_events_filter(filter)
     }
 
+    /// Gets a snapshot of the input devices currently known by the view-process.
+    ///
+    /// Note that some systems only report devices after they are used, subscribe to [`INPUT_DEVICES_CHANGED_EVENT`]
+    /// to track changes.
+    ///
+    /// [`INPUT_DEVICES_CHANGED_EVENT`]: crate::view_process::raw_device_events::INPUT_DEVICES_CHANGED_EVENT
+    pub fn connected_devices(&self) -> Result<HashMap<InputDeviceId, raw_device_events::InputDeviceInfo>> {
+        let devices = self.write().process.connected_devices()?;
+        Ok(devices.into_iter().map(|(id, info)| (self.input_device_id(id), info)).collect())
+    }
+
     /// Sends a request to open a window and associate it with the `window_id`.
     ///
     /// A [`RAW_WINDOW_OPEN_EVENT`] or [`RAW_WINDOW_OR_HEADLESS_OPEN_ERROR_EVENT`] will be received in response to this request.
@@ -158,7 +178,11 @@ impl VIEW_PROCESS {
     /// [`RAW_WINDOW_OPEN_EVENT`]: crate::view_process::raw_events::RAW_WINDOW_OPEN_EVENT
     /// [`RAW_WINDOW_OR_HEADLESS_OPEN_ERROR_EVENT`]: crate::view_process::raw_events::RAW_WINDOW_OR_HEADLESS_OPEN_ERROR_EVENT
     pub fn open_window(&self, config: WindowRequest) -> Result<()> {
-        self.write().process.open_window(config)
+        let mut app = self.write();
+        let id = WindowId::from_raw(config.id.get());
+        app.set_window_hidden(id, !config.visible);
+        app.set_window_minimized(id, config.state.state == WindowState::Minimized);
+        app.process.open_window(config)
     }
 
     /// Sends a request to open a headless renderer and associate it with the `window_id`.
@@ -174,6 +198,13 @@ impl VIEW_PROCESS {
         self.write().process.open_headless(config)
     }
 
+    /// Create and discard a throwaway renderer in the view-process to prime the graphics driver caches.
+    ///
+    /// Call this during a splash screen so that the first window opens faster.
+    pub fn prewarm_renderer(&self, render_mode: RenderMode) -> Result<()> {
+        self.write().process.prewarm_renderer(render_mode)
+    }
+
     /// Send a request to open a connection to an audio output device.
     ///
     /// A [`RAW_AUDIO_OUTPUT_OPEN_EVENT`] or [`RAW_AUDIO_OUTPUT_OPEN_ERROR_EVENT`]
@@ -320,6 +351,52 @@ impl VIEW_PROCESS {
         Ok(())
     }
 
+    /// Register a system wide keyboard shortcut.
+    ///
+    /// The [`RAW_GLOBAL_HOTKEY_EVENT`] notifies every time the hotkey is pressed or released, even when no
+    /// app window is focused. Only available if [`KeyboardCapability::GLOBAL_HOTKEY`] is in the [`info`].
+    ///
+    /// Note that registered hotkeys are lost on view-process respawn, you must subscribe to the
+    /// [`VIEW_PROCESS_INITED_EVENT`] to register again.
+    ///
+    /// [`RAW_GLOBAL_HOTKEY_EVENT`]: crate::view_process::raw_events::RAW_GLOBAL_HOTKEY_EVENT
+    /// [`KeyboardCapability::GLOBAL_HOTKEY`]: zng_view_api::keyboard::KeyboardCapability::GLOBAL_HOTKEY
+    /// [`info`]: Self::info
+    pub fn register_global_hotkey(&self, hotkey: GlobalHotkey) -> Result<std::result::Result<GlobalHotkeyId, GlobalHotkeyError>> {
+        self.write().process.register_global_hotkey(hotkey)
+    }
+
+    /// Unregister a system wide keyboard shortcut.
+    pub fn unregister_global_hotkey(&self, id: GlobalHotkeyId) -> Result<()> {
+        self.write().process.unregister_global_hotkey(id)
+    }
+
+    /// Set or remove the app tray icon.
+    ///
+    /// The `tray.icon` is replaced with the `icon` handle, if `icon` is `None` the tray icon is removed. Only available if
+    /// [`MenuCapability::TRAY_ICON`] is in the [`info`], the [`RAW_MENU_COMMAND_EVENT`] notifies when a menu item or the
+    /// tray primary command is clicked.
+    ///
+    /// Note that the tray icon is lost on view-process respawn, you must subscribe to the
+    /// [`VIEW_PROCESS_INITED_EVENT`] to set again.
+    ///
+    /// [`MenuCapability::TRAY_ICON`]: zng_view_api::menu::MenuCapability::TRAY_ICON
+    /// [`RAW_MENU_COMMAND_EVENT`]: crate::view_process::raw_events::RAW_MENU_COMMAND_EVENT
+    /// [`info`]: Self::info
+    pub fn set_tray_icon(&self, icon: Option<&ViewImageHandle>, mut tray: TrayIcon) -> Result<()> {
+        let mut app = self.write();
+        match icon.and_then(|i| i.0.as_ref()) {
+            Some(icon) => {
+                if app.process.generation() != icon.1 {
+                    return Err(ChannelError::disconnected());
+                }
+                tray.icon = icon.2;
+            }
+            None => tray = TrayIcon::none(),
+        }
+        app.process.set_tray_icon(tray)
+    }
+
     /// Number of frame send that have not finished rendering.
     ///
     /// This is the sum of pending frames for all renderers.
@@ -406,10 +483,13 @@ impl VIEW_PROCESS {
             encoding_images: vec![],
             loading_audios: vec![],
             pending_frames: IdMap::new(),
+            hidden_windows: IdSet::new(),
+            minimized_windows: IdSet::new(),
             message_dialogs: vec![],
             file_dialogs: vec![],
             notifications: vec![],
             ping_count: 0,
+            frame_pending_since: None,
         });
     }
 
@@ -639,11 +719,21 @@ impl VIEW_PROCESS {
         found.map(|h| ViewAudioHandle(Some(h)))
     }
 
+    /// Gets if any renderer has not finished rendering a frame sent to it.
+    pub(crate) fn has_pending_frames(&self) -> bool {
+        self.read().pending_frames.values().any(|&c| c > 0)
+    }
+
     pub(crate) fn on_frame_rendered(&self, id: WindowId) {
         let mut vp = self.write();
         if let Some(c) = vp.pending_frames.get_mut(&id) {
             *c = c.saturating_sub(1);
         }
+        vp.frame_pending_since = None;
+    }
+
+    pub(crate) fn on_window_state(&self, id: WindowId, state: WindowState) {
+        self.write().set_window_minimized(id, state == WindowState::Minimized);
     }
 
     pub(crate) fn on_frame_image(&self, data: &ImageDecoded) -> ViewImageHandle {
@@ -694,6 +784,9 @@ impl VIEW_PROCESS {
     pub(super) fn on_respawned(&self, _gen: ViewProcessGen) {
         let mut app = self.write();
         app.pending_frames.clear();
+        app.hidden_windows.clear();
+        app.minimized_windows.clear();
+        app.frame_pending_since = None;
         for (_, r) in app.message_dialogs.drain(..) {
             r.respond(MsgDialogResponse::Error(Txt::from_static("respawn")));
         }
@@ -718,6 +811,32 @@ impl VIEW_PROCESS {
             tracing::error!("incorrect ping response, expected {count}, was {c}");
         }
         app.ping_count = count;
+
+        // the view-process main loop can stay responsive while a renderer thread is frozen,
+        // so pending frames that never render also count as unresponsive.
+        // hidden and minimized windows may never present.
+        if app
+            .pending_frames
+            .iter()
+            .any(|(id, &c)| c > 0 && !app.hidden_windows.contains(id) && !app.minimized_windows.contains(id))
+        {
+            let since = *app.frame_pending_since.get_or_insert_with(Instant::now);
+            let timeout = app.process.unresponsive_timeout();
+            if since.elapsed() >= timeout {
+                app.frame_pending_since = None;
+                let generation = app.process.generation();
+                let same_process = app.process.same_process();
+                drop(app);
+
+                tracing::error!("view-process did not render a frame for {timeout:?}, considered unresponsive");
+                VIEW_PROCESS_UNRESPONSIVE_EVENT.notify(ViewProcessUnresponsiveArgs::now(generation));
+                if !same_process {
+                    self.respawn();
+                }
+            }
+        } else {
+            app.frame_pending_since = None;
+        }
     }
 
     pub(crate) fn on_pong(&self, count: u16) {
@@ -730,6 +849,22 @@ impl VIEW_PROCESS {
     }
 }
 impl ViewProcessService {
+    fn set_window_hidden(&mut self, id: WindowId, hidden: bool) {
+        if hidden {
+            self.hidden_windows.insert(id);
+        } else {
+            self.hidden_windows.remove(&id);
+        }
+    }
+
+    fn set_window_minimized(&mut self, id: WindowId, minimized: bool) {
+        if minimized {
+            self.minimized_windows.insert(id);
+        } else {
+            self.minimized_windows.remove(&id);
+        }
+    }
+
     #[must_use = "if `true` all current WinId, DevId and MonId are invalid"]
     fn check_generation(&mut self) -> bool {
         let vp_gen = self.process.generation();
@@ -767,6 +902,19 @@ event_args! {
             true
         }
     }
+
+    /// Arguments for the [`VIEW_PROCESS_UNRESPONSIVE_EVENT`].
+    pub struct ViewProcessUnresponsiveArgs {
+        /// Generation of the view-process that is unresponsive.
+        pub generation: ViewProcessGen,
+
+        ..
+
+        /// Broadcast to all.
+        fn is_in_target(&self, _id: WidgetId) -> bool {
+            true
+        }
+    }
 }
 impl std::ops::Deref for ViewProcessInitedArgs {
     type Target = zng_view_api::ViewProcessInfo;
@@ -784,6 +932,13 @@ event! {
     /// The view-process will only be available if the app resumes. On resume [`VIEW_PROCESS_INITED_EVENT`]
     /// notify a view-process respawn.
     pub static VIEW_PROCESS_SUSPENDED_EVENT: ViewProcessSuspendedArgs;
+    /// View-Process did not respond or did not render a requested frame for longer than the `ZNG_VIEW_TIMEOUT`.
+    ///
+    /// The app pings the view-process periodically, if no event is received or a pending frame does not render
+    /// within the timeout the view-process is considered hung and is respawned after this event. Pending frames of
+    /// hidden or minimized windows are ignored, as these windows may not present. In same process mode the view-process
+    /// cannot be respawned, this event only notifies.
+    pub static VIEW_PROCESS_UNRESPONSIVE_EVENT: ViewProcessUnresponsiveArgs;
 }
 
 /// Information about a successfully opened window.
@@ -853,7 +1008,11 @@ impl ViewWindow {
 
     /// Set the window visibility.
     pub fn set_visible(&self, visible: bool) -> Result<()> {
-        self.0.call(|id, p| p.set_visible(id, visible))
+        self.0.call(|id, p| p.set_visible(id, visible))?;
+        VIEW_PROCESS
+            .handle_write(self.0.app_id)
+            .set_window_hidden(WindowId::from_raw(self.0.id.get()), !visible);
+        Ok(())
     }
 
     /// Set if the window is "top-most".
@@ -886,6 +1045,22 @@ impl ViewWindow {
         })
     }
 
+    /// Set the window icon from images of the same icon at different sizes.
+    ///
+    /// The system selects the best size for each place the icon is shown. An empty set removes the custom icon.
+    pub fn set_icon_set(&self, icons: &[ViewImageHandle]) -> Result<()> {
+        self.0.call(|id, p| {
+            let mut ids = Vec::with_capacity(icons.len());
+            for icon in icons.iter().filter_map(|i| i.0.as_ref()) {
+                if p.generation() != icon.1 {
+                    return Err(ChannelError::disconnected());
+                }
+                ids.push(icon.2);
+            }
+            p.set_icon_set(id, ids)
+        })
+    }
+
     /// Set the window cursor icon and visibility.
     pub fn set_cursor(&self, cursor: Option<CursorIcon>) -> Result<()> {
         self.0.call(|id, p| p.set_cursor(id, cursor))
@@ -921,9 +1096,53 @@ impl ViewWindow {
         self.0.call(|id, p| p.bring_to_top(id))
     }
 
+    /// Query the modifier keys currently pressed from the operating system.
+    pub fn modifiers_state(&self) -> Result<ModifiersState> {
+        self.0
+            .call(|id, p| p.modifiers_state(id))
+            .map(|m| ModifiersState::from_bits_truncate(m.bits()))
+    }
+
     /// Set the window state.
     pub fn set_state(&self, state: WindowStateAll) -> Result<()> {
-        self.0.call(|id, p| p.set_state(id, state))
+        let minimized = state.state == WindowState::Minimized;
+        self.0.call(|id, p| p.set_state(id, state))?;
+        VIEW_PROCESS
+            .handle_write(self.0.app_id)
+            .set_window_minimized(WindowId::from_raw(self.0.id.get()), minimized);
+        Ok(())
+    }
+
+    /// Move the window to the center of the owner window or the active monitor work area.
+    ///
+    /// If `on_owner` is `true` centers on the owner window, if the window has no owner or `on_owner` is `false`
+    /// centers on the active monitor.
+    pub fn center(&self, on_owner: bool) -> Result<()> {
+        let target = if on_owner {
+            CenterTarget::ParentWindow
+        } else {
+            CenterTarget::ActiveMonitor
+        };
+        self.0.call(|id, p| p.center_window(id, target))
+    }
+
+    /// Move the window to the center of the `monitor` work area.
+    pub fn center_on_monitor(&self, monitor: MonitorId) -> Result<()> {
+        let api_monitor = VIEW_PROCESS
+            .read()
+            .monitor_ids
+            .iter()
+            .find_map(|(api_id, id)| if *id == monitor { Some(*api_id) } else { None });
+        let target = match api_monitor {
+            Some(m) => CenterTarget::Monitor(m),
+            None => CenterTarget::ActiveMonitor,
+        };
+        self.0.call(|id, p| p.center_window(id, target))
+    }
+
+    /// Restore the window to the state it was opened with.
+    pub fn reset_state(&self) -> Result<()> {
+        self.0.call(|id, p| p.reset_window_state(id))
     }
 
     /// Set video mode used in exclusive fullscreen.
@@ -936,6 +1155,11 @@ impl ViewWindow {
         self.0.call(|id, p| p.set_enabled_buttons(id, buttons))
     }
 
+    /// Set the window content render transform.
+    pub fn set_transform(&self, transform: PxTransform) -> Result<()> {
+        self.0.call(|id, p| p.set_window_transform(id, transform))
+    }
+
     /// Reference the window renderer.
     pub fn renderer(&self) -> ViewRenderer {
         ViewRenderer(ArcEq::downgrade(&self.0))
@@ -976,6 +1200,13 @@ impl ViewWindow {
         self.0.call(|id, p| p.drag_resize(id, direction))
     }
 
+    /// Set a region of the window that starts a [`drag_move`] when pressed with the left mouse button.
+    ///
+    /// [`drag_move`]: Self::drag_move
+    pub fn set_drag_region(&self, region: Option<DipRect>) -> Result<()> {
+        self.0.call(|id, p| p.set_drag_region(id, region))
+    }
+
     /// Start a drag and drop operation, if the window is pressed.
     ///
     /// A [`RAW_APP_DRAG_ENDED_EVENT`] will be received when the operation finishes.
@@ -994,6 +1225,23 @@ impl ViewWindow {
         self.0.call(|id, p| p.drag_dropped(id, drop_id, applied))
     }
 
+    /// Set the effect that will be applied if the current drag&drop hovering the window is dropped.
+    ///
+    /// Empty `effect` rejects the drop.
+    pub fn set_drop_effect(&self, effect: DragDropEffect) -> Result<()> {
+        self.0.call(|id, p| p.set_drop_effect(id, effect))
+    }
+
+    /// Toggle the window between the maximized and normal state, like a double-click on the system title bar.
+    pub fn maximize_toggle(&self) -> Result<()> {
+        self.0.call(|id, p| p.maximize_toggle(id))
+    }
+
+    /// Set the window to the `Normal` state and position it to fill a region of the current monitor work area.
+    pub fn snap(&self, zone: SnapZone) -> Result<()> {
+        self.0.call(|id, p| p.snap_window(id, zone))
+    }
+
     /// Open system title bar context menu.
     pub fn open_title_bar_context_menu(&self, position: DipPoint) -> Result<()> {
         self.0.call(|id, p| p.open_title_bar_context_menu(id, position))
@@ -1045,6 +1293,33 @@ impl ViewWindow {
         self.0.call(move |id, p| p.set_system_shutdown_warn(id, reason))
     }
 
+    /// Set the number of buffers in the window swap chain, returns the actual depth.
+    pub fn set_swap_chain_depth(&self, depth: u8) -> Result<u8> {
+        self.0.call(move |id, p| p.set_swap_chain_depth(id, depth))
+    }
+
+    /// Set or unset a non-rectangular window shape.
+    ///
+    /// Note that the mask image must stay loaded while the shape is set.
+    pub fn set_shape(&self, shape: Option<WindowShape>) -> Result<()> {
+        self.0.call(move |id, p| p.set_window_shape(id, shape))
+    }
+
+    /// Set or unset the window input region.
+    ///
+    /// Pointer input outside the region passes through to the windows below.
+    pub fn set_input_region(&self, region: Option<Vec<DipRect>>) -> Result<()> {
+        self.0.call(move |id, p| p.set_input_region(id, region))
+    }
+
+    /// Set or unset the owner window.
+    ///
+    /// Owned windows are minimized and restored together with the owner and always stay on top of it.
+    pub fn set_owner(&self, owner: Option<WindowId>) -> Result<()> {
+        self.0
+            .call(move |id, p| p.set_window_owner(id, owner.map(|o| ApiWindowId::from_raw(o.get()))))
+    }
+
     /// Drop `self`.
     pub fn close(self) {
         drop(self)
@@ -1231,7 +1506,10 @@ impl Drop for ViewWindowData {
             let mut app = VIEW_PROCESS.handle_write(self.app_id);
             if self.generation == app.process.generation() {
                 let _ = app.process.close(self.id);
-                app.pending_frames.remove(&WindowId::from_raw(self.id.get()));
+                let id = WindowId::from_raw(self.id.get());
+                app.pending_frames.remove(&id);
+                app.hidden_windows.remove(&id);
+                app.minimized_windows.remove(&id);
             }
         }
     }
@@ -1409,6 +1687,16 @@ impl ViewRenderer {
         }
     }
 
+    /// Create a new image resource from the current rendered frame, downscaled to fit `max_size`.
+    pub fn window_thumbnail(&self, max_size: PxSize) -> Result<ViewImageHandle> {
+        if let Some(c) = self.0.upgrade() {
+            let id = c.call(|id, p| p.window_thumbnail(id, max_size))?;
+            Ok(Self::add_frame_image(c.app_id, id))
+        } else {
+            Err(ChannelError::disconnected())
+        }
+    }
+
     fn add_frame_image(app_id: AppId, id: ImageId) -> ViewImageHandle {
         if id == ImageId::INVALID {
             ViewImageHandle::dummy()
@@ -18,12 +18,12 @@ use zng_txt::Txt;
 use zng_var::WeakEq;
 use zng_view_api::{
     AxisId, DragDropId, Ime,
-    api_extension::{ApiExtensionId, ApiExtensionPayload},
+    api_extension::{ApiExtensionId, ApiExtensionPayload, ApiExtensionRequestId},
     audio::{AudioDecoded, AudioMetadata},
     config::{AnimationsConfig, ColorsConfig, FontAntiAliasing, KeyRepeatConfig, LocaleConfig, MultiClickConfig, TouchConfig},
     drag_drop::{DragDropData, DragDropEffect},
     image::{ImageDecoded, ImageMetadata},
-    keyboard::{Key, KeyCode, KeyLocation, KeyState},
+    keyboard::{GlobalHotkeyId, Key, KeyCode, KeyLocation, KeyState},
     mouse::{ButtonState, MouseButton, MouseScrollDelta},
     touch::{TouchPhase, TouchUpdate},
     window::{EventCause, FrameId, FrameWaitId, HeadlessOpenData, MonitorInfo, WindowStateAll},
@@ -81,6 +81,35 @@ event_args! {
         }
     }
 
+    /// Arguments for the [`RAW_MENU_COMMAND_EVENT`].
+    pub struct RawMenuCommandArgs {
+        /// Menu command ID.
+        pub id: Txt,
+
+        ..
+
+        /// Broadcast to all widgets.
+        fn is_in_target(&self, id: WidgetId) -> bool {
+            true
+        }
+    }
+
+    /// Arguments for the [`RAW_GLOBAL_HOTKEY_EVENT`].
+    pub struct RawGlobalHotkeyArgs {
+        /// The registered hotkey.
+        pub hotkey_id: GlobalHotkeyId,
+
+        /// If the hotkey was pressed or released.
+        pub state: KeyState,
+
+        ..
+
+        /// Broadcast to all widgets.
+        fn is_in_target(&self, id: WidgetId) -> bool {
+            true
+        }
+    }
+
     /// Arguments for the [`RAW_IME_EVENT`].
     pub struct RawImeArgs {
         /// Window that received the event.
@@ -811,6 +840,23 @@ event_args! {
         }
     }
 
+    /// Arguments for the [`RAW_EXTENSION_RESPONSE_EVENT`].
+    pub struct RawExtensionResponseArgs {
+        /// Id of the extension that responded.
+        pub extension_id: ApiExtensionId,
+        /// Request ID from the pending payload returned by the extension command.
+        pub request_id: ApiExtensionRequestId,
+        /// Command response.
+        pub response: ApiExtensionPayload,
+
+        ..
+
+        /// Broadcast to all widgets.
+        fn is_in_target(&self, id: WidgetId) -> bool {
+            true
+        }
+    }
+
     /// Arguments for [`LOW_MEMORY_EVENT`].
     pub struct LowMemoryArgs {
 
@@ -832,6 +878,20 @@ event! {
     /// *See also the [module level documentation](self) for details of how you can fake this event*
     pub static RAW_KEY_INPUT_EVENT: RawKeyInputArgs;
 
+    /// A global hotkey registered using [`VIEW_PROCESS.register_global_hotkey`] was pressed or released.
+    ///
+    /// This event is received even when no app window is focused.
+    ///
+    /// [`VIEW_PROCESS.register_global_hotkey`]: crate::view_process::VIEW_PROCESS::register_global_hotkey
+    pub static RAW_GLOBAL_HOTKEY_EVENT: RawGlobalHotkeyArgs;
+
+    /// A system menu item or tray icon primary command was clicked.
+    ///
+    /// See [`VIEW_PROCESS.set_tray_icon`] for more details.
+    ///
+    /// [`VIEW_PROCESS.set_tray_icon`]: crate::view_process::VIEW_PROCESS::set_tray_icon
+    pub static RAW_MENU_COMMAND_EVENT: RawMenuCommandArgs;
+
     /// An IME event was received by a window.
     pub static RAW_IME_EVENT: RawImeArgs;
 
@@ -966,4 +1026,10 @@ event! {
 
     /// Custom view-process extension event.
     pub static RAW_EXTENSION_EVENT: RawExtensionEventArgs;
+
+    /// Asynchronous response of a view-process extension command.
+    ///
+    /// Extension commands can return a [`ApiExtensionPayload::pending`] response, the actual
+    /// response is notified in this event later.
+    pub static RAW_EXTENSION_RESPONSE_EVENT: RawExtensionResponseArgs;
 }
@@ -1152,6 +1152,30 @@ impl WIDGET {
         }
     }
 
+    /// Spawn a parallel async task that is cancelled (dropped) when the widget is deinited.
+    ///
+    /// The `task` runs like [`task::spawn`], but if the widget deinits before the task finishes the task is
+    /// dropped at the next await point, this avoids leaking per-widget background work after the widget is removed.
+    ///
+    /// [`task::spawn`]: zng_task::spawn
+    pub fn spawn<F>(&self, task: impl IntoFuture<IntoFuture = F>)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let cancel = zng_task::SignalOnce::new();
+        if WIDGET_HANDLES_CTX.is_default() {
+            WIDGET_CTX.get().handles.push_task(cancel.clone());
+        } else {
+            WIDGET_HANDLES_CTX.get().push_task(cancel.clone());
+        }
+        let task = task.into_future();
+        zng_task::spawn(async move {
+            zng_task::any!(task, cancel.clone()).await;
+            // finished, allow removal from the widget handles
+            cancel.set();
+        });
+    }
+
     /// Transform point in the window space to the widget inner bounds.
     pub fn win_point_to_wgt(&self, point: DipPoint) -> Option<PxPoint> {
         let wgt_info = WIDGET.info();
@@ -1362,7 +1386,7 @@ impl WidgetCtx {
     /// If `retain_state` is enabled the state will not be cleared and can still read.
     pub fn deinit(&mut self, retain_state: bool) {
         let ctx = self.0.as_mut().unwrap();
-        ctx.handles.var_handles.lock().clear();
+        ctx.handles.clear();
         ctx.flags.store(UpdateFlags::empty(), Relaxed);
         *ctx.render_reuse.lock() = None;
 
@@ -1444,12 +1468,34 @@ impl WidgetCtxData {
 
 struct WidgetHandlesCtxData {
     var_handles: Mutex<VarHandles>,
+    tasks: Mutex<Vec<zng_task::SignalOnce>>,
 }
 
 impl WidgetHandlesCtxData {
     const fn dummy() -> Self {
         Self {
             var_handles: Mutex::new(VarHandles::dummy()),
+            tasks: Mutex::new(vec![]),
+        }
+    }
+
+    fn push_task(&self, cancel: zng_task::SignalOnce) {
+        let mut tasks = self.tasks.lock();
+        tasks.retain(|t| !t.is_set());
+        tasks.push(cancel);
+    }
+
+    fn clear(&self) {
+        self.var_handles.lock().clear();
+        for t in self.tasks.lock().drain(..) {
+            t.set();
+        }
+    }
+}
+impl Drop for WidgetHandlesCtxData {
+    fn drop(&mut self) {
+        for t in self.tasks.get_mut().drain(..) {
+            t.set();
         }
     }
 }
@@ -1466,8 +1512,7 @@ impl WidgetHandlesCtx {
 
     /// Drop all handles.
     pub fn clear(&mut self) {
-        let h = self.0.as_ref().unwrap();
-        h.var_handles.lock().clear();
+        self.0.as_ref().unwrap().clear();
     }
 }
 impl Default for WidgetHandlesCtx {
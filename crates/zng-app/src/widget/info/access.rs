@@ -45,6 +45,14 @@ impl WidgetAccessInfoBuilder<'_> {
         self.with_access(|a| a.role = Some(role))
     }
 
+    /// Set a human readable description of the widget role.
+    ///
+    /// Only use this to describe custom widgets that are not fully represented by a standard [`AccessRole`].
+    pub fn set_role_description(&mut self, description: impl Into<Txt>) {
+        let description = description.into();
+        self.with_access(|a| a.set_state(AccessState::RoleDescription(description)))
+    }
+
     /// Add a supported access command.
     pub fn push_command(&mut self, cmd: AccessCmdName) {
         self.with_access(|a| a.commands.push(cmd))
@@ -641,6 +649,11 @@ impl WidgetAccessInfo {
         &self.access().commands
     }
 
+    /// Human readable description of the widget role.
+    pub fn role_description(&self) -> Option<Txt> {
+        get_state!(self.RoleDescription).cloned()
+    }
+
     /// How input text triggers display of one or more predictions of the user's intended value.
     pub fn auto_complete(&self) -> Option<AutoComplete> {
         get_state!(self.AutoComplete).copied()
@@ -971,9 +984,67 @@ impl WidgetAccessInfo {
             });
         }
 
+        // only validates once for each info rebuild, not for every bounds update
+        #[cfg(debug_assertions)]
+        if !a.validated.swap(true, std::sync::atomic::Ordering::Relaxed) {
+            self.validate(&node);
+        }
+
         node
     }
 
+    /// Warn about common mistakes, like states that require a role set on a widget without role.
+    #[cfg(debug_assertions)]
+    fn validate(&self, node: &zng_view_api::access::AccessNode) {
+        let id = self.info.id();
+        let tree = &self.info.tree;
+        let exists = |n: &AccessNodeId| tree.get(WidgetId::from_raw(n.0)).is_some();
+        for state in &node.state {
+            match state {
+                AccessState::Checked(_)
+                | AccessState::Expanded(_)
+                | AccessState::Value(_)
+                | AccessState::ValueMin(_)
+                | AccessState::ValueMax(_)
+                | AccessState::ValueText(_)
+                | AccessState::RoleDescription(_)
+                    if node.role.is_none() =>
+                {
+                    tracing::warn!("access state {state:?} set on widget {id:?} without `access_role`");
+                }
+                AccessState::ErrorMessage(n) => match tree.get(WidgetId::from_raw(n.0)).and_then(|w| w.access()) {
+                    Some(w) if w.invalid().is_empty() => {
+                        tracing::warn!("widget {id:?} is error message for {:?} that is not `invalid`", w.info().id())
+                    }
+                    None => tracing::warn!("widget {id:?} is error message for a widget not found or not accessible"),
+                    _ => {}
+                },
+                AccessState::ActiveDescendant(n) if !exists(n) => {
+                    tracing::warn!("widget {id:?} active descendant not found")
+                }
+                AccessState::Controls(ns)
+                | AccessState::DescribedBy(ns)
+                | AccessState::Details(ns)
+                | AccessState::FlowTo(ns)
+                | AccessState::LabelledBy(ns)
+                | AccessState::Owns(ns) => {
+                    if let Some(n) = ns.iter().find(|n| !exists(n)) {
+                        tracing::warn!(
+                            "widget {id:?} access state references {:?} that is not found",
+                            WidgetId::from_raw(n.0)
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+        if let (Some(min), Some(max)) = (self.value_min(), self.value_max())
+            && min > max
+        {
+            tracing::warn!("widget {id:?} access `value_min` is greater than `value_max`");
+        }
+    }
+
     fn bounds_info(&self) -> ViewBoundsInfo {
         let bounds = self.info.bounds_info();
         let undo_parent_transform = self
@@ -1178,6 +1249,8 @@ struct AccessInfo {
     inverse_state: Vec<InverseAccessState>,
 
     view_bounds: Mutex<Option<ViewBoundsInfo>>,
+    #[cfg(debug_assertions)]
+    validated: std::sync::atomic::AtomicBool,
     build_handlers: Vec<Box<dyn Fn(AccessBuildArgs) + Send + Sync>>,
 }
 impl AccessInfo {
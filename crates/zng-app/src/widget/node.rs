@@ -22,7 +22,10 @@ mod trace;
 mod list;
 pub use list::*;
 use zng_app_proc_macros::widget;
-use zng_layout::{context::LAYOUT, unit::PxSize};
+use zng_layout::{
+    context::{LAYOUT, LayoutMetrics},
+    unit::PxSize,
+};
 use zng_var::{BoxAnyVarValue, ContextInitHandle, ResponseVar, response_done_var, response_var};
 
 use crate::{
@@ -837,6 +840,24 @@ impl UiNode {
         self.0.measure_list(wm, &measure, &fold_size)
     }
 
+    /// Measure the desired size of a node that is not inserted in a window.
+    ///
+    /// The node is inited, measured with the `metrics` and deinited. This can be used to compute the size of
+    /// content ahead of display, like sizing a popup before opening it. The `metrics` are usually the
+    /// current [`LAYOUT.metrics`] with custom constraints.
+    ///
+    /// This must be called inside a window context. Note that content that loads asynchronously, like fonts and images,
+    /// may not be ready on the first measure, the node must be inited again when inserted in a window.
+    ///
+    /// [`LAYOUT.metrics`]: zng_layout::context::LAYOUT::metrics
+    #[must_use]
+    pub fn measure_detached(&mut self, metrics: LayoutMetrics) -> PxSize {
+        self.init();
+        let size = LAYOUT.with_context(metrics, || self.measure(&mut WidgetMeasure::new_reuse(None)));
+        self.deinit();
+        size
+    }
+
     /// Update node layout.
     ///
     /// See [`UiNodeImpl::layout`] for more details.
@@ -1107,6 +1107,11 @@ impl_from_and_into_var! {
         GradientStops::from_colors(colors)
     }
 
+    /// [`GradientStops::from_colors`]
+    fn from(colors: &[Oklch]) -> GradientStops {
+        GradientStops::from_colors(colors)
+    }
+
     /// [`GradientStops::from_stops`]
     fn from<L: Into<Length> + Copy>(stops: &[(Rgba, L)]) -> GradientStops {
         GradientStops::from_stops(stops)
@@ -1135,6 +1140,11 @@ impl_from_and_into_var! {
         GradientStops::from_colors(colors)
     }
 
+    /// [`GradientStops::from_colors`]
+    fn from<const N: usize>(colors: &[Oklch; N]) -> GradientStops {
+        GradientStops::from_colors(colors)
+    }
+
     /// [`GradientStops::from_stops`]
     fn from<L: Into<Length> + Copy, const N: usize>(stops: &[(Rgba, L); N]) -> GradientStops {
         GradientStops::from_stops(stops)
@@ -710,6 +710,218 @@ impl_from_and_into_var! {
     }
 }
 
+/// OKLCH + alpha.
+///
+/// The OKLCH space is a cylindrical representation of the OKLab perceptual color space, changes in lightness and chroma
+/// are perceived uniformly across hues, this makes it a good space for color pickers and palette generation.
+///
+/// # Equality
+///
+/// Equality is determined using [`about_eq`] with `0.001` granularity for [`hue`](Oklch::hue)
+/// and `0.00001` granularity for the others.
+///
+/// [`about_eq`]: zng_layout::unit::about_eq
+#[derive(Copy, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Oklch {
+    /// Perceived lightness in the `[0.0..=1.0]` range, zero is black, one is white.
+    pub lightness: f32,
+    /// Chroma amount, zero is gray, the maximum chroma that can be displayed in sRGB depends on the hue and lightness,
+    /// it is never more than `0.4`.
+    pub chroma: f32,
+    /// Hue color angle in the `[0.0..=360.0]` range.
+    pub hue: f32,
+    /// Alpha channel in the `[0.0..=1.0]` range, zero is invisible, one is opaque.
+    pub alpha: f32,
+}
+impl PartialEq for Oklch {
+    fn eq(&self, other: &Self) -> bool {
+        about_eq(self.lightness, other.lightness, EQ_GRANULARITY)
+            && about_eq(self.chroma, other.chroma, EQ_GRANULARITY)
+            && about_eq(self.hue, other.hue, EQ_GRANULARITY_100)
+            && about_eq(self.alpha, other.alpha, EQ_GRANULARITY)
+    }
+}
+impl Eq for Oklch {}
+impl PartialOrd for Oklch {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Oklch {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        about_eq_ord(self.lightness, other.lightness, EQ_GRANULARITY)
+            .cmp(&about_eq_ord(self.chroma, other.chroma, EQ_GRANULARITY))
+            .cmp(&about_eq_ord(self.hue, other.hue, EQ_GRANULARITY_100))
+            .cmp(&about_eq_ord(self.alpha, other.alpha, EQ_GRANULARITY))
+    }
+}
+impl std::hash::Hash for Oklch {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        about_eq_hash(self.lightness, EQ_GRANULARITY, state);
+        about_eq_hash(self.chroma, EQ_GRANULARITY, state);
+        about_eq_hash(self.hue, EQ_GRANULARITY_100, state);
+        about_eq_hash(self.alpha, EQ_GRANULARITY, state);
+    }
+}
+impl Oklch {
+    /// Sets the [`lightness`](Self::lightness) value.
+    pub fn set_lightness<L: Into<Factor>>(&mut self, lightness: L) {
+        self.lightness = lightness.into().0;
+    }
+
+    /// Sets the [`chroma`](Self::chroma) value.
+    pub fn set_chroma(&mut self, chroma: f32) {
+        self.chroma = chroma.max(0.0);
+    }
+
+    /// Sets the [`hue`](Self::hue) color angle.
+    ///
+    /// The value is normalized to be in the `[0.0..=360.0]` range, that is `362.deg()` becomes `2.0`.
+    pub fn set_hue<H: Into<AngleDegree>>(&mut self, hue: H) {
+        self.hue = hue.into().modulo().0
+    }
+
+    /// Sets the [`alpha`](Self::alpha) value.
+    pub fn set_alpha<A: Into<Factor>>(&mut self, alpha: A) {
+        self.alpha = alpha.into().0
+    }
+
+    /// Returns a copy of this color with a new `lightness`.
+    pub fn with_lightness<L: Into<Factor>>(mut self, lightness: L) -> Self {
+        self.set_lightness(lightness);
+        self
+    }
+
+    /// Returns a copy of this color with a new `chroma`.
+    pub fn with_chroma(mut self, chroma: f32) -> Self {
+        self.set_chroma(chroma);
+        self
+    }
+
+    /// Returns a copy of this color with a new `hue`.
+    pub fn with_hue<H: Into<AngleDegree>>(mut self, hue: H) -> Self {
+        self.set_hue(hue);
+        self
+    }
+
+    /// Returns a copy of this color with a new `alpha`.
+    pub fn with_alpha<A: Into<Factor>>(mut self, alpha: A) -> Self {
+        self.set_alpha(alpha);
+        self
+    }
+
+    /// If all components are finite.
+    pub fn is_valid(&self) -> bool {
+        self.lightness.is_finite() && self.chroma.is_finite() && self.hue.is_finite() && self.alpha.is_finite()
+    }
+}
+impl fmt::Debug for Oklch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() || !self.is_valid() {
+            f.debug_struct("Oklch")
+                .field("lightness", &self.lightness)
+                .field("chroma", &self.chroma)
+                .field("hue", &self.hue)
+                .field("alpha", &self.alpha)
+                .finish()
+        } else {
+            let l = clamp_normal(self.lightness) * 100.0;
+            let h = AngleDegree(self.hue).modulo().0.round();
+            let a = clamp_normal(self.alpha) * 100.0;
+            if (a - 100.0).abs() <= EQ_GRANULARITY {
+                write!(f, "oklch({l}.pct(), {}, {h}.deg())", self.chroma)
+            } else {
+                write!(f, "oklcha({l}.pct(), {}, {h}.deg(), {a}.pct())", self.chroma)
+            }
+        }
+    }
+}
+impl fmt::Display for Oklch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let l = clamp_normal(self.lightness) * 100.0;
+        let h = AngleDegree(self.hue).modulo().0.round();
+        let a = clamp_normal(self.alpha) * 100.0;
+        if (a - 100.0).abs() <= EQ_GRANULARITY {
+            write!(f, "oklch({l}% {} {h}º)", self.chroma)
+        } else {
+            write!(f, "oklch({l}% {} {h}º / {a}%)", self.chroma)
+        }
+    }
+}
+impl_from_and_into_var! {
+    fn from(rgba: Rgba) -> Oklch {
+        fn linear(c: f32) -> f32 {
+            let c = clamp_normal(c);
+            if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+        }
+        let (r, g, b) = (linear(rgba.red), linear(rgba.green), linear(rgba.blue));
+
+        let l = (0.41222146 * r + 0.53633255 * g + 0.051445995 * b).cbrt();
+        let m = (0.2119035 * r + 0.6806995 * g + 0.10739696 * b).cbrt();
+        let s = (0.08830246 * r + 0.28171885 * g + 0.6299787 * b).cbrt();
+
+        let lightness = 0.21045426 * l + 0.7936178 * m - 0.004072047 * s;
+        let a = 1.9779985 * l - 2.4285922 * m + 0.4505937 * s;
+        let b = 0.025904037 * l + 0.78277177 * m - 0.8086758 * s;
+
+        let chroma = (a * a + b * b).sqrt();
+        let hue = if chroma <= EQ_GRANULARITY {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        };
+
+        Oklch {
+            lightness,
+            chroma,
+            hue,
+            alpha: rgba.alpha,
+        }
+    }
+
+    fn from(oklch: Oklch) -> Rgba {
+        let lightness = clamp_normal(oklch.lightness);
+        let chroma = oklch.chroma.max(0.0);
+        let (sin, cos) = oklch.hue.to_radians().sin_cos();
+        let (a, b) = (chroma * cos, chroma * sin);
+
+        let l = (lightness + 0.39633778 * a + 0.21580376 * b).powi(3);
+        let m = (lightness - 0.105561346 * a - 0.06385417 * b).powi(3);
+        let s = (lightness - 0.08948418 * a - 1.2914855 * b).powi(3);
+
+        fn gamma(c: f32) -> f32 {
+            let c = if c <= 0.0031308 { 12.92 * c } else { 1.055 * c.powf(1.0 / 2.4) - 0.055 };
+            (clamp_normal(c) * 255.0).round() / 255.0
+        }
+
+        Rgba {
+            red: gamma(4.0767417 * l - 3.3077116 * m + 0.23096994 * s),
+            green: gamma(-1.268438 * l + 2.6097574 * m - 0.34131938 * s),
+            blue: gamma(-0.0041960864 * l - 0.7034186 * m + 1.7076147 * s),
+            alpha: oklch.alpha,
+        }
+    }
+
+    fn from(hsla: Hsla) -> Oklch {
+        Rgba::from(hsla).into()
+    }
+
+    fn from(hsva: Hsva) -> Oklch {
+        Rgba::from(hsva).into()
+    }
+}
+impl Transitionable for Oklch {
+    fn lerp(self, to: &Self, step: EasingStep) -> Self {
+        let hue_delta = (to.hue - self.hue + 180.0).rem_euclid(360.0) - 180.0;
+        Oklch {
+            lightness: self.lightness.lerp(&to.lightness, step),
+            chroma: self.chroma.lerp(&to.chroma, step),
+            hue: (self.hue + hue_delta * step.0).rem_euclid(360.0),
+            alpha: self.alpha.lerp(&to.alpha, step),
+        }
+    }
+}
+
 // Util
 fn clamp_normal(i: f32) -> f32 {
     i.clamp(0.0, 1.0)
@@ -873,9 +1085,73 @@ pub fn hsva<H: Into<AngleDegree>, N: Into<Factor>, A: Into<Factor>>(hue: H, satu
     c
 }
 
+/// OKLCH color, opaque, alpha is set to `1.0`.
+///
+/// # Arguments
+///
+/// The `lightness` argument can be [`f32`] in the `0.0..=1.0` range or a [percentage](zng_layout::unit::FactorPercent),
+/// the `chroma` is usually in the `0.0..=0.4` range and the `hue` can be any [angle unit].
+///
+/// # Examples
+///
+/// ```
+/// use zng_color::oklch;
+/// use zng_layout::unit::*;
+///
+/// let orange = oklch(70.pct(), 0.19, 45.deg());
+/// ```
+///
+/// [angle unit]: trait@zng_layout::unit::AngleUnits
+pub fn oklch<L: Into<Factor>, H: Into<AngleDegree>>(lightness: L, chroma: f32, hue: H) -> Oklch {
+    oklcha(lightness, chroma, hue, 1.0)
+}
+
+/// OKLCH + alpha color.
+///
+/// # Arguments
+///
+/// The `lightness` and `alpha` arguments can be [`f32`] in the `0.0..=1.0` range or a [percentage](zng_layout::unit::FactorPercent),
+/// the `chroma` is usually in the `0.0..=0.4` range and the `hue` can be any [angle unit].
+///
+/// # Examples
+///
+/// ```
+/// use zng_color::oklcha;
+/// use zng_layout::unit::*;
+///
+/// let half_orange = oklcha(70.pct(), 0.19, 45.deg(), 50.pct());
+/// ```
+///
+/// [angle unit]: trait@zng_layout::unit::AngleUnits
+pub fn oklcha<L: Into<Factor>, H: Into<AngleDegree>, A: Into<Factor>>(lightness: L, chroma: f32, hue: H, alpha: A) -> Oklch {
+    let c = Oklch {
+        lightness: lightness.into().0,
+        chroma,
+        hue: hue.into().modulo().0,
+        alpha: alpha.into().0,
+    };
+    debug_assert!(c.is_valid(), "oklch color components must be finite, was {c:?}");
+    c
+}
+
 context_var! {
     /// Defines the preferred color scheme in a context.
     pub static COLOR_SCHEME_VAR: ColorScheme = ColorScheme::default();
+
+    /// Defines the blend between light and dark colors in a context, `0.fct()` is light and `1.fct()` is dark.
+    ///
+    /// By default is the [`COLOR_SCHEME_VAR`] exact value, windows animate this variable when the color scheme changes
+    /// if a transition duration is configured. Only [`LightDark`] colors blend, values that match the color scheme directly
+    /// still snap to the new scheme.
+    pub static COLOR_SCHEME_BLEND_VAR: Factor = COLOR_SCHEME_VAR.map(|&s| color_scheme_blend(s));
+}
+
+/// Gets the [`COLOR_SCHEME_BLEND_VAR`] value that represents the color `scheme`.
+pub fn color_scheme_blend(scheme: ColorScheme) -> Factor {
+    match scheme {
+        ColorScheme::Dark => 1.fct(),
+        _ => 0.fct(),
+    }
 }
 
 /// RGBA color pair.
@@ -934,11 +1210,7 @@ impl_from_and_into_var! {
 }
 impl IntoVar<Rgba> for LightDark {
     fn into_var(self) -> Var<Rgba> {
-        COLOR_SCHEME_VAR.map(move |s| match s {
-            ColorScheme::Light => self.light,
-            ColorScheme::Dark => self.dark,
-            _ => self.light,
-        })
+        COLOR_SCHEME_BLEND_VAR.map(move |&f| self.blend(f))
     }
 }
 impl LightDark {
@@ -980,11 +1252,20 @@ impl LightDark {
 
     /// Gets a contextual `Rgba` var that selects the color for the context scheme and `map` it.
     pub fn rgba_map<T: VarValue>(self, mut map: impl FnMut(Rgba) -> T + Send + 'static) -> Var<T> {
-        COLOR_SCHEME_VAR.map(move |s| match s {
-            ColorScheme::Light => map(self.light),
-            ColorScheme::Dark => map(self.dark),
-            _ => map(self.light),
-        })
+        COLOR_SCHEME_BLEND_VAR.map(move |&f| map(self.blend(f)))
+    }
+
+    /// Gets the light color if `dark_factor` is zero or less, the dark color if it is one or more, or a blend of both colors.
+    ///
+    /// See [`COLOR_SCHEME_BLEND_VAR`] for more details.
+    pub fn blend(self, dark_factor: Factor) -> Rgba {
+        if dark_factor <= 0.fct() {
+            self.light
+        } else if dark_factor >= 1.fct() {
+            self.dark
+        } else {
+            self.light.lerp(&self.dark, dark_factor)
+        }
     }
 
     /// Gets a contextual `Rgba` var that selects the color for the context scheme and converts it to `T`.
@@ -1044,22 +1325,14 @@ impl LightDarkVarExt for Var<LightDark> {
     fn rgba(&self) -> Var<Rgba> {
         expr_var! {
             let c = #{self.clone()};
-            match *#{COLOR_SCHEME_VAR} {
-                ColorScheme::Light => c.light,
-                ColorScheme::Dark => c.dark,
-                _ => c.light,
-            }
+            c.blend(*#{COLOR_SCHEME_BLEND_VAR})
         }
     }
 
     fn rgba_map<T: VarValue>(&self, mut map: impl FnMut(Rgba) -> T + Send + 'static) -> Var<T> {
         expr_var! {
             let c = #{self.clone()};
-            match *#{COLOR_SCHEME_VAR} {
-                ColorScheme::Light => map(c.light),
-                ColorScheme::Dark => map(c.dark),
-                _ => map(c.light),
-            }
+            map(c.blend(*#{COLOR_SCHEME_BLEND_VAR}))
         }
     }
 
@@ -1070,22 +1343,14 @@ impl LightDarkVarExt for Var<LightDark> {
     fn map_rgba(&self, mut map: impl FnMut(LightDark) -> LightDark + Send + 'static) -> Var<Rgba> {
         expr_var! {
             let c = map(*#{self.clone()});
-            match *#{COLOR_SCHEME_VAR} {
-                ColorScheme::Light => c.light,
-                ColorScheme::Dark => c.dark,
-                _ => c.light,
-            }
+            c.blend(*#{COLOR_SCHEME_BLEND_VAR})
         }
     }
 
     fn map_rgba_into<T: VarValue + From<Rgba>>(&self, mut map: impl FnMut(LightDark) -> LightDark + Send + 'static) -> Var<T> {
         expr_var! {
             let c = map(*#{self.clone()});
-            match *#{COLOR_SCHEME_VAR} {
-                ColorScheme::Light => T::from(c.light),
-                ColorScheme::Dark => T::from(c.dark),
-                _ => T::from(c.light),
-            }
+            T::from(c.blend(*#{COLOR_SCHEME_BLEND_VAR}))
         }
     }
 
@@ -1093,22 +1358,14 @@ impl LightDarkVarExt for Var<LightDark> {
         let fct = fct.into();
         expr_var! {
             let c = #{self.clone()}.shade_fct(fct);
-            match *#{COLOR_SCHEME_VAR} {
-                ColorScheme::Light => c.light,
-                ColorScheme::Dark => c.dark,
-                _ => c.light,
-            }
+            c.blend(*#{COLOR_SCHEME_BLEND_VAR})
         }
     }
 
     fn shade(&self, shade: i8) -> Var<Rgba> {
         expr_var! {
             let c = #{self.clone()}.shade(shade);
-            match *#{COLOR_SCHEME_VAR} {
-                ColorScheme::Light => c.light,
-                ColorScheme::Dark => c.dark,
-                _ => c.light,
-            }
+            c.blend(*#{COLOR_SCHEME_BLEND_VAR})
         }
     }
 
@@ -1116,22 +1373,14 @@ impl LightDarkVarExt for Var<LightDark> {
         let fct = fct.into();
         expr_var! {
             let c = #{self.clone()}.shade_fct(fct);
-            match *#{COLOR_SCHEME_VAR} {
-                ColorScheme::Light => T::from(c.light),
-                ColorScheme::Dark => T::from(c.dark),
-                _ => T::from(c.light),
-            }
+            T::from(c.blend(*#{COLOR_SCHEME_BLEND_VAR}))
         }
     }
 
     fn shade_into<T: VarValue + From<Rgba>>(&self, shade: i8) -> Var<T> {
         expr_var! {
             let c = #{self.clone()}.shade(shade);
-            match *#{COLOR_SCHEME_VAR} {
-                ColorScheme::Light => T::from(c.light),
-                ColorScheme::Dark => T::from(c.dark),
-                _ => T::from(c.light),
-            }
+            T::from(c.blend(*#{COLOR_SCHEME_BLEND_VAR}))
         }
     }
 }
@@ -1228,6 +1477,23 @@ mod tests {
         assert_eq!(a, b)
     }
 
+    #[test]
+    fn rgb_to_oklch() {
+        let color = rgba(0, 100, 200, 0.2);
+        let a = format!("{color:?}");
+        let b = format!("{:?}", Rgba::from(Oklch::from(color)));
+        assert_eq!(a, b)
+    }
+
+    #[test]
+    fn oklch_white_black() {
+        let white = Oklch::from(colors::WHITE);
+        assert!((white.lightness - 1.0).abs() < 0.001);
+        assert!(white.chroma < 0.001);
+        let black = Oklch::from(colors::BLACK);
+        assert!(black.lightness.abs() < 0.001);
+    }
+
     #[test]
     fn rgba_display() {
         macro_rules! test {
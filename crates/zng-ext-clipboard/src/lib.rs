@@ -78,6 +78,40 @@ impl From<ViewError> for ClipboardError {
     }
 }
 
+/// Clipboard data read by [`CLIPBOARD.read_best`].
+///
+/// [`CLIPBOARD.read_best`]: CLIPBOARD::read_best
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum ClipboardContent {
+    /// Text string.
+    Text(Txt),
+    /// Image, loaded by the [`IMAGES`] service.
+    ///
+    /// [`IMAGES`]: zng_ext_image::IMAGES
+    Image(ImageVar),
+    /// List of paths.
+    Paths(Vec<PathBuf>),
+    /// Custom data.
+    Extension {
+        /// Type key.
+        data_type: Txt,
+        /// The raw data.
+        data: IpcBytes,
+    },
+}
+impl ClipboardContent {
+    /// Gets the content type.
+    pub fn data_type(&self) -> ClipboardType {
+        match self {
+            ClipboardContent::Text(_) => ClipboardType::Text,
+            ClipboardContent::Image(_) => ClipboardType::Image,
+            ClipboardContent::Paths(_) => ClipboardType::Paths,
+            ClipboardContent::Extension { data_type, .. } => ClipboardType::Extension(data_type.clone()),
+        }
+    }
+}
+
 #[derive(Default)]
 struct ClipboardService {
     update_text: std::sync::Weak<Result<Option<Txt>, ClipboardError>>,
@@ -335,6 +369,50 @@ impl CLIPBOARD {
     pub fn available_types(&self) -> ClipboardTypes {
         VIEW_PROCESS.info().clipboard.clone()
     }
+
+    /// Gets the first data type in `preferences` order that is set on the clipboard.
+    ///
+    /// Types the view-process cannot read are skipped, so a paste handler can list the richest format first and degrade
+    /// to plain text, for example, `[ClipboardType::Extension("text/html".into()), ClipboardType::Text]`.
+    ///
+    /// Returns `Ok(None)` if none of the types are set on the clipboard. Errors reading a type are logged and the next
+    /// type is tried, the first error is returned only if no type could be read.
+    pub fn read_best(&self, preferences: impl IntoIterator<Item = ClipboardType>) -> Result<Option<ClipboardContent>, ClipboardError> {
+        if !VIEW_PROCESS.is_available() {
+            return Err(ClipboardError::Disconnected);
+        }
+        let available = self.available_types();
+
+        let mut first_error = None;
+        for data_type in preferences {
+            if !available.read.contains(&data_type) {
+                continue;
+            }
+            let r = match data_type {
+                ClipboardType::Text => self.text().map(|r| r.map(ClipboardContent::Text)),
+                ClipboardType::Image => self.image().map(|r| r.map(ClipboardContent::Image)),
+                ClipboardType::Paths => self.paths().map(|r| r.map(ClipboardContent::Paths)),
+                ClipboardType::Extension(data_type) => self
+                    .extension(data_type.clone())
+                    .map(|r| r.map(|data| ClipboardContent::Extension { data_type, data })),
+                _ => continue,
+            };
+            match r {
+                Ok(Some(c)) => return Ok(Some(c)),
+                Ok(None) | Err(ClipboardError::NotSupported) => {}
+                Err(ClipboardError::Disconnected) => return Err(ClipboardError::Disconnected),
+                Err(e) => {
+                    tracing::error!("clipboard read error, {e}");
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        match first_error {
+            Some(e) => Err(e),
+            None => Ok(None),
+        }
+    }
 }
 
 command! {
@@ -2,7 +2,10 @@
 Loaded data is !Send+!Sync so we probably don't need to cache it.
 */
 
-use std::{fmt, mem::size_of};
+use std::{
+    fmt::{self, Write as _},
+    mem::size_of,
+};
 
 use byteorder::{BigEndian, ByteOrder as _, ReadBytesExt};
 use icu_properties::props::{self, BinaryProperty};
@@ -178,6 +181,13 @@ impl<'a> ColorPalettes<'a> {
         })
     }
 
+    /// Gets the index of the palette that is selected by [`palette`], or 0 if the font has no palette.
+    ///
+    /// [`palette`]: Self::palette
+    pub fn palette_index(&self, p: impl Into<FontColorPalette>) -> u16 {
+        self.palette_i(p.into()).unwrap_or(0)
+    }
+
     fn palette_i(&self, p: FontColorPalette) -> Option<u16> {
         match p {
             FontColorPalette::Light => self
@@ -521,3 +531,237 @@ impl_from_and_into_var! {
         }
     }
 }
+
+/// If the font has a COLR table with version 1 or above.
+pub(crate) fn has_colr_v1(font: &ttf_parser::RawFace) -> bool {
+    match font.table(ttf_parser::Tag(COLR)) {
+        Some(t) => t.len() >= 2 && BigEndian::read_u16(t) >= 1,
+        None => false,
+    }
+}
+
+/// Convert a COLRv1 glyph to an SVG document.
+///
+/// The SVG view-box is a square of `units_per_em` size, with the font baseline at the bottom. Returns `None` if the glyph
+/// is not a color glyph in the font.
+///
+/// Sweep gradients are not supported by SVG, they are painted with the first stop color.
+pub(crate) fn colr_v1_svg(face: &ttf_parser::Face, glyph_id: ttf_parser::GlyphId, palette: u16) -> Option<String> {
+    if !face.is_color_glyph(glyph_id) {
+        return None;
+    }
+
+    let upem = face.units_per_em() as f32;
+    let mut painter = ColrSvgPainter {
+        face,
+        palette,
+        defs: String::new(),
+        body: String::new(),
+        outline: String::new(),
+        next_id: 0,
+    };
+    face.paint_color_glyph(glyph_id, palette, ttf_parser::RgbaColor::new(0, 0, 0, 255), &mut painter)?;
+
+    let mut svg = String::with_capacity(painter.defs.len() + painter.body.len() + 256);
+    let _ = write!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{upem}" height="{upem}" viewBox="0 0 {upem} {upem}"><defs>{}</defs><g transform="matrix(1 0 0 -1 0 {upem})">{}</g></svg>"#,
+        painter.defs, painter.body
+    );
+    Some(svg)
+}
+
+struct ColrSvgPainter<'a, 'f> {
+    face: &'f ttf_parser::Face<'a>,
+    palette: u16,
+    defs: String,
+    body: String,
+    // path data of the last `outline_glyph`
+    outline: String,
+    next_id: u32,
+}
+impl ColrSvgPainter<'_, '_> {
+    fn new_id(&mut self) -> u32 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    fn stops(&mut self, stops: ttf_parser::colr::GradientStopsIter) {
+        for s in stops {
+            let c = s.color;
+            let _ = write!(
+                self.defs,
+                r#"<stop offset="{}" stop-color="rgb({},{},{})" stop-opacity="{}"/>"#,
+                s.stop_offset,
+                c.red,
+                c.green,
+                c.blue,
+                c.alpha as f32 / 255.0
+            );
+        }
+    }
+
+    fn fill_rect(&mut self, fill: &str) {
+        // large enough to cover any clip in font units
+        let _ = write!(
+            self.body,
+            r#"<rect x="-32768" y="-32768" width="65536" height="65536" fill="{fill}"/>"#
+        );
+    }
+}
+impl<'a> ttf_parser::colr::Painter<'a> for ColrSvgPainter<'a, '_> {
+    fn outline_glyph(&mut self, glyph_id: ttf_parser::GlyphId) {
+        self.outline.clear();
+        let mut builder = SvgPathBuilder(&mut self.outline);
+        if self.face.outline_glyph(glyph_id, &mut builder).is_none() {
+            self.outline.clear();
+        }
+    }
+
+    fn paint(&mut self, paint: ttf_parser::colr::Paint<'a>) {
+        use ttf_parser::colr::{GradientExtend, Paint};
+
+        fn spread(e: GradientExtend) -> &'static str {
+            match e {
+                GradientExtend::Pad => "pad",
+                GradientExtend::Repeat => "repeat",
+                GradientExtend::Reflect => "reflect",
+            }
+        }
+
+        let face = self.face;
+        let coords = face.variation_coordinates();
+        match paint {
+            Paint::Solid(c) => {
+                let fill = format!("rgba({},{},{},{})", c.red, c.green, c.blue, c.alpha as f32 / 255.0);
+                self.fill_rect(&fill);
+            }
+            Paint::LinearGradient(g) => {
+                let id = self.new_id();
+                let _ = write!(
+                    self.defs,
+                    r#"<linearGradient id="g{id}" gradientUnits="userSpaceOnUse" x1="{}" y1="{}" x2="{}" y2="{}" spreadMethod="{}">"#,
+                    g.x0,
+                    g.y0,
+                    g.x1,
+                    g.y1,
+                    spread(g.extend)
+                );
+                self.stops(g.stops(self.palette, coords));
+                self.defs.push_str("</linearGradient>");
+                self.fill_rect(&format!("url(#g{id})"));
+            }
+            Paint::RadialGradient(g) => {
+                let id = self.new_id();
+                let _ = write!(
+                    self.defs,
+                    r#"<radialGradient id="g{id}" gradientUnits="userSpaceOnUse" fx="{}" fy="{}" fr="{}" cx="{}" cy="{}" r="{}" spreadMethod="{}">"#,
+                    g.x0,
+                    g.y0,
+                    g.r0,
+                    g.x1,
+                    g.y1,
+                    g.r1,
+                    spread(g.extend)
+                );
+                self.stops(g.stops(self.palette, coords));
+                self.defs.push_str("</radialGradient>");
+                self.fill_rect(&format!("url(#g{id})"));
+            }
+            Paint::SweepGradient(g) => {
+                if let Some(c) = g.stops(self.palette, coords).next().map(|s| s.color) {
+                    let fill = format!("rgba({},{},{},{})", c.red, c.green, c.blue, c.alpha as f32 / 255.0);
+                    self.fill_rect(&fill);
+                }
+            }
+        }
+    }
+
+    fn push_clip(&mut self) {
+        let id = self.new_id();
+        let _ = write!(self.defs, r#"<clipPath id="c{id}"><path d="{}"/></clipPath>"#, self.outline);
+        let _ = write!(self.body, r#"<g clip-path="url(#c{id})">"#);
+    }
+
+    fn push_clip_box(&mut self, clipbox: ttf_parser::colr::ClipBox) {
+        let id = self.new_id();
+        let _ = write!(
+            self.defs,
+            r#"<clipPath id="c{id}"><rect x="{}" y="{}" width="{}" height="{}"/></clipPath>"#,
+            clipbox.x_min,
+            clipbox.y_min,
+            clipbox.width(),
+            clipbox.height()
+        );
+        let _ = write!(self.body, r#"<g clip-path="url(#c{id})">"#);
+    }
+
+    fn pop_clip(&mut self) {
+        self.body.push_str("</g>");
+    }
+
+    fn push_layer(&mut self, mode: ttf_parser::colr::CompositeMode) {
+        use ttf_parser::colr::CompositeMode::*;
+        // SVG only supports the blend modes, Porter-Duff modes other than source-over are painted as source-over
+        let blend = match mode {
+            Screen => "screen",
+            Overlay => "overlay",
+            Darken => "darken",
+            Lighten => "lighten",
+            ColorDodge => "color-dodge",
+            ColorBurn => "color-burn",
+            HardLight => "hard-light",
+            SoftLight => "soft-light",
+            Difference => "difference",
+            Exclusion => "exclusion",
+            Multiply => "multiply",
+            Hue => "hue",
+            Saturation => "saturation",
+            Color => "color",
+            Luminosity => "luminosity",
+            _ => "normal",
+        };
+        self.body.push_str(r#"<g style="isolation:isolate;mix-blend-mode:"#);
+        self.body.push_str(blend);
+        self.body.push_str(r#"">"#);
+    }
+
+    fn pop_layer(&mut self) {
+        self.body.push_str("</g>");
+    }
+
+    fn push_transform(&mut self, t: ttf_parser::Transform) {
+        let _ = write!(
+            self.body,
+            r#"<g transform="matrix({} {} {} {} {} {})">"#,
+            t.a, t.b, t.c, t.d, t.e, t.f
+        );
+    }
+
+    fn pop_transform(&mut self) {
+        self.body.push_str("</g>");
+    }
+}
+
+struct SvgPathBuilder<'a>(&'a mut String);
+impl ttf_parser::OutlineBuilder for SvgPathBuilder<'_> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let _ = write!(self.0, "M{x} {y}");
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let _ = write!(self.0, "L{x} {y}");
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let _ = write!(self.0, "Q{x1} {y1} {x} {y}");
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let _ = write!(self.0, "C{x1} {y1} {x2} {y2} {x} {y}");
+    }
+
+    fn close(&mut self) {
+        self.0.push('Z');
+    }
+}
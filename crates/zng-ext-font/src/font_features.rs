@@ -1820,6 +1820,21 @@ impl FontVariations {
             .collect()
     }
 }
+/// Interpolates the variations set in both, variations not set in `to` are removed, variations not set in `self` are
+/// set to the `to` value.
+impl zng_var::animation::Transitionable for FontVariations {
+    fn lerp(self, to: &Self, step: zng_var::animation::easing::EasingStep) -> Self {
+        let mut r = Self::with_capacity(to.len());
+        for (name, to_value) in &to.0 {
+            let value = match self.get(*name) {
+                Some(from) => zng_var::animation::Transitionable::lerp(from, to_value, step),
+                None => *to_value,
+            };
+            r.0.push((*name, value));
+        }
+        r
+    }
+}
 impl fmt::Debug for FontVariations {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if f.alternate() {
@@ -831,6 +831,7 @@ bitflags! {
         const HAS_LIGATURES = 0b0000_0010;
         const HAS_RASTER_IMAGES = 0b0000_0100;
         const HAS_SVG_IMAGES = 0b0000_1000;
+        const HAS_COLR_V1 = 0b0001_0000;
     }
 }
 struct FontFaceMut {
@@ -945,6 +946,8 @@ impl FontFace {
             }
         }
 
+        let bytes = decompress_woff(bytes)?;
+
         let ttf_face = match ttf_parser::Face::parse(&bytes, face_index) {
             Ok(f) => f,
             Err(e) => {
@@ -979,6 +982,7 @@ impl FontFace {
         flags.set(FontFaceFlags::HAS_LIGATURES, has_ligatures);
         flags.set(FontFaceFlags::HAS_RASTER_IMAGES, has_raster_images);
         flags.set(FontFaceFlags::HAS_SVG_IMAGES, ttf_face.tables().svg.is_some());
+        flags.set(FontFaceFlags::HAS_COLR_V1, emoji_util::has_colr_v1(ttf_face.raw_face()));
 
         Ok(FontFace(Arc::new(LoadedFontFace {
             face_index,
@@ -1075,6 +1079,7 @@ impl FontFace {
         flags.set(FontFaceFlags::HAS_LIGATURES, has_ligatures);
         flags.set(FontFaceFlags::HAS_RASTER_IMAGES, has_raster_images);
         flags.set(FontFaceFlags::HAS_SVG_IMAGES, ttf_face.tables().svg.is_some());
+        flags.set(FontFaceFlags::HAS_COLR_V1, emoji_util::has_colr_v1(ttf_face.raw_face()));
 
         Ok(FontFace(Arc::new(LoadedFontFace {
             face_index,
@@ -1300,6 +1305,13 @@ impl FontFace {
     pub fn has_svg_images(&self) -> bool {
         self.0.flags.contains(FontFaceFlags::HAS_SVG_IMAGES)
     }
+
+    /// If this font has a COLR version 1 table, with gradient and composite color glyphs.
+    ///
+    /// These glyphs are rendered as SVG images, only if the `"svg"` feature is enabled.
+    pub fn has_colr_v1(&self) -> bool {
+        self.0.flags.contains(FontFaceFlags::HAS_COLR_V1)
+    }
 }
 
 /// A sized font face.
@@ -3993,6 +4005,19 @@ impl_from_and_into_var! {
     }
 }
 
+/// Decompress WOFF and WOFF2 font data to the SFNT format, other data is returned unchanged.
+fn decompress_woff(bytes: FontBytes) -> Result<FontBytes, FontLoadingError> {
+    let sfnt = match bytes.get(..4) {
+        Some(b"wOFF") => wuff::decompress_woff1(&bytes),
+        Some(b"wOF2") => wuff::decompress_woff2(&bytes),
+        _ => return Ok(bytes),
+    };
+    match sfnt {
+        Ok(sfnt) => Ok(FontBytes::from_vec(sfnt)?),
+        Err(e) => Err(FontLoadingError::Decompress(zng_txt::formatx!("{e:?}"))),
+    }
+}
+
 /// Reasons why a loader might fail to load a font.
 #[derive(Debug, Clone)]
 #[non_exhaustive]
@@ -4011,6 +4036,8 @@ pub enum FontLoadingError {
     NoFilesystem,
     /// A disk or similar I/O error occurred while attempting to load the font.
     Io(Arc<std::io::Error>),
+    /// Attempted to load a malformed or corrupted WOFF or WOFF2 font.
+    Decompress(Txt),
 }
 impl PartialEq for FontLoadingError {
     fn eq(&self, other: &Self) -> bool {
@@ -4033,6 +4060,7 @@ impl fmt::Display for FontLoadingError {
             Self::NoFilesystem => write!(f, "no filesystem present"),
             Self::Parse(e) => fmt::Display::fmt(e, f),
             Self::Io(e) => fmt::Display::fmt(e, f),
+            Self::Decompress(e) => write!(f, "cannot decompress font, {e}"),
         }
     }
 }
@@ -4052,6 +4080,15 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn decompress_woff_invalid() {
+        let r = decompress_woff(FontBytes::from_static(b"wOF2 not a font"));
+        assert!(matches!(r, Err(FontLoadingError::Decompress(_))));
+
+        let r = decompress_woff(FontBytes::from_static(b"\0\x01\0\0 sfnt")).unwrap();
+        assert_eq!(&r[..], b"\0\x01\0\0 sfnt");
+    }
+
     #[test]
     fn generic_fonts_default() {
         let _app = APP.minimal().run_headless(false);
@@ -186,6 +186,22 @@ impl SegmentedText {
         self.base_direction
     }
 
+    /// Gets the direction of the first strong directional character in the `text`.
+    ///
+    /// Returns `None` if the text has no strong directional character, for example, if it only contains
+    /// numbers, punctuation and emoji.
+    pub fn detect_direction(text: &str) -> Option<LayoutDirection> {
+        use unicode_bidi::BidiClass::*;
+        for c in text.chars() {
+            match unicode_bidi::HardcodedBidiData.bidi_class(c) {
+                L => return Some(LayoutDirection::LTR),
+                R | AL => return Some(LayoutDirection::RTL),
+                _ => {}
+            }
+        }
+        None
+    }
+
     /// Gets if the text contains segments not in the base direction.
     pub fn is_bidi(&self) -> bool {
         for seg in self.segments.iter() {
@@ -751,6 +767,18 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn detect_direction() {
+        assert_eq!(SegmentedText::detect_direction("Hello"), Some(LayoutDirection::LTR));
+        assert_eq!(SegmentedText::detect_direction("123, Hello"), Some(LayoutDirection::LTR));
+        assert_eq!(SegmentedText::detect_direction("مرحبا"), Some(LayoutDirection::RTL));
+        assert_eq!(SegmentedText::detect_direction("1. שלום"), Some(LayoutDirection::RTL));
+        assert_eq!(SegmentedText::detect_direction("Hello שלום"), Some(LayoutDirection::LTR));
+        assert_eq!(SegmentedText::detect_direction("שלום Hello"), Some(LayoutDirection::RTL));
+        assert_eq!(SegmentedText::detect_direction("123 (!?) 😀"), None);
+        assert_eq!(SegmentedText::detect_direction(""), None);
+    }
+
     #[test]
     fn reorder_line() {
         let test = "0 2 4";
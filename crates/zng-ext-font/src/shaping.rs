@@ -15,8 +15,8 @@ use zng_txt::Txt;
 use zng_view_api::font::{GlyphIndex, GlyphInstance};
 
 use crate::{
-    BidiLevel, CaretIndex, Font, FontList, HYPHENATION, Hyphens, Justify, LineBreak, ParagraphBreak, SegmentedText, TextSegment, WordBreak,
-    font_features::RFontFeatures,
+    BidiLevel, CaretIndex, Font, FontColorPalette, FontList, HYPHENATION, Hyphens, Justify, LineBreak, ParagraphBreak, SegmentedText,
+    TextSegment, WordBreak, font_features::RFontFeatures,
 };
 
 /// Reasons why a font might fail to load a glyph.
@@ -112,6 +112,11 @@ pub struct TextShapingArgs {
 
     /// Obscure the text with the replacement char.
     pub obscuring_char: Option<char>,
+
+    /// Color palette used to render COLRv1 glyphs.
+    ///
+    /// COLRv1 glyphs are converted to images during shaping, other colored glyphs select the palette during render.
+    pub font_palette: FontColorPalette,
 }
 impl Default for TextShapingArgs {
     fn default() -> Self {
@@ -136,6 +141,7 @@ impl Default for TextShapingArgs {
             hyphens: Default::default(),
             hyphen_char: Txt::from_char('-'),
             obscuring_char: None,
+            font_palette: FontColorPalette::Light,
         }
     }
 }
@@ -2001,6 +2007,47 @@ impl ShapedText {
         })
     }
 
+    /// Text ranges of all non-overlapping matches of `query` in the text.
+    ///
+    /// The `full_txt` must be the same text used to shape. If `case_sensitive` is `false` the text and query are
+    /// compared using the Unicode lowercase of each char. Returns empty if the `query` is empty.
+    pub fn find_ranges(&self, full_txt: &str, query: &str, case_sensitive: bool) -> Vec<ops::Range<CaretIndex>> {
+        let mut r = vec![];
+        if query.is_empty() {
+            return r;
+        }
+
+        let mut start = 0;
+        while start < full_txt.len() {
+            if let Some(len) = find_match_len(&full_txt[start..], query, case_sensitive) {
+                let end = start + len;
+                // start snaps to the line after a wrap point, end snaps to the line before
+                let start_caret = self.snap_caret_line(CaretIndex {
+                    index: start,
+                    line: usize::MAX,
+                });
+                let end_caret = self.snap_caret_line(CaretIndex { index: end, line: 0 });
+                r.push(start_caret..end_caret);
+                start = end;
+            } else {
+                start += full_txt[start..].chars().next().map(|c| c.len_utf8()).unwrap_or(1);
+            }
+        }
+        r
+    }
+
+    /// Bounding rectangles of all matches of `query` in the text.
+    ///
+    /// Matches that span multiple lines have one rectangle per line. See [`find_ranges`] for details about the search.
+    ///
+    /// [`find_ranges`]: Self::find_ranges
+    pub fn find(&self, full_txt: &str, query: &str, case_sensitive: bool) -> Vec<PxRect> {
+        self.find_ranges(full_txt, query, case_sensitive)
+            .into_iter()
+            .flat_map(|r| self.highlight_rects(r, full_txt))
+            .collect()
+    }
+
     /// Clip under/overline to a text `clip_range` area, if `clip_out` only lines outside the range are visible.
     pub fn clip_lines(
         &self,
@@ -2318,6 +2365,7 @@ struct ShapedTextBuilder {
     tab_index: u32,
     hyphens: Hyphens,
     lang: Lang,
+    font_palette: FontColorPalette,
 
     origin: euclid::Point2D<f32, ()>,
     allow_first_wrap: bool,
@@ -2389,6 +2437,7 @@ impl ShapedTextBuilder {
             tab_index: 0,
             hyphens: config.hyphens,
             lang: config.lang.clone(),
+            font_palette: config.font_palette,
             allow_first_wrap: false,
 
             origin: euclid::point2(0.0, 0.0),
@@ -2815,9 +2864,11 @@ impl ShapedTextBuilder {
         if !font.face().color_glyphs().is_empty() {
             self.out.has_colored_glyphs = true;
         }
-        if (font.face().has_raster_images() || (cfg!(feature = "svg") && font.face().has_svg_images()))
+        let has_colr_v1 = cfg!(feature = "svg") && font.face().has_colr_v1();
+        if (font.face().has_raster_images() || (cfg!(feature = "svg") && font.face().has_svg_images()) || has_colr_v1)
             && let Some(ttf) = font.face().ttf()
         {
+            let color_glyphs = font.face().color_glyphs();
             for (i, g) in shaped_seg.glyphs.iter().enumerate() {
                 let id = ttf_parser::GlyphId(g.index as _);
                 let ppm = font.size().0 as u16;
@@ -2828,6 +2879,12 @@ impl ShapedTextBuilder {
                     && let Some(img) = ttf.glyph_svg_image(id)
                 {
                     self.push_glyph_svg(glyphs_i as _, img);
+                } else if has_colr_v1
+                    && color_glyphs.glyph(g.index).is_none()
+                    && let Some(svg) =
+                        crate::emoji_util::colr_v1_svg(&ttf, id, font.face().color_palettes().palette_index(self.font_palette))
+                {
+                    self.push_glyph_colr_v1(glyphs_i as _, svg);
                 }
             }
         }
@@ -2983,6 +3040,11 @@ impl ShapedTextBuilder {
         self.push_glyph_img(glyphs_i, ImageSource::from((img.data, ImageDataFormat::from("svg"))));
     }
 
+    /// COLRv1 glyphs are converted to SVG using the shaping `font_palette`.
+    fn push_glyph_colr_v1(&mut self, glyphs_i: u32, svg: String) {
+        self.push_glyph_img(glyphs_i, ImageSource::from((svg.as_bytes(), ImageDataFormat::from("svg"))));
+    }
+
     fn push_glyph_img(&mut self, glyphs_i: u32, source: ImageSource) {
         let img = IMAGES.image(source, ImageOptions::cache(), None);
 
@@ -4821,6 +4883,26 @@ fn into_harf_direction(d: LayoutDirection) -> rustybuzz::Direction {
     }
 }
 
+/// Byte length of the `query` match at the start of `txt`.
+fn find_match_len(txt: &str, query: &str, case_sensitive: bool) -> Option<usize> {
+    if case_sensitive {
+        return txt.starts_with(query).then_some(query.len());
+    }
+
+    let mut query = query.chars().flat_map(char::to_lowercase).peekable();
+    for (i, c) in txt.char_indices() {
+        for c in c.to_lowercase() {
+            if query.next() != Some(c) {
+                return None;
+            }
+        }
+        if query.peek().is_none() {
+            return Some(i + c.len_utf8());
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -4969,6 +5051,31 @@ mod tests {
         .unwrap()
     }
 
+    #[test]
+    fn find_ranges() {
+        let font = test_font();
+        let text = SegmentedText::new("Find the fox, FOX and Fox.", LayoutDirection::LTR);
+        let shaped = font.shape_text(&text, &TextShapingArgs::default());
+
+        let ranges: Vec<_> = shaped
+            .find_ranges(text.text(), "fox", false)
+            .into_iter()
+            .map(|r| r.start.index..r.end.index)
+            .collect();
+        assert_eq!(ranges, vec![9..12, 14..17, 22..25]);
+
+        let ranges: Vec<_> = shaped
+            .find_ranges(text.text(), "Fox", true)
+            .into_iter()
+            .map(|r| r.start.index..r.end.index)
+            .collect();
+        assert_eq!(ranges, vec![22..25]);
+
+        assert_eq!(shaped.find(text.text(), "fox", false).len(), 3);
+        assert!(shaped.find(text.text(), "", false).is_empty());
+        assert!(shaped.find(text.text(), "wolf", false).is_empty());
+    }
+
     #[test]
     fn cluster_is_byte() {
         let font = test_font();
@@ -494,6 +494,44 @@ fn image(mut source: ImageSource, mut options: ImageOptions, limits: Option<Imag
                 }
             });
         }
+        ImageSource::MemoryMapped(path) => {
+            fn map(path: &ImageMmapPath, limit: &ImageSourceFilter<PathBuf>) -> std::io::Result<(IpcReadHandle, u64)> {
+                let path = path.path();
+                if !limit.allows(&path.to_path_buf()) {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::PermissionDenied,
+                        "file path no allowed by limit",
+                    ));
+                }
+                #[cfg(ipc)]
+                {
+                    // SAFETY: `ImageMmapPath::new` is unsafe and requires the file to not change
+                    let data = unsafe { IpcBytes::open_memmap_blocking(path.to_path_buf(), None) }?;
+                    let len = data.len() as u64;
+                    Ok((data.into(), len))
+                }
+                #[cfg(not(ipc))]
+                {
+                    let file = std::fs::File::open(path)?;
+                    let len = file.metadata()?.len();
+                    Ok((IpcReadHandle::best_read_blocking(file)?, len))
+                }
+            }
+            let data_format = match path.path().extension() {
+                Some(ext) => ImageDataFormat::FileExtension(ext.to_string_lossy().to_txt()),
+                None => ImageDataFormat::Unknown,
+            };
+            zng_task::spawn_wait(move || match map(&path, &limits.allow_path) {
+                Ok((data, len)) => {
+                    tracing::trace!("map {:?}, len: {:?}, fmt: {data_format:?}", path.path(), len.bytes());
+                    image_data(false, Some(key), data_format, data, options, limits, r)
+                }
+                Err(e) => {
+                    tracing::debug!("cannot map {:?}, {e}", path.path());
+                    r.set(ImageEntry::new_error(e.to_txt()));
+                }
+            });
+        }
         #[cfg(feature = "http")]
         ImageSource::Download(uri, accept) => {
             let accept = accept.unwrap_or_else(|| IMAGES.http_accept());
@@ -590,6 +628,8 @@ fn image_data(
         options.mask,
     );
     request.entries = options.entries;
+    request.region = options.region;
+    request.compressed_texture = options.compressed_texture;
 
     if is_respawn {
         request.parent = r.with(|r| r.data.meta.parent.clone());
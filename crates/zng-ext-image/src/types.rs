@@ -59,7 +59,8 @@ pub trait ImagesExtension: Send + Sync + Any {
 
     /// Image data loaded.
     ///
-    /// This is called for [`ImageSource::Read`], [`ImageSource::Download`] and [`ImageSource::Data`] after the data is loaded and before
+    /// This is called for [`ImageSource::Read`], [`ImageSource::MemoryMapped`], [`ImageSource::Download`] and [`ImageSource::Data`]
+    /// after the data is loaded and before
     /// decoding starts.
     ///
     /// Return a replacement variable to skip decoding or redirect to a different image. Note that by the time this is called the service
@@ -244,6 +245,16 @@ impl ImageEntry {
         self.data.meta.density
     }
 
+    /// Full image size and the decoded region if the image was requested with [`ImageOptions::region`].
+    ///
+    /// When set the [`size`] is the region size, or the downscaled region size. Memory mapped TIFF images only
+    /// read the strips or tiles that intersect the region, other formats decode the full image and crop it.
+    ///
+    /// [`size`]: Self::size
+    pub fn region(&self) -> Option<(PxSize, PxRect)> {
+        self.data.meta.region
+    }
+
     /// Gets the [`ImageFormat::display_name`] that was decoded or the [`ColorType::name`] if the image was not decoded.
     pub fn format_name(&self) -> Txt {
         self.data.meta.format_name.clone()
@@ -939,6 +950,20 @@ pub enum ImageSource {
     ///
     /// Image equality is defined by the path, a copy of the image in another path is a different image.
     Read(PathBuf),
+    /// A path to an image file in the file system that is memory mapped instead of read.
+    ///
+    /// The file is mapped by the app-process and shared with the view-process without copying the encoded bytes to memory,
+    /// this is recommended for very large images. The [`ImageLimits::max_encoded_len`] is not applied to memory mapped files,
+    /// the [`ImageLimits::max_decoded_len`] and [`ImageLimits::allow_path`] still apply. Use [`ImageOptions::region`] to
+    /// only decode parts of the image, the image widget presents memory mapped images in tiles, requesting only the
+    /// tiles that are visible in the scroll viewport at the zoom resolution.
+    ///
+    /// Image equality is defined by the path, the same as [`Read`], both sources share the same cache entry.
+    ///
+    /// Memory mapping requires the `"ipc"` feature, without it the file is read like [`Read`].
+    ///
+    /// [`Read`]: Self::Read
+    MemoryMapped(ImageMmapPath),
     /// A uri to an image resource downloaded using HTTP GET with an optional HTTP ACCEPT string.
     ///
     /// If the ACCEPT line is not given, all image formats supported by the view-process backend are accepted.
@@ -990,6 +1015,7 @@ impl ImageSource {
     pub fn hash128(&self, options: &ImageOptions) -> Option<ImageHash> {
         match self {
             ImageSource::Read(p) => Some(Self::hash128_read(p, options)),
+            ImageSource::MemoryMapped(p) => Some(Self::hash128_read(p.path(), options)),
             #[cfg(feature = "http")]
             ImageSource::Download(u, a) => Some(Self::hash128_download(u, a, options)),
             ImageSource::Data(h, _, _) => Some(Self::hash128_data(*h, options)),
@@ -1003,22 +1029,30 @@ impl ImageSource {
     ///
     /// [`Data`]: Self::Data
     pub fn hash128_data(data_hash: ImageHash, options: &ImageOptions) -> ImageHash {
-        if options.downscale.is_some() || options.mask.is_some() || !options.entries.is_empty() {
+        if options.downscale.is_some()
+            || options.mask.is_some()
+            || !options.entries.is_empty()
+            || options.region.is_some()
+            || options.compressed_texture
+        {
             use std::hash::Hash;
             let mut h = ImageHash::hasher();
             data_hash.0.hash(&mut h);
             options.downscale.hash(&mut h);
             options.mask.hash(&mut h);
             options.entries.hash(&mut h);
+            options.region.hash(&mut h);
+            options.compressed_texture.hash(&mut h);
             h.finish()
         } else {
             data_hash
         }
     }
 
-    /// Compute hash for a borrowed [`Read`] path.
+    /// Compute hash for a borrowed [`Read`] or [`MemoryMapped`] path.
     ///
     /// [`Read`]: Self::Read
+    /// [`MemoryMapped`]: Self::MemoryMapped
     pub fn hash128_read(path: &Path, options: &ImageOptions) -> ImageHash {
         use std::hash::Hash;
         let mut h = ImageHash::hasher();
@@ -1027,6 +1061,8 @@ impl ImageSource {
         options.downscale.hash(&mut h);
         options.mask.hash(&mut h);
         options.entries.hash(&mut h);
+        options.region.hash(&mut h);
+        options.compressed_texture.hash(&mut h);
         h.finish()
     }
 
@@ -1043,6 +1079,8 @@ impl ImageSource {
         options.downscale.hash(&mut h);
         options.mask.hash(&mut h);
         options.entries.hash(&mut h);
+        options.region.hash(&mut h);
+        options.compressed_texture.hash(&mut h);
         h.finish()
     }
 
@@ -1060,6 +1098,8 @@ impl ImageSource {
         options.downscale.hash(&mut h);
         options.mask.hash(&mut h);
         options.entries.hash(&mut h);
+        options.region.hash(&mut h);
+        options.compressed_texture.hash(&mut h);
         h.finish()
     }
 }
@@ -1388,10 +1428,33 @@ impl ImageSource {
     }
 }
 
+/// Path to an image file that can be memory mapped.
+///
+/// See [`ImageSource::MemoryMapped`] for more details.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ImageMmapPath(PathBuf);
+impl ImageMmapPath {
+    /// New memory mapped image file.
+    ///
+    /// # Safety
+    ///
+    /// You must ensure the file content does not change and that the file is not removed while the image is loaded,
+    /// changes to a memory mapped file are undefined behavior.
+    pub unsafe fn new(path: impl Into<PathBuf>) -> Self {
+        Self(path.into())
+    }
+
+    /// The file path.
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
 impl PartialEq for ImageSource {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::Read(l), Self::Read(r)) => l == r,
+            (Self::MemoryMapped(l), Self::MemoryMapped(r)) => l == r,
             #[cfg(feature = "http")]
             (Self::Download(lu, la), Self::Download(ru, ra)) => lu == ru && la == ra,
             (Self::Render(lf, la), Self::Render(rf, ra)) => Arc::ptr_eq(lf, rf) && la == ra,
@@ -1418,6 +1481,7 @@ impl fmt::Debug for ImageSource {
         }
         match self {
             ImageSource::Read(p) => f.debug_tuple("Read").field(p).finish(),
+            ImageSource::MemoryMapped(p) => f.debug_tuple("MemoryMapped").field(&p.path()).finish(),
             #[cfg(feature = "http")]
             ImageSource::Download(u, a) => f.debug_tuple("Download").field(u).field(a).finish(),
             ImageSource::Data(key, bytes, fmt) => f.debug_tuple("Data").field(key).field(bytes).field(fmt).finish(),
@@ -1541,6 +1605,9 @@ impl_from_and_into_var! {
         ImageSource::Data(ImageHash::compute(&data[..]), data, format.into())
     }
 }
+impl_from_and_into_var! {
+    fn from(some: ImageSource) -> Option<ImageSource>;
+}
 
 /// Cache mode of [`IMAGES`].
 ///
@@ -1784,7 +1851,7 @@ pub struct ImageLimits {
     /// An error is returned if the decoded image memory (width * height * 4) would surpass this.
     pub max_decoded_len: ByteLength,
 
-    /// Filter for [`ImageSource::Read`] paths.
+    /// Filter for [`ImageSource::Read`] and [`ImageSource::MemoryMapped`] paths.
     pub allow_path: PathFilter,
 
     /// Filter for [`ImageSource::Download`] URIs.
@@ -1868,6 +1935,16 @@ pub struct ImageOptions {
     pub mask: Option<ImageMaskMode>,
     /// How to decode containers with multiple images.
     pub entries: ImageEntriesMode,
+    /// Only decode this rectangle of the image pixels.
+    ///
+    /// See [`ImageEntry::region`] for more details.
+    pub region: Option<PxRect>,
+    /// Upload the image to the GPU as a compressed texture, if supported by the renderer.
+    ///
+    /// Compressed textures use 4x to 8x less GPU memory, the compression is lossy and done on first render, it is
+    /// intended for large collections of small images, like thumbnails. Falls back to uncompressed BGRA8 if the
+    /// renderer does not support S3TC (BC1/BC3).
+    pub compressed_texture: bool,
 }
 
 impl ImageOptions {
@@ -1883,6 +1960,8 @@ impl ImageOptions {
             downscale,
             mask,
             entries,
+            region: None,
+            compressed_texture: false,
         }
     }
 
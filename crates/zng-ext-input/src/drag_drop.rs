@@ -19,7 +19,7 @@
 //!
 //! * [`DRAG_DROP`]
 
-use std::mem;
+use std::{mem, path::PathBuf};
 
 use parking_lot::Mutex;
 use zng_app::{
@@ -42,11 +42,11 @@ use zng_state_map::StateId;
 use zng_task::channel::IpcBytes;
 use zng_txt::{Txt, formatx};
 use zng_var::{ArcEq, Var, var};
-use zng_view_api::{DragDropId, mouse::ButtonState, touch::TouchPhase};
+use zng_view_api::{DragDropId, image::ImageId, mouse::ButtonState, touch::TouchPhase};
 
 use crate::{mouse::MOUSE_INPUT_EVENT, touch::TOUCH_INPUT_EVENT};
 
-pub use zng_view_api::drag_drop::{DragDropData, DragDropEffect};
+pub use zng_view_api::drag_drop::{DragDropData, DragDropEffect, DragDropType};
 
 /// Drag & drop service.
 #[allow(non_camel_case_types)]
@@ -239,6 +239,10 @@ fn hooks() {
 
                 // drag_enter/leave.
                 let hovered_args = if s.hovered != target {
+                    if !s.system_dragging.is_empty() {
+                        // reset, new target handlers can reject
+                        WINDOWS_DRAG_DROP.set_drop_effect(args.window_id, DragDropEffect::all());
+                    }
                     let prev_target = mem::replace(&mut s.hovered, target.clone());
                     let args = DragHoveredArgs::now(prev_target, target.clone(), position, pos_hits.clone());
                     Some(args)
@@ -670,6 +674,43 @@ impl DropArgs {
             tracing::error!("drop already handled");
         }
     }
+
+    /// Gets the first text payload.
+    pub fn text(&self) -> Option<&Txt> {
+        self.data.iter().find_map(|d| match d {
+            DragDropData::Text(t) => Some(t),
+            _ => None,
+        })
+    }
+
+    /// Gets the first image payload.
+    pub fn image(&self) -> Option<ImageId> {
+        self.data.iter().find_map(|d| match d {
+            DragDropData::Image(id) => Some(*id),
+            _ => None,
+        })
+    }
+
+    /// Gets the first paths payload.
+    pub fn paths(&self) -> Option<&[PathBuf]> {
+        self.data.iter().find_map(|d| match d {
+            DragDropData::Paths(p) => Some(&p[..]),
+            _ => None,
+        })
+    }
+
+    /// Gets the first extension payload of the `data_type`.
+    pub fn extension(&self, data_type: &str) -> Option<&IpcBytes> {
+        self.data.iter().find_map(|d| match d {
+            DragDropData::Extension { data_type: t, data } if t == data_type => Some(data),
+            _ => None,
+        })
+    }
+
+    /// If any payload is of one of the `types`.
+    pub fn has_any(&self, types: &[DragDropType]) -> bool {
+        self.data.iter().any(|d| types.contains(&d.data_type()))
+    }
 }
 
 impl DragHoveredArgs {
@@ -680,6 +721,23 @@ impl DragHoveredArgs {
         DRAG_DROP.dragging_data()
     }
 
+    /// Set the effect that will be applied if the system drag&drop is dropped on the new [`target`].
+    ///
+    /// Drop targets can call this on drag enter to indicate what data they accept, an empty `effect` rejects
+    /// the drop. The effect is reset to accept all every time the hovered target changes.
+    ///
+    /// Note that this only affects drag&drop from other apps, and the view-process may not be able to show the
+    /// effect on the drag cursor, if the drop is rejected the [`DROP_EVENT`] does not notify.
+    ///
+    /// [`target`]: Self::target
+    pub fn set_drop_effect(&self, effect: DragDropEffect) {
+        if self.target.is_some()
+            && let Some(win) = DRAG_DROP_SV.read().pos_window
+        {
+            WINDOWS_DRAG_DROP.set_drop_effect(win, effect);
+        }
+    }
+
     /// Returns `true` if the `wgt` was not hovered, but now is.
     pub fn is_drag_enter(&self, wgt: WidgetId) -> bool {
         !self.was_over(wgt) && self.is_over(wgt)
@@ -25,7 +25,9 @@ use zng_app::{
     APP, DInstant,
     access::{ACCESS_CLICK_EVENT, ACCESS_FOCUS_EVENT, ACCESS_FOCUS_NAV_ORIGIN_EVENT},
     event::event,
-    event_args, hn,
+    event_args,
+    handler::{Handler, HandlerExt as _},
+    hn,
     update::UPDATES,
     view_process::raw_events::RAW_KEY_INPUT_EVENT,
     widget::{
@@ -37,7 +39,7 @@ use zng_app::{
 use zng_app_context::app_local;
 use zng_ext_window::{FocusIndicator, WINDOW_FOCUS_CHANGED_EVENT, WINDOWS, WINDOWS_FOCUS, WindowInstanceState};
 use zng_layout::unit::TimeUnits as _;
-use zng_var::{Var, WeakVar, const_var, var};
+use zng_var::{Var, VarHandle, WeakVar, const_var, var};
 
 use crate::{mouse::MOUSE_INPUT_EVENT, touch::TOUCH_INPUT_EVENT};
 
@@ -310,6 +312,25 @@ impl FocusChangedCause {
             _ => None,
         }
     }
+
+    /// If the focus changed by a request that moves the focus relative to the current focus, like the
+    /// keyboard navigation requests made by `Tab` and the arrow keys.
+    pub fn is_navigation(self) -> bool {
+        matches!(
+            self.request_target(),
+            Some(
+                FocusTarget::Enter
+                    | FocusTarget::Exit { .. }
+                    | FocusTarget::Next
+                    | FocusTarget::Prev
+                    | FocusTarget::Up
+                    | FocusTarget::Right
+                    | FocusTarget::Down
+                    | FocusTarget::Left
+                    | FocusTarget::Alt
+            )
+        )
+    }
 }
 
 event! {
@@ -388,6 +409,20 @@ impl FOCUS {
         FOCUS_SV.read().focused.read_only()
     }
 
+    /// Calls `handler` every time the focused widget changes.
+    ///
+    /// The `handler` is called for all focus changes in the app, except changes that only affect the highlight state,
+    /// the interactivity or the path of the same focused widget. Use [`FocusChangedCause::is_navigation`] to filter changes
+    /// caused by keyboard navigation, to play a sound or haptic feedback for example.
+    ///
+    /// The `handler` is called in the preview route, before widget handlers, and is called even if propagation is stopped.
+    pub fn on_focus_moved(&self, handler: Handler<FocusChangedArgs>) -> VarHandle {
+        FOCUS_CHANGED_EVENT.on_pre_event(
+            true,
+            handler.filtered(|args| !args.is_highlight_changed() && !args.is_enabled_change() && !args.is_widget_move()),
+        )
+    }
+
     /// Current return focus of a scope.
     #[must_use]
     pub fn return_focused(&self, scope_id: WidgetId) -> Var<Option<InteractionPath>> {
@@ -21,11 +21,12 @@ use zng_app::{
     APP, DInstant, HeadlessApp,
     access::{ACCESS_CLICK_EVENT, AccessClickArgs},
     event::{AnyEventArgs as _, Command, CommandScope, EVENTS, EventPropagationHandle, event, event_args},
-    hn,
+    hn, hn_once,
     shortcut::{
         CommandShortcutExt, GestureKey, KeyChord, KeyGesture, ModifierGesture, ModifiersState, Shortcut, ShortcutFilter, Shortcuts,
         shortcut,
     },
+    timer::TIMERS,
     view_process::raw_device_events::InputDeviceId,
     widget::{
         WidgetId,
@@ -38,7 +39,8 @@ use zng_env::on_process_start;
 use zng_ext_window::WINDOWS;
 use zng_handle::{Handle, HandleOwner, WeakHandle};
 use zng_layout::unit::DipPoint;
-use zng_var::{Var, var};
+use zng_txt::Txt;
+use zng_var::{Var, VarHandles, var};
 use zng_view_api::{
     keyboard::{Key, KeyCode, KeyLocation, KeyState, NativeKeyCode},
     mouse::MouseButton,
@@ -417,6 +419,24 @@ struct GesturesService {
     primary_clicks: Vec<(Shortcut, Arc<ShortcutTarget>)>,
     context_clicks: Vec<(Shortcut, Arc<ShortcutTarget>)>,
     focus: Vec<(Shortcut, Arc<ShortcutTarget>)>,
+
+    keymap: HashMap<Txt, KeymapEntry>,
+    keymap_source: Option<KeymapSource>,
+
+    #[cfg(debug_assertions)]
+    conflicts_check: ConflictsCheck,
+}
+#[cfg(debug_assertions)]
+#[derive(Default)]
+struct ConflictsCheck {
+    scheduled: bool,
+    warned: HashSet<Shortcut>,
+}
+type KeymapSource = Arc<dyn Fn(Txt, Shortcuts) -> Var<Shortcuts> + Send + Sync>;
+struct KeymapEntry {
+    shortcuts: Var<Shortcuts>,
+    source_binding: VarHandles,
+    command_bindings: Vec<(Command, VarHandles)>,
 }
 impl GesturesService {
     fn new() -> Self {
@@ -432,9 +452,85 @@ impl GesturesService {
             primary_clicks: vec![],
             context_clicks: vec![],
             focus: vec![],
+
+            keymap: HashMap::default(),
+            keymap_source: None,
+
+            #[cfg(debug_assertions)]
+            conflicts_check: ConflictsCheck::default(),
         }
     }
 
+    /// Logs a warning for each new shortcut conflict after the current update.
+    #[cfg(debug_assertions)]
+    fn schedule_conflicts_check(&mut self) {
+        if std::mem::replace(&mut self.conflicts_check.scheduled, true) {
+            return;
+        }
+        // after the update so that the new widgets are in the info tree
+        TIMERS
+            .on_deadline(
+                Duration::ZERO,
+                hn_once!(|_| {
+                    let conflicts = GESTURES.shortcut_conflicts();
+                    let mut sv = GESTURES_SV.write();
+                    let check = &mut sv.conflicts_check;
+                    check.scheduled = false;
+                    check.warned.retain(|s| conflicts.iter().any(|c| &c.shortcut == s));
+                    for c in conflicts {
+                        if check.warned.insert(c.shortcut.clone()) {
+                            tracing::warn!("shortcut `{}` conflict, bound to {:?}", c.shortcut, c.targets);
+                        }
+                    }
+                }),
+            )
+            .perm();
+    }
+
+    fn keymap(&mut self, id: Txt, default: Shortcuts) -> &mut KeymapEntry {
+        let source = self.keymap_source.as_ref();
+        self.keymap.entry(id).or_insert_with_key(|id| {
+            let shortcuts = var(default);
+            let source_binding = match source {
+                Some(s) => Self::bind_keymap_source(s, id.clone(), &shortcuts),
+                None => VarHandles::dummy(),
+            };
+            KeymapEntry {
+                shortcuts,
+                source_binding,
+                command_bindings: vec![],
+            }
+        })
+    }
+
+    fn keymap_command(&mut self, cmd: Command, default: Shortcuts) -> Var<Shortcuts> {
+        let entry = self.keymap(cmd.static_name().into(), default);
+        if !entry.command_bindings.iter().any(|(c, _)| *c == cmd) {
+            let shortcut = cmd.shortcut();
+            shortcut.set_from(&entry.shortcuts);
+            let binding = entry.shortcuts.bind_bidi(&shortcut);
+            entry.command_bindings.push((cmd, binding));
+        }
+        let shortcuts = entry.shortcuts.clone();
+        #[cfg(debug_assertions)]
+        self.schedule_conflicts_check();
+        shortcuts
+    }
+
+    fn keymap_source(&mut self, source: KeymapSource) {
+        for (id, entry) in self.keymap.iter_mut() {
+            // drops the previous source binding
+            entry.source_binding = Self::bind_keymap_source(&source, id.clone(), &entry.shortcuts);
+        }
+        self.keymap_source = Some(source);
+    }
+
+    fn bind_keymap_source(source: &KeymapSource, id: Txt, entry: &Var<Shortcuts>) -> VarHandles {
+        let source = source(id, entry.get());
+        entry.set_from(&source);
+        source.bind_bidi(entry)
+    }
+
     fn register_target(&mut self, shortcuts: Shortcuts, kind: Option<ShortcutClick>, target: WidgetId) -> ShortcutsHandle {
         if shortcuts.is_empty() {
             return ShortcutsHandle::dummy();
@@ -465,6 +561,9 @@ impl GesturesService {
             collection.push((s, target.clone()));
         }
 
+        #[cfg(debug_assertions)]
+        self.schedule_conflicts_check();
+
         handle
     }
 
@@ -708,6 +807,204 @@ impl GESTURES {
     pub fn shortcut_actions(&self, shortcut: Shortcut) -> ShortcutActions {
         ShortcutActions::new(&mut GESTURES_SV.write(), shortcut)
     }
+
+    /// Gets a variable that is the current user shortcuts for the logical `id`.
+    ///
+    /// The first call for an `id` initializes the shortcuts to `default`, subsequent calls return the same variable. The
+    /// variable can be set at runtime to remap the shortcuts, widgets that use it in [`click_shortcut`] or [`focus_shortcut`]
+    /// update their registration when it changes. Use [`keymap_source`] to persist the user shortcuts.
+    ///
+    /// [`click_shortcut`]: Self::click_shortcut
+    /// [`focus_shortcut`]: Self::focus_shortcut
+    /// [`keymap_source`]: Self::keymap_source
+    pub fn keymap(&self, id: impl Into<Txt>, default: impl Into<Shortcuts>) -> Var<Shortcuts> {
+        GESTURES_SV.write().keymap(id.into(), default.into()).shortcuts.clone()
+    }
+
+    /// Bind the command [`shortcut`] to the [`keymap`] entry with the same id as the command [`static_name`].
+    ///
+    /// The current command shortcuts are the default value. Subsequent calls for the same command return the same
+    /// entry without binding again.
+    ///
+    /// [`shortcut`]: CommandShortcutExt::shortcut
+    /// [`keymap`]: Self::keymap
+    /// [`static_name`]: Command::static_name
+    pub fn keymap_command(&self, cmd: Command) -> Var<Shortcuts> {
+        let default = cmd.shortcut().get();
+        GESTURES_SV.write().keymap_command(cmd, default)
+    }
+
+    /// Gets the logical ids of all [`keymap`] entries.
+    ///
+    /// [`keymap`]: Self::keymap
+    pub fn keymap_ids(&self) -> Vec<Txt> {
+        GESTURES_SV.read().keymap.keys().cloned().collect()
+    }
+
+    /// Set a closure that provides the variable that persists a [`keymap`] entry.
+    ///
+    /// The closure is called for each existing and new entry with the id and current shortcuts, the returned variable is
+    /// bound bidirectionally with the entry. Replaces the previous source, the entries are unbound from the previous source
+    /// variables. This is usually set to a config once on app init, for example,
+    /// `GESTURES.keymap_source(|id, default| CONFIG.get(formatx!("keymap.{id}"), default))`.
+    ///
+    /// [`keymap`]: Self::keymap
+    pub fn keymap_source(&self, source: impl Fn(Txt, Shortcuts) -> Var<Shortcuts> + Send + Sync + 'static) {
+        GESTURES_SV.write().keymap_source(Arc::new(source));
+    }
+
+    /// Gets all shortcuts currently registered.
+    ///
+    /// Includes the [`click_shortcut`] and [`focus_shortcut`] claims that are not released and the shortcuts
+    /// of all registered commands. Note that the [`click_focused`] and [`context_click_focused`] shortcuts are not included.
+    ///
+    /// [`click_shortcut`]: Self::click_shortcut
+    /// [`focus_shortcut`]: Self::focus_shortcut
+    /// [`click_focused`]: Self::click_focused
+    /// [`context_click_focused`]: Self::context_click_focused
+    pub fn shortcut_bindings(&self) -> Vec<ShortcutBinding> {
+        let mut r = vec![];
+        {
+            let sv = GESTURES_SV.read();
+            for (kind, collection) in [
+                (Some(ShortcutClick::Primary), &sv.primary_clicks),
+                (Some(ShortcutClick::Context), &sv.context_clicks),
+                (None, &sv.focus),
+            ] {
+                for (s, entry) in collection {
+                    if entry.handle.is_dropped() {
+                        continue;
+                    }
+                    let target = match kind {
+                        Some(k) => ShortcutBindingTarget::Click(entry.widget_id, k),
+                        None => ShortcutBindingTarget::Focus(entry.widget_id),
+                    };
+                    r.push(ShortcutBinding {
+                        shortcut: s.clone(),
+                        target,
+                    });
+                }
+            }
+        }
+        for cmd in EVENTS.commands() {
+            cmd.shortcut().with(|s| {
+                for s in s.iter() {
+                    r.push(ShortcutBinding {
+                        shortcut: s.clone(),
+                        target: ShortcutBindingTarget::Command(cmd),
+                    });
+                }
+            });
+        }
+        r
+    }
+
+    /// Gets all registered shortcuts that are bound to multiple targets in overlapping scopes.
+    ///
+    /// Two bindings overlap if any is an app scoped command, or if both targets are in the same window. Bindings
+    /// to the same command in different scopes do not conflict, the command scope is resolved by focus.
+    ///
+    /// This method can be used by a keybinding settings UI to flag duplicates. In debug builds a warning is also logged
+    /// once for each new conflict after click, focus and keymap command shortcuts are registered.
+    ///
+    /// See [`shortcut_bindings`] for details of what bindings are included.
+    ///
+    /// [`shortcut_bindings`]: Self::shortcut_bindings
+    pub fn shortcut_conflicts(&self) -> Vec<ShortcutConflict> {
+        let mut groups: HashMap<Shortcut, Vec<ShortcutBindingTarget>> = HashMap::new();
+        for b in self.shortcut_bindings() {
+            groups.entry(b.shortcut).or_default().push(b.target);
+        }
+
+        let mut r = vec![];
+        for (shortcut, targets) in groups {
+            if targets.len() < 2 {
+                continue;
+            }
+            let scopes: Vec<_> = targets.iter().map(|t| t.scope()).collect();
+            let mut conflicting = vec![false; targets.len()];
+            for (i, a) in targets.iter().enumerate() {
+                for (j, b) in targets.iter().enumerate().skip(i + 1) {
+                    if let (ShortcutBindingTarget::Command(a), ShortcutBindingTarget::Command(b)) = (a, b)
+                        && a.static_name() == b.static_name()
+                    {
+                        continue;
+                    }
+                    let overlap = match (&scopes[i], &scopes[j]) {
+                        (Some(None), Some(_)) | (Some(_), Some(None)) => true,
+                        (Some(Some(a)), Some(Some(b))) => a == b,
+                        _ => false,
+                    };
+                    if overlap {
+                        conflicting[i] = true;
+                        conflicting[j] = true;
+                    }
+                }
+            }
+            let targets: Vec<_> = targets.into_iter().zip(conflicting).filter_map(|(t, c)| c.then_some(t)).collect();
+            if !targets.is_empty() {
+                r.push(ShortcutConflict { shortcut, targets });
+            }
+        }
+        r
+    }
+}
+
+/// Represents a registered shortcut.
+///
+/// You can use the [`GESTURES.shortcut_bindings`] method to get all bindings.
+///
+/// [`GESTURES.shortcut_bindings`]: GESTURES::shortcut_bindings
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ShortcutBinding {
+    /// The shortcut.
+    pub shortcut: Shortcut,
+    /// What the shortcut is bound to.
+    pub target: ShortcutBindingTarget,
+}
+
+/// Represents the target of a [`ShortcutBinding`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShortcutBindingTarget {
+    /// Widget registered using [`GESTURES.click_shortcut`].
+    ///
+    /// [`GESTURES.click_shortcut`]: GESTURES::click_shortcut
+    Click(WidgetId, ShortcutClick),
+    /// Widget registered using [`GESTURES.focus_shortcut`].
+    ///
+    /// [`GESTURES.focus_shortcut`]: GESTURES::focus_shortcut
+    Focus(WidgetId),
+    /// Command shortcut.
+    Command(Command),
+}
+impl ShortcutBindingTarget {
+    /// Resolve the window scope, `Some(None)` is app scope, `None` is a widget not found.
+    fn scope(&self) -> Option<Option<WindowId>> {
+        let widget_window = |id: WidgetId| WINDOWS.widget_info(id).map(|w| Some(w.tree().window_id()));
+        match self {
+            ShortcutBindingTarget::Click(id, _) | ShortcutBindingTarget::Focus(id) => widget_window(*id),
+            ShortcutBindingTarget::Command(cmd) => match cmd.scope() {
+                CommandScope::App => Some(None),
+                CommandScope::Window(id) => Some(Some(id)),
+                CommandScope::Widget(id) => widget_window(id),
+            },
+        }
+    }
+}
+
+/// Represents a shortcut that is bound to multiple targets in overlapping scopes.
+///
+/// You can use the [`GESTURES.shortcut_conflicts`] method to get all conflicts.
+///
+/// [`GESTURES.shortcut_conflicts`]: GESTURES::shortcut_conflicts
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ShortcutConflict {
+    /// The shortcut.
+    pub shortcut: Shortcut,
+    /// The conflicting targets.
+    pub targets: Vec<ShortcutBindingTarget>,
 }
 
 /// Represents the resolved targets for a shortcut at a time.
@@ -22,6 +22,7 @@ use zng_app::{
     DInstant, HeadlessApp,
     event::{event, event_args},
     shortcut::{GestureKey, KeyGesture, ModifierGesture, ModifiersState, Shortcut},
+    timer::{DeadlineVar, TIMERS},
     view_process::{
         VIEW_PROCESS_INITED_EVENT,
         raw_device_events::InputDeviceId,
@@ -30,15 +31,16 @@ use zng_app::{
             RawKeyInputArgs,
         },
     },
-    widget::info::InteractionPath,
+    widget::info::{InteractionPath, WidgetInfo, WidgetInfoBuilder},
     window::WindowId,
 };
 use zng_app_context::app_local;
 use zng_clone_move::clmv;
 use zng_ext_window::WINDOWS;
 use zng_layout::unit::{Factor, FactorUnits};
+use zng_state_map::{StateId, state_map, static_id};
 use zng_txt::Txt;
-use zng_var::{Var, var, var_default};
+use zng_var::{IntoVar, Var, var, var_default};
 use zng_view_api::config::AnimationsConfig;
 pub use zng_view_api::{
     config::KeyRepeatConfig,
@@ -252,7 +254,12 @@ fn hooks() {
 
     RAW_WINDOW_FOCUS_EVENT
         .hook(|args| {
-            if args.new_focus.is_none() {
+            if let Some(new_focus) = args.new_focus {
+                // resync, modifiers may have changed while the app was not focused
+                if let Some(m) = WINDOWS.modifiers_state(new_focus) {
+                    KEYBOARD_SV.write().sync_modifiers(m);
+                }
+            } else {
                 let mut kb = KEYBOARD_SV.write();
                 kb.clear_modifiers();
                 kb.codes.set(vec![]);
@@ -346,10 +353,82 @@ impl KEYBOARD {
     ///
     /// [`Var::easing`]: zng_var::Var::easing
     pub fn caret_animation(&self) -> Var<Factor> {
-        KEYBOARD_SV.read().caret_animation()
+        let sv = KEYBOARD_SV.read();
+        let interval = sv.caret_animation_config.map(|c| c.0);
+        sv.caret_animation(interval, Duration::ZERO)
+    }
+
+    /// Returns a new read-only variable that animates the caret opacity with a custom blink `interval`.
+    ///
+    /// The caret stays visible for the `pause` duration before the blinking starts, this is used to keep the caret
+    /// visible while the user is typing. The animation timeout is the same as [`caret_animation_config`].
+    ///
+    /// [`caret_animation_config`]: Self::caret_animation_config
+    pub fn caret_animation_with(&self, interval: impl IntoVar<Duration>, pause: Duration) -> Var<Factor> {
+        KEYBOARD_SV.read().caret_animation(interval.into_var(), pause)
     }
 }
 
+fn repeat_timer(delay: Duration) -> DeadlineVar {
+    let t = TIMERS.deadline(delay);
+    t.hook(|t| {
+        let elapsed = t.value().has_elapsed();
+        if elapsed {
+            KEYBOARD_SV.write().on_repeat_timer_elapsed();
+        }
+        !elapsed
+    })
+    .perm();
+    t
+}
+
+/// Keyboard config methods.
+pub trait WidgetInfoKeyboardExt {
+    /// Gets the key repeat config override that applies to the widget.
+    ///
+    /// Returns `None` if the widget and ancestors do not override the [`KEYBOARD.repeat_config`].
+    ///
+    /// [`KEYBOARD.repeat_config`]: KEYBOARD::repeat_config
+    fn key_repeat_config(&self) -> Option<KeyRepeatConfig>;
+}
+impl WidgetInfoKeyboardExt for WidgetInfo {
+    fn key_repeat_config(&self) -> Option<KeyRepeatConfig> {
+        for w in self.self_and_ancestors() {
+            if let Some(c) = w.meta().get_clone(*KEY_REPEAT_CONFIG_ID).flatten() {
+                return Some(c);
+            }
+        }
+        None
+    }
+}
+
+/// Keyboard config builder methods.
+pub trait WidgetInfoBuilderKeyboardExt {
+    /// Sets the key repeat config override of the widget and descendants.
+    ///
+    /// When the focused widget or an ancestor sets an override the system key repeat events are ignored and the
+    /// keyboard service generates the repeat events using the override config.
+    ///
+    /// Setting this to `None` will cause the widget to inherit the override.
+    fn set_key_repeat_config(&mut self, config: Option<KeyRepeatConfig>);
+}
+impl WidgetInfoBuilderKeyboardExt for WidgetInfoBuilder {
+    fn set_key_repeat_config(&mut self, config: Option<KeyRepeatConfig>) {
+        self.with_meta(|mut m| match m.entry(*KEY_REPEAT_CONFIG_ID) {
+            state_map::StateMapEntry::Occupied(mut e) => *e.get_mut() = config,
+            state_map::StateMapEntry::Vacant(e) => {
+                if config.is_some() {
+                    e.insert(config);
+                }
+            }
+        })
+    }
+}
+
+static_id! {
+    static ref KEY_REPEAT_CONFIG_ID: StateId<Option<KeyRepeatConfig>>;
+}
+
 app_local! {
     static KEYBOARD_SV: KeyboardService = {
         hooks();
@@ -366,6 +445,7 @@ app_local! {
             caret_animation_config: sys_caret_animation_config.cow(),
             sys_caret_animation_config,
             last_key_down: None,
+            repeat_override: None,
         }
     };
 }
@@ -382,6 +462,12 @@ struct KeyboardService {
     sys_caret_animation_config: Var<(Duration, Duration)>,
 
     last_key_down: Option<(InputDeviceId, KeyCode, DInstant, u32)>,
+    repeat_override: Option<RepeatOverride>,
+}
+struct RepeatOverride {
+    args: KeyInputArgs,
+    config: KeyRepeatConfig,
+    timer: DeadlineVar,
 }
 impl KeyboardService {
     fn key_input(&mut self, args: &RawKeyInputArgs, focused: Option<InteractionPath>) {
@@ -427,6 +513,7 @@ impl KeyboardService {
             }
             KeyState::Released => {
                 self.last_key_down = None;
+                self.repeat_override = None;
 
                 let key = args.key_code;
                 self.codes.modify(move |cs| {
@@ -458,6 +545,15 @@ impl KeyboardService {
                     .and_then(|v| if v.nest_parent().get().is_some() { v.parent().get() } else { None })
                     == Some(args.window_id))
         {
+            let repeat_config = WINDOWS
+                .widget_tree(target.window_id())
+                .and_then(|t| t.get(target.widget_id()))
+                .and_then(|w| w.key_repeat_config());
+            if repeat_config.is_some() && repeat > 0 {
+                // system repeat replaced by the override timer
+                return;
+            }
+
             let args = KeyInputArgs::now(
                 target.window_id(),
                 args.device_id,
@@ -471,6 +567,45 @@ impl KeyboardService {
                 repeat,
                 target,
             );
+            if let Some(config) = repeat_config
+                && args.state == KeyState::Pressed
+            {
+                self.repeat_override = Some(RepeatOverride {
+                    args: args.clone(),
+                    config,
+                    timer: repeat_timer(config.start_delay),
+                });
+            }
+            KEY_INPUT_EVENT.notify(args);
+        }
+    }
+
+    fn on_repeat_timer_elapsed(&mut self) {
+        let modifiers = self.current_modifiers();
+        if let Some(r) = &mut self.repeat_override
+            && r.timer.get().has_elapsed()
+        {
+            if FOCUS.focused().with(|f| f.as_ref().map(|p| p.widget_id())) != Some(r.args.target.widget_id()) {
+                self.repeat_override = None;
+                return;
+            }
+
+            let a = &r.args;
+            let args = KeyInputArgs::now(
+                a.window_id,
+                a.device_id,
+                a.key_code,
+                a.key_location,
+                a.state,
+                a.key.clone(),
+                a.key_modified.clone(),
+                a.text.clone(),
+                modifiers,
+                a.repeat_count.saturating_add(1),
+                a.target.clone(),
+            );
+            r.args = args.clone();
+            r.timer = repeat_timer(r.config.interval);
             KEY_INPUT_EVENT.notify(args);
         }
     }
@@ -491,6 +626,30 @@ impl KeyboardService {
         }
     }
 
+    fn sync_modifiers(&mut self, state: ModifiersState) {
+        let prev_modifiers = self.current_modifiers();
+
+        self.current_modifiers.clear();
+        for (flags, key) in [
+            (ModifiersState::SHIFT, Key::Shift),
+            (ModifiersState::CTRL, Key::Ctrl),
+            (ModifiersState::L_ALT, Key::Alt),
+            (ModifiersState::R_ALT, Key::AltGraph),
+            (ModifiersState::SUPER, Key::Super),
+        ] {
+            if state.intersects(flags) {
+                self.current_modifiers.insert(key);
+            }
+        }
+
+        let new_modifiers = self.current_modifiers();
+
+        if prev_modifiers != new_modifiers {
+            self.modifiers.set(new_modifiers);
+            MODIFIERS_CHANGED_EVENT.notify(ModifiersChangedArgs::now(prev_modifiers, new_modifiers));
+        }
+    }
+
     fn clear_modifiers(&mut self) {
         let prev_modifiers = self.current_modifiers();
         self.current_modifiers.clear();
@@ -510,7 +669,7 @@ impl KeyboardService {
         state
     }
 
-    fn caret_animation(&self) -> Var<Factor> {
+    fn caret_animation(&self, interval: Var<Duration>, pause: Duration) -> Var<Factor> {
         let var = var(1.fct());
         let cfg = self.caret_animation_config.clone();
 
@@ -519,21 +678,25 @@ impl KeyboardService {
         let mut init = true;
 
         var.animate(move |anim, vm| {
-            let (interval, timeout) = cfg.get();
-            if anim.start_time().elapsed() >= timeout || interval == Duration::MAX {
+            let interval = interval.get();
+            let timeout = cfg.with(|c| c.1);
+            if anim.start_time().elapsed() >= timeout.saturating_add(pause) || interval == Duration::MAX {
                 if **vm != one {
                     vm.set(one);
                 }
                 anim.stop();
             } else {
+                let mut sleep = interval;
                 if **vm == one {
-                    if !std::mem::take(&mut init) {
+                    if std::mem::take(&mut init) {
+                        sleep = sleep.saturating_add(pause);
+                    } else {
                         vm.set(zero);
                     }
                 } else {
                     vm.set(one);
                 }
-                anim.sleep(interval, false);
+                anim.sleep(sleep, false);
             }
         })
         .perm();
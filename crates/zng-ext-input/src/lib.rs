@@ -19,3 +19,5 @@ pub mod keyboard;
 pub mod mouse;
 pub mod pointer_capture;
 pub mod touch;
+
+mod velocity;
@@ -39,7 +39,7 @@ use zng_app::{
 };
 use zng_app_context::app_local;
 use zng_ext_window::{NestedWindowWidgetInfoExt, WINDOWS};
-use zng_layout::unit::{Dip, DipPoint, DipToPx, Factor, PxPoint, PxToDip};
+use zng_layout::unit::{Dip, DipPoint, DipToPx, DipVector, Factor, PxPoint, PxToDip};
 use zng_state_map::{StateId, state_map, static_id};
 use zng_var::{IntoVar, Var, context_var, impl_from_and_into_var, var};
 use zng_view_api::touch::TouchPhase;
@@ -51,6 +51,7 @@ pub use zng_view_api::{
 use crate::{
     keyboard::{KEYBOARD, MODIFIERS_CHANGED_EVENT},
     pointer_capture::{CaptureInfo, CaptureMode, POINTER_CAPTURE, POINTER_CAPTURE_EVENT},
+    velocity::VelocitySampler,
 };
 
 event_args! {
@@ -73,6 +74,11 @@ event_args! {
         /// Position of the mouse in the window's content area.
         pub position: DipPoint,
 
+        /// Velocity in device independent pixels per second.
+        ///
+        /// The velocity is computed from the latest 4 cursor move events in the same window. Is zero before the fourth event.
+        pub velocity: DipVector,
+
         /// Hit-test result for the mouse point in the window.
         pub hits: HitTestInfo,
 
@@ -786,6 +792,13 @@ impl ClickMode {
 pub trait WidgetInfoMouseExt {
     /// Gets the click mode of the widget.
     fn click_mode(&self) -> ClickMode;
+
+    /// Gets the repeat config override that applies to the widget.
+    ///
+    /// Returns `None` if the widget and ancestors do not override the [`MOUSE.repeat_config`].
+    ///
+    /// [`MOUSE.repeat_config`]: MOUSE::repeat_config
+    fn button_repeat_config(&self) -> Option<ButtonRepeatConfig>;
 }
 impl WidgetInfoMouseExt for WidgetInfo {
     fn click_mode(&self) -> ClickMode {
@@ -796,6 +809,15 @@ impl WidgetInfoMouseExt for WidgetInfo {
         }
         ClickMode::default()
     }
+
+    fn button_repeat_config(&self) -> Option<ButtonRepeatConfig> {
+        for w in self.self_and_ancestors() {
+            if let Some(c) = w.meta().get_clone(*BUTTON_REPEAT_CONFIG_ID).flatten() {
+                return Some(c);
+            }
+        }
+        None
+    }
 }
 
 /// Mouse config builder methods.
@@ -804,6 +826,11 @@ pub trait WidgetInfoBuilderMouseExt {
     ///
     /// Setting this to `None` will cause the widget to inherit the click mode.
     fn set_click_mode(&mut self, mode: Option<ClickMode>);
+
+    /// Sets the [`ClickMode::repeat`] config override of the widget and descendants.
+    ///
+    /// Setting this to `None` will cause the widget to inherit the override.
+    fn set_button_repeat_config(&mut self, config: Option<ButtonRepeatConfig>);
 }
 impl WidgetInfoBuilderMouseExt for WidgetInfoBuilder {
     fn set_click_mode(&mut self, mode: Option<ClickMode>) {
@@ -816,26 +843,78 @@ impl WidgetInfoBuilderMouseExt for WidgetInfoBuilder {
             }
         })
     }
+
+    fn set_button_repeat_config(&mut self, config: Option<ButtonRepeatConfig>) {
+        self.with_meta(|mut m| match m.entry(*BUTTON_REPEAT_CONFIG_ID) {
+            state_map::StateMapEntry::Occupied(mut e) => *e.get_mut() = config,
+            state_map::StateMapEntry::Vacant(e) => {
+                if config.is_some() {
+                    e.insert(config);
+                }
+            }
+        })
+    }
 }
 
 static_id! {
     static ref CLICK_MODE_ID: StateId<Option<ClickMode>>;
+    static ref BUTTON_REPEAT_CONFIG_ID: StateId<Option<ButtonRepeatConfig>>;
 }
 
 /// Settings that define the mouse button pressed repeat.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 pub struct ButtonRepeatConfig {
     /// Delay before repeat starts.
     pub start_delay: Duration,
     /// Delay before each repeat event after the first.
     pub interval: Duration,
+    /// Multiplier applied to the interval after each repeat.
+    ///
+    /// Values less than `1.0` make the repeat faster the longer the button is held.
+    pub acceleration: Factor,
+    /// Minimum interval when accelerating.
+    pub min_interval: Duration,
 }
 impl Default for ButtonRepeatConfig {
-    /// 600ms, 100ms.
+    /// 600ms, 100ms, no acceleration.
     fn default() -> Self {
         Self {
             start_delay: Duration::from_millis(600),
             interval: Duration::from_millis(100),
+            acceleration: Factor(1.0),
+            min_interval: Duration::from_millis(20),
+        }
+    }
+}
+impl ButtonRepeatConfig {
+    /// New constant interval config.
+    pub fn new(start_delay: Duration, interval: Duration) -> Self {
+        Self {
+            start_delay,
+            interval,
+            ..Default::default()
+        }
+    }
+
+    /// New accelerating config.
+    ///
+    /// The interval is multiplied by the `acceleration` after each repeat, down to the `min_interval`.
+    pub fn new_accelerated(start_delay: Duration, interval: Duration, acceleration: Factor, min_interval: Duration) -> Self {
+        Self {
+            start_delay,
+            interval,
+            acceleration,
+            min_interval,
+        }
+    }
+
+    /// Gets the interval after the `interval` that was just used.
+    pub fn next_interval(&self, interval: Duration) -> Duration {
+        if self.acceleration == Factor(1.0) {
+            self.interval
+        } else {
+            interval.mul_f32(self.acceleration.0.max(0.0)).max(self.min_interval)
         }
     }
 }
@@ -924,10 +1003,7 @@ app_local! {
             sys_multi_click_config,
             repeat_config: KEYBOARD
                 .repeat_config()
-                .map(|c| ButtonRepeatConfig {
-                    start_delay: c.start_delay,
-                    interval: c.interval,
-                })
+                .map(|c| ButtonRepeatConfig::new(c.start_delay, c.interval))
                 .cow(),
             buttons: var(vec![]),
             hovered: var(None),
@@ -937,6 +1013,7 @@ app_local! {
             pos_window: None,
             pos_device: None,
             hits: None,
+            velocity: VelocitySampler::default(),
 
             modifiers: ModifiersState::default(),
 
@@ -960,6 +1037,8 @@ struct MouseService {
     pos_device: Option<InputDeviceId>,
     // last cursor move hit-test (on the pos_window or a nested window).
     hits: Option<HitTestInfo>,
+    // cursor move samples in `pos_window`.
+    velocity: VelocitySampler,
 
     /// last modifiers.
     modifiers: ModifiersState,
@@ -979,6 +1058,8 @@ struct ClickingInfo {
 
     repeat_timer: Option<DeadlineVar>,
     repeat_count: u32,
+    repeat_config: ButtonRepeatConfig,
+    repeat_interval: Duration,
 }
 
 fn hooks() {
@@ -991,9 +1072,13 @@ fn hooks() {
 
     RAW_MOUSE_MOVED_EVENT
         .hook(|args| {
-            MOUSE_SV
-                .write()
-                .on_cursor_moved(args.window_id, args.device_id, args.coalesced_pos.clone(), args.position);
+            MOUSE_SV.write().on_cursor_moved(
+                args.window_id,
+                args.device_id,
+                args.coalesced_pos.clone(),
+                args.position,
+                args.timestamp,
+            );
             true
         })
         .perm();
@@ -1088,10 +1173,17 @@ impl MouseService {
             position = hits.point().to_dip(wgt_tree.scale_factor());
         }
 
-        let (wgt_path, click_mode) = hits
+        let (wgt_path, click_mode, repeat_config) = hits
             .target()
-            .and_then(|t| wgt_tree.get(t.widget_id).map(|w| (w.interaction_path(), w.click_mode())))
-            .unwrap_or_else(|| (wgt_tree.root().interaction_path(), wgt_tree.root().click_mode()));
+            .and_then(|t| {
+                wgt_tree
+                    .get(t.widget_id)
+                    .map(|w| (w.interaction_path(), w.click_mode(), w.button_repeat_config()))
+            })
+            .unwrap_or_else(|| {
+                let root = wgt_tree.root();
+                (root.interaction_path(), root.click_mode(), root.button_repeat_config())
+            });
 
         let wgt_path = match wgt_path.unblocked() {
             Some(p) => p,
@@ -1126,6 +1218,8 @@ impl MouseService {
             click_count: 0,
             repeat_timer: None,
             repeat_count: 0,
+            repeat_config: ButtonRepeatConfig::default(),
+            repeat_interval: Duration::ZERO,
         });
 
         if entry.path != wgt_path {
@@ -1176,8 +1270,10 @@ impl MouseService {
 
         if click_mode.repeat {
             if click {
-                let t = self.repeat_config.get().start_delay;
-                let t = TIMERS.deadline(t);
+                let cfg = repeat_config.unwrap_or_else(|| self.repeat_config.get());
+                entry.repeat_config = cfg;
+                entry.repeat_interval = cfg.interval;
+                let t = TIMERS.deadline(cfg.start_delay);
                 t.hook(|t| {
                     let elapsed = t.value().has_elapsed();
                     if elapsed {
@@ -1244,14 +1340,23 @@ impl MouseService {
         }
     }
 
-    fn on_cursor_moved(&mut self, window_id: WindowId, device_id: InputDeviceId, coalesced_pos: Vec<DipPoint>, mut position: DipPoint) {
+    fn on_cursor_moved(
+        &mut self,
+        window_id: WindowId,
+        device_id: InputDeviceId,
+        coalesced_pos: Vec<DipPoint>,
+        mut position: DipPoint,
+        timestamp: DInstant,
+    ) {
         let mut moved = Some(window_id) != self.pos_window || Some(device_id) != self.pos_device;
 
         if moved {
             // if is over another window now.
             self.pos_window = Some(window_id);
             self.pos_device = Some(device_id);
+            self.velocity.clear();
         }
+        self.velocity.push(timestamp, position);
 
         moved |= position != self.pos;
 
@@ -1349,6 +1454,7 @@ impl MouseService {
                     self.modifiers,
                     coalesced_pos,
                     position,
+                    self.velocity.velocity(),
                     pos_hits,
                     target,
                     capture,
@@ -1600,7 +1706,8 @@ impl MouseService {
                                 MOUSE_CLICK_EVENT.notify(args);
 
                                 // continue timer
-                                let t = MOUSE.repeat_config().get().interval;
+                                let t = info.repeat_interval;
+                                info.repeat_interval = info.repeat_config.next_interval(t);
                                 let t = TIMERS.deadline(t);
                                 t.hook(|t| {
                                     let elapsed = t.value().has_elapsed();
@@ -1622,3 +1729,44 @@ impl MouseService {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeat_next_interval_constant() {
+        let cfg = ButtonRepeatConfig::new(Duration::from_millis(600), Duration::from_millis(100));
+        assert_eq!(Duration::from_millis(100), cfg.next_interval(Duration::from_millis(100)));
+        assert_eq!(Duration::from_millis(100), cfg.next_interval(Duration::from_millis(30)));
+    }
+
+    #[test]
+    fn repeat_next_interval_accelerated() {
+        let cfg = ButtonRepeatConfig::new_accelerated(
+            Duration::from_millis(600),
+            Duration::from_millis(100),
+            Factor(0.5),
+            Duration::from_millis(20),
+        );
+        let mut interval = cfg.interval;
+        let mut intervals = vec![];
+        for _ in 0..4 {
+            interval = cfg.next_interval(interval);
+            // interval is multiplied in `f32` precision
+            intervals.push((interval.as_secs_f64() * 1000.0).round() as u64);
+        }
+        assert_eq!(vec![50, 25, 20, 20], intervals);
+    }
+
+    #[test]
+    fn repeat_next_interval_negative_acceleration() {
+        let cfg = ButtonRepeatConfig::new_accelerated(
+            Duration::from_millis(600),
+            Duration::from_millis(100),
+            Factor(-1.0),
+            Duration::from_millis(20),
+        );
+        assert_eq!(Duration::from_millis(20), cfg.next_interval(Duration::from_millis(100)));
+    }
+}
@@ -39,7 +39,7 @@ use zng_app::{
 use zng_app_context::app_local;
 use zng_ext_window::{NestedWindowWidgetInfoExt as _, WINDOWS};
 use zng_layout::unit::{
-    AngleRadian, Dip, DipPoint, DipToPx, DipVector, Factor, Px, PxPoint, PxToDip, PxTransform, PxVector, TimeUnits, euclid,
+    AngleRadian, Dip, DipPoint, DipSize, DipToPx, DipVector, Factor, Px, PxPoint, PxToDip, PxTransform, PxVector, TimeUnits, euclid,
 };
 use zng_var::{Var, VarHandle, impl_from_and_into_var, var};
 pub use zng_view_api::{
@@ -50,6 +50,7 @@ pub use zng_view_api::{
 use crate::{
     keyboard::MODIFIERS_CHANGED_EVENT,
     pointer_capture::{CaptureInfo, POINTER_CAPTURE, POINTER_CAPTURE_EVENT},
+    velocity::VelocitySampler,
 };
 
 struct PressedInfo {
@@ -59,59 +60,7 @@ struct PressedInfo {
     position: DipPoint,
     force: Option<TouchForce>,
     hits: HitTestInfo,
-    velocity_samples: Vec<(DInstant, DipPoint)>,
-}
-impl PressedInfo {
-    fn push_velocity_sample(&mut self, timestamp: DInstant, position: DipPoint) {
-        if let Some(last) = self.velocity_samples.last_mut()
-            && timestamp.duration_since(last.0) < 1.ms()
-        {
-            last.1 = position;
-            return;
-        }
-
-        if self.velocity_samples.len() == 4 {
-            self.velocity_samples.remove(0);
-        }
-        self.velocity_samples.push((timestamp, position));
-    }
-
-    fn velocity(&self) -> DipVector {
-        if self.velocity_samples.len() < 4 {
-            DipVector::zero()
-        } else {
-            let samples = [
-                self.velocity_samples[0].1.cast::<f64>(),
-                self.velocity_samples[1].1.cast(),
-                self.velocity_samples[2].1.cast(),
-                self.velocity_samples[3].1.cast(),
-            ];
-            let velocity_at = |end_i: usize| {
-                let start_i = end_i - 1;
-
-                let start_t = self.velocity_samples[start_i].0;
-                let end_t = self.velocity_samples[end_i].0;
-
-                let start_s = samples[start_i];
-                let end_s = samples[end_i];
-
-                let delta = (end_t - start_t).as_secs_f64();
-
-                if delta > 0.0 {
-                    (end_s - start_s) / delta
-                } else {
-                    euclid::vec2(0.0, 0.0)
-                }
-            };
-
-            let v23 = velocity_at(3) * 0.6;
-            let v12 = velocity_at(2) * 0.35;
-            let v01 = velocity_at(1) * 0.05;
-            let v = v23 + v12 + v01;
-
-            v.cast::<f32>().cast()
-        }
-    }
+    velocity: VelocitySampler,
 }
 
 /// Touch service.
@@ -153,6 +102,15 @@ impl TOUCH {
         TOUCH_SV.read().positions.read_only()
     }
 
+    /// Variable that defines the distance from a window edge a touch must start to be an edge swipe.
+    ///
+    /// Is `24` by default.
+    ///
+    /// See [`TOUCH_EDGE_SWIPE_EVENT`] for more details.
+    pub fn edge_swipe_area(&self) -> Var<Dip> {
+        TOUCH_SV.read().edge_swipe_area.clone()
+    }
+
     /// Test mode, generates touch events for a single touch contact from raw mouse events.
     ///
     /// Is disabled by default.
@@ -208,6 +166,8 @@ app_local! {
             transform_gesture: Default::default(),
             long_press_gesture: Default::default(),
             mouse_touch: Default::default(),
+            edge_swipe_area: var(Dip::new(24)),
+            edge_swipe_gesture: Default::default(),
         }
     };
 }
@@ -223,6 +183,8 @@ struct TouchService {
     transform_gesture: TransformGesture,
     long_press_gesture: LongPressGesture,
     mouse_touch: Option<TouchId>,
+    edge_swipe_area: Var<Dip>,
+    edge_swipe_gesture: EdgeSwipeGesture,
 }
 
 /// Identify the moves of one touch contact in [`TouchMoveArgs`].
@@ -532,6 +494,55 @@ event_args! {
         }
     }
 
+    /// Arguments for [`TOUCH_EDGE_SWIPE_EVENT`].
+    pub struct TouchEdgeSwipeArgs {
+        /// Id of window that received the event.
+        pub window_id: WindowId,
+
+        /// Id of device that generated the event.
+        pub device_id: InputDeviceId,
+
+        /// Identify the touch contact or *finger*.
+        pub touch: TouchId,
+
+        /// Window edge where the swipe started.
+        pub edge: SwipeEdge,
+
+        /// Touch position when the swipe started, in the window's content area.
+        pub start_position: DipPoint,
+
+        /// Latest touch position in the window's content area.
+        pub position: DipPoint,
+
+        /// Distance of the latest touch position from the [`edge`].
+        ///
+        /// [`edge`]: Self::edge
+        pub distance: Dip,
+
+        /// Velocity in device independent pixels per second.
+        pub velocity: DipVector,
+
+        /// Swipe phase.
+        ///
+        /// Is `Start` for the first event, `Move` while the touch moves, and `End` or `Cancel` for the last event.
+        pub phase: TouchPhase,
+
+        /// Full path to the widget touched when the swipe started.
+        pub target: InteractionPath,
+
+        /// What modifier keys where pressed when this event happened.
+        pub modifiers: ModifiersState,
+
+        ..
+
+        /// If is in [`target`].
+        ///
+        /// [`target`]: Self::target
+        fn is_in_target(&self, id: WidgetId) -> bool {
+            self.target.contains(id)
+        }
+    }
+
     /// Arguments for [`TOUCH_TRANSFORM_EVENT`].
     pub struct TouchTransformArgs {
         /// Id of window that received the touch events.
@@ -588,6 +599,44 @@ event_args! {
     }
 }
 
+/// Window edge of a [`TOUCH_EDGE_SWIPE_EVENT`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SwipeEdge {
+    /// Swipe from the left edge to the right.
+    Left,
+    /// Swipe from the top edge down.
+    Top,
+    /// Swipe from the right edge to the left.
+    Right,
+    /// Swipe from the bottom edge up.
+    Bottom,
+}
+
+impl TouchEdgeSwipeArgs {
+    /// If the swipe is still active.
+    pub fn is_active(&self) -> bool {
+        matches!(self.phase, TouchPhase::Start | TouchPhase::Move)
+    }
+
+    /// If the swipe ended with a fling away from the edge.
+    ///
+    /// Navigation can use this to complete a back swipe even if the [`distance`] is short.
+    ///
+    /// [`distance`]: Self::distance
+    pub fn is_fling(&self) -> bool {
+        if self.phase != TouchPhase::End {
+            return false;
+        }
+        let v = match self.edge {
+            SwipeEdge::Left => self.velocity.x,
+            SwipeEdge::Top => self.velocity.y,
+            SwipeEdge::Right => -self.velocity.x,
+            SwipeEdge::Bottom => -self.velocity.y,
+        };
+        v >= TOUCH.touch_config().get().min_fling_velocity
+    }
+}
+
 impl TouchMoveArgs {
     /// If [`capture`] is `None` or [`allows`] the `wgt` to receive this event.
     ///
@@ -1038,6 +1087,18 @@ event! {
     pub static TOUCH_LONG_PRESS_EVENT: TouchLongPressArgs {
         let _ = TOUCH_SV.read();
     };
+
+    /// Single touch contact started near a window edge and moved away from it.
+    ///
+    /// The touch must start inside the [`TOUCH.edge_swipe_area`] and move away from the edge more than the [`tap_area`]
+    /// before moving along the edge, the touch propagation is stopped when the swipe starts. The event notifies continuously
+    /// while the touch moves so that a navigation back swipe can follow the finger.
+    ///
+    /// [`TOUCH.edge_swipe_area`]: TOUCH::edge_swipe_area
+    /// [`tap_area`]: TouchConfig::tap_area
+    pub static TOUCH_EDGE_SWIPE_EVENT: TouchEdgeSwipeArgs {
+        let _ = TOUCH_SV.read();
+    };
 }
 
 impl TouchService {
@@ -1086,7 +1147,7 @@ impl TouchService {
                             position,
                             force: update.force,
                             hits: hits.clone(),
-                            velocity_samples: vec![], // skip input (will only have velocity after 4 moves)
+                            velocity: VelocitySampler::default(), // skip input (will only have velocity after 4 moves)
                         },
                     ) {
                         weird.touch_propagation.stop();
@@ -1095,7 +1156,7 @@ impl TouchService {
                 }
                 TouchPhase::End => {
                     if let Some(handle) = self.pressed.remove(&update.touch) {
-                        let vel = handle.velocity();
+                        let vel = handle.velocity.velocity();
                         (handle.touch_propagation, vel)
                     } else {
                         let weird = EventPropagationHandle::new();
@@ -1304,8 +1365,8 @@ impl TouchService {
             for m in &mut moves {
                 if let Some(i) = self.pressed.get_mut(&m.touch) {
                     let (position, force) = *m.moves.last().unwrap();
-                    i.push_velocity_sample(args.timestamp, position);
-                    m.velocity = i.velocity();
+                    i.velocity.push(args.timestamp, position);
+                    m.velocity = i.velocity.velocity();
                     i.position = position;
                     i.force = force;
                     i.hits = m.hits.clone();
@@ -1572,6 +1633,141 @@ impl LongPressGesture {
     }
 }
 
+struct PendingEdgeSwipe {
+    window_id: WindowId,
+    device_id: InputDeviceId,
+    touch: TouchId,
+    propagation: EventPropagationHandle,
+    target: InteractionPath,
+    edge: SwipeEdge,
+    window_size: DipSize,
+    start_position: DipPoint,
+    started: bool,
+    canceled: bool,
+}
+impl PendingEdgeSwipe {
+    fn distance(&self, position: DipPoint) -> Dip {
+        match self.edge {
+            SwipeEdge::Left => position.x,
+            SwipeEdge::Top => position.y,
+            SwipeEdge::Right => self.window_size.width - position.x,
+            SwipeEdge::Bottom => self.window_size.height - position.y,
+        }
+    }
+
+    fn notify(&self, position: DipPoint, velocity: DipVector, phase: TouchPhase, modifiers: ModifiersState) {
+        let args = TouchEdgeSwipeArgs::now(
+            self.window_id,
+            self.device_id,
+            self.touch,
+            self.edge,
+            self.start_position,
+            position,
+            self.distance(position),
+            velocity,
+            phase,
+            self.target.clone(),
+            modifiers,
+        );
+        TOUCH_EDGE_SWIPE_EVENT.notify(args);
+    }
+}
+
+#[derive(Default)]
+struct EdgeSwipeGesture {
+    pending: Option<PendingEdgeSwipe>,
+}
+impl EdgeSwipeGesture {
+    fn on_input(&mut self, args: &TouchInputArgs, area: &Var<Dip>) {
+        match args.phase {
+            TouchPhase::Start => {
+                if let Some(p) = &mut self.pending {
+                    // only valid if single touch contact
+                    if p.started && !p.canceled {
+                        p.notify(args.position, DipVector::zero(), TouchPhase::Cancel, args.modifiers);
+                    }
+                    p.canceled = true;
+                } else if let Some(size) = WINDOWS.vars(args.window_id).map(|v| v.actual_size().get()) {
+                    let area = area.get();
+                    let pos = args.position;
+                    let edge = [
+                        (SwipeEdge::Left, pos.x),
+                        (SwipeEdge::Top, pos.y),
+                        (SwipeEdge::Right, size.width - pos.x),
+                        (SwipeEdge::Bottom, size.height - pos.y),
+                    ]
+                    .into_iter()
+                    .filter(|(_, d)| *d >= Dip::new(0) && *d <= area)
+                    .min_by_key(|(_, d)| *d);
+
+                    if let Some((edge, _)) = edge {
+                        self.pending = Some(PendingEdgeSwipe {
+                            window_id: args.window_id,
+                            device_id: args.device_id,
+                            touch: args.touch,
+                            propagation: args.touch_propagation.clone(),
+                            target: args.target.clone(),
+                            edge,
+                            window_size: size,
+                            start_position: pos,
+                            started: false,
+                            canceled: false,
+                        });
+                    }
+                }
+            }
+            TouchPhase::End | TouchPhase::Cancel => {
+                if let Some(p) = &self.pending
+                    && args.touch_propagation == p.propagation
+                {
+                    if p.started && !p.canceled {
+                        p.notify(args.position, args.velocity, args.phase, args.modifiers);
+                    }
+                    self.pending = None;
+                }
+            }
+            TouchPhase::Move => unreachable!(),
+        }
+    }
+
+    fn on_move(&mut self, args: &TouchMoveArgs, cfg: &Var<TouchConfig>) {
+        if let Some(p) = &mut self.pending
+            && !p.canceled
+        {
+            for m in &args.touches {
+                if p.propagation != m.touch_propagation {
+                    continue;
+                }
+
+                let position = m.position();
+                if p.started {
+                    p.notify(position, m.velocity, TouchPhase::Move, args.modifiers);
+                } else {
+                    let delta = position - p.start_position;
+                    let (inward, along, threshold) = match p.edge {
+                        SwipeEdge::Left => (delta.x, delta.y, cfg.get().tap_area.width),
+                        SwipeEdge::Right => (-delta.x, delta.y, cfg.get().tap_area.width),
+                        SwipeEdge::Top => (delta.y, delta.x, cfg.get().tap_area.height),
+                        SwipeEdge::Bottom => (-delta.y, delta.x, cfg.get().tap_area.height),
+                    };
+                    if along.abs() > threshold && along.abs() >= inward {
+                        // moved along the edge first
+                        p.canceled = true;
+                    } else if inward > threshold {
+                        p.started = true;
+                        p.propagation.stop();
+                        p.notify(position, m.velocity, TouchPhase::Start, args.modifiers);
+                    }
+                }
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.pending = None;
+    }
+}
+
 #[derive(Default)]
 struct TapGesture {
     pending_double: Option<PendingDoubleTap>,
@@ -2496,6 +2692,7 @@ fn hooks() {
                 s.tap_gesture.clear();
                 s.transform_gesture.clear();
                 s.long_press_gesture.clear();
+                s.edge_swipe_gesture.clear();
                 s.positions.set(vec![]);
 
                 for (touch, info) in s.pressed.drain() {
@@ -2545,6 +2742,7 @@ fn hooks() {
                 s.tap_gesture.on_input(args, &s.touch_config);
                 s.transform_gesture.on_input(args);
                 s.long_press_gesture.on_input(args, &s.touch_config);
+                s.edge_swipe_gesture.on_input(args, &s.edge_swipe_area);
             }),
         )
         .perm();
@@ -2558,6 +2756,7 @@ fn hooks() {
                 s.tap_gesture.on_move(args);
                 s.transform_gesture.on_move(args, &s.touch_config);
                 s.long_press_gesture.on_move(args, &s.touch_config);
+                s.edge_swipe_gesture.on_move(args, &s.touch_config);
             }),
         )
         .perm();
@@ -0,0 +1,65 @@
+use zng_app::DInstant;
+use zng_layout::unit::{DipPoint, DipVector, TimeUnits as _, euclid};
+
+/// Pointer velocity from the latest 4 move samples.
+#[derive(Default, Clone)]
+pub(crate) struct VelocitySampler {
+    samples: Vec<(DInstant, DipPoint)>,
+}
+impl VelocitySampler {
+    pub fn push(&mut self, timestamp: DInstant, position: DipPoint) {
+        if let Some(last) = self.samples.last_mut()
+            && timestamp.duration_since(last.0) < 1.ms()
+        {
+            last.1 = position;
+            return;
+        }
+
+        if self.samples.len() == 4 {
+            self.samples.remove(0);
+        }
+        self.samples.push((timestamp, position));
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Velocity in device independent pixels per second, zero if has less than 4 samples.
+    pub fn velocity(&self) -> DipVector {
+        if self.samples.len() < 4 {
+            DipVector::zero()
+        } else {
+            let samples = [
+                self.samples[0].1.cast::<f64>(),
+                self.samples[1].1.cast(),
+                self.samples[2].1.cast(),
+                self.samples[3].1.cast(),
+            ];
+            let velocity_at = |end_i: usize| {
+                let start_i = end_i - 1;
+
+                let start_t = self.samples[start_i].0;
+                let end_t = self.samples[end_i].0;
+
+                let start_s = samples[start_i];
+                let end_s = samples[end_i];
+
+                let delta = (end_t - start_t).as_secs_f64();
+
+                if delta > 0.0 {
+                    (end_s - start_s) / delta
+                } else {
+                    euclid::vec2(0.0, 0.0)
+                }
+            };
+
+            let v23 = velocity_at(3) * 0.6;
+            let v12 = velocity_at(2) * 0.35;
+            let v01 = velocity_at(1) * 0.05;
+            let v = v23 + v12 + v01;
+
+            v.cast::<f32>().cast()
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::{cmp::Ordering, fmt};
+
+#[cfg(feature = "collator")]
+use std::sync::Arc;
+
+use crate::Lang;
+
+/// Locale aware string comparer.
+///
+/// Compares strings using the collation rules of a language, for example, in Swedish `"ö"` sorts after `"z"` and in German
+/// it sorts with `"o"`. Use [`L10N.collator`] to get a collator for the contextual language.
+///
+/// The collation rules are only available if the crate is built with the `"collator"` feature, without it strings are compared
+/// by case-insensitive Unicode code points and then by exact code points.
+///
+/// [`L10N.collator`]: crate::L10N::collator
+#[derive(Clone)]
+pub struct Collator {
+    lang: Lang,
+    #[cfg(feature = "collator")]
+    inner: Option<Arc<icu_collator::CollatorBorrowed<'static>>>,
+}
+impl Collator {
+    /// New collator for the language.
+    ///
+    /// If the language has no specific collation rules the root rules are used.
+    pub fn new(lang: &Lang) -> Self {
+        #[cfg(feature = "collator")]
+        {
+            let inner = match icu_locale_core::LanguageIdentifier::try_from_str(&lang.0.to_string()) {
+                Ok(id) => match icu_collator::Collator::try_new((&id).into(), icu_collator::options::CollatorOptions::default()) {
+                    Ok(c) => Some(Arc::new(c)),
+                    Err(e) => {
+                        tracing::error!("cannot load collator for {lang}, {e}");
+                        None
+                    }
+                },
+                Err(e) => {
+                    tracing::error!("cannot parse {lang} for collator, {e}");
+                    None
+                }
+            };
+            Self { lang: lang.clone(), inner }
+        }
+        #[cfg(not(feature = "collator"))]
+        {
+            Self { lang: lang.clone() }
+        }
+    }
+
+    /// Language of the collation rules.
+    pub fn lang(&self) -> &Lang {
+        &self.lang
+    }
+
+    /// If the collator uses language specific rules.
+    ///
+    /// Is `false` if not built with the `"collator"` feature or if the rules failed to load.
+    pub fn is_localized(&self) -> bool {
+        #[cfg(feature = "collator")]
+        {
+            self.inner.is_some()
+        }
+        #[cfg(not(feature = "collator"))]
+        {
+            false
+        }
+    }
+
+    /// Compare `a` with `b`.
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        #[cfg(feature = "collator")]
+        if let Some(c) = &self.inner {
+            return c.compare(a, b);
+        }
+        unicase::UniCase::new(a).cmp(&unicase::UniCase::new(b)).then_with(|| a.cmp(b))
+    }
+
+    /// Sort the `list` using [`compare`].
+    ///
+    /// [`compare`]: Self::compare
+    pub fn sort<T: AsRef<str>>(&self, list: &mut [T]) {
+        list.sort_by(|a, b| self.compare(a.as_ref(), b.as_ref()));
+    }
+
+    /// Sort the `list` using [`compare`] on the key extracted from each item.
+    ///
+    /// [`compare`]: Self::compare
+    pub fn sort_by_key<T, K: AsRef<str>>(&self, list: &mut [T], mut key: impl FnMut(&T) -> K) {
+        list.sort_by(|a, b| self.compare(key(a).as_ref(), key(b).as_ref()));
+    }
+}
+impl fmt::Debug for Collator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Collator")
+            .field("lang", &self.lang)
+            .field("is_localized", &self.is_localized())
+            .finish()
+    }
+}
+impl PartialEq for Collator {
+    fn eq(&self, other: &Self) -> bool {
+        self.lang == other.lang && self.is_localized() == other.is_localized()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang;
+
+    fn fallback() -> Collator {
+        Collator {
+            lang: lang!("en-US"),
+            #[cfg(feature = "collator")]
+            inner: None,
+        }
+    }
+
+    #[test]
+    fn fallback_compare() {
+        let c = fallback();
+        assert!(!c.is_localized());
+
+        assert_eq!(c.compare("a", "B"), Ordering::Less);
+        assert_eq!(c.compare("b", "A"), Ordering::Greater);
+        assert_eq!(c.compare("A", "a"), Ordering::Less);
+        assert_eq!(c.compare("a", "a"), Ordering::Equal);
+        assert_eq!(c.compare("z", "ö"), Ordering::Less);
+    }
+
+    #[test]
+    fn fallback_sort() {
+        let c = fallback();
+
+        let mut list = ["b", "a", "B", "A", "ö", "z"];
+        c.sort(&mut list);
+        assert_eq!(list, ["A", "a", "B", "b", "z", "ö"]);
+
+        let mut list = [(2, "b"), (1, "C"), (3, "a")];
+        c.sort_by_key(&mut list, |(_, k)| *k);
+        assert_eq!(list, [(3, "a"), (2, "b"), (1, "C")]);
+    }
+
+    #[cfg(feature = "collator")]
+    #[test]
+    fn localized_sort() {
+        let sv = Collator::new(&lang!("sv"));
+        assert!(sv.is_localized());
+        let mut list = ["ö", "z", "o"];
+        sv.sort(&mut list);
+        assert_eq!(list, ["o", "z", "ö"]);
+
+        let de = Collator::new(&lang!("de"));
+        assert!(de.is_localized());
+        let mut list = ["z", "ö", "p", "o"];
+        de.sort(&mut list);
+        assert_eq!(list, ["o", "ö", "p", "z"]);
+    }
+}
@@ -49,6 +49,9 @@ use service::L10N_SV;
 mod sources;
 pub use sources::*;
 
+mod collator;
+pub use collator::*;
+
 /// Localization service.
 pub struct L10N;
 
@@ -311,6 +314,29 @@ impl L10N {
         L10N_SV.read().sys_lang()
     }
 
+    /// Gets a read-only variable that is a string comparer for the [`LANG_VAR`] in the context
+    /// where the variable is first used. The variable will update when the contextual language changes.
+    ///
+    /// The collator can be used to sort lists of text according to the language rules, for example,
+    /// in a [`SortingList`] or in parallel sorts.
+    ///
+    /// ```
+    /// # use zng_ext_l10n::*;
+    /// # fn demo() {
+    /// let collator = L10N.collator().get();
+    /// let mut names = vec!["Zoë", "Örjan", "Anna"];
+    /// collator.sort(&mut names);
+    /// # }
+    /// ```
+    ///
+    /// Note that language specific rules are only available if the crate is built with the `"collator"` feature,
+    /// see [`Collator`] for more details.
+    ///
+    /// [`SortingList`]: zng_app::widget::node::SortingList
+    pub fn collator(&self) -> Var<Collator> {
+        LANG_VAR.map(|l| Collator::new(l.best()))
+    }
+
     /// Gets a read-only variable that is a localized message in the localization context
     /// where the variable is first used. The variable will update when the contextual language changes.
     ///
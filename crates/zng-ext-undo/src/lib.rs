@@ -20,6 +20,7 @@
 
 use std::{
     any::Any,
+    collections::HashMap,
     fmt, mem,
     sync::{
         Arc,
@@ -285,6 +286,25 @@ impl UNDO {
         UNDO_SCOPE_CTX.with_context(&mut scope.0, f)
     }
 
+    /// Runs `f` in a context where consecutive undo actions registered in the current scope are merged according to the `predicate`.
+    ///
+    /// Inside `f` the `predicate` replaces the [`undo_interval`] merge logic, if it returns `true` the previous action
+    /// is merged with the next, first using [`UndoAction::merge`] with `within_undo_interval` set, if the action does not
+    /// merge both are grouped in a single entry. If the `predicate` returns `false` the actions are never merged.
+    ///
+    /// This can be used for finer-grained undo granularity, for example, merging consecutive single character typing,
+    /// but not cut or paste.
+    ///
+    /// [`undo_interval`]: Self::undo_interval
+    pub fn with_merge_scope<R>(
+        &self,
+        predicate: impl Fn(&UndoMergePredicateArgs) -> bool + Send + Sync + 'static,
+        f: impl FnOnce() -> R,
+    ) -> R {
+        let predicate: UndoMergePredicate = Arc::new(predicate);
+        UNDO_MERGE_CTX.with_context(&mut Some(Arc::new(Some(predicate))), f)
+    }
+
     /// Runs `f` in a disabled scope, all undo actions registered inside `f` are ignored.
     pub fn with_disabled<R>(&self, f: impl FnOnce() -> R) -> R {
         let mut scope = UndoScope::default();
@@ -366,6 +386,59 @@ impl UNDO {
         !UNDO_SCOPE_CTX.get().redo.lock().is_empty()
     }
 
+    /// Save the undo and redo stacks of the current scope.
+    ///
+    /// Only actions that implement [`UndoAction::save`] and [`RedoAction::save`] are saved, other actions
+    /// are replaced with a [`UndoHistoryEntry::Boundary`]. Note that the actions registered using [`register_op`],
+    /// [`register_full_op`] and groups are not saved.
+    ///
+    /// The history can be restored using [`load_history`], after the action loaders are registered.
+    ///
+    /// [`register_op`]: Self::register_op
+    /// [`register_full_op`]: Self::register_full_op
+    /// [`load_history`]: Self::load_history
+    pub fn save_history(&self) -> UndoHistory {
+        UNDO_SCOPE_CTX.get().save_history()
+    }
+
+    /// Replace the undo and redo stacks of the current scope with actions loaded from the `history`.
+    ///
+    /// Actions are loaded by the loader registered for the [`UndoActionData::kind`], actions that cannot load
+    /// count as a boundary, only entries after the last boundary are restored, as the previous actions
+    /// cannot be reached without undoing the missing action first.
+    ///
+    /// Note that the actions are not executed, the state of the document must be restored to the same
+    /// state it was when the history was saved.
+    pub fn load_history(&self, history: UndoHistory) {
+        UNDO_SCOPE_CTX.get().load_history(history)
+    }
+
+    /// Register a function that loads undo actions saved with `kind`.
+    ///
+    /// See [`load_history`] for more details.
+    ///
+    /// [`load_history`]: Self::load_history
+    pub fn register_undo_loader(
+        &self,
+        kind: impl Into<Txt>,
+        loader: impl Fn(&UndoActionData) -> Option<Box<dyn UndoAction>> + Send + Sync + 'static,
+    ) {
+        UNDO_SV.write().undo_loaders.insert(kind.into(), Arc::new(loader));
+    }
+
+    /// Register a function that loads redo actions saved with `kind`.
+    ///
+    /// See [`load_history`] for more details.
+    ///
+    /// [`load_history`]: Self::load_history
+    pub fn register_redo_loader(
+        &self,
+        kind: impl Into<Txt>,
+        loader: impl Fn(&UndoActionData) -> Option<Box<dyn RedoAction>> + Send + Sync + 'static,
+    ) {
+        UNDO_SV.write().redo_loaders.insert(kind.into(), Arc::new(loader));
+    }
+
     /// Clones the timestamp and info of all entries in the current undo stack.
     ///
     /// The latest undo action is the last entry in the list.
@@ -563,6 +636,15 @@ pub trait UndoAction: Send + Any {
     /// [`UNDO.undo_select`]: UNDO::undo_select
     /// [`UNDO.undo`]: UNDO::undo
     fn merge(self: Box<Self>, args: UndoActionMergeArgs) -> Result<Box<dyn UndoAction>, (Box<dyn UndoAction>, Box<dyn UndoAction>)>;
+
+    /// Save the action for [`UNDO.save_history`].
+    ///
+    /// Returns `None` by default, actions that cannot be saved are replaced with a [`UndoHistoryEntry::Boundary`].
+    ///
+    /// [`UNDO.save_history`]: UNDO::save_history
+    fn save(&mut self) -> Option<UndoActionData> {
+        None
+    }
 }
 
 /// Arguments for [`UndoAction::merge`].
@@ -580,6 +662,21 @@ pub struct UndoActionMergeArgs {
     pub within_undo_interval: bool,
 }
 
+/// Arguments for the predicate of [`UNDO.with_merge_scope`].
+///
+/// [`UNDO.with_merge_scope`]: UNDO::with_merge_scope
+pub struct UndoMergePredicateArgs {
+    /// Info of the previous action registered.
+    pub prev: Arc<dyn UndoInfo>,
+    /// Timestamp of the previous action registered.
+    pub prev_timestamp: DInstant,
+
+    /// Info of the action being registered.
+    pub next: Arc<dyn UndoInfo>,
+    /// Timestamp of the action being registered.
+    pub timestamp: DInstant,
+}
+
 /// Represents a single redo action.
 pub trait RedoAction: Send + Any {
     /// Gets display info about the action that will be redone.
@@ -587,6 +684,78 @@ pub trait RedoAction: Send + Any {
 
     /// Redo action and returns a [`UndoAction`] that undoes it.
     fn redo(self: Box<Self>) -> Box<dyn UndoAction>;
+
+    /// Save the action for [`UNDO.save_history`].
+    ///
+    /// Returns `None` by default, actions that cannot be saved are replaced with a [`UndoHistoryEntry::Boundary`].
+    ///
+    /// [`UNDO.save_history`]: UNDO::save_history
+    fn save(&mut self) -> Option<UndoActionData> {
+        None
+    }
+}
+
+/// Serialized undo or redo action.
+///
+/// See [`UNDO.save_history`] for more details.
+///
+/// [`UNDO.save_history`]: UNDO::save_history
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
+pub struct UndoActionData {
+    /// Key of the loader registered in [`UNDO.register_undo_loader`] or [`UNDO.register_redo_loader`].
+    ///
+    /// [`UNDO.register_undo_loader`]: UNDO::register_undo_loader
+    /// [`UNDO.register_redo_loader`]: UNDO::register_redo_loader
+    pub kind: Txt,
+    /// Action data, in any format understood by the loader.
+    pub data: Vec<u8>,
+}
+impl UndoActionData {
+    /// New from loader kind and serialized data.
+    pub fn new(kind: impl Into<Txt>, data: Vec<u8>) -> Self {
+        Self { kind: kind.into(), data }
+    }
+}
+
+/// Entry in an [`UndoHistory`].
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum UndoHistoryEntry {
+    /// Saved action.
+    Action {
+        /// Time elapsed from the moment the action was registered to the moment the history was saved.
+        age: Duration,
+        /// Serialized action.
+        data: UndoActionData,
+    },
+    /// Marks the position of one or more actions that could not be saved.
+    ///
+    /// Only the entries after the last boundary are loaded.
+    Boundary,
+}
+
+/// Serializable snapshot of the undo and redo stacks in an [`UNDO`] scope.
+///
+/// See [`UNDO.save_history`] for more details.
+///
+/// [`UNDO.save_history`]: UNDO::save_history
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
+pub struct UndoHistory {
+    /// Undo stack, the latest action is the last entry.
+    pub undo: Vec<UndoHistoryEntry>,
+    /// Redo stack, the latest undone action is the last entry.
+    pub redo: Vec<UndoHistoryEntry>,
+}
+impl UndoHistory {
+    /// If has no saved actions.
+    pub fn is_empty(&self) -> bool {
+        !self
+            .undo
+            .iter()
+            .chain(self.redo.iter())
+            .any(|e| matches!(e, UndoHistoryEntry::Action { .. }))
+    }
 }
 
 /// Represents an undo/redo action.
@@ -918,21 +1087,56 @@ impl UndoScope {
         self.with_enabled_undo_redo(|undo, redo| {
             let now = INSTANT.now();
             if let Some(prev) = undo.pop() {
-                match prev.action.merge(UndoActionMergeArgs {
-                    next: action,
-                    prev_timestamp: prev.timestamp,
-                    within_undo_interval: now.duration_since(prev.timestamp) <= UNDO_SV.read().undo_interval.get(),
-                }) {
-                    Ok(merged) => undo.push(UndoEntry {
+                // the merge scope predicate replaces the undo interval and groups actions that do not merge
+                let predicate = UNDO_MERGE_CTX.get();
+                let within_undo_interval = match &*predicate {
+                    Some(p) => p(&UndoMergePredicateArgs {
+                        prev: prev.action.info(),
+                        prev_timestamp: prev.timestamp,
+                        next: action.info(),
                         timestamp: now,
-                        action: merged,
                     }),
-                    Err((p, action)) => {
-                        undo.push(UndoEntry {
-                            timestamp: prev.timestamp,
-                            action: p,
-                        });
-                        undo.push(UndoEntry { timestamp: now, action });
+                    None => now.duration_since(prev.timestamp) <= UNDO_SV.read().undo_interval.get(),
+                };
+                if predicate.is_some() && !within_undo_interval {
+                    undo.push(prev);
+                    undo.push(UndoEntry { timestamp: now, action });
+                } else {
+                    match prev.action.merge(UndoActionMergeArgs {
+                        next: action,
+                        prev_timestamp: prev.timestamp,
+                        within_undo_interval,
+                    }) {
+                        Ok(merged) => undo.push(UndoEntry {
+                            timestamp: now,
+                            action: merged,
+                        }),
+                        Err((mut p, action)) => {
+                            let next = UndoEntry { timestamp: now, action };
+                            if predicate.is_none() {
+                                undo.push(UndoEntry {
+                                    timestamp: prev.timestamp,
+                                    action: p,
+                                });
+                                undo.push(next);
+                            } else if let Some(g) = p.as_any().downcast_mut::<UndoGroup>() {
+                                g.undo.push(next);
+                                undo.push(UndoEntry { timestamp: now, action: p });
+                            } else {
+                                let info = p.info();
+                                let prev = UndoEntry {
+                                    timestamp: prev.timestamp,
+                                    action: p,
+                                };
+                                undo.push(UndoEntry {
+                                    timestamp: now,
+                                    action: Box::new(UndoGroup {
+                                        info,
+                                        undo: vec![prev, next],
+                                    }),
+                                });
+                            }
+                        }
                     }
                 }
             } else {
@@ -997,6 +1201,66 @@ impl UndoScope {
     fn id(&self) -> Option<WidgetId> {
         self.id.load(Ordering::Relaxed)
     }
+
+    fn save_history(&self) -> UndoHistory {
+        fn push(entries: &mut Vec<UndoHistoryEntry>, timestamp: DInstant, data: Option<UndoActionData>) {
+            match data {
+                Some(data) => entries.push(UndoHistoryEntry::Action {
+                    age: timestamp.elapsed(),
+                    data,
+                }),
+                None => {
+                    if !matches!(entries.last(), Some(UndoHistoryEntry::Boundary)) {
+                        entries.push(UndoHistoryEntry::Boundary);
+                    }
+                }
+            }
+        }
+
+        let mut history = UndoHistory::default();
+        for e in self.undo.lock().iter_mut() {
+            push(&mut history.undo, e.timestamp, e.action.save());
+        }
+        for e in self.redo.lock().iter_mut() {
+            push(&mut history.redo, e.timestamp, e.action.save());
+        }
+        history
+    }
+
+    fn load_history(&self, history: UndoHistory) {
+        fn load<A>(
+            entries: Vec<UndoHistoryEntry>,
+            loaders: &HashMap<Txt, Arc<dyn Fn(&UndoActionData) -> Option<A> + Send + Sync>>,
+        ) -> Vec<(DInstant, A)> {
+            let now = INSTANT.now();
+            let mut r = vec![];
+            for e in entries {
+                let action = match e {
+                    UndoHistoryEntry::Action { age, data } => loaders.get(&data.kind).and_then(|l| l(&data)).map(|a| (now - age, a)),
+                    UndoHistoryEntry::Boundary => None,
+                };
+                match action {
+                    Some(a) => r.push(a),
+                    None => r.clear(),
+                }
+            }
+            r
+        }
+
+        let sv = UNDO_SV.read();
+        let undo = load(history.undo, &sv.undo_loaders);
+        let redo = load(history.redo, &sv.redo_loaders);
+        drop(sv);
+
+        *self.undo.lock() = undo
+            .into_iter()
+            .map(|(timestamp, action)| UndoEntry { timestamp, action })
+            .collect();
+        *self.redo.lock() = redo
+            .into_iter()
+            .map(|(timestamp, action)| RedoEntry { timestamp, action })
+            .collect();
+    }
 }
 
 struct UndoEntry {
@@ -1148,6 +1412,8 @@ impl RedoAction for UndoRedoFullOp {
 struct UndoService {
     undo_limit: Var<u32>,
     undo_interval: Var<Duration>,
+    undo_loaders: HashMap<Txt, Arc<dyn Fn(&UndoActionData) -> Option<Box<dyn UndoAction>> + Send + Sync>>,
+    redo_loaders: HashMap<Txt, Arc<dyn Fn(&UndoActionData) -> Option<Box<dyn RedoAction>> + Send + Sync>>,
 }
 
 impl Default for UndoService {
@@ -1155,12 +1421,17 @@ impl Default for UndoService {
         Self {
             undo_limit: var(u32::MAX),
             undo_interval: KEYBOARD.repeat_config().map(|c| c.start_delay + c.interval).cow(),
+            undo_loaders: HashMap::new(),
+            redo_loaders: HashMap::new(),
         }
     }
 }
 
+type UndoMergePredicate = Arc<dyn Fn(&UndoMergePredicateArgs) -> bool + Send + Sync>;
+
 context_local! {
     static UNDO_SCOPE_CTX: UndoScope = UndoScope::default();
+    static UNDO_MERGE_CTX: Option<UndoMergePredicate> = None;
 }
 app_local! {
     static UNDO_SV: UndoService = {
@@ -1628,6 +1899,100 @@ mod tests {
         assert_eq!(20, test_var.get());
     }
 
+    #[test]
+    fn save_load_history() {
+        let _a = APP.minimal().run_headless(false);
+        let data = Arc::new(Mutex::new(vec![]));
+
+        UNDO.run_op(
+            "push 0",
+            clmv!(data, |op| match op {
+                UndoOp::Undo => assert_eq!(data.lock().pop(), Some(0)),
+                UndoOp::Redo => data.lock().push(0),
+            }),
+        );
+        UNDO.run(PushAction {
+            data: data.clone(),
+            item: 1,
+        });
+        UNDO.run(PushAction {
+            data: data.clone(),
+            item: 2,
+        });
+        UNDO.undo_select(1);
+        assert_eq!(&[0, 1], &data.lock()[..]);
+
+        let history = UNDO.save_history();
+        assert_eq!(2, history.undo.len());
+        assert_eq!(UndoHistoryEntry::Boundary, history.undo[0]);
+        assert_eq!(1, history.redo.len());
+
+        UNDO.clear();
+        assert!(!UNDO.can_undo());
+
+        UNDO.register_undo_loader(
+            "push",
+            clmv!(data, |d| Some(Box::new(PushAction {
+                data: data.clone(),
+                item: d.data[0],
+            }) as Box<dyn UndoAction>)),
+        );
+        UNDO.register_redo_loader(
+            "push",
+            clmv!(data, |d| Some(Box::new(PushAction {
+                data: data.clone(),
+                item: d.data[0],
+            }) as Box<dyn RedoAction>)),
+        );
+        UNDO.load_history(history);
+
+        UNDO.redo_select(1);
+        assert_eq!(&[0, 1, 2], &data.lock()[..]);
+        UNDO.undo_select(1);
+        UNDO.undo_select(1);
+        assert_eq!(&[0], &data.lock()[..]);
+
+        // boundary, push 0 not restored
+        assert!(!UNDO.can_undo());
+    }
+
+    #[test]
+    fn merge_scope() {
+        let _a = APP.minimal().run_headless(false);
+        let data = Arc::new(Mutex::new(vec![]));
+
+        UNDO.with_merge_scope(
+            |a| a.prev.description() == "push" && a.next.description() == "push",
+            || {
+                UNDO.run(PushAction {
+                    data: data.clone(),
+                    item: 1,
+                });
+                UNDO.run(PushAction {
+                    data: data.clone(),
+                    item: 2,
+                });
+                UNDO.run_op(
+                    "push 3",
+                    clmv!(data, |op| match op {
+                        UndoOp::Undo => assert_eq!(data.lock().pop(), Some(3)),
+                        UndoOp::Redo => data.lock().push(3),
+                    }),
+                );
+            },
+        );
+        assert_eq!(&[1, 2, 3], &data.lock()[..]);
+        assert_eq!(2, UNDO.undo_stack().stack.len());
+
+        UNDO.undo_select(1);
+        assert_eq!(&[1, 2], &data.lock()[..]);
+        UNDO.undo_select(1);
+        assert!(data.lock().is_empty());
+
+        UNDO.redo_select(1);
+        assert_eq!(&[1, 2], &data.lock()[..]);
+    }
+
     struct PushAction {
         data: Arc<Mutex<Vec<u8>>>,
         item: u8,
@@ -1649,6 +2014,10 @@ mod tests {
         fn merge(self: Box<Self>, args: UndoActionMergeArgs) -> Result<Box<dyn UndoAction>, (Box<dyn UndoAction>, Box<dyn UndoAction>)> {
             Err((self, args.next))
         }
+
+        fn save(&mut self) -> Option<UndoActionData> {
+            Some(UndoActionData::new("push", vec![self.item]))
+        }
     }
     impl RedoAction for PushAction {
         fn redo(self: Box<Self>) -> Box<dyn UndoAction> {
@@ -1659,5 +2028,9 @@ mod tests {
         fn info(&mut self) -> Arc<dyn UndoInfo> {
             Arc::new("push")
         }
+
+        fn save(&mut self) -> Option<UndoActionData> {
+            Some(UndoActionData::new("push", vec![self.item]))
+        }
     }
 }
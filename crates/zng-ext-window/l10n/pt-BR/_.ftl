@@ -6,6 +6,10 @@ EXCLUSIVE_FULLSCREEN_CMD =
     .info = Alternar o modo de tela cheia exclusivo na janela
     .name = Tela Cheia Exclusiva
 
+EXPORT_PDF_CMD =
+    .info = Exportar a janela para um arquivo PDF
+    .name = Exportar PDF
+
 FULLSCREEN_CMD =
     .info = Alternar o modo tela cheia na janela
     .name = Tela Cheia
@@ -18,6 +22,10 @@ MINIMIZE_CMD =
     .info = Minimizar a janela
     .name = Minimizar
 
+RESET_WINDOW_STATE_CMD =
+    .info = Restaurar a janela para seu estado, posição e tamanho iniciais
+    .name = Redefinir Janela
+
 RESTORE_CMD =
     .info = Restaurar a janela para sua posição e tamanho anterior
     .name = Restaurar
@@ -8,6 +8,10 @@ EXCLUSIVE_FULLSCREEN_CMD =
     .info = Toggle exclusive fullscreen mode on the window
     .name = Exclusive Fullscreen
 
+EXPORT_PDF_CMD =
+    .info = Export the window to a PDF file
+    .name = Export PDF
+
 FULLSCREEN_CMD =
     .info = Toggle fullscreen mode on the window
     .name = Fullscreen
@@ -20,6 +24,10 @@ MINIMIZE_CMD =
     .info = Minimize the window
     .name = Minimize
 
+RESET_WINDOW_STATE_CMD =
+    .info = Restore the window to its initial state, position and size
+    .name = Reset Window
+
 RESTORE_CMD =
     .info = Restores the window to its previous non-minimized state or normal state
     .name = Restore
@@ -120,6 +120,36 @@ pub trait HeadlessAppWindowExt {
     fn doc_test_window<F>(&mut self, new_window: impl IntoFuture<IntoFuture = F>)
     where
         F: Future<Output = WindowRoot> + 'static + Send;
+
+    /// Open a headless test window with the content at a fixed `size` and `scale_factor`, await the first frame and
+    /// return the window info tree and frame pixels.
+    ///
+    /// The `child` closure is called in the new [`WINDOW`] context to create the window content. The window is closed
+    /// before returning, the info tree and image are still valid and can be used to assert on bounds and appearance.
+    ///
+    /// Note that the image is only rendered if the app was started with a renderer, otherwise the error is reported
+    /// in the [image error].
+    ///
+    /// [`WINDOW`]: zng_app::window::WINDOW
+    /// [image error]: zng_ext_image::ImageEntry::error
+    #[cfg(all(feature = "image", any(test, doc, feature = "test_util")))]
+    fn layout_snapshot(
+        &mut self,
+        size: zng_layout::unit::DipSize,
+        scale_factor: zng_layout::unit::Factor,
+        child: impl FnOnce() -> zng_app::widget::node::UiNode + Send + 'static,
+    ) -> LayoutSnapshot;
+}
+
+/// Result of [`HeadlessAppWindowExt::layout_snapshot`].
+#[cfg(all(feature = "image", any(test, doc, feature = "test_util")))]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct LayoutSnapshot {
+    /// Window info tree after the first frame, with the bounds of all widgets.
+    pub info: zng_app::widget::info::WidgetInfoTree,
+    /// First frame pixels, or the render error.
+    pub image: zng_ext_image::ImageEntry,
 }
 impl HeadlessAppWindowExt for HeadlessApp {
     fn open_window<F>(&mut self, window_id: impl Into<WindowId>, new_window: impl IntoFuture<IntoFuture = F>) -> WindowVars
@@ -210,4 +240,44 @@ impl HeadlessAppWindowExt for HeadlessApp {
             }
         }
     }
+
+    #[cfg(all(feature = "image", any(test, doc, feature = "test_util")))]
+    fn layout_snapshot(
+        &mut self,
+        size: zng_layout::unit::DipSize,
+        scale_factor: zng_layout::unit::Factor,
+        child: impl FnOnce() -> zng_app::widget::node::UiNode + Send + 'static,
+    ) -> LayoutSnapshot {
+        use crate::{HeadlessMonitor, StartPosition, WINDOW_Ext as _};
+        use zng_app::widget::WidgetId;
+        use zng_app::window::WINDOW;
+
+        let window_id = WindowId::new_unique();
+        self.open_window(window_id, async move {
+            WINDOW.vars().size().set(size);
+            WindowRoot::new_container(
+                WidgetId::named("test-window-root"),
+                StartPosition::Default,
+                false,
+                false,
+                None,
+                HeadlessMonitor::new_scale(scale_factor),
+                false,
+                child(),
+            )
+        });
+
+        let image = WINDOWS.frame_image(window_id, None);
+        let image = self
+            .run_task(async move {
+                image.wait_match(|i| i.is_loaded()).await;
+                image.get()
+            })
+            .unwrap();
+        let info = WINDOWS.widget_tree(window_id).expect("layout_snapshot window closed early");
+
+        self.close_window(window_id);
+
+        LayoutSnapshot { info, image }
+    }
 }
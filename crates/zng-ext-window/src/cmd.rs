@@ -14,7 +14,7 @@ use zng_wgt::{CommandIconExt as _, ICONS, wgt_fn};
 
 use crate::{IME_EVENT, ImeArgs, WINDOWS, WINDOWS_SV, WindowInstanceState, WindowVars};
 
-pub use zng_view_api::window::ResizeDirection;
+pub use zng_view_api::window::{ResizeDirection, SnapZone};
 
 command! {
     /// Represents the window **close** action.
@@ -86,6 +86,15 @@ command! {
         icon: wgt_fn!(|_| ICONS.get(["window-restore"])),
     };
 
+    /// Represents the window **reset state** action.
+    ///
+    /// Restores the window to the state, position and size it was opened with, useful for "reset layout" actions.
+    pub static RESET_WINDOW_STATE_CMD {
+        l10n!: true,
+        name: "Reset Window",
+        info: "Restore the window to its initial state, position and size",
+    };
+
     /// Represents the **close IME** action.
     ///
     /// If any IME preview is active close it without committing.
@@ -111,6 +120,37 @@ command! {
     /// [`DipPoint`]: zng_layout::unit::DipPoint
     /// [`PxPoint`]: zng_layout::unit::PxPoint
     pub static OPEN_TITLE_BAR_CONTEXT_MENU_CMD;
+
+    /// Represents the window **snap** action.
+    ///
+    /// Restores the window to the normal state filling a half or quarter of the monitor work area, useful for tiling shortcuts.
+    ///
+    /// # Parameter
+    ///
+    /// This command requires a [`SnapZone`] parameter.
+    pub static SNAP_CMD;
+}
+
+#[cfg(feature = "image")]
+command! {
+    /// Represents the window **export to PDF** action.
+    ///
+    /// Captures the current frame of the window and writes it as a single page raster PDF, the page size is
+    /// the frame size at the window pixel density.
+    ///
+    /// # Parameter
+    ///
+    /// This command requires a [`PathBuf`] or an [`ExportPdfRequest`] parameter. The export runs in a background task,
+    /// the [`EXPORT_PDF_EVENT`] notifies when the file is written or if it failed.
+    ///
+    /// [`PathBuf`]: std::path::PathBuf
+    /// [`ExportPdfRequest`]: crate::ExportPdfRequest
+    /// [`EXPORT_PDF_EVENT`]: crate::EXPORT_PDF_EVENT
+    pub static EXPORT_PDF_CMD {
+        l10n!: true,
+        name: "Export PDF",
+        info: "Export the window to a PDF file",
+    };
 }
 
 pub(super) struct WindowCommands {
@@ -122,6 +162,8 @@ pub(super) struct WindowCommands {
     _exclusive_handle: CommandHandle,
 
     _close_handle: CommandHandle,
+    #[cfg(feature = "image")]
+    _export_pdf_handle: CommandHandle,
 }
 impl WindowCommands {
     /// Setup command handlers, handles live in the WindowVars hooks.
@@ -190,6 +232,23 @@ impl WindowCommands {
                     let _ = WINDOWS.close(id);
                 }),
             ),
+            #[cfg(feature = "image")]
+            _export_pdf_handle: EXPORT_PDF_CMD.scoped(id).on_event(
+                true,
+                true,
+                false,
+                hn!(|args| {
+                    let request = if let Some(r) = args.param::<crate::ExportPdfRequest>() {
+                        r.clone()
+                    } else if let Some(p) = args.param::<std::path::PathBuf>() {
+                        crate::ExportPdfRequest::new(p.clone())
+                    } else {
+                        return;
+                    };
+                    args.propagation.stop();
+                    crate::pdf::export_pdf(id, request);
+                }),
+            ),
         };
         state
             .hook(move |a| {
@@ -292,5 +351,52 @@ impl WindowCommands {
                 true
             })
             .perm();
+
+        let handle = SNAP_CMD.scoped(id).on_event(
+            vars.0.resizable.get(),
+            true,
+            false,
+            hn!(|args| {
+                if let Some(zone) = args.param::<SnapZone>()
+                    && let Some(w) = WINDOWS_SV.read().windows.get(&id)
+                    && let Some(r) = &w.root
+                    && let Some(v) = &r.view_window
+                {
+                    args.propagation.stop();
+                    let _ = v.snap(*zone);
+                }
+            }),
+        );
+        vars.0
+            .resizable
+            .hook(move |a| {
+                handle.enabled().set(*a.value());
+                true
+            })
+            .perm();
+
+        let handle = RESET_WINDOW_STATE_CMD.scoped(id).on_event(
+            matches!(vars.0.instance_state.get(), WindowInstanceState::Loaded { has_view: true }),
+            true,
+            false,
+            hn!(|args| {
+                if let Some(w) = WINDOWS_SV.read().windows.get(&id)
+                    && let Some(r) = &w.root
+                    && let Some(v) = &r.view_window
+                {
+                    args.propagation.stop();
+                    let _ = v.reset_state();
+                }
+            }),
+        );
+        vars.0
+            .instance_state
+            .hook(move |a| {
+                handle
+                    .enabled()
+                    .set(matches!(a.value(), WindowInstanceState::Loaded { has_view: true }));
+                true
+            })
+            .perm();
     }
 }
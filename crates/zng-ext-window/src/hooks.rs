@@ -1,3 +1,6 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
 use zng_app::{
     EXIT_REQUESTED_EVENT,
     access::{ACCESS_DEINITED_EVENT, ACCESS_INITED_EVENT},
@@ -6,7 +9,7 @@ use zng_app::{
     view_process::{
         VIEW_PROCESS, VIEW_PROCESS_INITED_EVENT, ViewWindow,
         raw_events::{
-            RAW_COLORS_CONFIG_CHANGED_EVENT, RAW_IME_EVENT, RAW_WINDOW_CHANGED_EVENT, RAW_WINDOW_CLOSE_EVENT,
+            RAW_COLORS_CONFIG_CHANGED_EVENT, RAW_FRAME_RENDERED_EVENT, RAW_IME_EVENT, RAW_WINDOW_CHANGED_EVENT, RAW_WINDOW_CLOSE_EVENT,
             RAW_WINDOW_CLOSE_REQUESTED_EVENT, RAW_WINDOW_FOCUS_EVENT, RAW_WINDOW_OR_HEADLESS_OPEN_ERROR_EVENT,
         },
     },
@@ -23,7 +26,7 @@ use zng_layout::{
         Dip, DipRect, DipSize, DipToPx, Factor, FactorUnits as _, Layout2d, Length, Px, PxDensity, PxPoint, PxRect, PxSize, PxToDip as _,
     },
 };
-use zng_var::VarHandle;
+use zng_var::{VarHandle, VarHandles};
 use zng_view_api::window::{WindowCapability, WindowState};
 use zng_wgt::prelude::{DIRECTION_VAR, InteractionPath, LAYOUT, LayoutMetrics};
 
@@ -494,7 +497,10 @@ pub(crate) fn hook_window_vars_cmds(id: WindowId, vars: &WindowVars) {
             .actual_icon
             .hook(move |args| {
                 with_view(id, |_, _, v| {
-                    let _ = v.set_icon(args.value().as_ref().map(|i| i.view_handle()));
+                    let _ = match args.value() {
+                        Some(i) if i.has_entries() => v.set_icon_set(&icon_set(i)),
+                        i => v.set_icon(i.as_ref().map(|i| i.view_handle())),
+                    };
                 });
                 true
             })
@@ -794,6 +800,26 @@ pub(crate) fn hook_window_vars_cmds(id: WindowId, vars: &WindowVars) {
         })
         .perm();
 
+    vars.0
+        .drag_region
+        .hook(move |a| {
+            with_view(id, |_, _, v| {
+                let _ = v.set_drag_region(*a.value());
+            });
+            true
+        })
+        .perm();
+
+    vars.0
+        .transform
+        .hook(move |a| {
+            with_view(id, |_, _, v| {
+                let _ = v.set_transform(*a.value());
+            });
+            true
+        })
+        .perm();
+
     // enable/disable resizable
     vars.0
         .resizable
@@ -869,6 +895,35 @@ pub(crate) fn hook_window_vars_cmds(id: WindowId, vars: &WindowVars) {
         })
         .perm();
 
+    // set swap chain depth
+    vars.0
+        .swap_chain_depth
+        .hook(move |a| {
+            let mut actual = None;
+            with_view(id, |_, _, v| {
+                actual = v.set_swap_chain_depth(*a.value()).ok();
+            });
+            if let Some(actual) = actual
+                && let Some(vars) = WINDOWS.vars(id)
+            {
+                vars.set_from_view(|v| &v.0.actual_swap_chain_depth, actual);
+            }
+            true
+        })
+        .perm();
+
+    // set platform owner window
+    vars.0
+        .parent
+        .hook(move |a| {
+            let owner = *a.value();
+            with_view(id, |_, _, v| {
+                let _ = v.set_owner(owner);
+            });
+            true
+        })
+        .perm();
+
     // set system shutdown warning message
     vars.0
         .system_shutdown_warn
@@ -1134,10 +1189,24 @@ fn load_bind_ico<T: zng_var::VarValue>(
     })
 }
 
+/// View handles of the icon image and its loaded reduced alternates.
+#[cfg(feature = "image")]
+pub(crate) fn icon_set(icon: &zng_ext_image::ImageEntry) -> Vec<zng_app::view_process::ViewImageHandle> {
+    let mut set = vec![icon.view_handle().clone()];
+    for e in icon.entries() {
+        e.with(|e| {
+            if e.is_loaded() && matches!(e.entry_kind(), zng_view_api::image::ImageEntryKind::Reduced { .. }) {
+                set.push(e.view_handle().clone());
+            }
+        });
+    }
+    set
+}
+
 /// FOCUS service focused hook
 pub(crate) fn focused_widget_handler() -> impl FnMut(&Option<InteractionPath>) + Send + 'static {
     let mut prev_ime_area = None::<(WindowId, WidgetId)>;
-    let mut _render_handle = VarHandle::dummy();
+    let mut _render_handle = VarHandles::dummy();
     move |focused| {
         // focus service calls WINDOWS.focus, this is the focused
         let s = WINDOWS_SV.read();
@@ -1171,7 +1240,7 @@ pub(crate) fn focused_widget_handler() -> impl FnMut(&Option<InteractionPath>) +
             if new_ime_area.map(|(i, _)| i) == Some(win) {
                 // or replace it, if is same window
                 let _ = v.set_ime_area(Some(area));
-                _render_handle = hook_ime_area_update(win, new_ime_area.unwrap().1);
+                _render_handle = hook_ime_area_update(win, new_ime_area.unwrap().1, area);
                 prev_ime_area = new_ime_area;
                 return;
             }
@@ -1179,7 +1248,7 @@ pub(crate) fn focused_widget_handler() -> impl FnMut(&Option<InteractionPath>) +
             let _ = v.set_ime_area(None);
         } else {
             prev_ime_area = None;
-            _render_handle = VarHandle::dummy();
+            _render_handle = VarHandles::dummy();
         }
 
         if let Some((win, wgt)) = new_ime_area
@@ -1189,12 +1258,16 @@ pub(crate) fn focused_widget_handler() -> impl FnMut(&Option<InteractionPath>) +
         {
             let _ = v.set_ime_area(Some(area));
             prev_ime_area = new_ime_area;
-            _render_handle = hook_ime_area_update(win, wgt);
+            _render_handle = hook_ime_area_update(win, wgt, area);
         }
     }
 }
-fn hook_ime_area_update(window_id: WindowId, area_id: WidgetId) -> VarHandle {
-    WIDGET_TREE_CHANGED_EVENT.hook(move |args| {
+fn hook_ime_area_update(window_id: WindowId, area_id: WidgetId, area: DipRect) -> VarHandles {
+    let last_area = Arc::new(Mutex::new(area));
+    let mut handles = VarHandles::dummy();
+
+    let last = last_area.clone();
+    handles.push(WIDGET_TREE_CHANGED_EVENT.hook(move |args| {
         if args.tree.window_id() == window_id {
             if let Some(area) = args.tree.get(area_id)
                 && let Some(area) = area.ime_area()
@@ -1202,11 +1275,37 @@ fn hook_ime_area_update(window_id: WindowId, area_id: WidgetId) -> VarHandle {
                 && let Some(r) = &w.root
                 && let Some(v) = &r.view_window
             {
-                let _ = v.set_ime_area(Some(area.to_dip(args.tree.scale_factor())));
+                update_view_ime_area(v, &last, area.to_dip(args.tree.scale_factor()));
             } else {
                 return false;
             }
         }
         true
-    })
+    }));
+
+    // caret moves only cause a new frame, the text widget updates the area on layout/render
+    handles.push(RAW_FRAME_RENDERED_EVENT.hook(move |args| {
+        if args.window_id == window_id {
+            let s = WINDOWS_SV.read();
+            if let Some(w) = s.windows.get(&window_id)
+                && let Some(tree) = &w.info
+                && let Some(area) = tree.get(area_id)
+                && let Some(area) = area.ime_area()
+                && let Some(r) = &w.root
+                && let Some(v) = &r.view_window
+            {
+                update_view_ime_area(v, &last_area, area.to_dip(tree.scale_factor()));
+            }
+        }
+        true
+    }));
+
+    handles
+}
+fn update_view_ime_area(view: &ViewWindow, last: &Mutex<DipRect>, area: DipRect) {
+    let mut last = last.lock();
+    if *last != area {
+        *last = area;
+        let _ = view.set_ime_area(Some(area));
+    }
 }
@@ -73,6 +73,9 @@ pub trait WidgetInfoImeArea {
 /// [`WidgetInfoBuilder`]: zng_app::widget::info::WidgetInfoBuilder
 pub trait WidgetInfoBuilderImeArea {
     /// Set an IME exclusion area in the window space when this widget is focused.
+    ///
+    /// The `area` only needs to be set once, the widget can update the area on render and the window will
+    /// update the view-process IME area after each frame, so the IME candidate window follows the caret.
     fn set_ime_area(&mut self, area: Arc<Atomic<PxRect>>);
 }
 
@@ -68,4 +68,9 @@ pub use windows::*;
 
 pub mod cmd;
 
+#[cfg(feature = "image")]
+mod pdf;
+#[cfg(feature = "image")]
+pub use pdf::*;
+
 mod hooks;
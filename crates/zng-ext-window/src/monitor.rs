@@ -247,6 +247,7 @@ pub struct MonitorInfo {
     name: Var<Txt>,
     position: Var<PxPoint>,
     size: Var<PxSize>,
+    work_area: Var<PxRect>,
     video_modes: Var<Vec<VideoMode>>,
     scale_factor: Var<Factor>,
     density: Var<PxDensity>,
@@ -277,6 +278,7 @@ impl MonitorInfo {
             name: var(info.name.to_txt()),
             position: var(info.position),
             size: var(info.size),
+            work_area: var(info.work_area),
             scale_factor: var(info.scale_factor),
             video_modes: var(info.video_modes),
             refresh_rate: var(info.refresh_rate),
@@ -297,6 +299,7 @@ impl MonitorInfo {
             | check_set(&self.name, info.name.to_txt())
             | check_set(&self.position, info.position)
             | check_set(&self.size, info.size)
+            | check_set(&self.work_area, info.work_area)
             | check_set(&self.scale_factor, info.scale_factor)
             | check_set(&self.video_modes, info.video_modes)
             | check_set(&self.refresh_rate, info.refresh_rate)
@@ -324,6 +327,12 @@ impl MonitorInfo {
     pub fn size(&self) -> Var<PxSize> {
         self.size.read_only()
     }
+    /// Region of the monitor that is not covered by system UI like the taskbar, in the virtual screen, in pixels.
+    ///
+    /// Is the full monitor region if the system does not provide this info.
+    pub fn work_area(&self) -> Var<PxRect> {
+        self.work_area.read_only()
+    }
 
     /// Exclusive fullscreen video modes.
     pub fn video_modes(&self) -> Var<Vec<VideoMode>> {
@@ -376,6 +385,7 @@ impl MonitorInfo {
             name: var("<fallback>".into()),
             position: var(PxPoint::zero()),
             size: var(defaults.size.to_px(fct)),
+            work_area: var(PxRect::from_size(defaults.size.to_px(fct))),
             video_modes: var(vec![]),
             scale_factor: var(fct),
             density: var(PxDensity::default()),
@@ -0,0 +1,212 @@
+use std::{io::Write as _, path::PathBuf};
+
+use zng_app::{
+    event::{event, event_args},
+    widget::WidgetId,
+    window::WindowId,
+};
+use zng_layout::unit::PxSize;
+use zng_txt::{ToTxt as _, Txt};
+
+use crate::WINDOWS;
+
+/// Parameter for the [`EXPORT_PDF_CMD`].
+///
+/// The command also accepts a [`PathBuf`] parameter, that exports the full window.
+///
+/// [`EXPORT_PDF_CMD`]: crate::cmd::EXPORT_PDF_CMD
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ExportPdfRequest {
+    /// File path, the file is created or overwritten.
+    pub path: PathBuf,
+    /// Widget subtree to export, if not set the full window is exported.
+    ///
+    /// The widget inner bounds area of the window frame is exported.
+    pub widget: Option<WidgetId>,
+}
+impl ExportPdfRequest {
+    /// New request to export the full window.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            widget: None,
+        }
+    }
+
+    /// Only export the area of the widget.
+    pub fn with_widget(mut self, widget: impl Into<WidgetId>) -> Self {
+        self.widget = Some(widget.into());
+        self
+    }
+}
+impl From<PathBuf> for ExportPdfRequest {
+    fn from(path: PathBuf) -> Self {
+        Self::new(path)
+    }
+}
+
+event_args! {
+    /// Arguments for [`EXPORT_PDF_EVENT`].
+    pub struct ExportPdfArgs {
+        /// Window that was exported.
+        pub window_id: WindowId,
+
+        /// File path.
+        pub path: PathBuf,
+
+        /// Ok or error message.
+        pub result: Result<(), Txt>,
+
+        ..
+
+        /// Broadcast to all widgets.
+        fn is_in_target(&self, _id: WidgetId) -> bool {
+            true
+        }
+    }
+}
+
+event! {
+    /// A window export to PDF has finished.
+    ///
+    /// See [`EXPORT_PDF_CMD`] for more details.
+    ///
+    /// [`EXPORT_PDF_CMD`]: crate::cmd::EXPORT_PDF_CMD
+    pub static EXPORT_PDF_EVENT: ExportPdfArgs;
+}
+
+/// Capture the window frame and write it to PDF, notifies [`EXPORT_PDF_EVENT`] on completion.
+pub(crate) fn export_pdf(window_id: WindowId, request: ExportPdfRequest) {
+    let rect = request
+        .widget
+        .and_then(|w| WINDOWS.widget_tree(window_id)?.get(w).map(|w| w.inner_bounds()));
+    let frame = match rect {
+        Some(r) => WINDOWS.frame_image_rect(window_id, r, None),
+        None => WINDOWS.frame_image(window_id, None),
+    };
+    let scale_factor = WINDOWS.vars(window_id).map(|v| v.scale_factor().get().0).unwrap_or(1.0);
+
+    zng_task::spawn(async move {
+        frame.wait_match(|f| !f.is_loading()).await;
+        let frame = frame.get();
+
+        let result = if let Some(e) = frame.error() {
+            Err(e)
+        } else if let Some(pixels) = frame.pixels() {
+            let ppi = frame.density().map(|d| d.width.ppi()).unwrap_or(96.0 * scale_factor);
+            let size = frame.size();
+            let path = request.path.clone();
+            zng_task::wait(move || {
+                let pdf = encode_pdf(&pixels, size, ppi);
+                std::fs::write(path, pdf)
+            })
+            .await
+            .map_err(|e| e.to_txt())
+        } else {
+            Err(Txt::from_static("no frame pixels"))
+        };
+
+        if let Err(e) = &result {
+            tracing::error!("failed to export window {window_id:?} to PDF, {e}");
+        }
+        EXPORT_PDF_EVENT.notify(ExportPdfArgs::now(window_id, request.path, result));
+    });
+}
+
+/// Encode a single page raster PDF from premultiplied BGRA8 pixels.
+///
+/// The image is composed over white and the page size is the image size at the `ppi` density.
+fn encode_pdf(bgra: &[u8], size: PxSize, ppi: f32) -> Vec<u8> {
+    let width = size.width.0.max(0) as usize;
+    let height = size.height.0.max(0) as usize;
+    let mut rgb = Vec::with_capacity(width * height * 3);
+    for px in bgra.chunks_exact(4).take(width * height) {
+        // premultiplied, so adding the missing alpha composes over white
+        let bg = 255 - px[3];
+        rgb.extend_from_slice(&[px[2].saturating_add(bg), px[1].saturating_add(bg), px[0].saturating_add(bg)]);
+    }
+
+    let ppi = if ppi > 0.0 { ppi } else { 96.0 };
+    let page_w = width as f32 * 72.0 / ppi;
+    let page_h = height as f32 * 72.0 / ppi;
+    let contents = format!("q {page_w} 0 0 {page_h} 0 0 cm /Im0 Do Q");
+
+    let mut pdf = Vec::with_capacity(rgb.len() + 1024);
+    let mut offsets = Vec::with_capacity(5);
+    pdf.extend_from_slice(b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n");
+
+    offsets.push(pdf.len());
+    pdf.extend_from_slice(b"1 0 obj\n<< /Type /Catalog /Pages 2 0 R >>\nendobj\n");
+
+    offsets.push(pdf.len());
+    pdf.extend_from_slice(b"2 0 obj\n<< /Type /Pages /Kids [3 0 R] /Count 1 >>\nendobj\n");
+
+    offsets.push(pdf.len());
+    let _ = write!(
+        pdf,
+        "3 0 obj\n<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {page_w} {page_h}] /Resources << /XObject << /Im0 4 0 R >> >> /Contents 5 0 R >>\nendobj\n"
+    );
+
+    offsets.push(pdf.len());
+    let _ = write!(
+        pdf,
+        "4 0 obj\n<< /Type /XObject /Subtype /Image /Width {width} /Height {height} /ColorSpace /DeviceRGB /BitsPerComponent 8 /Length {} >>\nstream\n",
+        rgb.len()
+    );
+    pdf.extend_from_slice(&rgb);
+    pdf.extend_from_slice(b"\nendstream\nendobj\n");
+
+    offsets.push(pdf.len());
+    let _ = write!(
+        pdf,
+        "5 0 obj\n<< /Length {} >>\nstream\n{contents}\nendstream\nendobj\n",
+        contents.len()
+    );
+
+    let xref = pdf.len();
+    let _ = write!(pdf, "xref\n0 {}\n0000000000 65535 f \n", offsets.len() + 1);
+    for o in offsets.iter() {
+        let _ = write!(pdf, "{o:010} 00000 n \n");
+    }
+    let _ = write!(
+        pdf,
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+        offsets.len() + 1
+    );
+
+    pdf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zng_layout::unit::Px;
+
+    #[test]
+    fn encode_pdf_structure() {
+        // 2x1, opaque red and fully transparent
+        let bgra = [0, 0, 255, 255, 0, 0, 0, 0];
+        let pdf = encode_pdf(&bgra, PxSize::new(Px(2), Px(1)), 144.0);
+
+        assert!(pdf.starts_with(b"%PDF-1.4\n"));
+        assert!(pdf.ends_with(b"%%EOF\n"));
+
+        let text = String::from_utf8_lossy(&pdf);
+        assert!(text.contains("/MediaBox [0 0 1 0.5]"));
+        assert!(text.contains("/Width 2 /Height 1"));
+
+        // transparent pixel composed over white
+        let stream = b"stream\n";
+        let start = pdf.windows(stream.len()).position(|w| w == stream).unwrap() + stream.len();
+        assert_eq!(&pdf[start..start + 6], &[255, 0, 0, 255, 255, 255]);
+
+        // xref offsets point to the objects
+        let xref: usize = text.rsplit("startxref\n").next().unwrap().lines().next().unwrap().parse().unwrap();
+        assert!(text[xref..].starts_with("xref\n0 6\n"));
+        for (i, line) in text[xref..].lines().skip(3).take(5).enumerate() {
+            let offset: usize = line[..10].parse().unwrap();
+            assert!(text[offset..].starts_with(&format!("{} 0 obj", i + 1)));
+        }
+    }
+}
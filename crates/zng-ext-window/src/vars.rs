@@ -9,7 +9,7 @@ use zng_app::{
 use zng_color::LightDark;
 use zng_layout::unit::{
     Dip, DipPoint, DipRect, DipSideOffsets, DipSize, DipToPx, Factor, FactorUnits, Frequency, FrequencyUnits as _, Length, LengthUnits,
-    Point, PxPoint, PxSize, Size,
+    Point, PxPoint, PxSize, PxTransform, Size,
 };
 use zng_state_map::{StateId, static_id};
 use zng_txt::Txt;
@@ -67,6 +67,8 @@ pub(crate) struct WindowVarsData {
     pub(crate) restore_rect: Var<DipRect>,
 
     pub(crate) enabled_buttons: Var<WindowButton>,
+    pub(crate) transform: Var<PxTransform>,
+    pub(crate) drag_region: Var<Option<DipRect>>,
 
     pub(crate) resizable: Var<bool>,
     pub(crate) movable: Var<bool>,
@@ -91,6 +93,8 @@ pub(crate) struct WindowVarsData {
     #[cfg(feature = "image")]
     pub(crate) frame_capture_mode: Var<FrameCaptureMode>,
     pub(crate) render_mode: Var<RenderMode>,
+    pub(crate) swap_chain_depth: Var<u8>,
+    pub(crate) actual_swap_chain_depth: Var<u8>,
 
     pub(crate) access_enabled: Var<AccessEnabled>,
     pub(crate) system_shutdown_warn: Var<Txt>,
@@ -156,6 +160,8 @@ impl WindowVars {
             )),
 
             enabled_buttons: var(WindowButton::all()),
+            transform: var(PxTransform::identity()),
+            drag_region: var(None),
 
             min_size: var(Size::new(192, 48)),
             max_size: var(Size::new(100.pct(), 100.pct())),
@@ -186,6 +192,8 @@ impl WindowVars {
             #[cfg(feature = "image")]
             frame_capture_mode: var(FrameCaptureMode::Sporadic),
             render_mode: var(default_render_mode),
+            swap_chain_depth: var(2),
+            actual_swap_chain_depth: var(2),
 
             access_enabled: var(AccessEnabled::empty()),
             system_shutdown_warn: var(Txt::from("")),
@@ -232,6 +240,9 @@ impl WindowVars {
     ///
     /// The default value is [`WindowIcon::Default`].
     ///
+    /// If the icon image has reduced alternates, like the multiple sizes in an ICO file, all sizes are
+    /// provided to the system so it can select the best size for the title bar, taskbar and task switcher.
+    ///
     /// You can retrieve the custom icon image using [`actual_icon`].
     ///
     /// [`actual_icon`]: Self::actual_icon
@@ -597,6 +608,46 @@ impl WindowVars {
         self.0.enabled_buttons.clone()
     }
 
+    /// Defines a region of the window that starts a window move when pressed with the primary mouse button.
+    ///
+    /// The region is in the window content space. This is handled directly by the view-process, so it works even
+    /// when the app-process is busy, it is intended for custom title bars in windows with [`chrome`] disabled.
+    ///
+    /// The default value is `None`.
+    ///
+    /// [`chrome`]: Self::chrome
+    pub fn drag_region(&self) -> Var<Option<DipRect>> {
+        self.0.drag_region.clone()
+    }
+
+    /// Defines a visual transform applied to the window, relative to the normal window position.
+    ///
+    /// This is intended for short effects like shake or wobble animations, the transform is applied to the window
+    /// content render only, it does not affect the window [`position`] or layout.
+    ///
+    /// A shake effect can be defined by animating a translation and resetting to identity after:
+    ///
+    /// ```
+    /// # use zng_ext_window::*;
+    /// # use zng_app::window::WINDOW;
+    /// # use zng_layout::unit::{PxTransform, TimeUnits as _};
+    /// # use zng_var::*;
+    /// # fn demo() {
+    /// let offset = var(16.0f32);
+    /// offset
+    ///     .bind_map(&WINDOW.vars().transform(), |&x| PxTransform::translation(x, 0.0))
+    ///     .perm();
+    /// offset.set_ease(16.0, 0.0, 400.ms(), |t| animation::easing::ease_out(animation::easing::elastic, t)).perm();
+    /// # }
+    /// ```
+    ///
+    /// The default value is identity.
+    ///
+    /// [`position`]: Self::position
+    pub fn transform(&self) -> Var<PxTransform> {
+        self.0.transform.clone()
+    }
+
     /// Defines if the window should always stay on top of other windows.
     ///
     /// Note this only applies to other windows that are not also "always-on-top".
@@ -634,6 +685,7 @@ impl WindowVars {
     /// * If [`modal`] is set, the parent window cannot be focused while this window is open.
     /// * If a [`color_scheme`] is not set, the fallback is the parent's actual scheme.
     /// * If an [`accent_color`] is not set, the fallback is the parent's actual accent.
+    /// * The view-process sets the platform owned-window relationship, if [`WindowCapability::SET_OWNER`] is supported.
     ///
     /// The default value is `None`.
     ///
@@ -647,6 +699,7 @@ impl WindowVars {
     /// [`modal`]: Self::modal
     /// [`color_scheme`]: Self::color_scheme
     /// [`accent_color`]: Self::accent_color
+    /// [`WindowCapability::SET_OWNER`]: zng_view_api::window::WindowCapability::SET_OWNER
     pub fn parent(&self) -> Var<Option<WindowId>> {
         self.0.parent.clone()
     }
@@ -758,6 +811,26 @@ impl WindowVars {
         self.0.render_mode.read_only()
     }
 
+    /// Number of buffers in the window swap chain, `2` for double buffering or `3` for triple buffering.
+    ///
+    /// Triple buffering can reduce stutter in some drivers at the cost of one frame of latency. The default is `2`.
+    ///
+    /// The view-process may not support the requested depth, see [`actual_swap_chain_depth`] for the actual value.
+    ///
+    /// [`actual_swap_chain_depth`]: Self::actual_swap_chain_depth
+    pub fn swap_chain_depth(&self) -> Var<u8> {
+        self.0.swap_chain_depth.clone()
+    }
+
+    /// Window actual swap chain depth.
+    ///
+    /// Updates after the view-process creates the backend window and after each [`swap_chain_depth`] change.
+    ///
+    /// [`swap_chain_depth`]: Self::swap_chain_depth
+    pub fn actual_swap_chain_depth(&self) -> Var<u8> {
+        self.0.actual_swap_chain_depth.read_only()
+    }
+
     /// If an accessibility service has requested info from this window.
     ///
     /// You can enable this in the app-process using [`enable_access`], the
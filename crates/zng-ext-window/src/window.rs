@@ -1,4 +1,4 @@
-use std::{any::Any, mem, pin::Pin, sync::Arc};
+use std::{any::Any, mem, pin::Pin, sync::Arc, time::Duration};
 
 use parking_lot::Mutex;
 use zng_app::{
@@ -18,19 +18,19 @@ use zng_app::{
     window::{MonitorId, WINDOW, WindowCtx, WindowId, WindowMode},
 };
 use zng_app_context::LocalContext;
-use zng_color::{COLOR_SCHEME_VAR, Rgba, colors::ACCENT_COLOR_VAR};
+use zng_color::{COLOR_SCHEME_BLEND_VAR, COLOR_SCHEME_VAR, Rgba, colors::ACCENT_COLOR_VAR};
 use zng_layout::unit::{DipSize, TimeUnits as _};
 use zng_layout::{
     context::LayoutPassId,
     unit::{
-        Dip, DipPoint, DipToPx as _, FactorUnits as _, Layout2d as _, Length, Px, PxConstraints, PxConstraints2d, PxPoint, PxRect, PxSize,
-        PxToDip as _, PxVector,
+        Dip, DipPoint, DipToPx as _, Factor, FactorUnits as _, Layout2d as _, Length, Px, PxConstraints, PxConstraints2d, PxPoint, PxRect,
+        PxSize, PxToDip as _, PxVector,
     },
 };
 use zng_state_map::StateId;
 use zng_txt::Txt;
 use zng_unique_id::IdSet;
-use zng_var::{ResponderVar, ResponseVar, VarHandle};
+use zng_var::{ResponderVar, ResponseVar, Var, VarHandle, animation::easing, var};
 use zng_view_api::{
     api_extension::{ApiExtensionId, ApiExtensionPayload},
     config::{ColorsConfig, FontAntiAliasing},
@@ -275,6 +275,7 @@ impl WindowInstance {
                     let child = mem::replace(&mut root.child, UiNode::nil());
                     let child = with_context_var(child, ACCENT_COLOR_VAR, vars.actual_accent_color());
                     let child = with_context_var(child, COLOR_SCHEME_VAR, vars.actual_color_scheme());
+                    let child = with_context_var(child, COLOR_SCHEME_BLEND_VAR, color_scheme_blend_var(&vars));
                     let child = with_context_var(child, PARALLEL_VAR, vars.parallel());
                     root.child = child;
 
@@ -387,6 +388,28 @@ impl NestedWindowWidgetInfoExt for WidgetInfo {
     }
 }
 
+/// Tracks the `actual_color_scheme` as a blend factor, animated by [`WINDOWS.color_scheme_transition`].
+///
+/// [`WINDOWS.color_scheme_transition`]: WINDOWS::color_scheme_transition
+fn color_scheme_blend_var(vars: &WindowVars) -> Var<Factor> {
+    let scheme = vars.actual_color_scheme();
+    let blend = var(zng_color::color_scheme_blend(scheme.get()));
+    let b = blend.clone();
+    scheme
+        .hook(move |a| {
+            let target = zng_color::color_scheme_blend(*a.value());
+            let duration = WINDOWS.color_scheme_transition().get();
+            if duration == Duration::ZERO {
+                b.set(target);
+            } else {
+                b.ease(target, duration, easing::linear).perm();
+            }
+            true
+        })
+        .perm();
+    blend.read_only()
+}
+
 pub(crate) fn layout_open_view((id, n, vars): &mut (WindowId, WindowNode, Option<WindowVars>), updates: &Arc<LayoutUpdates>) {
     if !updates.delivery_list().enter_window(*id) {
         return;
@@ -619,6 +642,7 @@ pub(crate) fn layout_open_view((id, n, vars): &mut (WindowId, WindowNode, Option
                         vars.set_from_view(|v| &v.0.scale_factor, a.data.scale_factor);
                         vars.set_from_view(|v| &v.0.refresh_rate, a.data.refresh_rate);
                         vars.set_from_view(|v| &v.0.render_mode, a.data.render_mode);
+                        vars.set_from_view(|v| &v.0.actual_swap_chain_depth, a.data.swap_chain_depth);
                         vars.set_from_view(|v| &v.0.safe_padding, a.data.safe_padding);
 
                         s.set_frame_duration();
@@ -707,7 +731,7 @@ pub(crate) fn layout_open_view((id, n, vars): &mut (WindowId, WindowNode, Option
                         }
                     });
                 }
-                let r = VIEW_PROCESS.open_window(WindowRequest::new(
+                let mut request = WindowRequest::new(
                     zng_view_api::window::WindowId::from_raw(id.get()),
                     vars.0.title.get(),
                     state_all,
@@ -742,7 +766,17 @@ pub(crate) fn layout_open_view((id, n, vars): &mut (WindowId, WindowNode, Option
                     vars.0.enabled_buttons.get(),
                     vars.0.system_shutdown_warn.get(),
                     WINDOWS_EXTENSIONS.take_view_extensions_init(id),
-                ));
+                );
+                request.swap_chain_depth = vars.0.swap_chain_depth.get();
+                request.owner = vars.0.parent.get().map(|p| zng_view_api::window::WindowId::from_raw(p.get()));
+                #[cfg(feature = "image")]
+                {
+                    request.icon_set = vars.0.actual_icon.with(|i| match i {
+                        Some(i) if i.has_entries() => crate::hooks::icon_set(i).iter().skip(1).map(|h| h.image_id()).collect(),
+                        _ => vec![],
+                    });
+                }
+                let r = VIEW_PROCESS.open_window(request);
                 if r.is_err() {
                     tracing::error!("view-process window {id:?} open request failed, will retry on respawn");
                     n.view_opening = VarHandle::dummy();
@@ -1,4 +1,4 @@
-use std::{any::Any, mem, pin::Pin, sync::Arc};
+use std::{any::Any, mem, pin::Pin, sync::Arc, time::Duration};
 
 use parking_lot::Mutex;
 use zng_app::{
@@ -11,12 +11,12 @@ use zng_app::{
     },
     widget::{
         WIDGET, WidgetId,
-        info::{WidgetInfoTree, access::AccessEnabled},
+        info::{HitTestInfo, WidgetInfoTree, access::AccessEnabled},
     },
     window::{WINDOW, WINDOWS_APP, WindowId, WindowMode},
 };
 use zng_app_context::{RunOnDrop, app_local};
-use zng_layout::unit::FrequencyUnits;
+use zng_layout::unit::{DipPoint, DipToPx as _, FrequencyUnits};
 use zng_task::{ParallelIteratorExt, rayon::prelude::*};
 use zng_txt::{ToTxt as _, Txt, formatx};
 use zng_unique_id::{IdEntry, IdMap, IdSet};
@@ -43,6 +43,7 @@ pub(crate) struct WindowsService {
     pub(crate) default_render_mode: Var<RenderMode>,
     parallel: Var<ParallelWin>,
     pub(crate) frame_duration_from_monitor: Var<bool>,
+    pub(crate) color_scheme_transition: Var<Duration>,
     // Mutex for Sync
     pub(crate) root_extenders: Mutex<Vec<Box<dyn FnMut(WindowRootExtenderArgs) -> UiNode + Send + 'static>>>,
     pub(crate) open_nested_handlers: Mutex<Vec<Box<dyn FnMut(&mut OpenNestedHandlerArgs) + Send + 'static>>>,
@@ -64,6 +65,7 @@ impl WindowsService {
             default_render_mode: var_default(),
             parallel: var_default(),
             frame_duration_from_monitor: var(true),
+            color_scheme_transition: var(Duration::ZERO),
             root_extenders: Mutex::new(vec![]),
             open_nested_handlers: Mutex::new(vec![]),
 
@@ -186,6 +188,18 @@ impl WINDOWS {
     pub fn frame_duration_from_monitor(&self) -> Var<bool> {
         WINDOWS_SV.read().frame_duration_from_monitor.clone()
     }
+
+    /// Defines the duration of the cross-fade between light and dark colors when a window [`actual_color_scheme`] changes.
+    ///
+    /// This is [`Duration::ZERO`] by default, the colors change instantly. If set the window animates the [`COLOR_SCHEME_BLEND_VAR`],
+    /// the animation is skipped when [`VARS.animations_enabled`] is `false`, so it respects the system reduced motion config.
+    ///
+    /// [`actual_color_scheme`]: WindowVars::actual_color_scheme
+    /// [`COLOR_SCHEME_BLEND_VAR`]: zng_color::COLOR_SCHEME_BLEND_VAR
+    /// [`VARS.animations_enabled`]: VARS::animations_enabled
+    pub fn color_scheme_transition(&self) -> Var<Duration> {
+        WINDOWS_SV.read().color_scheme_transition.clone()
+    }
 }
 impl WINDOWS {
     /// Requests a new window.
@@ -548,6 +562,18 @@ impl WINDOWS {
         zng_app::window::WindowsService::widget_info(self, id.into())
     }
 
+    /// Hit test the latest frame of the window at a `point` in the window content area.
+    ///
+    /// Returns all widgets hit, sorted front to back, or `None` if the window is not found or has not rendered yet. This
+    /// can be used to resolve the widget at a position on demand, without a pointer event.
+    pub fn hit_test(&self, window_id: impl Into<WindowId>, point: DipPoint) -> Option<HitTestInfo> {
+        let tree = self.widget_tree(window_id)?;
+        if !tree.is_rendered() {
+            return None;
+        }
+        Some(tree.root().hit_test(point.to_px(tree.scale_factor())))
+    }
+
     /// Returns shared references to the widget trees of each open window.
     pub fn widget_trees(&self) -> Vec<WidgetInfoTree> {
         WINDOWS_SV.read().windows.values().filter_map(|v| v.info.clone()).collect()
@@ -752,6 +778,68 @@ impl WINDOWS {
         self.frame_image_task(window_id.into(), Box::new(move |v| v.frame_image_rect(rect, mask)))
     }
 
+    /// Generate a downscaled image from the current rendered frame of the window, for use as a window switcher preview.
+    ///
+    /// The image fits `max_size` preserving the aspect ratio, it is not upscaled. The image is not loaded at the
+    /// moment of return, it will update when the frame pixels are copied.
+    ///
+    /// If the window is not found the error is reported in the [image error].
+    ///
+    /// [image error]: zng_ext_image::ImageEntry::error
+    pub fn window_thumbnail(&self, window_id: impl Into<WindowId>, max_size: zng_layout::unit::PxSize) -> zng_ext_image::ImageVar {
+        self.frame_image_task(window_id.into(), Box::new(move |v| v.window_thumbnail(max_size)))
+    }
+
+    /// Generate an image from the area of the widget in the current rendered frame of the window.
+    ///
+    /// The widget [`inner_bounds`] of the latest rendered frame define the [`frame_image_rect`], so the image includes the
+    /// border and content of the widget and anything rendered over it. The image is not loaded at the moment of return,
+    /// it will update when the frame pixels are copied.
+    ///
+    /// If the window or widget is not found, or the widget has not rendered yet, the error is reported in the [image error].
+    ///
+    /// [`inner_bounds`]: zng_app::widget::info::WidgetInfo::inner_bounds
+    /// [`frame_image_rect`]: Self::frame_image_rect
+    /// [image error]: zng_ext_image::ImageEntry::error
+    pub fn capture_widget(
+        &self,
+        window_id: impl Into<WindowId>,
+        widget_id: impl Into<WidgetId>,
+        mask: Option<zng_ext_image::ImageMaskMode>,
+    ) -> zng_ext_image::ImageVar {
+        let window_id = window_id.into();
+        let widget_id = widget_id.into();
+
+        let tree = match self.widget_tree(window_id) {
+            Some(t) => t,
+            None => return const_var(zng_ext_image::ImageEntry::new_error(formatx!("window {window_id} not found"))),
+        };
+        let rect = match tree.get(widget_id) {
+            Some(w) => w.inner_bounds(),
+            None => {
+                return const_var(zng_ext_image::ImageEntry::new_error(formatx!(
+                    "widget {widget_id} not found in window {window_id}"
+                )));
+            }
+        };
+        if rect.size.is_empty() {
+            return const_var(zng_ext_image::ImageEntry::new_error(formatx!(
+                "widget {widget_id} has not rendered"
+            )));
+        }
+
+        self.frame_image_rect(window_id, rect, mask)
+    }
+
+    /// Query the modifier keys currently pressed from the operating system.
+    ///
+    /// Returns `None` if the window is not found, has no view or the view-process is not available.
+    pub fn modifiers_state(&self, window_id: impl Into<WindowId>) -> Option<zng_app::shortcut::ModifiersState> {
+        let s = WINDOWS_SV.read();
+        let v = s.windows.get(&window_id.into())?.root.as_ref()?.view_window.as_ref()?;
+        v.modifiers_state().ok()
+    }
+
     fn frame_image_task(
         &self,
         window_id: WindowId,
@@ -1274,6 +1362,18 @@ impl WINDOWS_DRAG_DROP {
             let _ = v.drag_dropped(drop_id, applied);
         }
     }
+
+    /// Set the effect that will be applied if the current system drag&drop hovering the window is dropped.
+    ///
+    /// Empty `effect` rejects the drop. Note that unlike normal service methods this applies immediately.
+    pub fn set_drop_effect(&self, window_id: WindowId, effect: DragDropEffect) {
+        if let Some(w) = WINDOWS_SV.read().windows.get(&window_id)
+            && let Some(root) = &w.root
+            && let Some(v) = &root.view_window
+        {
+            let _ = v.set_drop_effect(effect);
+        }
+    }
 }
 
 #[cfg(feature = "image")]
@@ -2,6 +2,7 @@
 //!
 //! Use [`bounded`], [`unbounded`] and [`rendezvous`] to create channels for use across threads in the same process.
 //! Use [`ipc_unbounded`] to create channels that work across processes.
+//! Use [`select`] to wait on multiple receivers.
 //!
 //! # Examples
 //!
@@ -25,7 +26,7 @@
 //! });
 //! ```
 
-use std::{fmt, sync::Arc, time::Duration};
+use std::{fmt, sync::Arc, task::Poll, time::Duration};
 
 use zng_time::{Deadline, INSTANT};
 
@@ -414,6 +415,66 @@ pub fn rendezvous<T>() -> (Sender<T>, Receiver<T>) {
     bounded::<T>(0)
 }
 
+/// Wait for the first incoming value from any of the `receivers`.
+///
+/// Returns the index of the receiver and the value received. If more than one receiver is ready the
+/// first in the list is selected. Receivers that disconnect are ignored, returns an error if all
+/// senders of all receivers have been dropped or if `receivers` is empty.
+///
+/// # Examples
+///
+/// ```no_run
+/// use zng_task::channel;
+///
+/// # async fn demo(clicks: channel::Receiver<u32>, keys: channel::Receiver<u32>) {
+/// while let Ok((i, msg)) = channel::select([&clicks, &keys]).await {
+///     match i {
+///         0 => println!("click {msg}"),
+///         1 => println!("key {msg}"),
+///         _ => unreachable!(),
+///     }
+/// }
+/// # }
+/// ```
+pub async fn select<'a, T: 'a>(receivers: impl IntoIterator<Item = &'a Receiver<T>>) -> Result<(usize, T), ChannelError> {
+    let mut futs: Vec<_> = receivers.into_iter().map(|r| Some(Box::pin(r.0.recv_async()))).collect();
+    std::future::poll_fn(move |cx| {
+        let mut any_pending = false;
+        for (i, fut) in futs.iter_mut().enumerate() {
+            if let Some(f) = fut {
+                match f.as_mut().poll(cx) {
+                    Poll::Ready(Ok(msg)) => return Poll::Ready(Ok((i, msg))),
+                    Poll::Ready(Err(_)) => *fut = None,
+                    Poll::Pending => any_pending = true,
+                }
+            }
+        }
+        if any_pending {
+            Poll::Pending
+        } else {
+            Poll::Ready(Err(ChannelError::disconnected()))
+        }
+    })
+    .await
+}
+
+/// Wait for the first incoming value from any of the `receivers` that `map` converts to `Some(_)`.
+///
+/// This is like [`select`], but values that map to `None` are dropped and the wait continues. Returns the index
+/// of the receiver and the mapped value.
+pub async fn select_map<'a, T: 'a, O>(
+    receivers: impl IntoIterator<Item = &'a Receiver<T>>,
+    mut map: impl FnMut(usize, T) -> Option<O>,
+) -> Result<(usize, O), ChannelError> {
+    let receivers: Vec<_> = receivers.into_iter().collect();
+    loop {
+        let (i, msg) = select(receivers.iter().copied()).await?;
+        if let Some(o) = map(i, msg) {
+            return Ok((i, o));
+        }
+    }
+}
+
 /// Error during channel send or receive.
 #[derive(Debug, Clone)]
 pub enum ChannelError {
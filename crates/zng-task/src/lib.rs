@@ -54,6 +54,10 @@ pub use ui::*;
 mod progress;
 pub use progress::*;
 
+mod metrics;
+#[cfg(feature = "metrics")]
+pub use metrics::{TaskMetrics, metrics, reset_metrics};
+
 /// Spawn a parallel async task, this function is not blocking and the `task` starts executing immediately.
 ///
 /// # Parallel
@@ -131,7 +135,7 @@ where
         ctx: LocalContext::capture(),
         fut: Mutex::new(Some(Box::pin(task.into_future()))),
     })
-    .poll()
+    .poll(true)
 }
 
 /// Polls the `task` once immediately on the calling thread, if the `task` is pending, continues execution in [`spawn`].
@@ -167,7 +171,7 @@ where
                     ctx,
                     fut: Mutex::new(Some(Box::pin(task))),
                 })
-                .poll();
+                .poll(false);
             }
         }
     }
@@ -186,8 +190,10 @@ struct RayonTask {
     fut: Mutex<Option<RayonSpawnFut>>,
 }
 impl RayonTask {
-    fn poll(self: Arc<Self>) {
+    fn poll(self: Arc<Self>, first: bool) {
+        let queued = metrics::QueuedPoll::new(first);
         rayon::spawn(move || {
+            queued.start();
             // this `Option<Fut>` dance is used to avoid a `poll` after `Ready` or panic.
             let mut task = self.fut.lock();
             if let Some(mut t) = task.take() {
@@ -214,7 +220,7 @@ impl RayonTask {
 }
 impl std::task::Wake for RayonTask {
     fn wake(self: Arc<Self>) {
-        self.poll()
+        self.poll(false)
     }
 }
 
@@ -422,12 +428,14 @@ where
         sender: flume::Sender<Result<R, TaskPanicError>>,
     }
     impl<R: Send + 'static> RayonCatchTask<R> {
-        fn poll(self: Arc<Self>) {
+        fn poll(self: Arc<Self>, first: bool) {
             let sender = self.sender.clone();
             if sender.is_disconnected() {
                 return; // cancel.
             }
+            let queued = metrics::QueuedPoll::new(first);
             rayon::spawn(move || {
+                queued.start();
                 // this `Option<Fut>` dance is used to avoid a `poll` after `Ready` or panic.
                 let mut task = self.fut.lock();
                 if let Some(mut t) = task.take() {
@@ -456,7 +464,7 @@ where
     }
     impl<R: Send + 'static> std::task::Wake for RayonCatchTask<R> {
         fn wake(self: Arc<Self>) {
-            self.poll()
+            self.poll(false)
         }
     }
 
@@ -467,7 +475,7 @@ where
         fut: Mutex::new(Some(Box::pin(task.into_future()))),
         sender: sender.into(),
     })
-    .poll();
+    .poll(true);
 
     receiver.recv().await.unwrap()
 }
@@ -526,12 +534,14 @@ where
         responder: zng_var::ResponderVar<R>,
     }
     impl<R: VarValue> RayonRespondTask<R> {
-        fn poll(self: Arc<Self>) {
+        fn poll(self: Arc<Self>, first: bool) {
             let responder = self.responder.clone();
             if responder.strong_count() == 2 {
                 return; // cancel.
             }
+            let queued = metrics::QueuedPoll::new(first);
             rayon::spawn(move || {
+                queued.start();
                 // this `Option<Fut>` dance is used to avoid a `poll` after `Ready` or panic.
                 let mut task = self.fut.lock();
                 if let Some(mut t) = task.take() {
@@ -563,7 +573,7 @@ where
     }
     impl<R: VarValue> std::task::Wake for RayonRespondTask<R> {
         fn wake(self: Arc<Self>) {
-            self.poll()
+            self.poll(false)
         }
     }
 
@@ -572,7 +582,7 @@ where
         fut: Mutex::new(Some(Box::pin(task))),
         responder,
     })
-    .poll();
+    .poll(true);
 
     response
 }
@@ -679,6 +689,85 @@ where
         .map_err(TaskPanicError::new)
 }
 
+/// Like [`wait`], but with a `deadline` after which the `task` is asked to stop.
+///
+/// The `task` closure receives an [`InterruptFlag`] that is set when the `deadline` is reached or when the returned
+/// future is dropped. If the `deadline` is reached first returns [`DeadlineError`] immediately, without waiting for the `task` to return.
+///
+/// # Cooperation
+///
+/// The blocking `task` cannot be forcefully stopped, it must check the flag periodically and return early when it is
+/// interrupted, otherwise it keeps occupying a thread from the blocking pool until it finishes normally. For blocking IO
+/// operations configure a short timeout so that the flag can be checked between attempts.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use zng_task as task;
+/// # use zng_unit::*;
+/// # async fn demo(socket: std::net::UdpSocket) {
+/// let r = task::wait_interruptible(
+///     move |flag| {
+///         socket.set_read_timeout(Some(100.ms())).unwrap();
+///         let mut buf = [0; 1024];
+///         while !flag.is_interrupted() {
+///             if let Ok(len) = socket.recv(&mut buf) {
+///                 return Some(buf[..len].to_vec());
+///             }
+///         }
+///         None
+///     },
+///     5.secs(),
+/// )
+/// .await;
+/// # }
+/// ```
+///
+/// # Panic Handling
+///
+/// If the `task` panics before the `deadline` the panic is resumed in the awaiting thread.
+pub async fn wait_interruptible<T, F>(task: F, deadline: impl Into<Deadline>) -> Result<T, DeadlineError>
+where
+    F: FnOnce(&InterruptFlag) -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let flag = InterruptFlag::new();
+    let _interrupt_on_drop = zng_app_context::RunOnDrop::new(zng_clone_move::clmv!(flag, || flag.interrupt()));
+
+    match with_deadline(wait_catch(zng_clone_move::clmv!(flag, || task(&flag))), deadline).await {
+        Ok(Ok(r)) => Ok(r),
+        Ok(Err(p)) => panic::resume_unwind(p.payload),
+        Err(e) => Err(e),
+    }
+}
+
+/// Interrupt signal for [`wait_interruptible`] tasks.
+///
+/// The flag can be cloned and shared with other threads.
+#[derive(Clone, Default)]
+pub struct InterruptFlag(Arc<AtomicBool>);
+impl fmt::Debug for InterruptFlag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("InterruptFlag").field(&self.is_interrupted()).finish()
+    }
+}
+impl InterruptFlag {
+    /// New flag, not interrupted.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the flag.
+    pub fn interrupt(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// If the flag is set, the task must return as soon as possible.
+    pub fn is_interrupted(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
 /// Fire and forget a [`wait`] task. The `task` starts executing immediately.
 ///
 /// # Panic Handling
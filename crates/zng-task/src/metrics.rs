@@ -0,0 +1,170 @@
+#[cfg(feature = "metrics")]
+pub use enabled::*;
+#[cfg(feature = "metrics")]
+mod enabled {
+    use std::{
+        sync::atomic::{AtomicU64, Ordering},
+        time::{Duration, Instant},
+    };
+
+    use parking_lot::Mutex;
+
+    /// Task executor metrics.
+    ///
+    /// See [`metrics`] for more details.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub struct TaskMetrics {
+        /// Number of tasks started by [`spawn`], [`run`], [`respond`] and related functions.
+        ///
+        /// [`spawn`]: crate::spawn
+        /// [`run`]: crate::run
+        /// [`respond`]: crate::respond
+        pub spawn_count: u64,
+        /// Number of task polls dispatched to the rayon thread-pool.
+        pub poll_count: u64,
+        /// Number of polls dispatched that have not started running yet.
+        pub queue_depth: u64,
+        /// Maximum `queue_depth` observed.
+        pub max_queue_depth: u64,
+
+        /// Number of tasks that have started running.
+        pub first_poll_count: u64,
+        /// Sum of the time each task waited from spawn to first poll.
+        pub first_poll_latency_total: Duration,
+        /// Maximum time a task waited from spawn to first poll.
+        pub first_poll_latency_max: Duration,
+    }
+    impl TaskMetrics {
+        /// Average time tasks waited from spawn to first poll.
+        pub fn first_poll_latency_avg(&self) -> Duration {
+            if self.first_poll_count == 0 {
+                Duration::ZERO
+            } else {
+                Duration::from_secs_f64(self.first_poll_latency_total.as_secs_f64() / self.first_poll_count as f64)
+            }
+        }
+    }
+
+    static SPAWN_COUNT: AtomicU64 = AtomicU64::new(0);
+    static POLL_COUNT: AtomicU64 = AtomicU64::new(0);
+    static QUEUE_DEPTH: AtomicU64 = AtomicU64::new(0);
+    static MAX_QUEUE_DEPTH: AtomicU64 = AtomicU64::new(0);
+    static FIRST_POLL: Mutex<(u64, Duration, Duration)> = Mutex::new((0, Duration::ZERO, Duration::ZERO));
+
+    /// Gets a snapshot of the task executor metrics.
+    ///
+    /// The metrics are collected for all rayon backed tasks, polls that are queued for a long time indicate that the
+    /// rayon thread-pool is saturated, possibly by blocking operations running in it.
+    pub fn metrics() -> TaskMetrics {
+        let (first_poll_count, first_poll_latency_total, first_poll_latency_max) = *FIRST_POLL.lock();
+        TaskMetrics {
+            spawn_count: SPAWN_COUNT.load(Ordering::Relaxed),
+            poll_count: POLL_COUNT.load(Ordering::Relaxed),
+            queue_depth: QUEUE_DEPTH.load(Ordering::Relaxed),
+            max_queue_depth: MAX_QUEUE_DEPTH.load(Ordering::Relaxed),
+            first_poll_count,
+            first_poll_latency_total,
+            first_poll_latency_max,
+        }
+    }
+
+    /// Reset the task executor metrics.
+    ///
+    /// Note that the current `queue_depth` is not reset.
+    pub fn reset_metrics() {
+        SPAWN_COUNT.store(0, Ordering::Relaxed);
+        POLL_COUNT.store(0, Ordering::Relaxed);
+        MAX_QUEUE_DEPTH.store(QUEUE_DEPTH.load(Ordering::Relaxed), Ordering::Relaxed);
+        *FIRST_POLL.lock() = (0, Duration::ZERO, Duration::ZERO);
+    }
+
+    pub(crate) struct QueuedPoll {
+        first: bool,
+        queued: Instant,
+    }
+    impl QueuedPoll {
+        pub(crate) fn new(first: bool) -> Self {
+            if first {
+                SPAWN_COUNT.fetch_add(1, Ordering::Relaxed);
+            }
+            POLL_COUNT.fetch_add(1, Ordering::Relaxed);
+            let depth = QUEUE_DEPTH.fetch_add(1, Ordering::Relaxed) + 1;
+            MAX_QUEUE_DEPTH.fetch_max(depth, Ordering::Relaxed);
+            Self {
+                first,
+                queued: Instant::now(),
+            }
+        }
+
+        pub(crate) fn start(self) {
+            QUEUE_DEPTH.fetch_sub(1, Ordering::Relaxed);
+            if self.first {
+                let latency = self.queued.elapsed();
+                let mut f = FIRST_POLL.lock();
+                f.0 += 1;
+                f.1 += latency;
+                f.2 = f.2.max(latency);
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "metrics"))]
+pub(crate) struct QueuedPoll {}
+#[cfg(not(feature = "metrics"))]
+impl QueuedPoll {
+    pub(crate) fn new(first: bool) -> Self {
+        let _ = first;
+        Self {}
+    }
+
+    pub(crate) fn start(self) {}
+}
+
+#[cfg(all(test, feature = "metrics"))]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    };
+
+    use zng_unit::TimeUnits as _;
+
+    use crate::*;
+
+    #[test]
+    fn counters_move() {
+        // other tests can run tasks in parallel, so the counters are only compared as lower bounds
+        let before = metrics();
+
+        let flag = Arc::new(AtomicBool::new(false));
+        let (sender, receiver) = channel::bounded(1);
+        spawn({
+            let flag = flag.clone();
+            async move {
+                flag.store(true, Ordering::Relaxed);
+                let _ = sender.send(()).await;
+            }
+        });
+        let r = block_on(with_deadline(
+            async {
+                receiver.recv().await.unwrap();
+                let a = run(async { 1 }).await;
+                let b = wait(|| 2).await;
+                a + b
+            },
+            20.secs(),
+        ))
+        .unwrap();
+        assert_eq!(3, r);
+        assert!(flag.load(Ordering::Relaxed));
+
+        let after = metrics();
+        assert!(after.spawn_count >= before.spawn_count + 2, "{before:?}\n{after:?}");
+        assert!(after.poll_count >= before.poll_count + 2, "{before:?}\n{after:?}");
+        assert!(after.first_poll_count >= before.first_poll_count + 2, "{before:?}\n{after:?}");
+        assert!(after.max_queue_depth >= 1, "{after:?}");
+        assert!(after.first_poll_latency_max >= after.first_poll_latency_avg());
+    }
+}
@@ -213,3 +213,49 @@ fn fn_all_some_none() {
     assert!(results.is_none());
     assert!((30 * 50).ms() > t.elapsed())
 }
+
+#[test]
+fn channel_select() {
+    async_test(async {
+        let (s0, r0) = channel::unbounded::<u32>();
+        let (s1, r1) = channel::unbounded::<u32>();
+
+        s1.send(10).await.unwrap();
+        assert_eq!(Ok((1, 10)), channel::select([&r0, &r1]).await);
+
+        s0.send(1).await.unwrap();
+        s1.send(11).await.unwrap();
+        assert_eq!(Ok((0, 1)), channel::select([&r0, &r1]).await);
+        assert_eq!(Ok((1, 11)), channel::select([&r0, &r1]).await);
+
+        drop(s0);
+        s1.send(12).await.unwrap();
+        s1.send(13).await.unwrap();
+        assert_eq!(Ok((1, 13)), channel::select_map([&r0, &r1], |_, m| (m > 12).then_some(m)).await);
+
+        drop(s1);
+        assert!(channel::select([&r0, &r1]).await.is_err());
+    })
+}
+
+#[test]
+fn wait_interruptible_deadline() {
+    async_test(async {
+        let r = wait_interruptible(|_| 10, 5.secs()).await;
+        assert_eq!(10, r.unwrap());
+
+        let (sender, receiver) = channel::bounded(1);
+        let r = wait_interruptible(
+            move |flag| {
+                while !flag.is_interrupted() {
+                    std::thread::sleep(std::time::Duration::from_millis(1));
+                }
+                sender.send_blocking(true).unwrap();
+            },
+            20.ms(),
+        )
+        .await;
+        assert!(r.is_err());
+        assert!(receiver.recv().await.unwrap());
+    })
+}
@@ -159,6 +159,11 @@ impl<T: VarValue> ContextVar<T> {
         let mut var = Some(Arc::new(var.into_var().as_any().current_context()));
         self.with_context(id, &mut var, action)
     }
+
+    /// Gets if the variable is not set in the current context, that is, it represents the default value.
+    pub fn is_default(self) -> bool {
+        self.ctx.is_default()
+    }
 }
 impl<T: VarValue> ops::Deref for ContextVar<T> {
     type Target = Var<T>;
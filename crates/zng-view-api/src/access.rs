@@ -369,6 +369,11 @@ pub enum AccessState {
 
     /// Normalized (0..1) vertical scroll, 0 is showing the content topmost edge, 1 is showing the content the bottommost edge.
     ScrollVertical(f32),
+
+    /// Defines a human readable description of the widget role.
+    ///
+    /// Only use this to describe custom widgets that are not fully represented by a standard [`AccessRole`].
+    RoleDescription(Txt),
 }
 
 #[cfg(feature = "var")]
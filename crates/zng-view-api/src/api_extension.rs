@@ -5,6 +5,13 @@ use std::{fmt, ops};
 use serde::{Deserialize, Serialize};
 use zng_txt::Txt;
 
+crate::declare_id! {
+    /// Identifies an extension command that responds asynchronously.
+    ///
+    /// The View Process defines the ID.
+    pub struct ApiExtensionRequestId(_);
+}
+
 /// Custom serialized data, in a format defined by the extension.
 ///
 /// Note that the bytes here should represent a serialized small `struct` only, you
@@ -28,6 +35,11 @@ impl ApiExtensionPayload {
             })
         } else if let Some(id) = self.parse_unknown_extension() {
             Err(ApiExtensionRecvError::UnknownExtension { extension_id: id })
+        } else if let Some((id, request_id)) = self.parse_pending() {
+            Err(ApiExtensionRecvError::Pending {
+                extension_id: id,
+                request_id,
+            })
         } else {
             match postcard::from_bytes::<T>(&self.0) {
                 Ok(r) => Ok(r),
@@ -55,6 +67,38 @@ impl ApiExtensionPayload {
         Self(format!("zng-view-api.invalid_request;id={extension_id};error={error}").into_bytes())
     }
 
+    /// Value returned when the extension will respond asynchronously.
+    ///
+    /// The response is send later using an [`Event::ExtensionResponse`] with the same `request_id`.
+    ///
+    /// Value is a string `"zng-view-api.pending;id={extension_id};request={request_id}"`.
+    ///
+    /// [`Event::ExtensionResponse`]: crate::Event::ExtensionResponse
+    pub fn pending(extension_id: ApiExtensionId, request_id: ApiExtensionRequestId) -> Self {
+        Self(format!("zng-view-api.pending;id={extension_id};request={}", request_id.get()).into_bytes())
+    }
+
+    /// If the payload is a [`pending`] message, returns the key and request ID.
+    ///
+    /// [`pending`]: Self::pending
+    pub fn parse_pending(&self) -> Option<(ApiExtensionId, ApiExtensionRequestId)> {
+        let p = self.0.strip_prefix(b"zng-view-api.pending;")?;
+        if let Some(p) = p.strip_prefix(b"id=")
+            && let Some(id_end) = p.iter().position(|&b| b == b';')
+            && let Ok(id_str) = std::str::from_utf8(&p[..id_end])
+            && let Some(p) = p[id_end..].strip_prefix(b";request=")
+            && let Ok(request_str) = std::str::from_utf8(p)
+            && let Ok(request) = request_str.parse::<u32>()
+        {
+            let id = match id_str.parse::<ApiExtensionId>() {
+                Ok(id) => id,
+                Err(id) => id,
+            };
+            return Some((id, ApiExtensionRequestId::from_raw(request)));
+        }
+        Some((ApiExtensionId::INVALID, ApiExtensionRequestId::INVALID))
+    }
+
     /// If the payload is an [`unknown_extension`] error message, returns the key.
     ///
     /// if the payload starts with the invalid request header and the key cannot be retrieved the
@@ -319,6 +363,19 @@ pub enum ApiExtensionRecvError {
     },
     /// Failed to deserialize to the expected response type.
     Deserialize(postcard::Error),
+    /// Extension will respond asynchronously.
+    ///
+    /// The response is send later using an [`Event::ExtensionResponse`] with the same `request_id`.
+    ///
+    /// [`Event::ExtensionResponse`]: crate::Event::ExtensionResponse
+    Pending {
+        /// Extension that was requested.
+        ///
+        /// Is `INVALID` only if the message is corrupted.
+        extension_id: ApiExtensionId,
+        /// Request ID of the response.
+        request_id: ApiExtensionRequestId,
+    },
 }
 impl fmt::Display for ApiExtensionRecvError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -328,6 +385,9 @@ impl fmt::Display for ApiExtensionRecvError {
                 write!(f, "invalid API request for extension id {extension_id:?}, {error}")
             }
             ApiExtensionRecvError::Deserialize(e) => write!(f, "API extension response failed to deserialize, {e}"),
+            ApiExtensionRecvError::Pending { extension_id, request_id } => {
+                write!(f, "API extension {extension_id:?} will respond later to request {request_id:?}")
+            }
         }
     }
 }
@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, VecDeque},
     panic,
     path::{Path, PathBuf},
     sync::Arc,
@@ -9,7 +9,7 @@ use std::{
 
 use std::time::Duration;
 
-use parking_lot::Mutex;
+use parking_lot::{Condvar, Mutex};
 use zng_task::channel::ChannelError;
 use zng_txt::Txt;
 
@@ -32,6 +32,30 @@ enum ViewState {
     Suspended,
 }
 
+/// Defines how events received from the view-process are buffered before the controller `on_event` closure is called.
+///
+/// See [`Controller::start_with_event_buffer`] for more details.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum EventBufferPolicy {
+    /// Events are dispatched in the IPC listener thread, the IPC channel grows without limit if `on_event` is slower
+    /// than the view-process.
+    #[default]
+    Unbounded,
+    /// Events are queued in a buffer with the maximum `capacity`, `on_event` is called in a dispatcher thread.
+    ///
+    /// When the buffer is full incoming [coalescable] events are coalesced with the newest buffered event or
+    /// the oldest buffered coalescable event is dropped to make room. If the buffer is full of other events
+    /// the listener blocks until `on_event` catches up, this applies backpressure to the view-process so critical
+    /// events like key presses and window changes are never dropped.
+    ///
+    /// [coalescable]: Event::is_coalescable
+    Bounded {
+        /// Maximum number of events waiting for dispatch. The minimum value is 1.
+        capacity: usize,
+    },
+}
+
 /// View Process controller, used in the App Process.
 ///
 /// # Exit
@@ -55,6 +79,7 @@ pub struct Controller {
     request_sender: ipc::RequestSender,
     response_receiver: ipc::ResponseReceiver,
     event_listener: Option<EventListenerJoin>,
+    event_buffer: EventBufferPolicy,
     headless: bool,
     same_process: bool,
     last_respawn: Option<Instant>,
@@ -94,12 +119,40 @@ impl Controller {
     where
         F: FnMut(Event) + Send + 'static,
     {
-        Self::start_impl(view_process_exe, view_process_env, headless, Box::new(on_event))
+        Self::start_impl(
+            view_process_exe,
+            view_process_env,
+            headless,
+            EventBufferPolicy::Unbounded,
+            Box::new(on_event),
+        )
+    }
+
+    /// Start with a custom view process and event buffer policy.
+    ///
+    /// This is the same as [`start`], but the events are buffered according to the `event_buffer` policy. Note that
+    /// the buffer only fills if `on_event` takes longer to process events than the view-process takes to generate then,
+    /// a flood of device events can otherwise exhaust memory in the IPC channel.
+    ///
+    /// [`start`]: Self::start
+    pub fn start_with_event_buffer<F>(
+        view_process_exe: PathBuf,
+        view_process_env: HashMap<Txt, Txt>,
+        headless: bool,
+        event_buffer: EventBufferPolicy,
+        on_event: F,
+    ) -> Self
+    where
+        F: FnMut(Event) + Send + 'static,
+    {
+        Self::start_impl(view_process_exe, view_process_env, headless, event_buffer, Box::new(on_event))
     }
+
     fn start_impl(
         view_process_exe: PathBuf,
         view_process_env: HashMap<Txt, Txt>,
         headless: bool,
+        event_buffer: EventBufferPolicy,
         on_event: Box<dyn FnMut(Event) + Send>,
     ) -> Self {
         if ViewConfig::from_env().is_some() {
@@ -111,9 +164,9 @@ impl Controller {
         let same_process = process.is_none();
         let process = Arc::new(Mutex::new(process.map(|p| (p, false))));
         let ev = if same_process {
-            Self::spawn_same_process_listener(on_event, event_receiver, ViewProcessGen::first())
+            Self::spawn_same_process_listener(on_event, event_buffer, event_receiver, ViewProcessGen::first())
         } else {
-            Self::spawn_other_process_listener(on_event, event_receiver, process.clone(), ViewProcessGen::first())
+            Self::spawn_other_process_listener(on_event, event_buffer, event_receiver, process.clone(), ViewProcessGen::first())
         };
 
         let mut c = Controller {
@@ -125,6 +178,7 @@ impl Controller {
             request_sender,
             response_receiver,
             event_listener: Some(ev),
+            event_buffer,
             headless,
             generation: ViewProcessGen::INVALID,
             is_respawn: false,
@@ -139,25 +193,28 @@ impl Controller {
         c
     }
     fn spawn_same_process_listener(
-        mut on_event: Box<dyn FnMut(Event) + Send>,
+        on_event: Box<dyn FnMut(Event) + Send>,
+        event_buffer: EventBufferPolicy,
         mut event_receiver: EventReceiver,
         generation: ViewProcessGen,
     ) -> std::thread::JoinHandle<Box<dyn FnMut(Event) + Send>> {
         thread::Builder::new()
             .name("same_process_listener".into())
             .spawn(move || {
+                let mut on_event = EventSink::new(on_event, event_buffer);
                 while let Ok(ev) = event_receiver.recv() {
-                    on_event(ev);
+                    on_event.send(ev);
                 }
-                on_event(Event::Disconnected(generation));
+                on_event.send(Event::Disconnected(generation));
 
                 // return to reuse in respawn.
-                on_event
+                on_event.into_inner()
             })
             .expect("failed to spawn thread")
     }
     fn spawn_other_process_listener(
-        mut on_event: Box<dyn FnMut(Event) + Send>,
+        on_event: Box<dyn FnMut(Event) + Send>,
+        event_buffer: EventBufferPolicy,
         mut event_receiver: EventReceiver,
         process: Arc<Mutex<Option<(std::process::Child, bool)>>>,
         generation: ViewProcessGen,
@@ -168,6 +225,7 @@ impl Controller {
         thread::Builder::new()
             .name("other_process_listener".into())
             .spawn(move || {
+                let mut on_event = EventSink::new(on_event, event_buffer);
                 const PROCESS_CHECK_DUR: Duration = Duration::from_secs(1);
                 let timeout = view_timeout();
                 let mut check_count = 0u64;
@@ -175,7 +233,7 @@ impl Controller {
                     match event_receiver.recv_timeout(PROCESS_CHECK_DUR) {
                         Ok(ev) => {
                             check_count = 0;
-                            on_event(ev)
+                            on_event.send(ev)
                         }
                         Err(ChannelError::Timeout) => {
                             if let Some(p) = &mut *process.lock() {
@@ -188,6 +246,7 @@ impl Controller {
                                             check_count += 1;
                                             if check_count == timeout {
                                                 tracing::error!("view-process not responding for {timeout}s, will respawn");
+                                                on_event.send(Event::ViewProcessUnresponsive(generation));
                                                 let _ = p.0.kill();
                                                 p.1 = true;
                                                 break;
@@ -209,10 +268,10 @@ impl Controller {
                         Err(_) => break,
                     }
                 }
-                on_event(Event::Disconnected(generation));
+                on_event.send(Event::Disconnected(generation));
 
                 // return to reuse in respawn.
-                on_event
+                on_event.into_inner()
             })
             .expect("failed to spawn thread")
     }
@@ -237,11 +296,25 @@ impl Controller {
         self.headless
     }
 
+    /// Event buffer policy.
+    pub fn event_buffer(&self) -> EventBufferPolicy {
+        self.event_buffer
+    }
+
     /// If is running both view and app in the same process.
     pub fn same_process(&self) -> bool {
         self.same_process
     }
 
+    /// Duration the view-process can stay unresponsive before it is considered hung.
+    ///
+    /// This is the `"ZNG_VIEW_TIMEOUT"` value, see [`start`] for more details.
+    ///
+    /// [`start`]: Self::start
+    pub fn unresponsive_timeout(&self) -> Duration {
+        Duration::from_secs(view_timeout())
+    }
+
     fn try_talk(&mut self, req: Request) -> Result<Response, ChannelError> {
         self.request_sender.send(req)?;
         self.response_receiver.recv()
@@ -590,7 +663,7 @@ impl Controller {
         let next_id = self.generation.next();
         self.generation = next_id;
 
-        let ev = Self::spawn_other_process_listener(on_event, event_listener, self.process.clone(), self.generation);
+        let ev = Self::spawn_other_process_listener(on_event, self.event_buffer, event_listener, self.process.clone(), self.generation);
         self.event_listener = Some(ev);
 
         if let Err(ChannelError::Disconnected { .. }) = self.try_init() {
@@ -616,6 +689,160 @@ impl Drop for Controller {
     }
 }
 
+/// Calls `on_event` directly or using a bounded buffer and dispatcher thread.
+enum EventSink {
+    Direct(Box<dyn FnMut(Event) + Send>),
+    Buffered {
+        buffer: Arc<EventBuffer>,
+        dispatcher: EventListenerJoin,
+    },
+}
+impl EventSink {
+    fn new(on_event: Box<dyn FnMut(Event) + Send>, policy: EventBufferPolicy) -> Self {
+        match policy {
+            EventBufferPolicy::Unbounded => Self::Direct(on_event),
+            EventBufferPolicy::Bounded { capacity } => {
+                let buffer = Arc::new(EventBuffer {
+                    state: Mutex::new(EventBufferState {
+                        queue: VecDeque::with_capacity(capacity.clamp(1, 1024)),
+                        closed: false,
+                    }),
+                    changed: Condvar::new(),
+                    capacity: capacity.max(1),
+                });
+                let b = buffer.clone();
+                let dispatcher = thread::Builder::new()
+                    .name("view_event_dispatcher".into())
+                    .spawn(move || {
+                        let mut on_event = on_event;
+                        while let Some(ev) = b.pop() {
+                            on_event(ev);
+                        }
+                        on_event
+                    })
+                    .expect("failed to spawn thread");
+                Self::Buffered { buffer, dispatcher }
+            }
+        }
+    }
+
+    fn send(&mut self, ev: Event) {
+        match self {
+            EventSink::Direct(on_event) => on_event(ev),
+            EventSink::Buffered { buffer, .. } => buffer.push(ev),
+        }
+    }
+
+    /// Dispatch all buffered events and returns the closure.
+    fn into_inner(self) -> Box<dyn FnMut(Event) + Send> {
+        match self {
+            EventSink::Direct(on_event) => on_event,
+            EventSink::Buffered { buffer, dispatcher } => {
+                buffer.state.lock().closed = true;
+                buffer.changed.notify_all();
+                match dispatcher.join() {
+                    Ok(on_event) => on_event,
+                    Err(p) => panic::resume_unwind(p),
+                }
+            }
+        }
+    }
+}
+
+struct EventBufferState {
+    queue: VecDeque<Event>,
+    closed: bool,
+}
+struct EventBuffer {
+    state: Mutex<EventBufferState>,
+    changed: Condvar,
+    capacity: usize,
+}
+impl EventBuffer {
+    fn push(&self, mut ev: Event) {
+        let mut s = self.state.lock();
+        loop {
+            if s.queue.len() < self.capacity {
+                s.queue.push_back(ev);
+                self.changed.notify_all();
+                return;
+            }
+
+            if ev.is_coalescable()
+                && let Some(last) = s.queue.back_mut()
+            {
+                match last.coalesce(ev) {
+                    Ok(()) => return,
+                    Err(e) => ev = e,
+                }
+            }
+
+            if let Some(i) = s.queue.iter().position(Event::is_coalescable) {
+                // drop-oldest
+                let dropped = s.queue.remove(i).unwrap();
+                if let Some(dropped) = merge_into_next(&mut s.queue, i, dropped, &mut ev) {
+                    tracing::trace!("event buffer full, dropped {dropped:?}");
+                }
+                continue;
+            }
+
+            // backpressure, blocks the IPC listener until the dispatcher catches up
+            self.changed.wait(&mut s);
+        }
+    }
+
+    fn pop(&self) -> Option<Event> {
+        let mut s = self.state.lock();
+        loop {
+            if let Some(ev) = s.queue.pop_front() {
+                self.changed.notify_all();
+                return Some(ev);
+            }
+            if s.closed {
+                return None;
+            }
+            self.changed.wait(&mut s);
+        }
+    }
+}
+
+/// Coalesce a dropped raw device motion event into the next event of the same device from `i` or into the `incoming` event,
+/// so that the motion deltas are not lost. Returns the event back if it was not merged.
+fn merge_into_next(queue: &mut VecDeque<Event>, i: usize, dropped: Event, incoming: &mut Event) -> Option<Event> {
+    use crate::raw_input::InputDeviceEvent::*;
+
+    if !matches!(
+        &dropped,
+        Event::InputDeviceEvent {
+            event: PointerMotion { .. } | ScrollMotion { .. },
+            ..
+        }
+    ) {
+        return Some(dropped);
+    }
+
+    let mut dropped = dropped;
+    for j in i..queue.len() {
+        if !queue[j].is_coalescable() {
+            continue;
+        }
+        let next = queue.remove(j).unwrap();
+        match dropped.coalesce(next) {
+            Ok(()) => {
+                queue.insert(j, dropped);
+                return None;
+            }
+            Err(next) => queue.insert(j, next),
+        }
+    }
+
+    let next = std::mem::replace(incoming, dropped);
+    match incoming.coalesce(next) {
+        Ok(()) => None,
+        Err(next) => Some(std::mem::replace(incoming, next)),
+    }
+}
+
 const VIEW_TIMEOUT: &str = "ZNG_VIEW_TIMEOUT";
 const VIEW_TIMEOUT_DEFAULT: u64 = 20;
 /// Timeout in seconds.
@@ -634,3 +861,77 @@ pub(crate) fn view_timeout() -> u64 {
         _ => VIEW_TIMEOUT_DEFAULT,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raw_input::{InputDeviceEvent, InputDeviceId};
+
+    fn motion(device: u64, x: f64) -> Event {
+        Event::InputDeviceEvent {
+            device: InputDeviceId::from_raw(device),
+            event: InputDeviceEvent::PointerMotion {
+                delta: euclid::vec2(x, 0.0),
+            },
+        }
+    }
+
+    fn motion_x(ev: &Event) -> f64 {
+        match ev {
+            Event::InputDeviceEvent {
+                event: InputDeviceEvent::PointerMotion { delta },
+                ..
+            } => delta.x,
+            e => panic!("expected pointer motion, was {e:?}"),
+        }
+    }
+
+    #[test]
+    fn merge_into_next_sums_deltas() {
+        let mut queue = VecDeque::from(vec![Event::Pong(0), motion(1, 2.0), Event::Pong(1)]);
+        let dropped = motion(1, 3.0);
+
+        let mut incoming = Event::Pong(2);
+        assert!(merge_into_next(&mut queue, 0, dropped, &mut incoming).is_none());
+        assert_eq!(queue.len(), 3);
+        assert_eq!(motion_x(&queue[1]), 5.0);
+    }
+
+    #[test]
+    fn merge_into_next_other_device() {
+        let mut queue = VecDeque::from(vec![motion(2, 2.0)]);
+        let dropped = motion(1, 3.0);
+
+        let mut incoming = motion(3, 1.0);
+        assert!(merge_into_next(&mut queue, 0, dropped, &mut incoming).is_some());
+        assert_eq!(motion_x(&queue[0]), 2.0);
+        assert_eq!(motion_x(&incoming), 1.0);
+    }
+
+    #[test]
+    fn event_buffer_full_keeps_motion_delta() {
+        let buffer = EventBuffer {
+            state: Mutex::new(EventBufferState {
+                queue: VecDeque::new(),
+                closed: false,
+            }),
+            changed: Condvar::new(),
+            capacity: 2,
+        };
+        buffer.push(motion(1, 1.0));
+        buffer.push(Event::Pong(0));
+        // full and last is not coalescable, oldest motion merges into the incoming motion
+        buffer.push(motion(1, 2.0));
+
+        let mut total = 0.0;
+        while let Some(ev) = {
+            let mut s = buffer.state.lock();
+            s.queue.pop_front()
+        } {
+            if let Event::InputDeviceEvent { .. } = &ev {
+                total += motion_x(&ev);
+            }
+        }
+        assert_eq!(total, 3.0);
+    }
+}
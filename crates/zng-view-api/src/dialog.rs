@@ -4,6 +4,8 @@ use std::{mem, path::PathBuf, time::Duration};
 
 use zng_txt::Txt;
 
+use crate::image::ImageId;
+
 crate::declare_id! {
     /// Identifies an ongoing async native dialog with the user.
     pub struct DialogId(_);
@@ -367,6 +369,11 @@ pub struct Notification {
     pub title: Txt,
     /// The full notification content.
     pub message: Txt,
+    /// Notification image.
+    ///
+    /// Only used if the image is valid and loaded and the view-process has [`DialogCapability::NOTIFICATION_ICON`].
+    /// The image must stay loaded while the notification is showing.
+    pub icon: ImageId,
     /// Response buttons.
     pub actions: Vec<NotificationAction>,
     /// Maximum time to keep the notification on the list.
@@ -378,6 +385,7 @@ impl Notification {
         Self {
             title: title.into(),
             message: body.into(),
+            icon: ImageId::INVALID,
             actions: vec![],
             timeout: None,
         }
@@ -390,6 +398,7 @@ impl Notification {
         Self {
             title: Txt::from_static(""),
             message: Txt::from_static(""),
+            icon: ImageId::INVALID,
             actions: vec![],
             timeout: Some(Duration::ZERO),
         }
@@ -465,6 +474,8 @@ bitflags::bitflags! {
         const CLOSE_NOTIFICATION = (1 << 8) | Self::NOTIFICATION.bits();
         /// View-process can update notification content.
         const UPDATE_NOTIFICATION = (1 << 9) | Self::NOTIFICATION.bits();
+        /// View-process can show the notification icon image.
+        const NOTIFICATION_ICON = (1 << 10) | Self::NOTIFICATION.bits();
     }
 }
 
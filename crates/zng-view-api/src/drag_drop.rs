@@ -37,6 +37,32 @@ pub enum DragDropData {
     },
 }
 
+impl DragDropData {
+    /// Gets the data type.
+    pub fn data_type(&self) -> DragDropType {
+        match self {
+            DragDropData::Text(_) => DragDropType::Text,
+            DragDropData::Image(_) => DragDropType::Image,
+            DragDropData::Paths(_) => DragDropType::Paths,
+            DragDropData::Extension { data_type, .. } => DragDropType::Extension(data_type.clone()),
+        }
+    }
+}
+
+/// Drag&drop data type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
+pub enum DragDropType {
+    /// A [`DragDropData::Text`].
+    Text,
+    /// A [`DragDropData::Image`].
+    Image,
+    /// A [`DragDropData::Paths`].
+    Paths,
+    /// A [`DragDropData::Extension`].
+    Extension(Txt),
+}
+
 bitflags! {
     /// Drag&drop drop effect on the data source.
     #[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use zng_task::channel::IpcBytes;
 use zng_txt::Txt;
 
-use zng_unit::{Px, PxDensity2d, PxSize};
+use zng_unit::{Px, PxDensity2d, PxRect, PxSize};
 
 use crate::api_extension::{ApiExtensionId, ApiExtensionPayload};
 
@@ -122,6 +122,23 @@ pub struct ImageRequest<D> {
     /// This value is now used by the view-process, it is just returned with the metadata. This is useful when
     /// an already decoded image is requested after a respawn to maintain the original container structure.
     pub parent: Option<ImageEntryMetadata>,
+
+    /// Only decode this rectangle of the image pixels.
+    ///
+    /// The region is in the full image pixel space, it is clamped to the image size. Only the first page is decoded
+    /// and the `downscale` and `max_decoded_len` apply to the region. The decoded image [`ImageMetadata::region`] is set.
+    ///
+    /// Formats that support random access (tiled or stripped TIFF) only read and decompress the chunks that intersect
+    /// the region, other formats decode the full image and crop it.
+    pub region: Option<PxRect>,
+
+    /// Upload the decoded image to the GPU as a compressed texture.
+    ///
+    /// If the renderer supports S3TC the image is encoded to BC1 (opaque) or BC3 (with alpha) on first use, reducing
+    /// texture memory by 8x or 4x, otherwise the image is uploaded as an uncompressed BGRA8 texture. The compression is lossy,
+    /// it is intended for large collections of small images, like thumbnails. Masks and images that do not fit in a single
+    /// texture are never compressed.
+    pub compressed_texture: bool,
 }
 impl<D> ImageRequest<D> {
     /// New request.
@@ -140,6 +157,8 @@ impl<D> ImageRequest<D> {
             mask,
             entries: ImageEntriesMode::PRIMARY,
             parent: None,
+            region: None,
+            compressed_texture: false,
         }
     }
 }
@@ -495,6 +514,8 @@ pub struct ImageMetadata {
     ///
     /// When this is `None` the is the first [`ImageEntryKind::Page`] in the container, usually the only page.
     pub parent: Option<ImageEntryMetadata>,
+    /// Full image size and the decoded region if this image is an [`ImageRequest::region`].
+    pub region: Option<(PxSize, PxRect)>,
 
     /// Custom metadata.
     pub extensions: Vec<(ApiExtensionId, ApiExtensionPayload)>,
@@ -509,6 +530,7 @@ impl ImageMetadata {
             is_mask,
             original_color_type,
             parent: None,
+            region: None,
             extensions: vec![],
             format_name: Txt::default(),
         }
@@ -523,6 +545,7 @@ impl Default for ImageMetadata {
             is_mask: Default::default(),
             original_color_type: ColorType::BGRA8,
             parent: Default::default(),
+            region: None,
             extensions: vec![],
             format_name: Txt::default(),
         }
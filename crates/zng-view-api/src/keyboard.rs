@@ -2048,6 +2048,127 @@ pub enum KeyState {
     Released,
 }
 
+crate::declare_id! {
+    /// Identifies a registered [`GlobalHotkey`].
+    ///
+    /// The View Process defines the ID.
+    pub struct GlobalHotkeyId(_);
+}
+
+bitflags::bitflags! {
+    /// Modifier keys of a [`GlobalHotkey`].
+    ///
+    /// Global hotkeys do not distinguish between the left and right modifier keys.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Serialize, serde::Deserialize)]
+    #[serde(transparent)]
+    pub struct HotkeyModifiers: u8 {
+        /// Any "shift" key.
+        const SHIFT = 0b0001;
+        /// Any "control" key.
+        const CTRL = 0b0010;
+        /// Any "alt" key.
+        const ALT = 0b0100;
+        /// Any "logo" key.
+        ///
+        /// This is the "windows" key on PC and "command" key on Mac.
+        const SUPER = 0b1000;
+    }
+}
+
+/// Represents a system wide keyboard shortcut.
+///
+/// Global hotkeys are received by the app even when no app window is focused.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct GlobalHotkey {
+    /// Modifier keys that must be pressed with the key.
+    pub modifiers: HotkeyModifiers,
+    /// The physical key.
+    ///
+    /// Must be an identified key that is not a modifier.
+    pub key_code: KeyCode,
+}
+impl GlobalHotkey {
+    /// New.
+    pub fn new(modifiers: HotkeyModifiers, key_code: KeyCode) -> Self {
+        Self { modifiers, key_code }
+    }
+}
+
+/// Error for global hotkey register.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
+pub enum GlobalHotkeyError {
+    /// View-process implementer does not support global hotkeys.
+    NotSupported,
+    /// The hotkey is already registered by this app or by another app.
+    AlreadyRegistered,
+    /// Cannot register the hotkey.
+    CannotRegister(Txt),
+}
+impl fmt::Display for GlobalHotkeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GlobalHotkeyError::NotSupported => write!(f, "not supported"),
+            GlobalHotkeyError::AlreadyRegistered => write!(f, "already registered"),
+            GlobalHotkeyError::CannotRegister(txt) => write!(f, "cannot register, {txt}"),
+        }
+    }
+}
+impl std::error::Error for GlobalHotkeyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+bitflags::bitflags! {
+    /// Modifier keys currently pressed, see [`Api::modifiers_state`].
+    ///
+    /// Each flag represents a modifier and is set if this modifier is active.
+    ///
+    /// [`Api::modifiers_state`]: crate::Api::modifiers_state
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Serialize, serde::Deserialize)]
+    #[serde(transparent)]
+    pub struct ModifiersState: u8 {
+        /// The left "shift" key.
+        const L_SHIFT = 0b0000_0001;
+        /// The right "shift" key.
+        const R_SHIFT = 0b0000_0010;
+        /// Any "shift" key.
+        const SHIFT = 0b0000_0011;
+
+        /// The left "control" key.
+        const L_CTRL = 0b0000_0100;
+        /// The right "control" key.
+        const R_CTRL = 0b0000_1000;
+        /// Any "control" key.
+        const CTRL = 0b0000_1100;
+
+        /// The left "alt" key.
+        const L_ALT = 0b0001_0000;
+        /// The right "alt" key.
+        const R_ALT = 0b0010_0000;
+        /// Any "alt" key.
+        const ALT = 0b0011_0000;
+
+        /// The left "logo" key.
+        const L_SUPER = 0b0100_0000;
+        /// The right "logo" key.
+        const R_SUPER = 0b1000_0000;
+        /// Any "logo" key.
+        const SUPER = 0b1100_0000;
+    }
+}
+
+bitflags::bitflags! {
+    /// Keyboard operations the view-process implements.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+    pub struct KeyboardCapability: u32 {
+        /// View-process can register system wide hotkeys.
+        const GLOBAL_HOTKEY = 1 << 0;
+    }
+}
+
 // monomorphize
 fn key_clone(key: &Key) -> Key {
     match key {
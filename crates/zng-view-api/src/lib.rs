@@ -66,7 +66,7 @@ use font::{FontFaceId, FontId, FontOptions, FontVariationName};
 use image::{ImageId, ImageMaskMode, ImageRequest, ImageTextureId};
 use window::WindowId;
 use zng_task::channel::{IpcBytes, IpcReadHandle, IpcReceiver};
-use zng_unit::{DipPoint, DipRect, DipSize, Factor, Px, PxRect};
+use zng_unit::{DipPoint, DipRect, DipSize, Factor, Px, PxRect, PxSize, PxTransform};
 
 /// Packaged API request.
 #[derive(Debug)]
@@ -284,6 +284,12 @@ declare_api! {
     /// key presses when the app has no focused window.
     pub fn set_device_events_filter(&mut self, filter: DeviceEventsFilter);
 
+    /// Gets a snapshot of the input devices currently known by the view-process.
+    ///
+    /// This is the same list last sent by [`Event::InputDevicesChanged`], it can be used to populate the devices
+    /// list on startup without waiting for device events. Note that some systems only report devices after they are used.
+    pub fn connected_devices(&mut self) -> Vec<(raw_input::InputDeviceId, raw_input::InputDeviceInfo)>;
+
     /// Open a window.
     ///
     /// Sends an [`Event::WindowOpened`] once the window, context and renderer have finished initializing or a
@@ -299,6 +305,11 @@ declare_api! {
     /// [`Event::WindowOrHeadlessOpenError`] if it failed.
     pub fn open_headless(&mut self, request: window::HeadlessRequest);
 
+    /// Create and discard a throwaway renderer to prime the graphics driver caches.
+    ///
+    /// This can be called while a splash screen is visible, so that the first window opens faster.
+    pub fn prewarm_renderer(&mut self, render_mode: window::RenderMode);
+
     /// Close the window or headless surface.
     ///
     /// All documents associated with the window or surface are also closed.
@@ -328,12 +339,25 @@ declare_api! {
     /// Set the window state, position, size.
     pub fn set_state(&mut self, id: WindowId, state: window::WindowStateAll);
 
+    /// Restore the window to the state, position and size it was opened with.
+    ///
+    /// A [`Event::WindowChanged`] is emitted if the state changes, the same as [`set_state`].
+    ///
+    /// [`set_state`]: Self::set_state
+    pub fn reset_window_state(&mut self, id: WindowId);
+
     /// Set the headless surface or document area size (viewport size).
     pub fn set_headless_size(&mut self, id: WindowId, size: DipSize, scale_factor: Factor);
 
     /// Set the window icon, the icon image must be loaded.
     pub fn set_icon(&mut self, id: WindowId, icon: Option<ImageId>);
 
+    /// Set the window icon from multiple images of the same icon at different sizes, the images must be loaded.
+    ///
+    /// The system selects the best size for each place the icon is shown, like the title bar, taskbar and task switcher.
+    /// An empty set removes the custom icon.
+    pub fn set_icon_set(&mut self, id: WindowId, icons: Vec<ImageId>);
+
     /// Set the window cursor icon and visibility.
     pub fn set_cursor(&mut self, id: WindowId, cursor: Option<window::CursorIcon>);
 
@@ -349,6 +373,12 @@ declare_api! {
     /// Set enabled window chrome buttons.
     pub fn set_enabled_buttons(&mut self, id: WindowId, buttons: window::WindowButton);
 
+    /// Set a render transform applied to the window content.
+    ///
+    /// This is intended for short visual effects like shake or wobble animations. The transform is applied to the latest
+    /// frame and all subsequent frames, the window position and size are not affected. Setting to identity restores the normal render.
+    pub fn set_window_transform(&mut self, id: WindowId, transform: PxTransform);
+
     /// Brings the window to the front and sets input focus.
     ///
     /// Sends an [`Event::FocusChanged`] if the window is focused, the request can be ignored by the window manager, or if the
@@ -357,6 +387,15 @@ declare_api! {
     /// This request can steal focus from other apps disrupting the user, be careful with it.
     pub fn focus(&mut self, id: WindowId) -> FocusResult;
 
+    /// Query the modifier keys currently pressed.
+    ///
+    /// The state is read from the operating system when possible, this is the same state tracked by the
+    /// keyboard events, but it can be used to resync the app state after the window regains focus.
+    ///
+    /// The `zng-view` crate queries the system in Windows, macOS and X11, in other platforms it returns the
+    /// last state received from keyboard events, that can be outdated after the window regains focus.
+    pub fn modifiers_state(&mut self, id: WindowId) -> keyboard::ModifiersState;
+
     /// Moves the window with the left mouse button until the button is released.
     ///
     /// There's no guarantee that this will work unless the left mouse button was pressed immediately before this function is called.
@@ -367,6 +406,37 @@ declare_api! {
     /// There's no guarantee that this will work unless the left mouse button was pressed immediately before this function is called.
     pub fn drag_resize(&mut self, id: WindowId, direction: window::ResizeDirection);
 
+    /// Toggle the window between the maximized and normal state, like a double-click on the system title bar.
+    pub fn maximize_toggle(&mut self, id: WindowId);
+
+    /// Set the window to the `Normal` state and position it to fill a region of the current monitor work area.
+    ///
+    /// Sends an [`Event::WindowChanged`] with the new state and [`restore_rect`].
+    ///
+    /// [`restore_rect`]: window::WindowStateAll::restore_rect
+    pub fn snap_window(&mut self, id: WindowId, zone: window::SnapZone);
+
+    /// Move the window to the center of the `target` area.
+    ///
+    /// Monitor targets center the window in the monitor work area. If the window is not in the `Normal` state
+    /// only the [`restore_rect`] is centered. Sends an [`Event::WindowChanged`] with the new position.
+    ///
+    /// [`restore_rect`]: window::WindowStateAll::restore_rect
+    pub fn center_window(&mut self, id: WindowId, target: window::CenterTarget);
+
+    /// Set a region of the window that starts a [`drag_move`] when pressed with the left mouse button.
+    ///
+    /// The region is in the window client area space, the mouse input event is still send to the app-process.
+    /// This is used to implement custom title bars, set to `None` to remove the region.
+    ///
+    /// The region also replicates the other system title bar interactions, a double-click calls [`maximize_toggle`] and
+    /// a right-click calls [`open_title_bar_context_menu`].
+    ///
+    /// [`drag_move`]: Self::drag_move
+    /// [`maximize_toggle`]: Self::maximize_toggle
+    /// [`open_title_bar_context_menu`]: Self::open_title_bar_context_menu
+    pub fn set_drag_region(&mut self, id: WindowId, region: Option<DipRect>);
+
     /// Open the system title bar context menu.
     pub fn open_title_bar_context_menu(&mut self, id: WindowId, position: DipPoint);
 
@@ -517,6 +587,15 @@ declare_api! {
     /// Returns [`ImageId::INVALID`] if the window is not found.
     pub fn frame_image_rect(&mut self, id: WindowId, rect: PxRect, mask: Option<ImageMaskMode>) -> ImageId;
 
+    /// Create a new image from the current rendered frame, downscaled to fit `max_size`.
+    ///
+    /// The aspect ratio is preserved and the image is never upscaled, use this to generate window switcher previews.
+    ///
+    /// Returns immediately, an [`Event::ImageDecoded`] will be send when the image is ready.
+    ///
+    /// Returns [`ImageId::INVALID`] if the window is not found.
+    pub fn window_thumbnail(&mut self, id: WindowId, max_size: PxSize) -> ImageId;
+
     /// Set the video mode used when the window is in exclusive fullscreen.
     pub fn set_video_mode(&mut self, id: WindowId, mode: window::VideoMode);
 
@@ -577,11 +656,37 @@ declare_api! {
     /// Notify the drag source of what effect was applied for a received drag&drop.
     pub fn drag_dropped(&mut self, id: WindowId, drop_id: DragDropId, applied: DragDropEffect);
 
+    /// Set the effect that will be applied if the current drag&drop hovering the window is dropped.
+    ///
+    /// An empty `effect` indicates the window will not accept the drop, in this case the view-process
+    /// notifies [`Event::DragCancelled`] instead of [`Event::DragDropped`]. The view-process also
+    /// uses this value to update the system drag cursor if [`WindowCapability::SET_DROP_EFFECT`] is supported.
+    ///
+    /// The effect is reset to accept all on every [`Event::DragHovered`].
+    ///
+    /// [`WindowCapability::SET_DROP_EFFECT`]: crate::window::WindowCapability::SET_DROP_EFFECT
+    pub fn set_drop_effect(&mut self, id: WindowId, effect: DragDropEffect);
+
     /// Enable or disable IME by setting a cursor area.
     ///
     /// In mobile platforms also shows the software keyboard for `Some(_)` and hides it for `None`.
     pub fn set_ime_area(&mut self, id: WindowId, area: Option<DipRect>);
 
+    /// Register a system wide keyboard shortcut.
+    ///
+    /// Sends an [`Event::GlobalHotkey`] every time the hotkey is pressed or released, even when no app window is focused.
+    ///
+    /// Only available if [`KeyboardCapability::GLOBAL_HOTKEY`] is reported on init.
+    ///
+    /// [`KeyboardCapability::GLOBAL_HOTKEY`]: keyboard::KeyboardCapability::GLOBAL_HOTKEY
+    pub fn register_global_hotkey(
+        &mut self,
+        hotkey: keyboard::GlobalHotkey,
+    ) -> Result<keyboard::GlobalHotkeyId, keyboard::GlobalHotkeyError>;
+
+    /// Unregister a system wide keyboard shortcut.
+    pub fn unregister_global_hotkey(&mut self, id: keyboard::GlobalHotkeyId);
+
     /// Attempt to set a system wide shutdown warning associated with the window.
     ///
     /// Operating systems that support this show the `reason` in a warning for the user, it must be a short text
@@ -594,6 +699,36 @@ declare_api! {
     /// Set to an empty text to remove the warning.
     pub fn set_system_shutdown_warn(&mut self, id: WindowId, reason: Txt);
 
+    /// Set the number of buffers in the window swap chain, `2` for double buffering or `3` for triple buffering.
+    ///
+    /// Returns the actual depth, it can be different from the requested depth if it is not available.
+    pub fn set_swap_chain_depth(&mut self, id: WindowId, depth: u8) -> u8;
+
+    /// Set or unset the window shape.
+    ///
+    /// Areas outside the shape are not visible and do not receive input, this is useful for custom chrome windows
+    /// with transparent rounded corners. Only implemented if [`WindowCapability::SET_SHAPE`] is reported.
+    ///
+    /// [`WindowCapability::SET_SHAPE`]: crate::window::WindowCapability::SET_SHAPE
+    pub fn set_window_shape(&mut self, id: WindowId, shape: Option<window::WindowShape>);
+
+    /// Set or unset the owner window.
+    ///
+    /// Owned windows are minimized and restored together with the owner and always stay on top of it.
+    /// Only implemented if [`WindowCapability::SET_OWNER`] is reported.
+    ///
+    /// [`WindowCapability::SET_OWNER`]: crate::window::WindowCapability::SET_OWNER
+    pub fn set_window_owner(&mut self, id: WindowId, owner: Option<WindowId>);
+
+    /// Set or unset the window input region.
+    ///
+    /// Pointer input outside the region rectangles passes through to the windows below, this is useful for
+    /// click-through overlay windows. Set to `None` to receive input in the entire window area.
+    /// Only implemented if [`WindowCapability::SET_INPUT_REGION`] is reported.
+    ///
+    /// [`WindowCapability::SET_INPUT_REGION`]: crate::window::WindowCapability::SET_INPUT_REGION
+    pub fn set_input_region(&mut self, id: WindowId, region: Option<Vec<DipRect>>);
+
     /// Set the custom menu items for the system application menu.
     ///
     /// The application menu is shown outside the app windows, usually at the top of the main screen in macOS and Gnome desktops.
@@ -603,7 +738,8 @@ declare_api! {
 
     /// Set the tray icon indicator for the app.
     ///
-    /// This is a small status indicator icon displayed near the notifications area.
+    /// This is a small status indicator icon displayed near the notifications area. Set to [`menu::TrayIcon::none`]
+    /// to remove the icon. Only implemented if [`menu::MenuCapability::TRAY_ICON`] is reported.
     pub fn set_tray_icon(&mut self, indicator: menu::TrayIcon);
 
     /// Licenses that may be required to be displayed in the app about screen.
@@ -77,9 +77,13 @@ pub struct TrayIcon {
     ///
     /// The tray icon will only be inserted when this image is valid and loaded.
     pub icon: ImageId,
+    /// Tooltip text shown when the pointer hovers the icon.
+    ///
+    /// If empty no tooltip is set.
+    pub tooltip: Txt,
     /// Optional context menu.
     ///
-    /// If not empty a context menu shows on context clock.
+    /// If not empty a context menu shows on context click.
     pub context_menu: Vec<MenuItem>,
     /// A command ID for a primary click on the icon.
     ///
@@ -93,6 +97,7 @@ impl TrayIcon {
     pub fn new(icon: ImageId, context_menu: Vec<MenuItem>) -> Self {
         Self {
             icon,
+            tooltip: Txt::from_static(""),
             context_menu,
             primary_command_id: Txt::from_static(""),
         }
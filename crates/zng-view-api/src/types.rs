@@ -2,13 +2,13 @@
 
 use crate::{
     access::{AccessCmd, AccessNodeId},
-    api_extension::{ApiExtensionId, ApiExtensionPayload, ApiExtensions},
+    api_extension::{ApiExtensionId, ApiExtensionPayload, ApiExtensionRequestId, ApiExtensions},
     audio::{AudioDecoded, AudioDeviceId, AudioDeviceInfo, AudioId, AudioMetadata, AudioOutputId, AudioOutputOpenData, AudioPlayId},
     config::{AnimationsConfig, ColorsConfig, FontAntiAliasing, KeyRepeatConfig, LocaleConfig, MultiClickConfig, TouchConfig},
     dialog::{DialogId, FileDialogResponse, MsgDialogResponse, NotificationResponse},
     drag_drop::{DragDropData, DragDropEffect},
     image::{ImageDecoded, ImageEncodeId, ImageId, ImageMetadata},
-    keyboard::{Key, KeyCode, KeyLocation, KeyState},
+    keyboard::{GlobalHotkeyId, Key, KeyCode, KeyLocation, KeyState},
     mouse::{ButtonState, MouseButton, MouseScrollDelta},
     raw_input::{InputDeviceCapability, InputDeviceEvent, InputDeviceId, InputDeviceInfo},
     touch::{TouchPhase, TouchUpdate},
@@ -120,6 +120,9 @@ pub struct ViewProcessInfo {
     /// Input device events implemented by the view-process.
     pub input_device: InputDeviceCapability,
 
+    /// Keyboard operations implemented by the view-process.
+    pub keyboard: crate::keyboard::KeyboardCapability,
+
     /// Window operations implemented by the view-process.
     pub window: crate::window::WindowCapability,
 
@@ -150,6 +153,7 @@ impl ViewProcessInfo {
             generation,
             is_respawn,
             input_device: InputDeviceCapability::empty(),
+            keyboard: crate::keyboard::KeyboardCapability::empty(),
             window: crate::window::WindowCapability::empty(),
             dialog: crate::dialog::DialogCapability::empty(),
             menu: crate::menu::MenuCapability::empty(),
@@ -194,6 +198,15 @@ pub enum Event {
     /// [`Controller::handle_disconnect`]: crate::Controller::handle_disconnect
     Disconnected(ViewProcessGen),
 
+    /// The view-process did not respond for longer than the `ZNG_VIEW_TIMEOUT` and is considered hung.
+    ///
+    /// This event is generated in the app-process by the [`Controller`], in multi-process mode the view-process is
+    /// killed after this event, so a [`Disconnected`] event follows.
+    ///
+    /// [`Controller`]: crate::Controller
+    /// [`Disconnected`]: Event::Disconnected
+    ViewProcessUnresponsive(ViewProcessGen),
+
     /// Window, context and renderer have finished initializing and is ready to receive commands.
     WindowOpened(WindowId, WindowOpenData),
 
@@ -315,6 +328,15 @@ pub enum Event {
         /// will consist of two characters: the dead-key-character followed by the character resulting from this key press.
         text: Txt,
     },
+    /// A global hotkey was pressed or released.
+    ///
+    /// This event is send even if no app window is focused.
+    GlobalHotkey {
+        /// The registered hotkey.
+        id: GlobalHotkeyId,
+        /// If the hotkey was pressed or released.
+        state: KeyState,
+    },
     /// IME composition event.
     Ime {
         /// Window that received the IME event.
@@ -569,12 +591,39 @@ pub enum Event {
     /// Represents a custom event send by the extension.
     ExtensionEvent(ApiExtensionId, ApiExtensionPayload),
 
+    /// Represents the response of an extension command that returned [`ApiExtensionPayload::pending`].
+    ExtensionResponse {
+        /// Extension that was requested.
+        extension_id: ApiExtensionId,
+        /// Request ID from the pending payload.
+        request_id: ApiExtensionRequestId,
+        /// Command response.
+        response: ApiExtensionPayload,
+    },
+
     /// Signal the view-process is alive.
     ///
     /// The associated value must be the count requested by [`Api::ping`](crate::Api::ping).
     Pong(u16),
 }
 impl Event {
+    /// If the event is a high frequency update that can be dropped when a newer event of the same kind follows.
+    ///
+    /// This is true for mouse, drag and raw device motion events.
+    pub fn is_coalescable(&self) -> bool {
+        matches!(
+            self,
+            Event::MouseMoved { .. }
+                | Event::DragMoved { .. }
+                | Event::InputDeviceEvent {
+                    event: crate::raw_input::InputDeviceEvent::PointerMotion { .. }
+                        | crate::raw_input::InputDeviceEvent::ScrollMotion { .. }
+                        | crate::raw_input::InputDeviceEvent::AxisMotion { .. },
+                    ..
+                }
+        )
+    }
+
     /// Change `self` to incorporate `other` or returns `other` if both events cannot be coalesced.
     #[expect(clippy::result_large_err)]
     pub fn coalesce(&mut self, other: Event) -> Result<(), Event> {
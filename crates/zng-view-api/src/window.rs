@@ -11,7 +11,8 @@ use crate::{
     image::{ImageDecoded, ImageId, ImageMaskMode},
 };
 use zng_unit::{
-    Dip, DipPoint, DipRect, DipSideOffsets, DipSize, DipToPx as _, Factor, Frequency, Px, PxPoint, PxSize, PxToDip, PxTransform, Rgba,
+    Dip, DipPoint, DipRect, DipSideOffsets, DipSize, DipToPx as _, Factor, Frequency, Px, PxPoint, PxRect, PxSize, PxToDip, PxTransform,
+    Rgba,
 };
 
 crate::declare_id! {
@@ -153,6 +154,10 @@ pub struct MonitorInfo {
     pub position: PxPoint,
     /// Width/height of the monitor region in the virtual screen, in pixels.
     pub size: PxSize,
+    /// Region of the monitor that is not covered by system UI like the taskbar, in the virtual screen, in pixels.
+    ///
+    /// Is the full monitor region if the system does not provide this info.
+    pub work_area: PxRect,
     /// The monitor scale factor.
     pub scale_factor: Factor,
     /// The refresh rate of this monitor in normal desktop.
@@ -173,6 +178,7 @@ impl MonitorInfo {
             name,
             position,
             size,
+            work_area: PxRect::new(position, size),
             scale_factor,
             video_modes,
             is_primary,
@@ -274,6 +280,9 @@ pub struct WindowOpenData {
     /// Note that the *unsafe* area must still be rendered as it may be partially visible, just don't place nay
     /// interactive or important content outside of this padding.
     pub safe_padding: DipSideOffsets,
+
+    /// Actual number of buffers in the window swap chain, can be different from the requested depth if it is not available.
+    pub swap_chain_depth: u8,
 }
 impl WindowOpenData {
     /// New response.
@@ -295,6 +304,7 @@ impl WindowOpenData {
             render_mode,
             safe_padding,
             refresh_rate: Frequency::from_hertz(60.0),
+            swap_chain_depth: 2,
         }
     }
 }
@@ -544,6 +554,26 @@ pub struct WindowRequest {
     ///
     /// The `zng-view` crate implements this by calling `WindowExtension::configure` and `RendererExtension::configure` with the payload.
     pub extensions: Vec<(ApiExtensionId, ApiExtensionPayload)>,
+
+    /// Number of buffers in the window swap chain, `2` for double buffering or `3` for triple buffering.
+    ///
+    /// Triple buffering can reduce stutter in some drivers at the cost of one frame of latency. This is `2` by default,
+    /// the actual depth is reported in [`WindowOpenData::swap_chain_depth`].
+    pub swap_chain_depth: u8,
+
+    /// Owner window.
+    ///
+    /// Owned windows are minimized and restored together with the owner and always stay on top of it. Only
+    /// implemented if [`WindowCapability::SET_OWNER`] is reported.
+    pub owner: Option<WindowId>,
+
+    /// Alternate sizes of the window icon.
+    ///
+    /// The [`icon`] and these images are used to build a multi-resolution icon, the system selects
+    /// the best size for each place the icon is shown. Is empty by default.
+    ///
+    /// [`icon`]: Self::icon
+    pub icon_set: Vec<ImageId>,
 }
 impl WindowRequest {
     /// New request.
@@ -597,6 +627,9 @@ impl WindowRequest {
             ime_area,
             enabled_buttons,
             system_shutdown_warn,
+            swap_chain_depth: 2,
+            owner: None,
+            icon_set: vec![],
         }
     }
 
@@ -961,6 +994,73 @@ impl CursorImage {
     }
 }
 
+/// Defines a region of the monitor work area that a window can be snapped to.
+///
+/// See [`Api::snap_window`] for more details.
+///
+/// [`Api::snap_window`]: crate::Api::snap_window
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
+pub enum SnapZone {
+    /// Left half.
+    Left,
+    /// Right half.
+    Right,
+    /// Top half.
+    Top,
+    /// Bottom half.
+    Bottom,
+    /// Top-left quarter.
+    TopLeft,
+    /// Top-right quarter.
+    TopRight,
+    /// Bottom-left quarter.
+    BottomLeft,
+    /// Bottom-right quarter.
+    BottomRight,
+}
+impl SnapZone {
+    /// Compute the zone rectangle in the `work_area`.
+    pub fn rect(self, work_area: PxRect) -> PxRect {
+        let PxRect { origin, size } = work_area;
+        let half = PxSize::new(size.width / Px(2), size.height / Px(2));
+        let (x, y, width, height) = match self {
+            SnapZone::Left => (origin.x, origin.y, half.width, size.height),
+            SnapZone::Right => (origin.x + half.width, origin.y, size.width - half.width, size.height),
+            SnapZone::Top => (origin.x, origin.y, size.width, half.height),
+            SnapZone::Bottom => (origin.x, origin.y + half.height, size.width, size.height - half.height),
+            SnapZone::TopLeft => (origin.x, origin.y, half.width, half.height),
+            SnapZone::TopRight => (origin.x + half.width, origin.y, size.width - half.width, half.height),
+            SnapZone::BottomLeft => (origin.x, origin.y + half.height, half.width, size.height - half.height),
+            SnapZone::BottomRight => (
+                origin.x + half.width,
+                origin.y + half.height,
+                size.width - half.width,
+                size.height - half.height,
+            ),
+        };
+        PxRect::new(PxPoint::new(x, y), PxSize::new(width, height))
+    }
+}
+
+/// Target area of a window center operation.
+///
+/// See [`Api::center_window`] for more details.
+///
+/// [`Api::center_window`]: crate::Api::center_window
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
+pub enum CenterTarget {
+    /// Center in the work area of the monitor.
+    Monitor(MonitorId),
+    /// Center on the owner window, see [`WindowRequest::owner`].
+    ///
+    /// Centers on the active monitor if the window has no owner.
+    ParentWindow,
+    /// Center in the work area of the monitor that currently contains the window.
+    ActiveMonitor,
+}
+
 /// Defines the orientation that a window resize will be performed.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum ResizeDirection {
@@ -1021,6 +1121,23 @@ impl ResizeDirection {
     }
 }
 
+/// Defines a non-rectangular window shape.
+///
+/// Pixels outside the shape are not rendered and do not receive pointer input.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum WindowShape {
+    /// Rounded rectangle that fills the window.
+    ///
+    /// The size defines the width and height of the ellipse used to round all corners.
+    RoundedRect(DipSize),
+    /// Alpha mask image, positioned at the top-left of the window area.
+    ///
+    /// Pixels with alpha above zero are inside the shape, pixels outside the image are outside the shape.
+    /// The image must stay loaded while the shape is set.
+    Mask(ImageId),
+}
+
 /// Window state.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Default)]
 pub enum WindowState {
@@ -1359,5 +1476,38 @@ bitflags::bitflags! {
 
         /// Can set the IME area, show virtual keyboard.
         const SET_IME_AREA = (1 << 28);
+
+        /// Can show the drop effect accepted by the window in the system drag&drop cursor.
+        const SET_DROP_EFFECT = (1 << 29);
+
+        /// Can set a non-rectangular window shape.
+        const SET_SHAPE = (1 << 30);
+
+        /// Can set the owner window, the platform owned-window relationship.
+        const SET_OWNER = (1 << 31);
+
+        /// Can set an input region, areas outside the region pass input to windows below.
+        const SET_INPUT_REGION = (1 << 32);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snap_zone_rect() {
+        // odd size work area with offset, like a monitor with a taskbar on the left
+        let work_area = PxRect::new(PxPoint::new(Px(40), Px(0)), PxSize::new(Px(1001), Px(701)));
+        let rect = |x, y, w, h| PxRect::new(PxPoint::new(Px(x), Px(y)), PxSize::new(Px(w), Px(h)));
+
+        assert_eq!(rect(40, 0, 500, 701), SnapZone::Left.rect(work_area));
+        assert_eq!(rect(540, 0, 501, 701), SnapZone::Right.rect(work_area));
+        assert_eq!(rect(40, 0, 1001, 350), SnapZone::Top.rect(work_area));
+        assert_eq!(rect(40, 350, 1001, 351), SnapZone::Bottom.rect(work_area));
+        assert_eq!(rect(40, 0, 500, 350), SnapZone::TopLeft.rect(work_area));
+        assert_eq!(rect(540, 0, 501, 350), SnapZone::TopRight.rect(work_area));
+        assert_eq!(rect(40, 350, 500, 351), SnapZone::BottomLeft.rect(work_area));
+        assert_eq!(rect(540, 350, 501, 351), SnapZone::BottomRight.rect(work_area));
     }
 }
@@ -241,6 +241,7 @@ pub struct DisplayListCache {
 
     latest_frame: FrameId,
     bindings: FxHashMap<FrameValueId, (FrameId, usize)>,
+    root_transform: PxTransform,
 
     wr_list: Option<wr::DisplayListBuilder>,
 }
@@ -254,10 +255,37 @@ impl DisplayListCache {
             latest_frame: FrameId::INVALID,
             space_and_clip: Some(SpaceAndClip::new(pipeline_id)),
             bindings: FxHashMap::default(),
+            root_transform: PxTransform::identity(),
             wr_list: Some(wr::DisplayListBuilder::new(pipeline_id)),
         }
     }
 
+    /// Set a transform applied to all display items, returns `true` if changed.
+    ///
+    /// The transform applies to the next build, use [`rebuild`] to apply to the latest frame.
+    ///
+    /// [`rebuild`]: Self::rebuild
+    pub fn set_root_transform(&mut self, transform: PxTransform) -> bool {
+        if self.root_transform != transform {
+            self.root_transform = transform;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Build the latest frame again, returns `None` if no frame was inserted yet.
+    pub fn rebuild(
+        &mut self,
+        ext: &mut crate::extensions::DisplayListExtAdapter,
+        images: &mut ImageUseMap,
+    ) -> Option<wr::BuiltDisplayList> {
+        let list = mem::take(&mut self.lists.get_mut(&self.latest_frame)?.list);
+        let r = display_list_build(&list, self, ext, images, true);
+        self.lists.get_mut(&self.latest_frame).unwrap().list = list;
+        Some(r)
+    }
+
     /// Keys namespace.
     pub fn id_namespace(&self) -> wr::IdNamespace {
         self.id_namespace
@@ -392,12 +420,7 @@ impl DisplayListCache {
         }
 
         if new_frame {
-            let list = self.lists.get_mut(&self.latest_frame).expect("no frame to update");
-            let list = mem::take(&mut list.list);
-            let r = display_list_build(&list, self, ext, images, true);
-            self.lists.get_mut(&self.latest_frame).unwrap().list = list;
-
-            Err(r)
+            Err(self.rebuild(ext, images).expect("no frame to update"))
         } else {
             properties.transforms.extend(transforms.into_iter().filter_map(PxToWr::to_wr));
             properties.floats.extend(floats.into_iter().filter_map(PxToWr::to_wr));
@@ -428,10 +451,33 @@ fn display_list_build(
         sc: &mut sc,
     });
 
+    let root_transform = !cache.root_transform.is_identity();
+    if root_transform {
+        let spatial_id = wr_list.push_reference_frame(
+            wr::units::LayoutPoint::zero(),
+            sc.spatial_id(),
+            wr::TransformStyle::Flat,
+            wr::PropertyBinding::Value(cache.root_transform.to_wr()),
+            wr::ReferenceFrameKind::Transform {
+                is_2d_scale_translation: false,
+                should_snap: false,
+                paired_with_perspective: false,
+            },
+            // reserved key, app keys are derived from widget IDs
+            wr::SpatialTreeItemKey::new(u64::MAX, u64::MAX),
+        );
+        sc.push_spatial(spatial_id);
+    }
+
     for item in list {
         display_item_to_webrender(item, &mut wr_list, ext, images, &mut sc, cache, is_reuse);
     }
 
+    if root_transform {
+        wr_list.pop_reference_frame();
+        sc.pop_spatial();
+    }
+
     ext.display_list_end(&mut DisplayExtensionArgs {
         list: &mut wr_list,
         sc: &mut sc,
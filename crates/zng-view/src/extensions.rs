@@ -19,7 +19,7 @@ use zng_unit::{Factor, PxSize};
 use zng_view_api::window::RenderMode;
 use zng_view_api::{
     Event,
-    api_extension::{ApiExtensionId, ApiExtensionName, ApiExtensionPayload, ApiExtensions},
+    api_extension::{ApiExtensionId, ApiExtensionName, ApiExtensionPayload, ApiExtensionRequestId, ApiExtensions},
 };
 
 use crate::display_list::{DisplayExtensionArgs, DisplayExtensionItemArgs, DisplayExtensionUpdateArgs, DisplayListExtension, SpaceAndClip};
@@ -38,6 +38,8 @@ pub trait ViewExtension: Send + Any {
     fn name(&self) -> &ApiExtensionName;
 
     /// Run the extension as an app level command.
+    ///
+    /// Long running commands can use [`ExtensionEventSender::respond_later`] to respond asynchronously.
     fn command(&mut self, request: ApiExtensionPayload) -> Option<ApiExtensionPayload> {
         let _ = request;
         None
@@ -193,6 +195,28 @@ pub trait RendererExtension: Any {
     fn as_any_mut(&mut self) -> &mut dyn Any;
 }
 
+/// Represents a renderer extension with strongly typed commands.
+///
+/// Register using [`ViewExtensions::renderer_typed`], the registration wraps the extension to deserialize
+/// requests and serialize responses, the [`RendererExtension::command`] method is not called for typed extensions.
+///
+/// The request and response types are usually declared in a module shared by the app-process and view-process, the app-process
+/// calls the extension with the same types using `ViewRenderer::render_extension`.
+pub trait TypedRendererExtension: RendererExtension + Sized {
+    /// Command request type.
+    type Request: serde::de::DeserializeOwned;
+    /// Command response type.
+    type Response: serde::Serialize;
+
+    /// New extension instance for a renderer.
+    fn new(id: ApiExtensionId) -> Self;
+
+    /// Called when a command request is made for the extension and renderer (window ID).
+    ///
+    /// Note that [`RendererCommandArgs::request`] is the raw payload, already deserialized to `request`.
+    fn typed_command(&mut self, request: Self::Request, args: &mut RendererCommandArgs) -> Self::Response;
+}
+
 /// Arguments for [`RendererExtension::render_start`] and [`RendererExtension::render_end`].
 #[non_exhaustive]
 pub struct RenderArgs<'a> {
@@ -378,6 +402,61 @@ impl ExtensionEventSender {
     pub fn send(&self, payload: ApiExtensionPayload) -> Result<(), ChannelError> {
         self.sender.send(crate::AppEvent::Notify(Event::ExtensionEvent(self.id, payload)))
     }
+
+    /// Start an asynchronous command response.
+    ///
+    /// Returns the [`ApiExtensionPayload::pending`] payload that must be returned by the command and a responder that
+    /// sends the actual response later, without blocking the event loop.
+    pub fn respond_later(&self) -> (ApiExtensionPayload, ExtensionResponder) {
+        static REQUEST_ID: Mutex<ApiExtensionRequestId> = Mutex::new(ApiExtensionRequestId::first());
+        let request_id = REQUEST_ID.lock().incr();
+        (
+            ApiExtensionPayload::pending(self.id, request_id),
+            ExtensionResponder {
+                sender: Some(self.clone()),
+                request_id,
+            },
+        )
+    }
+
+    fn send_response(&self, request_id: ApiExtensionRequestId, response: ApiExtensionPayload) -> Result<(), ChannelError> {
+        self.sender.send(crate::AppEvent::Notify(Event::ExtensionResponse {
+            extension_id: self.id,
+            request_id,
+            response,
+        }))
+    }
+}
+
+/// Sends the response of an extension command that returned a pending payload.
+///
+/// The response is received in the app-process as an [`Event::ExtensionResponse`]. If the responder
+/// is dropped without responding an [`ApiExtensionPayload::invalid_request`] is send as the response.
+///
+/// Use [`ExtensionEventSender::respond_later`] to create.
+pub struct ExtensionResponder {
+    sender: Option<ExtensionEventSender>,
+    request_id: ApiExtensionRequestId,
+}
+impl ExtensionResponder {
+    /// Request ID of the response.
+    pub fn request_id(&self) -> ApiExtensionRequestId {
+        self.request_id
+    }
+
+    /// Send the command response.
+    pub fn respond(mut self, response: ApiExtensionPayload) -> Result<(), ChannelError> {
+        let sender = self.sender.take().unwrap();
+        sender.send_response(self.request_id, response)
+    }
+}
+impl Drop for ExtensionResponder {
+    fn drop(&mut self) {
+        if let Some(sender) = self.sender.take() {
+            let response = ApiExtensionPayload::invalid_request(sender.id, "responder dropped without responding");
+            let _ = sender.send_response(self.request_id, response);
+        }
+    }
 }
 
 /// Snapshot of a [`BlobExtension`] that can render/copy pixels.
@@ -572,6 +651,7 @@ impl ExternalImages {
             is_opaque,
             density: None,
             stripes: Mutex::new(Box::new([])),
+            compressed_texture: false,
         })
     }
 
@@ -855,6 +935,70 @@ impl ViewExtensions {
         self
     }
 
+    /// Register a renderer extension with strongly typed commands.
+    ///
+    /// Invalid requests are answered with [`ApiExtensionPayload::invalid_request`], see [`TypedRendererExtension`] for more details.
+    pub fn renderer_typed<E: TypedRendererExtension>(&mut self, name: impl Into<ApiExtensionName>) -> &mut Self {
+        struct TypedExt<E>(ApiExtensionId, E);
+        impl<E: TypedRendererExtension> RendererExtension for TypedExt<E> {
+            fn configure(&mut self, args: &mut RendererConfigArgs) {
+                self.1.configure(args)
+            }
+            fn renderer_inited(&mut self, args: &mut RendererInitedArgs) {
+                self.1.renderer_inited(args)
+            }
+            fn is_init_only(&self) -> bool {
+                self.1.is_init_only()
+            }
+            fn command(&mut self, args: &mut RendererCommandArgs) -> ApiExtensionPayload {
+                match args.request.deserialize::<E::Request>() {
+                    Ok(r) => {
+                        let r = self.1.typed_command(r, args);
+                        match ApiExtensionPayload::serialize(&r) {
+                            Ok(r) => r,
+                            Err(e) => ApiExtensionPayload::invalid_request(self.0, format_args!("cannot serialize response, {e}")),
+                        }
+                    }
+                    Err(e) => ApiExtensionPayload::invalid_request(self.0, e),
+                }
+            }
+            fn render_start(&mut self, args: &mut RenderArgs) {
+                self.1.render_start(args)
+            }
+            fn render_push(&mut self, args: &mut RenderItemArgs) {
+                self.1.render_push(args)
+            }
+            fn render_pop(&mut self, args: &mut RenderItemArgs) {
+                self.1.render_pop(args)
+            }
+            fn render_end(&mut self, args: &mut RenderArgs) {
+                self.1.render_end(args)
+            }
+            fn render_update(&mut self, args: &mut RenderUpdateArgs) {
+                self.1.render_update(args)
+            }
+            fn frame_ready(&mut self, args: &mut FrameReadyArgs) {
+                self.1.frame_ready(args)
+            }
+            fn redraw(&mut self, args: &mut RedrawArgs) {
+                self.1.redraw(args)
+            }
+            fn low_memory(&mut self) {
+                self.1.low_memory()
+            }
+            fn renderer_deinited(&mut self, args: &mut RendererDeinitedArgs) {
+                self.1.renderer_deinited(args)
+            }
+            fn as_any(&self) -> &dyn Any {
+                self.1.as_any()
+            }
+            fn as_any_mut(&mut self) -> &mut dyn Any {
+                self.1.as_any_mut()
+            }
+        }
+        self.renderer(name, |id| TypedExt(id, E::new(id)))
+    }
+
     /// Register a data extension, custom image, audio metadata and others.
     ///
     /// Use [`id`] to retrieve identifier.
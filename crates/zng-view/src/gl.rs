@@ -647,6 +647,21 @@ impl GlContext {
         self.render_mode
     }
 
+    /// Number of buffers in the presentation swap chain.
+    ///
+    /// Note that the driver may still queue more frames internally.
+    pub(crate) fn swap_chain_depth(&self) -> u8 {
+        match &self.backend {
+            // glutin only requests double buffered configs, triple buffering is not portable in EGL/WGL/GLX
+            #[cfg(feature = "hardware")]
+            GlBackend::Glutin { .. } => 2,
+            // softbuffer presents one back buffer
+            #[cfg(feature = "software")]
+            GlBackend::Swgl { .. } => 2,
+            GlBackend::Dropped => 0,
+        }
+    }
+
     pub(crate) fn resize(&mut self, size: PhysicalSize<u32>) {
         assert!(self.is_current());
 
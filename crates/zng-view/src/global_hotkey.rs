@@ -0,0 +1,190 @@
+//! System wide keyboard shortcuts.
+
+use zng_view_api::keyboard::{GlobalHotkey, GlobalHotkeyError, GlobalHotkeyId, KeyboardCapability};
+
+use crate::AppEventSender;
+
+#[cfg(any(
+    windows,
+    target_os = "macos",
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+mod platform {
+    use std::sync::{Arc, Mutex};
+
+    use global_hotkey::{
+        GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState,
+        hotkey::{Code, HotKey, Modifiers},
+    };
+    use zng_txt::ToTxt as _;
+    use zng_view_api::{
+        Event,
+        keyboard::{GlobalHotkey, GlobalHotkeyError, GlobalHotkeyId, HotkeyModifiers, KeyState, KeyboardCapability},
+    };
+
+    use crate::{AppEvent, AppEventSender};
+
+    type HotkeyMap = Arc<Mutex<Vec<(GlobalHotkeyId, HotKey)>>>;
+
+    pub struct GlobalHotkeyService {
+        manager: Option<GlobalHotKeyManager>,
+        hotkeys: HotkeyMap,
+        id_gen: GlobalHotkeyId,
+    }
+    impl Default for GlobalHotkeyService {
+        fn default() -> Self {
+            Self {
+                manager: None,
+                hotkeys: HotkeyMap::default(),
+                id_gen: GlobalHotkeyId::first(),
+            }
+        }
+    }
+    impl GlobalHotkeyService {
+        pub fn capabilities(&self, headless: bool) -> KeyboardCapability {
+            // X11 only, Wayland does not allow apps to grab keys
+            if headless || (cfg!(unix) && !cfg!(target_os = "macos") && std::env::var("WAYLAND_DISPLAY").is_ok()) {
+                KeyboardCapability::empty()
+            } else {
+                KeyboardCapability::GLOBAL_HOTKEY
+            }
+        }
+
+        fn manager(&mut self, app_sender: &AppEventSender) -> Result<&GlobalHotKeyManager, GlobalHotkeyError> {
+            if self.manager.is_none() {
+                let manager = GlobalHotKeyManager::new().map_err(|e| GlobalHotkeyError::CannotRegister(e.to_txt()))?;
+
+                let app_sender = Mutex::new(app_sender.clone());
+                let hotkeys = self.hotkeys.clone();
+                GlobalHotKeyEvent::set_event_handler(Some(move |ev: GlobalHotKeyEvent| {
+                    let id = hotkeys.lock().unwrap().iter().find(|(_, k)| k.id() == ev.id()).map(|(id, _)| *id);
+                    if let Some(id) = id {
+                        let state = match ev.state() {
+                            HotKeyState::Pressed => KeyState::Pressed,
+                            HotKeyState::Released => KeyState::Released,
+                        };
+                        let _ = app_sender.lock().unwrap().send(AppEvent::Notify(Event::GlobalHotkey { id, state }));
+                    }
+                }));
+
+                self.manager = Some(manager);
+            }
+            Ok(self.manager.as_ref().unwrap())
+        }
+
+        pub fn register(&mut self, app_sender: &AppEventSender, hotkey: GlobalHotkey) -> Result<GlobalHotkeyId, GlobalHotkeyError> {
+            if hotkey.key_code.is_modifier() || hotkey.key_code.is_unidentified() {
+                return Err(GlobalHotkeyError::CannotRegister(
+                    format!("cannot register `{:?}` as hotkey", hotkey.key_code).into(),
+                ));
+            }
+            let code = hotkey
+                .key_code
+                .name()
+                .parse::<Code>()
+                .map_err(|_| GlobalHotkeyError::CannotRegister(format!("`{:?}` not supported as hotkey", hotkey.key_code).into()))?;
+
+            let mut mods = Modifiers::empty();
+            if hotkey.modifiers.contains(HotkeyModifiers::SHIFT) {
+                mods |= Modifiers::SHIFT;
+            }
+            if hotkey.modifiers.contains(HotkeyModifiers::CTRL) {
+                mods |= Modifiers::CONTROL;
+            }
+            if hotkey.modifiers.contains(HotkeyModifiers::ALT) {
+                mods |= Modifiers::ALT;
+            }
+            if hotkey.modifiers.contains(HotkeyModifiers::SUPER) {
+                mods |= Modifiers::SUPER;
+            }
+            let key = HotKey::new(if mods.is_empty() { None } else { Some(mods) }, code);
+
+            if self.hotkeys.lock().unwrap().iter().any(|(_, k)| k.id() == key.id()) {
+                return Err(GlobalHotkeyError::AlreadyRegistered);
+            }
+
+            self.manager(app_sender)?.register(key).map_err(|e| match e {
+                global_hotkey::Error::AlreadyRegistered(_) => GlobalHotkeyError::AlreadyRegistered,
+                e => GlobalHotkeyError::CannotRegister(e.to_txt()),
+            })?;
+
+            let id = self.id_gen.incr();
+            self.hotkeys.lock().unwrap().push((id, key));
+            Ok(id)
+        }
+
+        pub fn unregister(&mut self, id: GlobalHotkeyId) {
+            let mut hotkeys = self.hotkeys.lock().unwrap();
+            if let Some(i) = hotkeys.iter().position(|(i, _)| *i == id) {
+                let (_, key) = hotkeys.swap_remove(i);
+                if let Some(m) = &self.manager
+                    && let Err(e) = m.unregister(key)
+                {
+                    tracing::error!("failed to unregister global hotkey, {e}");
+                }
+            }
+        }
+    }
+    impl Drop for GlobalHotkeyService {
+        fn drop(&mut self) {
+            if self.manager.is_some() {
+                GlobalHotKeyEvent::set_event_handler(None::<fn(GlobalHotKeyEvent)>);
+            }
+        }
+    }
+}
+
+#[cfg(not(any(
+    windows,
+    target_os = "macos",
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+)))]
+mod platform {
+    use zng_view_api::keyboard::{GlobalHotkey, GlobalHotkeyError, GlobalHotkeyId, KeyboardCapability};
+
+    use crate::AppEventSender;
+
+    #[derive(Default)]
+    pub struct GlobalHotkeyService {}
+    impl GlobalHotkeyService {
+        pub fn capabilities(&self, _: bool) -> KeyboardCapability {
+            KeyboardCapability::empty()
+        }
+
+        pub fn register(&mut self, _: &AppEventSender, hotkey: GlobalHotkey) -> Result<GlobalHotkeyId, GlobalHotkeyError> {
+            let _ = hotkey;
+            tracing::error!("register_global_hotkey not implemented for {}", std::env::consts::OS);
+            Err(GlobalHotkeyError::NotSupported)
+        }
+
+        pub fn unregister(&mut self, id: GlobalHotkeyId) {
+            let _ = id;
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct GlobalHotkeyService {
+    service: platform::GlobalHotkeyService,
+}
+impl GlobalHotkeyService {
+    pub fn capabilities(&self, headless: bool) -> KeyboardCapability {
+        self.service.capabilities(headless)
+    }
+
+    pub fn register(&mut self, app_sender: &AppEventSender, hotkey: GlobalHotkey) -> Result<GlobalHotkeyId, GlobalHotkeyError> {
+        self.service.register(app_sender, hotkey)
+    }
+
+    pub fn unregister(&mut self, id: GlobalHotkeyId) {
+        self.service.unregister(id)
+    }
+}
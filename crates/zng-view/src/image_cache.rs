@@ -16,7 +16,7 @@ use zng_txt::Txt;
 use zng_txt::formatx;
 
 use zng_task::channel::{IpcBytes, IpcReceiver};
-use zng_unit::{Px, PxDensity2d, PxSize};
+use zng_unit::{Px, PxDensity2d, PxRect, PxSize};
 use zng_view_api::{
     Event,
     image::{
@@ -26,15 +26,17 @@ use zng_view_api::{
 };
 
 use crate::{AppEvent, AppEventSender};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 // Image data is provided to webrender directly from the BGRA8 shared memory.
 // The `ExternalImageId` is the Arc pointer to ImageData.
 mod capture;
+mod compress;
 mod decode;
 mod dyn_image;
 mod encode;
 mod external;
+pub(crate) use compress::TextureCompression;
 pub(crate) use external::{ImageUseMap, WrImageCache};
 
 #[cfg(not(feature = "_image_any"))]
@@ -98,6 +100,8 @@ pub(crate) type ResizerCache = Mutex<fast_image_resize::Resizer>;
 pub(crate) struct ImageCache {
     app_sender: AppEventSender,
     images: FxHashMap<ImageId, Image>,
+    // images and entries requested with `compressed_texture`
+    compressed_texture: FxHashSet<ImageId>,
     image_id_gen: Arc<Mutex<ImageId>>,
     encode_id_gen: ImageEncodeId,
     resizer: Arc<ResizerCache>,
@@ -118,6 +122,7 @@ impl ImageCache {
         Self {
             app_sender,
             images: FxHashMap::default(),
+            compressed_texture: FxHashSet::default(),
             image_id_gen: Arc::new(Mutex::new(ImageId::first())),
             encode_id_gen: ImageEncodeId::first(),
             resizer: Arc::new(Mutex::new(fast_image_resize::Resizer::new())),
@@ -140,10 +145,15 @@ impl ImageCache {
             mask,
             entries,
             parent,
+            region,
+            compressed_texture,
             ..
         }: ImageRequest<IpcReadHandle>,
     ) -> ImageId {
         let id = self.image_id_gen.lock().incr();
+        if compressed_texture {
+            self.compressed_texture.insert(id);
+        }
         let id_gen = self.image_id_gen.clone();
         let app_sender = self.app_sender.clone();
         let resizer = self.resizer.clone();
@@ -173,6 +183,7 @@ impl ImageCache {
                 mask,
                 entries,
                 parent,
+                region,
             );
         });
 
@@ -189,10 +200,15 @@ impl ImageCache {
             mask,
             entries,
             parent,
+            region,
+            compressed_texture,
             ..
         }: ImageRequest<IpcReceiver<IpcBytes>>,
     ) -> ImageId {
         let id = self.image_id_gen.lock().incr();
+        if compressed_texture {
+            self.compressed_texture.insert(id);
+        }
         let id_gen = self.image_id_gen.clone();
         let app_sender = self.app_sender.clone();
         let resizer = self.resizer.clone();
@@ -221,7 +237,8 @@ impl ImageCache {
             // try parse header early at least
             let mut header_reader = IpcReadBlocking::Bytes(io::Cursor::new(first_chunk.clone()));
             #[cfg(feature = "_image_any")]
-            if let ImageDataFormat::FileExtension(_) | ImageDataFormat::MimeType(_) | ImageDataFormat::Unknown = &format
+            if region.is_none()
+                && let ImageDataFormat::FileExtension(_) | ImageDataFormat::MimeType(_) | ImageDataFormat::Unknown = &format
                 && let Ok((fmt, entries)) = Self::decode_container(&format, &mut header_reader)
                 && entries.first() == Some(&(0, ImageEntryKind::Page))
             {
@@ -308,6 +325,7 @@ impl ImageCache {
                 mask,
                 entries,
                 parent,
+                region,
             );
         });
         id
@@ -331,18 +349,21 @@ impl ImageCache {
         mask: Option<ImageMaskMode>,
         entries: ImageEntriesMode,
         parent: Option<ImageEntryMetadata>,
+        region: Option<PxRect>,
     ) {
         macro_rules! error {
             ($($tt:tt)*) => {{
                 let _ = app_sender.send(AppEvent::Notify(Event::ImageDecodeError { image: id, error: formatx!($($tt)*) }));
             }};
         }
+        let mut region_meta = None;
         macro_rules! decoded {
             ($r:tt, $og_color_type:expr, $return_data:expr) => {{
                 let (pixels, size, density, is_opaque, is_mask) = $r;
                 let mut meta = ImageMetadata::new(id, size, is_mask, $og_color_type);
                 meta.density = density;
                 meta.parent = parent;
+                meta.region = region_meta;
                 meta.format_name = meta.original_color_type.name.clone();
                 if !notified_meta {
                     let _ = app_sender.send(AppEvent::Notify(Event::ImageMetadataDecoded(meta.clone())));
@@ -371,7 +392,6 @@ impl ImageCache {
                     Ok(b) => b,
                     Err(e) => return error!("cannot read bgra8 data, {e}"),
                 };
-                let downscale_sizes = self::downscale_sizes(downscale.as_ref(), size, &[]);
 
                 let expected_len = size.width.0 as usize * size.height.0 as usize * 4;
                 if data.len() != expected_len {
@@ -381,6 +401,18 @@ impl ImageCache {
                     );
                 }
 
+                let (size, data) = match region {
+                    Some(rect) => match Self::crop_decoded(false, size, &data, rect).and_then(|(r, d)| Ok((r, d.finish_blocking()?))) {
+                        Ok((r, d)) => {
+                            region_meta = Some((size, r));
+                            (r.size, d)
+                        }
+                        Err(e) => return error!("{e}"),
+                    },
+                    None => (size, data),
+                };
+                let downscale_sizes = self::downscale_sizes(downscale.as_ref(), size, &[]);
+
                 if let Some(mask) = mask {
                     match Self::convert_bgra8_to_mask(size, &data, mask, density, downscale_sizes.0, &resizer) {
                         Ok(r) => {
@@ -424,13 +456,24 @@ impl ImageCache {
                     Ok(b) => b,
                     Err(e) => return error!("cannot read a8 data, {e}"),
                 };
-                let downscale_sizes = self::downscale_sizes(downscale.as_ref(), size, &[]);
 
                 let expected_len = size.width.0 as usize * size.height.0 as usize;
                 if data.len() != expected_len {
                     return error!("pixels.len() is not width * height, expected {expected_len}, found {}", data.len());
                 }
 
+                let (size, data) = match region {
+                    Some(rect) => match Self::crop_decoded(true, size, &data, rect).and_then(|(r, d)| Ok((r, d.finish_blocking()?))) {
+                        Ok((r, d)) => {
+                            region_meta = Some((size, r));
+                            (r.size, d)
+                        }
+                        Err(e) => return error!("{e}"),
+                    },
+                    None => (size, data),
+                };
+                let downscale_sizes = self::downscale_sizes(downscale.as_ref(), size, &[]);
+
                 if mask.is_none() {
                     match Self::convert_a8_to_bgra8(size, &data, None, downscale_sizes.0, &resizer) {
                         Ok(r) => {
@@ -465,7 +508,7 @@ impl ImageCache {
             // needs decoding
             #[cfg(not(feature = "_image_any"))]
             fmt => {
-                let _ = (max_decoded_len, downscale);
+                let _ = (max_decoded_len, downscale, region);
                 return error!("no decoder for {fmt:?}");
             }
             #[cfg(feature = "_image_any")]
@@ -478,6 +521,99 @@ impl ImageCache {
                     return error!("empty container");
                 }
 
+                if let Some(region) = region {
+                    // only the first page, decode only the region if the format allows it
+
+                    let entry_index = entries_kind[0].0;
+                    if let Err(e) = data.seek(io::SeekFrom::Start(0)) {
+                        return error!("cannot read image, {e}");
+                    }
+                    let h = match Self::decode_metadata(&mut data, fmt, entry_index) {
+                        Ok(h) => h,
+                        Err(e) => return error!("{e}"),
+                    };
+                    let mut full_size = h.size;
+                    {
+                        use image::metadata::Orientation::*;
+                        if let Rotate90 | Rotate270 | Rotate90FlipH | Rotate270FlipH = h.orientation {
+                            full_size = PxSize::new(full_size.height, full_size.width);
+                        }
+                    }
+                    let rect = match PxRect::from_size(full_size).intersection(&region) {
+                        Some(r) if !r.is_empty() => r,
+                        _ => return error!("region {region:?} is outside the image {full_size:?}"),
+                    };
+                    let decoded_len = rect.size.width.0 as u64 * rect.size.height.0 as u64 * 4;
+                    if decoded_len > max_decoded_len {
+                        return error!(
+                            "image region {rect:?} needs to allocate {decoded_len} bytes, but max allowed size is {max_decoded_len} bytes"
+                        );
+                    }
+                    let downscale = self::downscale_sizes(downscale.as_ref(), rect.size, &[]).0;
+
+                    let mut meta = ImageMetadata::new(
+                        id,
+                        downscale.unwrap_or(rect.size),
+                        mask.is_some(),
+                        image_color_type_to_vp(h.og_color_type),
+                    );
+                    meta.density = h.density;
+                    meta.parent = parent;
+                    meta.format_name = h.format_name.clone();
+                    meta.region = Some((full_size, rect));
+                    if !notified_meta
+                        && app_sender
+                            .send(AppEvent::Notify(Event::ImageMetadataDecoded(meta.clone())))
+                            .is_err()
+                    {
+                        return;
+                    }
+
+                    if let Err(e) = data.seek(io::SeekFrom::Start(0)) {
+                        return error!("cannot read image, {e}");
+                    }
+                    let img = match Self::decode_image_region(&mut data, fmt, entry_index, &h, rect) {
+                        Ok(Some(img)) => Ok((img, None)),
+                        Ok(None) => {
+                            // format cannot decode only the region
+                            let decoded_len = h.size.width.0 as u64 * h.size.height.0 as u64 * 4;
+                            if decoded_len > max_decoded_len {
+                                return error!(
+                                    "image {:?} needs to allocate {decoded_len} bytes to decode the region, but max allowed size is {max_decoded_len} bytes",
+                                    h.size
+                                );
+                            }
+                            if let Err(e) = data.seek(io::SeekFrom::Start(0)) {
+                                return error!("cannot read image, {e}");
+                            }
+                            Self::decode_image(&mut data, fmt, entry_index).map(|img| (img, Some(rect)))
+                        }
+                        Err(e) => Err(e),
+                    };
+                    match img {
+                        Ok((img, crop)) => match Self::convert_decoded(
+                            img,
+                            mask,
+                            h.density,
+                            h.icc_profile.as_ref(),
+                            crop,
+                            downscale,
+                            h.orientation,
+                            &resizer,
+                        ) {
+                            Ok((pixels, size, density, is_opaque, is_mask)) => {
+                                meta.size = size;
+                                meta.density = density;
+                                meta.is_mask = is_mask;
+                                let _ = app_sender.send(AppEvent::ImageCanRender(ImageDecoded::new(meta, pixels, is_opaque)));
+                            }
+                            Err(e) => error!("{e}"),
+                        },
+                        Err(e) => error!("{e}"),
+                    }
+                    return;
+                }
+
                 let mut headers = Vec::with_capacity(entries_kind.len());
                 for (i, kind) in entries_kind {
                     if let Err(e) = data.seek(io::SeekFrom::Start(0)) {
@@ -756,6 +892,7 @@ impl ImageCache {
                                     mask,
                                     entry_header.density,
                                     entry_header.icc_profile.as_ref(),
+                                    None,
                                     downscale,
                                     entry_header.orientation,
                                     &resizer,
@@ -915,6 +1052,7 @@ impl ImageCache {
 
     pub fn forget(&mut self, id: ImageId) {
         self.images.remove(&id);
+        self.compressed_texture.remove(&id);
     }
 
     pub fn get(&self, id: ImageId) -> Option<&Image> {
@@ -923,6 +1061,16 @@ impl ImageCache {
 
     /// Called after receive and decode completes correctly.
     pub(crate) fn on_image_can_render(&mut self, data: ImageDecoded) {
+        let compressed_texture = self.compressed_texture.contains(&data.meta.id)
+            || data
+                .meta
+                .parent
+                .as_ref()
+                .is_some_and(|p| self.compressed_texture.contains(&p.parent));
+        if compressed_texture {
+            // entries of entries
+            self.compressed_texture.insert(data.meta.id);
+        }
         self.images.insert(
             data.meta.id,
             Image(Arc::new(ImageData::RawData {
@@ -932,6 +1080,7 @@ impl ImageCache {
                 is_opaque: data.is_opaque,
                 density: data.meta.density,
                 stripes: Mutex::new(Box::new([])),
+                compressed_texture,
             })),
         );
 
@@ -1011,6 +1160,8 @@ pub(crate) enum ImageData {
         density: Option<PxDensity2d>,
         range: std::ops::Range<usize>,
         stripes: Mutex<Box<[Image]>>,
+        /// Upload as a compressed texture if the renderer supports it.
+        compressed_texture: bool,
     },
     NativeTexture {
         uv: webrender::api::units::TexelRect,
@@ -1125,6 +1276,44 @@ impl Image {
         }
     }
 
+    /// Gets the horizontal runs of pixels with alpha above zero, one single row rectangle per run.
+    ///
+    /// Used to build the window region for [`WindowShape::Mask`].
+    ///
+    /// [`WindowShape::Mask`]: zng_view_api::window::WindowShape::Mask
+    #[cfg(windows)]
+    pub fn alpha_runs(&self) -> Vec<zng_unit::PxRect> {
+        use zng_unit::{PxPoint, PxRect};
+
+        let size = self.size();
+        let pixels = &self.pixels()[self.range()];
+        let (bpp, alpha) = if self.0.is_mask() { (1, 0) } else { (4, 3) };
+        let width = size.width.0 as usize;
+
+        let mut r = vec![];
+        if width == 0 {
+            return r;
+        }
+        for (y, row) in pixels.chunks_exact(width * bpp).enumerate() {
+            let mut start = None;
+            for x in 0..=width {
+                let inside = x < width && row[x * bpp + alpha] > 0;
+                match (inside, start) {
+                    (true, None) => start = Some(x),
+                    (false, Some(s)) => {
+                        r.push(PxRect::new(
+                            PxPoint::new(Px(s as _), Px(y as _)),
+                            PxSize::new(Px((x - s) as _), Px(1)),
+                        ));
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        r
+    }
+
     /// If this is `true` needs to replace with `wr_stripes`
     pub fn should_stripe(&self) -> bool {
         self.pixels().len() > Self::SHOULD_STRIPE_LEN
@@ -1205,6 +1394,7 @@ impl Image {
                 range,
                 // always empty
                 stripes: Mutex::new(Box::new([])),
+                compressed_texture: false,
             }));
 
             stripes.push(stripe);
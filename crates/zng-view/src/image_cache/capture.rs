@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use zng_task::{channel::IpcBytesMut, parking_lot::Mutex};
 use zng_txt::formatx;
-use zng_unit::{Factor, PxDensity2d, PxDensityUnits as _, PxRect};
+use zng_unit::{Factor, Px, PxDensity2d, PxDensityUnits as _, PxRect, PxSize};
 use zng_view_api::{
     Event,
     image::{ColorType, ImageDecoded, ImageId, ImageMaskMode, ImageMetadata},
@@ -26,6 +26,7 @@ impl ImageCache {
         frame_id: FrameId,
         scale_factor: Factor,
         mask: Option<ImageMaskMode>,
+        downscale: Option<PxSize>,
     ) -> ImageId {
         if frame_id == FrameId::INVALID {
             let id = self.image_id_gen.lock().incr();
@@ -36,7 +37,7 @@ impl ImageCache {
             return id;
         }
 
-        match self.frame_image_data(gl, rect, scale_factor, mask) {
+        match self.frame_image_data(gl, rect, scale_factor, mask, downscale) {
             Ok(data) => {
                 let id = data.meta.id;
                 let _ = self.app_sender.send(AppEvent::Notify(Event::ImageDecoded(data)));
@@ -54,12 +55,15 @@ impl ImageCache {
     }
 
     /// Create frame_image for a capture request in the FrameRequest.
+    ///
+    /// If `downscale` is set the image is resized to fit it, preserving the aspect ratio.
     pub fn frame_image_data(
         &mut self,
         gl: &dyn gleam::gl::Gl,
         rect: PxRect,
         scale_factor: Factor,
         mask: Option<ImageMaskMode>,
+        downscale: Option<PxSize>,
     ) -> std::io::Result<ImageDecoded> {
         if rect.size.is_empty() {
             return Err(std::io::Error::new(
@@ -87,12 +91,17 @@ impl ImageCache {
             buf.copy_from_slice(px);
         }
 
-        if let Some(mask) = mask {
-            let density = 96.0 * scale_factor.0;
-            let density = Some(PxDensity2d::splat(density.ppi()));
+        let mut density = 96.0 * scale_factor.0;
+        let downscale = downscale.map(|max| {
+            let fit = fit_size(rect.size, max);
+            density *= fit.width.0 as f32 / rect.size.width.0 as f32;
+            fit
+        });
+        let density = Some(PxDensity2d::splat(density.ppi()));
 
+        if let Some(mask) = mask {
             let r = if format == gleam::gl::BGRA {
-                Self::convert_bgra8_to_mask_in_place(rect.size, buf, mask, density, None, &self.resizer)
+                Self::convert_bgra8_to_mask_in_place(rect.size, buf, mask, density, downscale, &self.resizer)
             } else {
                 Self::convert_decoded(
                     IpcDynamicImage::ImageRgba8(
@@ -102,6 +111,7 @@ impl ImageCache {
                     density,
                     None,
                     None,
+                    downscale,
                     image::metadata::Orientation::NoTransforms,
                     &self.resizer,
                 )
@@ -120,6 +130,7 @@ impl ImageCache {
                     is_opaque,
                     density,
                     stripes: Mutex::new(Box::new([])),
+                    compressed_texture: false,
                 })),
             );
 
@@ -133,12 +144,15 @@ impl ImageCache {
                 }
             }
 
+            let mut size = rect.size;
+            if let Some((s, px)) = Self::downscale_decoded(None, downscale, &self.resizer, size, &buf)? {
+                size = s;
+                buf = px;
+            }
+
             let is_opaque = buf.chunks_exact(4).all(|bgra| bgra[3] == 255);
 
             let data = buf.finish_blocking()?;
-            let density = 96.0 * scale_factor.0;
-            let density = Some(PxDensity2d::splat(density.ppi()));
-            let size = rect.size;
 
             let id = self.image_id_gen.lock().incr();
             self.images.insert(
@@ -150,6 +164,7 @@ impl ImageCache {
                     is_opaque,
                     density,
                     stripes: Mutex::new(Box::new([])),
+                    compressed_texture: false,
                 })),
             );
             let mut meta = ImageMetadata::new(id, size, false, og_color_type);
@@ -158,3 +173,44 @@ impl ImageCache {
         }
     }
 }
+
+/// Scale `size` to fit `max`, preserving the aspect ratio, never upscales.
+fn fit_size(size: PxSize, max: PxSize) -> PxSize {
+    let scale = (max.width.0 as f32 / size.width.0 as f32)
+        .min(max.height.0 as f32 / size.height.0 as f32)
+        .min(1.0);
+    PxSize::new(
+        Px(((size.width.0 as f32 * scale).round() as i32).max(1)),
+        Px(((size.height.0 as f32 * scale).round() as i32).max(1)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn size(w: i32, h: i32) -> PxSize {
+        PxSize::new(Px(w), Px(h))
+    }
+
+    #[test]
+    fn fit_size_downscale_wide() {
+        assert_eq!(size(200, 100), fit_size(size(1000, 500), size(200, 200)));
+    }
+
+    #[test]
+    fn fit_size_downscale_tall() {
+        assert_eq!(size(100, 200), fit_size(size(500, 1000), size(200, 200)));
+    }
+
+    #[test]
+    fn fit_size_no_upscale() {
+        assert_eq!(size(50, 20), fit_size(size(50, 20), size(200, 200)));
+        assert_eq!(size(200, 200), fit_size(size(200, 200), size(200, 200)));
+    }
+
+    #[test]
+    fn fit_size_min_one_px() {
+        assert_eq!(size(200, 1), fit_size(size(10000, 10), size(200, 200)));
+    }
+}
@@ -0,0 +1,281 @@
+//! S3TC (BC1/BC3) texture compression.
+
+use gleam::gl::{self, GLenum};
+use zng_unit::PxSize;
+
+const COMPRESSED_RGB_S3TC_DXT1_EXT: GLenum = 0x83F0;
+const COMPRESSED_RGBA_S3TC_DXT5_EXT: GLenum = 0x83F3;
+
+/// Renderer compressed texture support.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct TextureCompression {
+    max_size: i32,
+}
+impl TextureCompression {
+    /// Query the GL context, returns `None` if S3TC is not supported.
+    ///
+    /// The context must be current.
+    pub fn query(gl: &dyn gl::Gl) -> Option<Self> {
+        let mut n = [0];
+        let mut max_size = [0];
+        // SAFETY: get_integer_v API available in all impls, webrender requires GL 3.1 or GLES 3.0
+        unsafe {
+            gl.get_integer_v(gl::NUM_EXTENSIONS, &mut n);
+            gl.get_integer_v(gl::MAX_TEXTURE_SIZE, &mut max_size);
+        }
+        let s3tc = (0..n[0].max(0) as u32).any(|i| {
+            matches!(
+                gl.get_string_i(gl::EXTENSIONS, i).as_str(),
+                "GL_EXT_texture_compression_s3tc" | "GL_NV_texture_compression_s3tc"
+            )
+        });
+        if s3tc { Some(Self { max_size: max_size[0] }) } else { None }
+    }
+
+    /// If an image of the `size` can be a single compressed texture.
+    pub fn fits(&self, size: PxSize) -> bool {
+        size.width.0 > 0 && size.height.0 > 0 && size.width.0 <= self.max_size && size.height.0 <= self.max_size
+    }
+
+    /// Encode and upload the premultiplied BGRA8 `pixels` to a new texture.
+    ///
+    /// The context must be current, the current texture and unpack buffer bindings are restored.
+    pub fn upload(&self, gl: &dyn gl::Gl, size: PxSize, pixels: &[u8], is_opaque: bool) -> gl::GLuint {
+        let (format, blocks) = if is_opaque {
+            (COMPRESSED_RGB_S3TC_DXT1_EXT, encode_bc1(size, pixels))
+        } else {
+            (COMPRESSED_RGBA_S3TC_DXT5_EXT, encode_bc3(size, pixels))
+        };
+
+        let mut prev_texture = [0];
+        let mut prev_unpack = [0];
+        // SAFETY: get_integer_v API available in all impls
+        unsafe {
+            gl.get_integer_v(gl::TEXTURE_BINDING_2D, &mut prev_texture);
+            gl.get_integer_v(gl::PIXEL_UNPACK_BUFFER_BINDING, &mut prev_unpack);
+        }
+
+        let texture = gl.gen_textures(1)[0];
+        gl.bind_buffer(gl::PIXEL_UNPACK_BUFFER, 0);
+        gl.bind_texture(gl::TEXTURE_2D, texture);
+        gl.tex_parameter_i(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::LINEAR as _);
+        gl.tex_parameter_i(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::LINEAR as _);
+        gl.tex_parameter_i(gl::TEXTURE_2D, gl::TEXTURE_WRAP_S, gl::CLAMP_TO_EDGE as _);
+        gl.tex_parameter_i(gl::TEXTURE_2D, gl::TEXTURE_WRAP_T, gl::CLAMP_TO_EDGE as _);
+        gl.compressed_tex_image_2d(gl::TEXTURE_2D, 0, format, size.width.0, size.height.0, 0, &blocks);
+
+        gl.bind_texture(gl::TEXTURE_2D, prev_texture[0] as _);
+        gl.bind_buffer(gl::PIXEL_UNPACK_BUFFER, prev_unpack[0] as _);
+
+        texture
+    }
+}
+
+/// Encode BGRA8 pixels to BC1 blocks, alpha is ignored.
+pub(crate) fn encode_bc1(size: PxSize, pixels: &[u8]) -> Vec<u8> {
+    encode(size, pixels, 8, |block, out| {
+        encode_color(block, out);
+    })
+}
+
+/// Encode BGRA8 pixels to BC3 blocks.
+pub(crate) fn encode_bc3(size: PxSize, pixels: &[u8]) -> Vec<u8> {
+    encode(size, pixels, 16, |block, out| {
+        encode_alpha(block, &mut out[..8]);
+        encode_color(block, &mut out[8..]);
+    })
+}
+
+fn encode(size: PxSize, pixels: &[u8], block_len: usize, encode_block: impl Fn(&[[u8; 4]; 16], &mut [u8])) -> Vec<u8> {
+    let width = size.width.0.max(0) as usize;
+    let height = size.height.0.max(0) as usize;
+    debug_assert_eq!(pixels.len(), width * height * 4);
+
+    let blocks_x = width.div_ceil(4);
+    let blocks_y = height.div_ceil(4);
+    let mut out = vec![0; blocks_x * blocks_y * block_len];
+    let mut block = [[0u8; 4]; 16];
+    let mut out_blocks = out.chunks_exact_mut(block_len);
+    for by in 0..blocks_y {
+        for bx in 0..blocks_x {
+            for (i, px) in block.iter_mut().enumerate() {
+                // repeat the edge pixels in partial blocks
+                let x = (bx * 4 + i % 4).min(width - 1);
+                let y = (by * 4 + i / 4).min(height - 1);
+                let p = (y * width + x) * 4;
+                // BGRA to RGBA
+                *px = [pixels[p + 2], pixels[p + 1], pixels[p], pixels[p + 3]];
+            }
+            encode_block(&block, out_blocks.next().unwrap());
+        }
+    }
+    out
+}
+
+fn to_565(c: [u8; 3]) -> u16 {
+    ((c[0] as u16 >> 3) << 11) | ((c[1] as u16 >> 2) << 5) | (c[2] as u16 >> 3)
+}
+fn from_565(c: u16) -> [u8; 3] {
+    let r = ((c >> 11) & 0x1F) as u8;
+    let g = ((c >> 5) & 0x3F) as u8;
+    let b = (c & 0x1F) as u8;
+    [(r << 3) | (r >> 2), (g << 2) | (g >> 4), (b << 3) | (b >> 2)]
+}
+
+/// Encode the color block using the bounding box diagonal, always in the 4 colors mode.
+fn encode_color(block: &[[u8; 4]; 16], out: &mut [u8]) {
+    let min: [u8; 3] = std::array::from_fn(|c| block.iter().map(|px| px[c]).min().unwrap());
+    let max: [u8; 3] = std::array::from_fn(|c| block.iter().map(|px| px[c]).max().unwrap());
+    // inset the box to reduce the error of the interpolated colors
+    let inset: [u8; 3] = std::array::from_fn(|c| (max[c] - min[c]) / 16);
+    let min = std::array::from_fn(|c| min[c] + inset[c]);
+    let max = std::array::from_fn(|c| max[c] - inset[c]);
+
+    let mut c0 = to_565(max);
+    let mut c1 = to_565(min);
+    if c0 < c1 {
+        std::mem::swap(&mut c0, &mut c1);
+    }
+
+    let mut indices = 0u32;
+    if c0 != c1 {
+        let a = from_565(c0);
+        let b = from_565(c1);
+        let lerp = |w0: u16, w1: u16| -> [u8; 3] { std::array::from_fn(|c| ((a[c] as u16 * w0 + b[c] as u16 * w1) / 3) as u8) };
+        // index order of the BC1 4 colors mode
+        let palette = [a, b, lerp(2, 1), lerp(1, 2)];
+        for (i, px) in block.iter().enumerate() {
+            let best = nearest(palette.iter().map(|p| (0..3).map(|c| (p[c] as i32 - px[c] as i32).pow(2)).sum()));
+            indices |= (best as u32) << (i * 2);
+        }
+    }
+
+    out[0..2].copy_from_slice(&c0.to_le_bytes());
+    out[2..4].copy_from_slice(&c1.to_le_bytes());
+    out[4..8].copy_from_slice(&indices.to_le_bytes());
+}
+
+/// Encode the BC3 alpha block, always in the 8 values mode.
+fn encode_alpha(block: &[[u8; 4]; 16], out: &mut [u8]) {
+    let a0 = block.iter().map(|px| px[3]).max().unwrap();
+    let a1 = block.iter().map(|px| px[3]).min().unwrap();
+
+    let mut indices = 0u64;
+    if a0 != a1 {
+        // index order of the BC3 8 values mode
+        let palette: [u8; 8] = std::array::from_fn(|i| match i {
+            0 => a0,
+            1 => a1,
+            i => (((8 - i) as u16 * a0 as u16 + (i - 1) as u16 * a1 as u16) / 7) as u8,
+        });
+        for (i, px) in block.iter().enumerate() {
+            let best = nearest(palette.iter().map(|&p| (p as i32 - px[3] as i32).abs()));
+            indices |= (best as u64) << (i * 3);
+        }
+    }
+
+    out[0] = a0;
+    out[1] = a1;
+    out[2..8].copy_from_slice(&indices.to_le_bytes()[..6]);
+}
+
+fn nearest(distances: impl Iterator<Item = i32>) -> usize {
+    distances.enumerate().min_by_key(|(_, d)| *d).unwrap().0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zng_unit::Px;
+
+    fn decode_color(block: &[u8]) -> [[u8; 3]; 16] {
+        let c0 = u16::from_le_bytes([block[0], block[1]]);
+        let c1 = u16::from_le_bytes([block[2], block[3]]);
+        let indices = u32::from_le_bytes([block[4], block[5], block[6], block[7]]);
+        let a = from_565(c0);
+        let b = from_565(c1);
+        let palette = [
+            a,
+            b,
+            std::array::from_fn(|c| ((2 * a[c] as u16 + b[c] as u16) / 3) as u8),
+            std::array::from_fn(|c| ((a[c] as u16 + 2 * b[c] as u16) / 3) as u8),
+        ];
+        std::array::from_fn(|i| palette[((indices >> (i * 2)) & 0b11) as usize])
+    }
+
+    fn decode_alpha(block: &[u8]) -> [u8; 16] {
+        let (a0, a1) = (block[0] as u16, block[1] as u16);
+        let mut bits = [0; 8];
+        bits[..6].copy_from_slice(&block[2..8]);
+        let indices = u64::from_le_bytes(bits);
+        std::array::from_fn(|i| match (indices >> (i * 3)) & 0b111 {
+            0 => a0 as u8,
+            1 => a1 as u8,
+            j => (((8 - j as u16) * a0 + (j as u16 - 1) * a1) / 7) as u8,
+        })
+    }
+
+    fn bgra(size: PxSize, f: impl Fn(usize, usize) -> [u8; 4]) -> Vec<u8> {
+        let mut px = vec![];
+        for y in 0..size.height.0 as usize {
+            for x in 0..size.width.0 as usize {
+                px.extend_from_slice(&f(x, y));
+            }
+        }
+        px
+    }
+
+    #[test]
+    fn bc1_len() {
+        let size = PxSize::new(Px(10), Px(5));
+        let blocks = encode_bc1(size, &bgra(size, |_, _| [0, 0, 0, 255]));
+        assert_eq!(blocks.len(), 3 * 2 * 8);
+    }
+
+    #[test]
+    fn bc3_len() {
+        let size = PxSize::new(Px(4), Px(9));
+        let blocks = encode_bc3(size, &bgra(size, |_, _| [0, 0, 0, 255]));
+        assert_eq!(blocks.len(), 3 * 16);
+    }
+
+    #[test]
+    fn bc1_solid() {
+        let size = PxSize::splat(Px(4));
+        let blocks = encode_bc1(size, &bgra(size, |_, _| [0, 0, 255, 255]));
+        assert_eq!(decode_color(&blocks), [[255, 0, 0]; 16]);
+    }
+
+    #[test]
+    fn bc1_gradient() {
+        let size = PxSize::splat(Px(4));
+        let blocks = encode_bc1(size, &bgra(size, |x, _| [0, (x * 80) as u8, 0, 255]));
+        let decoded = decode_color(&blocks);
+        for (i, px) in decoded.iter().enumerate() {
+            let expected = (i % 4 * 80) as i32;
+            assert!((px[1] as i32 - expected).abs() <= 24, "{i}: {px:?} != {expected}");
+            assert_eq!((px[0], px[2]), (0, 0));
+        }
+    }
+
+    #[test]
+    fn bc3_alpha() {
+        let size = PxSize::splat(Px(4));
+        let blocks = encode_bc3(size, &bgra(size, |x, y| [0, 0, 0, ((y * 4 + x) * 17) as u8]));
+        let decoded = decode_alpha(&blocks[..8]);
+        for (i, a) in decoded.iter().enumerate() {
+            let expected = (i * 17) as i32;
+            assert!((*a as i32 - expected).abs() <= 19, "{i}: {a} != {expected}");
+        }
+        assert_eq!(decoded[0], 0);
+        assert_eq!(decoded[15], 255);
+    }
+
+    #[test]
+    fn partial_block_repeats_edge() {
+        let size = PxSize::new(Px(1), Px(1));
+        let blocks = encode_bc3(size, &[255, 0, 0, 128]);
+        assert_eq!(decode_alpha(&blocks[..8]), [128; 16]);
+        assert_eq!(decode_color(&blocks[8..]), [[0, 0, 255]; 16]);
+    }
+}
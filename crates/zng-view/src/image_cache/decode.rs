@@ -14,7 +14,7 @@ use zng_task::channel::IpcReadBlocking;
 use zng_txt::ToTxt as _;
 use zng_txt::Txt;
 use zng_unit::PxDensityUnits as _;
-use zng_unit::{Px, PxDensity2d, PxSize};
+use zng_unit::{Px, PxDensity2d, PxRect, PxSize};
 use zng_view_api::image::ImageDataFormat;
 use zng_view_api::image::ImageEntryKind;
 use zng_view_api::image::ImageMaskMode;
@@ -441,11 +441,27 @@ impl ImageCache {
         IpcDynamicImage::decode(buf, format, entry)
     }
 
+    /// Decode only the `rect` pixels, returns `None` if the format or image cannot decode regions.
+    #[cfg(feature = "_image_any")]
+    pub(super) fn decode_image_region(
+        buf: &mut IpcReadBlocking,
+        format: ContainerFormat,
+        entry: usize,
+        header: &ImageHeader,
+        rect: PxRect,
+    ) -> image::ImageResult<Option<IpcDynamicImage>> {
+        if !matches!(header.orientation, image::metadata::Orientation::NoTransforms) {
+            return Ok(None);
+        }
+        IpcDynamicImage::decode_region(buf, format, entry, rect)
+    }
+
     pub(super) fn convert_decoded(
         image: IpcDynamicImage,
         mask: Option<ImageMaskMode>,
         density: Option<PxDensity2d>,
         icc_profile: Option<&lcms2::Profile>,
+        crop: Option<PxRect>,
         downscale: Option<PxSize>,
         orientation: image::metadata::Orientation,
         resizer_cache: &ResizerCache,
@@ -800,6 +816,12 @@ impl ImageCache {
 
         let (mut size, mut pixels) = Self::apply_orientation(orientation, size, mask.is_some(), pixels)?;
 
+        if let Some(rect) = crop {
+            let (r, px) = Self::crop_decoded(mask.is_some(), size, &pixels, rect)?;
+            size = r.size;
+            pixels = px;
+        }
+
         if let Some((s, px)) = Self::downscale_decoded(mask, downscale, resizer_cache, size, &pixels)? {
             size = s;
             pixels = px;
@@ -1035,6 +1057,31 @@ impl ImageCache {
         ))
     }
 
+    /// Copy the `rect` pixels, `rect` is clamped to `size`, returns the clamped rect.
+    pub(super) fn crop_decoded(is_mask: bool, size: PxSize, pixels: &[u8], rect: PxRect) -> std::io::Result<(PxRect, IpcBytesMut)> {
+        let rect = match PxRect::from_size(size).intersection(&rect) {
+            Some(r) if !r.is_empty() => r,
+            _ => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "crop rect is outside the image",
+                ));
+            }
+        };
+
+        let bpp = if is_mask { 1 } else { 4 };
+        let row_len = size.width.0 as usize * bpp;
+        let x = rect.origin.x.0 as usize * bpp;
+        let crop_row_len = rect.size.width.0 as usize * bpp;
+
+        let mut crop = IpcBytesMut::new_blocking(crop_row_len * rect.size.height.0 as usize)?;
+        for (y, row) in (rect.origin.y.0 as usize..).zip(crop.chunks_exact_mut(crop_row_len)) {
+            let start = y * row_len + x;
+            row.copy_from_slice(&pixels[start..start + crop_row_len]);
+        }
+        Ok((rect, crop))
+    }
+
     pub(super) fn downscale_decoded(
         mask: Option<ImageMaskMode>,
         downscale: Option<PxSize>,
@@ -1095,3 +1142,37 @@ fn luminance_f32(r: f32, g: f32, b: f32) -> u8 {
     let l = r * 0.2126 + g * 0.7152 + b * 0.0722;
     (l * 255.0).clamp(0.0, 255.0) as u8
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zng_unit::PxPoint;
+
+    fn rect(x: i32, y: i32, w: i32, h: i32) -> PxRect {
+        PxRect::new(PxPoint::new(Px(x), Px(y)), PxSize::new(Px(w), Px(h)))
+    }
+
+    #[test]
+    fn crop_decoded_mask() {
+        // 4x3, each pixel is its index
+        let pixels: Vec<u8> = (0..12).collect();
+        let (r, crop) = ImageCache::crop_decoded(true, PxSize::new(Px(4), Px(3)), &pixels, rect(1, 1, 2, 2)).unwrap();
+        assert_eq!(rect(1, 1, 2, 2), r);
+        assert_eq!(&[5, 6, 9, 10], &crop[..]);
+    }
+
+    #[test]
+    fn crop_decoded_bgra_clamped() {
+        // 2x2, each pixel is [index; 4]
+        let pixels: Vec<u8> = (0..4).flat_map(|i| [i; 4]).collect();
+        let (r, crop) = ImageCache::crop_decoded(false, PxSize::new(Px(2), Px(2)), &pixels, rect(1, 0, 10, 10)).unwrap();
+        assert_eq!(rect(1, 0, 1, 2), r);
+        assert_eq!(&[1, 1, 1, 1, 3, 3, 3, 3], &crop[..]);
+    }
+
+    #[test]
+    fn crop_decoded_outside() {
+        let pixels = vec![0; 4];
+        assert!(ImageCache::crop_decoded(true, PxSize::new(Px(2), Px(2)), &pixels, rect(5, 5, 1, 1)).is_err());
+    }
+}
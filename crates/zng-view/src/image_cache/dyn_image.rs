@@ -3,6 +3,7 @@
 use image::{error::*, *};
 use zng_task::channel::IpcReadBlocking;
 use zng_task::channel::{IpcBytesMut, IpcBytesMutCast};
+use zng_unit::PxRect;
 
 use crate::image_cache::decode::ContainerFormat;
 
@@ -128,6 +129,21 @@ impl IpcDynamicImage {
         Self::from_decoded(buf, color_type, w, h)
     }
 
+    /// Decode only the `rect` pixels, returns `None` if the format or image cannot decode regions.
+    pub fn decode_region(
+        buf: &mut IpcReadBlocking,
+        format: ContainerFormat,
+        entry: usize,
+        rect: PxRect,
+    ) -> image::ImageResult<Option<Self>> {
+        #[cfg(feature = "image_tiff")]
+        if let ContainerFormat::Image(ImageFormat::Tiff) = format {
+            return Self::decode_tiff_region(buf, entry, rect);
+        }
+        let _ = (buf, format, entry, rect);
+        Ok(None)
+    }
+
     #[cfg(any(feature = "image_ico", feature = "image_cur"))]
     fn decode_ico(buf: &mut IpcReadBlocking, entry: usize) -> image::ImageResult<Self> {
         let icon = ico::IconDir::read(buf)?;
@@ -156,6 +172,72 @@ impl IpcDynamicImage {
         Self::from_decoded(buf, color_type, w, h)
     }
 
+    /// Only reads and decompresses the strips or tiles that intersect the `rect`.
+    #[cfg(feature = "image_tiff")]
+    fn decode_tiff_region(buf: &mut IpcReadBlocking, entry: usize, rect: PxRect) -> image::ImageResult<Option<Self>> {
+        let mut tiff = tiff::decoder::Decoder::new(buf).map_err(tiff_error)?;
+        tiff.seek_to_image(entry).map_err(tiff_error)?;
+
+        // only formats that decode to the same bytes layout as `read_image_bytes`
+        match tiff.colortype().map_err(tiff_error)? {
+            tiff::ColorType::Gray(8 | 16)
+            | tiff::ColorType::GrayA(8 | 16)
+            | tiff::ColorType::RGB(8 | 16 | 32)
+            | tiff::ColorType::RGBA(8 | 16 | 32) => {}
+            _ => return Ok(None),
+        }
+        let planar = tiff
+            .find_tag(tiff::tags::Tag::PlanarConfiguration)
+            .map_err(tiff_error)?
+            .and_then(|v| v.into_u16().ok())
+            .unwrap_or(1);
+        if planar != 1 {
+            return Ok(None);
+        }
+
+        let (w, _) = tiff.dimensions().map_err(tiff_error)?;
+        let color_type = tiff_color_type(&mut tiff)?;
+        let bpp = color_type.bytes_per_pixel() as usize;
+        let (chunk_w, chunk_h) = tiff.chunk_dimensions();
+        let chunks_across = w.div_ceil(chunk_w);
+
+        let (rx, ry) = (rect.origin.x.0 as u32, rect.origin.y.0 as u32);
+        let (rw, rh) = (rect.size.width.0 as u32, rect.size.height.0 as u32);
+        let row_len = rw as usize * bpp;
+        let mut pixels = Self::alloc_buf(row_len as u64 * rh as u64)?;
+
+        for cy in ry / chunk_h..(ry + rh).div_ceil(chunk_h) {
+            for cx in rx / chunk_w..(rx + rw).div_ceil(chunk_w) {
+                let index = cy * chunks_across + cx;
+                let (data_w, data_h) = tiff.chunk_data_dimensions(index);
+                let chunk = match tiff.read_chunk(index).map_err(tiff_error)? {
+                    tiff::decoder::DecodingResult::U8(b) => b,
+                    tiff::decoder::DecodingResult::U16(b) => b.into_iter().flat_map(|v| v.to_ne_bytes()).collect(),
+                    tiff::decoder::DecodingResult::F32(b) => b.into_iter().flat_map(|v| v.to_ne_bytes()).collect(),
+                    _ => return Ok(None),
+                };
+                let chunk_row_len = data_w as usize * bpp;
+
+                let (chunk_x, chunk_y) = (cx * chunk_w, cy * chunk_h);
+                let x0 = chunk_x.max(rx);
+                let x1 = (chunk_x + data_w).min(rx + rw);
+                let y0 = chunk_y.max(ry);
+                let y1 = (chunk_y + data_h).min(ry + rh);
+                if x0 >= x1 {
+                    continue;
+                }
+                let len = (x1 - x0) as usize * bpp;
+                for y in y0..y1 {
+                    let src = (y - chunk_y) as usize * chunk_row_len + (x0 - chunk_x) as usize * bpp;
+                    let dst = (y - ry) as usize * row_len + (x0 - rx) as usize * bpp;
+                    pixels[dst..dst + len].copy_from_slice(&chunk[src..src + len]);
+                }
+            }
+        }
+
+        Self::from_decoded(pixels, color_type, rw, rh).map(Some)
+    }
+
     fn from_decoded(buf: IpcBytesMut, color_type: image::ColorType, w: u32, h: u32) -> image::ImageResult<Self> {
         // copied from image-0.25.9\src\images\dynimage.rs
         match color_type {
@@ -4,7 +4,7 @@ use winit::{
 };
 use zng_task::channel::{IpcBytes, IpcBytesMut};
 use zng_txt::{ToTxt as _, formatx};
-use zng_unit::PxPoint;
+use zng_unit::{Px, PxPoint};
 use zng_view_api::{
     Event,
     image::{ImageEncodeId, ImageEncodeRequest, ImageEntryKind, ImageFormatCapability, ImageId},
@@ -107,6 +107,34 @@ impl ImageCache {
 impl Image {
     /// Generate a window icon from the image.
     pub fn icon(&self) -> Option<Icon> {
+        self.icon_max(255)
+    }
+
+    /// Generate a window icon from the image in the set that is nearest to `size`, prefers downscaling.
+    pub fn best_icon(images: &[Image], size: u32) -> Option<Icon> {
+        let img = images
+            .iter()
+            .filter(|i| !i.0.is_mask() && i.size().width > Px(0) && i.size().height > Px(0))
+            .min_by_key(|i| {
+                let s = i.size().width.0.min(i.size().height.0) as u32;
+                if s >= size { (0, s) } else { (1, u32::MAX - s) }
+            })?;
+        img.icon_max(size)
+    }
+
+    fn icon_max(&self, max_size: u32) -> Option<Icon> {
+        let (buf, width, height) = self.icon_rgba(max_size)?;
+        match winit::window::Icon::from_rgba(buf, width, height) {
+            Ok(i) => Some(i),
+            Err(e) => {
+                tracing::error!("failed to convert image to custom icon, {e}");
+                None
+            }
+        }
+    }
+
+    /// Generate RGBA8 pixels for an icon, resizes the image if it is larger than `max_size`.
+    pub fn icon_rgba(&self, max_size: u32) -> Option<(Vec<u8>, u32, u32)> {
         let (size, pixels) = match &*self.0 {
             ImageData::RawData { size, pixels, .. } => (size, pixels),
             ImageData::NativeTexture { .. } => unreachable!(),
@@ -117,29 +145,18 @@ impl Image {
         if width == 0 || height == 0 || self.0.is_mask() {
             None
         } else {
-            let r = if width > 255 || height > 255 {
-                // resize to max 255
-                let mut buf = pixels[..].to_vec();
-                bgra_pre_mul_to_rgba(&mut buf, self.is_opaque());
+            let mut buf = pixels[..].to_vec();
+            bgra_pre_mul_to_rgba(&mut buf, self.is_opaque());
+            if width > max_size || height > max_size {
                 let img = image::ImageBuffer::from_raw(width, height, buf).unwrap();
                 let img = image::DynamicImage::ImageRgba8(img);
-                let img = img.resize(255, 255, image::imageops::FilterType::Lanczos3);
+                let img = img.resize(max_size, max_size, image::imageops::FilterType::Lanczos3);
 
                 use image::GenericImageView;
                 let (width, height) = img.dimensions();
-                let buf = img.into_rgba8().into_raw();
-                winit::window::Icon::from_rgba(buf, width, height)
+                Some((img.into_rgba8().into_raw(), width, height))
             } else {
-                let mut buf = pixels[..].to_vec();
-                bgra_pre_mul_to_rgba(&mut buf, self.is_opaque());
-                winit::window::Icon::from_rgba(buf, width, height)
-            };
-            match r {
-                Ok(i) => Some(i),
-                Err(e) => {
-                    tracing::error!("failed to convert image to custom icon, {e}");
-                    None
-                }
+                Some((buf, width, height))
             }
         }
     }
@@ -1,11 +1,15 @@
-use std::{collections::hash_map::Entry, sync::Arc};
+use std::{
+    collections::hash_map::Entry,
+    rc::Rc,
+    sync::{Arc, Weak},
+};
 
 use rustc_hash::FxHashMap;
 use webrender::{
     RenderApi,
     api::{
         self as wr, DocumentId, ExternalImage, ExternalImageData, ExternalImageHandler, ExternalImageId, ExternalImageSource,
-        ExternalImageType, ImageKey,
+        ExternalImageType, ImageBufferKind, ImageKey,
         units::{ImageDirtyRect, TexelRect},
     },
 };
@@ -17,7 +21,7 @@ use crate::{
     px_wr::PxToWr as _,
 };
 
-use super::{Image, ImageData};
+use super::{Image, ImageData, TextureCompression};
 
 /// Implements [`ExternalImageHandler`].
 ///
@@ -26,10 +30,66 @@ use super::{Image, ImageData};
 /// This is only safe if use with [`ImageUseMap`].
 pub(crate) struct WrImageCache {
     locked: Vec<Arc<ImageData>>,
+    gl: Rc<dyn gleam::gl::Gl>,
+    compression: Option<TextureCompression>,
+    // compressed textures, created on first lock
+    textures: FxHashMap<ExternalImageId, (Weak<ImageData>, gleam::gl::GLuint)>,
 }
 impl WrImageCache {
-    pub fn new_boxed() -> Box<dyn ExternalImageHandler> {
-        Box::new(WrImageCache { locked: vec![] })
+    /// The `compression` must be the same used in the renderer [`ImageUseMap`].
+    pub fn new_boxed(gl: Rc<dyn gleam::gl::Gl>, compression: Option<TextureCompression>) -> Box<dyn ExternalImageHandler> {
+        Box::new(WrImageCache {
+            locked: vec![],
+            gl,
+            compression,
+            textures: FxHashMap::default(),
+        })
+    }
+
+    fn compressed_texture(&mut self, key: ExternalImageId, img: &Arc<ImageData>) -> gleam::gl::GLuint {
+        if let Some((weak, texture)) = self.textures.get(&key)
+            && weak.strong_count() > 0
+        {
+            return *texture;
+        }
+
+        // delete textures of dropped images, the `key` may be a reused pointer
+        let mut dead = vec![];
+        self.textures.retain(|k, (img, texture)| {
+            let retain = *k != key && img.strong_count() > 0;
+            if !retain {
+                dead.push(*texture);
+            }
+            retain
+        });
+        if !dead.is_empty() {
+            self.gl.delete_textures(&dead);
+        }
+
+        let texture = match &**img {
+            ImageData::RawData {
+                size,
+                pixels,
+                range,
+                is_opaque,
+                ..
+            } => self
+                .compression
+                .unwrap()
+                .upload(&*self.gl, *size, &pixels[range.clone()], *is_opaque),
+            ImageData::NativeTexture { .. } => unreachable!(),
+        };
+        self.textures.insert(key, (Arc::downgrade(img), texture));
+        texture
+    }
+}
+impl Drop for WrImageCache {
+    fn drop(&mut self) {
+        // dropped with the renderer on deinit, context is current
+        let textures: Vec<_> = self.textures.drain().map(|(_, (_, t))| t).collect();
+        if !textures.is_empty() {
+            self.gl.delete_textures(&textures);
+        }
     }
 }
 impl ExternalImageHandler for WrImageCache {
@@ -41,6 +101,16 @@ impl ExternalImageHandler for WrImageCache {
             Arc::<ImageData>::from_raw(ptr)
         };
 
+        if img.is_compressed_texture(self.compression) {
+            let texture = self.compressed_texture(key, &img);
+            let size = img.size();
+            self.locked.push(img);
+            return ExternalImage {
+                uv: TexelRect::new(0.0, 0.0, size.width.0 as f32, size.height.0 as f32),
+                source: ExternalImageSource::NativeTexture(texture),
+            };
+        }
+
         self.locked.push(img); // keep alive in case the image is removed mid-use
 
         match &**self.locked.last().unwrap() {
@@ -66,16 +136,42 @@ impl ExternalImageHandler for WrImageCache {
     }
 }
 
+impl ImageData {
+    /// If the image is uploaded as a compressed texture by [`WrImageCache`].
+    fn is_compressed_texture(&self, compression: Option<TextureCompression>) -> bool {
+        match self {
+            ImageData::RawData {
+                size,
+                compressed_texture: true,
+                pixels,
+                ..
+            } => compression.is_some_and(|c| c.fits(*size)) && !self.is_mask() && pixels.len() <= Image::SHOULD_STRIPE_LEN,
+            _ => false,
+        }
+    }
+
+    fn size(&self) -> PxSize {
+        match self {
+            ImageData::RawData { size, .. } => *size,
+            ImageData::NativeTexture { .. } => unreachable!(),
+        }
+    }
+}
+
 impl Image {
     fn external_id(&self) -> ExternalImageId {
         ExternalImageId(Arc::as_ptr(&self.0) as u64)
     }
 
-    fn data(&self) -> webrender::api::ImageData {
+    fn data(&self, compression: Option<TextureCompression>) -> webrender::api::ImageData {
         webrender::api::ImageData::External(ExternalImageData {
             id: self.external_id(),
             channel_index: 0,
-            image_type: ExternalImageType::Buffer,
+            image_type: if self.0.is_compressed_texture(compression) {
+                ExternalImageType::TextureHandle(ImageBufferKind::Texture2D)
+            } else {
+                ExternalImageType::Buffer
+            },
             normalized_uvs: false,
         })
     }
@@ -94,12 +190,15 @@ struct ImageUse {
 pub(crate) struct ImageUseMap {
     id_tex: FxHashMap<ExternalImageId, ImageUse>,
     tex_id: FxHashMap<ImageTextureId, ExternalImageId>,
+    compression: Option<TextureCompression>,
 }
 impl ImageUseMap {
-    pub fn new() -> Self {
+    /// The `compression` must be the same used in the renderer [`WrImageCache`].
+    pub fn new(compression: Option<TextureCompression>) -> Self {
         Self {
             id_tex: FxHashMap::default(),
             tex_id: FxHashMap::default(),
+            compression,
         }
     }
     pub fn new_use(&mut self, image: &Image, document_id: DocumentId, api: &mut RenderApi) -> ImageTextureId {
@@ -122,7 +221,7 @@ impl ImageUseMap {
                 // during `push_display_list_img` may register other derived images
                 // like gigapixel stripes or resized images.
                 let mut txn = webrender::Transaction::new();
-                txn.add_image(key, image.descriptor(), image.data(), None);
+                txn.add_image(key, image.descriptor(), image.data(self.compression), None);
                 api.send_transaction(document_id, txn);
 
                 tex_id
@@ -155,7 +254,7 @@ impl ImageUseMap {
                 txn.update_image(
                     ImageKey(api.get_namespace_id(), texture_id.get()),
                     image.descriptor(),
-                    image.data(),
+                    image.data(self.compression),
                     &match dirty_rect {
                         Some(r) => ImageDirtyRect::Partial(r.to_box2d().cast().cast_unit()),
                         None => ImageDirtyRect::All,
@@ -268,7 +367,7 @@ impl ImageUseMap {
                         let key = api.generate_image_key();
                         let tex_id = ImageTextureId::from_raw(key.1);
                         stripe_ids.push(tex_id);
-                        txn.add_image(key, stripe.descriptor(), stripe.data(), None);
+                        txn.add_image(key, stripe.descriptor(), stripe.data(None), None);
                     }
                     api.send_transaction(document_id, txn);
                     img.stripes = stripe_ids.into_boxed_slice();
@@ -120,6 +120,7 @@ mod audio_cache;
 mod config;
 mod display_list;
 mod gl;
+mod global_hotkey;
 mod image_cache;
 #[cfg(windows)]
 mod input_device_info;
@@ -127,6 +128,7 @@ mod low_memory;
 mod notification;
 mod px_wr;
 mod surface;
+mod tray_icon;
 mod util;
 mod window;
 
@@ -147,7 +149,7 @@ pub use gleam;
 use webrender::api::*;
 use window::Window;
 use zng_txt::Txt;
-use zng_unit::{Dip, DipPoint, DipRect, DipSideOffsets, DipSize, Factor, Px, PxPoint, PxRect, PxToDip};
+use zng_unit::{Dip, DipPoint, DipRect, DipSideOffsets, DipSize, Factor, Px, PxPoint, PxRect, PxSize, PxToDip, PxTransform};
 use zng_view_api::{
     ViewProcessInfo,
     api_extension::{ApiExtensionId, ApiExtensionPayload},
@@ -162,7 +164,7 @@ use zng_view_api::{
     window::{
         CursorIcon, CursorImage, EventCause, EventFrameRendered, FocusIndicator, FrameRequest, FrameUpdateRequest, FrameWaitId,
         HeadlessOpenData, HeadlessRequest, MonitorId, MonitorInfo, VideoMode, WindowChanged, WindowId, WindowOpenData, WindowRequest,
-        WindowState, WindowStateAll,
+        WindowShape, WindowState, WindowStateAll,
     },
     *,
 };
@@ -171,6 +173,7 @@ use rustc_hash::FxHashMap;
 
 use crate::{
     audio_cache::{AudioCache, AudioTrack},
+    global_hotkey::GlobalHotkeyService,
     notification::NotificationService,
 };
 
@@ -395,6 +398,8 @@ pub(crate) struct App {
     pressed_modifiers: FxHashMap<(Key, KeyLocation), (InputDeviceId, KeyCode)>,
     pending_modifiers_update: Option<ModifiersState>,
     pending_modifiers_focus_clear: bool,
+    #[cfg(not(any(windows, target_os = "macos")))]
+    last_modifiers: winit::event::Modifiers,
 
     #[cfg(not(any(windows, target_os = "android")))]
     arboard: Option<arboard::Clipboard>,
@@ -405,9 +410,12 @@ pub(crate) struct App {
     config_listener_exit: Option<Box<dyn FnOnce()>>,
 
     notifications: NotificationService,
+    global_hotkeys: GlobalHotkeyService,
+    tray_icon: crate::tray_icon::TrayIconService,
 
     app_state: AppState,
     drag_drop_hovered: Option<(WindowId, DipPoint)>,
+    drag_drop_effect: Option<DragDropEffect>,
     drag_drop_next_move: Option<(Instant, PathBuf)>,
     exited: bool,
 }
@@ -686,6 +694,7 @@ impl winit::application::ApplicationHandler<AppEvent> for App {
                     winit_loop.listen_device_events(winit::event_loop::DeviceEvents::Always);
                 }
                 self.drag_drop_hovered = Some((id, DipPoint::splat(Dip::new(-1000))));
+                self.drag_drop_effect = None;
                 self.notify(Event::DragHovered {
                     window: id,
                     data: vec![DragDropData::Paths(vec![file])],
@@ -716,12 +725,7 @@ impl winit::application::ApplicationHandler<AppEvent> for App {
                 if delay_to_next_move {
                     self.drag_drop_next_move = Some((Instant::now(), file));
                 } else {
-                    self.notify(Event::DragDropped {
-                        window: id,
-                        data: vec![DragDropData::Paths(vec![file])],
-                        allowed: DragDropEffect::all(),
-                        drop_id: DragDropId(0),
-                    });
+                    self.notify_drag_dropped(id, file);
                 }
             }
             WindowEvent::HoveredFileCancelled => {
@@ -819,6 +823,10 @@ impl winit::application::ApplicationHandler<AppEvent> for App {
             }
             WindowEvent::ModifiersChanged(m) => {
                 linux_modal_dialog_bail!();
+                #[cfg(not(any(windows, target_os = "macos")))]
+                {
+                    self.last_modifiers = m;
+                }
                 if self.windows[i].is_focused() {
                     self.pending_modifiers_update = Some(m.state());
                 }
@@ -854,12 +862,7 @@ impl winit::application::ApplicationHandler<AppEvent> for App {
                         coalesced_pos: vec![],
                         position: p,
                     });
-                    self.notify(Event::DragDropped {
-                        window: window_id,
-                        data: vec![DragDropData::Paths(vec![file])],
-                        allowed: DragDropEffect::all(),
-                        drop_id: DragDropId(0),
-                    });
+                    self.notify_drag_dropped(window_id, file);
                 }
             }
             WindowEvent::CursorEntered { device_id } => {
@@ -905,6 +908,23 @@ impl winit::application::ApplicationHandler<AppEvent> for App {
                     state: util::element_state_to_button_state(state),
                     button: util::winit_mouse_button_to_zng(button),
                 });
+
+                if state == winit::event::ElementState::Pressed && self.windows[i].is_cursor_in_drag_region() {
+                    match button {
+                        winit::event::MouseButton::Left => {
+                            if self.windows[i].drag_region_double_press(config::multi_click_config()) {
+                                self.maximize_toggle(id);
+                            } else {
+                                self.windows[i].drag_move();
+                            }
+                        }
+                        winit::event::MouseButton::Right => {
+                            let (pos, _) = self.windows[i].last_cursor_pos();
+                            self.windows[i].open_title_bar_context_menu(pos);
+                        }
+                        _ => {}
+                    }
+                }
             }
             WindowEvent::TouchpadPressure {
                 device_id,
@@ -958,6 +978,7 @@ impl winit::application::ApplicationHandler<AppEvent> for App {
             }
             WindowEvent::ScaleFactorChanged { .. } => {
                 self.refresh_monitors();
+                self.windows[i].scale_factor_changed();
                 // winit notified this event before any window state update, like monitor change, to avoid
                 // a layout pass with only the new scale and other stale state the factor change is only
                 // notified with other WindowChanged events, usually on ::Resized
@@ -1022,6 +1043,14 @@ impl winit::application::ApplicationHandler<AppEvent> for App {
             }
             AppEvent::Notify(ev) => self.notify(ev),
             AppEvent::WinitFocused(window_id, focused) => self.window_event(winit_loop, window_id, WindowEvent::Focused(focused)),
+            AppEvent::CheckInputRegion(window_id) => {
+                #[cfg(windows)]
+                if let Some(w) = self.windows.iter_mut().find(|w| w.window_id() == window_id) {
+                    w.check_input_region();
+                }
+                #[cfg(not(windows))]
+                let _ = window_id;
+            }
             AppEvent::RefreshMonitors => self.refresh_monitors(),
             AppEvent::ParentProcessExited => {
                 self.exited = true;
@@ -1315,7 +1344,7 @@ impl App {
                             AppEvent::RefreshMonitors => {
                                 panic!("no monitor info in headless mode")
                             }
-                            AppEvent::WinitFocused(_, _) => {
+                            AppEvent::WinitFocused(_, _) | AppEvent::CheckInputRegion(_) => {
                                 panic!("no winit event loop in headless mode")
                             }
                             AppEvent::ParentProcessExited => {
@@ -1447,12 +1476,17 @@ impl App {
             pressed_modifiers: FxHashMap::default(),
             pending_modifiers_update: None,
             pending_modifiers_focus_clear: false,
+            #[cfg(not(any(windows, target_os = "macos")))]
+            last_modifiers: Default::default(),
             config_listener_exit: None,
             drag_drop_hovered: None,
+            drag_drop_effect: None,
             drag_drop_next_move: None,
             #[cfg(not(any(windows, target_os = "android")))]
             arboard: None,
             notifications: NotificationService::default(),
+            global_hotkeys: GlobalHotkeyService::default(),
+            tray_icon: crate::tray_icon::TrayIconService::default(),
             low_memory_watcher: low_memory::LowMemoryWatcher::new(),
             last_pull_event: Instant::now(),
         }
@@ -1594,6 +1628,21 @@ impl App {
         }
     }
 
+    fn notify_drag_dropped(&mut self, window: WindowId, file: PathBuf) {
+        let allowed = self.drag_drop_effect.unwrap_or(DragDropEffect::all());
+        if allowed.is_empty() {
+            // app rejected the drop
+            self.notify(Event::DragCancelled { window });
+        } else {
+            self.notify(Event::DragDropped {
+                window,
+                data: vec![DragDropData::Paths(vec![file])],
+                allowed,
+                drop_id: DragDropId(0),
+            });
+        }
+    }
+
     pub(crate) fn notify(&mut self, event: Event) {
         let now = Instant::now();
         if let Some((mut coal, timestamp)) = self.coalescing_event.take() {
@@ -1850,6 +1899,8 @@ impl Api for App {
             info.input_device |= InputDeviceCapability::AXIS_MOTION;
             info.input_device |= InputDeviceCapability::POINTER_MOTION;
         }
+        info.keyboard = self.global_hotkeys.capabilities(headless);
+        info.menu |= self.tray_icon.capabilities(headless);
         info.image = crate::image_cache::FORMATS.to_vec();
         info.audio = crate::audio_cache::FORMATS.to_vec();
         info.extensions = self.exts.api_extensions();
@@ -1882,9 +1933,16 @@ impl Api for App {
             info.window |= WindowCapability::SET_TASKBAR_VISIBLE;
             info.window |= WindowCapability::OPEN_TITLE_BAR_CONTEXT_MENU;
             info.window |= WindowCapability::SET_SYSTEM_SHUTDOWN_WARN;
+            info.window |= WindowCapability::SET_SHAPE;
+            info.window |= WindowCapability::SET_OWNER;
         }
         if !headless && !cfg!(target_os = "android") && !cfg!(target_os = "macos") {
             info.window |= WindowCapability::DRAG_RESIZE;
+
+            if cfg!(windows) || std::env::var("WAYLAND_DISPLAY").is_err() {
+                // X11 SHAPE extension or Windows emulation
+                info.window |= WindowCapability::SET_INPUT_REGION;
+            }
         }
         // not headless, not Android and not Wayland
         if !headless && !cfg!(target_os = "android") && (!cfg!(unix) || std::env::var("WAYLAND_DISPLAY").is_err()) {
@@ -1982,6 +2040,10 @@ impl Api for App {
         let _ = self.app_sender.send(AppEvent::SetDeviceEventsFilter(filter));
     }
 
+    fn connected_devices(&mut self) -> Vec<(InputDeviceId, InputDeviceInfo)> {
+        self.devices.iter().map(|(id, _, info)| (*id, info.clone())).collect()
+    }
+
     fn open_window(&mut self, mut config: WindowRequest) {
         let _s = tracing::debug_span!("open_window", ?config).entered();
 
@@ -2026,6 +2088,12 @@ impl Api for App {
             }
 
             let id = config.id;
+            let owner = config.owner;
+            let icon_set = if config.icon_set.is_empty() {
+                vec![]
+            } else {
+                config.icon.iter().chain(&config.icon_set).copied().collect()
+            };
             let win = Window::open(
                 self.generation,
                 config.icon.and_then(|i| self.image_cache.get(i)).and_then(|i| i.icon()),
@@ -2050,9 +2118,17 @@ impl Api for App {
                 win.safe_padding(),
             );
             msg.refresh_rate = win.refresh_rate();
+            msg.swap_chain_depth = win.swap_chain_depth();
 
             self.windows.push(win);
 
+            if owner.is_some() {
+                self.set_window_owner(id, owner);
+            }
+            if !icon_set.is_empty() {
+                self.set_icon_set(id, icon_set);
+            }
+
             self.notify(Event::WindowOpened(id, msg));
 
             // winit does not notify focus for Android window
@@ -2073,6 +2149,22 @@ impl Api for App {
         self.notify(Event::HeadlessOpened(id, msg));
     }
 
+    fn prewarm_renderer(&mut self, render_mode: zng_view_api::window::RenderMode) {
+        let _s = tracing::debug_span!("prewarm_renderer", ?render_mode).entered();
+
+        self.assert_resumed();
+        let surf = Surface::open(
+            self.generation,
+            HeadlessRequest::new(WindowId::INVALID, Factor(1.0), DipSize::splat(Dip::new(1)), render_mode, vec![]),
+            &self.winit_loop,
+            &mut self.gl_manager,
+            self.exts.new_window(),
+            self.exts.new_renderer(),
+            self.app_sender.clone(),
+        );
+        drop(surf);
+    }
+
     fn close(&mut self, id: WindowId) {
         self.assert_resumed();
         if let Some(i) = self.windows.iter().position(|w| w.id() == id) {
@@ -2129,6 +2221,12 @@ impl Api for App {
         }
     }
 
+    fn reset_window_state(&mut self, id: WindowId) {
+        if let Some(state) = self.windows.iter().find(|w| w.id() == id).map(|w| w.initial_state()) {
+            self.set_state(id, state);
+        }
+    }
+
     fn set_headless_size(&mut self, renderer: WindowId, size: DipSize, scale_factor: Factor) {
         self.assert_resumed();
         if let Some(surf) = self.surfaces.iter_mut().find(|s| s.id() == renderer) {
@@ -2145,6 +2243,11 @@ impl Api for App {
         self.with_window(id, |w| w.set_icon(icon), || ())
     }
 
+    fn set_icon_set(&mut self, id: WindowId, icons: Vec<ImageId>) {
+        let icons: Vec<_> = icons.into_iter().filter_map(|i| self.image_cache.get(i).cloned()).collect();
+        self.with_window(id, |w| w.set_icon_set(icons), || ())
+    }
+
     fn set_focus_indicator(&mut self, id: WindowId, request: Option<FocusIndicator>) {
         self.with_window(id, |w| w.set_focus_request(request), || ())
     }
@@ -2163,6 +2266,115 @@ impl Api for App {
         }
     }
 
+    fn modifiers_state(&mut self, id: WindowId) -> zng_view_api::keyboard::ModifiersState {
+        use zng_view_api::keyboard::ModifiersState as M;
+
+        #[cfg(any(windows, target_os = "macos"))]
+        let _ = id;
+        let mut state = M::empty();
+
+        #[cfg(windows)]
+        {
+            use windows_sys::Win32::UI::Input::KeyboardAndMouse::*;
+
+            for (vk, flag) in [
+                (VK_LSHIFT, M::L_SHIFT),
+                (VK_RSHIFT, M::R_SHIFT),
+                (VK_LCONTROL, M::L_CTRL),
+                (VK_RCONTROL, M::R_CTRL),
+                (VK_LMENU, M::L_ALT),
+                (VK_RMENU, M::R_ALT),
+                (VK_LWIN, M::L_SUPER),
+                (VK_RWIN, M::R_SUPER),
+            ] {
+                // SAFETY: function can be called at any time, the high bit is set if the key is down.
+                if unsafe { GetAsyncKeyState(vk as _) } as u16 & 0x8000 != 0 {
+                    state |= flag;
+                }
+            }
+        }
+
+        #[cfg(target_os = "macos")]
+        {
+            use objc2_app_kit::NSEvent;
+
+            // device dependent masks, see `IOKit/hidsystem/IOLLEvent.h`
+            // SAFETY: class method reads the current modifier keys state.
+            let flags = unsafe { NSEvent::modifierFlags_class() }.0;
+            for (mask, flag) in [
+                (0x2, M::L_SHIFT),
+                (0x4, M::R_SHIFT),
+                (0x1, M::L_CTRL),
+                (0x2000, M::R_CTRL),
+                (0x20, M::L_ALT),
+                (0x40, M::R_ALT),
+                (0x8, M::L_SUPER),
+                (0x10, M::R_SUPER),
+            ] {
+                if flags & mask != 0 {
+                    state |= flag;
+                }
+            }
+        }
+
+        // X11 is queried, winit does not provide a query for other platforms, use the last state
+        #[cfg(not(any(windows, target_os = "macos")))]
+        {
+            #[cfg(any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ))]
+            let queried = self.with_window(id, |w| w.x11_modifiers_state(), || None);
+            #[cfg(not(any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            )))]
+            let queried = {
+                let _ = id;
+                None
+            };
+
+            if let Some(s) = queried {
+                state = s;
+            } else {
+                use winit::keyboard::ModifiersKeyState::Pressed;
+
+                let m = &self.last_modifiers;
+                for (any, left, right, l_flag, r_flag) in [
+                    (m.state().shift_key(), m.lshift_state(), m.rshift_state(), M::L_SHIFT, M::R_SHIFT),
+                    (
+                        m.state().control_key(),
+                        m.lcontrol_state(),
+                        m.rcontrol_state(),
+                        M::L_CTRL,
+                        M::R_CTRL,
+                    ),
+                    (m.state().alt_key(), m.lalt_state(), m.ralt_state(), M::L_ALT, M::R_ALT),
+                    (m.state().super_key(), m.lsuper_state(), m.rsuper_state(), M::L_SUPER, M::R_SUPER),
+                ] {
+                    if left == Pressed {
+                        state |= l_flag;
+                    }
+                    if right == Pressed {
+                        state |= r_flag;
+                    }
+                    if any && left != Pressed && right != Pressed {
+                        // side unknown
+                        state |= l_flag;
+                    }
+                }
+            }
+        }
+
+        state
+    }
+
     fn drag_move(&mut self, id: WindowId) {
         self.with_window(id, |w| w.drag_move(), || ())
     }
@@ -2171,10 +2383,63 @@ impl Api for App {
         self.with_window(id, |w| w.drag_resize(direction), || ())
     }
 
+    fn maximize_toggle(&mut self, id: WindowId) {
+        if let Some(w) = self.windows.iter().find(|w| w.id() == id) {
+            let mut state = w.state();
+            state.state = if state.state == WindowState::Maximized {
+                WindowState::Normal
+            } else {
+                WindowState::Maximized
+            };
+            self.set_state(id, state);
+        } else {
+            tracing::error!("headed window `{id:?}` not found, will return fallback result");
+        }
+    }
+
+    fn snap_window(&mut self, id: WindowId, zone: zng_view_api::window::SnapZone) {
+        if let Some(state) = self.with_window(id, |w| w.snap_state(zone), || None) {
+            self.set_state(id, state);
+        }
+    }
+
+    fn center_window(&mut self, id: WindowId, target: zng_view_api::window::CenterTarget) {
+        use zng_view_api::window::CenterTarget;
+
+        let area = match target {
+            CenterTarget::Monitor(m) => match self.monitor_ids.iter().find(|(i, _)| *i == m) {
+                Some((_, h)) => Some((crate::util::monitor_work_area(h), h.clone())),
+                None => {
+                    tracing::error!("monitor `{m:?}` not found, will center on the active monitor");
+                    None
+                }
+            },
+            CenterTarget::ParentWindow => self
+                .windows
+                .iter()
+                .find(|w| w.id() == id)
+                .and_then(|w| w.owner())
+                .and_then(|o| self.windows.iter().find(|w| w.id() == o))
+                .and_then(|o| o.center_area()),
+            _ => None,
+        };
+        if let Some(state) = self.with_window(id, |w| w.center_state(area), || None) {
+            self.set_state(id, state);
+        }
+    }
+
+    fn set_drag_region(&mut self, id: WindowId, region: Option<DipRect>) {
+        self.with_window(id, |w| w.set_drag_region(region), || ())
+    }
+
     fn set_enabled_buttons(&mut self, id: WindowId, buttons: zng_view_api::window::WindowButton) {
         self.with_window(id, |w| w.set_enabled_buttons(buttons), || ())
     }
 
+    fn set_window_transform(&mut self, id: WindowId, transform: PxTransform) {
+        self.with_window(id, |w| w.set_transform(transform), || ())
+    }
+
     fn open_title_bar_context_menu(&mut self, id: WindowId, position: DipPoint) {
         self.with_window(id, |w| w.open_title_bar_context_menu(position), || ())
     }
@@ -2299,6 +2564,12 @@ impl Api for App {
         })
     }
 
+    fn window_thumbnail(&mut self, id: WindowId, max_size: PxSize) -> ImageId {
+        with_window_or_surface!(self, id, |w| w.frame_thumbnail(&mut self.image_cache, max_size), || {
+            ImageId::INVALID
+        })
+    }
+
     fn render(&mut self, id: WindowId, frame: FrameRequest) {
         with_window_or_surface!(self, id, |w| w.render(frame), || ())
     }
@@ -2337,12 +2608,14 @@ impl Api for App {
 
     fn notification_dialog(&mut self, dialog: dialog::Notification) -> DialogId {
         let id = self.dialog_id_gen.incr();
-        self.notifications.notification_dialog(&self.app_sender, id, dialog);
+        let icon = self.image_cache.get(dialog.icon).and_then(|i| i.icon_rgba(256));
+        self.notifications.notification_dialog(&self.app_sender, id, dialog, icon);
         id
     }
 
     fn update_notification(&mut self, id: DialogId, dialog: dialog::Notification) {
-        self.notifications.update_notification(&self.app_sender, id, dialog);
+        let icon = self.image_cache.get(dialog.icon).and_then(|i| i.icon_rgba(256));
+        self.notifications.update_notification(&self.app_sender, id, dialog, icon);
     }
 
     #[cfg(windows)]
@@ -2569,16 +2842,59 @@ impl Api for App {
         let _ = (id, drop_id, applied);
     }
 
+    fn set_drop_effect(&mut self, id: WindowId, effect: DragDropEffect) {
+        if let Some((hovered, _)) = &self.drag_drop_hovered
+            && *hovered == id
+        {
+            self.drag_drop_effect = Some(effect);
+        }
+    }
+
+    fn register_global_hotkey(&mut self, hotkey: keyboard::GlobalHotkey) -> Result<keyboard::GlobalHotkeyId, keyboard::GlobalHotkeyError> {
+        self.global_hotkeys.register(&self.app_sender, hotkey)
+    }
+
+    fn unregister_global_hotkey(&mut self, id: keyboard::GlobalHotkeyId) {
+        self.global_hotkeys.unregister(id)
+    }
+
     fn set_system_shutdown_warn(&mut self, id: WindowId, reason: Txt) {
         self.with_window(id, move |w| w.set_system_shutdown_warn(reason), || ())
     }
 
+    fn set_swap_chain_depth(&mut self, id: WindowId, depth: u8) -> u8 {
+        self.with_window(id, |w| w.set_swap_chain_depth(depth), || 0)
+    }
+
+    fn set_window_shape(&mut self, id: WindowId, shape: Option<WindowShape>) {
+        let mask = match &shape {
+            Some(WindowShape::Mask(img)) => self.image_cache.get(*img).cloned(),
+            _ => None,
+        };
+        self.with_window(id, move |w| w.set_shape(shape, mask), || ())
+    }
+
+    fn set_window_owner(&mut self, id: WindowId, owner: Option<WindowId>) {
+        #[cfg(windows)]
+        {
+            let owner_hwnd = owner.and_then(|o| self.windows.iter().find(|w| w.id() == o)).map(|w| w.hwnd());
+            self.with_window(id, move |w| w.set_owner(owner, owner_hwnd), || ())
+        }
+        #[cfg(not(windows))]
+        self.with_window(id, move |w| w.set_owner(owner), || ())
+    }
+
+    fn set_input_region(&mut self, id: WindowId, region: Option<Vec<DipRect>>) {
+        self.with_window(id, move |w| w.set_input_region(region), || ())
+    }
+
     fn set_app_menu(&mut self, menu: menu::AppMenu) {
         let _ = menu;
     }
 
     fn set_tray_icon(&mut self, indicator: menu::TrayIcon) {
-        let _ = indicator;
+        let icon = self.image_cache.get(indicator.icon).and_then(|i| i.icon_rgba(64));
+        self.tray_icon.set(&self.app_sender, indicator, icon);
     }
 
     fn third_party_licenses(&mut self) -> Vec<zng_tp_licenses::LicenseUsed> {
@@ -2642,6 +2958,10 @@ pub(crate) enum AppEvent {
     #[cfg_attr(not(windows), allow(unused))]
     WinitFocused(winit::window::WindowId, bool),
 
+    /// Update cursor hit-test of a window with input region.
+    #[cfg_attr(not(windows), allow(unused))]
+    CheckInputRegion(winit::window::WindowId),
+
     /// Lost connection with app-process.
     ParentProcessExited,
 
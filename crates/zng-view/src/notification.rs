@@ -56,11 +56,25 @@ impl NotificationService {
         self.service.capabilities()
     }
 
-    pub fn notification_dialog(&mut self, app_sender: &AppEventSender, id: DialogId, dialog: Notification) {
-        self.service.notification_dialog(app_sender, id, dialog);
+    /// Show notification, `icon` is the RGBA8 pixels and size of the `dialog.icon`.
+    pub fn notification_dialog(
+        &mut self,
+        app_sender: &AppEventSender,
+        id: DialogId,
+        dialog: Notification,
+        icon: Option<(Vec<u8>, u32, u32)>,
+    ) {
+        self.service.notification_dialog(app_sender, id, dialog, icon);
     }
 
-    pub fn update_notification(&mut self, app_sender: &AppEventSender, id: DialogId, dialog: Notification) {
-        self.service.update_notification(app_sender, id, dialog);
+    /// Update notification, `icon` is the RGBA8 pixels and size of the `dialog.icon`.
+    pub fn update_notification(
+        &mut self,
+        app_sender: &AppEventSender,
+        id: DialogId,
+        dialog: Notification,
+        icon: Option<(Vec<u8>, u32, u32)>,
+    ) {
+        self.service.update_notification(app_sender, id, dialog, icon);
     }
 }
@@ -10,7 +10,14 @@ impl NotificationService {
         DialogCapability::NOTIFICATION
     }
 
-    pub fn notification_dialog(&mut self, app_sender: &AppEventSender, id: DialogId, dialog: Notification) {
+    pub fn notification_dialog(
+        &mut self,
+        app_sender: &AppEventSender,
+        id: DialogId,
+        dialog: Notification,
+        icon: Option<(Vec<u8>, u32, u32)>,
+    ) {
+        let _ = icon;
         let mut n = notify_rust::Notification::new();
         n.summary(&dialog.title).body(&dialog.message);
         if let Some(t) = dialog.timeout {
@@ -45,7 +52,13 @@ impl NotificationService {
         }
     }
 
-    pub fn update_notification(&mut self, app_sender: &AppEventSender, id: DialogId, dialog: Notification) {
-        super::other::NotificationService::default().update_notification(app_sender, id, dialog);
+    pub fn update_notification(
+        &mut self,
+        app_sender: &AppEventSender,
+        id: DialogId,
+        dialog: Notification,
+        icon: Option<(Vec<u8>, u32, u32)>,
+    ) {
+        super::other::NotificationService::default().update_notification(app_sender, id, dialog, icon);
     }
 }
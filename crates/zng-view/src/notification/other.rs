@@ -14,7 +14,14 @@ impl NotificationService {
         DialogCapability::empty()
     }
 
-    pub fn notification_dialog(&mut self, app_sender: &AppEventSender, id: DialogId, dialog: Notification) {
+    pub fn notification_dialog(
+        &mut self,
+        app_sender: &AppEventSender,
+        id: DialogId,
+        dialog: Notification,
+        icon: Option<(Vec<u8>, u32, u32)>,
+    ) {
+        let _ = icon;
         let _ = dialog;
         let _ = app_sender.send(AppEvent::Notify(Event::NotificationResponse(
             id,
@@ -23,8 +30,14 @@ impl NotificationService {
         tracing::error!("notification_dialog not implemented for {}", std::env::consts::OS);
     }
 
-    pub fn update_notification(&mut self, app_sender: &AppEventSender, id: DialogId, dialog: Notification) {
-        let _ = (app_sender, id, dialog);
+    pub fn update_notification(
+        &mut self,
+        app_sender: &AppEventSender,
+        id: DialogId,
+        dialog: Notification,
+        icon: Option<(Vec<u8>, u32, u32)>,
+    ) {
+        let _ = (app_sender, id, dialog, icon);
         tracing::error!("update_notification not implemented for {}", std::env::consts::OS);
     }
 }
@@ -72,7 +72,14 @@ impl NotificationService {
         };
     }
 
-    pub fn notification_dialog(&mut self, app_sender: &AppEventSender, id: DialogId, dialog: Notification) {
+    pub fn notification_dialog(
+        &mut self,
+        app_sender: &AppEventSender,
+        id: DialogId,
+        dialog: Notification,
+        icon: Option<(Vec<u8>, u32, u32)>,
+    ) {
+        let _ = icon;
         self.init();
 
         if let Some(notifier) = &self.notifier {
@@ -143,7 +150,15 @@ impl NotificationService {
         }
     }
 
-    pub fn update_notification<'a>(&'a mut self, app_sender: &AppEventSender, id: DialogId, dialog: Notification) {
+    pub fn update_notification<'a>(
+        &'a mut self,
+        app_sender: &AppEventSender,
+        id: DialogId,
+        dialog: Notification,
+        icon: Option<(Vec<u8>, u32, u32)>,
+    ) {
+        // the NOTIFICATION_ICON capability is not implemented for Windows, same as in `notification_dialog`
+        let _ = icon;
         if self.notifier.is_none() {
             return;
         }
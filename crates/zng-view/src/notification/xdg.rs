@@ -23,14 +23,28 @@ pub struct NotificationService {
 
 impl NotificationService {
     pub fn capabilities(&self) -> DialogCapability {
-        DialogCapability::NOTIFICATION | DialogCapability::NOTIFICATION_ACTIONS
+        DialogCapability::NOTIFICATION | DialogCapability::NOTIFICATION_ACTIONS | DialogCapability::NOTIFICATION_ICON
         // | DialogCapability::CLOSE_NOTIFICATION
         // | DialogCapability::UPDATE_NOTIFICATION
     }
 
-    pub fn notification_dialog(&mut self, app_sender: &AppEventSender, id: DialogId, dialog: Notification) {
+    pub fn notification_dialog(
+        &mut self,
+        app_sender: &AppEventSender,
+        id: DialogId,
+        dialog: Notification,
+        icon: Option<(Vec<u8>, u32, u32)>,
+    ) {
         let mut n = notify_rust::Notification::new();
         n.summary(&dialog.title).body(&dialog.message);
+        if let Some((rgba, width, height)) = icon {
+            match notify_rust::Image::from_rgba(width as i32, height as i32, rgba) {
+                Ok(img) => {
+                    n.image_data(img);
+                }
+                Err(e) => tracing::error!("failed to convert image to notification icon, {e}"),
+            }
+        }
         if let Some(t) = dialog.timeout {
             n.timeout(t);
         }
@@ -87,8 +101,14 @@ impl NotificationService {
             });
     }
 
-    pub fn update_notification(&mut self, app_sender: &AppEventSender, id: DialogId, dialog: Notification) {
-        let _ = (app_sender, id, dialog);
+    pub fn update_notification(
+        &mut self,
+        app_sender: &AppEventSender,
+        id: DialogId,
+        dialog: Notification,
+        icon: Option<(Vec<u8>, u32, u32)>,
+    ) {
+        let _ = (app_sender, id, dialog, icon);
         // if let Some(i) = self.handles.iter().position(|(i, _)| *i == id) {
         //     if let Some(t) = dialog.timeout
         //         && t == std::time::Duration::ZERO
@@ -100,6 +120,11 @@ impl NotificationService {
         //     }
         //     let n = &mut self.handles[i].1;
         //     n.summary(&dialog.title).body(&dialog.message);
+        //     if let Some((rgba, width, height)) = icon
+        //         && let Ok(img) = notify_rust::Image::from_rgba(width as i32, height as i32, rgba)
+        //     {
+        //         n.image_data(img);
+        //     }
         //     if let Some(t) = dialog.timeout {
         //         n.timeout(t);
         //     }
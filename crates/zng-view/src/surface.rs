@@ -7,7 +7,7 @@ use webrender::{
 };
 use winit::event_loop::ActiveEventLoop;
 use zng_txt::{Txt, formatx};
-use zng_unit::{DipSize, DipToPx, Factor, Px, PxRect, Rgba};
+use zng_unit::{DipSize, DipToPx, Factor, Px, PxRect, PxSize, Rgba};
 use zng_view_api::{
     ViewProcessGen,
     api_extension::{ApiExtensionId, ApiExtensionPayload},
@@ -24,7 +24,7 @@ use crate::{
         RendererDeinitedArgs, RendererExtension, RendererInitedArgs, WindowConfigArgs, WindowExtension,
     },
     gl::{GlContext, GlContextManager},
-    image_cache::{Image, ImageCache, ImageUseMap, WrImageCache},
+    image_cache::{Image, ImageCache, ImageUseMap, TextureCompression, WrImageCache},
     px_wr::PxToWr as _,
     util::{PxToWinit, frame_render_reasons, frame_update_render_reasons},
 };
@@ -140,7 +140,8 @@ impl Surface {
 
         let (mut renderer, sender) =
             webrender::create_webrender_instance(context.gl().clone(), WrNotifier::create(id, event_sender), opts, None).unwrap();
-        renderer.set_external_image_handler(WrImageCache::new_boxed());
+        let texture_compression = TextureCompression::query(&**context.gl());
+        renderer.set_external_image_handler(WrImageCache::new_boxed(context.gl().clone(), texture_compression));
 
         let mut external_images = extensions::ExternalImages::default();
 
@@ -175,7 +176,7 @@ impl Surface {
             renderer: Some(renderer),
             renderer_exts,
             external_images,
-            image_use: ImageUseMap::new(),
+            image_use: ImageUseMap::new(texture_compression),
 
             clear_color: None,
 
@@ -462,6 +463,7 @@ impl Surface {
                         PxRect::from_size(self.size.to_px(self.scale_factor)),
                         self.scale_factor,
                         mask,
+                        None,
                     )
                     .ok();
             }
@@ -477,12 +479,33 @@ impl Surface {
             self.rendered_frame_id,
             self.scale_factor,
             mask,
+            None,
         )
     }
 
     pub fn frame_image_rect(&mut self, images: &mut ImageCache, rect: PxRect, mask: Option<ImageMaskMode>) -> ImageId {
         let rect = PxRect::from_size(self.size.to_px(self.scale_factor)).intersection(&rect).unwrap();
-        images.frame_image(&**self.context.gl(), rect, self.id, self.rendered_frame_id, self.scale_factor, mask)
+        images.frame_image(
+            &**self.context.gl(),
+            rect,
+            self.id,
+            self.rendered_frame_id,
+            self.scale_factor,
+            mask,
+            None,
+        )
+    }
+
+    pub fn frame_thumbnail(&mut self, images: &mut ImageCache, max_size: PxSize) -> ImageId {
+        images.frame_image(
+            &**self.context.gl(),
+            PxRect::from_size(self.size.to_px(self.scale_factor)),
+            self.id,
+            self.rendered_frame_id,
+            self.scale_factor,
+            None,
+            Some(max_size),
+        )
     }
 
     /// Calls the render extension command.
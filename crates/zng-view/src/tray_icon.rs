@@ -0,0 +1,175 @@
+//! Tray icon status indicator.
+
+use zng_view_api::menu::{MenuCapability, TrayIcon};
+
+use crate::AppEventSender;
+
+#[cfg(any(windows, target_os = "macos"))]
+mod platform {
+    use std::sync::{Arc, Mutex};
+
+    use tray_icon::{
+        MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent,
+        menu::{IsMenuItem, Menu, MenuEvent, PredefinedMenuItem, Submenu},
+    };
+    use zng_txt::Txt;
+    use zng_view_api::{
+        Event,
+        menu::{MenuCapability, MenuItem, TrayIcon},
+    };
+
+    use crate::{AppEvent, AppEventSender};
+
+    #[derive(Default)]
+    pub struct TrayIconService {
+        tray: Option<tray_icon::TrayIcon>,
+        primary_command_id: Arc<Mutex<Txt>>,
+        handlers_set: bool,
+    }
+    impl TrayIconService {
+        pub fn capabilities(&self, headless: bool) -> MenuCapability {
+            if headless {
+                MenuCapability::empty()
+            } else {
+                MenuCapability::TRAY_ICON
+            }
+        }
+
+        fn set_handlers(&mut self, app_sender: &AppEventSender) {
+            if self.handlers_set {
+                return;
+            }
+            self.handlers_set = true;
+
+            let menu_sender = Mutex::new(app_sender.clone());
+            MenuEvent::set_event_handler(Some(move |ev: MenuEvent| {
+                let id = Txt::from_str(ev.id().as_ref());
+                let _ = menu_sender.lock().unwrap().send(AppEvent::Notify(Event::MenuCommand { id }));
+            }));
+
+            let tray_sender = Mutex::new(app_sender.clone());
+            let primary_command_id = self.primary_command_id.clone();
+            TrayIconEvent::set_event_handler(Some(move |ev: TrayIconEvent| {
+                if let TrayIconEvent::Click {
+                    button: MouseButton::Left,
+                    button_state: MouseButtonState::Up,
+                    ..
+                } = ev
+                {
+                    let id = primary_command_id.lock().unwrap().clone();
+                    if !id.is_empty() {
+                        let _ = tray_sender.lock().unwrap().send(AppEvent::Notify(Event::MenuCommand { id }));
+                    }
+                }
+            }));
+        }
+
+        pub fn set(&mut self, app_sender: &AppEventSender, tray: TrayIcon, icon: Option<(Vec<u8>, u32, u32)>) {
+            // always rebuild, the icon is only updated by the app on state changes
+            self.tray = None;
+
+            let icon = match icon {
+                Some((rgba, width, height)) => match tray_icon::Icon::from_rgba(rgba, width, height) {
+                    Ok(i) => i,
+                    Err(e) => {
+                        tracing::error!("failed to convert image to tray icon, {e}");
+                        return;
+                    }
+                },
+                None => return,
+            };
+
+            self.set_handlers(app_sender);
+            *self.primary_command_id.lock().unwrap() = tray.primary_command_id.clone();
+
+            let mut builder = TrayIconBuilder::new()
+                .with_icon(icon)
+                .with_menu_on_left_click(tray.primary_command_id.is_empty());
+            if !tray.tooltip.is_empty() {
+                builder = builder.with_tooltip(tray.tooltip.as_str());
+            }
+            if !tray.context_menu.is_empty() {
+                let menu = Menu::new();
+                append_items(&tray.context_menu, &mut |item| {
+                    if let Err(e) = menu.append(item) {
+                        tracing::error!("failed to build tray menu, {e}");
+                    }
+                });
+                builder = builder.with_menu(Box::new(menu));
+            }
+
+            match builder.build() {
+                Ok(t) => self.tray = Some(t),
+                Err(e) => tracing::error!("failed to create tray icon, {e}"),
+            }
+        }
+    }
+    impl Drop for TrayIconService {
+        fn drop(&mut self) {
+            self.tray = None;
+            if self.handlers_set {
+                MenuEvent::set_event_handler(None::<fn(MenuEvent)>);
+                TrayIconEvent::set_event_handler(None::<fn(TrayIconEvent)>);
+            }
+        }
+    }
+
+    fn append_items(items: &[MenuItem], append: &mut dyn FnMut(&dyn IsMenuItem)) {
+        for item in items {
+            match item {
+                MenuItem::Command { id, label, .. } => {
+                    let item = tray_icon::menu::MenuItem::with_id(id.as_str(), label.as_str(), !id.is_empty(), None);
+                    append(&item);
+                }
+                MenuItem::SubMenu { label, children, .. } => {
+                    let sub = Submenu::new(label.as_str(), true);
+                    append_items(children, &mut |item| {
+                        if let Err(e) = sub.append(item) {
+                            tracing::error!("failed to build tray submenu, {e}");
+                        }
+                    });
+                    append(&sub);
+                }
+                MenuItem::Separator => append(&PredefinedMenuItem::separator()),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(not(any(windows, target_os = "macos")))]
+mod platform {
+    use zng_view_api::menu::{MenuCapability, TrayIcon};
+
+    use crate::AppEventSender;
+
+    #[derive(Default)]
+    pub struct TrayIconService {}
+    impl TrayIconService {
+        pub fn capabilities(&self, _: bool) -> MenuCapability {
+            MenuCapability::empty()
+        }
+
+        pub fn set(&mut self, _: &AppEventSender, tray: TrayIcon, icon: Option<(Vec<u8>, u32, u32)>) {
+            let _ = tray;
+            if icon.is_some() {
+                tracing::error!("set_tray_icon not implemented for {}", std::env::consts::OS);
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct TrayIconService {
+    service: platform::TrayIconService,
+}
+impl TrayIconService {
+    pub fn capabilities(&self, headless: bool) -> MenuCapability {
+        self.service.capabilities(headless)
+    }
+
+    /// Set or remove the tray icon, `icon` is the RGBA8 pixels and size.
+    pub fn set(&mut self, app_sender: &AppEventSender, tray: TrayIcon, icon: Option<(Vec<u8>, u32, u32)>) {
+        self.service.set(app_sender, tray, icon)
+    }
+}
@@ -308,9 +308,36 @@ pub(crate) fn monitor_handle_to_info(handle: &MonitorHandle, is_primary: bool, n
     if let Some(mhz) = handle.refresh_rate_millihertz() {
         m.refresh_rate = Frequency::from_millihertz(mhz as _);
     }
+    m.work_area = monitor_work_area(handle);
     m.is_primary = is_primary;
     m
 }
+
+/// Gets the monitor region not covered by the taskbar or other system UI, in the virtual screen.
+pub(crate) fn monitor_work_area(handle: &MonitorHandle) -> PxRect {
+    #[cfg(windows)]
+    {
+        use windows::Win32::Graphics::Gdi::*;
+        use winit::platform::windows::MonitorHandleExtWindows;
+
+        let hmonitor = HMONITOR(handle.hmonitor() as _);
+        let mut monitor_info = MONITORINFO {
+            cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+            ..Default::default()
+        };
+        // SAFETY: this is the correct way to call
+        // https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getmonitorinfow
+        if unsafe { GetMonitorInfoW(hmonitor, &mut monitor_info) }.as_bool() {
+            let r = monitor_info.rcWork;
+            return PxRect::new(
+                PxPoint::new(Px(r.left), Px(r.top)),
+                PxSize::new(Px(r.right - r.left), Px(r.bottom - r.top)),
+            );
+        }
+    }
+
+    PxRect::new(handle.position().to_px(), handle.size().to_px())
+}
 #[cfg(windows)]
 fn windows_monitor_name(handle: &MonitorHandle, n: usize) -> Txt {
     use windows::Win32::Devices::Display::*;
@@ -1561,6 +1588,7 @@ fn access_node_to_kit(
                 builder.set_scroll_y_min(0.0);
                 builder.set_scroll_y_max(1.0);
             }
+            RoleDescription(d) => builder.set_role_description(d.clone().into_owned().into_boxed_str()),
             _ => {}
         }
     }
@@ -5,6 +5,7 @@ use std::{
         Arc,
         atomic::{AtomicBool, Ordering},
     },
+    time::Instant,
 };
 
 use tracing::span::EnteredSpan;
@@ -19,16 +20,20 @@ use winit::{
     window::{CustomCursor, Fullscreen, Icon, Window as GWindow, WindowAttributes},
 };
 use zng_txt::{ToTxt, Txt, formatx};
-use zng_unit::{Dip, DipPoint, DipRect, DipSideOffsets, DipSize, DipToPx, Factor, Frequency, Px, PxPoint, PxRect, PxToDip, PxVector, Rgba};
+use zng_unit::{
+    Dip, DipPoint, DipRect, DipSideOffsets, DipSize, DipToPx, Factor, Frequency, Px, PxPoint, PxRect, PxSize, PxToDip, PxTransform,
+    PxVector, Rgba,
+};
 use zng_view_api::{
     Event, ViewProcessGen,
     api_extension::{ApiExtensionId, ApiExtensionPayload},
+    config::MultiClickConfig,
     font::{FontFaceId, FontId, FontOptions, FontVariationName, IpcFontBytes},
     image::{ImageDecoded, ImageId, ImageMaskMode, ImageTextureId},
     raw_input::InputDeviceId,
     window::{
-        CursorIcon, FocusIndicator, FrameCapture, FrameId, FrameRequest, FrameUpdateRequest, RenderMode, ResizeDirection, VideoMode,
-        WindowButton, WindowId, WindowRequest, WindowState, WindowStateAll,
+        CursorIcon, FocusIndicator, FrameCapture, FrameId, FrameRequest, FrameUpdateRequest, RenderMode, ResizeDirection, SnapZone,
+        VideoMode, WindowButton, WindowId, WindowRequest, WindowShape, WindowState, WindowStateAll,
     },
 };
 
@@ -46,7 +51,7 @@ use crate::{
         WindowExtension, WindowInitedArgs,
     },
     gl::{GlContext, GlContextManager},
-    image_cache::{Image, ImageCache, ImageUseMap, WrImageCache},
+    image_cache::{Image, ImageCache, ImageUseMap, TextureCompression, WrImageCache},
     px_wr::PxToWr as _,
     util::{
         CursorToWinit, DipToWinit, PxToWinit, ResizeDirectionToWinit as _, WindowButtonsToWinit as _, WinitToDip, WinitToPx,
@@ -54,6 +59,10 @@ use crate::{
     },
 };
 
+/// Timer ID used to check the cursor position over the input region.
+#[cfg(windows)]
+const INPUT_REGION_TIMER: usize = u32::from_ne_bytes(*b"inrg") as _;
+
 /// A headed window.
 pub(crate) struct Window {
     id: WindowId,
@@ -83,6 +92,9 @@ pub(crate) struct Window {
     video_mode: VideoMode,
 
     state: WindowStateAll,
+    initial_state: WindowStateAll,
+    maximized_to_work_area: bool, // chromeless windows are maximized by filling the monitor work area
+    snap: Option<(SnapZone, DipRect, DipRect)>, // (zone, pre-snap restore_rect, snapped restore_rect)
 
     prev_pos: PxPoint, // in the global space
     prev_size: DipSize,
@@ -104,6 +116,8 @@ pub(crate) struct Window {
     cursor_pos: DipPoint,
     cursor_device: InputDeviceId,
     cursor_over: bool,
+    drag_region: Option<DipRect>,
+    drag_region_press: Option<(Instant, DipPoint)>,
 
     touch_pos: Vec<((InputDeviceId, u64), DipPoint)>,
 
@@ -118,6 +132,14 @@ pub(crate) struct Window {
     ime_area: Option<DipRect>,
     #[cfg(windows)]
     has_shutdown_warn: bool,
+    shape: Option<(WindowShape, Option<Image>)>,
+    input_region: Option<Vec<DipRect>>,
+    #[cfg(windows)]
+    has_input_region: Arc<AtomicBool>,
+    #[cfg(windows)]
+    cursor_hittest: bool,
+    owner: Option<WindowId>,
+    icon_set: Vec<Image>,
 
     cursor: Option<CursorIcon>,
     cursor_img: Option<CustomCursor>,
@@ -278,9 +300,13 @@ impl Window {
         // * Extend the winit Windows window to not block the Alt+F4 key press.
         // * Check if the window is actually keyboard focused until first focus.
         // * Block system shutdown if a block is set.
+        // * Check the input region on cursor hit-test.
+        #[cfg(windows)]
+        let has_input_region = Arc::new(AtomicBool::new(false));
         #[cfg(windows)]
         {
             let event_sender = event_sender.clone();
+            let has_input_region = has_input_region.clone();
 
             let mut first_focus = false;
 
@@ -317,6 +343,14 @@ impl Window {
                         }));
                         return Some(0);
                     }
+                    windows_sys::Win32::UI::WindowsAndMessaging::WM_TIMER if wparam == INPUT_REGION_TIMER => {
+                        let _ = event_sender.send(AppEvent::CheckInputRegion(window_id));
+                        return Some(0);
+                    }
+                    windows_sys::Win32::UI::WindowsAndMessaging::WM_NCHITTEST if has_input_region.load(Ordering::Relaxed) => {
+                        // cursor moved over the window, continue default handling
+                        let _ = event_sender.send(AppEvent::CheckInputRegion(window_id));
+                    }
                     windows_sys::Win32::UI::WindowsAndMessaging::WM_QUERYENDSESSION => {
                         let mut reason = [0u16; 256];
                         let mut reason_size = reason.len() as u32;
@@ -395,7 +429,8 @@ impl Window {
 
         let (mut renderer, sender) =
             webrender::create_webrender_instance(context.gl().clone(), WrNotifier::create(id, event_sender.clone()), opts, None).unwrap();
-        renderer.set_external_image_handler(WrImageCache::new_boxed());
+        let texture_compression = TextureCompression::query(&**context.gl());
+        renderer.set_external_image_handler(WrImageCache::new_boxed(context.gl().clone(), texture_compression));
 
         let mut external_images = extensions::ExternalImages::default();
 
@@ -435,12 +470,15 @@ impl Window {
 
         let mut win = Self {
             id,
-            image_use: ImageUseMap::new(),
+            image_use: ImageUseMap::new(texture_compression),
+            maximized_to_work_area: false,
+            snap: None,
             prev_pos: winit_window.inner_position().unwrap_or_default().to_px(),
             prev_size: winit_window.inner_size().to_px().to_dip(Factor(winit_window.scale_factor() as _)),
             prev_monitor: None,
             prev_refresh_rate: Frequency::from_hertz(60.0),
             prev_scale_factor: Factor(1.0),
+            initial_state: s.clone(),
             state: s,
             kiosk: cfg.kiosk,
             window: winit_window,
@@ -469,6 +507,8 @@ impl Window {
             touch_pos: vec![],
             cursor_device: InputDeviceId::INVALID,
             cursor_over: false,
+            drag_region: None,
+            drag_region_press: None,
             clear_color: None,
             focused: None,
             modal_dialog_active: Arc::new(AtomicBool::new(false)),
@@ -477,6 +517,14 @@ impl Window {
             ime_area: cfg.ime_area,
             #[cfg(windows)]
             has_shutdown_warn: false,
+            shape: None,
+            input_region: None,
+            #[cfg(windows)]
+            has_input_region,
+            #[cfg(windows)]
+            cursor_hittest: true,
+            owner: None,
+            icon_set: vec![],
             cursor: None,
             cursor_img: None,
 
@@ -519,6 +567,8 @@ impl Window {
             win.set_system_shutdown_warn(cfg.system_shutdown_warn);
         }
 
+        let _ = win.set_swap_chain_depth(cfg.swap_chain_depth);
+
         if win.ime_area.is_some() {
             win.window.set_ime_allowed(true);
         }
@@ -852,6 +902,10 @@ impl Window {
             self.prev_size = new_size;
             self.resized = true;
 
+            if let Some((WindowShape::RoundedRect(_), _)) = &self.shape {
+                self.apply_shape();
+            }
+
             Some(new_size)
         } else {
             None
@@ -968,9 +1022,49 @@ impl Window {
     }
 
     pub fn set_icon(&mut self, icon: Option<Icon>) {
+        self.icon_set.clear();
         self.window.set_window_icon(icon);
     }
 
+    /// Set the icon from images of the same icon at different sizes.
+    ///
+    /// The icon is selected again when the scale factor changes.
+    pub fn set_icon_set(&mut self, icons: Vec<Image>) {
+        self.icon_set = icons;
+        self.apply_icon_set();
+    }
+
+    /// Select the icon from the icon set for the new scale factor.
+    pub fn scale_factor_changed(&mut self) {
+        if !self.icon_set.is_empty() {
+            self.apply_icon_set();
+        }
+    }
+
+    fn apply_icon_set(&mut self) {
+        let icons = &self.icon_set[..];
+        let scale = self.window.scale_factor();
+
+        #[cfg(windows)]
+        {
+            use winit::platform::windows::WindowExtWindows as _;
+
+            // title bar uses the small icon, taskbar and Alt+Tab use the big icon
+            let small = (16.0 * scale).round() as u32;
+            let big = (32.0 * scale).round() as u32;
+            self.window.set_window_icon(Image::best_icon(icons, small));
+            self.window.set_taskbar_icon(Image::best_icon(icons, big));
+        }
+
+        #[cfg(not(windows))]
+        {
+            // only one icon can be set, the system resizes it for each place it is shown,
+            // select the size for the largest common use, the task switcher
+            let size = (64.0 * scale).round() as u32;
+            self.window.set_window_icon(Image::best_icon(icons, size));
+        }
+    }
+
     /// Set named cursor.
     pub fn set_cursor(&mut self, icon: Option<CursorIcon>) {
         self.cursor = icon;
@@ -1068,6 +1162,31 @@ impl Window {
         }
     }
 
+    /// Gets if the window is still filling the monitor work area after a maximize request without system chrome.
+    fn is_maximized_to_work_area(&self) -> bool {
+        self.maximized_to_work_area
+            && self.window.current_monitor().is_some_and(|m| {
+                let work_area = crate::util::monitor_work_area(&m);
+                self.window.outer_position().is_ok_and(|p| p.to_px() == work_area.origin)
+                    && self.window.outer_size().to_px() == work_area.size
+            })
+    }
+
+    /// Maximize by filling the monitor work area, returns `false` if the monitor is not found.
+    ///
+    /// System maximize can cover the taskbar for windows without system chrome in some systems.
+    fn maximize_to_work_area(&mut self) -> bool {
+        if let Some(m) = self.window.current_monitor() {
+            let work_area = crate::util::monitor_work_area(&m);
+            self.maximized_to_work_area = true;
+            self.window.set_outer_position(work_area.origin.to_winit());
+            let _ = self.window.request_inner_size(work_area.size.to_winit());
+            true
+        } else {
+            false
+        }
+    }
+
     /// Gets the current Maximized status.
     fn is_minimized(&self) -> bool {
         let size = self.window.inner_size();
@@ -1104,7 +1223,7 @@ impl Window {
                 Fullscreen::Exclusive(_) => WindowState::Exclusive,
                 Fullscreen::Borderless(_) => WindowState::Fullscreen,
             };
-        } else if self.is_maximized() {
+        } else if self.is_maximized() || self.is_maximized_to_work_area() {
             state.state = WindowState::Maximized;
         } else {
             state.state = WindowState::Normal;
@@ -1276,6 +1395,11 @@ impl Window {
         self.state.clone()
     }
 
+    /// State the window was opened with.
+    pub fn initial_state(&self) -> WindowStateAll {
+        self.initial_state.clone()
+    }
+
     fn set_inner_position(&self, pos: DipPoint) {
         let monitor_offset = if let Some(m) = self.window.current_monitor() {
             m.position().to_px().to_vector()
@@ -1323,11 +1447,134 @@ impl Window {
         }
     }
 
+    /// Set the region that starts a drag move when pressed.
+    pub fn set_drag_region(&mut self, region: Option<DipRect>) {
+        self.drag_region = region;
+    }
+
+    /// If the cursor is over the window and inside the drag region.
+    pub fn is_cursor_in_drag_region(&self) -> bool {
+        self.cursor_over && self.movable && self.drag_region.is_some_and(|r| r.contains(self.cursor_pos))
+    }
+
+    /// Compute the `Normal` state that fills the `zone` in the current monitor work area.
+    ///
+    /// The `restore_rect` before the first snap is preserved, snapping again to the same zone restores it.
+    pub fn snap_state(&mut self, zone: SnapZone) -> Option<WindowStateAll> {
+        let pre_snap_rect = match self.snap.take() {
+            // still snapped
+            Some((z, pre, snapped)) if self.state.state == WindowState::Normal && self.state.restore_rect == snapped => {
+                if z == zone {
+                    let mut state = self.state.clone();
+                    state.restore_rect = pre;
+                    return Some(state);
+                }
+                pre
+            }
+            _ => self.state.restore_rect,
+        };
+
+        let monitor = self.window.current_monitor()?;
+        let scale_factor = Factor(monitor.scale_factor() as _);
+        let mut rect = zone.rect(crate::util::monitor_work_area(&monitor));
+
+        // zone is the outer rect, remove the system chrome
+        if let (Ok(outer_pos), Ok(inner_pos)) = (self.window.outer_position(), self.window.inner_position()) {
+            let outer_size = self.window.outer_size();
+            let inner_size = self.window.inner_size();
+            rect.origin.x += Px(inner_pos.x - outer_pos.x);
+            rect.origin.y += Px(inner_pos.y - outer_pos.y);
+            rect.size.width -= Px((outer_size.width - inner_size.width) as i32);
+            rect.size.height -= Px((outer_size.height - inner_size.height) as i32);
+        }
+
+        let mut state = self.state.clone();
+        state.state = WindowState::Normal;
+        state.global_position = rect.origin;
+        rect.origin -= monitor.position().to_px().to_vector();
+        state.restore_rect = rect.to_dip(scale_factor);
+        self.snap = Some((zone, pre_snap_rect, state.restore_rect));
+        Some(state)
+    }
+
+    /// Compute the state that centers the window in the `area` or in the current monitor work area.
+    ///
+    /// If the window is not in the `Normal` state only the restore rectangle is centered.
+    pub fn center_state(&self, area: Option<(PxRect, MonitorHandle)>) -> Option<WindowStateAll> {
+        let (area, monitor) = match area {
+            Some(a) => a,
+            None => {
+                let monitor = self.window.current_monitor()?;
+                (crate::util::monitor_work_area(&monitor), monitor)
+            }
+        };
+        let scale_factor = Factor(monitor.scale_factor() as _);
+
+        // center the outer rect, offset to the inner position
+        let outer_size = self.window.outer_size().to_px();
+        let mut pos = area.origin + ((area.size - outer_size) / Px(2)).to_vector();
+        if let (Ok(outer_pos), Ok(inner_pos)) = (self.window.outer_position(), self.window.inner_position()) {
+            pos.x += Px(inner_pos.x - outer_pos.x);
+            pos.y += Px(inner_pos.y - outer_pos.y);
+        }
+
+        let mut state = self.state.clone();
+        if state.state == WindowState::Normal {
+            state.global_position = pos;
+        }
+        state.restore_rect.origin = (pos - monitor.position().to_px().to_vector()).to_dip(scale_factor);
+        Some(state)
+    }
+
+    /// Register a left mouse press in the drag region, returns `true` if it is the second press of a double-click.
+    pub fn drag_region_double_press(&mut self, cfg: MultiClickConfig) -> bool {
+        let now = Instant::now();
+        let pos = self.cursor_pos;
+        if let Some((t, p)) = self.drag_region_press.take()
+            && now.duration_since(t) <= cfg.time
+            && (pos.x - p.x).abs() <= cfg.area.width
+            && (pos.y - p.y).abs() <= cfg.area.height
+        {
+            return true;
+        }
+        self.drag_region_press = Some((now, pos));
+        false
+    }
+
     /// Set enabled chrome buttons.
     pub fn set_enabled_buttons(&self, buttons: WindowButton) {
         self.window.set_enabled_buttons(buttons.to_winit());
     }
 
+    /// Set the window content render transform.
+    ///
+    /// The transform is applied to the latest frame immediately and to all subsequent frames, the window position is not affected.
+    pub fn set_transform(&mut self, transform: PxTransform) {
+        if !self.display_list_cache.set_root_transform(transform) {
+            return;
+        }
+
+        let mut txn = Transaction::new();
+        txn.set_root_pipeline(self.pipeline_id);
+        if let Some(display_list) = self.display_list_cache.rebuild(
+            &mut DisplayListExtAdapter {
+                frame_id: self.frame_id(),
+                extensions: &mut self.renderer_exts,
+                transaction: &mut txn,
+                document_id: self.document_id,
+                renderer: self.renderer.as_mut().unwrap(),
+                api: &mut self.api,
+                external_images: &mut self.external_images,
+            },
+            &mut self.image_use,
+        ) {
+            // no pending frame is pushed, the frame ready is handled as a redraw of the latest rendered frame
+            txn.generate_frame(self.frame_id().get(), true, false, webrender::api::RenderReasons::SCENE);
+            txn.set_display_list(webrender::api::Epoch(self.frame_id().epoch()), (self.pipeline_id, display_list));
+            self.api.send_transaction(self.document_id, txn);
+        }
+    }
+
     /// Open windows title bar context menu.
     pub fn open_title_bar_context_menu(&self, pos: DipPoint) {
         self.window.show_window_menu(pos.to_winit())
@@ -1348,7 +1595,10 @@ impl Window {
                 WindowState::Normal => {}
                 WindowState::Minimized => self.window.set_minimized(false),
                 WindowState::Maximized => {
-                    if !new_state.state.is_fullscreen() && new_state.state != WindowState::Minimized {
+                    if self.maximized_to_work_area {
+                        // Normal restores the `restore_rect` below
+                        self.maximized_to_work_area = false;
+                    } else if !new_state.state.is_fullscreen() && new_state.state != WindowState::Minimized {
                         self.window.set_maximized(false);
                     }
                 }
@@ -1359,7 +1609,11 @@ impl Window {
             match new_state.state {
                 WindowState::Normal => {}
                 WindowState::Minimized => self.window.set_minimized(true),
-                WindowState::Maximized => self.window.set_maximized(true),
+                WindowState::Maximized => {
+                    if chrome_visible || !self.maximize_to_work_area() {
+                        self.window.set_maximized(true);
+                    }
+                }
                 WindowState::Fullscreen => {
                     self.window.set_fullscreen(Some(Fullscreen::Borderless(None)));
                 }
@@ -1742,6 +1996,7 @@ impl Window {
                     PxRect::from_size(self.window.inner_size().to_px()),
                     scale_factor,
                     mask,
+                    None,
                 )
                 .ok()
         } else {
@@ -1810,6 +2065,7 @@ impl Window {
             self.rendered_frame_id,
             scale_factor,
             mask,
+            None,
         )
     }
 
@@ -1821,7 +2077,31 @@ impl Window {
         if !self.context.is_software() {
             self.redraw(); // refresh back buffer
         }
-        images.frame_image(&**self.context.gl(), rect, self.id, self.rendered_frame_id, scale_factor, mask)
+        images.frame_image(
+            &**self.context.gl(),
+            rect,
+            self.id,
+            self.rendered_frame_id,
+            scale_factor,
+            mask,
+            None,
+        )
+    }
+
+    pub fn frame_thumbnail(&mut self, images: &mut ImageCache, max_size: PxSize) -> ImageId {
+        let scale_factor = self.scale_factor();
+        if !self.context.is_software() {
+            self.redraw(); // refresh back buffer
+        }
+        images.frame_image(
+            &**self.context.gl(),
+            PxRect::from_size(self.window.inner_size().to_px()),
+            self.id,
+            self.rendered_frame_id,
+            scale_factor,
+            None,
+            Some(max_size),
+        )
     }
 
     /// (global_position, monitor_position)
@@ -1904,6 +2184,20 @@ impl Window {
         self.render_mode
     }
 
+    /// Window actual swap chain depth.
+    pub fn swap_chain_depth(&self) -> u8 {
+        self.context.swap_chain_depth()
+    }
+
+    /// Request a swap chain depth, returns the actual depth.
+    pub(crate) fn set_swap_chain_depth(&mut self, depth: u8) -> u8 {
+        let actual = self.context.swap_chain_depth();
+        if depth != actual {
+            tracing::warn!("swap chain depth `{depth}` is not available, will use `{actual}`");
+        }
+        actual
+    }
+
     /// Calls the window extension command.
     pub fn window_extension(&mut self, extension_id: ApiExtensionId, request: ApiExtensionPayload) -> ApiExtensionPayload {
         for (key, ext) in &mut self.window_exts {
@@ -2251,6 +2545,297 @@ impl Window {
         }
     }
 
+    pub(crate) fn set_input_region(&mut self, region: Option<Vec<DipRect>>) {
+        self.input_region = region;
+        self.apply_input_region();
+    }
+
+    /// Windows does not have an input shape API, the input region is implemented by toggling the cursor hit-test.
+    ///
+    /// While hit-test is enabled the region is checked on each `WM_NCHITTEST`, the window does not receive any cursor
+    /// message while hit-test is disabled, so a timer checks the cursor position until it enters the region again.
+    #[cfg(windows)]
+    fn apply_input_region(&mut self) {
+        self.has_input_region.store(self.input_region.is_some(), Ordering::Relaxed);
+        if self.input_region.is_some() {
+            self.check_input_region();
+        } else {
+            self.set_input_region_timer(false);
+            if !self.cursor_hittest {
+                self.cursor_hittest = true;
+                let _ = self.window.set_cursor_hittest(true);
+            }
+        }
+    }
+
+    /// Enable cursor hit-test if the cursor is inside the input region.
+    #[cfg(windows)]
+    pub(crate) fn check_input_region(&mut self) {
+        use windows_sys::Win32::{Foundation::POINT, Graphics::Gdi::ScreenToClient, UI::WindowsAndMessaging::GetCursorPos};
+
+        let region = match &self.input_region {
+            Some(r) => r,
+            None => return,
+        };
+
+        let hwnd = crate::util::winit_to_hwnd(&self.window);
+        let mut pt = POINT { x: 0, y: 0 };
+        // SAFETY: function errors handled.
+        let hit = if unsafe { GetCursorPos(&mut pt) } != 0 && unsafe { ScreenToClient(hwnd as _, &mut pt) } != 0 {
+            let pt = PxPoint::new(Px(pt.x), Px(pt.y)).to_dip(self.scale_factor());
+            region.iter().any(|r| r.contains(pt))
+        } else {
+            true
+        };
+
+        if hit != self.cursor_hittest {
+            self.cursor_hittest = hit;
+            if let Err(e) = self.window.set_cursor_hittest(hit) {
+                tracing::error!("cannot set cursor hit-test, {e}");
+            }
+            self.set_input_region_timer(!hit);
+        }
+    }
+
+    #[cfg(windows)]
+    fn set_input_region_timer(&self, enabled: bool) {
+        use windows_sys::Win32::UI::WindowsAndMessaging::{KillTimer, SetTimer};
+
+        let hwnd = crate::util::winit_to_hwnd(&self.window);
+        if enabled {
+            // SAFETY: timer is associated with the window, the message is handled in the window subclass.
+            unsafe { SetTimer(hwnd as _, INPUT_REGION_TIMER, 30, None) };
+        } else {
+            // SAFETY: timer is associated with the window.
+            unsafe { KillTimer(hwnd as _, INPUT_REGION_TIMER) };
+        }
+    }
+
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
+    fn apply_input_region(&mut self) {
+        use raw_window_handle::{HasDisplayHandle as _, HasWindowHandle as _, RawDisplayHandle, RawWindowHandle};
+
+        let (display, window) = match (
+            self.window.display_handle().map(|h| h.as_raw()),
+            self.window.window_handle().map(|h| h.as_raw()),
+        ) {
+            (Ok(RawDisplayHandle::Xlib(d)), Ok(RawWindowHandle::Xlib(w))) => match d.display {
+                Some(d) => (d.as_ptr() as *mut x11_dl::xlib::Display, w.window),
+                None => return,
+            },
+            _ => {
+                if self.input_region.is_some() {
+                    tracing::warn!("set_input_region not implemented for Wayland");
+                }
+                return;
+            }
+        };
+
+        let (xfixes, xlib) = match (x11_dl::xfixes::XFixes::open(), x11_dl::xlib::Xlib::open()) {
+            (Ok(f), Ok(l)) => (f, l),
+            (Err(e), _) | (_, Err(e)) => {
+                tracing::error!("cannot set input region, {e}");
+                return;
+            }
+        };
+
+        // X11 SHAPE extension kind, see `X11/extensions/shape.h`.
+        const SHAPE_INPUT: std::ffi::c_int = 2;
+
+        let scale_factor = self.scale_factor();
+        // SAFETY: display and window are valid while the winit window is alive, region is destroyed after use.
+        unsafe {
+            match &self.input_region {
+                Some(region) => {
+                    let mut rects: Vec<_> = region
+                        .iter()
+                        .map(|r| {
+                            let r = r.to_px(scale_factor);
+                            x11_dl::xlib::XRectangle {
+                                x: r.origin.x.0 as _,
+                                y: r.origin.y.0 as _,
+                                width: r.size.width.0.max(0) as _,
+                                height: r.size.height.0.max(0) as _,
+                            }
+                        })
+                        .collect();
+                    let xregion = (xfixes.XFixesCreateRegion)(display, rects.as_mut_ptr(), rects.len() as _);
+                    (xfixes.XFixesSetWindowShapeRegion)(display, window, SHAPE_INPUT, 0, 0, xregion);
+                    (xfixes.XFixesDestroyRegion)(display, xregion);
+                }
+                None => (xfixes.XFixesSetWindowShapeRegion)(display, window, SHAPE_INPUT, 0, 0, 0),
+            }
+            (xlib.XFlush)(display);
+        }
+    }
+
+    /// Query the modifier keys pressed from the X11 server, returns `None` in Wayland.
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
+    pub(crate) fn x11_modifiers_state(&self) -> Option<zng_view_api::keyboard::ModifiersState> {
+        use raw_window_handle::{HasDisplayHandle as _, RawDisplayHandle};
+        use x11_dl::keysym::*;
+        use zng_view_api::keyboard::ModifiersState as M;
+
+        let display = match self.window.display_handle().map(|h| h.as_raw()) {
+            Ok(RawDisplayHandle::Xlib(d)) => d.display?.as_ptr() as *mut x11_dl::xlib::Display,
+            _ => return None,
+        };
+        let xlib = match x11_dl::xlib::Xlib::open() {
+            Ok(l) => l,
+            Err(e) => {
+                tracing::error!("cannot query modifiers, {e}");
+                return None;
+            }
+        };
+
+        let mut keys = [0 as std::ffi::c_char; 32];
+        let mut state = M::empty();
+        // SAFETY: display is valid while the winit window is alive, keys has the 32 bytes required.
+        unsafe {
+            (xlib.XQueryKeymap)(display, keys.as_mut_ptr());
+            for (sym, flag) in [
+                (XK_Shift_L, M::L_SHIFT),
+                (XK_Shift_R, M::R_SHIFT),
+                (XK_Control_L, M::L_CTRL),
+                (XK_Control_R, M::R_CTRL),
+                (XK_Alt_L, M::L_ALT),
+                (XK_Alt_R, M::R_ALT),
+                (XK_Super_L, M::L_SUPER),
+                (XK_Super_R, M::R_SUPER),
+            ] {
+                let code = (xlib.XKeysymToKeycode)(display, sym as _) as usize;
+                if code != 0 && keys[code / 8] as u8 & (1 << (code % 8)) != 0 {
+                    state |= flag;
+                }
+            }
+        }
+        Some(state)
+    }
+
+    #[cfg(not(any(
+        windows,
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    )))]
+    fn apply_input_region(&mut self) {
+        if self.input_region.is_some() {
+            tracing::warn!("set_input_region not implemented on {}", std::env::consts::OS);
+        }
+    }
+
+    /// Native handle, used as the owner of other windows.
+    #[cfg(windows)]
+    pub(crate) fn hwnd(&self) -> isize {
+        crate::util::winit_to_hwnd(&self.window)
+    }
+
+    /// Owner window, set by [`set_owner`].
+    ///
+    /// [`set_owner`]: Self::set_owner
+    pub(crate) fn owner(&self) -> Option<WindowId> {
+        self.owner
+    }
+
+    #[cfg(windows)]
+    pub(crate) fn set_owner(&mut self, owner: Option<WindowId>, owner_hwnd: Option<isize>) {
+        use windows_sys::Win32::UI::WindowsAndMessaging::GWLP_HWNDPARENT;
+
+        self.owner = owner;
+
+        let hwnd = crate::util::winit_to_hwnd(&self.window);
+        let owner = owner_hwnd.unwrap_or(0);
+        // SAFETY: GWLP_HWNDPARENT of a top-level window sets the owner, the owner is a valid window or null.
+        #[cfg(target_pointer_width = "64")]
+        unsafe {
+            windows_sys::Win32::UI::WindowsAndMessaging::SetWindowLongPtrW(hwnd as _, GWLP_HWNDPARENT, owner);
+        }
+        #[cfg(not(target_pointer_width = "64"))]
+        unsafe {
+            windows_sys::Win32::UI::WindowsAndMessaging::SetWindowLongW(hwnd as _, GWLP_HWNDPARENT, owner as _);
+        }
+    }
+
+    /// Only records the owner, `WindowCapability::SET_OWNER` is not reported in this platform.
+    #[cfg(not(windows))]
+    pub(crate) fn set_owner(&mut self, owner: Option<WindowId>) {
+        self.owner = owner;
+    }
+
+    /// Outer bounds and monitor of the window, used as the center area of owned windows.
+    pub(crate) fn center_area(&self) -> Option<(PxRect, MonitorHandle)> {
+        let monitor = self.window.current_monitor()?;
+        let pos = self.window.outer_position().ok()?;
+        let size = self.window.outer_size();
+        Some((PxRect::new(pos.to_px(), size.to_px()), monitor))
+    }
+
+    pub(crate) fn set_shape(&mut self, shape: Option<WindowShape>, mask: Option<Image>) {
+        self.shape = shape.map(|s| (s, mask));
+        self.apply_shape();
+    }
+
+    #[cfg(windows)]
+    fn apply_shape(&self) {
+        use windows_sys::Win32::Graphics::Gdi::{CombineRgn, CreateRectRgn, CreateRoundRectRgn, DeleteObject, RGN_OR, SetWindowRgn};
+
+        let rgn = match &self.shape {
+            None => std::ptr::null_mut(),
+            Some((WindowShape::RoundedRect(radius), _)) => {
+                let size = self.window.outer_size();
+                let radius = radius.to_px(self.scale_factor());
+                // SAFETY: null return handled by SetWindowRgn.
+                unsafe { CreateRoundRectRgn(0, 0, size.width as i32 + 1, size.height as i32 + 1, radius.width.0, radius.height.0) }
+            }
+            Some((WindowShape::Mask(_), Some(img))) => {
+                // SAFETY: all regions created here are deleted or owned by the system after SetWindowRgn.
+                unsafe {
+                    let rgn = CreateRectRgn(0, 0, 0, 0);
+                    for run in img.alpha_runs() {
+                        let r = CreateRectRgn(run.min_x().0, run.min_y().0, run.max_x().0, run.max_y().0);
+                        CombineRgn(rgn, rgn, r, RGN_OR);
+                        DeleteObject(r);
+                    }
+                    rgn
+                }
+            }
+            Some(_) => {
+                tracing::error!("cannot set window shape, mask image not found");
+                return;
+            }
+        };
+
+        let hwnd = crate::util::winit_to_hwnd(&self.window);
+        // SAFETY: the system owns the region after a successful call.
+        if unsafe { SetWindowRgn(hwnd as _, rgn, 1) } == 0 {
+            if !rgn.is_null() {
+                unsafe { DeleteObject(rgn) };
+            }
+            tracing::error!("failed to set window shape");
+        }
+    }
+
+    #[cfg(not(windows))]
+    fn apply_shape(&self) {
+        if self.shape.is_some() {
+            tracing::warn!("window shape not implemented on {}", std::env::consts::OS);
+        }
+    }
+
     pub(crate) fn drag_drop_cursor_pos(&self) -> Option<DipPoint> {
         #[cfg(windows)]
         {
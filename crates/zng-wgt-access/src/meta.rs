@@ -16,6 +16,17 @@ pub fn access_role(child: impl IntoUiNode, role: impl IntoVar<AccessRole>) -> Ui
     with_access_state(child, role, |b, v| b.set_role(*v))
 }
 
+/// Sets a human readable description of the widget role for accessibility services.
+///
+/// Only use this to describe custom widgets that are not fully represented by a standard [`AccessRole`],
+/// the [`access_role`] must also be set.
+///
+/// [`access_role`]: fn@access_role
+#[property(CONTEXT)]
+pub fn role_description(child: impl IntoUiNode, description: impl IntoVar<Txt>) -> UiNode {
+    with_access_state(child, description, |b, v| b.set_role_description(v.clone()))
+}
+
 /// Append supported access commands.
 #[property(CONTEXT)]
 pub fn access_commands(child: impl IntoUiNode, commands: impl IntoVar<Vec<AccessCmdName>>) -> UiNode {
@@ -23,6 +23,8 @@ use zng_wgt_text::*;
 #[doc(hidden)]
 pub use zng_wgt_text::__formatx;
 
+pub use zng_wgt_text::{LINK_EVENT, LinkArgs, on_link, on_pre_link};
+
 /// Render text styled using ANSI escape sequences.
 ///
 /// Supports color, weight, italic and more, see [`AnsiStyle`] for the full style supported.
@@ -275,6 +277,78 @@ mod ansi_parse {
         }
     }
 
+    /// Represents a line of ANSI styled text parsed by [`AnsiStreamParser`].
+    #[derive(Debug, Clone, Default)]
+    #[non_exhaustive]
+    pub struct AnsiLine {
+        /// Text runs and style.
+        pub runs: Vec<(Txt, AnsiStyle)>,
+    }
+
+    /// Incremental ANSI escape codes parser.
+    ///
+    /// Parses chunks of bytes, like the output of a running process, into complete [`AnsiLine`] values. The SGR style
+    /// is preserved across chunks and lines, incomplete lines and UTF-8 sequences are retained until the next chunk.
+    ///
+    /// Only the new chunk is parsed on each push, this is the parser for live log viewers that append lines.
+    #[derive(Debug, Default)]
+    pub struct AnsiStreamParser {
+        pending: Vec<u8>,
+        style: AnsiStyle,
+    }
+    impl AnsiStreamParser {
+        /// New parser with the default style.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Style at the start of the next line.
+        pub fn style(&self) -> &AnsiStyle {
+            &self.style
+        }
+
+        /// Parse a `chunk` of bytes, returns the new complete lines.
+        ///
+        /// Lines are terminated by `\n` or `\r\n`, the last incomplete line is retained for the next push.
+        pub fn push(&mut self, chunk: &[u8]) -> Vec<AnsiLine> {
+            let search_start = self.pending.len();
+            self.pending.extend_from_slice(chunk);
+
+            let last_break = match self.pending[search_start..].iter().rposition(|&b| b == b'\n') {
+                Some(i) => search_start + i,
+                None => return vec![],
+            };
+
+            let incomplete = self.pending.split_off(last_break + 1);
+            let complete = std::mem::replace(&mut self.pending, incomplete);
+            // `\n` is never part of a multi-byte UTF-8 sequence, so complete lines are valid UTF-8 if the source is
+            let complete = String::from_utf8_lossy(&complete[..last_break]);
+            complete
+                .split('\n')
+                .map(|line| self.parse_line(line.strip_suffix('\r').unwrap_or(line)))
+                .collect()
+        }
+
+        /// Parse the retained incomplete line, if there is any.
+        ///
+        /// Call this when the stream ends without a line break.
+        pub fn finish(&mut self) -> Option<AnsiLine> {
+            if self.pending.is_empty() {
+                return None;
+            }
+            let pending = std::mem::take(&mut self.pending);
+            Some(self.parse_line(&String::from_utf8_lossy(&pending)))
+        }
+
+        fn parse_line(&mut self, line: &str) -> AnsiLine {
+            let mut parser = AnsiTextParser::new(line);
+            parser.style = self.style.clone();
+            let runs = parser.by_ref().map(|t| (t.txt.to_txt(), t.style)).collect();
+            self.style = parser.style;
+            AnsiLine { runs }
+        }
+    }
+
     impl AnsiStyle {
         fn set(&mut self, esc_codes: &str) {
             let mut esc_codes = esc_codes.split(';');
@@ -373,11 +447,27 @@ mod ansi_fn {
         pub txt: Txt,
         /// The ANSI style.
         pub style: AnsiStyle,
+        /// URL if the text is an auto detected link.
+        ///
+        /// See [`auto_link`] for more details.
+        ///
+        /// [`auto_link`]: fn@auto_link
+        pub link: Option<Txt>,
     }
     impl TextFnArgs {
         /// New from text and style.
         pub fn new(txt: impl Into<Txt>, style: AnsiStyle) -> Self {
-            Self { txt: txt.into(), style }
+            Self {
+                txt: txt.into(),
+                style,
+                link: None,
+            }
+        }
+
+        /// Set the link URL.
+        pub fn with_link(mut self, url: impl Into<Txt>) -> Self {
+            self.link = Some(url.into());
+            self
         }
     }
 
@@ -468,6 +558,11 @@ mod ansi_fn {
         ///
         /// Is `200` by default.
         pub static LINES_PER_PAGE_VAR: u32 = 200;
+
+        /// If `http://` and `https://` URLs are detected in the text and presented as links.
+        ///
+        /// Is `false` by default.
+        pub static AUTO_LINK_VAR: bool = false;
     }
 
     /// Default [`TEXT_FN_VAR`].
@@ -510,7 +605,19 @@ mod ansi_fn {
             }
         }
 
-        if args.style.underline {
+        if let Some(url) = args.link {
+            widget_set! {
+                &mut text;
+                underline = 1, LineStyle::Solid;
+                cursor = CursorIcon::Pointer;
+                zng_wgt_input::gesture::on_click = hn!(|args| {
+                    args.propagation.stop();
+
+                    let link = WINDOW.info().get(WIDGET.id()).unwrap().interaction_path();
+                    LINK_EVENT.notify(LinkArgs::now(url.clone(), link));
+                });
+            }
+        } else if args.style.underline {
             widget_set! {
                 &mut text;
                 underline = 1, LineStyle::Solid;
@@ -664,6 +771,20 @@ mod ansi_fn {
     pub fn lines_per_page(child: impl IntoUiNode, count: impl IntoVar<u32>) -> UiNode {
         with_context_var(child, LINES_PER_PAGE_VAR, count)
     }
+
+    /// Detect `http://` and `https://` URLs in the text and present them as links.
+    ///
+    /// When enabled the URL spans are generated with [`TextFnArgs::link`] set, the [`default_text_fn`] underlines
+    /// the link and notifies [`LINK_EVENT`] on click. Use [`on_link`] to handle the event, for example, to open the URL
+    /// in the system browser.
+    ///
+    /// Sets the [`AUTO_LINK_VAR`].
+    ///
+    /// [`on_link`]: fn@on_link
+    #[property(CONTEXT, default(AUTO_LINK_VAR), widget_impl(AnsiText))]
+    pub fn auto_link(child: impl IntoUiNode, enabled: impl IntoVar<bool>) -> UiNode {
+        with_context_var(child, AUTO_LINK_VAR, enabled)
+    }
 }
 
 fn generate_ansi(txt: &Var<Txt>) -> UiNode {
@@ -676,18 +797,34 @@ fn generate_ansi(txt: &Var<Txt>) -> UiNode {
         let page_fn = PAGE_FN_VAR.get();
         let panel_fn = PANEL_FN_VAR.get();
         let lines_per_page = LINES_PER_PAGE_VAR.get() as usize;
+        let auto_link = AUTO_LINK_VAR.get();
 
         let mut pages = Vec::with_capacity(4);
         let mut lines = Vec::with_capacity(50);
 
         for (i, line) in txt.lines().enumerate() {
             let text = ansi_parse::AnsiTextParser::new(line)
-                .filter_map(|txt| {
-                    text_fn.call_checked(TextFnArgs {
-                        txt: txt.txt.to_txt(),
-                        style: txt.style,
-                    })
+                .flat_map(|txt| {
+                    let mut segs = vec![];
+                    if auto_link {
+                        let mut start = 0;
+                        for range in find_urls(txt.txt) {
+                            if range.start > start {
+                                segs.push(TextFnArgs::new(txt.txt[start..range.start].to_txt(), txt.style.clone()));
+                            }
+                            start = range.end;
+                            let url = txt.txt[range].to_txt();
+                            segs.push(TextFnArgs::new(url.clone(), txt.style.clone()).with_link(url));
+                        }
+                        if start < txt.txt.len() {
+                            segs.push(TextFnArgs::new(txt.txt[start..].to_txt(), txt.style));
+                        }
+                    } else {
+                        segs.push(TextFnArgs::new(txt.txt.to_txt(), txt.style));
+                    }
+                    segs
                 })
+                .filter_map(|args| text_fn.call_checked(args))
                 .collect();
 
             lines.push(line_fn(LineFnArgs {
@@ -728,7 +865,8 @@ pub fn ansi_node(txt: impl IntoVar<Txt>) -> UiNode {
                 .sub_var(&PAGE_FN_VAR)
                 .sub_var(&PANEL_FN_VAR)
                 .sub_var(&LINES_PER_PAGE_VAR)
-                .sub_var(&BLINK_INTERVAL_VAR);
+                .sub_var(&BLINK_INTERVAL_VAR)
+                .sub_var(&AUTO_LINK_VAR);
 
             *c.node() = generate_ansi(&txt);
         }
@@ -746,6 +884,7 @@ pub fn ansi_node(txt: impl IntoVar<Txt>) -> UiNode {
                 || PANEL_FN_VAR.is_new()
                 || LINES_PER_PAGE_VAR.is_new()
                 || BLINK_INTERVAL_VAR.is_new()
+                || AUTO_LINK_VAR.is_new()
             {
                 c.node().deinit();
                 *c.node() = generate_ansi(&txt);
@@ -1028,3 +1167,66 @@ static X_TERM_256: [(u8, u8, u8); 256] = [
     (228, 228, 228),
     (238, 238, 238),
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn txt(line: &AnsiLine) -> String {
+        line.runs.iter().map(|(t, _)| t.as_str()).collect()
+    }
+
+    #[test]
+    fn stream_escape_split() {
+        let mut parser = AnsiStreamParser::new();
+        assert!(parser.push(b"\x1b").is_empty());
+        assert!(parser.push(b"[3").is_empty());
+        let lines = parser.push(b"1mred\n");
+
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].runs.len(), 1);
+        assert_eq!(lines[0].runs[0].0.as_str(), "red");
+        assert_eq!(lines[0].runs[0].1.color, AnsiColor::Red);
+    }
+
+    #[test]
+    fn stream_style_across_chunks_and_lines() {
+        let mut parser = AnsiStreamParser::new();
+        let lines = parser.push(b"a\x1b[1;3");
+        assert!(lines.is_empty());
+        let lines = parser.push(b"2mb\r\nc\n\x1b[0");
+        assert_eq!(lines.len(), 2);
+        assert_eq!(txt(&lines[0]), "ab");
+        assert_eq!(lines[0].runs[1].1.weight, AnsiWeight::Bold);
+        assert_eq!(lines[0].runs[1].1.color, AnsiColor::Green);
+        // style continues in the next line
+        assert_eq!(txt(&lines[1]), "c");
+        assert_eq!(lines[1].runs[0].1.color, AnsiColor::Green);
+        assert_eq!(parser.style().color, AnsiColor::Green);
+
+        let lines = parser.push(b"md\n");
+        assert_eq!(txt(&lines[0]), "d");
+        assert_eq!(lines[0].runs[0].1.color, AnsiColor::White);
+        assert_eq!(lines[0].runs[0].1.weight, AnsiWeight::Normal);
+    }
+
+    #[test]
+    fn stream_utf8_split() {
+        let mut parser = AnsiStreamParser::new();
+        let bytes = "olá\n".as_bytes();
+        let (a, b) = bytes.split_at(3); // splits 'á'
+        assert!(parser.push(a).is_empty());
+        let lines = parser.push(b);
+        assert_eq!(txt(&lines[0]), "olá");
+    }
+
+    #[test]
+    fn stream_finish() {
+        let mut parser = AnsiStreamParser::new();
+        assert!(parser.push(b"\x1b[31mpartial").is_empty());
+        let line = parser.finish().unwrap();
+        assert_eq!(txt(&line), "partial");
+        assert_eq!(line.runs[0].1.color, AnsiColor::Red);
+        assert!(parser.finish().is_none());
+    }
+}
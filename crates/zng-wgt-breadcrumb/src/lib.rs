@@ -0,0 +1,532 @@
+#![doc(html_favicon_url = "https://zng-ui.github.io/res/zng-logo-icon.png")]
+#![doc(html_logo_url = "https://zng-ui.github.io/res/zng-logo.png")]
+//!
+//! Breadcrumb path navigation widget.
+//!
+//! # Crate
+//!
+#![doc = include_str!(concat!("../", std::env!("CARGO_PKG_README")))]
+#![warn(unused_extern_crates)]
+#![warn(missing_docs)]
+
+zng_wgt::enable_widget_macros!();
+
+use zng_wgt::{margin, prelude::*};
+use zng_wgt_access::{AccessRole, CurrentKind, access_role, current};
+use zng_wgt_button::Button;
+use zng_wgt_input::gesture::ClickArgs;
+use zng_wgt_layer::popup::POPUP;
+use zng_wgt_menu::context::ContextMenu;
+use zng_wgt_style::{Style, StyleMix, impl_style_fn};
+use zng_wgt_text::Text;
+
+/// Path navigation bar.
+///
+/// Shows the [`segments`] in order, separated by the [`separator_fn`] widgets. When there is not enough space to show all
+/// segments the middle segments collapse into an overflow widget, by default a *…* button that opens a menu listing the hidden
+/// segments. The first segment and as many of the last segments that fit are always shown.
+///
+/// When a segment is activated the [`SEGMENT_ACTIVATED_EVENT`] notifies, see [`on_segment_activated`].
+///
+/// # Shorthand
+///
+/// The `Breadcrumb!` macro provides a shorthand init that sets the segments, `Breadcrumb!(segments)`.
+///
+/// [`segments`]: fn@segments
+/// [`separator_fn`]: fn@separator_fn
+/// [`on_segment_activated`]: fn@on_segment_activated
+#[widget($crate::Breadcrumb {
+    ($segments:expr) => {
+        segments = $segments;
+    };
+})]
+pub struct Breadcrumb(StyleMix<WidgetBase>);
+impl Breadcrumb {
+    fn widget_intrinsic(&mut self) {
+        self.style_intrinsic(STYLE_FN_VAR, property_id!(self::style_fn));
+
+        widget_set! {
+            self;
+            access_role = AccessRole::Navigation;
+        }
+
+        self.widget_builder().push_build_action(|wgt| {
+            wgt.set_child(node());
+        });
+    }
+}
+impl_style_fn!(Breadcrumb, DefaultStyle);
+
+/// Default breadcrumb style.
+#[widget($crate::DefaultStyle)]
+pub struct DefaultStyle(Style);
+impl DefaultStyle {
+    fn widget_intrinsic(&mut self) {
+        widget_set! {
+            self;
+            replace = true;
+        }
+    }
+}
+
+/// Breadcrumb path segment.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct BreadcrumbSegment {
+    /// Segment display text.
+    pub label: Txt,
+    /// Command notified when the segment is activated.
+    pub action: Option<Command>,
+}
+impl BreadcrumbSegment {
+    /// New segment without action.
+    pub fn new(label: impl Into<Txt>) -> Self {
+        Self {
+            label: label.into(),
+            action: None,
+        }
+    }
+
+    /// Set the command notified when the segment is activated.
+    pub fn with_action(mut self, action: Command) -> Self {
+        self.action = Some(action);
+        self
+    }
+}
+impl_from_and_into_var! {
+    fn from(label: &'static str) -> BreadcrumbSegment {
+        BreadcrumbSegment::new(label)
+    }
+    fn from(label: Txt) -> BreadcrumbSegment {
+        BreadcrumbSegment::new(label)
+    }
+    fn from(label: String) -> BreadcrumbSegment {
+        BreadcrumbSegment::new(label)
+    }
+}
+
+/// Arguments for the [`segment_fn`] and the overflow menu items.
+///
+/// [`segment_fn`]: fn@segment_fn
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct SegmentFnArgs {
+    /// Breadcrumb widget.
+    pub breadcrumb_id: WidgetId,
+    /// Segment index.
+    pub index: usize,
+    /// Segment.
+    pub segment: BreadcrumbSegment,
+    /// If the segment is the last, usually the current location.
+    pub is_last: bool,
+}
+impl SegmentFnArgs {
+    /// Notify the [`SEGMENT_ACTIVATED_EVENT`] and the segment action command.
+    ///
+    /// Must be called inside the breadcrumb window.
+    pub fn activate(&self) {
+        if let Some(w) = WINDOW.info().get(self.breadcrumb_id) {
+            SEGMENT_ACTIVATED_EVENT.notify(SegmentActivatedArgs::now(w.path(), self.index, self.segment.clone()));
+        }
+        if let Some(cmd) = &self.segment.action {
+            cmd.notify();
+        }
+    }
+}
+
+/// Arguments for the [`overflow_fn`].
+///
+/// [`overflow_fn`]: fn@overflow_fn
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct OverflowFnArgs {
+    /// Segments currently collapsed, updated on layout.
+    pub collapsed: Var<Vec<SegmentFnArgs>>,
+}
+
+context_var! {
+    /// Breadcrumb path segments.
+    pub static SEGMENTS_VAR: Vec<BreadcrumbSegment> = vec![];
+    /// Segment widget generator.
+    pub static SEGMENT_FN_VAR: WidgetFn<SegmentFnArgs> = WidgetFn::new(default_segment_fn);
+    /// Separator widget generator.
+    pub static SEPARATOR_FN_VAR: WidgetFn<()> = WidgetFn::new(default_separator_fn);
+    /// Overflow widget generator.
+    pub static OVERFLOW_FN_VAR: WidgetFn<OverflowFnArgs> = WidgetFn::new(default_overflow_fn);
+}
+
+/// Breadcrumb path segments.
+///
+/// This property sets the [`SEGMENTS_VAR`].
+#[property(CONTEXT, default(SEGMENTS_VAR), widget_impl(Breadcrumb))]
+pub fn segments(child: impl IntoUiNode, segments: impl IntoVar<Vec<BreadcrumbSegment>>) -> UiNode {
+    with_context_var(child, SEGMENTS_VAR, segments)
+}
+
+/// Segment widget generator.
+///
+/// This property sets the [`SEGMENT_FN_VAR`].
+#[property(CONTEXT, default(SEGMENT_FN_VAR), widget_impl(Breadcrumb))]
+pub fn segment_fn(child: impl IntoUiNode, wgt_fn: impl IntoVar<WidgetFn<SegmentFnArgs>>) -> UiNode {
+    with_context_var(child, SEGMENT_FN_VAR, wgt_fn)
+}
+
+/// Separator widget generator.
+///
+/// This property sets the [`SEPARATOR_FN_VAR`].
+#[property(CONTEXT, default(SEPARATOR_FN_VAR), widget_impl(Breadcrumb))]
+pub fn separator_fn(child: impl IntoUiNode, wgt_fn: impl IntoVar<WidgetFn<()>>) -> UiNode {
+    with_context_var(child, SEPARATOR_FN_VAR, wgt_fn)
+}
+
+/// Overflow widget generator.
+///
+/// The overflow widget is shown in place of the collapsed segments.
+///
+/// This property sets the [`OVERFLOW_FN_VAR`].
+#[property(CONTEXT, default(OVERFLOW_FN_VAR), widget_impl(Breadcrumb))]
+pub fn overflow_fn(child: impl IntoUiNode, wgt_fn: impl IntoVar<WidgetFn<OverflowFnArgs>>) -> UiNode {
+    with_context_var(child, OVERFLOW_FN_VAR, wgt_fn)
+}
+
+/// Default value of [`segment_fn`].
+///
+/// The last segment is a text marked as the current page, other segments are buttons that activate on click.
+///
+/// [`segment_fn`]: fn@segment_fn
+pub fn default_segment_fn(args: SegmentFnArgs) -> UiNode {
+    if args.is_last {
+        Text! {
+            txt = args.segment.label.clone();
+            current = CurrentKind::Page;
+            zng_wgt_text::font_weight = zng_ext_font::FontWeight::BOLD;
+            margin = (0, 4);
+        }
+    } else {
+        let label = args.segment.label.clone();
+        Button! {
+            style_fn = zng_wgt_button::LightStyle!();
+            child = Text!(label);
+            on_click = hn!(|a: &ClickArgs| {
+                a.propagation.stop();
+                args.activate();
+            });
+        }
+    }
+}
+
+/// Default value of [`separator_fn`].
+///
+/// [`separator_fn`]: fn@separator_fn
+pub fn default_separator_fn(_: ()) -> UiNode {
+    Text! {
+        txt = "›";
+        margin = (0, 2);
+    }
+}
+
+/// Default value of [`overflow_fn`].
+///
+/// A button that opens a [`ContextMenu!`] with one item for each collapsed segment.
+///
+/// [`overflow_fn`]: fn@overflow_fn
+/// [`ContextMenu!`]: struct@ContextMenu
+pub fn default_overflow_fn(args: OverflowFnArgs) -> UiNode {
+    Button! {
+        style_fn = zng_wgt_button::LightStyle!();
+        child = Text!("…");
+        on_click = hn!(|a: &ClickArgs| {
+            a.propagation.stop();
+            let items = args.collapsed.get().into_iter().map(|s| {
+                Button! {
+                    child = Text!(s.segment.label.clone());
+                    on_click = hn!(|a: &ClickArgs| {
+                        a.propagation.stop();
+                        s.activate();
+                    });
+                }
+            });
+            let _ = POPUP.open(ContextMenu!(items));
+        });
+    }
+}
+
+event_args! {
+    /// Arguments for [`SEGMENT_ACTIVATED_EVENT`].
+    pub struct SegmentActivatedArgs {
+        /// Breadcrumb widget.
+        pub target: WidgetPath,
+        /// Segment index.
+        pub index: usize,
+        /// Segment.
+        pub segment: BreadcrumbSegment,
+
+        ..
+
+        fn is_in_target(&self, id: WidgetId) -> bool {
+            self.target.contains(id)
+        }
+    }
+}
+event! {
+    /// A breadcrumb segment was activated.
+    pub static SEGMENT_ACTIVATED_EVENT: SegmentActivatedArgs;
+}
+event_property! {
+    /// A breadcrumb segment was activated.
+    #[property(EVENT)]
+    pub fn on_segment_activated<on_pre_segment_activated>(child: impl IntoUiNode, handler: Handler<SegmentActivatedArgs>) -> UiNode {
+        const PRE: bool;
+        EventNodeBuilder::new(SEGMENT_ACTIVATED_EVENT).build::<PRE>(child, handler)
+    }
+}
+
+/// Breadcrumb node.
+///
+/// Can be used directly to layout the segments without declaring a breadcrumb widget, the context variables
+/// are configured by the [`Breadcrumb!`] properties.
+///
+/// [`Breadcrumb!`]: struct@Breadcrumb
+pub fn node() -> UiNode {
+    let collapsed = var(Vec::<SegmentFnArgs>::new());
+    let mut segment_args = vec![];
+    let mut visible = vec![];
+
+    match_node(UiNode::nil(), move |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET
+                .sub_var(&SEGMENTS_VAR)
+                .sub_var(&SEGMENT_FN_VAR)
+                .sub_var(&SEPARATOR_FN_VAR)
+                .sub_var(&OVERFLOW_FN_VAR);
+            segment_args = generate_args();
+            *c.node() = generate_children(&segment_args, &collapsed);
+        }
+        UiNodeOp::Deinit => {
+            c.deinit();
+            *c.node() = UiNode::nil();
+            collapsed.set(vec![]);
+        }
+        UiNodeOp::Update { updates } => {
+            if SEGMENTS_VAR.is_new() || SEGMENT_FN_VAR.is_new() || SEPARATOR_FN_VAR.is_new() || OVERFLOW_FN_VAR.is_new() {
+                c.node().deinit();
+                segment_args = generate_args();
+                *c.node() = generate_children(&segment_args, &collapsed);
+                c.node().init();
+                WIDGET.update_info().layout().render();
+            } else {
+                let mut changed = false;
+                c.update_list(updates, &mut changed);
+                if changed {
+                    WIDGET.layout();
+                }
+            }
+        }
+        UiNodeOp::Measure { wm, desired_size } => {
+            c.delegated();
+
+            let constraints = LAYOUT.constraints();
+            let child_constraints = constraints.with_new_min(Px(0), Px(0)).with_unbounded_x();
+            let mut size = PxSize::zero();
+            // all segments and separators, the overflow widget is ignored
+            c.node_impl::<PanelList>().for_each_child(|i, c, _| {
+                if i > 1 {
+                    let s = LAYOUT.with_constraints(child_constraints, || c.measure(wm));
+                    size.width += s.width;
+                    size.height = size.height.max(s.height);
+                }
+            });
+            *desired_size = constraints.clamp_size(size);
+        }
+        UiNodeOp::Layout { wl, final_size } => {
+            c.delegated();
+
+            let constraints = LAYOUT.constraints();
+            let children = c.node_impl::<PanelList>();
+
+            let child_constraints = constraints.with_new_min(Px(0), Px(0)).with_unbounded_x();
+            let mut sizes = vec![];
+            children.for_each_child(|_, c, _| {
+                sizes.push(LAYOUT.with_constraints(child_constraints, || c.measure(&mut wl.to_measure(None))));
+            });
+
+            let order = collapse_order(&sizes, constraints.x.max().unwrap_or(Px::MAX));
+
+            visible.clear();
+            visible.resize(sizes.len(), false);
+            let mut offsets = vec![None; sizes.len()];
+            let mut x = Px(0);
+            let mut height = Px(0);
+            for &i in &order {
+                visible[i] = true;
+                offsets[i] = Some(x);
+                x += sizes[i].width;
+                height = height.max(sizes[i].height);
+            }
+            let size = constraints.clamp_size(PxSize::new(x, height));
+            let is_rtl = LAYOUT.direction().is_rtl();
+
+            let new_collapsed: Vec<_> = segment_args.iter().filter(|a| !visible[segment_index(a.index)]).cloned().collect();
+            if collapsed.with(|c| c != &new_collapsed) {
+                collapsed.set(new_collapsed);
+            }
+
+            // collapse all, then layout only the visible children
+            wl.collapse_descendants();
+            children.layout_list(
+                wl,
+                |i, c, o, wl| {
+                    let Some(x) = offsets[i] else {
+                        return PxSize::zero();
+                    };
+                    let child_size = PxSize::new(sizes[i].width, size.height);
+                    let x = if is_rtl { size.width - x - child_size.width } else { x };
+                    let (_, define_ref_frame) =
+                        LAYOUT.with_constraints(PxConstraints2d::new_exact_size(child_size), || wl.with_child(|wl| c.layout(wl)));
+                    o.child_offset = PxVector::new(x, Px(0));
+                    o.define_reference_frame = define_ref_frame;
+                    child_size
+                },
+                |_, _| PxSize::zero(),
+            );
+            children.commit_data().request_render();
+
+            *final_size = size;
+        }
+        UiNodeOp::Render { frame } => {
+            c.delegated();
+            c.node_impl::<PanelList>().render_list(frame, |i, c, _, frame| {
+                if visible.get(i).copied().unwrap_or(false) {
+                    c.render(frame);
+                }
+            });
+        }
+        UiNodeOp::RenderUpdate { update } => {
+            c.delegated();
+            c.node_impl::<PanelList>().render_update_list(update, |i, c, _, update| {
+                if visible.get(i).copied().unwrap_or(false) {
+                    c.render_update(update);
+                }
+            });
+        }
+        _ => {}
+    })
+}
+
+fn generate_args() -> Vec<SegmentFnArgs> {
+    let breadcrumb_id = WIDGET.id();
+    SEGMENTS_VAR.with(|s| {
+        let len = s.len();
+        s.iter()
+            .enumerate()
+            .map(|(index, segment)| SegmentFnArgs {
+                breadcrumb_id,
+                index,
+                segment: segment.clone(),
+                is_last: index + 1 == len,
+            })
+            .collect()
+    })
+}
+
+/// Children are `[overflow, overflow_separator, segment_0, separator, segment_1, ..]`.
+fn generate_children(args: &[SegmentFnArgs], collapsed: &Var<Vec<SegmentFnArgs>>) -> UiNode {
+    let segment_fn = SEGMENT_FN_VAR.get();
+    let separator_fn = SEPARATOR_FN_VAR.get();
+
+    let mut children = ui_vec![
+        OVERFLOW_FN_VAR.get()(OverflowFnArgs {
+            collapsed: collapsed.read_only(),
+        }),
+        separator_fn(()),
+    ];
+    for a in args {
+        if a.index > 0 {
+            children.push(separator_fn(()));
+        }
+        children.push(segment_fn(a.clone()));
+    }
+    PanelList::new(children).into_node()
+}
+
+/// Child index of the segment.
+fn segment_index(i: usize) -> usize {
+    2 + i * 2
+}
+
+/// Computes the child indexes that are visible, in display order.
+///
+/// If all segments fit returns all segments and separators, otherwise collapses the middle segments into
+/// the overflow widget, keeping the first segment and as many trailing segments as fit.
+fn collapse_order(sizes: &[PxSize], available: Px) -> Vec<usize> {
+    let len = (sizes.len() - 2).div_ceil(2);
+    let w = |i: usize| sizes[i].width;
+
+    let full: Px = (2..sizes.len()).map(w).sum();
+    if full <= available || len < 3 {
+        return (2..sizes.len()).collect();
+    }
+
+    // hide segments `1..first_shown`, separator before `first_shown` is replaced by the overflow separator
+    let mut first_shown = len - 1;
+    let fixed = w(segment_index(0)) + w(segment_index(1) - 1) + w(0) + w(1);
+    let mut trailing = w(segment_index(first_shown));
+    while first_shown > 2 {
+        let next = first_shown - 1;
+        let next_trailing = trailing + w(segment_index(next)) + w(segment_index(first_shown) - 1);
+        if fixed + next_trailing > available {
+            break;
+        }
+        trailing = next_trailing;
+        first_shown = next;
+    }
+
+    let mut order = vec![segment_index(0), segment_index(1) - 1, 0, 1, segment_index(first_shown)];
+    for i in first_shown + 1..len {
+        order.push(segment_index(i) - 1);
+        order.push(segment_index(i));
+    }
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Overflow button `10` wide, separators `5`, segments `20`.
+    fn sizes(segments: usize) -> Vec<PxSize> {
+        let mut r = vec![PxSize::new(Px(10), Px(10)), PxSize::new(Px(5), Px(10))];
+        for i in 0..segments {
+            if i > 0 {
+                r.push(PxSize::new(Px(5), Px(10)));
+            }
+            r.push(PxSize::new(Px(20), Px(10)));
+        }
+        r
+    }
+
+    #[test]
+    fn collapse_order_fits() {
+        assert_eq!(collapse_order(&sizes(3), Px(70)), vec![2, 3, 4, 5, 6]);
+        assert_eq!(collapse_order(&sizes(5), Px(1000)), vec![2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn collapse_order_min_segments() {
+        assert_eq!(collapse_order(&sizes(1), Px(0)), vec![2]);
+        assert_eq!(collapse_order(&sizes(2), Px(0)), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn collapse_order_middle() {
+        // 120 wide, fixed first segment and overflow is 40
+        assert_eq!(collapse_order(&sizes(5), Px(100)), vec![2, 3, 0, 1, 8, 9, 10]);
+        assert_eq!(collapse_order(&sizes(5), Px(119)), vec![2, 3, 0, 1, 6, 7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn collapse_order_keeps_last() {
+        assert_eq!(collapse_order(&sizes(5), Px(10)), vec![2, 3, 0, 1, 10]);
+        assert_eq!(collapse_order(&sizes(3), Px(0)), vec![2, 3, 0, 1, 6]);
+    }
+}
@@ -11,7 +11,9 @@
 
 use std::ops;
 
+use zng_app::render::ImageRendering;
 use zng_color::COLOR_SCHEME_VAR;
+use zng_ext_image::{IMAGES, ImageOptions, ImageRenderArgs, ImageSource, ImageVar};
 use zng_wgt::prelude::{
     gradient::{RenderExtendMode, RenderGradientStop},
     *,
@@ -65,6 +67,11 @@ context_var! {
     ///
     /// Default is `10`.
     pub static SIZE_VAR: Size = 10;
+
+    /// Image tiled instead of the colors.
+    ///
+    /// Default is `None`, the checkerboard colors are used.
+    pub static TILE_IMG_VAR: Option<ImageSource> = None;
 }
 
 /// Set both checkerboard colors.
@@ -96,6 +103,21 @@ pub fn cb_origin(child: impl IntoUiNode, offset: impl IntoVar<Point>) -> UiNode
     with_context_var(child, ORIGIN_VAR, offset)
 }
 
+/// Set an image that is tiled instead of the checkerboard colors.
+///
+/// The image is resized to the [`cb_size`] and repeated to fill the widget, offset by the [`cb_origin`].
+/// The [`colors`] are used while the image is loading, if it fails to load or if set to `None`.
+///
+/// This property sets the [`TILE_IMG_VAR`] for all inner checkerboard widgets.
+///
+/// [`cb_size`]: fn@cb_size
+/// [`cb_origin`]: fn@cb_origin
+/// [`colors`]: fn@colors
+#[property(CONTEXT, default(TILE_IMG_VAR), widget_impl(Checkerboard))]
+pub fn cb_tile_img(child: impl IntoUiNode, source: impl IntoVar<Option<ImageSource>>) -> UiNode {
+    with_context_var(child, TILE_IMG_VAR, source)
+}
+
 /// Checkerboard node.
 ///
 /// The node is configured by the contextual variables defined in the widget.
@@ -104,13 +126,42 @@ pub fn node() -> UiNode {
     let mut tile_origin = PxPoint::zero();
     let mut tile_size = PxSize::zero();
 
+    let mut img: Option<ImageVar> = None;
+    let mut _img_sub = VarHandle::dummy();
+    let spatial_id = SpatialFrameId::new_unique();
+    let load_img = |img: &mut Option<ImageVar>, img_sub: &mut VarHandle| {
+        *img = TILE_IMG_VAR.get().map(|mut source| {
+            if let ImageSource::Render(_, args) = &mut source {
+                *args = Some(ImageRenderArgs::new(WINDOW.id()));
+            }
+            IMAGES.image(source, ImageOptions::cache(), None)
+        });
+        *img_sub = match img {
+            Some(i) => i.subscribe(UpdateOp::Render, WIDGET.id()),
+            None => VarHandle::dummy(),
+        };
+    };
+
     match_node_leaf(move |op| match op {
         UiNodeOp::Init => {
             WIDGET
                 .sub_var_render(&COLORS_VAR)
                 .sub_var_render(&COLOR_SCHEME_VAR)
                 .sub_var_layout(&SIZE_VAR)
-                .sub_var_layout(&ORIGIN_VAR);
+                .sub_var_layout(&ORIGIN_VAR)
+                .sub_var(&TILE_IMG_VAR);
+
+            load_img(&mut img, &mut _img_sub);
+        }
+        UiNodeOp::Deinit => {
+            img = None;
+            _img_sub = VarHandle::dummy();
+        }
+        UiNodeOp::Update { .. } => {
+            if TILE_IMG_VAR.is_new() {
+                load_img(&mut img, &mut _img_sub);
+                WIDGET.render();
+            }
         }
         UiNodeOp::Measure { desired_size, .. } => {
             *desired_size = LAYOUT.constraints().fill_size();
@@ -140,6 +191,26 @@ pub fn node() -> UiNode {
                 return;
             }
 
+            if let Some(img) = &img {
+                let img = img.get();
+                if img.is_loaded() {
+                    // tile image is one color rectangle, the gradient tile has 4.
+                    let cell = tile_size / 2.fct();
+                    if cell.is_empty() {
+                        return;
+                    }
+                    let offset = PxVector::new(
+                        Px(tile_origin.x.0.rem_euclid(cell.width.0)) - cell.width,
+                        Px(tile_origin.y.0.rem_euclid(cell.height.0)) - cell.height,
+                    );
+                    let clip = PxRect::new(PxPoint::new(-offset.x, -offset.y), render_size);
+                    frame.push_reference_frame(spatial_id.into(), FrameValue::Value(offset.into()), true, false, |frame| {
+                        frame.push_image(clip, render_size + cell, cell, PxSize::zero(), &img, ImageRendering::Auto);
+                    });
+                    return;
+                }
+            }
+
             let [c0, c1] = COLORS_VAR.get().0;
             let sch = COLOR_SCHEME_VAR.get();
             let colors = [c0[sch], c1[sch]];
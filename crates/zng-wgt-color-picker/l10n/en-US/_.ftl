@@ -0,0 +1,8 @@
+ColorPicker =
+    .blue = Blue
+    .chroma = Chroma
+    .green = Green
+    .hex = Hex
+    .hue = Hue
+    .lightness = Lightness
+    .red = Red
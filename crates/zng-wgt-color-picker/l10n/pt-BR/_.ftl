@@ -0,0 +1,8 @@
+ColorPicker =
+    .blue = Azul
+    .chroma = Croma
+    .green = Verde
+    .hex = Hex
+    .hue = Matiz
+    .lightness = Luminosidade
+    .red = Vermelho
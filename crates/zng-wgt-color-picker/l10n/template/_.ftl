@@ -0,0 +1,10 @@
+### Auto generated by `cargo zng l10n`
+
+ColorPicker =
+    .blue = Blue
+    .chroma = Chroma
+    .green = Green
+    .hex = Hex
+    .hue = Hue
+    .lightness = Lightness
+    .red = Red
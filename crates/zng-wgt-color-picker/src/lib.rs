@@ -0,0 +1,606 @@
+#![doc(html_favicon_url = "https://zng-ui.github.io/res/zng-logo-icon.png")]
+#![doc(html_logo_url = "https://zng-ui.github.io/res/zng-logo.png")]
+//!
+//! Color picker widget and properties.
+//!
+//! # Crate
+//!
+#![doc = include_str!(concat!("../", std::env!("CARGO_PKG_README")))]
+#![warn(unused_extern_crates)]
+#![warn(missing_docs)]
+
+zng_wgt::enable_widget_macros!();
+
+use std::fmt;
+
+use zng_app::event::CommandHandle;
+use zng_color::{Oklch, oklch};
+use zng_ext_clipboard::{CLIPBOARD, COPY_CMD, PASTE_CMD};
+use zng_ext_input::mouse::{ButtonState, MOUSE_INPUT_EVENT, MOUSE_MOVE_EVENT};
+use zng_ext_l10n::l10n;
+use zng_wgt::{
+    Wgt, border, corner_radius,
+    prelude::{gradient::*, *},
+    visibility,
+};
+use zng_wgt_access::{AccessRole, access_role};
+use zng_wgt_container::{Container, child_align};
+use zng_wgt_input::{CursorIcon, cursor, focus::focusable, pointer_capture::capture_pointer};
+use zng_wgt_size_offset::{height, size, width, x, y};
+use zng_wgt_stack::{Stack, StackDirection};
+use zng_wgt_style::{Style, StyleMix, impl_style_fn};
+use zng_wgt_text::Text;
+use zng_wgt_text_input::{TextInput, number_input::NumberInput};
+
+/// Color selector with a color area, hue and alpha sliders and input fields.
+///
+/// The [`color`] is selected by dragging on the saturation-value area (or chroma-lightness area in the OKLCH space),
+/// on the hue slider and on the alpha slider, the color can also be typed as hex or RGB components and as OKLCH
+/// components in the OKLCH space, see [`color_space`] for more details.
+///
+/// When the picker is focused [`COPY_CMD`] copies the color as hex text to the clipboard and [`PASTE_CMD`] sets
+/// the color parsed from the clipboard text, the parser accepts `#RRGGBB`, `#RRGGBBAA`, `rgb(..)` and `rgba(..)`.
+///
+/// # Shorthand
+///
+/// The `ColorPicker!` macro provides a shorthand init that sets the color, `ColorPicker!(color)`.
+///
+/// [`color`]: fn@color
+/// [`color_space`]: fn@color_space
+/// [`COPY_CMD`]: zng_ext_clipboard::COPY_CMD
+/// [`PASTE_CMD`]: zng_ext_clipboard::PASTE_CMD
+#[widget($crate::ColorPicker {
+    ($color:expr) => {
+        color = $color;
+    };
+})]
+pub struct ColorPicker(StyleMix<WidgetBase>);
+impl ColorPicker {
+    fn widget_intrinsic(&mut self) {
+        self.style_intrinsic(STYLE_FN_VAR, property_id!(self::style_fn));
+
+        widget_set! {
+            self;
+            color = var(colors::WHITE);
+            focusable = true;
+            access_role = AccessRole::Group;
+        }
+
+        self.widget_builder().push_build_action(|wgt| {
+            wgt.set_child(node());
+            wgt.push_intrinsic(NestGroup::EVENT, "color-picker-clipboard", clipboard_node);
+        });
+    }
+}
+impl_style_fn!(ColorPicker, DefaultStyle);
+
+/// Default color picker style.
+#[widget($crate::DefaultStyle)]
+pub struct DefaultStyle(Style);
+impl DefaultStyle {
+    fn widget_intrinsic(&mut self) {
+        widget_set! {
+            self;
+            replace = true;
+        }
+    }
+}
+
+/// Color space of the color picker area and extra input fields.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum ColorPickerSpace {
+    /// Area selects saturation and value of the hue, the hue slider is the HSV hue circle.
+    Hsv,
+    /// Area selects chroma and lightness of the hue, the hue slider is the OKLCH hue circle.
+    ///
+    /// Also shows input fields for the OKLCH components. Colors outside of the sRGB gamut are clamped.
+    Oklch,
+}
+impl ColorPickerSpace {
+    /// If is [`Hsv`].
+    ///
+    /// [`Hsv`]: Self::Hsv
+    pub fn is_hsv(self) -> bool {
+        matches!(self, Self::Hsv)
+    }
+
+    /// If is [`Oklch`].
+    ///
+    /// [`Oklch`]: Self::Oklch
+    pub fn is_oklch(self) -> bool {
+        matches!(self, Self::Oklch)
+    }
+}
+impl fmt::Debug for ColorPickerSpace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "ColorPickerSpace::")?;
+        }
+        match self {
+            Self::Hsv => write!(f, "Hsv"),
+            Self::Oklch => write!(f, "Oklch"),
+        }
+    }
+}
+
+context_var! {
+    /// Selected color.
+    pub static COLOR_VAR: Rgba = colors::WHITE;
+
+    /// Color picker space.
+    pub static COLOR_SPACE_VAR: ColorPickerSpace = ColorPickerSpace::Hsv;
+}
+
+/// Selected color.
+///
+/// Is a new `var(colors::WHITE)` by default in [`ColorPicker!`], set to a read-write variable to bind the color.
+///
+/// This property sets the [`COLOR_VAR`].
+///
+/// [`ColorPicker!`]: struct@ColorPicker
+#[property(CONTEXT, default(COLOR_VAR), widget_impl(ColorPicker))]
+pub fn color(child: impl IntoUiNode, color: impl IntoVar<Rgba>) -> UiNode {
+    with_context_var(child, COLOR_VAR, color)
+}
+
+/// Color space of the color picker area and extra input fields.
+///
+/// Is [`ColorPickerSpace::Hsv`] by default.
+///
+/// This property sets the [`COLOR_SPACE_VAR`].
+#[property(CONTEXT, default(COLOR_SPACE_VAR), widget_impl(ColorPicker))]
+pub fn color_space(child: impl IntoUiNode, space: impl IntoVar<ColorPickerSpace>) -> UiNode {
+    with_context_var(child, COLOR_SPACE_VAR, space)
+}
+
+/// Maximum chroma of the OKLCH area.
+const MAX_CHROMA: f32 = 0.4;
+
+/// Color picker node.
+///
+/// Can be used directly to present the picker area, sliders and input fields without declaring a color picker
+/// widget, the context variables are configured by the [`ColorPicker!`] properties.
+///
+/// [`ColorPicker!`]: struct@ColorPicker
+pub fn node() -> UiNode {
+    // the color is edited in the space models, so that hue and saturation are not lost when the color is gray or black.
+    let hsv = var(Hsva::from(colors::WHITE));
+    let lch = var(Oklch::from(colors::WHITE));
+
+    let area_id = WidgetId::new_unique();
+    let hue_id = WidgetId::new_unique();
+    let alpha_id = WidgetId::new_unique();
+
+    let content = Stack! {
+        direction = StackDirection::top_to_bottom();
+        spacing = 6;
+        children = ui_vec![
+            area(area_id, hsv.clone(), lch.clone()),
+            hue_slider(hue_id, hsv.clone(), lch.clone()),
+            alpha_slider(alpha_id),
+            fields(lch.clone()),
+        ];
+    };
+
+    let mut drag_target = None::<WidgetId>;
+
+    match_node(content, move |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET
+                .sub_var(&COLOR_VAR)
+                .sub_var(&COLOR_SPACE_VAR)
+                .sub_var(&hsv)
+                .sub_var(&lch)
+                .sub_event(&MOUSE_INPUT_EVENT)
+                .sub_event(&MOUSE_MOVE_EVENT);
+
+            let color = COLOR_VAR.get();
+            hsv.set(Hsva::from(color));
+            lch.set(Oklch::from(color));
+        }
+        UiNodeOp::Deinit => {
+            drag_target = None;
+        }
+        UiNodeOp::Update { updates } => {
+            c.update(updates);
+
+            let mut pick_pos = None;
+            MOUSE_INPUT_EVENT.each_update(false, |args| {
+                if !args.is_primary() {
+                    return;
+                }
+                if args.state == ButtonState::Pressed {
+                    drag_target = [area_id, hue_id, alpha_id].into_iter().find(|id| args.target.contains_enabled(*id));
+                    if drag_target.is_some() {
+                        pick_pos = Some(args.position);
+                    }
+                } else {
+                    drag_target = None;
+                }
+            });
+            if let Some(target) = drag_target {
+                MOUSE_MOVE_EVENT.each_update(false, |args| {
+                    if let Some(cap) = &args.capture
+                        && cap.target.contains(target)
+                    {
+                        pick_pos = Some(args.position);
+                        args.propagation.stop();
+                    }
+                });
+            }
+
+            if let Some(target) = drag_target
+                && let Some(pos) = pick_pos
+            {
+                let info = WIDGET.info();
+                if let Some(wgt) = info.tree().get(target) {
+                    let pos = pos.to_px(info.tree().scale_factor());
+                    let bounds = wgt.inner_bounds();
+                    let fct = |p: Px, origin: Px, len: Px| {
+                        if len > Px(0) {
+                            ((p - origin).0 as f32 / len.0 as f32).clamp(0.0, 1.0)
+                        } else {
+                            0.0
+                        }
+                    };
+                    let fx = fct(pos.x, bounds.origin.x, bounds.size.width);
+                    let fy = fct(pos.y, bounds.origin.y, bounds.size.height);
+
+                    // only the model of the current space is edited, the other is updated from the new color.
+                    let space = COLOR_SPACE_VAR.get();
+                    if target == area_id {
+                        match space {
+                            ColorPickerSpace::Hsv => hsv.modify(move |c| {
+                                c.saturation = fx;
+                                c.value = 1.0 - fy;
+                            }),
+                            ColorPickerSpace::Oklch => lch.modify(move |c| {
+                                c.chroma = fx * MAX_CHROMA;
+                                c.lightness = 1.0 - fy;
+                            }),
+                        }
+                    } else if target == hue_id {
+                        match space {
+                            ColorPickerSpace::Hsv => hsv.modify(move |c| c.hue = fx * 360.0),
+                            ColorPickerSpace::Oklch => lch.modify(move |c| c.hue = fx * 360.0),
+                        }
+                    } else {
+                        match space {
+                            ColorPickerSpace::Hsv => hsv.modify(move |c| c.alpha = fx),
+                            ColorPickerSpace::Oklch => lch.modify(move |c| c.alpha = fx),
+                        }
+                    }
+                }
+            }
+
+            if let Some(color) = COLOR_VAR.get_new() {
+                if !same_color(Rgba::from(hsv.get()), color) {
+                    hsv.modify(move |c| {
+                        let mut new = Hsva::from(color);
+                        if new.value <= 0.001 {
+                            new.hue = c.hue;
+                            new.saturation = c.saturation;
+                        } else if new.saturation <= 0.001 {
+                            new.hue = c.hue;
+                        }
+                        c.set(new);
+                    });
+                }
+                if !same_color(Rgba::from(lch.get()), color) {
+                    lch.modify(move |c| {
+                        let mut new = Oklch::from(color);
+                        if new.chroma <= 0.001 {
+                            new.hue = c.hue;
+                        }
+                        c.set(new);
+                    });
+                }
+            } else {
+                let model = match COLOR_SPACE_VAR.get() {
+                    ColorPickerSpace::Hsv => hsv.get_new().map(Rgba::from),
+                    ColorPickerSpace::Oklch => lch.get_new().map(Rgba::from),
+                };
+                if let Some(model) = model
+                    && !same_color(model, COLOR_VAR.get())
+                {
+                    COLOR_VAR.set(model);
+                }
+            }
+        }
+        _ => {}
+    })
+}
+
+/// If the colors are equal after 8-bit quantization, avoids update loops caused by rounding in the
+/// color space conversions.
+fn same_color(a: Rgba, b: Rgba) -> bool {
+    const TOLERANCE: f32 = 1.5 / 255.0;
+    (a.red - b.red).abs() <= TOLERANCE
+        && (a.green - b.green).abs() <= TOLERANCE
+        && (a.blue - b.blue).abs() <= TOLERANCE
+        && (a.alpha - b.alpha).abs() <= TOLERANCE
+}
+
+fn clipboard_node(child: impl IntoUiNode) -> UiNode {
+    let mut _copy_handle = CommandHandle::dummy();
+    let mut _paste_handle = CommandHandle::dummy();
+    match_node(child, move |c, op| match op {
+        UiNodeOp::Init => {
+            let id = WIDGET.id();
+            _copy_handle = COPY_CMD.scoped(id).subscribe(true);
+            _paste_handle = PASTE_CMD.scoped(id).subscribe(true);
+        }
+        UiNodeOp::Deinit => {
+            _copy_handle = CommandHandle::dummy();
+            _paste_handle = CommandHandle::dummy();
+        }
+        UiNodeOp::Update { updates } => {
+            // text inputs inside handle their own scoped commands.
+            c.update(updates);
+
+            let id = WIDGET.id();
+            COPY_CMD.scoped(id).each_update(true, false, |args| {
+                args.propagation.stop();
+                let _ = CLIPBOARD.set_text(COLOR_VAR.get().to_txt());
+            });
+            PASTE_CMD.scoped(id).each_update(true, false, |args| {
+                if let Some(txt) = CLIPBOARD.text().ok().flatten()
+                    && let Ok(color) = txt.trim().parse::<Rgba>()
+                {
+                    args.propagation.stop();
+                    COLOR_VAR.set(color);
+                }
+            });
+        }
+        _ => {}
+    })
+}
+
+fn area(id: WidgetId, hsv: Var<Hsva>, lch: Var<Oklch>) -> UiNode {
+    let pos = merge_var!(COLOR_SPACE_VAR, hsv.clone(), lch.clone(), |s, h, c| match s {
+        ColorPickerSpace::Hsv => (h.saturation, 1.0 - h.value),
+        ColorPickerSpace::Oklch => (c.chroma / MAX_CHROMA, 1.0 - c.lightness),
+    });
+    Container! {
+        id;
+        height = 150;
+        capture_pointer = true;
+        cursor = CursorIcon::Crosshair;
+        zng_wgt::clip_to_bounds = true;
+        zng_wgt_fill::background = area_node(hsv, lch);
+        child_align = Align::TOP_LEFT;
+        child = Wgt! {
+            size = 12;
+            corner_radius = 6;
+            border = 2, colors::WHITE;
+            x = pos.map(|p| Length::from(p.0.clamp(0.0, 1.0).fct()) - 6.dip());
+            y = pos.map(|p| Length::from(p.1.clamp(0.0, 1.0).fct()) - 6.dip());
+        };
+    }
+}
+
+fn area_node(hsv: Var<Hsva>, lch: Var<Oklch>) -> UiNode {
+    const ROWS: usize = 32;
+    const COLUMNS: usize = 8;
+
+    let mut render_size = PxSize::zero();
+    let mut stops = Vec::with_capacity(COLUMNS + 1);
+    match_node_leaf(move |op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_var_render(&COLOR_SPACE_VAR).sub_var_render(&hsv).sub_var_render(&lch);
+        }
+        UiNodeOp::Measure { desired_size, .. } => {
+            *desired_size = LAYOUT.constraints().fill_size();
+        }
+        UiNodeOp::Layout { final_size, .. } => {
+            *final_size = LAYOUT.constraints().fill_size();
+            if *final_size != render_size {
+                render_size = *final_size;
+                WIDGET.render();
+            }
+        }
+        UiNodeOp::Render { frame } => {
+            if render_size.is_empty() {
+                return;
+            }
+            let rect = PxRect::from_size(render_size);
+            let stop = |offset: f32, color: Rgba| RenderGradientStop { offset, color };
+            match COLOR_SPACE_VAR.get() {
+                ColorPickerSpace::Hsv => {
+                    let hue = Rgba::from(hsv.get().with_saturation(1.fct()).with_value(1.fct()).with_alpha(1.fct()));
+                    frame.push_color(rect, FrameValue::Value(hue));
+                    frame.push_linear_gradient(
+                        rect,
+                        PxLine::new(PxPoint::zero(), PxPoint::new(render_size.width, Px(0))),
+                        &[stop(0.0, colors::WHITE), stop(1.0, colors::WHITE.transparent())],
+                        RenderExtendMode::Clamp,
+                        PxPoint::zero(),
+                        render_size,
+                        PxSize::zero(),
+                    );
+                    frame.push_linear_gradient(
+                        rect,
+                        PxLine::new(PxPoint::zero(), PxPoint::new(Px(0), render_size.height)),
+                        &[stop(0.0, colors::BLACK.transparent()), stop(1.0, colors::BLACK)],
+                        RenderExtendMode::Clamp,
+                        PxPoint::zero(),
+                        render_size,
+                        PxSize::zero(),
+                    );
+                }
+                ColorPickerSpace::Oklch => {
+                    // OKLCH is not linear in sRGB, so the area is rendered in rows of horizontal gradients.
+                    let hue = lch.get().hue;
+                    let row_height = render_size.height.0 as f32 / ROWS as f32;
+                    for r in 0..ROWS {
+                        let y0 = Px((r as f32 * row_height).round() as i32);
+                        let y1 = Px(((r + 1) as f32 * row_height).round() as i32);
+                        if y1 <= y0 {
+                            continue;
+                        }
+                        let lightness = 1.0 - (r as f32 + 0.5) / ROWS as f32;
+                        stops.clear();
+                        for i in 0..=COLUMNS {
+                            let f = i as f32 / COLUMNS as f32;
+                            stops.push(stop(f, oklch(lightness, f * MAX_CHROMA, hue.deg()).into()));
+                        }
+                        let row = PxRect::new(PxPoint::new(Px(0), y0), PxSize::new(render_size.width, y1 - y0));
+                        frame.push_linear_gradient(
+                            row,
+                            PxLine::new(PxPoint::zero(), PxPoint::new(render_size.width, Px(0))),
+                            &stops,
+                            RenderExtendMode::Clamp,
+                            row.origin,
+                            row.size,
+                            PxSize::zero(),
+                        );
+                    }
+                }
+            }
+        }
+        _ => {}
+    })
+}
+
+fn slider_thumb(fct: Var<f32>) -> UiNode {
+    Wgt! {
+        size = (6, 100.pct());
+        corner_radius = 3;
+        border = 1, colors::WHITE;
+        x = fct.map(|f| Length::from(f.clamp(0.0, 1.0).fct()) - 3.dip());
+    }
+}
+
+fn hue_slider(id: WidgetId, hsv: Var<Hsva>, lch: Var<Oklch>) -> UiNode {
+    let stops = COLOR_SPACE_VAR.map(|s| {
+        let colors: Vec<Rgba> = match s {
+            ColorPickerSpace::Hsv => (0..=6).map(|i| hsv_color(i as f32 * 60.0)).collect(),
+            ColorPickerSpace::Oklch => (0..=12).map(|i| oklch(0.75, 0.12, (i as f32 * 30.0).deg()).into()).collect(),
+        };
+        GradientStops::from_colors(&colors)
+    });
+    let hue = merge_var!(COLOR_SPACE_VAR, hsv, lch, |s, h, c| match s {
+        ColorPickerSpace::Hsv => h.hue / 360.0,
+        ColorPickerSpace::Oklch => c.hue / 360.0,
+    });
+    Container! {
+        id;
+        height = 14;
+        capture_pointer = true;
+        corner_radius = 3;
+        zng_wgt::clip_to_bounds = true;
+        zng_wgt_fill::background_gradient = Line::to_right(), stops;
+        child_align = Align::TOP_LEFT;
+        child = slider_thumb(hue);
+    }
+}
+
+fn hsv_color(hue: f32) -> Rgba {
+    hsv(hue.deg(), 100.pct(), 100.pct()).into()
+}
+
+fn alpha_slider(id: WidgetId) -> UiNode {
+    let stops = COLOR_VAR.map(|c| GradientStops::from_colors(&[c.transparent(), c.with_alpha(1.fct())]));
+    Container! {
+        id;
+        height = 14;
+        capture_pointer = true;
+        corner_radius = 3;
+        zng_wgt::clip_to_bounds = true;
+        zng_wgt_fill::background = zng_wgt_checkerboard::Checkerboard!();
+        child_align = Align::FILL;
+        child = Container! {
+            zng_wgt_fill::background_gradient = Line::to_right(), stops;
+            child_align = Align::TOP_LEFT;
+            child = slider_thumb(COLOR_VAR.map(|c| c.alpha));
+        };
+    }
+}
+
+fn fields(lch: Var<Oklch>) -> UiNode {
+    fn field(label: &'static str, input: UiNode) -> UiNode {
+        Stack! {
+            direction = StackDirection::left_to_right();
+            spacing = 2;
+            width = 1.lft();
+            children = ui_vec![
+                Text! {
+                    txt = label;
+                    zng_wgt::align = Align::CENTER;
+                },
+                input,
+            ];
+        }
+    }
+    fn rgb(label: &'static str, access_label: Var<Txt>, get: fn(&Rgba) -> f32, set: fn(&mut Rgba, f32)) -> UiNode {
+        field(
+            label,
+            NumberInput! {
+                value = COLOR_VAR.map_bidi_modify(move |c| (get(c) * 255.0).round() as u8, move |v, c| set(c, *v as f32 / 255.0));
+                zng_wgt_text_input::number_input::value_min = 0.0;
+                zng_wgt_text_input::number_input::value_max = 255.0;
+                zng_wgt_access::label = access_label;
+            },
+        )
+    }
+
+    let hex = TextInput! {
+        zng_wgt_text::txt_parse = COLOR_VAR;
+        zng_wgt_text::txt_parse_on_stop = true;
+        width = 90;
+        zng_wgt_access::label = l10n!("ColorPicker.hex", "Hex");
+    };
+
+    let rgb_row = Stack! {
+        direction = StackDirection::left_to_right();
+        spacing = 4;
+        children = ui_vec![
+            hex,
+            rgb("R", l10n!("ColorPicker.red", "Red"), |c| c.red, |c, v| c.red = v),
+            rgb("G", l10n!("ColorPicker.green", "Green"), |c| c.green, |c, v| c.green = v),
+            rgb("B", l10n!("ColorPicker.blue", "Blue"), |c| c.blue, |c, v| c.blue = v),
+        ];
+    };
+
+    let lch_row = Stack! {
+        direction = StackDirection::left_to_right();
+        spacing = 4;
+        visibility = COLOR_SPACE_VAR.map(|s| Visibility::from(s.is_oklch()));
+        children = ui_vec![
+            field(
+                "L",
+                NumberInput! {
+                    zng_wgt_access::label = l10n!("ColorPicker.lightness", "Lightness");
+                    value = lch.map_bidi_modify(|c| (c.lightness * 1000.0).round() / 10.0, |v, c| c.lightness = *v / 100.0);
+                    zng_wgt_text_input::number_input::value_min = 0.0;
+                    zng_wgt_text_input::number_input::value_max = 100.0;
+                }
+            ),
+            field(
+                "C",
+                NumberInput! {
+                    zng_wgt_access::label = l10n!("ColorPicker.chroma", "Chroma");
+                    value = lch.map_bidi_modify(|c| (c.chroma * 1000.0).round() / 1000.0, |v, c| c.chroma = *v);
+                    zng_wgt_text_input::number_input::value_min = 0.0;
+                    zng_wgt_text_input::number_input::value_max = MAX_CHROMA as f64;
+                    zng_wgt_text_input::number_input::value_step = 0.01;
+                }
+            ),
+            field(
+                "H",
+                NumberInput! {
+                    zng_wgt_access::label = l10n!("ColorPicker.hue", "Hue");
+                    value = lch.map_bidi_modify(|c| c.hue.round(), |v, c| c.hue = *v);
+                    zng_wgt_text_input::number_input::value_min = 0.0;
+                    zng_wgt_text_input::number_input::value_max = 360.0;
+                }
+            ),
+        ];
+    };
+
+    Stack! {
+        direction = StackDirection::top_to_bottom();
+        spacing = 4;
+        children = ui_vec![rgb_row, lch_row];
+    }
+}
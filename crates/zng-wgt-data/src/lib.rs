@@ -19,6 +19,7 @@ use std::{
 };
 
 use zng_color::COLOR_SCHEME_VAR;
+use zng_ext_input::focus::{FOCUS, FocusInfoTree, WidgetInfoFocusExt as _};
 use zng_var::{AnyVar, Var, contextual_var};
 use zng_wgt::prelude::*;
 
@@ -415,6 +416,11 @@ impl DATA {
     pub fn annotate(&self, level: DataNoteLevel, note: impl DataNoteValue) -> DataNoteHandle {
         if !DATA_NOTES_CTX.is_default() {
             let (note, handle) = DataNote::new(WIDGET.id(), level, note);
+            {
+                let mut sv = DATA_SV.write();
+                sv.notes.retain(|n| n.retain());
+                sv.notes.push(note.clone());
+            }
             let notes = DATA_NOTES_CTX.get();
             let mut notes = notes.write();
             notes.notes.notes.push(note);
@@ -446,6 +452,68 @@ impl DATA {
         self.annotate(DataNoteLevel::ERROR, note)
     }
 
+    /// Focus the first focusable widget in the current window that has an `ERROR` note.
+    ///
+    /// Returns the widget that was requested to focus. See [`focus_first_note`] for more details.
+    ///
+    /// [`focus_first_note`]: Self::focus_first_note
+    pub fn focus_first_error(&self) -> Option<WidgetId> {
+        self.focus_first_note(DataNoteLevel::ERROR)
+    }
+
+    /// Focus the first focusable widget in the current window that has a note of `min_level` or above.
+    ///
+    /// The window focus tree is scanned in widget tree order, a focusable widget matches if it or an ancestor is the
+    /// [`source`] of a note, or if a descendant that is not focusable is the source. Focus is requested
+    /// using [`FOCUS.focus_widget`] with highlight. Returns the widget that was requested to focus or `None` if no
+    /// focusable widget has notes of the level.
+    ///
+    /// This is useful after a form submit fails, to move the user to the first invalid field.
+    ///
+    /// # Panics
+    ///
+    /// Panics if not called inside a window context.
+    ///
+    /// [`source`]: DataNote::source
+    /// [`FOCUS.focus_widget`]: zng_ext_input::focus::FOCUS::focus_widget
+    pub fn focus_first_note(&self, min_level: DataNoteLevel) -> Option<WidgetId> {
+        let tree = WINDOW.info();
+        let (disabled, hidden) = (FOCUS.focus_disabled_widgets().get(), FOCUS.focus_hidden_widgets().get());
+
+        let mut sources = IdSet::default();
+        {
+            let mut sv = DATA_SV.write();
+            sv.notes.retain(|n| n.retain());
+            for note in sv.notes.iter().filter(|n| n.level >= min_level) {
+                if let Some(wgt) = tree.get(note.source) {
+                    if wgt.clone().into_focusable(disabled, hidden).is_none()
+                        && wgt.descendants().all(|d| d.into_focusable(disabled, hidden).is_none())
+                        && let Some(f) = wgt.ancestors().find_map(|a| a.into_focusable(disabled, hidden))
+                    {
+                        // source inside a focusable, like a text node inside a text input.
+                        sources.insert(f.info().id());
+                    } else {
+                        sources.insert(note.source);
+                    }
+                }
+            }
+        }
+        if sources.is_empty() {
+            return None;
+        }
+
+        let tree = FocusInfoTree::new(tree, disabled, hidden);
+        let target = tree
+            .focusable_root()?
+            .self_and_descendants()
+            .find(|w| w.info().self_and_ancestors().any(|a| sources.contains(&a.id())))?
+            .info()
+            .id();
+
+        FOCUS.focus_widget(target, true);
+        Some(target)
+    }
+
     /// Read-only variable that is the best color for the note level in the context of the current color scheme.
     ///
     /// If the `level` is not found, gets the nearest less than level, if no color is set in the context gets
@@ -502,6 +570,15 @@ impl DATA {
 #[derive(Debug, PartialEq, Clone)]
 struct DataContextNotSet;
 
+#[derive(Default)]
+struct DataService {
+    notes: Vec<DataNote>,
+}
+
+app_local! {
+    static DATA_SV: DataService = DataService::default();
+}
+
 context_local! {
     static DATA_CTX: AnyVar = const_var(DataContextNotSet);
     static DATA_NOTES_CTX: RwLock<DataNotesProbe> = RwLock::default();
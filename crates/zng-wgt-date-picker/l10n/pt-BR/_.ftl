@@ -0,0 +1,21 @@
+DatePicker =
+    .month_april = Abril
+    .month_august = Agosto
+    .month_december = Dezembro
+    .month_february = Fevereiro
+    .month_january = Janeiro
+    .month_july = Julho
+    .month_june = Junho
+    .month_march = Março
+    .month_may = Maio
+    .month_november = Novembro
+    .month_october = Outubro
+    .month_september = Setembro
+    .month_year = {$month} de {$year}
+    .weekday_friday = Sx
+    .weekday_monday = Sg
+    .weekday_saturday = Sb
+    .weekday_sunday = Dm
+    .weekday_thursday = Qi
+    .weekday_tuesday = Te
+    .weekday_wednesday = Qa
@@ -0,0 +1,23 @@
+### Auto generated by `cargo zng l10n`
+
+DatePicker =
+    .month_april = April
+    .month_august = August
+    .month_december = December
+    .month_february = February
+    .month_january = January
+    .month_july = July
+    .month_june = June
+    .month_march = March
+    .month_may = May
+    .month_november = November
+    .month_october = October
+    .month_september = September
+    .month_year = {$month} {$year}
+    .weekday_friday = Fr
+    .weekday_monday = Mo
+    .weekday_saturday = Sa
+    .weekday_sunday = Su
+    .weekday_thursday = Th
+    .weekday_tuesday = Tu
+    .weekday_wednesday = We
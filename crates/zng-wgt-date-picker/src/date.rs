@@ -0,0 +1,348 @@
+use std::{fmt, str::FromStr};
+
+use zng_ext_l10n::Lang;
+
+/// Calendar date in the proleptic Gregorian calendar.
+///
+/// The date has no time zone, [`today`] uses the local system time.
+///
+/// [`today`]: Date::today
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+impl Date {
+    /// New date, returns `None` if the month is not in `1..=12` or the day is not in the month.
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month) {
+            Some(Self { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    /// Current date in the local time zone.
+    pub fn today() -> Self {
+        use chrono::Datelike as _;
+
+        let today = chrono::Local::now().date_naive();
+        Self {
+            year: today.year(),
+            month: today.month() as u8,
+            day: today.day() as u8,
+        }
+    }
+
+    /// Date from the number of days since `1970-01-01`.
+    pub fn from_days(days: i64) -> Self {
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = (yoe + era * 400 + if month <= 2 { 1 } else { 0 }) as i32;
+        Self { year, month, day }
+    }
+
+    /// Number of days since `1970-01-01`.
+    pub fn to_days(self) -> i64 {
+        let m = self.month as i64;
+        let y = self.year as i64 - if m <= 2 { 1 } else { 0 };
+        let era = y.div_euclid(400);
+        let yoe = y - era * 400;
+        let doy = (153 * if m > 2 { m - 3 } else { m + 9 } + 2) / 5 + self.day as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146097 + doe - 719468
+    }
+
+    /// Year.
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    /// Month, in `1..=12`.
+    pub fn month(self) -> u8 {
+        self.month
+    }
+
+    /// Day of the month, starting at `1`.
+    pub fn day(self) -> u8 {
+        self.day
+    }
+
+    /// Day of the week.
+    pub fn weekday(self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::from_index((self.to_days() + 4).rem_euclid(7) as u8)
+    }
+
+    /// Number of days in the date month.
+    pub fn days_in_month(self) -> u8 {
+        days_in_month(self.year, self.month)
+    }
+
+    /// First day of the date month.
+    pub fn first_of_month(self) -> Self {
+        Self { day: 1, ..self }
+    }
+
+    /// Add or subtract days.
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// Add or subtract months, the day is clamped to the new month length.
+    pub fn add_months(self, months: i32) -> Self {
+        let m = self.year as i64 * 12 + (self.month as i64 - 1) + months as i64;
+        let year = m.div_euclid(12) as i32;
+        let month = (m.rem_euclid(12) + 1) as u8;
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    /// First date of the week that contains this date.
+    pub fn start_of_week(self, first_weekday: Weekday) -> Self {
+        let offset = (self.weekday().index() as i64 - first_weekday.index() as i64).rem_euclid(7);
+        self.add_days(-offset)
+    }
+}
+impl Default for Date {
+    /// `1970-01-01`
+    fn default() -> Self {
+        Self::from_days(0)
+    }
+}
+impl fmt::Debug for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Date({self})")
+    }
+}
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+impl FromStr for Date {
+    type Err = ParseDateError;
+
+    /// Parse ISO 8601 `YYYY-MM-DD`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (sign, s) = match s.strip_prefix('-') {
+            Some(s) => (-1, s),
+            None => (1, s),
+        };
+        let mut parts = s.splitn(3, '-');
+        let mut next = || parts.next().ok_or(ParseDateError);
+        let year: i32 = next()?.parse().map_err(|_| ParseDateError)?;
+        let month: u8 = next()?.parse().map_err(|_| ParseDateError)?;
+        let day: u8 = next()?.parse().map_err(|_| ParseDateError)?;
+        Self::new(year * sign, month, day).ok_or(ParseDateError)
+    }
+}
+
+/// Error parsing a [`Date`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ParseDateError;
+impl fmt::Display for ParseDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid date, expected `YYYY-MM-DD`")
+    }
+}
+impl std::error::Error for ParseDateError {}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Day of the week.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[allow(missing_docs)]
+pub enum Weekday {
+    Sunday,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+}
+impl Weekday {
+    /// All weekdays, starting on Sunday.
+    pub const ALL: [Weekday; 7] = [
+        Self::Sunday,
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+    ];
+
+    /// Weekday from index, `0` is Sunday, wraps around.
+    pub fn from_index(index: u8) -> Self {
+        Self::ALL[(index % 7) as usize]
+    }
+
+    /// Weekday index, `0` is Sunday.
+    pub fn index(self) -> u8 {
+        self as u8
+    }
+
+    /// Next weekday, `n` days ahead.
+    pub fn add(self, n: u8) -> Self {
+        Self::from_index(self.index() + n % 7)
+    }
+
+    /// Gets the first day of the week commonly used in the `lang` region.
+    ///
+    /// Returns Monday if the region is not set or not known to use a different day.
+    pub fn first_of_week(lang: &Lang) -> Self {
+        let region = match &lang.region {
+            Some(r) => r.as_str(),
+            None => match lang.language.as_str() {
+                "en" => "US",
+                "pt" => "BR",
+                "ja" => "JP",
+                "ko" => "KR",
+                "he" => "IL",
+                "ar" => "SA",
+                "fa" => "IR",
+                _ => "",
+            },
+        };
+        match region {
+            "AG" | "AS" | "BD" | "BR" | "BS" | "BT" | "BW" | "BZ" | "CA" | "CN" | "CO" | "DM" | "DO" | "ET" | "GT" | "GU" | "HK" | "HN"
+            | "ID" | "IL" | "IN" | "JM" | "JP" | "KE" | "KH" | "KR" | "LA" | "MH" | "MM" | "MO" | "MT" | "MX" | "MZ" | "NI" | "NP"
+            | "PA" | "PE" | "PH" | "PK" | "PR" | "PT" | "PY" | "SA" | "SG" | "SV" | "TH" | "TT" | "TW" | "UM" | "US" | "VE" | "VI"
+            | "WS" | "YE" | "ZA" | "ZW" => Self::Sunday,
+            "AE" | "AF" | "BH" | "DJ" | "DZ" | "EG" | "IQ" | "IR" | "JO" | "KW" | "LY" | "OM" | "QA" | "SD" | "SY" => Self::Saturday,
+            "MV" => Self::Friday,
+            _ => Self::Monday,
+        }
+    }
+}
+impl fmt::Debug for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "Weekday::")?;
+        }
+        let name = match self {
+            Self::Sunday => "Sunday",
+            Self::Monday => "Monday",
+            Self::Tuesday => "Tuesday",
+            Self::Wednesday => "Wednesday",
+            Self::Thursday => "Thursday",
+            Self::Friday => "Friday",
+            Self::Saturday => "Saturday",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Inclusive range of dates.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct DateRange {
+    /// First date.
+    pub start: Date,
+    /// Last date, inclusive.
+    pub end: Date,
+}
+impl DateRange {
+    /// New range, the dates are sorted.
+    pub fn new(a: Date, b: Date) -> Self {
+        if a <= b {
+            Self { start: a, end: b }
+        } else {
+            Self { start: b, end: a }
+        }
+    }
+
+    /// Range of a single date.
+    pub fn single(date: Date) -> Self {
+        Self { start: date, end: date }
+    }
+
+    /// If the range has only one date.
+    pub fn is_single(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// If the `date` is in the range.
+    pub fn contains(&self, date: Date) -> bool {
+        self.start <= date && date <= self.end
+    }
+}
+impl fmt::Debug for DateRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DateRange({}..={})", self.start, self.end)
+    }
+}
+impl From<Date> for DateRange {
+    fn from(date: Date) -> Self {
+        Self::single(date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_round_trip() {
+        for days in -800_000..800_000 {
+            assert_eq!(days, Date::from_days(days).to_days());
+        }
+    }
+
+    #[test]
+    fn known_dates() {
+        assert_eq!(Date::from_days(0), Date::new(1970, 1, 1).unwrap());
+        assert_eq!(Date::new(2000, 2, 29).unwrap().to_days(), 11016);
+        assert_eq!(Date::new(2024, 7, 4).unwrap().weekday(), Weekday::Thursday);
+        assert!(Date::new(1900, 2, 29).is_none());
+        assert!(Date::new(2023, 13, 1).is_none());
+    }
+
+    #[test]
+    fn add_months_clamps_day() {
+        let d = Date::new(2024, 1, 31).unwrap();
+        assert_eq!(d.add_months(1), Date::new(2024, 2, 29).unwrap());
+        assert_eq!(d.add_months(-2), Date::new(2023, 11, 30).unwrap());
+        assert_eq!(d.add_months(12), Date::new(2025, 1, 31).unwrap());
+    }
+
+    #[test]
+    fn start_of_week() {
+        let d = Date::new(2024, 7, 4).unwrap();
+        assert_eq!(d.start_of_week(Weekday::Sunday), Date::new(2024, 6, 30).unwrap());
+        assert_eq!(d.start_of_week(Weekday::Monday), Date::new(2024, 7, 1).unwrap());
+        assert_eq!(d.start_of_week(Weekday::Thursday), d);
+    }
+
+    #[test]
+    fn parse_display() {
+        let d = Date::new(2024, 7, 4).unwrap();
+        assert_eq!(d.to_string(), "2024-07-04");
+        assert_eq!("2024-07-04".parse::<Date>(), Ok(d));
+        assert!("2024-02-30".parse::<Date>().is_err());
+    }
+}
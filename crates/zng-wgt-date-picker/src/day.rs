@@ -0,0 +1,128 @@
+//! Date picker day widget, properties and styles.
+
+use zng_wgt::{align, corner_radius, prelude::*};
+use zng_wgt_access::{AccessRole, access_role, selected};
+use zng_wgt_input::{gesture::on_click, is_cap_hovered};
+use zng_wgt_style::{Style, StyleMix, impl_style_fn};
+use zng_wgt_text::Text;
+
+use crate::{CURSOR_DATE_VAR, Date, SELECTION_VAR};
+
+/// Calendar day cell.
+///
+/// Shows the [`date`] day number and selects the date on click, shift click extends the selection in the
+/// range selection mode.
+///
+/// [`date`]: fn@date
+#[widget($crate::day::Day)]
+pub struct Day(StyleMix<WidgetBase>);
+impl Day {
+    fn widget_intrinsic(&mut self) {
+        self.style_intrinsic(STYLE_FN_VAR, property_id!(self::style_fn));
+
+        widget_set! {
+            self;
+            access_role = AccessRole::GridCell;
+            selected = is_selected_var();
+            on_click = hn!(|args| {
+                args.propagation.stop();
+                crate::select(DATE_VAR.get(), args.modifiers.has_shift());
+            });
+        }
+
+        self.widget_builder().push_build_action(|wgt| {
+            wgt.set_child(Text! {
+                txt = DATE_VAR.map(|d| d.day().to_txt());
+                align = Align::CENTER;
+            });
+        });
+    }
+}
+impl_style_fn!(Day, DefaultStyle);
+
+/// Default day style.
+#[widget($crate::day::DefaultStyle)]
+pub struct DefaultStyle(Style);
+impl DefaultStyle {
+    fn widget_intrinsic(&mut self) {
+        widget_set! {
+            self;
+            replace = true;
+            zng_wgt_size_offset::min_size = 28;
+            corner_radius = 4;
+            zng_wgt_container::padding = 2;
+
+            #[easing(150.ms())]
+            zng_wgt_fill::background_color = colors::BLACK.transparent();
+
+            when *#is_other_month {
+                zng_wgt_filter::opacity = 50.pct();
+            }
+            when *#is_today {
+                zng_wgt_text::font_weight = zng_ext_font::FontWeight::BOLD;
+            }
+            when *#is_cap_hovered {
+                #[easing(0.ms())]
+                zng_wgt_fill::background_color = light_dark(rgb(0.9, 0.9, 0.9), rgb(0.2, 0.2, 0.2)).rgba();
+            }
+            when *#is_cursor {
+                zng_wgt_fill::foreground_highlight = {
+                    offsets: 0,
+                    widths: 1,
+                    sides: colors::ACCENT_COLOR_VAR.rgba_into(),
+                };
+            }
+            when *#is_selected {
+                #[easing(0.ms())]
+                zng_wgt_fill::background_color = colors::ACCENT_COLOR_VAR.rgba();
+            }
+        }
+    }
+}
+
+context_var! {
+    /// Date of the day widget.
+    pub static DATE_VAR: Date = Date::default();
+}
+
+/// Date of the day widget.
+///
+/// This property sets the [`DATE_VAR`].
+#[property(CONTEXT, default(DATE_VAR), widget_impl(Day))]
+pub fn date(child: impl IntoUiNode, date: impl IntoVar<Date>) -> UiNode {
+    with_context_var(child, DATE_VAR, date)
+}
+
+fn is_selected_var() -> Var<bool> {
+    merge_var!(DATE_VAR, SELECTION_VAR, |d, s| s.contains(*d))
+}
+
+/// If the day date is in the date picker selection.
+#[property(EVENT, widget_impl(Day))]
+pub fn is_selected(child: impl IntoUiNode, state: impl IntoVar<bool>) -> UiNode {
+    bind_state(child, is_selected_var(), state)
+}
+
+/// If the day date is the date picker cursor date.
+#[property(EVENT, widget_impl(Day))]
+pub fn is_cursor(child: impl IntoUiNode, state: impl IntoVar<bool>) -> UiNode {
+    bind_state(child, merge_var!(DATE_VAR, CURSOR_DATE_VAR, |d, c| d == c), state)
+}
+
+/// If the day date is not in the month presented by the date picker.
+#[property(EVENT, widget_impl(Day))]
+pub fn is_other_month(child: impl IntoUiNode, state: impl IntoVar<bool>) -> UiNode {
+    bind_state(
+        child,
+        merge_var!(DATE_VAR, CURSOR_DATE_VAR, |d, c| d.first_of_month() != c.first_of_month()),
+        state,
+    )
+}
+
+/// If the day date is today.
+///
+/// The date is compared with [`Date::today`] when the day date changes.
+#[property(EVENT, widget_impl(Day))]
+pub fn is_today(child: impl IntoUiNode, state: impl IntoVar<bool>) -> UiNode {
+    bind_state(child, DATE_VAR.map(|&d| d == Date::today()), state)
+}
@@ -0,0 +1,340 @@
+#![doc(html_favicon_url = "https://zng-ui.github.io/res/zng-logo-icon.png")]
+#![doc(html_logo_url = "https://zng-ui.github.io/res/zng-logo.png")]
+//!
+//! Date picker widget, properties and date types.
+//!
+//! # Crate
+//!
+#![doc = include_str!(concat!("../", std::env!("CARGO_PKG_README")))]
+#![warn(unused_extern_crates)]
+#![warn(missing_docs)]
+
+zng_wgt::enable_widget_macros!();
+
+pub mod day;
+
+mod date;
+pub use date::*;
+
+use std::fmt;
+
+use zng_ext_input::keyboard::{KEY_INPUT_EVENT, Key, KeyState};
+use zng_ext_l10n::{LANG_VAR, l10n};
+use zng_wgt::{ICONS, prelude::*};
+use zng_wgt_access::{AccessRole, access_role};
+use zng_wgt_button::Button;
+use zng_wgt_grid::{self as grid, Grid};
+use zng_wgt_input::focus::focusable;
+use zng_wgt_stack::{Stack, StackDirection};
+use zng_wgt_style::{Style, StyleMix, impl_style_fn};
+use zng_wgt_text::Text;
+
+/// Calendar month view that selects a date or a range of dates.
+///
+/// The month of the [`cursor_date`] is presented in a [`Grid!`] of days, the [`selection`] is set on click or
+/// on `Enter` and `Space`. The cursor moves with arrow keys, `PageUp` and `PageDown` change month, `Home` and `End`
+/// move to the start and end of the week. If the [`selection_mode`] is [`DateSelectionMode::Range`] shift click
+/// and shift arrow keys extend the selection.
+///
+/// Month and day names are localized using [`l10n!`] and the first day of the week is selected from the [`LANG_VAR`]
+/// region by default, see [`first_weekday`] for more details.
+///
+/// # Shorthand
+///
+/// The `DatePicker!` macro provides a shorthand init that sets the selection, `DatePicker!(selection)`.
+///
+/// [`cursor_date`]: fn@cursor_date
+/// [`selection`]: fn@selection
+/// [`selection_mode`]: fn@selection_mode
+/// [`first_weekday`]: fn@first_weekday
+/// [`Grid!`]: struct@Grid
+/// [`l10n!`]: zng_ext_l10n::l10n
+#[widget($crate::DatePicker {
+    ($selection:expr) => {
+        selection = $selection;
+    };
+})]
+pub struct DatePicker(StyleMix<WidgetBase>);
+impl DatePicker {
+    fn widget_intrinsic(&mut self) {
+        self.style_intrinsic(STYLE_FN_VAR, property_id!(self::style_fn));
+
+        widget_set! {
+            self;
+            selection = var(DateRange::single(Date::today()));
+            cursor_date = var(Date::today());
+            focusable = true;
+            access_role = AccessRole::Group;
+        }
+
+        self.widget_builder().push_build_action(|wgt| {
+            wgt.set_child(calendar());
+            wgt.push_intrinsic(NestGroup::EVENT, "date-picker-keyboard", keyboard_node);
+        });
+    }
+}
+impl_style_fn!(DatePicker, DefaultStyle);
+
+/// Default date picker style.
+#[widget($crate::DefaultStyle)]
+pub struct DefaultStyle(Style);
+impl DefaultStyle {
+    fn widget_intrinsic(&mut self) {
+        widget_set! {
+            self;
+            replace = true;
+        }
+    }
+}
+
+/// Date picker selection mode.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum DateSelectionMode {
+    /// Selects one date, the [`DateRange`] start and end are always equal.
+    Single,
+    /// Selects a range of dates.
+    Range,
+}
+impl fmt::Debug for DateSelectionMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "DateSelectionMode::")?;
+        }
+        match self {
+            Self::Single => write!(f, "Single"),
+            Self::Range => write!(f, "Range"),
+        }
+    }
+}
+
+context_var! {
+    /// Selected dates.
+    pub static SELECTION_VAR: DateRange = DateRange::single(Date::default());
+
+    /// Date picker selection mode.
+    pub static SELECTION_MODE_VAR: DateSelectionMode = DateSelectionMode::Single;
+
+    /// Date of the keyboard cursor, the month of this date is presented.
+    pub static CURSOR_DATE_VAR: Date = Date::default();
+
+    /// First day of the week, the first column of the calendar.
+    pub static FIRST_WEEKDAY_VAR: Weekday = LANG_VAR.map(|l| Weekday::first_of_week(l.best()));
+}
+
+/// Selected dates.
+///
+/// Is a new `var(DateRange::single(Date::today()))` by default in [`DatePicker!`], set to a read-write variable
+/// to bind the selection. In the [`DateSelectionMode::Single`] mode only the range start needs to be read.
+///
+/// This property sets the [`SELECTION_VAR`].
+///
+/// [`DatePicker!`]: struct@DatePicker
+#[property(CONTEXT, default(SELECTION_VAR), widget_impl(DatePicker))]
+pub fn selection(child: impl IntoUiNode, selection: impl IntoVar<DateRange>) -> UiNode {
+    with_context_var(child, SELECTION_VAR, selection)
+}
+
+/// Date picker selection mode.
+///
+/// Is [`DateSelectionMode::Single`] by default.
+///
+/// This property sets the [`SELECTION_MODE_VAR`].
+#[property(CONTEXT, default(SELECTION_MODE_VAR), widget_impl(DatePicker))]
+pub fn selection_mode(child: impl IntoUiNode, mode: impl IntoVar<DateSelectionMode>) -> UiNode {
+    with_context_var(child, SELECTION_MODE_VAR, mode)
+}
+
+/// Date of the keyboard cursor, the month of this date is presented.
+///
+/// Is a new `var(Date::today())` by default in [`DatePicker!`], set to a read-write variable to control
+/// the presented month.
+///
+/// This property sets the [`CURSOR_DATE_VAR`].
+///
+/// [`DatePicker!`]: struct@DatePicker
+#[property(CONTEXT, default(CURSOR_DATE_VAR), widget_impl(DatePicker))]
+pub fn cursor_date(child: impl IntoUiNode, date: impl IntoVar<Date>) -> UiNode {
+    with_context_var(child, CURSOR_DATE_VAR, date)
+}
+
+/// First day of the week, the first column of the calendar.
+///
+/// By default is selected from the [`LANG_VAR`] region using [`Weekday::first_of_week`].
+///
+/// This property sets the [`FIRST_WEEKDAY_VAR`].
+#[property(CONTEXT, default(FIRST_WEEKDAY_VAR), widget_impl(DatePicker))]
+pub fn first_weekday(child: impl IntoUiNode, weekday: impl IntoVar<Weekday>) -> UiNode {
+    with_context_var(child, FIRST_WEEKDAY_VAR, weekday)
+}
+
+/// Move the cursor to `date` and select it, or extend the selection if `extend` is `true` and the
+/// [`SELECTION_MODE_VAR`] is [`DateSelectionMode::Range`].
+///
+/// Must be called inside a date picker context.
+pub fn select(date: Date, extend: bool) {
+    let cursor = CURSOR_DATE_VAR.get();
+    CURSOR_DATE_VAR.set(date);
+    if extend && SELECTION_MODE_VAR.get() == DateSelectionMode::Range {
+        let s = SELECTION_VAR.get();
+        // the selection end that is not at the cursor stays in place
+        let anchor = if s.start == cursor && !s.is_single() { s.end } else { s.start };
+        SELECTION_VAR.set(DateRange::new(anchor, date));
+    } else {
+        SELECTION_VAR.set(DateRange::single(date));
+    }
+}
+
+/// Localized month name, `month` is in `1..=12`.
+pub fn month_name(month: u8) -> Var<Txt> {
+    match month {
+        1 => l10n!("DatePicker.month_january", "January"),
+        2 => l10n!("DatePicker.month_february", "February"),
+        3 => l10n!("DatePicker.month_march", "March"),
+        4 => l10n!("DatePicker.month_april", "April"),
+        5 => l10n!("DatePicker.month_may", "May"),
+        6 => l10n!("DatePicker.month_june", "June"),
+        7 => l10n!("DatePicker.month_july", "July"),
+        8 => l10n!("DatePicker.month_august", "August"),
+        9 => l10n!("DatePicker.month_september", "September"),
+        10 => l10n!("DatePicker.month_october", "October"),
+        11 => l10n!("DatePicker.month_november", "November"),
+        12 => l10n!("DatePicker.month_december", "December"),
+        _ => const_var(Txt::from_static("")),
+    }
+}
+
+/// Localized short weekday name.
+pub fn weekday_name(weekday: Weekday) -> Var<Txt> {
+    match weekday {
+        Weekday::Sunday => l10n!("DatePicker.weekday_sunday", "Su"),
+        Weekday::Monday => l10n!("DatePicker.weekday_monday", "Mo"),
+        Weekday::Tuesday => l10n!("DatePicker.weekday_tuesday", "Tu"),
+        Weekday::Wednesday => l10n!("DatePicker.weekday_wednesday", "We"),
+        Weekday::Thursday => l10n!("DatePicker.weekday_thursday", "Th"),
+        Weekday::Friday => l10n!("DatePicker.weekday_friday", "Fr"),
+        Weekday::Saturday => l10n!("DatePicker.weekday_saturday", "Sa"),
+    }
+}
+
+fn calendar() -> UiNode {
+    // month navigation header, weekday names row and 6 weeks of days, so the grid size does not change.
+    let title = l10n!(
+        "DatePicker.month_year",
+        "{$month} {$year}",
+        month = CURSOR_DATE_VAR.flat_map(|d| month_name(d.month())),
+        year = CURSOR_DATE_VAR.map(|d| d.year().to_txt()),
+    );
+    let header = Stack! {
+        direction = StackDirection::left_to_right();
+        spacing = 4;
+        children = ui_vec![
+            month_button(false),
+            Text! {
+                txt = title;
+                zng_wgt_size_offset::width = 1.lft();
+                zng_wgt_text::txt_align = Align::CENTER;
+                zng_wgt::align = Align::FILL_X;
+            },
+            month_button(true),
+        ];
+    };
+
+    let mut cells = UiVec::with_capacity(7 * 7);
+    for i in 0..7u8 {
+        cells.push(Text! {
+            txt = FIRST_WEEKDAY_VAR.flat_map(move |f| weekday_name(f.add(i)));
+            zng_wgt_text::txt_align = Align::CENTER;
+            zng_wgt_text::font_weight = zng_ext_font::FontWeight::BOLD;
+            grid::cell::at = grid::cell::AT_AUTO;
+        });
+    }
+    for i in 0..(6 * 7) {
+        let date = merge_var!(CURSOR_DATE_VAR, FIRST_WEEKDAY_VAR, move |c, f| c
+            .first_of_month()
+            .start_of_week(*f)
+            .add_days(i));
+        cells.push(day::Day! {
+            day::date = date;
+            grid::cell::at = grid::cell::AT_AUTO;
+        });
+    }
+
+    Stack! {
+        direction = StackDirection::top_to_bottom();
+        spacing = 4;
+        children = ui_vec![
+            header,
+            Grid! {
+                columns = ui_vec![grid::Column!(1.lft()); 7];
+                auto_grow_fn = wgt_fn!(|_| grid::Row!());
+                spacing = 2;
+                cells;
+            },
+        ];
+    }
+}
+
+fn month_button(next: bool) -> UiNode {
+    let icon = if next {
+        ICONS.get_or(
+            ["date-picker.next-month", "material/rounded/chevron-right", "chevron-right"],
+            || Text!("›"),
+        )
+    } else {
+        ICONS.get_or(["date-picker.prev-month", "material/rounded/chevron-left", "chevron-left"], || {
+            Text!("‹")
+        })
+    };
+    Button! {
+        style_fn = zng_wgt_button::LightStyle!();
+        child = icon;
+        focusable = false;
+        on_click = hn!(|args| {
+            args.propagation.stop();
+            CURSOR_DATE_VAR.set(CURSOR_DATE_VAR.get().add_months(if next { 1 } else { -1 }));
+        });
+    }
+}
+
+fn keyboard_node(child: impl IntoUiNode) -> UiNode {
+    match_node(child, |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_event(&KEY_INPUT_EVENT);
+        }
+        UiNodeOp::Update { updates } => {
+            c.update(updates);
+
+            KEY_INPUT_EVENT.each_update(false, |args| {
+                if args.state != KeyState::Pressed || !args.target.contains_enabled(WIDGET.id()) {
+                    return;
+                }
+                let cursor = CURSOR_DATE_VAR.get();
+                let extend = args.modifiers.has_shift();
+                let rtl = DIRECTION_VAR.get().is_rtl();
+                let date = match &args.key {
+                    Key::ArrowLeft => cursor.add_days(if rtl { 1 } else { -1 }),
+                    Key::ArrowRight => cursor.add_days(if rtl { -1 } else { 1 }),
+                    Key::ArrowUp => cursor.add_days(-7),
+                    Key::ArrowDown => cursor.add_days(7),
+                    Key::PageUp => cursor.add_months(-1),
+                    Key::PageDown => cursor.add_months(1),
+                    Key::Home => cursor.start_of_week(FIRST_WEEKDAY_VAR.get()),
+                    Key::End => cursor.start_of_week(FIRST_WEEKDAY_VAR.get()).add_days(6),
+                    Key::Enter | Key::Space => {
+                        args.propagation.stop();
+                        select(cursor, extend);
+                        return;
+                    }
+                    _ => return,
+                };
+                args.propagation.stop();
+                if extend && SELECTION_MODE_VAR.get() == DateSelectionMode::Range {
+                    select(date, true);
+                } else {
+                    CURSOR_DATE_VAR.set(date);
+                }
+            });
+        }
+        _ => {}
+    })
+}
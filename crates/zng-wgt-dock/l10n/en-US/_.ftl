@@ -0,0 +1,7 @@
+DockArea =
+    .dock = Dock
+    .dock-bottom = Dock to Bottom
+    .dock-end = Dock to End
+    .dock-start = Dock to Start
+    .dock-top = Dock to Top
+    .float = Float
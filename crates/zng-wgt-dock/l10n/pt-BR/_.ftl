@@ -0,0 +1,7 @@
+DockArea =
+    .dock = Ancorar
+    .dock-bottom = Ancorar Embaixo
+    .dock-end = Ancorar no Fim
+    .dock-start = Ancorar no Início
+    .dock-top = Ancorar em Cima
+    .float = Flutuar
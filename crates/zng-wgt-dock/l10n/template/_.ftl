@@ -0,0 +1,9 @@
+### Auto generated by `cargo zng l10n`
+
+DockArea =
+    .dock = Dock
+    .dock-bottom = Dock to Bottom
+    .dock-end = Dock to End
+    .dock-start = Dock to Start
+    .dock-top = Dock to Top
+    .float = Float
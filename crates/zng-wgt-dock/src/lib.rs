@@ -0,0 +1,944 @@
+#![doc(html_favicon_url = "https://zng-ui.github.io/res/zng-logo-icon.png")]
+#![doc(html_logo_url = "https://zng-ui.github.io/res/zng-logo.png")]
+//!
+//! Dock area widget, layout and properties.
+//!
+//! # Crate
+//!
+#![doc = include_str!(concat!("../", std::env!("CARGO_PKG_README")))]
+#![warn(unused_extern_crates)]
+#![warn(missing_docs)]
+
+zng_wgt::enable_widget_macros!();
+
+use std::{
+    mem,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use zng_ext_l10n::l10n;
+use zng_ext_window::{WINDOW_Ext as _, WINDOWS, WindowChangedArgs, WindowCloseArgs, WindowRoot};
+use zng_wgt::prelude::*;
+use zng_wgt_button::Button;
+use zng_wgt_container::Container;
+use zng_wgt_menu::context::{ContextMenu, context_menu_fn};
+use zng_wgt_split::{SplitOrientation, SplitView};
+use zng_wgt_style::{Style, StyleMix, impl_style_fn};
+use zng_wgt_tabs::{TabHeaderArgs, TabItem, Tabs};
+use zng_wgt_text::Text;
+use zng_wgt_window::Window;
+
+/// Area of dockable panels.
+///
+/// The [`dock_layout`] variable defines where each panel is, docked panels are presented in nested [`SplitView!`] and
+/// [`Tabs!`] widgets, floating panels are presented in windows owned by the parent window. The [`panels`] define the
+/// title of each panel and the [`panel_fn`] generates the panel content.
+///
+/// The tab headers have a context menu that floats the panel or docks it to an edge of the area, the floating windows
+/// have a button that docks the panel back. Dragging the dividers, selecting, reordering and closing tabs and moving
+/// floating windows all modify the layout variable, so it must be read-write for that.
+///
+/// # Persistence
+///
+/// The [`DockLayout`] is serializable, bind the layout to a config variable to persist it, restore a saved workspace
+/// or reset the layout by setting the variable.
+///
+/// # Shorthand
+///
+/// The `DockArea!` macro provides a shorthand init that sets the layout, `DockArea!(layout)`.
+///
+/// [`dock_layout`]: fn@dock_layout
+/// [`panels`]: fn@panels
+/// [`panel_fn`]: fn@panel_fn
+/// [`SplitView!`]: struct@SplitView
+/// [`Tabs!`]: struct@Tabs
+#[widget($crate::DockArea {
+    ($layout:expr) => {
+        dock_layout = $layout;
+    };
+})]
+pub struct DockArea(StyleMix<WidgetBase>);
+impl DockArea {
+    fn widget_intrinsic(&mut self) {
+        self.style_intrinsic(STYLE_FN_VAR, property_id!(self::style_fn));
+
+        self.widget_builder().push_build_action(|wgt| {
+            wgt.set_child(node());
+        });
+    }
+}
+impl_style_fn!(DockArea, DefaultStyle);
+
+/// Default dock area style.
+#[widget($crate::DefaultStyle)]
+pub struct DefaultStyle(Style);
+impl DefaultStyle {
+    fn widget_intrinsic(&mut self) {
+        widget_set! {
+            self;
+            replace = true;
+        }
+    }
+}
+
+/// Edge or center of a dock area or docked tabs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DockSide {
+    /// Left side, or right side in right-to-left contexts.
+    Start,
+    /// Right side, or left side in right-to-left contexts.
+    End,
+    /// Top side.
+    Top,
+    /// Bottom side.
+    Bottom,
+    /// Stack as a tab.
+    Center,
+}
+
+/// Docked panels tree node.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum DockNode {
+    /// Panels stacked as tabs.
+    Tabs {
+        /// Panel keys, in the tabs order.
+        panels: Vec<Txt>,
+        /// Key of the selected panel.
+        selected: Txt,
+    },
+    /// Two areas separated by a divider.
+    Split {
+        /// Split orientation.
+        orientation: SplitOrientation,
+        /// Size of the first area as a fraction of the space available for both areas.
+        ratio: Factor,
+        /// First area.
+        first: Box<DockNode>,
+        /// Second area.
+        second: Box<DockNode>,
+    },
+}
+impl DockNode {
+    /// New tabs node, the first panel is selected.
+    pub fn tabs(panels: impl IntoIterator<Item = impl Into<Txt>>) -> Self {
+        let panels: Vec<Txt> = panels.into_iter().map(Into::into).collect();
+        Self::Tabs {
+            selected: panels.first().cloned().unwrap_or_default(),
+            panels,
+        }
+    }
+
+    /// New split node.
+    pub fn split(orientation: SplitOrientation, ratio: impl Into<Factor>, first: DockNode, second: DockNode) -> Self {
+        Self::Split {
+            orientation,
+            ratio: ratio.into(),
+            first: Box::new(first),
+            second: Box::new(second),
+        }
+    }
+
+    /// If the panel is in this node or descendants.
+    pub fn contains(&self, panel: &str) -> bool {
+        match self {
+            DockNode::Tabs { panels, .. } => panels.iter().any(|p| p == panel),
+            DockNode::Split { first, second, .. } => first.contains(panel) || second.contains(panel),
+        }
+    }
+
+    /// Node without empty tabs, splits with an empty side are replaced by the other side.
+    ///
+    /// Returns `None` if all tabs are empty.
+    pub fn normalized(self) -> Option<DockNode> {
+        match self {
+            DockNode::Tabs { panels, .. } if panels.is_empty() => None,
+            DockNode::Tabs { panels, selected } => {
+                let selected = if panels.contains(&selected) { selected } else { panels[0].clone() };
+                Some(DockNode::Tabs { panels, selected })
+            }
+            DockNode::Split {
+                orientation,
+                ratio,
+                first,
+                second,
+            } => match (first.normalized(), second.normalized()) {
+                (Some(first), Some(second)) => Some(DockNode::split(orientation, ratio, first, second)),
+                (Some(n), None) | (None, Some(n)) => Some(n),
+                (None, None) => None,
+            },
+        }
+    }
+
+    fn without(mut self, panel: &str) -> Option<DockNode> {
+        self.retain(panel);
+        self.normalized()
+    }
+
+    fn retain(&mut self, panel: &str) {
+        match self {
+            DockNode::Tabs { panels, .. } => panels.retain(|p| p != panel),
+            DockNode::Split { first, second, .. } => {
+                first.retain(panel);
+                second.retain(panel);
+            }
+        }
+    }
+
+    /// Copy without the split ratios, selection and tabs order.
+    fn structure(&self) -> DockNode {
+        match self {
+            DockNode::Tabs { panels, .. } => {
+                let mut panels = panels.clone();
+                panels.sort();
+                DockNode::Tabs {
+                    panels,
+                    selected: Txt::from_static(""),
+                }
+            }
+            DockNode::Split {
+                orientation,
+                first,
+                second,
+                ..
+            } => DockNode::split(*orientation, 0.5.fct(), first.structure(), second.structure()),
+        }
+    }
+
+    /// Path to the tabs that contain the panel, `false` is first and `true` is second.
+    fn find(&self, panel: &str, path: &mut Vec<bool>) -> bool {
+        match self {
+            DockNode::Tabs { panels, .. } => panels.iter().any(|p| p == panel),
+            DockNode::Split { first, second, .. } => {
+                path.push(false);
+                if first.find(panel, path) {
+                    return true;
+                }
+                path.pop();
+                path.push(true);
+                if second.find(panel, path) {
+                    return true;
+                }
+                path.pop();
+                false
+            }
+        }
+    }
+
+    fn get(&self, path: &[bool]) -> Option<&DockNode> {
+        match path.split_first() {
+            None => Some(self),
+            Some((is_second, path)) => match self {
+                DockNode::Split { first, second, .. } => {
+                    let node = if *is_second { second } else { first };
+                    node.get(path)
+                }
+                DockNode::Tabs { .. } => None,
+            },
+        }
+    }
+
+    fn get_mut(&mut self, path: &[bool]) -> Option<&mut DockNode> {
+        match path.split_first() {
+            None => Some(self),
+            Some((is_second, path)) => match self {
+                DockNode::Split { first, second, .. } => {
+                    let node = if *is_second { second } else { first };
+                    node.get_mut(path)
+                }
+                DockNode::Tabs { .. } => None,
+            },
+        }
+    }
+
+    fn first_tabs_mut(&mut self) -> &mut DockNode {
+        match self {
+            DockNode::Split { first, .. } => first.first_tabs_mut(),
+            tabs => tabs,
+        }
+    }
+
+    fn attach(self, panel: Txt, side: DockSide) -> DockNode {
+        let new = DockNode::tabs([panel]);
+        match side {
+            DockSide::Start => DockNode::split(SplitOrientation::Horizontal, 0.25.fct(), new, self),
+            DockSide::End => DockNode::split(SplitOrientation::Horizontal, 0.75.fct(), self, new),
+            DockSide::Top => DockNode::split(SplitOrientation::Vertical, 0.25.fct(), new, self),
+            DockSide::Bottom => DockNode::split(SplitOrientation::Vertical, 0.75.fct(), self, new),
+            DockSide::Center => match self.normalized() {
+                Some(mut n) => {
+                    if let DockNode::Tabs { panels, selected } = n.first_tabs_mut() {
+                        panels.push(panel.clone());
+                        *selected = panel;
+                    }
+                    n
+                }
+                None => new,
+            },
+        }
+    }
+}
+
+/// Floating panel, presented in a window.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FloatingPanel {
+    /// Panel key.
+    pub panel: Txt,
+    /// Window position.
+    pub position: DipPoint,
+    /// Window size.
+    pub size: DipSize,
+}
+
+/// Dock area layout.
+///
+/// The layout only references panels by key, it is serializable so that it can be persisted in a config.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct DockLayout {
+    /// Docked panels.
+    pub root: Option<DockNode>,
+    /// Floating panels.
+    pub floating: Vec<FloatingPanel>,
+}
+impl DockLayout {
+    /// New layout with docked panels.
+    pub fn new(root: DockNode) -> Self {
+        Self {
+            root: Some(root),
+            floating: vec![],
+        }
+    }
+
+    /// If the panel is docked or floating.
+    pub fn contains(&self, panel: &str) -> bool {
+        self.is_docked(panel) || self.is_floating(panel)
+    }
+
+    /// If the panel is docked.
+    pub fn is_docked(&self, panel: &str) -> bool {
+        self.root.as_ref().is_some_and(|r| r.contains(panel))
+    }
+
+    /// If the panel is floating.
+    pub fn is_floating(&self, panel: &str) -> bool {
+        self.floating.iter().any(|f| f.panel == panel)
+    }
+
+    /// Remove the panel from the layout.
+    ///
+    /// Returns `true` if the panel was in the layout.
+    pub fn remove(&mut self, panel: &str) -> bool {
+        let mut removed = false;
+        if self.is_docked(panel) {
+            self.root = self.root.take().and_then(|r| r.without(panel));
+            removed = true;
+        }
+        let len = self.floating.len();
+        self.floating.retain(|f| f.panel != panel);
+        removed || len != self.floating.len()
+    }
+
+    /// Dock the panel to a side of the area, or stack it as a tab in the first tabs.
+    ///
+    /// If the panel is already in the layout it is moved.
+    pub fn dock(&mut self, panel: impl Into<Txt>, side: DockSide) {
+        let panel = panel.into();
+        self.remove(&panel);
+        self.root = Some(match self.root.take() {
+            Some(root) => root.attach(panel, side),
+            None => DockNode::tabs([panel]),
+        });
+    }
+
+    /// Dock the panel to a side of the tabs that contain the `target` panel, or stack it as a tab in the same tabs.
+    ///
+    /// If the `target` is not docked this is the same as [`dock`].
+    ///
+    /// [`dock`]: Self::dock
+    pub fn dock_at(&mut self, panel: impl Into<Txt>, target: &str, side: DockSide) {
+        let panel = panel.into();
+        if panel == target {
+            return;
+        }
+        self.remove(&panel);
+
+        let mut path = vec![];
+        if let Some(root) = &mut self.root
+            && root.find(target, &mut path)
+            && let Some(node) = root.get_mut(&path)
+        {
+            let target = mem::replace(node, DockNode::tabs(Vec::<Txt>::new()));
+            *node = target.attach(panel, side);
+        } else {
+            self.dock(panel, side);
+        }
+    }
+
+    /// Float the panel in a window.
+    ///
+    /// If the panel is already in the layout it is moved.
+    pub fn float(&mut self, panel: impl Into<Txt>, position: DipPoint, size: DipSize) {
+        let panel = panel.into();
+        self.remove(&panel);
+        self.floating.push(FloatingPanel { panel, position, size });
+    }
+
+    /// Remove empty tabs and collapse splits with an empty side.
+    pub fn normalize(&mut self) {
+        self.root = self.root.take().and_then(DockNode::normalized);
+    }
+
+    fn node(&self, path: &[bool]) -> Option<&DockNode> {
+        self.root.as_ref().and_then(|r| r.get(path))
+    }
+
+    fn node_mut(&mut self, path: &[bool]) -> Option<&mut DockNode> {
+        self.root.as_mut().and_then(|r| r.get_mut(path))
+    }
+}
+
+/// Dock panel descriptor.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct DockPanel {
+    /// Unique panel key, identifies the panel in the [`DockLayout`].
+    pub key: Txt,
+    /// Tab header and floating window title.
+    pub title: Txt,
+    /// If the tab header shows a close button.
+    pub closable: bool,
+}
+impl DockPanel {
+    /// New panel that is not closable.
+    pub fn new(key: impl Into<Txt>, title: impl Into<Txt>) -> Self {
+        Self {
+            key: key.into(),
+            title: title.into(),
+            closable: false,
+        }
+    }
+
+    /// Set if the tab header shows a close button.
+    pub fn with_closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+}
+
+/// Arguments for the [`panel_fn`].
+///
+/// [`panel_fn`]: fn@panel_fn
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct DockPanelArgs {
+    /// Panel.
+    pub panel: DockPanel,
+    /// If the panel content is generated for a floating window.
+    pub is_floating: bool,
+}
+
+context_var! {
+    /// Dock area layout.
+    pub static DOCK_LAYOUT_VAR: DockLayout = DockLayout::default();
+    /// Dock panels.
+    pub static PANELS_VAR: Vec<DockPanel> = vec![];
+    /// Dock panel content generator.
+    pub static PANEL_FN_VAR: WidgetFn<DockPanelArgs> = WidgetFn::nil();
+}
+
+/// Dock area layout.
+///
+/// The variable is modified when panels are moved, resized or closed, so it must be read-write for that.
+///
+/// This property sets the [`DOCK_LAYOUT_VAR`].
+#[property(CONTEXT, default(DOCK_LAYOUT_VAR), widget_impl(DockArea))]
+pub fn dock_layout(child: impl IntoUiNode, layout: impl IntoVar<DockLayout>) -> UiNode {
+    with_context_var(child, DOCK_LAYOUT_VAR, layout)
+}
+
+/// Dock panels.
+///
+/// Panels in the layout that are not in this list use the key as title.
+///
+/// This property sets the [`PANELS_VAR`].
+#[property(CONTEXT, default(PANELS_VAR), widget_impl(DockArea))]
+pub fn panels(child: impl IntoUiNode, panels: impl IntoVar<Vec<DockPanel>>) -> UiNode {
+    with_context_var(child, PANELS_VAR, panels)
+}
+
+/// Dock panel content generator.
+///
+/// This property sets the [`PANEL_FN_VAR`].
+#[property(CONTEXT, default(PANEL_FN_VAR), widget_impl(DockArea))]
+pub fn panel_fn(child: impl IntoUiNode, wgt_fn: impl IntoVar<WidgetFn<DockPanelArgs>>) -> UiNode {
+    with_context_var(child, PANEL_FN_VAR, wgt_fn)
+}
+
+/// Dock area node.
+///
+/// Can be used directly to present docked panels without declaring a dock area widget, the context variables are
+/// configured by the [`DockArea!`] properties. Floating panel windows are owned by the window of the node.
+///
+/// [`DockArea!`]: struct@DockArea
+pub fn node() -> UiNode {
+    let mut structure = None;
+    let mut floating = FloatingWindows::default();
+    match_node(UiNode::nil(), move |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_var(&DOCK_LAYOUT_VAR).sub_var(&PANELS_VAR).sub_var(&PANEL_FN_VAR);
+
+            structure = DOCK_LAYOUT_VAR.with(|l| l.root.as_ref().map(DockNode::structure));
+            *c.node() = docked_node(structure.as_ref());
+            c.init();
+            floating.sync();
+        }
+        UiNodeOp::Deinit => {
+            c.deinit();
+            *c.node() = UiNode::nil();
+            floating.close_all();
+        }
+        UiNodeOp::Update { .. } => {
+            let mut regenerate = PANELS_VAR.is_new() || PANEL_FN_VAR.is_new();
+            if DOCK_LAYOUT_VAR.is_new() {
+                let new_structure = DOCK_LAYOUT_VAR.with(|l| l.root.as_ref().map(DockNode::structure));
+                if new_structure != structure {
+                    structure = new_structure;
+                    regenerate = true;
+                }
+                floating.sync();
+            }
+
+            if regenerate {
+                c.node().deinit();
+                *c.node() = docked_node(structure.as_ref());
+                c.node().init();
+                WIDGET.update_info().layout().render();
+            }
+        }
+        _ => {}
+    })
+}
+
+#[derive(Clone)]
+struct DockCtx {
+    layout: Var<DockLayout>,
+    panels: Vec<DockPanel>,
+    panel_fn: WidgetFn<DockPanelArgs>,
+    header_fn: WidgetFn<TabHeaderArgs>,
+}
+
+fn docked_node(structure: Option<&DockNode>) -> UiNode {
+    match structure {
+        Some(s) => {
+            let ctx = DockCtx {
+                layout: DOCK_LAYOUT_VAR.current_context(),
+                panels: PANELS_VAR.get(),
+                panel_fn: PANEL_FN_VAR.get(),
+                header_fn: zng_wgt_tabs::HEADER_FN_VAR.get(),
+            };
+            dock_node_widget(s, vec![], &ctx)
+        }
+        None => UiNode::nil(),
+    }
+}
+
+fn dock_panel(key: &Txt, panels: &[DockPanel]) -> DockPanel {
+    panels
+        .iter()
+        .find(|p| &p.key == key)
+        .cloned()
+        .unwrap_or_else(|| DockPanel::new(key.clone(), key.clone()))
+}
+
+fn dock_node_widget(node: &DockNode, path: Vec<bool>, ctx: &DockCtx) -> UiNode {
+    match node {
+        DockNode::Split {
+            orientation,
+            first,
+            second,
+            ..
+        } => {
+            let ratio = ctx.layout.map_bidi_modify(
+                clmv!(path, |l| match l.node(&path) {
+                    Some(DockNode::Split { ratio, .. }) => *ratio,
+                    _ => 0.5.fct(),
+                }),
+                clmv!(path, |r, l| {
+                    if matches!(l.node(&path), Some(DockNode::Split { ratio, .. }) if ratio != r)
+                        && let Some(DockNode::Split { ratio, .. }) = l.node_mut(&path)
+                    {
+                        *ratio = *r;
+                    }
+                }),
+            );
+            let mut first_path = path.clone();
+            first_path.push(false);
+            let mut second_path = path;
+            second_path.push(true);
+            SplitView! {
+                split_orientation = *orientation;
+                split_ratio = ratio;
+                first = dock_node_widget(first, first_path, ctx);
+                second = dock_node_widget(second, second_path, ctx);
+            }
+        }
+        DockNode::Tabs { .. } => {
+            let panels = ctx.panels.clone();
+            let tabs = ctx.layout.map_bidi_modify(
+                clmv!(path, |l| match l.node(&path) {
+                    Some(DockNode::Tabs { panels: keys, .. }) => keys
+                        .iter()
+                        .map(|k| {
+                            let p = dock_panel(k, &panels);
+                            TabItem::new(p.key, p.title).with_closable(p.closable)
+                        })
+                        .collect(),
+                    _ => vec![],
+                }),
+                clmv!(path, |tabs: &Vec<TabItem>, l| {
+                    let keys: Vec<Txt> = tabs.iter().map(|t| t.key.clone()).collect();
+                    if matches!(l.node(&path), Some(DockNode::Tabs { panels, .. }) if panels != &keys)
+                        && let Some(DockNode::Tabs { panels, .. }) = l.node_mut(&path)
+                    {
+                        *panels = keys;
+                        l.normalize();
+                    }
+                }),
+            );
+            let selected = ctx.layout.map_bidi_modify(
+                clmv!(path, |l| match l.node(&path) {
+                    Some(DockNode::Tabs { selected, .. }) => selected.clone(),
+                    _ => Txt::from_static(""),
+                }),
+                clmv!(path, |s, l| {
+                    if matches!(l.node(&path), Some(DockNode::Tabs { selected, .. }) if selected != s)
+                        && let Some(DockNode::Tabs { selected, .. }) = l.node_mut(&path)
+                    {
+                        *selected = s.clone();
+                    }
+                }),
+            );
+
+            let DockCtx {
+                layout,
+                panels,
+                panel_fn,
+                header_fn,
+            } = ctx.clone();
+            Tabs! {
+                tabs;
+                selected;
+                header_fn = wgt_fn!(layout, |args: TabHeaderArgs| {
+                    let panel = args.tab.key.clone();
+                    Container! {
+                        child = header_fn(args);
+                        context_menu_fn = wgt_fn!(panel, layout, |_| panel_menu(panel.clone(), layout.clone()));
+                    }
+                });
+                content_fn = wgt_fn!(|tab: TabItem| {
+                    panel_fn(DockPanelArgs {
+                        panel: dock_panel(&tab.key, &panels),
+                        is_floating: false,
+                    })
+                });
+            }
+        }
+    }
+}
+
+fn panel_menu(panel: Txt, layout: Var<DockLayout>) -> UiNode {
+    let dock_item = |label: Var<Txt>, side: DockSide| {
+        Button! {
+            child = Text!(label);
+            on_click = hn!(panel, layout, |_| {
+                let panel = panel.clone();
+                layout.modify(move |l| l.dock(panel, side));
+            });
+        }
+    };
+    ContextMenu!(ui_vec![
+        Button! {
+            child = Text!(l10n!("DockArea.float", "Float"));
+            on_click = hn!(panel, layout, |_| {
+                let panel = panel.clone();
+                let position = WINDOW.vars().actual_position().get() + DipVector::splat(Dip::new(40));
+                layout.modify(move |l| l.float(panel, position, DipSize::new(Dip::new(400), Dip::new(300))));
+            });
+        },
+        dock_item(l10n!("DockArea.dock-start", "Dock to Start"), DockSide::Start),
+        dock_item(l10n!("DockArea.dock-end", "Dock to End"), DockSide::End),
+        dock_item(l10n!("DockArea.dock-top", "Dock to Top"), DockSide::Top),
+        dock_item(l10n!("DockArea.dock-bottom", "Dock to Bottom"), DockSide::Bottom),
+    ])
+}
+
+/// Windows of the floating panels.
+#[derive(Default)]
+struct FloatingWindows {
+    open: Vec<(Txt, WindowId)>,
+    // set when the windows are closed by the dock area, not by the user
+    closing: Arc<AtomicBool>,
+}
+impl FloatingWindows {
+    fn sync(&mut self) {
+        let layout = DOCK_LAYOUT_VAR.current_context();
+        let floating = layout.with(|l| l.floating.clone());
+
+        self.open.retain(|(panel, id)| {
+            let retain = floating.iter().any(|f| &f.panel == panel);
+            if !retain {
+                let _ = WINDOWS.close(*id);
+            }
+            retain
+        });
+
+        let parent = WINDOW.id();
+        for f in floating {
+            if self.open.iter().any(|(p, _)| p == &f.panel) {
+                continue;
+            }
+            let id = WindowId::new_unique();
+            self.open.push((f.panel.clone(), id));
+
+            let panel = dock_panel(&f.panel, &PANELS_VAR.get());
+            let panel_fn = PANEL_FN_VAR.get();
+            let layout = layout.clone();
+            let closing = self.closing.clone();
+            WINDOWS.open(id, async move { floating_window(parent, f, panel, panel_fn, layout, closing) });
+        }
+    }
+
+    fn close_all(&mut self) {
+        self.closing.store(true, Ordering::Relaxed);
+        let _ = WINDOWS.close_together(self.open.drain(..).map(|(_, id)| id));
+        self.closing = Arc::default();
+    }
+}
+
+/// Delay after the last floating window move or resize before the layout is updated.
+const BOUNDS_COMMIT_DELAY: Duration = Duration::from_millis(300);
+
+fn floating_window(
+    parent: WindowId,
+    floating: FloatingPanel,
+    panel: DockPanel,
+    panel_fn: WidgetFn<DockPanelArgs>,
+    layout: Var<DockLayout>,
+    closing: Arc<AtomicBool>,
+) -> WindowRoot {
+    let key = panel.key.clone();
+    let title = panel.title.clone();
+    let mut _bounds_commit = None::<DeadlineHandle>;
+    Window! {
+        parent;
+        title;
+        position = floating.position;
+        size = floating.size;
+        child = Container! {
+            zng_wgt_container::child_top = Button! {
+                style_fn = zng_wgt_button::LightStyle!();
+                zng_wgt::align = Align::END;
+                child = Text!(l10n!("DockArea.dock", "Dock"));
+                on_click = hn!(layout, key, |_| {
+                    let key = key.clone();
+                    layout.modify(move |l| l.dock(key, DockSide::End));
+                });
+            };
+            child = panel_fn(DockPanelArgs { panel, is_floating: true });
+        };
+        zng_wgt_window::events::on_window_changed = hn!(layout, key, |args: &WindowChangedArgs| {
+            if !args.is_moved() && !args.is_resized() {
+                return;
+            }
+            // the layout is usually persisted in a config, only commit after the user stops moving or resizing
+            let vars = WINDOW.vars();
+            let layout = layout.clone();
+            let key = key.clone();
+            _bounds_commit = Some(TIMERS.on_deadline(
+                BOUNDS_COMMIT_DELAY,
+                hn_once!(|_| {
+                    let position = vars.actual_position().get();
+                    let size = vars.actual_size().get();
+                    layout.modify(move |l| {
+                        if let Some(f) = l.floating.iter().position(|f| f.panel == key)
+                            && (l.floating[f].position != position || l.floating[f].size != size)
+                        {
+                            let f = &mut l.floating[f];
+                            f.position = position;
+                            f.size = size;
+                        }
+                    });
+                }),
+            ));
+        });
+        on_close = hn!(layout, key, |args: &WindowCloseArgs| {
+            // closed by the user, not by the dock area or with the parent window
+            if !closing.load(Ordering::Relaxed) && !args.windows.contains(&parent) {
+                let key = key.clone();
+                layout.modify(move |l| {
+                    if l.is_floating(&key) {
+                        l.floating.retain(|f| f.panel != key);
+                    }
+                });
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tabs(panels: &[&'static str]) -> DockNode {
+        DockNode::tabs(panels.iter().copied())
+    }
+
+    #[test]
+    fn dock_empty() {
+        let mut l = DockLayout::default();
+        l.dock("a", DockSide::End);
+        assert_eq!(l.root, Some(tabs(&["a"])));
+    }
+
+    #[test]
+    fn dock_sides() {
+        let dock = |side| {
+            let mut l = DockLayout::new(tabs(&["a"]));
+            l.dock("b", side);
+            l.root.unwrap()
+        };
+        assert_eq!(
+            dock(DockSide::Start),
+            DockNode::split(SplitOrientation::Horizontal, 0.25, tabs(&["b"]), tabs(&["a"]))
+        );
+        assert_eq!(
+            dock(DockSide::End),
+            DockNode::split(SplitOrientation::Horizontal, 0.75, tabs(&["a"]), tabs(&["b"]))
+        );
+        assert_eq!(
+            dock(DockSide::Top),
+            DockNode::split(SplitOrientation::Vertical, 0.25, tabs(&["b"]), tabs(&["a"]))
+        );
+        assert_eq!(
+            dock(DockSide::Bottom),
+            DockNode::split(SplitOrientation::Vertical, 0.75, tabs(&["a"]), tabs(&["b"]))
+        );
+        assert_eq!(
+            dock(DockSide::Center),
+            DockNode::Tabs {
+                panels: vec![Txt::from("a"), Txt::from("b")],
+                selected: Txt::from("b"),
+            }
+        );
+    }
+
+    #[test]
+    fn dock_moves() {
+        let mut l = DockLayout::new(tabs(&["a", "b"]));
+        l.dock("a", DockSide::End);
+        assert_eq!(
+            l.root,
+            Some(DockNode::split(SplitOrientation::Horizontal, 0.75, tabs(&["b"]), tabs(&["a"])))
+        );
+        assert!(l.is_docked("a"));
+        assert!(!l.is_floating("a"));
+    }
+
+    #[test]
+    fn dock_at_target() {
+        let mut l = DockLayout::new(DockNode::split(SplitOrientation::Horizontal, 0.5, tabs(&["a"]), tabs(&["b"])));
+        l.dock_at("c", "b", DockSide::Bottom);
+        assert_eq!(
+            l.root,
+            Some(DockNode::split(
+                SplitOrientation::Horizontal,
+                0.5,
+                tabs(&["a"]),
+                DockNode::split(SplitOrientation::Vertical, 0.75, tabs(&["b"]), tabs(&["c"]))
+            ))
+        );
+
+        // same panel
+        let before = l.clone();
+        l.dock_at("c", "c", DockSide::Start);
+        assert_eq!(before, l);
+
+        // target not docked
+        let mut l = DockLayout::new(tabs(&["a"]));
+        l.dock_at("b", "x", DockSide::Start);
+        assert_eq!(
+            l.root,
+            Some(DockNode::split(SplitOrientation::Horizontal, 0.25, tabs(&["b"]), tabs(&["a"])))
+        );
+    }
+
+    #[test]
+    fn float_and_dock_back() {
+        let position = DipPoint::new(Dip::new(10), Dip::new(20));
+        let size = DipSize::new(Dip::new(400), Dip::new(300));
+
+        let mut l = DockLayout::new(tabs(&["a", "b"]));
+        l.float("a", position, size);
+        assert_eq!(l.root, Some(tabs(&["b"])));
+        assert_eq!(
+            l.floating,
+            vec![FloatingPanel {
+                panel: Txt::from("a"),
+                position,
+                size
+            }]
+        );
+        assert!(l.is_floating("a"));
+        assert!(!l.is_docked("a"));
+        assert!(l.contains("a"));
+
+        // floating the only docked panel
+        l.float("b", position, size);
+        assert_eq!(l.root, None);
+        assert_eq!(l.floating.len(), 2);
+
+        l.dock("a", DockSide::Center);
+        assert_eq!(l.root, Some(tabs(&["a"])));
+        assert_eq!(l.floating.len(), 1);
+        assert!(!l.is_floating("a"));
+    }
+
+    #[test]
+    fn remove() {
+        let mut l = DockLayout::new(DockNode::split(SplitOrientation::Horizontal, 0.5, tabs(&["a"]), tabs(&["b"])));
+        assert!(!l.remove("x"));
+        assert!(l.remove("a"));
+        assert_eq!(l.root, Some(tabs(&["b"])));
+        assert!(l.remove("b"));
+        assert_eq!(l.root, None);
+        assert!(!l.contains("b"));
+    }
+
+    #[test]
+    fn normalize() {
+        let mut l = DockLayout::new(DockNode::split(
+            SplitOrientation::Horizontal,
+            0.5,
+            tabs(&[]),
+            DockNode::split(SplitOrientation::Vertical, 0.5, tabs(&["a"]), tabs(&[])),
+        ));
+        l.normalize();
+        assert_eq!(l.root, Some(tabs(&["a"])));
+
+        let mut l = DockLayout::new(DockNode::split(SplitOrientation::Horizontal, 0.5, tabs(&[]), tabs(&[])));
+        l.normalize();
+        assert_eq!(l.root, None);
+
+        // selected is not in the tabs
+        let mut l = DockLayout::new(DockNode::Tabs {
+            panels: vec![Txt::from("a"), Txt::from("b")],
+            selected: Txt::from("x"),
+        });
+        l.normalize();
+        assert_eq!(l.root, Some(tabs(&["a", "b"])));
+    }
+}
@@ -0,0 +1,210 @@
+#![doc(html_favicon_url = "https://zng-ui.github.io/res/zng-logo-icon.png")]
+#![doc(html_logo_url = "https://zng-ui.github.io/res/zng-logo.png")]
+//!
+//! Expander widget, properties and styles.
+//!
+//! # Crate
+//!
+#![doc = include_str!(concat!("../", std::env!("CARGO_PKG_README")))]
+#![warn(unused_extern_crates)]
+#![warn(missing_docs)]
+
+zng_wgt::enable_widget_macros!();
+
+use std::time::Duration;
+
+use zng_var::animation::AnimationHandle;
+use zng_wgt::{ICONS, Wgt, align, clip_to_bounds, prelude::*, visibility};
+use zng_wgt_access::{AccessRole, access_role, expanded};
+use zng_wgt_container::{Container, child_align, child_out_top, child_spacing, child_start};
+use zng_wgt_size_offset::size;
+use zng_wgt_style::{StyleFn, style_fn};
+use zng_wgt_toggle::Toggle;
+use zng_wgt_transform::rotate;
+
+/// Collapsible container with a header that toggles the content visibility.
+///
+/// The [`header`] is presented inside a [`Toggle!`] that flips [`is_expanded`] on click, the content [`child`]
+/// height animates between zero and its measured height on change, the animation duration is configured by
+/// [`expand_duration`]. The animation is instant when [`VARS.animations_enabled`] is `false`, so the reduced-motion
+/// system setting is respected.
+///
+/// # Shorthand
+///
+/// The `Expander!` macro provides a shorthand init that sets the header and child, `Expander!("Details", content)`.
+///
+/// [`header`]: fn@header
+/// [`Toggle!`]: struct@Toggle
+/// [`is_expanded`]: fn@is_expanded
+/// [`child`]: fn@zng_wgt_container::child
+/// [`expand_duration`]: fn@expand_duration
+/// [`VARS.animations_enabled`]: zng_var::VARS::animations_enabled
+#[widget($crate::Expander {
+    ($header:expr, $child:expr $(,)?) => {
+        header = $header;
+        child = $child;
+    };
+})]
+pub struct Expander(Container);
+impl Expander {
+    fn widget_intrinsic(&mut self) {
+        widget_set! {
+            self;
+            is_expanded = var(false);
+        }
+
+        self.widget_builder().push_build_action(|wgt| {
+            let header = wgt.capture_ui_node_or_nil(property_id!(Self::header));
+            wgt.push_intrinsic(NestGroup::CHILD_LAYOUT, "expander", move |child| {
+                let progress = var(0.fct());
+                let content = Container! {
+                    child = expand_node(child, progress.clone());
+                    clip_to_bounds = true;
+                    visibility = progress.map(|&p| if p == 0.fct() { Visibility::Collapsed } else { Visibility::Visible });
+                };
+                let header = Toggle! {
+                    style_fn = HEADER_STYLE_FN_VAR;
+                    checked = IS_EXPANDED_VAR;
+                    expanded = IS_EXPANDED_VAR;
+                    child = header;
+                };
+                child_out_top(content, header)
+            });
+        });
+    }
+}
+
+context_var! {
+    /// If the expander content is visible.
+    pub static IS_EXPANDED_VAR: bool = false;
+
+    /// Duration of the expand and collapse animation.
+    pub static EXPAND_DURATION_VAR: Duration = 150.ms();
+
+    /// Style of the expander header toggle.
+    pub static HEADER_STYLE_FN_VAR: StyleFn = style_fn!(|_| HeaderStyle!());
+}
+
+/// Expander header content.
+///
+/// The header is always visible, it is the child of a [`Toggle!`] that expands and collapses the content on click.
+///
+/// [`Toggle!`]: struct@Toggle
+#[property(CHILD, widget_impl(Expander))]
+pub fn header(wgt: &mut WidgetBuilding, header: impl IntoUiNode) {
+    let _ = header;
+    wgt.expect_property_capture();
+}
+
+/// If the expander content is visible.
+///
+/// Is a new `var(false)` by default in [`Expander!`], set to a read-write variable to bind the state, the header
+/// toggle sets the variable on click.
+///
+/// This property sets the [`IS_EXPANDED_VAR`].
+///
+/// [`Expander!`]: struct@Expander
+#[property(CONTEXT, default(IS_EXPANDED_VAR), widget_impl(Expander))]
+pub fn is_expanded(child: impl IntoUiNode, expanded: impl IntoVar<bool>) -> UiNode {
+    with_context_var(child, IS_EXPANDED_VAR, expanded)
+}
+
+/// Duration of the expand and collapse animation.
+///
+/// Is `150.ms()` by default.
+///
+/// This property sets the [`EXPAND_DURATION_VAR`].
+#[property(CONTEXT, default(EXPAND_DURATION_VAR), widget_impl(Expander))]
+pub fn expand_duration(child: impl IntoUiNode, duration: impl IntoVar<Duration>) -> UiNode {
+    with_context_var(child, EXPAND_DURATION_VAR, duration)
+}
+
+/// Style of the expander header toggle.
+///
+/// Is [`HeaderStyle!`] by default.
+///
+/// This property sets the [`HEADER_STYLE_FN_VAR`].
+///
+/// [`HeaderStyle!`]: struct@HeaderStyle
+#[property(CONTEXT, default(HEADER_STYLE_FN_VAR), widget_impl(Expander))]
+pub fn header_style_fn(child: impl IntoUiNode, style_fn: impl IntoVar<StyleFn>) -> UiNode {
+    with_context_var(child, HEADER_STYLE_FN_VAR, style_fn)
+}
+
+/// Expander header toggle style.
+///
+/// Extends the button light style, aligns the header content to the start and inserts an arrow icon that rotates
+/// when the expander [`is_expanded`].
+///
+/// [`is_expanded`]: fn@is_expanded
+#[widget($crate::HeaderStyle)]
+pub struct HeaderStyle(zng_wgt_button::LightStyle);
+impl HeaderStyle {
+    fn widget_intrinsic(&mut self) {
+        widget_set! {
+            self;
+            replace = true;
+            access_role = AccessRole::Button;
+            child_align = Align::START;
+            child_spacing = 4;
+            child_start = expand_mark_visual();
+        }
+    }
+}
+
+fn expand_mark_visual() -> UiNode {
+    let icon = ICONS.get_or(
+        ["expander.expand", "material/rounded/keyboard-arrow-right", "keyboard-arrow-right"],
+        || {
+            zng_wgt_text::Text! {
+                txt = "▸";
+                align = Align::CENTER;
+            }
+        },
+    );
+    Wgt! {
+        size = 12;
+        align = Align::CENTER;
+        zng_wgt_fill::background = icon;
+
+        #[easing(150.ms())]
+        rotate = 0.deg();
+        when *#{IS_EXPANDED_VAR} {
+            #[easing(150.ms())]
+            rotate = 90.deg();
+        }
+    }
+}
+
+/// Animates the content height between zero and the measured height.
+///
+/// The `progress` is also used by the content widget to collapse when fully closed.
+fn expand_node(child: impl IntoUiNode, progress: Var<Factor>) -> UiNode {
+    let mut _animation = AnimationHandle::dummy();
+    match_node(child, move |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_var(&IS_EXPANDED_VAR).sub_var_layout(&progress);
+            progress.set(if IS_EXPANDED_VAR.get() { 1.fct() } else { 0.fct() });
+        }
+        UiNodeOp::Deinit => {
+            _animation = AnimationHandle::dummy();
+        }
+        UiNodeOp::Update { updates } => {
+            c.update(updates);
+
+            if let Some(expanded) = IS_EXPANDED_VAR.get_new() {
+                let target = if expanded { 1.fct() } else { 0.fct() };
+                _animation = progress.ease(target, EXPAND_DURATION_VAR.get(), |t| easing::ease_out(easing::quad, t));
+            }
+        }
+        UiNodeOp::Measure { wm, desired_size } => {
+            let size = LAYOUT.with_constraints(LAYOUT.constraints().with_unbounded_y(), || c.measure(wm));
+            *desired_size = PxSize::new(size.width, size.height * progress.get());
+        }
+        UiNodeOp::Layout { wl, final_size } => {
+            let size = LAYOUT.with_constraints(LAYOUT.constraints().with_unbounded_y(), || c.layout(wl));
+            *final_size = PxSize::new(size.width, size.height * progress.get());
+        }
+        _ => {}
+    })
+}
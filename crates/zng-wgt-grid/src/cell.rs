@@ -15,9 +15,33 @@ impl Cell {
             self;
             access_role = AccessRole::GridCell;
         }
+        self.widget_builder()
+            .push_build_action(|b| b.push_intrinsic(NestGroup::CONTEXT, "cell-access-index", access_index_node));
     }
 }
 
+/// Sets the accessibility column and row index and span of the cell.
+fn access_index_node(child: impl IntoUiNode) -> UiNode {
+    match_node(child, |c, op| {
+        if let UiNodeOp::Info { info } = op {
+            c.info(info);
+            if let Some(mut a) = info.access() {
+                if let Some((column, row)) = WIDGET.get_state(*ACCESS_INDEX_ID) {
+                    a.set_col_index(column);
+                    a.set_row_index(row);
+                }
+                let info = CellInfo::get();
+                if info.column_span > 1 {
+                    a.set_col_span(info.column_span);
+                }
+                if info.row_span > 1 {
+                    a.set_row_span(info.row_span);
+                }
+            }
+        }
+    })
+}
+
 /// Represents values set by cell properties in a widget.
 #[derive(Clone, Copy, Debug)]
 #[non_exhaustive]
@@ -89,6 +113,9 @@ static_id! {
     ///
     /// The parent grid uses this info to position and size the cell widget.
     pub static ref INFO_ID: StateId<CellInfo>;
+
+    /// Actual column and row, set by the parent grid.
+    pub(super) static ref ACCESS_INDEX_ID: StateId<(usize, usize)>;
 }
 
 /// Cell column index.
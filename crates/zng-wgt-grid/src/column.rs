@@ -30,9 +30,25 @@ impl Column {
             self;
             access_role = AccessRole::Column;
         }
+        self.widget_builder()
+            .push_build_action(|b| b.push_intrinsic(NestGroup::CONTEXT, "column-access-index", access_index_node));
     }
 }
 
+/// Sets the accessibility column index.
+fn access_index_node(child: impl IntoUiNode) -> UiNode {
+    match_node(child, |c, op| {
+        if let UiNodeOp::Info { info } = op {
+            c.info(info);
+            if let Some(mut a) = info.access()
+                && let Some((i, _)) = WIDGET.get_state(*INDEX_ID)
+            {
+                a.set_col_index(i);
+            }
+        }
+    })
+}
+
 static_id! {
     /// Column index, total in the parent widget set by the parent.
     pub(super) static ref INDEX_ID: StateId<(usize, usize)>;
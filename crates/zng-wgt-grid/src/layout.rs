@@ -245,7 +245,7 @@ impl GridLayout {
                 wgt.with_context(WidgetUpdateMode::Bubble, || {
                     let prev = WIDGET.set_state(*column::INDEX_ID, (i, columns_len));
                     if prev != Some((i, columns_len)) {
-                        WIDGET.update();
+                        WIDGET.update().update_info();
                     }
                 });
             }
@@ -256,12 +256,28 @@ impl GridLayout {
                 wgt.with_context(WidgetUpdateMode::Bubble, || {
                     let prev = WIDGET.set_state(*row::INDEX_ID, (i, rows_len));
                     if prev != Some((i, rows_len)) {
-                        WIDGET.update();
+                        WIDGET.update().update_info();
                     }
                 });
             }
         });
 
+        // Set actual column and row for cell accessibility info.
+        if columns_len > 0 {
+            children.cells().for_each_child(|i, c, _| {
+                if let Some(mut wgt) = c.as_widget() {
+                    wgt.with_context(WidgetUpdateMode::Bubble, || {
+                        let info = cell::CellInfo::get().actual(i, columns_len);
+                        let index = (info.column, info.row);
+                        let prev = WIDGET.set_state(*cell::ACCESS_INDEX_ID, index);
+                        if prev != Some(index) {
+                            WIDGET.update_info();
+                        }
+                    });
+                }
+            });
+        }
+
         self.columns.resize(columns_len, ColumnLayout::default());
         self.rows.resize(rows_len, RowLayout::default());
     }
@@ -257,8 +257,19 @@ pub fn node(
                 any = true;
             }
             if any {
+                let prev_len = (grid.columns.len(), grid.rows.len());
                 grid.update_entries(c.node(), auto_grow_mode.get(), &auto_grow_fn);
                 WIDGET.layout();
+                if prev_len != (grid.columns.len(), grid.rows.len()) {
+                    WIDGET.update_info();
+                }
+            }
+        }
+        UiNodeOp::Info { info } => {
+            c.info(info);
+            if let Some(mut a) = info.access() {
+                a.set_col_count(grid.columns.len());
+                a.set_row_count(grid.rows.len());
             }
         }
         UiNodeOp::Measure { wm, desired_size } => {
@@ -30,9 +30,25 @@ impl Row {
             self;
             access_role = AccessRole::Row;
         }
+        self.widget_builder()
+            .push_build_action(|b| b.push_intrinsic(NestGroup::CONTEXT, "row-access-index", access_index_node));
     }
 }
 
+/// Sets the accessibility row index.
+fn access_index_node(child: impl IntoUiNode) -> UiNode {
+    match_node(child, |c, op| {
+        if let UiNodeOp::Info { info } = op {
+            c.info(info);
+            if let Some(mut a) = info.access()
+                && let Some((i, _)) = WIDGET.get_state(*INDEX_ID)
+            {
+                a.set_row_index(i);
+            }
+        }
+    })
+}
+
 static_id! {
     /// Row index, total in the parent widget set by the parent.
     pub(super) static ref INDEX_ID: StateId<(usize, usize)>;
@@ -1,7 +1,7 @@
 use crate::node::CONTEXT_IMAGE_REDUCED_VAR;
 
 use super::*;
-use std::fmt;
+use std::{fmt, time::Duration};
 
 use node::CONTEXT_IMAGE_VAR;
 use zng_app::render::ImageRendering;
@@ -135,6 +135,11 @@ context_var! {
     /// Widget function for the content shown when the image is still loading.
     pub static IMAGE_LOADING_FN_VAR: WidgetFn<ImgLoadingArgs> = WidgetFn::nil();
 
+    /// Duration of the cross-fade from the loading view to the loaded image.
+    ///
+    /// Is `0` by default, no fade.
+    pub static IMAGE_FADE_IN_VAR: Duration = Duration::ZERO;
+
     /// Custom image load and decode limits.
     ///
     /// Set to `None` to use the [`IMAGES.limits`].
@@ -150,6 +155,11 @@ context_var! {
     /// Defines what images are decoded from multi image containers.
     pub static IMAGE_ENTRIES_MODE_VAR: ImageEntriesMode = ImageEntriesMode::PRIMARY;
 
+    /// If the image is uploaded to the GPU as a compressed texture.
+    ///
+    /// Is `false` by default.
+    pub static IMAGE_COMPRESSED_TEXTURE_VAR: bool = false;
+
     /// The image layout mode.
     ///
     /// Is [`ImageFit::Contain`] by default.
@@ -407,6 +417,19 @@ pub fn img_entries_mode(child: impl IntoUiNode, mode: impl IntoVar<ImageEntriesM
     with_context_var(child, IMAGE_ENTRIES_MODE_VAR, mode)
 }
 
+/// If the image is uploaded to the GPU as a compressed texture.
+///
+/// Compressed textures use 4x to 8x less GPU memory, this is a good optimization for large collections of
+/// thumbnails. The compression is lossy, if the renderer does not support it the image is uploaded uncompressed.
+///
+/// Changing this value after an image is already loaded or loading will cause the image to reload.
+///
+/// This property sets the [`IMAGE_COMPRESSED_TEXTURE_VAR`].
+#[property(CONTEXT, default(IMAGE_COMPRESSED_TEXTURE_VAR), widget_impl(Image))]
+pub fn img_compressed_texture(child: impl IntoUiNode, enabled: impl IntoVar<bool>) -> UiNode {
+    with_context_var(child, IMAGE_COMPRESSED_TEXTURE_VAR, enabled)
+}
+
 /// If the image has an error.
 ///
 /// Binds the [`ImageEntry::is_error`] of the [`CONTEXT_IMAGE_VAR`].
@@ -497,6 +520,21 @@ pub fn img_loading_fn(child: impl IntoUiNode, wgt_fn: impl IntoVar<WidgetFn<ImgL
     with_context_var(child, IMAGE_LOADING_FN_VAR, wgt_fn)
 }
 
+/// Sets the duration of the cross-fade from the [`img_loading_fn`] view to the loaded image.
+///
+/// The fade is only used if the image was loading when the widget inited, images that are already loaded (cached)
+/// show immediately. The fade is also skipped when animations are disabled, see [`VARS.animations_enabled`] for
+/// more details about the reduced motion config.
+///
+/// This property sets the [`IMAGE_FADE_IN_VAR`]. Is `0` by default, no fade.
+///
+/// [`img_loading_fn`]: fn@img_loading_fn
+/// [`VARS.animations_enabled`]: zng_app::var::VARS::animations_enabled
+#[property(CONTEXT, default(IMAGE_FADE_IN_VAR), widget_impl(Image))]
+pub fn img_fade_in(child: impl IntoUiNode, duration: impl IntoVar<Duration>) -> UiNode {
+    with_context_var(child, IMAGE_FADE_IN_VAR, duration)
+}
+
 /// Arguments for [`img_loading_fn`] and [`on_load_size_layout`].
 ///
 /// [`img_loading_fn`]: fn@img_loading_fn
@@ -1,11 +1,17 @@
 //! UI nodes used for building the image widget.
 
-use zng_ext_image::{IMAGES, ImageCacheMode, ImageOptions, ImageRenderArgs};
+use std::time::Duration;
+
+use zng_app::render::{ImageRendering, ReferenceFrameId};
+use zng_app::var::VARS;
+use zng_ext_image::{IMAGES, ImageCacheMode, ImageDownscaleMode, ImageEntriesMode, ImageOptions, ImageRenderArgs};
+use zng_wgt_scroll::SCROLL;
 use zng_wgt_stack::stack_nodes;
 
 use super::image_properties::{
-    IMAGE_ALIGN_VAR, IMAGE_AUTO_SCALE_VAR, IMAGE_CACHE_VAR, IMAGE_CROP_VAR, IMAGE_DOWNSCALE_VAR, IMAGE_ERROR_FN_VAR, IMAGE_FIT_VAR,
-    IMAGE_LIMITS_VAR, IMAGE_LOADING_FN_VAR, IMAGE_OFFSET_VAR, IMAGE_RENDERING_VAR, IMAGE_SCALE_VAR, ImageFit, ImgErrorArgs, ImgLoadingArgs,
+    IMAGE_ALIGN_VAR, IMAGE_AUTO_SCALE_VAR, IMAGE_CACHE_VAR, IMAGE_CROP_VAR, IMAGE_DOWNSCALE_VAR, IMAGE_ERROR_FN_VAR, IMAGE_FADE_IN_VAR,
+    IMAGE_FIT_VAR, IMAGE_LIMITS_VAR, IMAGE_LOADING_FN_VAR, IMAGE_OFFSET_VAR, IMAGE_RENDERING_VAR, IMAGE_SCALE_VAR, ImageFit, ImgErrorArgs,
+    ImgLoadingArgs,
 };
 use super::*;
 
@@ -22,6 +28,9 @@ context_var! {
     /// Context is set to a mutable variable in [`image_source`] and value is set by [`image_presenter`]
     /// when the image has multiple entries.
     pub static CONTEXT_IMAGE_REDUCED_VAR: Option<ImageEntry> = None;
+
+    /// Memory mapped source that is presented in tiles, set by [`image_source`].
+    static IMAGE_TILES_SOURCE_VAR: Option<ImageSource> = None;
 }
 fn no_context_image() -> ImageEntry {
     ImageEntry::new_error(Txt::from_static("no image source in context"))
@@ -33,6 +42,9 @@ fn no_context_image() -> ImageEntry {
 ///
 /// The image is not rendered by this property, the [`image_presenter`] renders the image in [`CONTEXT_IMAGE_VAR`].
 ///
+/// If the source is [`ImageSource::MemoryMapped`] only the first tile region is requested, the [`image_presenter`]
+/// requests the other tiles as they become visible. See [`ImageEntry::region`] for more details.
+///
 /// In a widget this should be placed inside context properties and before event properties.
 ///
 /// [`img_cache`]: fn@crate::img_cache
@@ -40,6 +52,14 @@ fn no_context_image() -> ImageEntry {
 pub fn image_source(child: impl IntoUiNode, source: impl IntoVar<ImageSource>) -> UiNode {
     let source = source.into_var();
     let ctx_img = var(ImageEntry::new_loading());
+    let child = with_context_var(
+        child,
+        IMAGE_TILES_SOURCE_VAR,
+        source.map(|s| match s {
+            ImageSource::MemoryMapped(_) => Some(s.clone()),
+            _ => None,
+        }),
+    );
     let child = with_context_var(child, CONTEXT_IMAGE_REDUCED_VAR, var(None));
     let child = with_context_var(child, CONTEXT_IMAGE_VAR, ctx_img.read_only());
     let mut _img = var(ImageEntry::new_loading()).read_only();
@@ -51,7 +71,8 @@ pub fn image_source(child: impl IntoUiNode, source: impl IntoVar<ImageSource>) -
                 .sub_var(&source)
                 .sub_var(&IMAGE_CACHE_VAR)
                 .sub_var(&IMAGE_DOWNSCALE_VAR)
-                .sub_var(&IMAGE_ENTRIES_MODE_VAR);
+                .sub_var(&IMAGE_ENTRIES_MODE_VAR)
+                .sub_var(&IMAGE_COMPRESSED_TEXTURE_VAR);
 
             let mode = if IMAGE_CACHE_VAR.get() {
                 ImageCacheMode::Cache
@@ -63,7 +84,7 @@ pub fn image_source(child: impl IntoUiNode, source: impl IntoVar<ImageSource>) -
             if let ImageSource::Render(_, args) = &mut source {
                 *args = Some(ImageRenderArgs::new(WINDOW.id()));
             }
-            let opt = ImageOptions::new(mode, IMAGE_DOWNSCALE_VAR.get(), None, IMAGE_ENTRIES_MODE_VAR.get());
+            let opt = image_options(mode, &source);
             _img = IMAGES.image(source, opt, IMAGE_LIMITS_VAR.get());
 
             ctx_img.set_from(&_img);
@@ -77,7 +98,7 @@ pub fn image_source(child: impl IntoUiNode, source: impl IntoVar<ImageSource>) -
             _ctx_binding = None;
         }
         UiNodeOp::Update { .. } => {
-            if source.is_new() || IMAGE_DOWNSCALE_VAR.is_new() || IMAGE_ENTRIES_MODE_VAR.is_new() {
+            if source.is_new() || IMAGE_DOWNSCALE_VAR.is_new() || IMAGE_ENTRIES_MODE_VAR.is_new() || IMAGE_COMPRESSED_TEXTURE_VAR.is_new() {
                 // source update:
 
                 let mut source = source.get();
@@ -91,7 +112,7 @@ pub fn image_source(child: impl IntoUiNode, source: impl IntoVar<ImageSource>) -
                 } else {
                     ImageCacheMode::Ignore
                 };
-                let opt = ImageOptions::new(mode, IMAGE_DOWNSCALE_VAR.get(), None, IMAGE_ENTRIES_MODE_VAR.get());
+                let opt = image_options(mode, &source);
                 _img = IMAGES.image(source, opt, IMAGE_LIMITS_VAR.get());
 
                 ctx_img.set_from(&_img);
@@ -101,7 +122,7 @@ pub fn image_source(child: impl IntoUiNode, source: impl IntoVar<ImageSource>) -
                 let is_cached = ctx_img.with(|img| IMAGES.is_cached(img));
                 if enabled != is_cached {
                     let source = source.get();
-                    let mut opt = ImageOptions::new(ImageCacheMode::Cache, IMAGE_DOWNSCALE_VAR.get(), None, IMAGE_ENTRIES_MODE_VAR.get());
+                    let mut opt = image_options(ImageCacheMode::Cache, &source);
 
                     if is_cached {
                         _img = const_var(ImageEntry::new_loading());
@@ -121,6 +142,16 @@ pub fn image_source(child: impl IntoUiNode, source: impl IntoVar<ImageSource>) -
     })
 }
 
+fn image_options(mode: ImageCacheMode, source: &ImageSource) -> ImageOptions {
+    let mut opt = ImageOptions::new(mode, IMAGE_DOWNSCALE_VAR.get(), None, IMAGE_ENTRIES_MODE_VAR.get());
+    opt.compressed_texture = IMAGE_COMPRESSED_TEXTURE_VAR.get();
+    if let ImageSource::MemoryMapped(_) = source {
+        // first tile, also loads the full image size
+        opt.region = Some(PxRect::from_size(PxSize::splat(TILE_SIZE)));
+    }
+    opt
+}
+
 context_local! {
     /// Used to avoid recursion in [`image_error_presenter`].
     static IN_ERROR_VIEW: bool = false;
@@ -157,18 +188,27 @@ pub fn image_error_presenter(child: impl IntoUiNode) -> UiNode {
 
 /// Presents the contextual [`IMAGE_LOADING_FN_VAR`] if [`is_loading`].
 ///
-/// The loading view is rendered under the `child`.
+/// The loading view is rendered under the `child`. If [`IMAGE_FADE_IN_VAR`] is set the loading view
+/// cross-fades to the `child` when the image finishes loading.
 ///
 /// The image widget adds this node around the [`image_error_presenter`] node.
 ///
 /// [`is_loading`]: fn@crate::is_loading
 pub fn image_loading_presenter(child: impl IntoUiNode) -> UiNode {
-    let args = expr_var! {
-        let is_loading = match #{CONTEXT_IMAGE_REDUCED_VAR} {
+    let is_loading = expr_var! {
+        match #{CONTEXT_IMAGE_REDUCED_VAR} {
             Some(img) => img.is_loading(),
             None => #{CONTEXT_IMAGE_VAR}.is_loading(),
-        };
-        if is_loading { Some(ImgLoadingArgs {}) } else { None }
+        }
+    };
+    // keeps the loading view during the fade-in
+    let fading = var(false);
+    // opacity of the child, the loading view is the inverse
+    let fade = var(1.fct());
+    let mut _fade_handle = None;
+
+    let args = expr_var! {
+        if *#{is_loading.clone()} || *#{fading.clone()} { Some(ImgLoadingArgs {}) } else { None }
     };
     let view = args.present_opt(IMAGE_LOADING_FN_VAR.map(|f| {
         wgt_fn!(f, |a| {
@@ -179,13 +219,87 @@ pub fn image_loading_presenter(child: impl IntoUiNode) -> UiNode {
             }
         })
     }));
+    let view = fade_opacity(view, fade.map(|f| 1.fct() - *f));
+    let child = fade_opacity(child, fade.clone());
 
-    stack_nodes(ui_vec![view, child], 1, |constraints, _, img_size| {
+    let node = stack_nodes(ui_vec![view, child], 1, |constraints, _, img_size| {
         if img_size == PxSize::zero() {
             constraints
         } else {
             PxConstraints2d::new_fill_size(img_size)
         }
+    });
+    match_node(node, move |_, op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_var(&is_loading).sub_var(&fade);
+            if is_loading.get() && IMAGE_FADE_IN_VAR.get() > Duration::ZERO {
+                fading.set(true);
+                fade.set(0.fct());
+            }
+        }
+        UiNodeOp::Deinit => {
+            _fade_handle = None;
+            fading.set(false);
+            fade.set(1.fct());
+        }
+        UiNodeOp::Update { .. } => {
+            if let Some(loading) = is_loading.get_new() {
+                let duration = IMAGE_FADE_IN_VAR.get();
+                if loading {
+                    _fade_handle = None;
+                    if duration > Duration::ZERO {
+                        fading.set(true);
+                        fade.set(0.fct());
+                    }
+                } else if fading.get() {
+                    if duration > Duration::ZERO && VARS.animations_enabled().get() {
+                        _fade_handle = Some(fade.ease(1.fct(), duration, easing::linear));
+                    } else {
+                        fade.set(1.fct());
+                    }
+                }
+            } else if let Some(f) = fade.get_new()
+                && f >= 1.fct()
+                && fading.get()
+            {
+                _fade_handle = None;
+                fading.set(false);
+            }
+        }
+        _ => {}
+    })
+}
+
+/// Renders the `child` with the `opacity`, no opacity is applied if the value is `1.0`.
+fn fade_opacity(child: impl IntoUiNode, opacity: Var<Factor>) -> UiNode {
+    let frame_key = FrameValueKey::new_unique();
+    let mut is_pushed = false;
+    match_node(child, move |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_var(&opacity);
+        }
+        UiNodeOp::Update { .. } => {
+            if let Some(o) = opacity.get_new() {
+                if (o < 1.fct()) != is_pushed {
+                    WIDGET.render();
+                } else if is_pushed {
+                    WIDGET.render_update();
+                }
+            }
+        }
+        UiNodeOp::Render { frame } => {
+            is_pushed = opacity.get() < 1.fct();
+            if is_pushed {
+                c.delegated();
+                frame.push_opacity(frame_key.bind_var(&opacity, |f| f.0), |frame| c.render(frame));
+            }
+        }
+        UiNodeOp::RenderUpdate { update } => {
+            if is_pushed {
+                update.update_f32_opt(frame_key.update_var(&opacity, |f| f.0));
+            }
+        }
+        _ => {}
     })
 }
 
@@ -202,6 +316,10 @@ pub fn image_loading_presenter(child: impl IntoUiNode) -> UiNode {
 /// * [`IMAGE_RENDERING_VAR`]: Defines the image resize algorithm used in the GPU.
 /// * [`IMAGE_OFFSET_VAR`]: Defines an offset applied to the image after all measure and arrange.
 /// * [`CONTEXT_IMAGE_REDUCED_VAR`]: Var set by this node to a reduced size alternate entry in multi entry images.
+///
+/// If the [`image_source`] is [`ImageSource::MemoryMapped`] the image is presented in tiles, only the tiles that intersect the
+/// render culling rect are requested, at a resolution that matches the render scale. Inside a scroll the tiles are updated as
+/// the viewport scrolls and zooms. The [`IMAGE_REPEAT_VAR`] is ignored for tiled images.
 pub fn image_presenter() -> UiNode {
     let mut img_size = PxSize::zero();
     let mut render_clip = PxRect::zero();
@@ -211,11 +329,15 @@ pub fn image_presenter() -> UiNode {
     let mut render_offset = PxVector::zero();
     let spatial_id = SpatialFrameId::new_unique();
     let mut reduced_img = None;
+    let mut tiles_source = None;
+    let mut tiles = ImageTiles::default();
+    let mut scroll_handles = VarHandles::dummy();
 
     match_node_leaf(move |op| match op {
         UiNodeOp::Init => {
             WIDGET
                 .sub_var(&CONTEXT_IMAGE_VAR)
+                .sub_var(&IMAGE_TILES_SOURCE_VAR)
                 .sub_var_layout(&IMAGE_CROP_VAR)
                 .sub_var_layout(&IMAGE_AUTO_SCALE_VAR)
                 .sub_var_layout(&IMAGE_SCALE_VAR)
@@ -226,16 +348,30 @@ pub fn image_presenter() -> UiNode {
                 .sub_var_layout(&IMAGE_REPEAT_SPACING_VAR)
                 .sub_var_render(&IMAGE_RENDERING_VAR);
 
-            img_size = CONTEXT_IMAGE_VAR.with(ImageEntry::size);
+            tiles_source = IMAGE_TILES_SOURCE_VAR.get();
+            scroll_handles = sub_scroll_render(tiles_source.is_some());
+            img_size = CONTEXT_IMAGE_VAR.with(|img| presented_size(img, tiles_source.is_some()));
         }
-        UiNodeOp::Deinit if reduced_img.take().is_some() => {
-            CONTEXT_IMAGE_REDUCED_VAR.set(None);
+        UiNodeOp::Deinit => {
+            if reduced_img.take().is_some() {
+                CONTEXT_IMAGE_REDUCED_VAR.set(None);
+            }
+            tiles.clear();
+            scroll_handles = VarHandles::dummy();
         }
         UiNodeOp::Update { .. } => {
+            if let Some(s) = IMAGE_TILES_SOURCE_VAR.get_new() {
+                scroll_handles = sub_scroll_render(s.is_some());
+                tiles_source = s;
+                tiles.clear();
+                img_size = CONTEXT_IMAGE_VAR.with(|img| presented_size(img, tiles_source.is_some()));
+                WIDGET.layout();
+            }
             if let Some(img) = CONTEXT_IMAGE_VAR.get_new() {
-                let ig_size = img.size();
+                let ig_size = presented_size(&img, tiles_source.is_some());
                 if img_size != ig_size {
                     img_size = ig_size;
+                    tiles.clear();
                     if reduced_img.take().is_some() {
                         CONTEXT_IMAGE_REDUCED_VAR.set(None);
                     }
@@ -253,6 +389,9 @@ pub fn image_presenter() -> UiNode {
                     WIDGET.render();
                 }
             }
+            if let Some(s) = &tiles_source {
+                tiles.request(s, img_size);
+            }
         }
         UiNodeOp::Measure { desired_size, .. } => {
             // Similar to `layout` Part 1.
@@ -447,6 +586,55 @@ pub fn image_presenter() -> UiNode {
                 return;
             }
 
+            if tiles_source.is_some() {
+                if img_size.is_empty() {
+                    return;
+                }
+                let scale = Factor2d::new(
+                    render_img_size.width.0 as f32 / img_size.width.0 as f32,
+                    render_img_size.height.0 as f32 / img_size.height.0 as f32,
+                );
+
+                // culling rect in the image pixels
+                let transform = *frame.transform();
+                let visible = transform
+                    .inverse()
+                    .and_then(|t| t.outer_transformed(frame.auto_hide_rect().to_box2d()))
+                    .map(|b| b.to_rect().translate(-render_offset))
+                    .unwrap_or(render_clip);
+                let visible = visible.intersection(&render_clip).unwrap_or_default();
+                let visible = PxRect::new(
+                    PxPoint::new(visible.origin.x / scale.x, visible.origin.y / scale.y),
+                    PxSize::new(visible.size.width / scale.x, visible.size.height / scale.y),
+                );
+
+                let screen_scale = match (
+                    transform.transform_point(PxPoint::zero()),
+                    transform.transform_point(PxPoint::new(Px(1000), Px(0))),
+                ) {
+                    (Some(a), Some(b)) => {
+                        let d = b - a;
+                        (d.x.0 as f32).hypot(d.y.0 as f32) / 1000.0
+                    }
+                    _ => 1.0,
+                };
+                let level = tile_level(scale.x.0.max(scale.y.0) * screen_scale);
+
+                if tiles.set_visible(visible_tiles(img_size, visible, level)) {
+                    // request in update
+                    WIDGET.update();
+                }
+                tiles.clean();
+                let r = TilesRender {
+                    clip: render_clip,
+                    offset: render_offset,
+                    scale,
+                    rendering: IMAGE_RENDERING_VAR.get(),
+                };
+                tiles.render(frame, spatial_id, img_size, &r);
+                return;
+            }
+
             let render = |img: &ImageEntry| {
                 if render_offset != PxVector::zero() {
                     let transform = PxTransform::from(render_offset);
@@ -498,3 +686,241 @@ fn tile_leftover(tile_size: PxSize, wgt_size: PxSize) -> PxSize {
         if spaces_y > Px(0) { full_leftover_y / spaces_y } else { Px(0) },
     )
 }
+
+/// Image size presented, the full image size for tiled images.
+fn presented_size(img: &ImageEntry, tiled: bool) -> PxSize {
+    match img.region() {
+        Some((full_size, _)) if tiled => full_size,
+        _ => img.size(),
+    }
+}
+
+/// Render on scroll, so that the visible tiles are updated.
+fn sub_scroll_render(tiled: bool) -> VarHandles {
+    if !tiled {
+        return VarHandles::dummy();
+    }
+    let id = WIDGET.id();
+    [
+        SCROLL.vertical_offset().subscribe(UpdateOp::Render, id),
+        SCROLL.horizontal_offset().subscribe(UpdateOp::Render, id),
+        SCROLL.zoom_scale().subscribe(UpdateOp::Render, id),
+    ]
+    .into()
+}
+
+/// Size of the tiles that present memory mapped images.
+const TILE_SIZE: Px = Px(512);
+/// Maximum tile level, each level halves the tile resolution.
+const TILE_MAX_LEVEL: u32 = 16;
+
+/// Identifies a tile of a memory mapped image.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct TileKey {
+    level: u32,
+    col: u32,
+    row: u32,
+}
+impl TileKey {
+    /// Length of the tile square in image pixels.
+    fn len(level: u32) -> i32 {
+        TILE_SIZE.0 << level
+    }
+
+    /// Image pixels covered by the tile, clamped to the image size.
+    fn rect(self, img_size: PxSize) -> PxRect {
+        let len = Self::len(self.level);
+        let rect = PxRect::new(
+            PxPoint::new(Px(len * self.col as i32), Px(len * self.row as i32)),
+            PxSize::splat(Px(len)),
+        );
+        PxRect::from_size(img_size).intersection(&rect).unwrap_or_default()
+    }
+
+    /// Image request for the tile.
+    fn options(self, img_size: PxSize) -> ImageOptions {
+        let rect = self.rect(img_size);
+        let downscale = if self.level > 0 {
+            let len = 1 << self.level;
+            Some(ImageDownscaleMode::Fit(PxSize::new(
+                Px((rect.size.width.0 + len - 1) / len),
+                Px((rect.size.height.0 + len - 1) / len),
+            )))
+        } else {
+            None
+        };
+        let mut opt = ImageOptions::new(ImageCacheMode::Ignore, downscale, None, ImageEntriesMode::PRIMARY);
+        opt.region = Some(rect);
+        opt
+    }
+}
+
+/// Gets the tile level that has at least one tile pixel per screen pixel at the `scale`, `scale` is screen pixels per image pixel.
+fn tile_level(scale: f32) -> u32 {
+    let mut level = 0;
+    while level < TILE_MAX_LEVEL && scale * (1u32 << (level + 1)) as f32 <= 1.0 {
+        level += 1;
+    }
+    level
+}
+
+/// Gets the tiles at the `level` that intersect the `visible` rect of the image.
+fn visible_tiles(img_size: PxSize, visible: PxRect, level: u32) -> Vec<TileKey> {
+    let visible = match PxRect::from_size(img_size).intersection(&visible) {
+        Some(r) if !r.is_empty() => r,
+        _ => return vec![],
+    };
+    let len = TileKey::len(level);
+    let (min, max) = (visible.min(), visible.max());
+    let cols = (min.x.0 / len) as u32..((max.x.0 + len - 1) / len) as u32;
+    let rows = (min.y.0 / len) as u32..((max.y.0 + len - 1) / len) as u32;
+    rows.flat_map(|row| cols.clone().map(move |col| TileKey { level, col, row }))
+        .collect()
+}
+
+/// Tiles requested by the [`image_presenter`] for memory mapped images.
+#[derive(Default)]
+struct ImageTiles {
+    /// Tiles that intersect the viewport at the current level.
+    visible: Vec<TileKey>,
+    /// Requested tiles, includes tiles that are not visible anymore until all visible tiles load.
+    tiles: Vec<(TileKey, Var<ImageEntry>, VarHandle)>,
+}
+impl ImageTiles {
+    fn clear(&mut self) {
+        self.visible.clear();
+        self.tiles.clear();
+    }
+
+    /// Computes the visible tiles, returns `true` if new tiles must be requested.
+    fn set_visible(&mut self, visible: Vec<TileKey>) -> bool {
+        if self.visible == visible {
+            return false;
+        }
+        self.visible = visible;
+        self.visible.iter().any(|k| !self.tiles.iter().any(|(t, _, _)| t == k))
+    }
+
+    /// Request the visible tiles that are not requested yet.
+    fn request(&mut self, source: &ImageSource, img_size: PxSize) {
+        for &key in &self.visible {
+            if !self.tiles.iter().any(|(t, _, _)| *t == key) {
+                let img = IMAGES.image(source.clone(), key.options(img_size), IMAGE_LIMITS_VAR.get());
+                let handle = img.subscribe(UpdateOp::Render, WIDGET.id());
+                self.tiles.push((key, img, handle));
+            }
+        }
+    }
+
+    /// Drop tiles that are not visible after all visible tiles load.
+    fn clean(&mut self) {
+        let visible_loaded = self
+            .tiles
+            .iter()
+            .all(|(k, img, _)| !self.visible.contains(k) || !img.with(ImageEntry::is_loading));
+        if visible_loaded {
+            let visible = &self.visible;
+            self.tiles.retain(|(k, _, _)| visible.contains(k));
+        }
+    }
+
+    fn render(&self, frame: &mut FrameBuilder, spatial_id: SpatialFrameId, img_size: PxSize, r: &TilesRender) {
+        let level = self.visible.first().map(|k| k.level);
+        // render previous level tiles under the current level tiles
+        let prev = self.tiles.iter().filter(|(k, _, _)| Some(k.level) != level);
+        let current = self.tiles.iter().filter(|(k, _, _)| Some(k.level) == level);
+        for (i, (key, img, _)) in prev.chain(current).enumerate() {
+            img.with(|img| {
+                if !img.is_loaded() {
+                    return;
+                }
+                let rect = key.rect(img_size);
+                // scale the corners so that neighbor tiles share the edges
+                let min = PxPoint::new(rect.min_x() * r.scale.x, rect.min_y() * r.scale.y);
+                let max = PxPoint::new(rect.max_x() * r.scale.x, rect.max_y() * r.scale.y);
+                let tile_rect = PxRect::new(min, (max - min).to_size());
+                if let Some(clip) = r
+                    .clip
+                    .translate(-min.to_vector())
+                    .intersection(&PxRect::from_size(tile_rect.size))
+                    .filter(|c| !c.is_empty())
+                {
+                    frame.push_reference_frame(
+                        ReferenceFrameId::from_unique_child(spatial_id, i as u32),
+                        FrameValue::Value(PxTransform::from(r.offset + min.to_vector())),
+                        true,
+                        false,
+                        |frame| frame.push_image(clip, tile_rect.size, tile_rect.size, PxSize::zero(), img, r.rendering),
+                    );
+                }
+            });
+        }
+    }
+}
+struct TilesRender {
+    clip: PxRect,
+    offset: PxVector,
+    scale: Factor2d,
+    rendering: ImageRendering,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: i32, y: i32, w: i32, h: i32) -> PxRect {
+        PxRect::new(PxPoint::new(Px(x), Px(y)), PxSize::new(Px(w), Px(h)))
+    }
+
+    #[test]
+    fn tile_level_scale() {
+        assert_eq!(0, tile_level(2.0));
+        assert_eq!(0, tile_level(1.0));
+        assert_eq!(0, tile_level(0.6));
+        assert_eq!(1, tile_level(0.5));
+        assert_eq!(1, tile_level(0.3));
+        assert_eq!(2, tile_level(0.25));
+        assert_eq!(TILE_MAX_LEVEL, tile_level(0.0));
+    }
+
+    #[test]
+    fn tile_rect_clamped() {
+        let img_size = PxSize::new(Px(1000), Px(600));
+        let key = TileKey { level: 0, col: 1, row: 1 };
+        assert_eq!(rect(512, 512, 488, 88), key.rect(img_size));
+        let key = TileKey { level: 1, col: 0, row: 0 };
+        assert_eq!(rect(0, 0, 1000, 600), key.rect(img_size));
+    }
+
+    #[test]
+    fn tile_options_downscale() {
+        let img_size = PxSize::new(Px(3000), Px(3000));
+        let opt = TileKey { level: 1, col: 2, row: 0 }.options(img_size);
+        assert_eq!(Some(rect(2048, 0, 952, 1024)), opt.region);
+        assert_eq!(Some(ImageDownscaleMode::Fit(PxSize::new(Px(476), Px(512)))), opt.downscale);
+
+        let opt = TileKey::default().options(img_size);
+        assert_eq!(Some(rect(0, 0, 512, 512)), opt.region);
+        assert_eq!(None, opt.downscale);
+    }
+
+    #[test]
+    fn visible_tiles_viewport() {
+        let img_size = PxSize::new(Px(2000), Px(2000));
+        let tiles = visible_tiles(img_size, rect(500, 0, 100, 600), 0);
+        assert_eq!(
+            vec![
+                TileKey { level: 0, col: 0, row: 0 },
+                TileKey { level: 0, col: 1, row: 0 },
+                TileKey { level: 0, col: 0, row: 1 },
+                TileKey { level: 0, col: 1, row: 1 },
+            ],
+            tiles
+        );
+
+        let tiles = visible_tiles(img_size, rect(0, 0, 5000, 5000), 1);
+        assert_eq!(4, tiles.len());
+
+        assert!(visible_tiles(img_size, rect(3000, 0, 100, 100), 0).is_empty());
+    }
+}
@@ -1,3 +1,6 @@
+CONTEXT_MENU_CMD =
+    .name = Context Menu
+
 NEW_CMD =
     .name = New
 
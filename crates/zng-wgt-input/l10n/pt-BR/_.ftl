@@ -1,3 +1,6 @@
+CONTEXT_MENU_CMD =
+    .name = Menu de Contexto
+
 NEW_CMD =
     .name = Novo
 
@@ -1,5 +1,8 @@
 ### Auto generated by `cargo zng l10n`
 
+CONTEXT_MENU_CMD =
+    .name = Context Menu
+
 NEW_CMD =
     .name = New
 
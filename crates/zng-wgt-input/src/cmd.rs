@@ -49,6 +49,8 @@ command! {
 
     /// Represents the **context menu open** action.
     pub static CONTEXT_MENU_CMD {
+        l10n!: true,
+        name: "Context Menu",
         shortcut: [shortcut!(SHIFT + F10), shortcut!(ContextMenu)],
         icon: wgt_fn!(|_| ICONS.get(["context-menu", "menu-open"])),
     };
@@ -3,9 +3,10 @@
 //! Drag&drop properties, event properties.
 
 use zng_ext_input::drag_drop::{
-    DRAG_END_EVENT, DRAG_HOVERED_EVENT, DRAG_START_EVENT, DROP_EVENT, DragEndArgs, DragHoveredArgs, DragStartArgs, DropArgs,
-    WidgetInfoBuilderDragDropExt as _,
+    DRAG_DROP, DRAG_END_EVENT, DRAG_HOVERED_EVENT, DRAG_START_EVENT, DROP_EVENT, DragDropData, DragDropEffect, DragDropType, DragEndArgs,
+    DragHoveredArgs, DragStartArgs, DropArgs, WidgetInfoBuilderDragDropExt as _,
 };
+use zng_ext_window::WINDOWS;
 use zng_wgt::{node::bind_state_init, prelude::*};
 
 /// If this widget can be dragged in a drag&drop operation.
@@ -29,6 +30,68 @@ pub fn draggable(child: impl IntoUiNode, input: impl IntoVar<bool>) -> UiNode {
     })
 }
 
+context_var! {
+    /// Drag&drop data types accepted by drop targets.
+    ///
+    /// Is empty by default, all types are accepted.
+    pub static DROP_ACCEPT_VAR: Vec<DragDropType> = vec![];
+}
+
+/// Drag&drop data types accepted by the widget and descendants.
+///
+/// When the dragging cursor enters the widget the drop effect is set to reject data that has none of the `types`
+/// and [`on_drop`] only notifies drops with accepted data. If `types` is empty all data is accepted, this is the default.
+///
+/// The innermost widget with this property in the hovered path defines the drop effect. Note that the drop effect only
+/// applies to drag&drop from other apps, use [`is_drop_accepted`] to show the accept/reject feedback for all drags.
+///
+/// This property sets the [`DROP_ACCEPT_VAR`].
+///
+/// [`on_drop`]: fn@on_drop
+/// [`is_drop_accepted`]: fn@is_drop_accepted
+#[property(CONTEXT, default(DROP_ACCEPT_VAR))]
+pub fn drop_accept(child: impl IntoUiNode, types: impl IntoVar<Vec<DragDropType>>) -> UiNode {
+    let child = match_node(child, |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_event(&DRAG_HOVERED_EVENT);
+        }
+        UiNodeOp::Info { info } => {
+            info.flag_meta(*DROP_ACCEPT_ID);
+        }
+        UiNodeOp::Update { updates } => {
+            c.update(updates);
+
+            let id = WIDGET.id();
+            DRAG_HOVERED_EVENT.each_update(true, |args| {
+                if let Some(target) = &args.target
+                    && target.contains_enabled(id)
+                    && innermost_drop_accept(target) == Some(id)
+                {
+                    let accepted = DROP_ACCEPT_VAR.with(|t| DRAG_DROP.dragging_data().with(|d| is_accepted(t, d)));
+                    args.set_drop_effect(if accepted { DragDropEffect::all() } else { DragDropEffect::empty() });
+                }
+            });
+        }
+        _ => {}
+    });
+    with_context_var(child, DROP_ACCEPT_VAR, types)
+}
+fn innermost_drop_accept(target: &InteractionPath) -> Option<WidgetId> {
+    let tree = WINDOWS.widget_tree(target.window_id())?;
+    target
+        .widgets_path()
+        .iter()
+        .rev()
+        .find(|id| tree.get(**id).is_some_and(|w| w.meta().flagged(*DROP_ACCEPT_ID)))
+        .copied()
+}
+fn is_accepted(types: &[DragDropType], data: &[DragDropData]) -> bool {
+    types.is_empty() || data.iter().any(|d| types.contains(&d.data_type()))
+}
+static_id! {
+    static ref DROP_ACCEPT_ID: StateId<()>;
+}
+
 event_property! {
     /// Draggable widget started dragging.
     ///
@@ -87,13 +150,19 @@ event_property! {
     }
 
     /// Dragging cursor dropped data in the widget area and the widget is enabled.
+    ///
+    /// Only drops with data accepted by [`drop_accept`] notify, the [`DropArgs`] has methods to get the text, image,
+    /// paths or custom payload.
+    ///
+    /// [`drop_accept`]: fn@drop_accept
     #[property(EVENT)]
     pub fn on_drop<on_pre_drop>(child: impl IntoUiNode, handler: Handler<DropArgs>) -> UiNode {
         const PRE: bool;
         EventNodeBuilder::new(DROP_EVENT)
             .filter(|| {
                 let id = WIDGET.id();
-                move |args| args.target.contains_enabled(id)
+                let accept = DROP_ACCEPT_VAR.current_context();
+                move |args| args.target.contains_enabled(id) && accept.with(|t| is_accepted(t, &args.data))
             })
             .build::<PRE>(child, handler)
     }
@@ -138,6 +207,27 @@ pub fn is_drag_hovered_disabled(child: impl IntoUiNode, state: impl IntoVar<bool
     })
 }
 
+/// If the dragging cursor is over the widget or a descendant, the widget is enabled and the dragging data is accepted
+/// by [`drop_accept`].
+///
+/// [`drop_accept`]: fn@drop_accept
+#[property(EVENT)]
+pub fn is_drop_accepted(child: impl IntoUiNode, state: impl IntoVar<bool>) -> UiNode {
+    bind_state_init(child, state, |s| {
+        let id = WIDGET.id();
+        let accept = DROP_ACCEPT_VAR.current_context();
+        DRAG_HOVERED_EVENT.var_bind(s, move |args| {
+            if args.is_drag_enter_enabled(id) {
+                Some(accept.with(|t| args.data().with(|d| is_accepted(t, d))))
+            } else if args.is_drag_leave_enabled(id) {
+                Some(false)
+            } else {
+                None
+            }
+        })
+    })
+}
+
 /// If the draggable widget is dragging.
 #[property(EVENT)]
 pub fn is_dragging(child: impl IntoUiNode, state: impl IntoVar<bool>) -> UiNode {
@@ -9,12 +9,13 @@ use std::{
 };
 
 use zng_app::{
-    shortcut::{GestureKey, Shortcuts},
+    shortcut::{GestureKey, KeyGesture, ModifiersState, Shortcuts},
     widget::info::{TreeFilter, iter::TreeIterator},
 };
 use zng_ext_input::{
     focus::{FOCUS, FOCUS_CHANGED_EVENT},
     gesture::{CLICK_EVENT, GESTURES, ShortcutClick},
+    keyboard::KEYBOARD,
 };
 use zng_var::AnyVar;
 use zng_view_api::{access::AccessCmdName, keyboard::Key};
@@ -350,7 +351,11 @@ pub fn mnemonic_txt(child: impl IntoUiNode, txt: impl IntoVar<Txt>) -> UiNode {
 /// When focus is within the scope widget and the focus event was caused by key press a
 /// [`GESTURES.click_shortcut`] is set for each [`mnemonic`] descendant.
 ///
+/// If [`mnemonic_alt`] is also enabled on the scope widget `Alt+char` shortcuts are set for each descendant while
+/// the scope is inited, independent of focus.
+///
 /// [`mnemonic`]: fn@mnemonic
+/// [`mnemonic_alt`]: fn@mnemonic_alt
 /// [`GESTURES.click_shortcut`]: GESTURES::click_shortcut
 #[property(CONTEXT, default(false))]
 pub fn mnemonic_scope(child: impl IntoUiNode, is_scope: impl IntoVar<bool>) -> UiNode {
@@ -360,6 +365,10 @@ pub fn mnemonic_scope(child: impl IntoUiNode, is_scope: impl IntoVar<bool>) -> U
     let mut var_subs = VarHandles::dummy();
     let mut shortcut_subs = vec![];
     let mut is_focus_within = false;
+    let mut alt = false;
+    let mut alt_held = false;
+    let mut alt_shortcut_subs = vec![];
+    let mut mnemonic_chars = HashMap::new();
     let active_mnemonics = var(HashMap::new());
     let child = with_context_var(child, ACTIVE_MNEMONICS_VAR, active_mnemonics.read_only());
     match_node(child, move |_, op| match op {
@@ -371,6 +380,10 @@ pub fn mnemonic_scope(child: impl IntoUiNode, is_scope: impl IntoVar<bool>) -> U
             var_subs = VarHandles::dummy();
             shortcut_subs = vec![];
             is_focus_within = false;
+            alt = false;
+            alt_held = false;
+            alt_shortcut_subs = vec![];
+            mnemonic_chars = HashMap::new();
             active_mnemonics.set(HashMap::new());
         }
         UiNodeOp::Info { info } => {
@@ -382,9 +395,20 @@ pub fn mnemonic_scope(child: impl IntoUiNode, is_scope: impl IntoVar<bool>) -> U
         }
         UiNodeOp::Update { .. } => {
             let mut set_shortcuts = false;
+            let mut show_changed = false;
             if mem::take(&mut init) {
                 var_subs.clear();
                 shortcut_subs.clear();
+                alt_shortcut_subs.clear();
+
+                alt = is_scope.get() && WIDGET.info().meta().flagged(*MNEMONIC_ALT_ID);
+                if alt {
+                    var_subs.push(KEYBOARD.modifiers().subscribe(UpdateOp::Update, WIDGET.id()));
+                    alt_held = KEYBOARD.modifiers().get().has_alt();
+                    set_shortcuts = true;
+                } else if mem::take(&mut alt_held) {
+                    show_changed = true;
+                }
 
                 if is_scope.get() {
                     // sub to is_focus_within
@@ -393,7 +417,7 @@ pub fn mnemonic_scope(child: impl IntoUiNode, is_scope: impl IntoVar<bool>) -> U
                         FOCUS_CHANGED_EVENT.subscribe_when(UpdateOp::Update, id, move |a| a.is_focus_enter(id) || a.is_focus_leave(id)),
                     );
                     is_focus_within = FOCUS.is_highlighting().get() && FOCUS.focused().with(|f| matches!(f, Some(f) if f.contains(id)));
-                    set_shortcuts = is_focus_within;
+                    set_shortcuts |= is_focus_within;
 
                     // sub to each descendant mnemonic properties
                     let mut var_sub = |v: &AnyVar| {
@@ -445,20 +469,26 @@ pub fn mnemonic_scope(child: impl IntoUiNode, is_scope: impl IntoVar<bool>) -> U
                         } else {
                             is_focus_within = false;
                             shortcut_subs.clear();
-                            active_mnemonics.modify(|a| {
-                                if !a.is_empty() {
-                                    a.clear();
-                                }
-                            });
+                            show_changed = true;
                         }
                     }
                 });
+
+                if alt && let Some(m) = KEYBOARD.modifiers().get_new() {
+                    let held = m.has_alt();
+                    if held != alt_held {
+                        alt_held = held;
+                        show_changed = true;
+                    }
+                }
             }
 
-            if is_focus_within && (set_shortcuts || update.is_new()) {
+            if (is_focus_within || alt) && (set_shortcuts || update.is_new()) {
                 // focus entered OR inited and is focus within OR is focus within and descendant state changed
+                // OR inited with alt shortcuts OR descendant state changed with alt shortcuts
 
                 shortcut_subs.clear();
+                alt_shortcut_subs.clear();
 
                 let mut chars = HashMap::new();
                 let mut auto = vec![];
@@ -605,15 +635,56 @@ pub fn mnemonic_scope(child: impl IntoUiNode, is_scope: impl IntoVar<bool>) -> U
 
                 // register shortcuts
                 for (_, (id, c)) in chars.iter() {
-                    let h = GESTURES.click_shortcut(GestureKey::Key(Key::Char(*c)), ShortcutClick::Primary, *id);
-                    shortcut_subs.push(h);
-                }
-                active_mnemonics.modify(move |m| {
-                    m.clear();
-                    for (_, (id, c)) in chars {
-                        m.insert(id, c);
+                    if is_focus_within {
+                        let h = GESTURES.click_shortcut(GestureKey::Key(Key::Char(*c)), ShortcutClick::Primary, *id);
+                        shortcut_subs.push(h);
                     }
-                });
+                    if alt {
+                        let s = KeyGesture::new(ModifiersState::ALT, GestureKey::Key(Key::Char(*c)));
+                        let h = GESTURES.click_shortcut(s, ShortcutClick::Primary, *id);
+                        alt_shortcut_subs.push(h);
+                    }
+                }
+                mnemonic_chars = chars.into_values().collect();
+                show_changed = true;
+            }
+
+            if show_changed {
+                // show underline while focus is within or while alt is held
+                if is_focus_within || alt_held {
+                    active_mnemonics.set(mnemonic_chars.clone());
+                } else {
+                    active_mnemonics.modify(|a| {
+                        if !a.is_empty() {
+                            a.clear();
+                        }
+                    });
+                }
+            }
+        }
+        _ => {}
+    })
+}
+
+/// Enables `Alt+char` shortcuts for the [`mnemonic`] descendants of a [`mnemonic_scope`].
+///
+/// This property must be set on the same widget as the scope. When enabled the mnemonics are active while the scope is inited, pressing
+/// `Alt` and the mnemonic char clicks the widget, independent of focus, and the mnemonic chars are marked while `Alt` is held.
+///
+/// Note that the scope mnemonics still activate without `Alt` when focus is within the scope.
+///
+/// [`mnemonic`]: fn@mnemonic
+/// [`mnemonic_scope`]: fn@mnemonic_scope
+#[property(CONTEXT, default(false))]
+pub fn mnemonic_alt(child: impl IntoUiNode, enabled: impl IntoVar<bool>) -> UiNode {
+    let enabled = enabled.into_var();
+    match_node(child, move |_, op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_var_info(&enabled);
+        }
+        UiNodeOp::Info { info } => {
+            if enabled.get() {
+                info.flag_meta(*MNEMONIC_ALT_ID);
             }
         }
         _ => {}
@@ -679,6 +750,7 @@ pub fn get_mnemonic(child: impl IntoUiNode, state: impl IntoVar<Mnemonic>) -> Ui
 
 static_id! {
     static ref MNEMONIC_SCOPE_ID: StateId<()>;
+    static ref MNEMONIC_ALT_ID: StateId<()>;
     static ref MNEMONIC_ID: StateId<Var<Mnemonic>>;
     static ref MNEMONIC_TXT_ID: StateId<Var<Txt>>;
 }
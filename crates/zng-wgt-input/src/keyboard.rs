@@ -4,7 +4,7 @@
 //! Before using them review the [`gesture`](super::gesture) properties, in particular
 //! the [`click_shortcut`](fn@super::gesture::click_shortcut) property.
 
-use zng_ext_input::keyboard::{KEY_INPUT_EVENT, KeyInputArgs, KeyState};
+use zng_ext_input::keyboard::{KEY_INPUT_EVENT, KeyInputArgs, KeyRepeatConfig, KeyState, WidgetInfoBuilderKeyboardExt as _};
 use zng_wgt::prelude::*;
 
 event_property! {
@@ -151,3 +151,27 @@ event_property! {
             .build::<PRE>(child, handler)
     }
 }
+
+/// Override the key repeat delay and interval for key presses when the widget or a descendant is focused.
+///
+/// When set the system key repeat events are ignored and the keyboard service generates repeat events using
+/// the override config, this can be used to tune a "hold to increment" control.
+///
+/// Setting this to `None` will cause the widget to inherit the parent override, or the [`KEYBOARD.repeat_config`] if
+/// no parent sets an override.
+///
+/// [`KEYBOARD.repeat_config`]: zng_ext_input::keyboard::KEYBOARD::repeat_config
+#[property(CONTEXT, default(None))]
+pub fn key_repeat_config(child: impl IntoUiNode, config: impl IntoVar<Option<KeyRepeatConfig>>) -> UiNode {
+    let config = config.into_var();
+
+    match_node(child, move |_, op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_var_info(&config);
+        }
+        UiNodeOp::Info { info } => {
+            info.set_key_repeat_config(config.get());
+        }
+        _ => {}
+    })
+}
@@ -1,6 +1,9 @@
 use std::sync::atomic::AtomicBool;
 
-use zng_ext_input::mouse::{ClickMode, MOUSE_HOVERED_EVENT, WidgetInfoBuilderMouseExt as _};
+use zng_ext_input::{
+    keyboard::{KeyRepeatConfig, WidgetInfoBuilderKeyboardExt as _},
+    mouse::{ButtonRepeatConfig, ClickMode, MOUSE_HOVERED_EVENT, WidgetInfoBuilderMouseExt as _},
+};
 use zng_ext_window::WINDOWS;
 use zng_wgt::prelude::*;
 
@@ -117,3 +120,32 @@ pub fn click_mode(child: impl IntoUiNode, mode: impl IntoVar<Option<ClickMode>>)
         _ => {}
     })
 }
+
+/// Fire the click action repeatedly while the pointer button or key is held pressed on the widget.
+///
+/// When set to a config the widget [`click_mode`] is [`ClickMode::repeat`], the mouse repeat uses the config delay, interval
+/// and acceleration and the keyboard repeat uses the config delay and interval. This can be used in scroll arrow buttons
+/// and numeric steppers.
+///
+/// Setting this to `None` does not override the click mode or repeat config, the widget inherits the parent config.
+///
+/// [`click_mode`]: fn@click_mode
+#[property(CONTEXT, default(None))]
+pub fn repeat_on_hold(child: impl IntoUiNode, config: impl IntoVar<Option<ButtonRepeatConfig>>) -> UiNode {
+    let config = config.into_var();
+
+    match_node(child, move |_, op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_var_info(&config);
+        }
+        UiNodeOp::Info { info } => {
+            let config = config.get();
+            if config.is_some() {
+                info.set_click_mode(Some(ClickMode::repeat()));
+            }
+            info.set_button_repeat_config(config);
+            info.set_key_repeat_config(config.map(|c| KeyRepeatConfig::new(c.start_delay, c.interval)));
+        }
+        _ => {}
+    })
+}
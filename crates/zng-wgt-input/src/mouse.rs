@@ -5,9 +5,13 @@
 //! Before using them review the [`gesture`](super::gesture) events, in particular the
 //! [`on_click`](fn@super::gesture::on_click) event.
 
-use zng_ext_input::mouse::{
-    CTRL_SCROLL_VAR, MOUSE_CLICK_EVENT, MOUSE_HOVERED_EVENT, MOUSE_INPUT_EVENT, MOUSE_MOVE_EVENT, MOUSE_WHEEL_EVENT, MouseClickArgs,
-    MouseHoverArgs, MouseInputArgs, MouseMoveArgs, MouseWheelArgs,
+use zng_app::widget::border::BorderSide;
+use zng_ext_input::{
+    mouse::{
+        ButtonState, CTRL_SCROLL_VAR, MOUSE_CLICK_EVENT, MOUSE_HOVERED_EVENT, MOUSE_INPUT_EVENT, MOUSE_MOVE_EVENT, MOUSE_WHEEL_EVENT,
+        MouseClickArgs, MouseHoverArgs, MouseInputArgs, MouseMoveArgs, MouseWheelArgs,
+    },
+    pointer_capture::{POINTER_CAPTURE, POINTER_CAPTURE_EVENT},
 };
 use zng_wgt::prelude::*;
 
@@ -323,3 +327,117 @@ event_property! {
 pub fn ctrl_scroll(child: impl IntoUiNode, ctrl_scroll: impl IntoVar<bool>) -> UiNode {
     with_context_var(child, CTRL_SCROLL_VAR, ctrl_scroll)
 }
+
+/// Rubber band selection, drag on empty space to select children.
+///
+/// When the primary mouse button is pressed directly on the widget, not on a child, and dragged, a selection
+/// rectangle is drawn over the children and `selection` is set to the IDs of the child widgets with inner bounds
+/// that intersect the rectangle, in tree order. The selection remains set after the button is released, a new
+/// drag replaces it. The drag also ends if the widget loses the pointer capture, for example, when the window loses focus.
+///
+/// The rectangle is drawn using the [`ACCENT_COLOR_VAR`]. This property is usually set on a panel widget,
+/// multi-select surfaces can then bind the `selection` to the selected state of the items.
+///
+/// [`ACCENT_COLOR_VAR`]: zng_wgt::prelude::colors::ACCENT_COLOR_VAR
+#[property(CHILD_CONTEXT)]
+pub fn rubber_band_select(child: impl IntoUiNode, selection: impl IntoVar<Vec<WidgetId>>) -> UiNode {
+    let selection = selection.into_var();
+    let mut color = const_var(colors::BLACK);
+    // start point in the window space.
+    let mut start = None::<PxPoint>;
+    // selection rectangle in the widget inner space.
+    let mut rect = PxRect::zero();
+    match_node(child, move |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET
+                .sub_event(&MOUSE_INPUT_EVENT)
+                .sub_event(&MOUSE_MOVE_EVENT)
+                .sub_event(&POINTER_CAPTURE_EVENT);
+            color = colors::ACCENT_COLOR_VAR.rgba();
+            WIDGET.sub_var_render(&color);
+        }
+        UiNodeOp::Deinit => {
+            start = None;
+            rect = PxRect::zero();
+            color = const_var(colors::BLACK);
+        }
+        UiNodeOp::Update { updates } => {
+            c.update(updates);
+
+            let id = WIDGET.id();
+            MOUSE_INPUT_EVENT.each_update(false, |args| {
+                if !args.is_primary() {
+                    return;
+                }
+                match args.state {
+                    ButtonState::Pressed => {
+                        if args.target.widget_id() == id && args.target.interactivity().is_enabled() {
+                            args.propagation.stop();
+                            start = Some(args.position.to_px(WINDOW.info().scale_factor()));
+                            rect = PxRect::zero();
+                            POINTER_CAPTURE.capture_widget(id);
+                            if selection.with(|s| !s.is_empty()) {
+                                selection.set(vec![]);
+                            }
+                        }
+                    }
+                    ButtonState::Released => {
+                        if start.take().is_some() {
+                            WIDGET.render();
+                        }
+                    }
+                }
+            });
+            if start.is_some() {
+                POINTER_CAPTURE_EVENT.each_update(true, |args| {
+                    if args.is_lost(id) {
+                        start = None;
+                        rect = PxRect::zero();
+                        WIDGET.render();
+                    }
+                });
+            }
+            MOUSE_MOVE_EVENT.each_update(false, |args| {
+                if let Some(s) = start {
+                    args.propagation.stop();
+
+                    let info = WIDGET.info();
+                    let pt = args.position.to_px(info.tree().scale_factor());
+                    let window_rect = PxBox::from_points([s, pt]).to_rect();
+
+                    let sel: Vec<_> = info
+                        .children()
+                        .filter(|w| w.inner_bounds().intersects(&window_rect))
+                        .map(|w| w.id())
+                        .collect();
+                    if selection.with(|s| s != &sel) {
+                        selection.set(sel);
+                    }
+
+                    let inverse = info.inner_transform().inverse();
+                    let to_local = |p: PxPoint| inverse.and_then(|t| t.transform_point(p)).unwrap_or(p);
+                    let r = PxBox::from_points([to_local(s), to_local(pt)]).to_rect();
+                    if r != rect {
+                        rect = r;
+                        WIDGET.render();
+                    }
+                }
+            });
+        }
+        UiNodeOp::Render { frame } => {
+            c.render(frame);
+            if start.is_some() && !rect.size.is_empty() {
+                let color = color.get();
+                frame.push_color(rect, FrameValue::Value(color.with_alpha(20.pct())));
+                let w = Dip::new(1).to_px(frame.scale_factor());
+                frame.push_border(
+                    rect,
+                    PxSideOffsets::new_all_same(w),
+                    BorderSides::new_all(BorderSide::solid(color)),
+                    PxCornerRadius::zero(),
+                );
+            }
+        }
+        _ => {}
+    })
+}
@@ -154,3 +154,84 @@ pub fn capture_pointer_on_init(child: impl IntoUiNode, mode: impl IntoVar<Captur
         _ => {}
     })
 }
+
+/// Capture mouse and touch for the widget while the button or touch that pressed it is held.
+///
+/// When enabled the widget captures the pointer on mouse down or touch start and releases the capture as soon as the
+/// same mouse button is released or the same touch ends or is canceled, even if other buttons or contacts are still pressed.
+/// This is the common pattern for custom sliders and drag handles, the widget keeps receiving pointer events when
+/// the pointer leaves its bounds.
+///
+/// The capture is also released if the property is disabled or the widget deinits while capturing. If the capture is lost
+/// for other reasons, like the window losing focus or another widget taking the capture, the pressed state is reset and the widget
+/// only captures again on the next press.
+#[property(CONTEXT, default(false))]
+pub fn capture_pointer_while_pressed(child: impl IntoUiNode, enabled: impl IntoVar<bool>) -> UiNode {
+    let enabled = enabled.into_var();
+    let mut mouse_button = None;
+    let mut touch = None;
+
+    fn release() {
+        let widget_id = WIDGET.id();
+        if let Some(current) = POINTER_CAPTURE.current_capture().get()
+            && current.target.widget_id() == widget_id
+        {
+            POINTER_CAPTURE.release_capture();
+        }
+    }
+
+    match_node(child, move |_, op| match op {
+        UiNodeOp::Init => {
+            WIDGET
+                .sub_event(&MOUSE_INPUT_EVENT)
+                .sub_event(&TOUCH_INPUT_EVENT)
+                .sub_event(&POINTER_CAPTURE_EVENT)
+                .sub_var(&enabled);
+        }
+        UiNodeOp::Deinit => {
+            if mouse_button.take().is_some() || touch.take().is_some() {
+                release();
+            }
+        }
+        UiNodeOp::Update { .. } => {
+            if let Some(false) = enabled.get_new()
+                && (mouse_button.take().is_some() || touch.take().is_some())
+            {
+                release();
+            }
+
+            MOUSE_INPUT_EVENT.each_update(true, |args| {
+                if args.is_mouse_down() {
+                    if enabled.get() && mouse_button.is_none() && touch.is_none() {
+                        mouse_button = Some(args.button);
+                        POINTER_CAPTURE.capture_widget(WIDGET.id());
+                    }
+                } else if args.is_mouse_up() && mouse_button == Some(args.button) {
+                    mouse_button = None;
+                    release();
+                }
+            });
+            TOUCH_INPUT_EVENT.each_update(true, |args| {
+                if args.is_touch_start() {
+                    if enabled.get() && mouse_button.is_none() && touch.is_none() {
+                        touch = Some(args.touch);
+                        POINTER_CAPTURE.capture_widget(WIDGET.id());
+                    }
+                } else if (args.is_touch_end() || args.is_touch_cancel()) && touch == Some(args.touch) {
+                    touch = None;
+                    release();
+                }
+            });
+            if mouse_button.is_some() || touch.is_some() {
+                let widget_id = WIDGET.id();
+                POINTER_CAPTURE_EVENT.each_update(true, |args| {
+                    if args.is_lost(widget_id) {
+                        mouse_button = None;
+                        touch = None;
+                    }
+                });
+            }
+        }
+        _ => {}
+    })
+}
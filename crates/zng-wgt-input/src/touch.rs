@@ -6,8 +6,8 @@
 //! [`on_click`](fn@super::gesture::on_click) event.
 
 use zng_ext_input::touch::{
-    TOUCH_INPUT_EVENT, TOUCH_LONG_PRESS_EVENT, TOUCH_MOVE_EVENT, TOUCH_TAP_EVENT, TOUCH_TRANSFORM_EVENT, TOUCHED_EVENT, TouchInputArgs,
-    TouchLongPressArgs, TouchMoveArgs, TouchTapArgs, TouchTransformArgs, TouchedArgs,
+    TOUCH_EDGE_SWIPE_EVENT, TOUCH_INPUT_EVENT, TOUCH_LONG_PRESS_EVENT, TOUCH_MOVE_EVENT, TOUCH_TAP_EVENT, TOUCH_TRANSFORM_EVENT,
+    TOUCHED_EVENT, TouchEdgeSwipeArgs, TouchInputArgs, TouchLongPressArgs, TouchMoveArgs, TouchTapArgs, TouchTransformArgs, TouchedArgs,
 };
 use zng_wgt::prelude::*;
 
@@ -189,4 +189,19 @@ event_property! {
             })
             .build::<PRE>(child, handler)
     }
+
+    /// Single touch contact started near a window edge and swiped away from it, started on the widget and the widget is enabled.
+    ///
+    /// The event notifies for each move until the touch ends, the [`TouchEdgeSwipeArgs::distance`] can be used to
+    /// implement an interactive back swipe that follows the finger.
+    #[property(EVENT)]
+    pub fn on_edge_swipe<on_pre_edge_swipe>(child: impl IntoUiNode, handler: Handler<TouchEdgeSwipeArgs>) -> UiNode {
+        const PRE: bool;
+        EventNodeBuilder::new(TOUCH_EDGE_SWIPE_EVENT)
+            .filter(|| {
+                let id = WIDGET.id();
+                move |args| args.target.contains_enabled(id)
+            })
+            .build::<PRE>(child, handler)
+    }
 }
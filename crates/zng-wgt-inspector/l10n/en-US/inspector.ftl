@@ -1,3 +1,7 @@
+FRAME_STATS_CMD =
+    .info = Toggle the frame statistics overlay
+    .name = Frame Stats
+
 INSPECT_CMD =
     .info = Inspect the window
     .name = Debug Inspector
@@ -1,3 +1,7 @@
+FRAME_STATS_CMD =
+    .info = Alternar a sobreposição de estatísticas de quadros
+    .name = Estatísticas de Quadros
+
 INSPECT_CMD =
     .info = Inspecionar a janela
     .name = Inspetor de Depuração
@@ -1,5 +1,9 @@
 ### Auto generated by `cargo zng l10n`
 
+FRAME_STATS_CMD =
+    .info = Toggle the frame statistics overlay
+    .name = Frame Stats
+
 INSPECT_CMD =
     .info = Inspect the window
     .name = Debug Inspector
@@ -1,6 +1,6 @@
 //! Debug inspection properties.
 
-use std::{cell::RefCell, fmt, rc::Rc};
+use std::{cell::RefCell, fmt, rc::Rc, time::Duration};
 
 use zng_ext_input::{
     focus::WidgetInfoFocusExt as _,
@@ -391,3 +391,169 @@ pub fn show_directional_query(child: impl IntoUiNode, orientation: impl IntoVar<
         _ => {}
     })
 }
+
+command! {
+    /// Represent the window **frame stats** overlay toggle action.
+    ///
+    /// The command is handled by [`show_frame_stats`].
+    ///
+    /// [`show_frame_stats`]: fn@show_frame_stats
+    pub static FRAME_STATS_CMD {
+        l10n!: "inspector",
+        name: "Frame Stats",
+        info: "Toggle the frame statistics overlay",
+        shortcut: shortcut!(CTRL | SHIFT + 'R'),
+    };
+}
+
+/// Window performance overlay, toggled by the [`FRAME_STATS_CMD`].
+///
+/// The overlay shows the frames per second and the interval between the last two frames presented by the view-process,
+/// the time spent building the last display list and the number of widgets in the window.
+///
+/// The overlay text updates every half second to avoid causing the frames it measures. The `can_show` value
+/// enables the command.
+///
+/// # Window Only
+///
+/// This property only works if set in a window, if set in another widget it will log an error and not render anything.
+#[property(WIDGET, default(false))]
+pub fn show_frame_stats(child: impl IntoUiNode, can_show: impl IntoVar<bool>) -> UiNode {
+    use std::collections::VecDeque;
+    use zng_app::view_process::raw_events::RAW_FRAME_RENDERED_EVENT;
+    use zng_wgt::{align, hit_test_mode, margin, visibility};
+    use zng_wgt_container::{Container, padding};
+    use zng_wgt_fill::background_color;
+    use zng_wgt_text::{Text, font_color, font_family, font_size};
+
+    #[derive(Default)]
+    struct Stats {
+        frames: VecDeque<DInstant>,
+        interval: Duration,
+        build: Duration,
+    }
+    impl Stats {
+        fn frame(&mut self, timestamp: DInstant) {
+            if let Some(last) = self.frames.back() {
+                self.interval = timestamp.duration_since(*last);
+            }
+            self.frames.push_back(timestamp);
+            while let Some(first) = self.frames.front()
+                && timestamp.duration_since(*first) > 1.secs()
+            {
+                self.frames.pop_front();
+            }
+        }
+
+        fn summary(&self, widgets: usize) -> Txt {
+            formatx!(
+                "{} fps\nframe {:.1}ms\nbuild {:.2}ms\nwidgets {widgets}",
+                self.frames.len(),
+                self.interval.as_secs_f32() * 1000.0,
+                self.build.as_secs_f32() * 1000.0,
+            )
+        }
+    }
+
+    let can_show = can_show.into_var();
+    let show = var(false);
+    let summary = var(Txt::from_static(""));
+
+    let mut valid = false;
+    let mut stats = Stats::default();
+    let mut timer = None::<TimerVar>;
+    let mut _timer_handle = VarHandle::dummy();
+    let mut cmd_handle = CommandHandle::dummy();
+
+    let child = match_node(
+        child,
+        clmv!(show, summary, |c, op| match op {
+            UiNodeOp::Init => {
+                valid = WIDGET.parent_id().is_none();
+                if valid {
+                    WIDGET.sub_var(&can_show).sub_event(&RAW_FRAME_RENDERED_EVENT);
+                    cmd_handle = FRAME_STATS_CMD.scoped(WINDOW.id()).subscribe_wgt(can_show.get(), WIDGET.id());
+                } else {
+                    tracing::error!("property `show_frame_stats` is only valid in a window");
+                }
+            }
+            UiNodeOp::Deinit => {
+                cmd_handle = CommandHandle::dummy();
+                timer = None;
+                _timer_handle = VarHandle::dummy();
+                stats = Stats::default();
+                show.set(false);
+            }
+            UiNodeOp::Update { updates } => {
+                c.update(updates);
+                if !valid {
+                    return;
+                }
+
+                if let Some(e) = can_show.get_new() {
+                    cmd_handle.enabled().set(e);
+                    if !e {
+                        timer = None;
+                        _timer_handle = VarHandle::dummy();
+                        show.set(false);
+                    }
+                }
+
+                FRAME_STATS_CMD.scoped(WINDOW.id()).each_update(true, false, |args| {
+                    args.propagation.stop();
+
+                    if timer.take().is_none() {
+                        let t = TIMERS.interval(500.ms(), false);
+                        _timer_handle = t.subscribe(UpdateOp::Update, WIDGET.id());
+                        timer = Some(t);
+                        summary.set(stats.summary(WINDOW.info().len()));
+                    } else {
+                        _timer_handle = VarHandle::dummy();
+                    }
+                    show.set(timer.is_some());
+                });
+
+                if let Some(t) = &timer {
+                    let window_id = WINDOW.id();
+                    RAW_FRAME_RENDERED_EVENT.each_update(true, |args| {
+                        if args.window_id == window_id {
+                            stats.frame(args.timestamp);
+                        }
+                    });
+
+                    if t.is_new() {
+                        summary.set(stats.summary(WINDOW.info().len()));
+                    }
+                }
+            }
+            UiNodeOp::Render { frame } if timer.is_some() => {
+                let start = INSTANT.now();
+                c.render(frame);
+                stats.build = start.elapsed();
+            }
+            UiNodeOp::RenderUpdate { update } if timer.is_some() => {
+                let start = INSTANT.now();
+                c.render_update(update);
+                stats.build = start.elapsed();
+            }
+            _ => {}
+        }),
+    );
+
+    let overlay = Container! {
+        child = Text! {
+            txt = summary;
+            font_family = "monospace";
+            font_size = 12;
+            font_color = colors::WHITE;
+        };
+        background_color = colors::BLACK.with_alpha(70.pct());
+        padding = (2, 6);
+        margin = 4;
+        align = Align::TOP_END;
+        hit_test_mode = false;
+        visibility = show.map(|&s| if s { Visibility::Visible } else { Visibility::Collapsed });
+    };
+
+    crate::inspector(child, overlay)
+}
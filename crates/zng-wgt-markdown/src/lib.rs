@@ -96,6 +96,32 @@ pub fn default_context_menu(args: zng_wgt_menu::context::ContextMenuArgs) -> UiN
     ])
 }
 
+context_var! {
+    /// If top-level markdown blocks are only built when they are near the viewport.
+    ///
+    /// Is `false` by default.
+    pub static LAZY_BLOCKS_VAR: bool = false;
+}
+
+/// Enables incremental rendering of large markdown documents.
+///
+/// When enabled the markdown is parsed once, but each top-level block (paragraph, heading, list, table and so on)
+/// is only built into widgets when it is near the [`SCROLL`] viewport. Blocks use a placeholder with estimated height
+/// until they are inited, after init the block stays inited.
+///
+/// The blocks are built using the same `*_fn` properties and are placed in the [`panel_fn`] panel.
+///
+/// Note that this mode should only be enabled for large documents inside a scroll, as each block is wrapped in an extra widget.
+///
+/// Sets the [`LAZY_BLOCKS_VAR`].
+///
+/// [`SCROLL`]: zng_wgt_scroll::SCROLL
+/// [`panel_fn`]: fn@panel_fn
+#[property(CONTEXT, default(LAZY_BLOCKS_VAR), widget_impl(Markdown))]
+pub fn lazy_blocks(child: impl IntoUiNode, enabled: impl IntoVar<bool>) -> UiNode {
+    with_context_var(child, LAZY_BLOCKS_VAR, enabled)
+}
+
 /// Implements the markdown parsing and view generation, configured by contextual properties.
 pub fn markdown_node(md: impl IntoVar<Txt>) -> UiNode {
     let md = md.into_var();
@@ -118,6 +144,7 @@ pub fn markdown_node(md: impl IntoVar<Txt>) -> UiNode {
                 .sub_var(&TABLE_FN_VAR)
                 .sub_var(&TABLE_CELL_FN_VAR)
                 .sub_var(&PANEL_FN_VAR)
+                .sub_var(&LAZY_BLOCKS_VAR)
                 .sub_var(&IMAGE_RESOLVER_VAR)
                 .sub_var(&LINK_RESOLVER_VAR);
 
@@ -150,6 +177,7 @@ pub fn markdown_node(md: impl IntoVar<Txt>) -> UiNode {
                 || TABLE_FN_VAR.is_new()
                 || TABLE_CELL_FN_VAR.is_new()
                 || PANEL_FN_VAR.is_new()
+                || LAZY_BLOCKS_VAR.is_new()
                 || IMAGE_RESOLVER_VAR.is_new()
                 || LINK_RESOLVER_VAR.is_new()
             {
@@ -305,6 +333,125 @@ fn markdown_parser<'a>(md: &'a str, mut next_event: impl FnMut(pulldown_cmark::E
 }
 
 fn markdown_view_fn(md: &str) -> UiNode {
+    use view_fn::*;
+
+    if LAZY_BLOCKS_VAR.get() {
+        return markdown_lazy_view_fn(md);
+    }
+
+    let blocks = markdown_blocks_view(|next_event| markdown_parser(md, next_event));
+    PANEL_FN_VAR.get()(PanelFnArgs { items: blocks })
+}
+
+/// Parse markdown into top-level block events, each block is a lazy widget that only builds
+/// the block views when it is near the viewport.
+fn markdown_lazy_view_fn(md: &str) -> UiNode {
+    use pulldown_cmark::*;
+    use view_fn::*;
+
+    let mut blocks = ui_vec![];
+    let mut block = vec![];
+    let mut depth = 0usize;
+    let mut lines = 1usize;
+    markdown_parser(md, |event| {
+        match &event {
+            Event::Start(_) => depth += 1,
+            Event::End(_) => depth = depth.saturating_sub(1),
+            Event::Text(t) | Event::Code(t) => lines += t.matches('\n').count() + t.len() / 80,
+            _ => {}
+        }
+        block.push(event.into_static());
+        if depth == 0 {
+            blocks.push(lazy_block(mem::take(&mut block), mem::replace(&mut lines, 1)));
+        }
+    });
+    if !block.is_empty() {
+        blocks.push(lazy_block(block, lines));
+    }
+
+    PANEL_FN_VAR.get()(PanelFnArgs { items: blocks })
+}
+
+fn lazy_block(events: Vec<pulldown_cmark::Event<'static>>, estimated_lines: usize) -> UiNode {
+    use view_fn::*;
+
+    // anchors are resolved before the block is inited, so that links can scroll to the block
+    let anchors = lazy_block_anchors(&events);
+
+    let child = match_node(UiNode::nil(), move |c, op| match op {
+        UiNodeOp::Init => {
+            let mut blocks = markdown_blocks_view(|next_event| {
+                for e in events.iter().cloned() {
+                    next_event(e);
+                }
+            });
+            *c.node() = if blocks.len() == 1 {
+                blocks.pop().unwrap()
+            } else {
+                PANEL_FN_VAR.get()(PanelFnArgs { items: blocks })
+            };
+        }
+        UiNodeOp::Deinit => {
+            c.deinit();
+            *c.node() = UiNode::nil();
+        }
+        _ => {}
+    });
+
+    let estimated_height = (estimated_lines as f32 * 1.5).em();
+    zng_wgt_container::Container! {
+        zng_wgt_scroll::lazy = zng_wgt_scroll::LazyMode::once_vertical(wgt_fn!(|_| zng_wgt::Wgt! {
+            zng_wgt_size_offset::height = estimated_height.clone();
+        }));
+        lazy_anchors = anchors;
+        child;
+    }
+}
+
+/// Collect the anchors the block views will set.
+fn lazy_block_anchors(events: &[pulldown_cmark::Event]) -> Vec<Txt> {
+    use pulldown_cmark::*;
+
+    let mut anchors = vec![];
+    let mut heading_anchor_txt = None::<String>;
+    for event in events {
+        match event {
+            Event::Start(Tag::Heading { .. }) => heading_anchor_txt = Some(String::new()),
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some(txt) = heading_anchor_txt.take() {
+                    anchors.push(heading_anchor(&txt));
+                }
+            }
+            Event::Text(t) => {
+                if let Some(a) = &mut heading_anchor_txt {
+                    a.push_str(&html_escape::decode_html_entities(t.as_ref()));
+                }
+            }
+            Event::Start(Tag::FootnoteDefinition(label)) => {
+                anchors.push(formatx!("footnote-{}", html_escape::decode_html_entities(label.as_ref())));
+            }
+            Event::FootnoteReference(label) => {
+                anchors.push(formatx!("footnote-ref-{}", html_escape::decode_html_entities(label.as_ref())));
+            }
+            _ => {}
+        }
+    }
+    anchors
+}
+
+/// Set the anchors of a lazy block that may not be inited yet.
+#[property(CONTEXT)]
+fn lazy_anchors(child: impl IntoUiNode, anchors: impl IntoValue<Vec<Txt>>) -> UiNode {
+    let anchors = anchors.into();
+    match_node(child, move |_, op| {
+        if let UiNodeOp::Info { info } = op {
+            info.set_meta(*resolvers::LAZY_ANCHORS_ID, anchors.clone());
+        }
+    })
+}
+
+/// Build block views from the markdown events.
+fn markdown_blocks_view<'a>(parse: impl FnOnce(&mut dyn FnMut(pulldown_cmark::Event<'a>))) -> UiVec {
     use pulldown_cmark::*;
     use resolvers::*;
     use view_fn::*;
@@ -375,7 +522,7 @@ fn markdown_view_fn(md: &str) -> UiNode {
     let mut table_col = 0;
     let mut table_head = false;
 
-    markdown_parser(md, |event| match event {
+    parse(&mut |event| match event {
         Event::Start(tag) => match tag {
             Tag::Paragraph => txt_style = StyleBuilder::default(),
             Tag::Heading { .. } => {
@@ -731,5 +878,22 @@ fn markdown_view_fn(md: &str) -> UiNode {
         Event::SoftBreak | Event::HardBreak => unreachable!(),
     });
 
-    PANEL_FN_VAR.get()(PanelFnArgs { items: blocks.into() })
+    blocks.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lazy_block_anchors_resolved() {
+        let md = "# Hello &amp; World\n\nSee note[^a].\n\n[^a]: The note.";
+        let mut events = vec![];
+        markdown_parser(md, |e| events.push(e));
+        let anchors = lazy_block_anchors(&events);
+
+        assert!(anchors.contains(&heading_anchor("Hello & World")), "{anchors:?}");
+        assert!(anchors.contains(&Txt::from_static("footnote-ref-a")), "{anchors:?}");
+        assert!(anchors.contains(&Txt::from_static("footnote-a")), "{anchors:?}");
+    }
 }
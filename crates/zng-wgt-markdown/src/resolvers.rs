@@ -183,36 +183,7 @@ impl PartialEq for LinkResolver {
     }
 }
 
-event! {
-    /// Event raised by markdown links when clicked.
-    pub static LINK_EVENT: LinkArgs;
-}
-
-event_property! {
-    /// Markdown link click.
-    #[property(EVENT)]
-    pub fn on_link<on_pre_link>(child: impl IntoUiNode, handler: Handler<LinkArgs>) -> UiNode {
-        const PRE: bool;
-        EventNodeBuilder::new(LINK_EVENT).build::<PRE>(child, handler)
-    }
-}
-
-event_args! {
-    /// Arguments for the [`LINK_EVENT`].
-    pub struct LinkArgs {
-        /// Raw URL.
-        pub url: Txt,
-
-        /// Link widget.
-        pub link: InteractionPath,
-
-        ..
-
-        fn is_in_target(&self, id: WidgetId) -> bool {
-            self.link.contains(id)
-        }
-    }
-}
+pub use zng_wgt_text::{LINK_EVENT, LinkArgs, on_link, on_pre_link};
 
 /// Default markdown link action.
 ///
@@ -451,6 +422,7 @@ pub fn try_open_link(args: &LinkArgs) -> bool {
 
 static_id! {
     static ref ANCHOR_ID: StateId<Txt>;
+    pub(super) static ref LAZY_ANCHORS_ID: StateId<Vec<Txt>>;
     pub(super) static ref MARKDOWN_INFO_ID: StateId<()>;
 }
 
@@ -485,6 +457,10 @@ pub trait WidgetInfoExt {
     fn is_markdown(&self) -> bool;
 
     /// Find descendant tagged by the given anchor.
+    ///
+    /// If the anchor is inside a [`lazy_blocks`] block that is not inited yet the block placeholder is returned.
+    ///
+    /// [`lazy_blocks`]: fn@crate::lazy_blocks
     fn find_anchor(&self, anchor: &str) -> Option<WidgetInfo>;
 }
 impl WidgetInfoExt for WidgetInfo {
@@ -497,7 +473,16 @@ impl WidgetInfoExt for WidgetInfo {
     }
 
     fn find_anchor(&self, anchor: &str) -> Option<WidgetInfo> {
-        self.descendants().find(|d| d.anchor().map(|a| a == anchor).unwrap_or(false))
+        self.descendants()
+            .find(|d| d.anchor().map(|a| a == anchor).unwrap_or(false))
+            .or_else(|| {
+                self.descendants().find(|d| {
+                    d.meta()
+                        .get(*LAZY_ANCHORS_ID)
+                        .map(|a| a.iter().any(|a| a == anchor))
+                        .unwrap_or(false)
+                })
+            })
     }
 }
 
@@ -1,7 +1,7 @@
 //! Context menu widget and properties.
 
 use colors::BASE_COLOR_VAR;
-use zng_ext_input::gesture::CLICK_EVENT;
+use zng_ext_input::gesture::{CLICK_EVENT, ClickArgsSource};
 use zng_wgt::prelude::*;
 use zng_wgt_input::{focus::alt_focus_scope, gesture::mnemonic_scope};
 use zng_wgt_layer::{
@@ -17,7 +17,12 @@ use zng_wgt_style::{impl_named_style_fn, impl_style_fn, style_fn};
 /// using [`POPUP`] and is expected to close itself when the context action is finished or it
 /// loses focus.
 ///
+/// Touch long press also counts as a context click, in this case the menu opens at the touch point
+/// and [`ContextMenu!`] widgets use the [`TouchStyle!`] if no [`style_fn`] is set in the context.
+///
+/// [`TouchStyle!`]: struct@TouchStyle
 /// [`ContextMenu!`]: struct@ContextMenu
+/// [`style_fn`]: fn@style_fn
 /// [`POPUP`]: zng_wgt_layer::popup::POPUP
 #[property(EVENT)]
 pub fn context_menu(child: impl IntoUiNode, menu: impl IntoUiNode) -> UiNode {
@@ -77,10 +82,15 @@ fn context_menu_node(child: impl IntoUiNode, menu: impl IntoVar<WidgetFn<Context
                     if apply {
                         args.propagation.stop();
 
-                        let menu = menu.get()(ContextMenuArgs {
+                        let is_touch = matches!(&args.source, ClickArgsSource::Touch { .. });
+                        let mut menu = menu.get()(ContextMenuArgs {
                             anchor_id: WIDGET.id(),
                             disabled: disabled_only,
+                            is_touch,
                         });
+                        if is_touch && STYLE_FN_VAR.is_default() {
+                            menu = with_context_var(menu, STYLE_FN_VAR, style_fn!(|_| TouchStyle!()));
+                        }
                         let is_shortcut = args.is_from_keyboard();
                         pop_state = POPUP.open_config(
                             menu,
@@ -144,6 +154,14 @@ pub struct ContextMenuArgs {
     /// [`context_menu_fn`]: fn@context_menu_fn
     /// [`disabled_context_menu_fn`]: fn@disabled_context_menu_fn
     pub disabled: bool,
+
+    /// Is `true` if the menu was requested by a touch long press.
+    ///
+    /// The [`ContextMenu!`] widget uses the [`TouchStyle!`] by default in this case.
+    ///
+    /// [`ContextMenu!`]: struct@ContextMenu
+    /// [`TouchStyle!`]: struct@TouchStyle
+    pub is_touch: bool,
 }
 impl ContextMenuArgs {
     /// New args.
@@ -151,6 +169,7 @@ impl ContextMenuArgs {
         Self {
             anchor_id: anchor_id.into(),
             disabled,
+            is_touch: false,
         }
     }
 }
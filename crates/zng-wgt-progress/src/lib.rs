@@ -22,6 +22,7 @@ use zng_wgt::{
     prelude::{colors::ACCENT_COLOR_VAR, *},
     visibility,
 };
+use zng_wgt_access::{AccessRole, access_role};
 use zng_wgt_container::{self as container, Container};
 use zng_wgt_fill::background_color;
 use zng_wgt_size_offset::{height, width, x};
@@ -30,15 +31,85 @@ use zng_wgt_style::{Style, StyleMix, impl_named_style_fn, impl_style_fn};
 pub use zng_task::Progress;
 
 /// Progress indicator widget.
+///
+/// The accessibility value is set to the progress factor in the `0.0..=1.0` range when the progress is not indeterminate.
 #[widget($crate::ProgressView { ($progress:expr) => { progress = $progress; }; })]
 pub struct ProgressView(StyleMix<WidgetBase>);
 impl ProgressView {
     fn widget_intrinsic(&mut self) {
         self.style_intrinsic(STYLE_FN_VAR, property_id!(self::style_fn));
+
+        widget_set! {
+            self;
+            access_role = AccessRole::ProgressBar;
+        }
+
+        self.widget_builder()
+            .push_build_action(|wgt| wgt.push_intrinsic(NestGroup::EVENT, "progress-access", access_node));
     }
 }
 impl_style_fn!(ProgressView, DefaultStyle);
 
+/// Progress bar widget.
+///
+/// This is a [`ProgressView!`] that always uses the [`DefaultStyle!`], the progress can be set to a [`Factor`]
+/// for determinate progress, the default progress is indeterminate.
+///
+/// [`ProgressView!`]: struct@ProgressView
+/// [`DefaultStyle!`]: struct@DefaultStyle
+#[widget($crate::ProgressBar { ($progress:expr) => { progress = $progress; }; })]
+pub struct ProgressBar(ProgressView);
+impl ProgressBar {
+    fn widget_intrinsic(&mut self) {
+        widget_set! {
+            self;
+            style_fn = DefaultStyle!();
+        }
+    }
+}
+
+/// Indeterminate progress spinner widget.
+///
+/// This is a [`ProgressView!`] that uses the [`CircularStyle!`], the default progress is indeterminate.
+///
+/// [`ProgressView!`]: struct@ProgressView
+/// [`CircularStyle!`]: struct@CircularStyle
+#[widget($crate::Spinner { ($progress:expr) => { progress = $progress; }; })]
+pub struct Spinner(ProgressView);
+impl Spinner {
+    fn widget_intrinsic(&mut self) {
+        widget_set! {
+            self;
+            style_fn = CircularStyle!();
+        }
+    }
+}
+
+fn access_node(child: impl IntoUiNode) -> UiNode {
+    match_node(child, |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_var_info(&PROGRESS_VAR);
+        }
+        UiNodeOp::Info { info } => {
+            c.info(info);
+            if let Some(mut a) = info.access() {
+                PROGRESS_VAR.with(|p| {
+                    if !p.is_indeterminate() {
+                        a.set_value_min(0.0);
+                        a.set_value_max(1.0);
+                        a.set_value(p.fct().0 as f64);
+                    }
+                    let msg = p.msg();
+                    if !msg.is_empty() {
+                        a.set_value_text(msg);
+                    }
+                });
+            }
+        }
+        _ => {}
+    })
+}
+
 context_var! {
     /// The progress status value in a [`ProgressView`](struct@ProgressView)
     pub static PROGRESS_VAR: Progress = Progress::indeterminate();
@@ -146,6 +217,11 @@ pub fn is_indeterminate(child: impl IntoUiNode, state: impl IntoVar<bool>) -> Ui
 }
 
 /// Progress view default style (progress bar with message text).
+///
+/// The indeterminate progress animation is replaced with a static full bar with half opacity
+/// when [`VARS.animations_enabled`] is `false`.
+///
+/// [`VARS.animations_enabled`]: zng_var::VARS::animations_enabled
 #[widget($crate::DefaultStyle)]
 pub struct DefaultStyle(Style);
 impl DefaultStyle {
@@ -172,7 +248,7 @@ impl DefaultStyle {
                         on_progress = {
                             let mut handle = VarHandle::dummy();
                             hn!(ind_x, |p| {
-                                if p.is_indeterminate() {
+                                if p.is_indeterminate() && VARS.animations_enabled().get() {
                                     // only animates when actually indeterminate
                                     if handle.is_dummy() {
                                         handle =
@@ -187,6 +263,11 @@ impl DefaultStyle {
                             width = ind_width;
                             x = ind_x;
                         }
+                        when #{PROGRESS_VAR}.is_indeterminate() && !*#{VARS.animations_enabled()} {
+                            width = 100.pct();
+                            x = 0;
+                            background_color = colors::ACCENT_COLOR_VAR.rgba().map(|c| c.with_alpha(50.pct()));
+                        }
                     }
                 };
             };
@@ -216,6 +297,11 @@ impl SimpleBarStyle {
 }
 
 /// Circular progress indicator style.
+///
+/// The indeterminate progress animation is replaced with a static three quarters arc when
+/// [`VARS.animations_enabled`] is `false`.
+///
+/// [`VARS.animations_enabled`]: zng_var::VARS::animations_enabled
 #[widget($crate::CircularStyle)]
 pub struct CircularStyle(Style);
 impl_named_style_fn!(circular, CircularStyle);
@@ -234,7 +320,11 @@ impl CircularStyle {
                     on_progress = {
                         let mut ind_handle = AnimationHandle::dummy();
                         hn!(|args| {
-                            if args.is_indeterminate() {
+                            if args.is_indeterminate() && !VARS.animations_enabled().get() {
+                                ind_handle = AnimationHandle::dummy();
+                                start.set(0.turn());
+                                end.set(0.75.turn());
+                            } else if args.is_indeterminate() {
                                 if ind_handle.is_stopped() {
                                     ind_handle = VARS.animate(clmv!(start, end, |a| {
                                         if a.count() == 0 {
@@ -57,3 +57,6 @@ ZOOM_RESET_CMD =
 
 ZOOM_TO_FIT_CMD =
     .name = Ajustar Zoom
+
+ZOOM_TO_RECT_CMD =
+    .name = Zoom na Seleção
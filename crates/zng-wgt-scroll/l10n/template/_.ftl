@@ -59,3 +59,6 @@ ZOOM_RESET_CMD =
 
 ZOOM_TO_FIT_CMD =
     .name = Zoom to Fit
+
+ZOOM_TO_RECT_CMD =
+    .name = Zoom to Selection
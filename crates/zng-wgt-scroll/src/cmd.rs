@@ -244,6 +244,26 @@ command! {
         icon: wgt_fn!(|_| ICONS.get(["zoom-to-fit", "fit-screen"])),
     };
 
+    /// Represents the **zoom to rect** action.
+    ///
+    /// The zoom scale and scroll offsets animate so that a rectangle in the content fills the viewport,
+    /// the scale is clamped by [`min_zoom`] and [`max_zoom`].
+    ///
+    /// # Parameter
+    ///
+    /// This command requires a parameter to work, it can be a [`ZoomToRectRequest`] instance, or a [`Rect`] or [`PxRect`]
+    /// resolved in the scrollable space.
+    ///
+    /// [`min_zoom`]: fn@crate::min_zoom
+    /// [`max_zoom`]: fn@crate::max_zoom
+    /// [`Rect`]: zng_wgt::prelude::Rect
+    /// [`PxRect`]: zng_wgt::prelude::PxRect
+    pub static ZOOM_TO_RECT_CMD {
+        l10n!: true,
+        name: "Zoom to Selection",
+        shortcut_filter: ShortcutFilter::FOCUSED | ShortcutFilter::CMD_ENABLED,
+    };
+
     /// Represents the **reset zoom** action.
     ///
     /// The content is scaled back to 100%, without adjusting the scroll.
@@ -298,6 +318,49 @@ impl ZoomToFitRequest {
     }
 }
 
+/// Parameters for the [`ZOOM_TO_RECT_CMD`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ZoomToRectRequest {
+    /// Rectangle in the content space that will fill the viewport.
+    pub rect: Rect,
+    /// Apply the change immediately, no easing/smooth animation.
+    pub skip_animation: bool,
+}
+impl ZoomToRectRequest {
+    /// New with rect.
+    pub fn new(rect: impl Into<Rect>) -> Self {
+        Self {
+            rect: rect.into(),
+            skip_animation: false,
+        }
+    }
+
+    /// Pack the request into a command parameter.
+    pub fn to_param(self) -> CommandParam {
+        CommandParam::new(self)
+    }
+
+    /// Extract a clone of the request from the command parameter if it is of a compatible type.
+    pub fn from_param(p: &CommandParam) -> Option<Self> {
+        if let Some(req) = p.downcast_ref::<Self>() {
+            Some(req.clone())
+        } else if let Some(rect) = p.downcast_ref::<Rect>() {
+            Some(Self::new(rect.clone()))
+        } else {
+            p.downcast_ref::<PxRect>().map(|&rect| Self::new(rect))
+        }
+    }
+}
+impl_from_and_into_var! {
+    fn from(rect: Rect) -> ZoomToRectRequest {
+        ZoomToRectRequest::new(rect)
+    }
+    fn from(rect: PxRect) -> ZoomToRectRequest {
+        ZoomToRectRequest::new(rect)
+    }
+}
+
 /// Parameters for the scroll and page commands.
 #[derive(Debug, Clone, PartialEq)]
 #[non_exhaustive]
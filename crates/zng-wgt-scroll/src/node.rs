@@ -1,7 +1,7 @@
 //! UI nodes used for building the scroll widget.
 //!
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use parking_lot::Mutex;
 use zng_app::{
@@ -13,6 +13,7 @@ use zng_ext_input::{
     focus::{FOCUS, FOCUS_CHANGED_EVENT},
     keyboard::{KEY_INPUT_EVENT, Key, KeyState},
     mouse::{ButtonState, MOUSE_INPUT_EVENT, MOUSE_WHEEL_EVENT, MouseButton, MouseScrollDelta},
+    pointer_capture::POINTER_CAPTURE,
     touch::{TOUCH_TRANSFORM_EVENT, TouchPhase},
 };
 use zng_wgt::prelude::{
@@ -52,6 +53,9 @@ pub fn viewport(child: impl IntoUiNode, mode: impl IntoVar<ScrollMode>, child_al
                 .sub_var_layout(&SCROLL_VERTICAL_OFFSET_VAR)
                 .sub_var_layout(&SCROLL_HORIZONTAL_OFFSET_VAR)
                 .sub_var_layout(&SCROLL_SCALE_VAR)
+                .sub_var_layout(&OVERSCROLL_MODE_VAR)
+                .sub_var_layout(&OVERSCROLL_VERTICAL_OFFSET_VAR)
+                .sub_var_layout(&OVERSCROLL_HORIZONTAL_OFFSET_VAR)
                 .sub_var_layout(&child_align);
         }
 
@@ -201,6 +205,13 @@ pub fn viewport(child: impl IntoUiNode, mode: impl IntoVar<ScrollMode>, child_al
                 ct_offset.x = align_offset.x;
             }
 
+            if OVERSCROLL_MODE_VAR.get() == OverscrollMode::Bounce {
+                // rubber-band, content moves at half speed past the edge
+                const RESISTANCE: f32 = 0.5;
+                ct_offset.y -= vp_size.height * OVERSCROLL_VERTICAL_OFFSET_VAR.get() * RESISTANCE.fct();
+                ct_offset.x -= vp_size.width * OVERSCROLL_HORIZONTAL_OFFSET_VAR.get() * RESISTANCE.fct();
+            }
+
             if ct_offset != content_offset {
                 content_offset = ct_offset;
 
@@ -621,15 +632,17 @@ pub fn scroll_to_edge_commands_node(child: impl IntoUiNode) -> UiNode {
 }
 
 /// Create a node that implements [`ZOOM_IN_CMD`], [`ZOOM_OUT_CMD`], [`ZOOM_TO_FIT_CMD`],
-/// and [`ZOOM_RESET_CMD`] scoped on the widget.
+/// [`ZOOM_TO_RECT_CMD`] and [`ZOOM_RESET_CMD`] scoped on the widget.
 pub fn zoom_commands_node(child: impl IntoUiNode) -> UiNode {
     let mut zoom_in = CommandHandle::dummy();
     let mut zoom_out = CommandHandle::dummy();
     let mut zoom_to_fit = CommandHandle::dummy();
+    let mut zoom_to_rect = CommandHandle::dummy();
     let mut zoom_reset = CommandHandle::dummy();
 
     let mut scale_delta = 0.fct();
     let mut origin = Point::default();
+    let mut to_rect = None::<ZoomToRectRequest>;
 
     fn fit_scale() -> Factor {
         let scroll = WIDGET.info().scroll_info().unwrap();
@@ -649,6 +662,7 @@ pub fn zoom_commands_node(child: impl IntoUiNode) -> UiNode {
             zoom_in = ZOOM_IN_CMD.scoped(scope).subscribe(SCROLL.can_zoom_in());
             zoom_out = ZOOM_OUT_CMD.scoped(scope).subscribe(SCROLL.can_zoom_out());
             zoom_to_fit = ZOOM_TO_FIT_CMD.scoped(scope).subscribe(true);
+            zoom_to_rect = ZOOM_TO_RECT_CMD.scoped(scope).subscribe(true);
             zoom_reset = ZOOM_RESET_CMD.scoped(scope).subscribe(true);
         }
         UiNodeOp::Deinit => {
@@ -657,7 +671,9 @@ pub fn zoom_commands_node(child: impl IntoUiNode) -> UiNode {
             zoom_in = CommandHandle::dummy();
             zoom_out = CommandHandle::dummy();
             zoom_to_fit = CommandHandle::dummy();
+            zoom_to_rect = CommandHandle::dummy();
             zoom_reset = CommandHandle::dummy();
+            to_rect = None;
         }
         UiNodeOp::Update { updates } => {
             child.update(updates);
@@ -699,6 +715,18 @@ pub fn zoom_commands_node(child: impl IntoUiNode) -> UiNode {
                     }
                 });
             }
+            if zoom_to_rect.enabled().get() {
+                ZOOM_TO_RECT_CMD.scoped(scope).each_update(true, false, |args| {
+                    if let Some(p) = &args.param
+                        && let Some(req) = ZoomToRectRequest::from_param(p)
+                    {
+                        args.propagation.stop();
+
+                        to_rect = Some(req);
+                        WIDGET.layout();
+                    }
+                });
+            }
             if zoom_reset.enabled().get() {
                 ZOOM_RESET_CMD.scoped(scope).each_update(true, false, |args| {
                     args.propagation.stop();
@@ -734,6 +762,23 @@ pub fn zoom_commands_node(child: impl IntoUiNode) -> UiNode {
                 SCROLL.zoom(|f| f + scale_delta, center_in_viewport);
                 scale_delta = 0.fct();
             }
+
+            if let Some(req) = to_rect.take() {
+                let scroll_info = WIDGET.info().scroll_info().unwrap();
+                let viewport = scroll_info.viewport_size().to_f32();
+                let content = scroll_info.content();
+                let rect = LAYOUT.with_constraints(PxConstraints2d::new_exact_size(content.size), || req.rect.layout());
+                let rect_size = rect.size.max(PxSize::splat(Px(1))).to_f32();
+
+                let fit = (viewport.width / rect_size.width).min(viewport.height / rect_size.height).fct();
+                let scale = (SCROLL.rendered_zoom_scale() * fit).clamp(SCROLL.actual_min_zoom(), MAX_ZOOM_VAR.get());
+
+                // SCROLL_TO_CMD animates the scale and offsets together
+                let mut request = ScrollToRequest::new(req.rect, ScrollToMode::center());
+                request.zoom = Some(scale);
+                request.skip_animation = req.skip_animation;
+                SCROLL_TO_CMD.scoped(WIDGET.id()).notify_param(request);
+            }
         }
         _ => {}
     })
@@ -1051,6 +1096,153 @@ pub fn scroll_to_node(child: impl IntoUiNode) -> UiNode {
         _ => {}
     })
 }
+/// Create a node that snaps the scroll offset to the content children after scrolling stops.
+///
+/// See [`v_scroll_snap`] for more details.
+///
+/// [`v_scroll_snap`]: fn@crate::v_scroll_snap
+pub fn scroll_snap_node(child: impl IntoUiNode) -> UiNode {
+    // time without offset changes that is considered the end of a scroll gesture
+    const IDLE: Duration = Duration::from_millis(150);
+
+    let mut idle_timer = None::<DeadlineVar>;
+    let mut snap = false;
+    match_node(child, move |child, op| match op {
+        UiNodeOp::Init => {
+            WIDGET
+                .sub_var(&VERTICAL_SCROLL_SNAP_VAR)
+                .sub_var(&HORIZONTAL_SCROLL_SNAP_VAR)
+                .sub_var(&SCROLL.vertical_offset())
+                .sub_var(&SCROLL.horizontal_offset())
+                .sub_var(&POINTER_CAPTURE.current_capture());
+        }
+        UiNodeOp::Deinit => {
+            idle_timer = None;
+            snap = false;
+        }
+        UiNodeOp::Update { updates } => {
+            child.update(updates);
+
+            if !VERTICAL_SCROLL_SNAP_VAR.with(ScrollSnapMode::is_enabled) && !HORIZONTAL_SCROLL_SNAP_VAR.with(ScrollSnapMode::is_enabled) {
+                idle_timer = None;
+                return;
+            }
+
+            if SCROLL.vertical_offset().is_new()
+                || SCROLL.horizontal_offset().is_new()
+                || POINTER_CAPTURE.current_capture().is_new()
+                || VERTICAL_SCROLL_SNAP_VAR.is_new()
+                || HORIZONTAL_SCROLL_SNAP_VAR.is_new()
+            {
+                let t = TIMERS.deadline(IDLE);
+                t.subscribe(UpdateOp::Update, WIDGET.id()).perm();
+                idle_timer = Some(t);
+            } else if let Some(t) = &idle_timer
+                && t.get().has_elapsed()
+            {
+                idle_timer = None;
+
+                let id = WIDGET.id();
+                let is_pressed = POINTER_CAPTURE
+                    .current_capture()
+                    .with(|c| c.as_ref().map(|c| c.target.contains(id)).unwrap_or(false));
+                let is_animating = SCROLL.vertical_offset().is_animating() || SCROLL.horizontal_offset().is_animating();
+                if !is_pressed && !is_animating {
+                    snap = true;
+                    WIDGET.layout();
+                }
+            }
+        }
+        UiNodeOp::Layout { wl, final_size } => {
+            *final_size = child.layout(wl);
+
+            if std::mem::take(&mut snap) {
+                scroll_snap(&WIDGET.info());
+            }
+        }
+        _ => {}
+    })
+}
+fn scroll_snap(us: &WidgetInfo) {
+    let scroll_info = match us.scroll_info() {
+        Some(s) => s,
+        None => return,
+    };
+
+    // the first descendant with multiple children defines the snap points
+    let mut panel = match us.children().next() {
+        Some(c) => c,
+        None => return,
+    };
+    loop {
+        let mut children = panel.children();
+        match (children.next(), children.next()) {
+            (Some(c), None) => panel = c,
+            _ => break,
+        }
+    }
+
+    let content = scroll_info.content();
+    let viewport_size = scroll_info.viewport_size();
+    let max_scroll = content.size - viewport_size;
+    let to_viewport = scroll_info.viewport_transform().inverse();
+
+    let mut points = vec![PxPoint::zero(), max_scroll.to_vector().to_point()];
+    for child in panel.children() {
+        let bounds = child.inner_bounds();
+        let bounds = to_viewport
+            .and_then(|t| t.outer_transformed(bounds.to_box2d()))
+            .map(|b| b.to_rect())
+            .unwrap_or(bounds);
+        // bounds is now in the content space
+        points.push(bounds.origin - content.origin.to_vector());
+    }
+
+    let nearest = |mode: ScrollSnapMode, current: Px, max: Px, viewport: Px, point: fn(&PxPoint) -> Px| -> Option<Px> {
+        if max <= Px(0) {
+            return None;
+        }
+        let target = points
+            .iter()
+            .map(point)
+            .filter(|p| *p >= Px(0) && *p <= max)
+            .min_by_key(|p| (*p - current).abs())?;
+        match mode {
+            ScrollSnapMode::None => None,
+            ScrollSnapMode::Mandatory => Some(target),
+            ScrollSnapMode::Proximity(l) => {
+                let l = LAYOUT.with_constraints(PxConstraints2d::new_fill_size(PxSize::splat(viewport)), || l.layout_x());
+                if (target - current).abs() <= l { Some(target) } else { None }
+            }
+        }
+    };
+
+    let current_v = max_scroll.height * SCROLL.vertical_offset().get();
+    if let Some(y) = nearest(
+        VERTICAL_SCROLL_SNAP_VAR.get(),
+        current_v,
+        max_scroll.height,
+        viewport_size.height,
+        |p| p.y,
+    ) && y != current_v
+    {
+        let offset = y.0 as f32 / max_scroll.height.0 as f32;
+        SCROLL.chase_vertical(|_| offset.fct());
+    }
+
+    let current_h = max_scroll.width * SCROLL.horizontal_offset().get();
+    if let Some(x) = nearest(
+        HORIZONTAL_SCROLL_SNAP_VAR.get(),
+        current_h,
+        max_scroll.width,
+        viewport_size.width,
+        |p| p.x,
+    ) && x != current_h
+    {
+        let offset = x.0 as f32 / max_scroll.width.0 as f32;
+        SCROLL.chase_horizontal(|_| offset.fct());
+    }
+}
 fn inflate_margin(mut r: PxRect, margin: PxSideOffsets) -> PxRect {
     r.origin.x -= margin.left;
     r.origin.y -= margin.top;
@@ -1309,13 +1501,17 @@ pub fn overscroll_node(child: impl IntoUiNode) -> UiNode {
         UiNodeOp::Init => {
             WIDGET
                 .sub_var_layout(&OVERSCROLL_VERTICAL_OFFSET_VAR)
-                .sub_var_layout(&OVERSCROLL_HORIZONTAL_OFFSET_VAR);
+                .sub_var_layout(&OVERSCROLL_HORIZONTAL_OFFSET_VAR)
+                .sub_var_layout(&OVERSCROLL_MODE_VAR);
         }
         UiNodeOp::Layout { final_size, wl } => {
             *final_size = c.layout(wl);
 
+            // only the glow mode shows the indicator
+            let glow = OVERSCROLL_MODE_VAR.get() == OverscrollMode::Glow;
+
             let mut new_v_rect = PxRect::zero();
-            let v = OVERSCROLL_VERTICAL_OFFSET_VAR.get();
+            let v = if glow { OVERSCROLL_VERTICAL_OFFSET_VAR.get() } else { 0.fct() };
             if v < 0.fct() {
                 // overscroll top
                 new_v_rect.size = *final_size;
@@ -1330,7 +1526,7 @@ pub fn overscroll_node(child: impl IntoUiNode) -> UiNode {
             }
 
             let mut new_h_rect = PxRect::zero();
-            let h = OVERSCROLL_HORIZONTAL_OFFSET_VAR.get();
+            let h = if glow { OVERSCROLL_HORIZONTAL_OFFSET_VAR.get() } else { 0.fct() };
             if h < 0.fct() {
                 // overscroll left
                 new_h_rect.size = *final_size;
@@ -1695,6 +1891,65 @@ fn auto_scroller_node(child: impl IntoUiNode, closed: Arc<Mutex<DInstant>>) -> U
     })
 }
 
+/// Renders a circle that slides down from the top with the pull, and a center point that grows to fill the circle
+/// when the pull reaches the threshold. The full circle stays visible while refreshing.
+///
+/// See [`pull_refresh_indicator`] for more details.
+///
+/// [`pull_refresh_indicator`]: fn@crate::pull_refresh_indicator
+pub fn default_pull_refresh_indicator(args: PullRefreshArgs) -> UiNode {
+    let mut area = PxSize::zero();
+    match_node_leaf(move |op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_var_render(&args.pull).sub_var_render(&args.refreshing);
+        }
+        UiNodeOp::Measure { desired_size, .. } => {
+            *desired_size = LAYOUT.constraints().fill_size();
+        }
+        UiNodeOp::Layout { final_size, .. } => {
+            *final_size = LAYOUT.constraints().fill_size();
+            area = *final_size;
+        }
+        UiNodeOp::Render { frame } => {
+            let pull = if args.refreshing.get() { 1.0 } else { args.pull.get().0.min(1.0) };
+            if pull <= 0.0 {
+                return;
+            }
+
+            let size = PxSize::splat(Dip::new(32).to_px(frame.scale_factor()));
+            let travel = Dip::new(48).to_px(frame.scale_factor());
+            let origin = PxPoint::new((area.width - size.width) / Px(2), travel * pull.fct() - size.height);
+            let corners = PxCornerRadius::new_all(size);
+
+            // white circle
+            frame.push_clip_rounded_rect(PxRect::new(origin, size), corners, false, false, |frame| {
+                frame.push_color(PxRect::new(origin, size), colors::WHITE.with_alpha(90.pct()).into());
+            });
+            // black border
+            let widths = Dip::new(1).to_px(frame.scale_factor());
+            frame.push_border(
+                PxRect::new(origin, size),
+                PxSideOffsets::new_all_same(widths),
+                colors::BLACK.with_alpha(80.pct()).into(),
+                corners,
+            );
+            // black point middle, grows with the pull
+            let pt_size = size * (0.6 * pull).fct();
+            let pt_origin = origin + (size / Px(2) - pt_size / Px(2)).to_vector();
+            frame.push_clip_rounded_rect(
+                PxRect::new(pt_origin, pt_size),
+                PxCornerRadius::new_all(pt_size),
+                false,
+                false,
+                |frame| {
+                    frame.push_color(PxRect::new(pt_origin, pt_size), colors::BLACK.into());
+                },
+            );
+        }
+        _ => {}
+    })
+}
+
 /// Renders a white circle with arrows that indicate what directions can be scrolled.
 ///
 /// This is the default [`auto_scroll_indicator`].
@@ -1,4 +1,5 @@
 use super::{cmd::ScrollToMode, types::*, *};
+use zng_app::widget::node::Z_INDEX;
 use zng_ext_input::{
     mouse::{MOUSE_INPUT_EVENT, MOUSE_MOVE_EVENT},
     pointer_capture::POINTER_CAPTURE,
@@ -69,6 +70,12 @@ context_var! {
     /// Smooth scrolling config for an scroll widget.
     pub static SMOOTH_SCROLLING_VAR: SmoothScrolling = SmoothScrolling::default();
 
+    /// Vertical scroll snap mode.
+    pub static VERTICAL_SCROLL_SNAP_VAR: ScrollSnapMode = ScrollSnapMode::None;
+
+    /// Horizontal scroll snap mode.
+    pub static HORIZONTAL_SCROLL_SNAP_VAR: ScrollSnapMode = ScrollSnapMode::None;
+
     /// If a scroll widget defines its viewport size as the [`LayoutMetrics::viewport`] for the scroll content.
     ///
     /// This is `true` by default.
@@ -99,6 +106,9 @@ context_var! {
     /// Color of the overscroll indicator.
     pub static OVERSCROLL_COLOR_VAR: Rgba = colors::GRAY.with_alpha(50.pct());
 
+    /// Overscroll feedback mode.
+    pub static OVERSCROLL_MODE_VAR: OverscrollMode = OverscrollMode::Glow;
+
     /// Minimum scale allowed when [`ScrollMode::ZOOM`] is enabled.
     ///
     /// `< 0.01.pct()` means the minimum required to fit the content in the viewport.
@@ -139,6 +149,20 @@ context_var! {
     ///
     /// [`node::default_auto_scroll_indicator`]: crate::node::default_auto_scroll_indicator
     pub static AUTO_SCROLL_INDICATOR_VAR: WidgetFn<AutoScrollArgs> = wgt_fn!(|_| { crate::node::default_auto_scroll_indicator() });
+
+    /// Vertical overscroll at the top that triggers [`on_pull_refresh`] on release, relative to the viewport height.
+    ///
+    /// Is `15.pct()` by default.
+    ///
+    /// [`on_pull_refresh`]: fn@on_pull_refresh
+    pub static PULL_REFRESH_THRESHOLD_VAR: Factor = 15.pct();
+
+    /// Pull-to-refresh indicator node.
+    ///
+    /// Is [`node::default_pull_refresh_indicator`] by default.
+    ///
+    /// [`node::default_pull_refresh_indicator`]: crate::node::default_pull_refresh_indicator
+    pub static PULL_REFRESH_INDICATOR_VAR: WidgetFn<PullRefreshArgs> = wgt_fn!(|a| { crate::node::default_pull_refresh_indicator(a) });
 }
 
 fn default_scrollbar() -> WidgetFn<ScrollBarArgs> {
@@ -343,6 +367,52 @@ pub fn smooth_scrolling(child: impl IntoUiNode, config: impl IntoVar<SmoothScrol
     with_context_var(child, SMOOTH_SCROLLING_VAR, config)
 }
 
+/// Vertical scroll snap mode.
+///
+/// When enabled the vertical offset animates to the nearest snap point after scrolling stops, that is, after the offset
+/// has not changed for 150ms and the scroll is not pressed. The snap points are the top edges of the content children,
+/// the content start and the content end. The content children are the children of the first
+/// descendant of the scroll content that has more than one child, for example, the items of a `Stack!` content.
+///
+/// The animation uses the [`smooth_scrolling`] config.
+///
+/// This property sets the [`VERTICAL_SCROLL_SNAP_VAR`].
+///
+/// [`smooth_scrolling`]: fn@smooth_scrolling
+#[property(CONTEXT, default(VERTICAL_SCROLL_SNAP_VAR), widget_impl(Scroll))]
+pub fn v_scroll_snap(child: impl IntoUiNode, mode: impl IntoVar<ScrollSnapMode>) -> UiNode {
+    with_context_var(child, VERTICAL_SCROLL_SNAP_VAR, mode)
+}
+
+/// Horizontal scroll snap mode.
+///
+/// When enabled the horizontal offset animates to the nearest snap point after scrolling stops. The snap points are
+/// the left edges of the content children and the content end. See [`v_scroll_snap`] for more details.
+///
+/// This property sets the [`HORIZONTAL_SCROLL_SNAP_VAR`].
+///
+/// [`v_scroll_snap`]: fn@v_scroll_snap
+#[property(CONTEXT, default(HORIZONTAL_SCROLL_SNAP_VAR), widget_impl(Scroll))]
+pub fn h_scroll_snap(child: impl IntoUiNode, mode: impl IntoVar<ScrollSnapMode>) -> UiNode {
+    with_context_var(child, HORIZONTAL_SCROLL_SNAP_VAR, mode)
+}
+
+/// Horizontal and vertical scroll snap modes.
+///
+/// The snap points are the edges of the children of the first descendant of the scroll content that has more than one
+/// child, so a `Stack!` content or a `Container!` that wraps a `Stack!` snaps to the stack items. Snapping happens after
+/// the offset has not changed for 150ms and the scroll is not pressed.
+///
+/// This property sets the [`h_scroll_snap`] and [`v_scroll_snap`].
+///
+/// [`h_scroll_snap`]: fn@h_scroll_snap
+/// [`v_scroll_snap`]: fn@v_scroll_snap
+#[property(CONTEXT, default(HORIZONTAL_SCROLL_SNAP_VAR, VERTICAL_SCROLL_SNAP_VAR), widget_impl(Scroll))]
+pub fn scroll_snap(child: impl IntoUiNode, horizontal: impl IntoVar<ScrollSnapMode>, vertical: impl IntoVar<ScrollSnapMode>) -> UiNode {
+    let child = h_scroll_snap(child, horizontal);
+    v_scroll_snap(child, vertical)
+}
+
 /// Scroll-to mode used by scroll widgets when scrolling to make the focused child visible.
 ///
 /// Default is minimal 0dip on all sides, set to `None` to disable.
@@ -402,6 +472,20 @@ pub fn overscroll_color(child: impl IntoUiNode, color: impl IntoVar<Rgba>) -> Ui
     with_context_var(child, OVERSCROLL_COLOR_VAR, color)
 }
 
+/// Overscroll feedback mode.
+///
+/// Overscroll happens when touch scroll tries to scroll past an edge in a dimension that can scroll. By default
+/// an edge glow in the [`overscroll_color`] is shown, the [`OverscrollMode::Bounce`] mode translates the content
+/// past the edge during the gesture and springs it back on release.
+///
+/// This property sets the [`OVERSCROLL_MODE_VAR`].
+///
+/// [`overscroll_color`]: fn@overscroll_color
+#[property(CONTEXT, default(OVERSCROLL_MODE_VAR), widget_impl(Scroll))]
+pub fn overscroll_mode(child: impl IntoUiNode, mode: impl IntoVar<OverscrollMode>) -> UiNode {
+    with_context_var(child, OVERSCROLL_MODE_VAR, mode)
+}
+
 /// Minimum scale allowed when [`ScrollMode::ZOOM`] is enabled.
 ///
 /// The actual minimum scale possible is `0.01.pct()`. A value under `0.01.pct()` means
@@ -491,6 +575,117 @@ pub fn auto_scroll_indicator(child: impl IntoUiNode, indicator: impl IntoVar<Wid
     with_context_var(child, AUTO_SCROLL_INDICATOR_VAR, indicator)
 }
 
+/// Pull-to-refresh handler.
+///
+/// When the content is pulled down past the top edge by more than the [`pull_refresh_threshold`] and released the `handler`
+/// is called and the [`pull_refresh_indicator`] shows a refreshing state until [`PullRefreshArgs::complete`] is called. The pull
+/// is detected from the vertical overscroll, so touch pan and [`mouse_pan`] can trigger it, the [`overscroll_mode`] must not be `None`.
+///
+/// [`pull_refresh_threshold`]: fn@pull_refresh_threshold
+/// [`pull_refresh_indicator`]: fn@pull_refresh_indicator
+/// [`mouse_pan`]: fn@mouse_pan
+/// [`overscroll_mode`]: fn@overscroll_mode
+#[property(EVENT, widget_impl(Scroll))]
+pub fn on_pull_refresh(child: impl IntoUiNode, handler: Handler<PullRefreshArgs>) -> UiNode {
+    let mut handler = handler.into_wgt_runner();
+    let pull = var(0.fct());
+    let refreshing = var(false);
+    let args = PullRefreshArgs {
+        pull: pull.read_only(),
+        refreshing: refreshing.clone(),
+    };
+    let mut gesture = PullRefreshGesture::default();
+
+    let child = zng_wgt_container::child_over(child, PULL_REFRESH_INDICATOR_VAR.present_data(args.clone()));
+    match_node(child, move |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET
+                .sub_var(&SCROLL.vertical_overscroll())
+                .sub_var(&POINTER_CAPTURE.current_capture())
+                .sub_var(&PULL_REFRESH_THRESHOLD_VAR);
+        }
+        UiNodeOp::Deinit => {
+            handler.deinit();
+            gesture = PullRefreshGesture::default();
+            pull.set(0.fct());
+            refreshing.set(false);
+        }
+        UiNodeOp::Update { updates } => {
+            c.update(updates);
+            handler.update();
+
+            if refreshing.get() {
+                gesture = PullRefreshGesture::default();
+                pull.set(0.fct());
+                return;
+            }
+
+            let id = WIDGET.id();
+            let is_pressed = POINTER_CAPTURE
+                .current_capture()
+                .with(|c| c.as_ref().map(|c| c.target.contains(id)).unwrap_or(false));
+            let (p, refresh) = gesture.update(SCROLL.vertical_overscroll().get(), PULL_REFRESH_THRESHOLD_VAR.get(), is_pressed);
+            pull.set(p);
+            if refresh {
+                refreshing.set(true);
+                handler.event(&args);
+            }
+        }
+        _ => {}
+    })
+}
+/// Pull-to-refresh gesture state.
+#[derive(Default)]
+struct PullRefreshGesture {
+    armed: bool,
+}
+impl PullRefreshGesture {
+    /// Returns the pull progress and if the refresh must start.
+    fn update(&mut self, overscroll: Factor, threshold: Factor, is_pressed: bool) -> (Factor, bool) {
+        let p = if overscroll < 0.fct() && threshold > 0.fct() {
+            -overscroll / threshold
+        } else {
+            0.fct()
+        };
+        if p >= 1.fct() {
+            self.armed = true;
+        }
+
+        if self.armed && !is_pressed {
+            self.armed = false;
+            (0.fct(), true)
+        } else {
+            (p, false)
+        }
+    }
+}
+
+/// Vertical overscroll at the top that triggers [`on_pull_refresh`] on release, relative to the viewport height.
+///
+/// Is `15.pct()` by default.
+///
+/// This property sets the [`PULL_REFRESH_THRESHOLD_VAR`].
+///
+/// [`on_pull_refresh`]: fn@on_pull_refresh
+#[property(CONTEXT, default(PULL_REFRESH_THRESHOLD_VAR), widget_impl(Scroll))]
+pub fn pull_refresh_threshold(child: impl IntoUiNode, threshold: impl IntoVar<Factor>) -> UiNode {
+    with_context_var(child, PULL_REFRESH_THRESHOLD_VAR, threshold)
+}
+
+/// Pull-to-refresh indicator node.
+///
+/// The `indicator` is presented over the scroll by [`on_pull_refresh`], it must use the [`PullRefreshArgs`] to show
+/// the pull progress and refreshing state, and be hidden when not pulling.
+///
+/// Is [`node::default_pull_refresh_indicator`] by default.
+///
+/// [`node::default_pull_refresh_indicator`]: crate::node::default_pull_refresh_indicator
+/// [`on_pull_refresh`]: fn@on_pull_refresh
+#[property(CONTEXT, default(PULL_REFRESH_INDICATOR_VAR), widget_impl(Scroll))]
+pub fn pull_refresh_indicator(child: impl IntoUiNode, indicator: impl IntoVar<WidgetFn<PullRefreshArgs>>) -> UiNode {
+    with_context_var(child, PULL_REFRESH_INDICATOR_VAR, indicator)
+}
+
 /// Binds the [`horizontal_offset`] scroll var to the property value.
 ///
 /// The binding is bidirectional and the scroll variable is assigned on init.
@@ -697,3 +892,153 @@ pub fn mouse_pan(child: impl IntoUiNode, enabled: impl IntoVar<bool>) -> UiNode
         _ => {}
     })
 }
+
+/// Keeps the widget pinned to the top edge of the parent scroll viewport while the content scrolls.
+///
+/// Set this property on the group header items of a [`Scroll!`] content panel. When the header scrolls past the viewport top
+/// it is translated to stay visible, until the next sibling header reaches it and pushes it out.
+///
+/// If the parent panel supports z-index and the widget does not set [`z_index`] it is set to [`ZIndex::FRONT`] so that
+/// the pinned header renders over the other items.
+///
+/// [`Scroll!`]: struct@crate::Scroll
+/// [`z_index`]: fn@zng_wgt::z_index
+#[property(LAYOUT, default(false))]
+pub fn sticky_header(child: impl IntoUiNode, enabled: impl IntoVar<bool>) -> UiNode {
+    let enabled = enabled.into_var();
+    let header = StickyHeader::default();
+    let binding_key = FrameValueKey::new_unique();
+    let mut offset = Px(0);
+
+    match_node(child, move |child, op| match op {
+        UiNodeOp::Init => {
+            WIDGET
+                .sub_var_info(&enabled)
+                .sub_var_layout(&enabled)
+                .sub_var_layout(&SCROLL.vertical_offset())
+                .sub_var_layout(&SCROLL.zoom_scale())
+                .sub_var_layout(&SCROLL.viewport_size());
+            if enabled.get() && Z_INDEX.get() == ZIndex::DEFAULT {
+                let _ = Z_INDEX.set(ZIndex::FRONT);
+            }
+        }
+        UiNodeOp::Info { info } => {
+            if enabled.get() {
+                info.set_meta(*STICKY_HEADER_ID, header.clone());
+            }
+        }
+        UiNodeOp::Layout { wl, final_size } => {
+            *final_size = child.layout(wl);
+
+            let new_offset = if enabled.get() {
+                sticky_header_offset(&header, offset)
+            } else {
+                Px(0)
+            };
+            if offset != new_offset {
+                offset = new_offset;
+                WIDGET.render_update();
+            }
+        }
+        UiNodeOp::Render { frame } => {
+            let transform = PxTransform::from(PxVector::new(Px(0), offset));
+            if frame.is_outer() {
+                frame.push_inner_transform(&transform, |frame| child.render(frame));
+            } else {
+                frame.push_reference_frame(binding_key.into(), binding_key.bind(transform, true), false, false, |frame| {
+                    child.render(frame)
+                });
+            }
+        }
+        UiNodeOp::RenderUpdate { update } => {
+            let transform = PxTransform::from(PxVector::new(Px(0), offset));
+            if update.is_outer() {
+                update.with_inner_transform(&transform, |update| child.render_update(update));
+            } else {
+                update.with_transform(binding_key.update(transform, true), false, |update| child.render_update(update));
+            }
+        }
+        _ => {}
+    })
+}
+/// Compute the pin offset for the current scroll offset.
+///
+/// The `rendered_offset` is the offset applied in the last frame, it is used to recover the unpinned position of the header.
+fn sticky_header_offset(header: &StickyHeader, rendered_offset: Px) -> Px {
+    let info = WIDGET.info();
+    let scroll = match info.ancestors().find_map(|a| a.scroll_info()) {
+        Some(s) => s,
+        None => return Px(0),
+    };
+
+    // the scroll info content and the widget bounds are both from the last frame
+    let bounds = WIDGET.bounds();
+    let zoom = SCROLL.zoom_scale().get();
+    if bounds.rendered().is_some()
+        && let Some(p) = scroll
+            .viewport_transform()
+            .inverse()
+            .and_then(|t| t.transform_point(bounds.inner_bounds().origin))
+    {
+        let content = scroll.content();
+        header.set_content_y(Px(((p.y - content.origin.y).0 as f32 / zoom.0).round() as i32) - rendered_offset);
+    }
+    let content_y = match header.content_y() {
+        Some(y) => y,
+        None => return Px(0),
+    };
+
+    // viewport top in the content space, from the current scroll offset
+    let overflow = (SCROLL.content_size().get().height - SCROLL.viewport_size().get().height).max(Px(0));
+    let top = Px((overflow.0 as f32 * SCROLL.vertical_offset().get().0 / zoom.0).round() as i32);
+    let mut offset = (top - content_y).max(Px(0));
+
+    if offset > Px(0)
+        && let Some(next_y) = info
+            .next_siblings()
+            .find_map(|s| s.meta().get_clone(*STICKY_HEADER_ID))
+            .and_then(|h| h.content_y())
+    {
+        // next header pushes this one out
+        offset = offset.min(next_y - content_y - info.inner_size().height).max(Px(0));
+    }
+
+    offset
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pull_refresh_released_past_threshold() {
+        let mut g = PullRefreshGesture::default();
+        let threshold = 0.2.fct();
+
+        assert_eq!((0.5.fct(), false), g.update(-0.1.fct(), threshold, true));
+        assert_eq!((1.fct(), false), g.update(-0.2.fct(), threshold, true));
+        // still pressed, can pull back before release
+        assert_eq!((0.5.fct(), false), g.update(-0.1.fct(), threshold, true));
+        assert_eq!((0.fct(), true), g.update(0.fct(), threshold, false));
+        assert_eq!((0.fct(), false), g.update(0.fct(), threshold, false));
+    }
+
+    #[test]
+    fn pull_refresh_released_before_threshold() {
+        let mut g = PullRefreshGesture::default();
+        let threshold = 0.2.fct();
+
+        assert_eq!((0.5.fct(), false), g.update(-0.1.fct(), threshold, true));
+        assert_eq!((0.fct(), false), g.update(0.fct(), threshold, false));
+    }
+
+    #[test]
+    fn pull_refresh_ignores_bottom_overscroll() {
+        let mut g = PullRefreshGesture::default();
+        let threshold = 0.2.fct();
+
+        assert_eq!((0.fct(), false), g.update(0.5.fct(), threshold, true));
+        assert_eq!((0.fct(), false), g.update(0.fct(), threshold, false));
+        assert_eq!((0.fct(), false), g.update(-0.5.fct(), 0.fct(), false));
+    }
+}
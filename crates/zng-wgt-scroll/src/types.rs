@@ -438,6 +438,27 @@ impl SCROLL {
             return;
         }
 
+        let mode = OVERSCROLL_MODE_VAR.get();
+
+        let mut delta = delta;
+        if mode == OverscrollMode::Bounce {
+            // consume the delta pulling back the overscroll first
+            let current_over = overscroll_offset_var.get();
+            if current_over != 0.fct() {
+                let over_px = viewport * current_over;
+                let next_over_px = over_px + delta;
+                if (next_over_px > Px(0)) == (over_px > Px(0)) && next_over_px != Px(0) {
+                    let next_over = (next_over_px.0 as f32 / viewport.0 as f32).fct().clamp(-1.fct(), 1.fct());
+                    self.stop_overscroll(vertical);
+                    overscroll_offset_var.set(next_over);
+                    return;
+                }
+                self.stop_overscroll(vertical);
+                overscroll_offset_var.set(0.fct());
+                delta = next_over_px;
+            }
+        }
+
         let delta = delta.0 as f32 / max_scroll.0 as f32;
 
         let current = scroll_offset_var.get();
@@ -461,12 +482,22 @@ impl SCROLL {
 
         scroll_offset_var.set(next);
         if overscroll != 0.fct() {
-            let new_handle = self.increment_overscroll(overscroll_offset_var, overscroll);
+            match mode {
+                OverscrollMode::Glow => {
+                    let new_handle = self.increment_overscroll(overscroll_offset_var, overscroll);
 
-            let config = SCROLL_CONFIG.get();
-            let mut handle = config.overscroll[vertical as usize].lock();
-            mem::replace(&mut *handle, new_handle).stop();
-        } else {
+                    let config = SCROLL_CONFIG.get();
+                    let mut handle = config.overscroll[vertical as usize].lock();
+                    mem::replace(&mut *handle, new_handle).stop();
+                }
+                OverscrollMode::Bounce => {
+                    // hold the overscroll until the gesture ends
+                    self.stop_overscroll(vertical);
+                    overscroll_offset_var.modify(move |o| **o = (**o + overscroll).clamp(-1.fct(), 1.fct()));
+                }
+                OverscrollMode::None => {}
+            }
+        } else if mode != OverscrollMode::Bounce {
             self.clear_horizontal_overscroll();
         }
     }
@@ -511,7 +542,12 @@ impl SCROLL {
 
     fn clear_overscroll(&self, vertical: bool, overscroll_offset_var: ContextVar<Factor>) {
         if overscroll_offset_var.get() != 0.fct() {
-            let new_handle = overscroll_offset_var.ease(0.fct(), 100.ms(), easing::linear);
+            let new_handle = if OVERSCROLL_MODE_VAR.get() == OverscrollMode::Bounce {
+                // spring back
+                overscroll_offset_var.ease(0.fct(), 400.ms(), |t| easing::ease_out(easing::back, t))
+            } else {
+                overscroll_offset_var.ease(0.fct(), 100.ms(), easing::linear)
+            };
 
             let config = SCROLL_CONFIG.get();
             let mut handle = config.overscroll[vertical as usize].lock();
@@ -519,6 +555,11 @@ impl SCROLL {
         }
     }
 
+    fn stop_overscroll(&self, vertical: bool) {
+        let config = SCROLL_CONFIG.get();
+        mem::take(&mut *config.overscroll[vertical as usize].lock()).stop();
+    }
+
     /// Animates to `delta` over `duration`.
     pub fn scroll_vertical_touch_inertia(&self, delta: Px, duration: Duration) {
         self.scroll_touch_inertia(true, SCROLL_VERTICAL_OFFSET_VAR, OVERSCROLL_VERTICAL_OFFSET_VAR, delta, duration)
@@ -572,6 +613,12 @@ impl SCROLL {
             overscroll = -(overscroll_px.min(overscroll_max) / overscroll_max);
         }
 
+        let mode = OVERSCROLL_MODE_VAR.get();
+        if mode == OverscrollMode::None {
+            overscroll = 0.fct();
+        }
+        let bounce = mode == OverscrollMode::Bounce;
+
         let cfg = SCROLL_CONFIG.get();
         let easing = |t| easing::ease_out(easing::quad, t);
         *cfg.inertia[vertical as usize].lock() = if overscroll != 0.fct() {
@@ -598,7 +645,11 @@ impl SCROLL {
                     }
                 } else {
                     // overscroll clear ease animation
-                    let step = easing::linear(animation.elapsed_stop(300.ms()));
+                    let step = if bounce {
+                        easing::ease_out(easing::back, animation.elapsed_stop(400.ms()))
+                    } else {
+                        easing::linear(animation.elapsed_stop(300.ms()))
+                    };
                     let v = overscroll_tr.sample(step);
                     overscroll_var.set(v);
                 }
@@ -1080,6 +1131,22 @@ impl ScrollInfo {
 
 static_id! {
     pub(super) static ref SCROLL_INFO_ID: StateId<ScrollInfo>;
+    pub(super) static ref STICKY_HEADER_ID: StateId<StickyHeader>;
+}
+
+/// Shared reference to the unpinned vertical position of a [`sticky_header`] widget, in the scroll content space.
+///
+/// [`sticky_header`]: fn@crate::sticky_header
+#[derive(Clone, Default, Debug)]
+pub(super) struct StickyHeader(Arc<Mutex<Option<Px>>>);
+impl StickyHeader {
+    pub(super) fn content_y(&self) -> Option<Px> {
+        *self.0.lock()
+    }
+
+    pub(super) fn set_content_y(&self, y: Px) {
+        *self.0.lock() = Some(y);
+    }
 }
 
 /// Smooth scrolling config.
@@ -1177,6 +1244,34 @@ impl_from_and_into_var! {
 #[non_exhaustive]
 pub struct AutoScrollArgs {}
 
+/// Arguments for the [`on_pull_refresh`] handler and the [`pull_refresh_indicator`] widget function.
+///
+/// [`on_pull_refresh`]: fn@crate::on_pull_refresh
+/// [`pull_refresh_indicator`]: fn@crate::pull_refresh_indicator
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct PullRefreshArgs {
+    /// Pull distance relative to the [`pull_refresh_threshold`], is `1.fct()` or more when releasing will refresh.
+    ///
+    /// Is `0.fct()` when not pulling or while refreshing.
+    ///
+    /// [`pull_refresh_threshold`]: fn@crate::pull_refresh_threshold
+    pub pull: Var<Factor>,
+    /// If the refresh handler was called and has not signaled completion yet.
+    pub refreshing: Var<bool>,
+}
+impl PullRefreshArgs {
+    /// Signal that the refresh operation has completed, the indicator is hidden and a new pull can start.
+    pub fn complete(&self) {
+        self.refreshing.set(false);
+    }
+}
+impl PartialEq for PullRefreshArgs {
+    fn eq(&self, other: &Self) -> bool {
+        self.pull.var_eq(&other.pull) && self.refreshing.var_eq(&other.refreshing)
+    }
+}
+
 /// Defines how the scale is changed by the [`ZOOM_TO_FIT_CMD`].
 ///
 /// See the [`zoom_to_fit_mode`] property for more details.
@@ -1191,3 +1286,45 @@ pub enum ZoomToFitMode {
     /// The content is only scaled down to fit the viewport. If the content is smaller them the viewport the scale is set to 100%.
     ScaleDown,
 }
+
+/// Defines how the scroll offset snaps to the content children after a scroll gesture ends.
+///
+/// See the [`scroll_snap`] property for more details.
+///
+/// [`scroll_snap`]: fn@crate::scroll_snap
+#[derive(Default, Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum ScrollSnapMode {
+    /// Does not snap.
+    #[default]
+    None,
+    /// Always snaps to the nearest snap point.
+    Mandatory,
+    /// Only snaps if the nearest snap point is within the distance.
+    ///
+    /// Relative lengths are relative to the viewport length in the snap dimension.
+    Proximity(Length),
+}
+impl ScrollSnapMode {
+    /// If is not [`None`](Self::None).
+    pub fn is_enabled(&self) -> bool {
+        !matches!(self, Self::None)
+    }
+}
+
+/// Defines how the scroll responds to touch scrolling past an edge.
+///
+/// See the [`overscroll_mode`] property for more details.
+///
+/// [`overscroll_mode`]: fn@crate::overscroll_mode
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum OverscrollMode {
+    /// Shows an edge glow in the [`overscroll_color`].
+    ///
+    /// [`overscroll_color`]: fn@crate::overscroll_color
+    #[default]
+    Glow,
+    /// Content translates past the edge during the gesture and springs back on release.
+    Bounce,
+    /// No overscroll feedback.
+    None,
+}
@@ -0,0 +1,56 @@
+//! Split view divider widget.
+
+use zng_wgt::prelude::*;
+use zng_wgt_access::{AccessRole, access_role};
+use zng_wgt_input::{CursorIcon, cursor, is_cap_hovered, pointer_capture::capture_pointer};
+use zng_wgt_style::{Style, StyleMix, impl_style_fn};
+
+use crate::SPLIT_ORIENTATION_VAR;
+
+/// Split view divider widget.
+///
+/// The divider captures the pointer while pressed, the parent split view handles the drag. The divider size
+/// in the split orientation axis defines the space between the panes.
+#[widget($crate::divider::Divider)]
+pub struct Divider(StyleMix<WidgetBase>);
+impl Divider {
+    fn widget_intrinsic(&mut self) {
+        self.style_intrinsic(STYLE_FN_VAR, property_id!(self::style_fn));
+
+        widget_set! {
+            self;
+            capture_pointer = true;
+            access_role = AccessRole::Separator;
+        }
+    }
+}
+impl_style_fn!(Divider, DefaultStyle);
+
+/// Default divider style.
+#[widget($crate::divider::DefaultStyle)]
+pub struct DefaultStyle(Style);
+impl DefaultStyle {
+    fn widget_intrinsic(&mut self) {
+        widget_set! {
+            self;
+            replace = true;
+
+            #[easing(150.ms())]
+            zng_wgt_fill::background_color = light_dark(rgb(0.82, 0.82, 0.82), rgb(0.18, 0.18, 0.18)).rgba();
+
+            when #{SPLIT_ORIENTATION_VAR}.is_horizontal() {
+                zng_wgt_size_offset::width = 5;
+                cursor = CursorIcon::ColResize;
+            }
+            when #{SPLIT_ORIENTATION_VAR}.is_vertical() {
+                zng_wgt_size_offset::height = 5;
+                cursor = CursorIcon::RowResize;
+            }
+
+            when *#is_cap_hovered {
+                #[easing(0.ms())]
+                zng_wgt_fill::background_color = colors::ACCENT_COLOR_VAR.rgba();
+            }
+        }
+    }
+}
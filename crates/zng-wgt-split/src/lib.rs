@@ -0,0 +1,527 @@
+#![doc(html_favicon_url = "https://zng-ui.github.io/res/zng-logo-icon.png")]
+#![doc(html_logo_url = "https://zng-ui.github.io/res/zng-logo.png")]
+//!
+//! Split view widget with a draggable divider.
+//!
+//! # Crate
+//!
+#![doc = include_str!(concat!("../", std::env!("CARGO_PKG_README")))]
+#![warn(unused_extern_crates)]
+#![warn(missing_docs)]
+
+zng_wgt::enable_widget_macros!();
+
+pub mod divider;
+
+use std::fmt;
+
+use zng_ext_input::mouse::{ButtonState, MOUSE_INPUT_EVENT, MOUSE_MOVE_EVENT};
+use zng_wgt::prelude::*;
+use zng_wgt_style::{Style, StyleMix, impl_style_fn};
+
+/// Two panes separated by a divider that can be dragged to resize the panes.
+///
+/// The [`first`] and [`second`] panes are placed side by side or one above the other depending on the [`split_orientation`],
+/// the [`split_ratio`] defines the size of the first pane as a fraction of the space available for both panes, dragging
+/// the divider sets the ratio variable. The panes min size is defined by [`first_min`] and [`second_min`], and
+/// [`split_snap`] can define ratios the divider snaps to while dragging.
+///
+/// # Shorthand
+///
+/// The `SplitView!` macro provides a shorthand init that sets the two panes, `SplitView!(first, second)`.
+///
+/// [`first`]: fn@first
+/// [`second`]: fn@second
+/// [`split_orientation`]: fn@split_orientation
+/// [`split_ratio`]: fn@split_ratio
+/// [`first_min`]: fn@first_min
+/// [`second_min`]: fn@second_min
+/// [`split_snap`]: fn@split_snap
+#[widget($crate::SplitView {
+    ($first:expr, $second:expr $(,)?) => {
+        first = $first;
+        second = $second;
+    };
+})]
+pub struct SplitView(StyleMix<WidgetBase>);
+impl SplitView {
+    fn widget_intrinsic(&mut self) {
+        self.style_intrinsic(STYLE_FN_VAR, property_id!(self::style_fn));
+
+        widget_set! {
+            self;
+            split_ratio = var(0.5.fct());
+        }
+
+        self.widget_builder().push_build_action(|wgt| {
+            let first = wgt.capture_ui_node_or_nil(property_id!(Self::first));
+            let second = wgt.capture_ui_node_or_nil(property_id!(Self::second));
+            wgt.set_child(node(first, second));
+        });
+    }
+}
+impl_style_fn!(SplitView, DefaultStyle);
+
+/// Default split view style.
+#[widget($crate::DefaultStyle)]
+pub struct DefaultStyle(Style);
+impl DefaultStyle {
+    fn widget_intrinsic(&mut self) {
+        widget_set! {
+            self;
+            replace = true;
+        }
+    }
+}
+
+/// Split view orientation.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum SplitOrientation {
+    /// Panes are placed side by side, the divider moves horizontally.
+    ///
+    /// The first pane is placed at the start, so it is on the right side in right-to-left contexts.
+    Horizontal,
+    /// Panes are placed one above the other, the divider moves vertically.
+    Vertical,
+}
+impl SplitOrientation {
+    /// If is [`Horizontal`].
+    ///
+    /// [`Horizontal`]: Self::Horizontal
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Self::Horizontal)
+    }
+
+    /// If is [`Vertical`].
+    ///
+    /// [`Vertical`]: Self::Vertical
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::Vertical)
+    }
+}
+impl fmt::Debug for SplitOrientation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "SplitOrientation::")?;
+        }
+        match self {
+            Self::Horizontal => write!(f, "Horizontal"),
+            Self::Vertical => write!(f, "Vertical"),
+        }
+    }
+}
+
+context_var! {
+    /// Size of the first pane as a fraction of the space available for both panes.
+    pub static SPLIT_RATIO_VAR: Factor = 0.5.fct();
+
+    /// Split view orientation.
+    pub static SPLIT_ORIENTATION_VAR: SplitOrientation = SplitOrientation::Horizontal;
+
+    /// Minimum size of the first pane.
+    pub static FIRST_MIN_VAR: Length = Length::Default;
+
+    /// Minimum size of the second pane.
+    pub static SECOND_MIN_VAR: Length = Length::Default;
+
+    /// Ratios the divider snaps to while dragging.
+    pub static SPLIT_SNAP_VAR: Vec<Factor> = vec![];
+
+    /// Distance from a snap ratio that causes the divider to snap.
+    pub static SPLIT_SNAP_DISTANCE_VAR: Length = 8;
+}
+
+/// First pane content.
+///
+/// This is the left pane in horizontal splits and the top pane in vertical splits.
+#[property(CHILD, widget_impl(SplitView))]
+pub fn first(wgt: &mut WidgetBuilding, pane: impl IntoUiNode) {
+    let _ = pane;
+    wgt.expect_property_capture();
+}
+
+/// Second pane content.
+///
+/// This is the right pane in horizontal splits and the bottom pane in vertical splits.
+#[property(CHILD, widget_impl(SplitView))]
+pub fn second(wgt: &mut WidgetBuilding, pane: impl IntoUiNode) {
+    let _ = pane;
+    wgt.expect_property_capture();
+}
+
+/// Size of the first pane as a fraction of the space available for both panes.
+///
+/// Is a new `var(0.5.fct())` by default in [`SplitView!`], set to a read-write variable to persist the ratio, the
+/// divider sets the variable on drag.
+///
+/// This property sets the [`SPLIT_RATIO_VAR`].
+///
+/// [`SplitView!`]: struct@SplitView
+#[property(CONTEXT, default(SPLIT_RATIO_VAR), widget_impl(SplitView))]
+pub fn split_ratio(child: impl IntoUiNode, ratio: impl IntoVar<Factor>) -> UiNode {
+    with_context_var(child, SPLIT_RATIO_VAR, ratio)
+}
+
+/// Split view orientation.
+///
+/// This property sets the [`SPLIT_ORIENTATION_VAR`].
+#[property(CONTEXT, default(SPLIT_ORIENTATION_VAR), widget_impl(SplitView))]
+pub fn split_orientation(child: impl IntoUiNode, orientation: impl IntoVar<SplitOrientation>) -> UiNode {
+    with_context_var(child, SPLIT_ORIENTATION_VAR, orientation)
+}
+
+/// Minimum size of the first pane.
+///
+/// Relative values are computed from the split view size. Is zero by default.
+///
+/// This property sets the [`FIRST_MIN_VAR`].
+#[property(CONTEXT, default(FIRST_MIN_VAR), widget_impl(SplitView))]
+pub fn first_min(child: impl IntoUiNode, min: impl IntoVar<Length>) -> UiNode {
+    with_context_var(child, FIRST_MIN_VAR, min)
+}
+
+/// Minimum size of the second pane.
+///
+/// Relative values are computed from the split view size. Is zero by default.
+///
+/// This property sets the [`SECOND_MIN_VAR`].
+#[property(CONTEXT, default(SECOND_MIN_VAR), widget_impl(SplitView))]
+pub fn second_min(child: impl IntoUiNode, min: impl IntoVar<Length>) -> UiNode {
+    with_context_var(child, SECOND_MIN_VAR, min)
+}
+
+/// Ratios the divider snaps to while dragging.
+///
+/// Is empty by default.
+///
+/// This property sets the [`SPLIT_SNAP_VAR`].
+#[property(CONTEXT, default(SPLIT_SNAP_VAR), widget_impl(SplitView))]
+pub fn split_snap(child: impl IntoUiNode, ratios: impl IntoVar<Vec<Factor>>) -> UiNode {
+    with_context_var(child, SPLIT_SNAP_VAR, ratios)
+}
+
+/// Distance from a [`split_snap`] ratio that causes the divider to snap.
+///
+/// Is `8` by default.
+///
+/// This property sets the [`SPLIT_SNAP_DISTANCE_VAR`].
+///
+/// [`split_snap`]: fn@split_snap
+#[property(CONTEXT, default(SPLIT_SNAP_DISTANCE_VAR), widget_impl(SplitView))]
+pub fn split_snap_distance(child: impl IntoUiNode, distance: impl IntoVar<Length>) -> UiNode {
+    with_context_var(child, SPLIT_SNAP_DISTANCE_VAR, distance)
+}
+
+/// Split view node.
+///
+/// Can be used directly to layout two panes and a [`Divider!`] without declaring a split view widget, the context
+/// variables are configured by the [`SplitView!`] properties.
+///
+/// [`Divider!`]: struct@divider::Divider
+/// [`SplitView!`]: struct@SplitView
+pub fn node(first: impl IntoUiNode, second: impl IntoUiNode) -> UiNode {
+    let children = PanelList::new(ui_vec![first, divider::Divider!(), second]);
+
+    let mut divider_id = None;
+    let mut drag_offset = None::<Px>;
+    let mut layout = SplitLayout::default();
+
+    match_node(children, move |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET
+                .sub_var_layout(&SPLIT_RATIO_VAR)
+                .sub_var_layout(&SPLIT_ORIENTATION_VAR)
+                .sub_var_layout(&FIRST_MIN_VAR)
+                .sub_var_layout(&SECOND_MIN_VAR)
+                .sub_event(&MOUSE_INPUT_EVENT)
+                .sub_event(&MOUSE_MOVE_EVENT);
+            c.init();
+            divider_id = c.node_impl::<PanelList>().with_child(1, |d, _| d.as_widget().map(|mut w| w.id()));
+        }
+        UiNodeOp::Deinit => {
+            drag_offset = None;
+        }
+        UiNodeOp::Update { updates } => {
+            let mut changed = false;
+            c.update_list(updates, &mut changed);
+            if changed {
+                WIDGET.layout();
+            }
+
+            let Some(divider_id) = divider_id else {
+                return;
+            };
+
+            MOUSE_INPUT_EVENT.each_update(false, |args| {
+                if !args.is_primary() {
+                    return;
+                }
+                if args.state == ButtonState::Pressed && args.target.contains(divider_id) {
+                    let info = WIDGET.info();
+                    if let Some(divider) = info.tree().get(divider_id) {
+                        let scale_factor = info.tree().scale_factor();
+                        let pos = args.position.to_px(scale_factor);
+                        let bounds = divider.inner_bounds();
+                        drag_offset = Some(if SPLIT_ORIENTATION_VAR.get().is_horizontal() {
+                            pos.x - bounds.origin.x
+                        } else {
+                            pos.y - bounds.origin.y
+                        });
+                    }
+                } else if args.state == ButtonState::Released {
+                    drag_offset = None;
+                }
+            });
+
+            let mut drag_pos = None;
+            MOUSE_MOVE_EVENT.each_update(false, |args| {
+                if let Some(cap) = &args.capture
+                    && cap.target.contains(divider_id)
+                {
+                    drag_pos = Some(args.position);
+                    args.propagation.stop();
+                }
+            });
+
+            if let Some(pos) = drag_pos
+                && let Some(drag_offset) = drag_offset
+            {
+                let info = WIDGET.info();
+                let pos = pos.to_px(info.tree().scale_factor());
+                let bounds = info.inner_bounds();
+                let horizontal = SPLIT_ORIENTATION_VAR.get().is_horizontal();
+
+                let divider_start = if horizontal {
+                    pos.x - drag_offset - bounds.origin.x
+                } else {
+                    pos.y - drag_offset - bounds.origin.y
+                };
+                let first_len = if horizontal && layout.is_rtl {
+                    bounds.size.width - divider_start - layout.divider_len
+                } else {
+                    divider_start
+                };
+
+                if let Some(ratio) = layout.drag_ratio(first_len, &SPLIT_SNAP_VAR.get()) {
+                    SPLIT_RATIO_VAR.set(ratio);
+                }
+            }
+        }
+        UiNodeOp::Measure { wm, desired_size } => {
+            c.delegated();
+
+            let constraints = LAYOUT.constraints();
+            *desired_size = if let Some(size) = constraints.fill_or_exact() {
+                size
+            } else {
+                let horizontal = SPLIT_ORIENTATION_VAR.get().is_horizontal();
+                let child_constraints = constraints.with_new_min(Px(0), Px(0));
+                let child_constraints = if horizontal {
+                    child_constraints.with_unbounded_x()
+                } else {
+                    child_constraints.with_unbounded_y()
+                };
+                let mut size = PxSize::zero();
+                c.node_impl::<PanelList>().for_each_child(|_, c, _| {
+                    let s = LAYOUT.with_constraints(child_constraints, || c.measure(wm));
+                    if horizontal {
+                        size.width += s.width;
+                        size.height = size.height.max(s.height);
+                    } else {
+                        size.height += s.height;
+                        size.width = size.width.max(s.width);
+                    }
+                });
+                constraints.clamp_size(size)
+            };
+        }
+        UiNodeOp::Layout { wl, final_size } => {
+            c.delegated();
+
+            let constraints = LAYOUT.constraints();
+            let size = constraints.fill_size();
+            let horizontal = SPLIT_ORIENTATION_VAR.get().is_horizontal();
+            let (main, cross) = if horizontal {
+                (size.width, size.height)
+            } else {
+                (size.height, size.width)
+            };
+
+            let children = c.node_impl::<PanelList>();
+
+            let divider_len = children.with_child(1, |d, _| {
+                let constraints = if horizontal {
+                    PxConstraints2d::new_unbounded().with_exact_y(cross)
+                } else {
+                    PxConstraints2d::new_unbounded().with_exact_x(cross)
+                };
+                let s = LAYOUT.with_constraints(constraints, || d.measure(&mut wl.to_measure(None)));
+                if horizontal { s.width } else { s.height }
+            });
+
+            layout = SplitLayout {
+                available: (main - divider_len).max(Px(0)),
+                divider_len,
+                first_min: if horizontal {
+                    FIRST_MIN_VAR.layout_dft_x(Px(0))
+                } else {
+                    FIRST_MIN_VAR.layout_dft_y(Px(0))
+                },
+                second_min: if horizontal {
+                    SECOND_MIN_VAR.layout_dft_x(Px(0))
+                } else {
+                    SECOND_MIN_VAR.layout_dft_y(Px(0))
+                },
+                snap_distance: if horizontal {
+                    SPLIT_SNAP_DISTANCE_VAR.layout_x()
+                } else {
+                    SPLIT_SNAP_DISTANCE_VAR.layout_y()
+                },
+                is_rtl: LAYOUT.direction().is_rtl(),
+            };
+
+            let first_len = layout.first_len(SPLIT_RATIO_VAR.get());
+            let second_len = layout.available - first_len;
+
+            let mut offsets = [Px(0), first_len, first_len + divider_len];
+            let lens = [first_len, divider_len, second_len];
+            if horizontal && layout.is_rtl {
+                for (o, l) in offsets.iter_mut().zip(lens) {
+                    *o = main - *o - l;
+                }
+            }
+
+            children.layout_list(
+                wl,
+                |i, c, o, wl| {
+                    let (child_size, offset) = if horizontal {
+                        (PxSize::new(lens[i], cross), PxVector::new(offsets[i], Px(0)))
+                    } else {
+                        (PxSize::new(cross, lens[i]), PxVector::new(Px(0), offsets[i]))
+                    };
+                    let (_, define_ref_frame) =
+                        LAYOUT.with_constraints(PxConstraints2d::new_exact_size(child_size), || wl.with_child(|wl| c.layout(wl)));
+                    o.child_offset = offset;
+                    o.define_reference_frame = define_ref_frame;
+                    child_size
+                },
+                |_, _| PxSize::zero(),
+            );
+            children.commit_data().request_render();
+
+            *final_size = size;
+        }
+        _ => {}
+    })
+}
+
+/// Last layout values, also used to compute the drag ratio.
+#[derive(Default)]
+struct SplitLayout {
+    available: Px,
+    divider_len: Px,
+    first_min: Px,
+    second_min: Px,
+    snap_distance: Px,
+    is_rtl: bool,
+}
+impl SplitLayout {
+    /// Actual first pane length for the `ratio`, honoring the min sizes.
+    fn first_len(&self, ratio: Factor) -> Px {
+        let first = self.available * ratio.clamp_range();
+        self.clamp(first)
+    }
+
+    fn clamp(&self, first_len: Px) -> Px {
+        let max = (self.available - self.second_min).max(Px(0));
+        first_len.min(max).max(self.first_min.min(self.available))
+    }
+
+    /// Ratio for a dragged first pane length, snapped and clamped.
+    fn drag_ratio(&self, first_len: Px, snap: &[Factor]) -> Option<Factor> {
+        if self.available <= Px(0) {
+            return None;
+        }
+        let mut first_len = self.clamp(first_len);
+        for &s in snap {
+            let snap_len = self.available * s.clamp_range();
+            if (snap_len - first_len).abs() <= self.snap_distance {
+                first_len = self.clamp(snap_len);
+                break;
+            }
+        }
+        Some((first_len.0 as f32 / self.available.0 as f32).fct())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout() -> SplitLayout {
+        SplitLayout {
+            available: Px(100),
+            divider_len: Px(4),
+            first_min: Px(10),
+            second_min: Px(20),
+            snap_distance: Px(5),
+            is_rtl: false,
+        }
+    }
+
+    #[test]
+    fn clamp_min_sizes() {
+        let l = layout();
+        assert_eq!(l.clamp(Px(50)), Px(50));
+        assert_eq!(l.clamp(Px(-5)), Px(10));
+        assert_eq!(l.clamp(Px(95)), Px(80));
+    }
+
+    #[test]
+    fn clamp_mins_overflow() {
+        let l = SplitLayout {
+            available: Px(20),
+            first_min: Px(15),
+            second_min: Px(10),
+            ..layout()
+        };
+        // first min wins
+        assert_eq!(l.clamp(Px(0)), Px(15));
+        assert_eq!(l.clamp(Px(20)), Px(15));
+
+        let l = SplitLayout { available: Px(5), ..l };
+        assert_eq!(l.clamp(Px(3)), Px(5));
+    }
+
+    #[test]
+    fn drag_ratio_clamped() {
+        let l = layout();
+        assert_eq!(l.drag_ratio(Px(50), &[]), Some(0.5.fct()));
+        assert_eq!(l.drag_ratio(Px(95), &[]), Some(0.8.fct()));
+        assert_eq!(l.drag_ratio(Px(0), &[]), Some(0.1.fct()));
+    }
+
+    #[test]
+    fn drag_ratio_snap() {
+        let l = layout();
+        assert_eq!(l.drag_ratio(Px(47), &[0.5.fct()]), Some(0.5.fct()));
+        assert_eq!(l.drag_ratio(Px(53), &[0.25.fct(), 0.5.fct()]), Some(0.5.fct()));
+        assert_eq!(l.drag_ratio(Px(40), &[0.5.fct()]), Some(0.4.fct()));
+
+        // snap value is clamped
+        let l = SplitLayout {
+            snap_distance: Px(15),
+            ..layout()
+        };
+        assert_eq!(l.drag_ratio(Px(12), &[0.fct()]), Some(0.1.fct()));
+    }
+
+    #[test]
+    fn drag_ratio_no_space() {
+        let l = SplitLayout {
+            available: Px(0),
+            ..layout()
+        };
+        assert_eq!(l.drag_ratio(Px(10), &[]), None);
+    }
+}
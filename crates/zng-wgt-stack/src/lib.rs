@@ -74,11 +74,12 @@ pub struct Stack(WidgetBase);
 impl Stack {
     fn widget_intrinsic(&mut self) {
         self.widget_builder().push_build_action(|wgt| {
-            let child = node(
+            let child = node_impl(
                 wgt.capture_ui_node_or_nil(property_id!(Self::children)),
                 wgt.capture_var_or_default(property_id!(Self::direction)),
                 wgt.capture_var_or_default(property_id!(Self::spacing)),
                 wgt.capture_var_or_else(property_id!(Self::children_align), || Align::FILL),
+                wgt.capture_var_or_default(property_id!(Self::spacing_includes_collapsed)),
             );
             wgt.set_child(child);
         });
@@ -112,6 +113,19 @@ pub fn spacing(wgt: &mut WidgetBuilding, spacing: impl IntoVar<Length>) {
     wgt.expect_property_capture();
 }
 
+/// If collapsed items also get spacing.
+///
+/// Items that are collapsed (layout size zero) are skipped by default, so the spacing is only applied between visible items and
+/// toggling an item visibility does not leave a double gap. If this is `true` collapsed items are positioned like a visible
+/// item of size zero, so each collapsed item still adds one spacing.
+///
+/// This is `false` by default.
+#[property(LAYOUT, default(false), widget_impl(Stack))]
+pub fn spacing_includes_collapsed(wgt: &mut WidgetBuilding, includes: impl IntoVar<bool>) {
+    let _ = includes;
+    wgt.expect_property_capture();
+}
+
 /// Items alignment.
 ///
 /// The items are aligned along axis that don't change, as defined by the [`direction`].
@@ -135,18 +149,29 @@ pub fn node(
     direction: impl IntoVar<StackDirection>,
     spacing: impl IntoVar<Length>,
     children_align: impl IntoVar<Align>,
+) -> UiNode {
+    node_impl(children, direction, spacing, children_align, false)
+}
+fn node_impl(
+    children: impl IntoUiNode,
+    direction: impl IntoVar<StackDirection>,
+    spacing: impl IntoVar<Length>,
+    children_align: impl IntoVar<Align>,
+    spacing_includes_collapsed: impl IntoVar<bool>,
 ) -> UiNode {
     let children = PanelList::new(children).track_info_range(*PANEL_LIST_ID);
     let direction = direction.into_var();
     let spacing = spacing.into_var();
     let children_align = children_align.into_var();
+    let spacing_includes_collapsed = spacing_includes_collapsed.into_var();
 
     match_node(children, move |c, op| match op {
         UiNodeOp::Init => {
             WIDGET
                 .sub_var_layout(&direction)
                 .sub_var_layout(&spacing)
-                .sub_var_layout(&children_align);
+                .sub_var_layout(&children_align)
+                .sub_var_layout(&spacing_includes_collapsed);
         }
         UiNodeOp::Update { updates } => {
             let mut changed = false;
@@ -158,11 +183,25 @@ pub fn node(
         }
         UiNodeOp::Measure { wm, desired_size } => {
             c.delegated();
-            *desired_size = measure(wm, c.node_impl::<PanelList>(), direction.get(), spacing.get(), children_align.get());
+            *desired_size = measure(
+                wm,
+                c.node_impl::<PanelList>(),
+                direction.get(),
+                spacing.get(),
+                children_align.get(),
+                spacing_includes_collapsed.get(),
+            );
         }
         UiNodeOp::Layout { wl, final_size } => {
             c.delegated();
-            *final_size = layout(wl, c.node_impl::<PanelList>(), direction.get(), spacing.get(), children_align.get());
+            *final_size = layout(
+                wl,
+                c.node_impl::<PanelList>(),
+                direction.get(),
+                spacing.get(),
+                children_align.get(),
+                spacing_includes_collapsed.get(),
+            );
         }
         _ => {}
     })
@@ -267,7 +306,14 @@ pub fn lazy_sample(
     })
 }
 
-fn measure(wm: &mut WidgetMeasure, children: &mut PanelList, direction: StackDirection, spacing: Length, children_align: Align) -> PxSize {
+fn measure(
+    wm: &mut WidgetMeasure,
+    children: &mut PanelList,
+    direction: StackDirection,
+    spacing: Length,
+    children_align: Align,
+    spacing_includes_collapsed: bool,
+) -> PxSize {
     let metrics = LAYOUT.metrics();
     let constraints = metrics.constraints();
     if let Some(known) = constraints.inner().fill_or_exact() {
@@ -304,7 +350,7 @@ fn measure(wm: &mut WidgetMeasure, children: &mut PanelList, direction: StackDir
                     Some(mut w) => w.with_context(WidgetUpdateMode::Ignore, || WIDGET.bounds().measure_outer_size()),
                     None => c.measure(wm),
                 };
-                if size.is_empty() {
+                if size.is_empty() && !spacing_includes_collapsed {
                     return; // continue, skip collapsed
                 }
 
@@ -323,7 +369,14 @@ fn measure(wm: &mut WidgetMeasure, children: &mut PanelList, direction: StackDir
 
     constraints.inner().fill_size_or(item_bounds.size())
 }
-fn layout(wl: &mut WidgetLayout, children: &mut PanelList, direction: StackDirection, spacing: Length, children_align: Align) -> PxSize {
+fn layout(
+    wl: &mut WidgetLayout,
+    children: &mut PanelList,
+    direction: StackDirection,
+    spacing: Length,
+    children_align: Align,
+    spacing_includes_collapsed: bool,
+) -> PxSize {
     let metrics = LAYOUT.metrics();
     let constraints = metrics.constraints();
     let child_align = children_align * direction.direction_scale();
@@ -370,11 +423,15 @@ fn layout(wl: &mut WidgetLayout, children: &mut PanelList, direction: StackDirec
                 if size.is_empty() {
                     o.child_offset = PxVector::zero();
                     o.define_reference_frame = false;
-                    return; // continue, skip collapsed
+                    if !spacing_includes_collapsed {
+                        return; // continue, skip collapsed
+                    }
                 }
 
                 let offset = direction.layout(item_rect, size) + child_spacing;
-                o.child_offset = offset;
+                if !size.is_empty() {
+                    o.child_offset = offset;
+                }
 
                 item_rect.origin = offset.to_point();
                 item_rect.size = size;
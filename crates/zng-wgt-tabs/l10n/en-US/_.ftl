@@ -0,0 +1,11 @@
+CLOSE_TAB_CMD =
+    .info = Close the selected tab
+    .name = Close Tab
+
+NEXT_TAB_CMD =
+    .info = Select the next tab
+    .name = Next Tab
+
+PREV_TAB_CMD =
+    .info = Select the previous tab
+    .name = Previous Tab
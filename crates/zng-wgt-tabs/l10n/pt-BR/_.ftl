@@ -0,0 +1,11 @@
+CLOSE_TAB_CMD =
+    .info = Fecha a aba selecionada
+    .name = Fechar Aba
+
+NEXT_TAB_CMD =
+    .info = Seleciona a próxima aba
+    .name = Próxima Aba
+
+PREV_TAB_CMD =
+    .info = Seleciona a aba anterior
+    .name = Aba Anterior
@@ -0,0 +1,723 @@
+#![doc(html_favicon_url = "https://zng-ui.github.io/res/zng-logo-icon.png")]
+#![doc(html_logo_url = "https://zng-ui.github.io/res/zng-logo.png")]
+//!
+//! Tab view widget, commands and properties.
+//!
+//! # Crate
+//!
+#![doc = include_str!(concat!("../", std::env!("CARGO_PKG_README")))]
+#![warn(unused_extern_crates)]
+#![warn(missing_docs)]
+
+zng_wgt::enable_widget_macros!();
+
+use std::mem;
+
+use zng_ext_input::mouse::{ButtonState, MOUSE_INPUT_EVENT, MOUSE_MOVE_EVENT};
+use zng_wgt::prelude::*;
+use zng_wgt_access::{AccessRole, access_role};
+use zng_wgt_button::Button;
+use zng_wgt_container::{Container, child_top};
+use zng_wgt_input::gesture::ClickArgs;
+use zng_wgt_layer::popup::POPUP;
+use zng_wgt_menu::context::ContextMenu;
+use zng_wgt_style::{Style, StyleMix, impl_style_fn};
+use zng_wgt_text::Text;
+
+/// Tab headers and the content panel of the selected tab.
+///
+/// The [`tabs`] define the tab headers, the [`selected`] tab key defines the tab content generated by [`content_fn`]. Headers
+/// can be dragged to reorder the tabs, closable tabs have a close button in the header and tabs that do not fit the header
+/// bar width are listed in an overflow menu.
+///
+/// The [`NEXT_TAB_CMD`], [`PREV_TAB_CMD`] and [`CLOSE_TAB_CMD`] commands scoped on the widget select the next, previous
+/// or close a tab, they have the `Ctrl+Tab`, `Ctrl+Shift+Tab` and `Ctrl+F4` shortcuts when focus is inside the widget.
+///
+/// Note that the [`tabs`] variable is modified when tabs are reordered or closed, so it must be read-write.
+///
+/// # Shorthand
+///
+/// The `Tabs!` macro provides a shorthand init that sets the tabs, `Tabs!(tabs)`.
+///
+/// [`tabs`]: fn@tabs
+/// [`selected`]: fn@selected
+/// [`content_fn`]: fn@content_fn
+#[widget($crate::Tabs {
+    ($tabs:expr) => {
+        tabs = $tabs;
+    };
+})]
+pub struct Tabs(StyleMix<WidgetBase>);
+impl Tabs {
+    fn widget_intrinsic(&mut self) {
+        self.style_intrinsic(STYLE_FN_VAR, property_id!(self::style_fn));
+
+        self.widget_builder().push_build_action(|wgt| {
+            wgt.set_child(node());
+        });
+    }
+}
+impl_style_fn!(Tabs, DefaultStyle);
+
+/// Default tabs style.
+#[widget($crate::DefaultStyle)]
+pub struct DefaultStyle(Style);
+impl DefaultStyle {
+    fn widget_intrinsic(&mut self) {
+        widget_set! {
+            self;
+            replace = true;
+        }
+    }
+}
+
+/// Tab descriptor.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct TabItem {
+    /// Unique tab key, identifies the tab in the [`selected`](fn@selected) variable.
+    pub key: Txt,
+    /// Header text.
+    pub label: Txt,
+    /// If the tab header shows a close button.
+    pub closable: bool,
+}
+impl TabItem {
+    /// New tab that is not closable.
+    pub fn new(key: impl Into<Txt>, label: impl Into<Txt>) -> Self {
+        Self {
+            key: key.into(),
+            label: label.into(),
+            closable: false,
+        }
+    }
+
+    /// Set if the tab header shows a close button.
+    pub fn with_closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+}
+
+/// Arguments for the [`header_fn`].
+///
+/// [`header_fn`]: fn@header_fn
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct TabHeaderArgs {
+    /// Tabs widget, the commands scope.
+    pub tabs_id: WidgetId,
+    /// Tab.
+    pub tab: TabItem,
+}
+
+/// Arguments for the [`overflow_fn`].
+///
+/// [`overflow_fn`]: fn@overflow_fn
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct TabsOverflowArgs {
+    /// Tabs widget, the commands scope.
+    pub tabs_id: WidgetId,
+    /// Tabs that do not fit the header bar, updated on layout.
+    pub hidden: Var<Vec<TabItem>>,
+}
+
+context_var! {
+    /// Tabs.
+    pub static TABS_VAR: Vec<TabItem> = vec![];
+    /// Selected tab key.
+    pub static SELECTED_VAR: Txt = Txt::from_static("");
+    /// Tab header widget generator.
+    pub static HEADER_FN_VAR: WidgetFn<TabHeaderArgs> = WidgetFn::new(default_header_fn);
+    /// Tab content widget generator.
+    pub static CONTENT_FN_VAR: WidgetFn<TabItem> = WidgetFn::nil();
+    /// Overflow widget generator.
+    pub static OVERFLOW_FN_VAR: WidgetFn<TabsOverflowArgs> = WidgetFn::new(default_overflow_fn);
+}
+
+/// Tabs.
+///
+/// The variable is modified when tabs are reordered or closed.
+///
+/// This property sets the [`TABS_VAR`].
+#[property(CONTEXT, default(TABS_VAR), widget_impl(Tabs))]
+pub fn tabs(child: impl IntoUiNode, tabs: impl IntoVar<Vec<TabItem>>) -> UiNode {
+    with_context_var(child, TABS_VAR, tabs)
+}
+
+/// Selected tab key.
+///
+/// The variable is set when a tab header is clicked or a tab command selects another tab.
+///
+/// This property sets the [`SELECTED_VAR`].
+#[property(CONTEXT, default(SELECTED_VAR), widget_impl(Tabs))]
+pub fn selected(child: impl IntoUiNode, key: impl IntoVar<Txt>) -> UiNode {
+    with_context_var(child, SELECTED_VAR, key)
+}
+
+/// Tab header widget generator.
+///
+/// This property sets the [`HEADER_FN_VAR`].
+#[property(CONTEXT, default(HEADER_FN_VAR), widget_impl(Tabs))]
+pub fn header_fn(child: impl IntoUiNode, wgt_fn: impl IntoVar<WidgetFn<TabHeaderArgs>>) -> UiNode {
+    with_context_var(child, HEADER_FN_VAR, wgt_fn)
+}
+
+/// Tab content widget generator.
+///
+/// The content is generated for the selected tab, it is dropped when another tab is selected.
+///
+/// This property sets the [`CONTENT_FN_VAR`].
+#[property(CONTEXT, default(CONTENT_FN_VAR), widget_impl(Tabs))]
+pub fn content_fn(child: impl IntoUiNode, wgt_fn: impl IntoVar<WidgetFn<TabItem>>) -> UiNode {
+    with_context_var(child, CONTENT_FN_VAR, wgt_fn)
+}
+
+/// Overflow widget generator.
+///
+/// The overflow widget is shown after the header of the last tab that fits the header bar.
+///
+/// This property sets the [`OVERFLOW_FN_VAR`].
+#[property(CONTEXT, default(OVERFLOW_FN_VAR), widget_impl(Tabs))]
+pub fn overflow_fn(child: impl IntoUiNode, wgt_fn: impl IntoVar<WidgetFn<TabsOverflowArgs>>) -> UiNode {
+    with_context_var(child, OVERFLOW_FN_VAR, wgt_fn)
+}
+
+/// Default value of [`header_fn`].
+///
+/// A button that selects the tab on click, with a close button if the tab is closable.
+///
+/// [`header_fn`]: fn@header_fn
+pub fn default_header_fn(args: TabHeaderArgs) -> UiNode {
+    let key = args.tab.key.clone();
+    let is_selected = SELECTED_VAR.map(move |s| s == &key);
+    let key = args.tab.key.clone();
+    let tabs_id = args.tabs_id;
+    let close = if args.tab.closable {
+        let key = key.clone();
+        Button! {
+            style_fn = zng_wgt_button::LightStyle!();
+            zng_wgt_container::padding = (0, 4);
+            child = Text!("×");
+            on_click = hn!(|a: &ClickArgs| {
+                a.propagation.stop();
+                CLOSE_TAB_CMD.scoped(tabs_id).notify_param(key.clone());
+            });
+        }
+    } else {
+        UiNode::nil()
+    };
+    Button! {
+        style_fn = zng_wgt_button::LightStyle!();
+        access_role = AccessRole::Tab;
+        zng_wgt_access::selected = is_selected.clone();
+        child = Text!(args.tab.label.clone());
+        zng_wgt_container::child_end = close;
+        on_click = hn!(|a: &ClickArgs| {
+            a.propagation.stop();
+            SELECTED_VAR.set(key.clone());
+        });
+
+        when *#{is_selected} {
+            zng_wgt_fill::foreground_highlight = {
+                offsets: 0,
+                widths: (0, 0, 2, 0),
+                sides: colors::ACCENT_COLOR_VAR.rgba_into(),
+            };
+        }
+    }
+}
+
+/// Default value of [`overflow_fn`].
+///
+/// A button that opens a [`ContextMenu!`] with one item for each hidden tab.
+///
+/// [`overflow_fn`]: fn@overflow_fn
+/// [`ContextMenu!`]: struct@ContextMenu
+pub fn default_overflow_fn(args: TabsOverflowArgs) -> UiNode {
+    let selected_key = SELECTED_VAR.current_context();
+    Button! {
+        style_fn = zng_wgt_button::LightStyle!();
+        child = Text!("»");
+        on_click = hn!(|a: &ClickArgs| {
+            a.propagation.stop();
+            let items = args.hidden.get().into_iter().map(|t| {
+                let selected_key = selected_key.clone();
+                Button! {
+                    child = Text!(t.label.clone());
+                    on_click = hn!(|a: &ClickArgs| {
+                        a.propagation.stop();
+                        selected_key.set(t.key.clone());
+                    });
+                }
+            });
+            let _ = POPUP.open(ContextMenu!(items));
+        });
+    }
+}
+
+command! {
+    /// Select the next tab, wraps around to the first.
+    pub static NEXT_TAB_CMD {
+        l10n!: true,
+        name: "Next Tab",
+        info: "Select the next tab",
+        shortcut: shortcut!(CTRL + Tab),
+        shortcut_filter: ShortcutFilter::FOCUSED | ShortcutFilter::CMD_ENABLED,
+    };
+
+    /// Select the previous tab, wraps around to the last.
+    pub static PREV_TAB_CMD {
+        l10n!: true,
+        name: "Previous Tab",
+        info: "Select the previous tab",
+        shortcut: shortcut!(CTRL | SHIFT + Tab),
+        shortcut_filter: ShortcutFilter::FOCUSED | ShortcutFilter::CMD_ENABLED,
+    };
+
+    /// Close a tab.
+    ///
+    /// # Parameter
+    ///
+    /// This command supports an optional [`Txt`] parameter, the key of the tab to close. If not set closes the selected tab.
+    pub static CLOSE_TAB_CMD {
+        l10n!: true,
+        name: "Close Tab",
+        info: "Close the selected tab",
+        shortcut: shortcut!(CTRL + F4),
+        shortcut_filter: ShortcutFilter::FOCUSED | ShortcutFilter::CMD_ENABLED,
+    };
+}
+
+/// Tabs node.
+///
+/// Can be used directly to present tabs without declaring a tabs widget, the context variables are configured by
+/// the [`Tabs!`] properties. The node must be the child of a widget, the commands are scoped on that widget.
+///
+/// [`Tabs!`]: struct@Tabs
+pub fn node() -> UiNode {
+    let bar = Container! {
+        access_role = AccessRole::TabList;
+        child = header_bar_node();
+    };
+    let content = Container! {
+        access_role = AccessRole::TabPanel;
+        child = presenter_opt(
+            merge_var!(TABS_VAR, SELECTED_VAR, |tabs, key| tabs.iter().find(|t| &t.key == key).cloned()),
+            CONTENT_FN_VAR,
+        );
+    };
+    let child = child_top(content, bar);
+
+    let mut next_handle = CommandHandle::dummy();
+    let mut prev_handle = CommandHandle::dummy();
+    let mut close_handle = CommandHandle::dummy();
+    match_node(child, move |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_var(&TABS_VAR);
+            let id = WIDGET.id();
+            let has_tabs = !TABS_VAR.with(|t| t.is_empty());
+            next_handle = NEXT_TAB_CMD.scoped(id).subscribe(has_tabs);
+            prev_handle = PREV_TAB_CMD.scoped(id).subscribe(has_tabs);
+            close_handle = CLOSE_TAB_CMD.scoped(id).subscribe(has_tabs);
+        }
+        UiNodeOp::Deinit => {
+            next_handle = CommandHandle::dummy();
+            prev_handle = CommandHandle::dummy();
+            close_handle = CommandHandle::dummy();
+        }
+        UiNodeOp::Update { updates } => {
+            c.update(updates);
+
+            if TABS_VAR.is_new() {
+                let has_tabs = !TABS_VAR.with(|t| t.is_empty());
+                next_handle.enabled().set(has_tabs);
+                prev_handle.enabled().set(has_tabs);
+                close_handle.enabled().set(has_tabs);
+            }
+
+            let id = WIDGET.id();
+            NEXT_TAB_CMD.scoped(id).each_update(true, false, |args| {
+                args.propagation.stop();
+                select_offset(1);
+            });
+            PREV_TAB_CMD.scoped(id).each_update(true, false, |args| {
+                args.propagation.stop();
+                select_offset(-1);
+            });
+            CLOSE_TAB_CMD.scoped(id).each_update(true, false, |args| {
+                args.propagation.stop();
+                let key = args.param::<Txt>().cloned().unwrap_or_else(|| SELECTED_VAR.get());
+                close_tab(key);
+            });
+        }
+        _ => {}
+    })
+}
+
+fn select_offset(offset: isize) {
+    let tabs = TABS_VAR.get();
+    if tabs.is_empty() {
+        return;
+    }
+    let i = match SELECTED_VAR.with(|s| tabs.iter().position(|t| &t.key == s)) {
+        Some(i) => (i as isize + offset).rem_euclid(tabs.len() as isize) as usize,
+        None => 0,
+    };
+    SELECTED_VAR.set(tabs[i].key.clone());
+}
+
+fn close_tab(key: Txt) {
+    let tabs = TABS_VAR.get();
+    let Some(i) = tabs.iter().position(|t| t.key == key) else {
+        return;
+    };
+    if SELECTED_VAR.with(|s| s == &key) {
+        let next = tabs.get(i + 1).or_else(|| i.checked_sub(1).and_then(|i| tabs.get(i)));
+        SELECTED_VAR.set(next.map(|t| t.key.clone()).unwrap_or_default());
+    }
+    TABS_VAR.modify(move |t| {
+        if let Some(i) = t.iter().position(|t| t.key == key) {
+            t.remove(i);
+        }
+    });
+}
+
+/// Children are `[overflow, header_0, header_1, ..]`, headers are reused when the tabs are reordered.
+fn header_bar_node() -> UiNode {
+    let hidden = var(Vec::<TabItem>::new());
+    let mut items = vec![];
+    let mut header_ids = vec![];
+    let mut visible = vec![];
+    let mut dragging = None::<Txt>;
+
+    match_node(UiNode::nil(), move |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET
+                .sub_var(&TABS_VAR)
+                .sub_var(&HEADER_FN_VAR)
+                .sub_var(&OVERFLOW_FN_VAR)
+                .sub_var_layout(&SELECTED_VAR)
+                .sub_event(&MOUSE_INPUT_EVENT)
+                .sub_event(&MOUSE_MOVE_EVENT);
+
+            items = TABS_VAR.get();
+            *c.node() = generate_headers(&items, &hidden);
+            c.init();
+            header_ids = collect_header_ids(c.node_impl::<PanelList>());
+        }
+        UiNodeOp::Deinit => {
+            c.deinit();
+            *c.node() = UiNode::nil();
+            hidden.set(vec![]);
+            dragging = None;
+        }
+        UiNodeOp::Update { updates } => {
+            if HEADER_FN_VAR.is_new() || OVERFLOW_FN_VAR.is_new() {
+                c.node().deinit();
+                items = TABS_VAR.get();
+                *c.node() = generate_headers(&items, &hidden);
+                c.node().init();
+                header_ids = collect_header_ids(c.node_impl::<PanelList>());
+                WIDGET.update_info().layout().render();
+            } else if let Some(tabs) = TABS_VAR.get_new() {
+                c.update(updates);
+                sync_headers(c.node_impl::<PanelList>(), &mut items, tabs);
+                header_ids = collect_header_ids(c.node_impl::<PanelList>());
+                WIDGET.update_info().layout().render();
+            } else {
+                let mut changed = false;
+                c.update_list(updates, &mut changed);
+                if changed {
+                    WIDGET.layout();
+                }
+            }
+
+            MOUSE_INPUT_EVENT.each_update(false, |args| {
+                if !args.is_primary() {
+                    return;
+                }
+                if args.state == ButtonState::Pressed {
+                    dragging = header_ids
+                        .iter()
+                        .position(|id| id.map(|id| args.target.contains(id)).unwrap_or(false))
+                        .map(|i| items[i].key.clone());
+                } else {
+                    dragging = None;
+                }
+            });
+
+            let Some(key) = &dragging else {
+                return;
+            };
+            let Some(from) = items.iter().position(|t| &t.key == key) else {
+                return;
+            };
+            let Some(from_id) = header_ids[from] else {
+                return;
+            };
+            let mut drag_pos = None;
+            MOUSE_MOVE_EVENT.each_update(false, |args| {
+                if let Some(cap) = &args.capture
+                    && cap.target.contains(from_id)
+                {
+                    drag_pos = Some(args.position);
+                }
+            });
+            if let Some(pos) = drag_pos {
+                let tree = WINDOW.info();
+                let x = pos.to_px(tree.scale_factor()).x;
+                let center = |i: usize| header_ids[i].and_then(|id| tree.get(id)).map(|w| w.inner_bounds().center().x);
+
+                // move to the farthest visible header that has its center crossed
+                let mut to = from;
+                if let Some(from_center) = center(from) {
+                    for i in 0..items.len() {
+                        if i == from || !visible.get(i + 1).copied().unwrap_or(false) {
+                            continue;
+                        }
+                        if let Some(c) = center(i) {
+                            let crossed = (c > from_center && x > c) || (c < from_center && x < c);
+                            if crossed && i.abs_diff(from) > to.abs_diff(from) {
+                                to = i;
+                            }
+                        }
+                    }
+                }
+                if to != from {
+                    TABS_VAR.modify(move |t| {
+                        if from < t.len() && to < t.len() {
+                            let tab = t.remove(from);
+                            t.insert(to, tab);
+                        }
+                    });
+                }
+            }
+        }
+        UiNodeOp::Measure { wm, desired_size } => {
+            c.delegated();
+
+            let constraints = LAYOUT.constraints();
+            let child_constraints = constraints.with_new_min(Px(0), Px(0)).with_unbounded_x();
+            let mut size = PxSize::zero();
+            c.node_impl::<PanelList>().for_each_child(|i, c, _| {
+                if i > 0 {
+                    let s = LAYOUT.with_constraints(child_constraints, || c.measure(wm));
+                    size.width += s.width;
+                    size.height = size.height.max(s.height);
+                }
+            });
+            *desired_size = constraints.clamp_size(size);
+        }
+        UiNodeOp::Layout { wl, final_size } => {
+            c.delegated();
+
+            let constraints = LAYOUT.constraints();
+            let children = c.node_impl::<PanelList>();
+
+            let child_constraints = constraints.with_new_min(Px(0), Px(0)).with_unbounded_x();
+            let mut sizes = vec![];
+            children.for_each_child(|_, c, _| {
+                sizes.push(LAYOUT.with_constraints(child_constraints, || c.measure(&mut wl.to_measure(None))));
+            });
+
+            let selected = SELECTED_VAR.with(|s| items.iter().position(|t| &t.key == s));
+            let order = visible_order(&sizes, selected, constraints.x.max().unwrap_or(Px::MAX));
+
+            visible.clear();
+            visible.resize(sizes.len(), false);
+            let mut offsets = vec![None; sizes.len()];
+            let mut x = Px(0);
+            let mut height = Px(0);
+            for &i in &order {
+                visible[i] = true;
+                offsets[i] = Some(x);
+                x += sizes[i].width;
+                height = height.max(sizes[i].height);
+            }
+            let size = constraints.clamp_size(PxSize::new(x, height));
+            let is_rtl = LAYOUT.direction().is_rtl();
+
+            let new_hidden: Vec<_> = items
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !visible[i + 1])
+                .map(|(_, t)| t.clone())
+                .collect();
+            if hidden.with(|h| h != &new_hidden) {
+                hidden.set(new_hidden);
+            }
+
+            // collapse all, then layout only the visible children
+            wl.collapse_descendants();
+            children.layout_list(
+                wl,
+                |i, c, o, wl| {
+                    let Some(x) = offsets[i] else {
+                        return PxSize::zero();
+                    };
+                    let child_size = PxSize::new(sizes[i].width, size.height);
+                    let x = if is_rtl { size.width - x - child_size.width } else { x };
+                    let (_, define_ref_frame) =
+                        LAYOUT.with_constraints(PxConstraints2d::new_exact_size(child_size), || wl.with_child(|wl| c.layout(wl)));
+                    o.child_offset = PxVector::new(x, Px(0));
+                    o.define_reference_frame = define_ref_frame;
+                    child_size
+                },
+                |_, _| PxSize::zero(),
+            );
+            children.commit_data().request_render();
+
+            *final_size = size;
+        }
+        UiNodeOp::Render { frame } => {
+            c.delegated();
+            c.node_impl::<PanelList>().render_list(frame, |i, c, _, frame| {
+                if visible.get(i).copied().unwrap_or(false) {
+                    c.render(frame);
+                }
+            });
+        }
+        UiNodeOp::RenderUpdate { update } => {
+            c.delegated();
+            c.node_impl::<PanelList>().render_update_list(update, |i, c, _, update| {
+                if visible.get(i).copied().unwrap_or(false) {
+                    c.render_update(update);
+                }
+            });
+        }
+        _ => {}
+    })
+}
+
+fn tabs_id() -> WidgetId {
+    WIDGET.parent_id().unwrap_or_else(|| WIDGET.id())
+}
+
+fn generate_headers(tabs: &[TabItem], hidden: &Var<Vec<TabItem>>) -> UiNode {
+    let tabs_id = tabs_id();
+    let header_fn = HEADER_FN_VAR.get();
+    let mut children = ui_vec![OVERFLOW_FN_VAR.get()(TabsOverflowArgs {
+        tabs_id,
+        hidden: hidden.read_only(),
+    })];
+    for tab in tabs {
+        children.push(header_fn(TabHeaderArgs { tabs_id, tab: tab.clone() }));
+    }
+    PanelList::new(children).into_node()
+}
+
+/// Reuse the header nodes of tabs that did not change, generate and init headers for new tabs, deinit removed headers.
+fn sync_headers(panel: &mut PanelList, items: &mut Vec<TabItem>, tabs: Vec<TabItem>) {
+    let tabs_id = tabs_id();
+    let header_fn = HEADER_FN_VAR.get();
+
+    let (mut list, ..) = mem::replace(panel, PanelList::new(ui_vec![])).into_parts();
+    let children = list.downcast_mut::<UiVec>().expect("expected header `UiVec`");
+
+    let mut old: Vec<_> = mem::take(items).into_iter().zip(children.drain(1..)).collect();
+    for tab in &tabs {
+        let header = match old.iter().position(|(t, _)| t == tab) {
+            Some(i) => old.remove(i).1,
+            None => {
+                let mut header = header_fn(TabHeaderArgs { tabs_id, tab: tab.clone() });
+                header.init();
+                header
+            }
+        };
+        children.push(header);
+    }
+    for (_, mut header) in old {
+        header.deinit();
+    }
+
+    *items = tabs;
+    *panel = PanelList::new(list);
+}
+
+fn collect_header_ids(panel: &mut PanelList) -> Vec<Option<WidgetId>> {
+    let mut ids = vec![];
+    panel.for_each_child(|i, c, _| {
+        if i > 0 {
+            ids.push(c.as_widget().map(|mut w| w.id()));
+        }
+    });
+    ids
+}
+
+/// Computes the child indexes that are visible, in display order.
+///
+/// If all headers fit returns all headers, otherwise returns the headers that fit with the overflow widget, always
+/// including the selected header.
+fn visible_order(sizes: &[PxSize], selected: Option<usize>, available: Px) -> Vec<usize> {
+    let w = |i: usize| sizes[i].width;
+
+    let full = (1..sizes.len()).fold(Px(0), |a, i| a + w(i));
+    if full <= available {
+        return (1..sizes.len()).collect();
+    }
+
+    let available = available - w(0);
+    let mut start = 1;
+    let mut end = 1;
+    let mut width = Px(0);
+    while end < sizes.len() && width + w(end) <= available {
+        width += w(end);
+        end += 1;
+    }
+    if let Some(s) = selected.map(|s| s + 1)
+        && s >= end
+    {
+        // only headers before the selected one that fit
+        start = s;
+        end = s + 1;
+        width = w(s);
+        while start > 1 && width + w(start - 1) <= available {
+            start -= 1;
+            width += w(start);
+        }
+    }
+
+    let mut order: Vec<_> = (start..end).collect();
+    order.push(0);
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Overflow widget `20` wide, headers `30`.
+    fn sizes(headers: usize) -> Vec<PxSize> {
+        let mut r = vec![PxSize::new(Px(20), Px(10))];
+        r.extend((0..headers).map(|_| PxSize::new(Px(30), Px(10))));
+        r
+    }
+
+    #[test]
+    fn visible_order_fits() {
+        assert_eq!(visible_order(&sizes(3), None, Px(90)), vec![1, 2, 3]);
+        assert_eq!(visible_order(&sizes(3), Some(2), Px(1000)), vec![1, 2, 3]);
+        assert_eq!(visible_order(&sizes(0), None, Px(0)), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn visible_order_overflow() {
+        assert_eq!(visible_order(&sizes(5), None, Px(100)), vec![1, 2, 0]);
+        assert_eq!(visible_order(&sizes(5), Some(1), Px(100)), vec![1, 2, 0]);
+    }
+
+    #[test]
+    fn visible_order_selected_after_fit() {
+        assert_eq!(visible_order(&sizes(5), Some(4), Px(100)), vec![4, 5, 0]);
+        assert_eq!(visible_order(&sizes(5), Some(2), Px(100)), vec![2, 3, 0]);
+    }
+
+    #[test]
+    fn visible_order_no_space() {
+        assert_eq!(visible_order(&sizes(5), None, Px(30)), vec![0]);
+        // selected is always visible
+        assert_eq!(visible_order(&sizes(5), Some(2), Px(30)), vec![3, 0]);
+    }
+}
@@ -0,0 +1,20 @@
+DECREMENT_CMD =
+    .name = Decrementar
+
+INCREMENT_CMD =
+    .name = Incrementar
+
+TOGGLE_BOLD_CMD =
+    .info = Alternar negrito no texto selecionado
+    .name = Negrito
+
+TOGGLE_ITALIC_CMD =
+    .info = Alternar itálico no texto selecionado
+    .name = Itálico
+
+TOGGLE_OPTIONS_CMD =
+    .name = Alternar Opções
+
+TOGGLE_UNDERLINE_CMD =
+    .info = Alternar sublinhado no texto selecionado
+    .name = Sublinhado
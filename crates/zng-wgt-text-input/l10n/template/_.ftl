@@ -0,0 +1,22 @@
+### Auto generated by `cargo zng l10n`
+
+DECREMENT_CMD =
+    .name = Decrement
+
+INCREMENT_CMD =
+    .name = Increment
+
+TOGGLE_BOLD_CMD =
+    .info = Toggle bold format of the selected text
+    .name = Bold
+
+TOGGLE_ITALIC_CMD =
+    .info = Toggle italic format of the selected text
+    .name = Italic
+
+TOGGLE_OPTIONS_CMD =
+    .name = Toggle Options
+
+TOGGLE_UNDERLINE_CMD =
+    .info = Toggle underline format of the selected text
+    .name = Underline
@@ -0,0 +1,439 @@
+//! Combo box widget, properties and commands.
+
+use std::mem;
+
+use zng_app::event::{CommandHandle, command};
+use zng_ext_input::{
+    focus::{FOCUS, FOCUS_CHANGED_EVENT},
+    keyboard::{KEY_INPUT_EVENT, Key, KeyState},
+};
+use zng_wgt::{ICONS, Wgt, fuzzy_match, prelude::*};
+use zng_wgt_access::{AccessRole, access_role};
+use zng_wgt_container::Container;
+use zng_wgt_input::gesture::ClickArgs;
+use zng_wgt_layer::popup::{POPUP, Popup, PopupState};
+use zng_wgt_scroll::{
+    LazyMode, Scroll, ScrollMode,
+    cmd::{ScrollToMode, scroll_to},
+};
+use zng_wgt_stack::{Stack, StackDirection};
+use zng_wgt_text::{Text, node::TEXT};
+
+use crate::TextInput;
+
+/// Editable text input with a drop-down of options filtered by the text.
+///
+/// The [`options`] are filtered by fuzzy matching the text as the user types, the matches are shown in a popup
+/// ordered by relevance. The arrow keys move the highlighted option, `Enter` or a click selects it and `Escape`
+/// closes the popup. Only the options visible in the popup are inited, so large option lists are supported.
+///
+/// The free text is the `txt` variable and the [`selected`] variable is the option that is equal to the text, if any.
+///
+/// # Shorthand
+///
+/// The `ComboBox!` macro provides a shorthand init that sets the options, `ComboBox!(options)`.
+///
+/// [`options`]: fn@options
+/// [`selected`]: fn@selected
+#[widget($crate::combo_box::ComboBox {
+    ($options:expr) => {
+        options = $options;
+    };
+})]
+pub struct ComboBox(TextInput);
+impl ComboBox {
+    fn widget_intrinsic(&mut self) {
+        widget_set! {
+            self;
+            access_role = AccessRole::ComboBox;
+            zng_wgt_container::child_out_end = dropdown_button();
+        }
+
+        self.widget_builder().push_build_action(|wgt| {
+            wgt.push_intrinsic(NestGroup::CHILD_LAYOUT, "combo_box", combo_node);
+        });
+    }
+}
+
+command! {
+    /// Opens the combo box options popup, or closes it if it is open.
+    pub static TOGGLE_OPTIONS_CMD {
+        l10n!: true,
+        name: "Toggle Options",
+    };
+}
+
+context_var! {
+    /// Combo box options.
+    pub static OPTIONS_VAR: Vec<Txt> = vec![];
+
+    /// Option that is equal to the combo box text.
+    pub static SELECTED_VAR: Option<Txt> = None;
+
+    /// Maximum height of the options popup.
+    pub static OPTIONS_MAX_HEIGHT_VAR: Length = 240;
+}
+
+/// Combo box options.
+///
+/// This property sets the [`OPTIONS_VAR`].
+#[property(CONTEXT, default(OPTIONS_VAR), widget_impl(ComboBox))]
+pub fn options(child: impl IntoUiNode, options: impl IntoVar<Vec<Txt>>) -> UiNode {
+    with_context_var(child, OPTIONS_VAR, options)
+}
+
+/// Option that is equal to the text.
+///
+/// The variable is set to the matching option or `None` when the text changes, when the variable is set to an option the
+/// text is also set.
+///
+/// This property sets the [`SELECTED_VAR`].
+#[property(CONTEXT, default(SELECTED_VAR), widget_impl(ComboBox))]
+pub fn selected(child: impl IntoUiNode, option: impl IntoVar<Option<Txt>>) -> UiNode {
+    with_context_var(child, SELECTED_VAR, option)
+}
+
+/// Maximum height of the options popup.
+///
+/// The options popup scrolls when the options do not fit.
+///
+/// This property sets the [`OPTIONS_MAX_HEIGHT_VAR`].
+#[property(CONTEXT, default(OPTIONS_MAX_HEIGHT_VAR), widget_impl(ComboBox))]
+pub fn options_max_height(child: impl IntoUiNode, height: impl IntoVar<Length>) -> UiNode {
+    with_context_var(child, OPTIONS_MAX_HEIGHT_VAR, height)
+}
+
+/// Options that match `query`, best matches first.
+///
+/// Options are matched using [`fuzzy_match`], an empty query matches all options in the original order.
+fn filter_options(options: &[Txt], query: &str) -> Vec<Txt> {
+    if query.trim().is_empty() {
+        return options.to_vec();
+    }
+    let mut matches: Vec<_> = options.iter().filter_map(|o| fuzzy_match(query, o).map(|(s, _)| (s, o))).collect();
+    // stable sort, equal scores keep the options order
+    matches.sort_by(|a, b| b.0.cmp(&a.0));
+    matches.into_iter().map(|(_, o)| o.clone()).collect()
+}
+
+fn dropdown_button() -> UiNode {
+    zng_wgt_button::Button! {
+        zng_wgt::corner_radius = 0;
+        style_fn = zng_wgt_button::LightStyle!();
+        child = ICONS.get_or(["combo-box.dropdown", "material/rounded/keyboard-arrow-down", "keyboard-arrow-down"], || {
+            Text!("▾")
+        });
+        focusable = false;
+        on_click = hn!(|args| {
+            args.propagation.stop();
+            if let Some(combo) = WIDGET.info().parent() {
+                TOGGLE_OPTIONS_CMD.scoped(combo.id()).notify();
+            }
+        });
+    }
+}
+
+fn combo_node(child: impl IntoUiNode) -> UiNode {
+    let filtered = var(Vec::<Txt>::new());
+    let highlighted = var(None::<usize>);
+    let chosen = var(None::<Txt>);
+    let item_ids = var(Vec::<WidgetId>::new());
+    let mut popup_state = var(PopupState::Closed).read_only();
+    let mut _popup_handle = VarHandle::dummy();
+    let mut _toggle_handle = CommandHandle::dummy();
+    // text set by the widget, not typed
+    let mut skip_filter = false;
+
+    match_node(child, move |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET
+                .sub_var(&TEXT.resolved().txt)
+                .sub_var(&OPTIONS_VAR)
+                .sub_var(&SELECTED_VAR)
+                .sub_var(&chosen)
+                .sub_var(&highlighted)
+                .sub_event(&KEY_INPUT_EVENT)
+                .sub_event(&FOCUS_CHANGED_EVENT);
+
+            _toggle_handle = TOGGLE_OPTIONS_CMD.scoped(WIDGET.id()).subscribe(true);
+
+            if let Some(s) = SELECTED_VAR.get()
+                && TEXT.resolved().txt.with(|t| t != &s)
+            {
+                skip_filter = true;
+                TEXT.resolved().txt.set(s);
+            }
+        }
+        UiNodeOp::Deinit => {
+            if let PopupState::Open(id) = popup_state.get() {
+                POPUP.close_id(id);
+            }
+            popup_state = var(PopupState::Closed).read_only();
+            _popup_handle = VarHandle::dummy();
+            _toggle_handle = CommandHandle::dummy();
+        }
+        UiNodeOp::Info { info } => {
+            if let Some(mut a) = info.access() {
+                let is_open = !matches!(popup_state.get(), PopupState::Closed);
+                a.set_expanded(is_open);
+                if is_open
+                    && let Some(i) = highlighted.get()
+                    && let Some(id) = item_ids.with(|ids| ids.get(i).copied())
+                {
+                    a.set_active_descendant(id);
+                }
+            }
+        }
+        UiNodeOp::Update { updates } => {
+            let id = WIDGET.id();
+            let is_open = !matches!(popup_state.get(), PopupState::Closed);
+            let mut open = None::<bool>;
+            let mut show_all = false;
+
+            // handle before the text edit nodes, arrow keys are caret navigation for them.
+            KEY_INPUT_EVENT.each_update(false, |args| {
+                if args.state != KeyState::Pressed || !args.target.contains_enabled(id) {
+                    return;
+                }
+                match &args.key {
+                    Key::ArrowDown => {
+                        args.propagation.stop();
+                        if is_open {
+                            move_highlight(&filtered, &highlighted, 1);
+                        } else {
+                            open = Some(true);
+                            show_all = true;
+                        }
+                    }
+                    Key::ArrowUp if is_open => {
+                        args.propagation.stop();
+                        move_highlight(&filtered, &highlighted, -1);
+                    }
+                    Key::Enter if is_open => {
+                        if let Some(i) = highlighted.get()
+                            && let Some(o) = filtered.with(|f| f.get(i).cloned())
+                        {
+                            args.propagation.stop();
+                            chosen.set(Some(o));
+                        }
+                    }
+                    Key::Escape if is_open => {
+                        args.propagation.stop();
+                        open = Some(false);
+                    }
+                    _ => {}
+                }
+            });
+
+            c.update(updates);
+
+            TOGGLE_OPTIONS_CMD.scoped(id).each_update(true, false, |args| {
+                args.propagation.stop();
+                open = Some(!is_open);
+                show_all = true;
+                FOCUS.focus_widget(id, false);
+            });
+            FOCUS_CHANGED_EVENT.each_update(true, |args| {
+                if args.is_blur(id)
+                    && is_open
+                    && !matches!((popup_state.get(), &args.new_focus), (PopupState::Open(p), Some(f)) if f.contains(p))
+                {
+                    open = Some(false);
+                }
+            });
+
+            if let Some(Some(o)) = chosen.get_new() {
+                chosen.set(None);
+                skip_filter = true;
+                TEXT.resolved().txt.set(o.clone());
+                SELECTED_VAR.set(Some(o));
+                open = Some(false);
+                FOCUS.focus_widget(id, false);
+            } else if let Some(txt) = TEXT.resolved().txt.get_new() {
+                let s = OPTIONS_VAR.with(|o| o.iter().find(|o| *o == &txt).cloned());
+                if SELECTED_VAR.with(|v| v != &s) {
+                    SELECTED_VAR.set(s);
+                }
+                if !mem::take(&mut skip_filter) && open.is_none() {
+                    // user typed
+                    filtered.set(OPTIONS_VAR.with(|o| filter_options(o, &txt)));
+                    highlighted.set(Some(0));
+                    open = Some(filtered.with(|f| !f.is_empty()));
+                }
+            } else if let Some(Some(s)) = SELECTED_VAR.get_new()
+                && TEXT.resolved().txt.with(|t| t != &s)
+            {
+                skip_filter = true;
+                TEXT.resolved().txt.set(s);
+            }
+
+            if show_all && open == Some(true) {
+                let query = if SELECTED_VAR.with(|s| s.is_some()) {
+                    Txt::from_static("")
+                } else {
+                    TEXT.resolved().txt.get()
+                };
+                filtered.set(OPTIONS_VAR.with(|o| filter_options(o, &query)));
+                let s = SELECTED_VAR.get();
+                highlighted.set(Some(filtered.with(|f| f.iter().position(|o| Some(o) == s.as_ref())).unwrap_or(0)));
+            } else if OPTIONS_VAR.is_new() && is_open {
+                filtered.set(OPTIONS_VAR.with(|o| TEXT.resolved().txt.with(|t| filter_options(o, t))));
+                highlighted.set(Some(0));
+            }
+
+            match open {
+                Some(true) if !is_open => {
+                    popup_state = POPUP.open(options_popup(
+                        filtered.read_only(),
+                        highlighted.clone(),
+                        chosen.clone(),
+                        item_ids.clone(),
+                    ));
+                    _popup_handle = popup_state.subscribe(UpdateOp::Update, id);
+                    WIDGET.update_info();
+                }
+                Some(false) if is_open => {
+                    if let PopupState::Open(p) = popup_state.get() {
+                        POPUP.close_id(p);
+                    }
+                }
+                _ => {}
+            }
+            if popup_state.is_new() {
+                WIDGET.update_info();
+            }
+
+            if let Some(Some(i)) = highlighted.get_new() {
+                if let Some(item) = item_ids.with(|ids| ids.get(i).copied()) {
+                    scroll_to(item, ScrollToMode::minimal(0));
+                }
+                WIDGET.update_info();
+            }
+        }
+        _ => {}
+    })
+}
+
+fn move_highlight(filtered: &Var<Vec<Txt>>, highlighted: &Var<Option<usize>>, offset: isize) {
+    if let Some(i) = next_highlight(filtered.with(|f| f.len()), highlighted.get(), offset) {
+        highlighted.set(Some(i));
+    }
+}
+
+/// Highlighted index after moving by `offset`, or `None` if there are no options.
+fn next_highlight(len: usize, current: Option<usize>, offset: isize) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    Some(match current {
+        Some(i) => (i as isize + offset).clamp(0, len as isize - 1) as usize,
+        None => 0,
+    })
+}
+
+fn options_popup(
+    filtered: Var<Vec<Txt>>,
+    highlighted: Var<Option<usize>>,
+    chosen: Var<Option<Txt>>,
+    item_ids: Var<Vec<WidgetId>>,
+) -> UiNode {
+    Popup! {
+        access_role = AccessRole::ListBox;
+        zng_wgt_input::focus::focus_on_init = false;
+        child = Scroll! {
+            mode = ScrollMode::VERTICAL;
+            child_align = Align::FILL_TOP;
+            focusable = false;
+            zng_wgt_size_offset::max_height = OPTIONS_MAX_HEIGHT_VAR;
+            child = presenter(
+                filtered,
+                wgt_fn!(|options: Vec<Txt>| {
+                    let ids: Vec<_> = options.iter().map(|_| WidgetId::new_unique()).collect();
+                    item_ids.set(ids.clone());
+                    Stack! {
+                        direction = StackDirection::top_to_bottom();
+                        children = options
+                            .into_iter()
+                            .zip(ids)
+                            .enumerate()
+                            .map(|(i, (o, id))| option_item(i, id, o, highlighted.clone(), chosen.clone()))
+                            .collect::<UiVec>();
+                    }
+                }),
+            );
+        };
+    }
+}
+
+fn option_item(i: usize, id: WidgetId, option: Txt, highlighted: Var<Option<usize>>, chosen: Var<Option<Txt>>) -> UiNode {
+    let is_highlighted = highlighted.map(move |h| *h == Some(i));
+    let is_selected = SELECTED_VAR.map(clmv!(option, |s| s.as_ref() == Some(&option)));
+    Container! {
+        id;
+        access_role = AccessRole::Option;
+        zng_wgt_access::selected = is_selected;
+        zng_wgt_scroll::lazy = LazyMode::lazy_vertical(wgt_fn!(|_| Wgt! {
+            zng_wgt_size_offset::height = 24;
+        }));
+        zng_wgt_size_offset::min_height = 24;
+        padding = (0, 6);
+        child_align = Align::START;
+        child = Text!(option.clone());
+        zng_wgt_input::gesture::on_click = hn!(|args: &ClickArgs| {
+            args.propagation.stop();
+            chosen.set(Some(option.clone()));
+        });
+
+        when *#zng_wgt_input::is_hovered {
+            zng_wgt_fill::background_color = colors::ACCENT_COLOR_VAR.rgba_map(|c| c.with_alpha(15.pct()));
+        }
+        when *#{is_highlighted} {
+            zng_wgt_fill::background_color = colors::ACCENT_COLOR_VAR.rgba_map(|c| c.with_alpha(35.pct()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> Vec<Txt> {
+        ["Apple", "Banana", "Pineapple", "Apricot"]
+            .into_iter()
+            .map(Txt::from_static)
+            .collect()
+    }
+
+    #[test]
+    fn filter_options_empty_query() {
+        assert_eq!(filter_options(&options(), ""), options());
+        assert_eq!(filter_options(&options(), "  "), options());
+    }
+
+    #[test]
+    fn filter_options_ranking() {
+        // word start and consecutive matches first
+        assert_eq!(filter_options(&options(), "ap"), ["Apple", "Apricot", "Pineapple"]);
+        assert_eq!(filter_options(&options(), "APPLE"), ["Apple", "Pineapple"]);
+        assert_eq!(filter_options(&options(), "ban"), ["Banana"]);
+    }
+
+    #[test]
+    fn filter_options_no_match() {
+        assert!(filter_options(&options(), "xyz").is_empty());
+        assert!(filter_options(&[], "a").is_empty());
+    }
+
+    #[test]
+    fn next_highlight_clamp() {
+        assert_eq!(next_highlight(0, None, 1), None);
+        assert_eq!(next_highlight(0, Some(2), -1), None);
+        assert_eq!(next_highlight(3, None, 1), Some(0));
+        assert_eq!(next_highlight(3, None, -1), Some(0));
+        assert_eq!(next_highlight(3, Some(0), 1), Some(1));
+        assert_eq!(next_highlight(3, Some(2), 1), Some(2));
+        assert_eq!(next_highlight(3, Some(0), -1), Some(0));
+        // options changed
+        assert_eq!(next_highlight(2, Some(5), 1), Some(1));
+    }
+}
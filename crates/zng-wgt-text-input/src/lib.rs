@@ -11,7 +11,10 @@
 
 zng_wgt::enable_widget_macros!();
 
+pub mod combo_box;
 pub mod label;
+pub mod number_input;
+pub mod rich_text_edit;
 pub mod selectable;
 
 mod text_input;
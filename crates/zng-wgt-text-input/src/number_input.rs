@@ -0,0 +1,393 @@
+//! Number input widget, properties and commands.
+
+use zng_app::event::{CommandHandle, command};
+use zng_ext_input::{
+    focus::FOCUS_CHANGED_EVENT,
+    keyboard::{KEY_INPUT_EVENT, Key, KeyState},
+    mouse::{MOUSE_WHEEL_EVENT, MouseScrollDelta},
+};
+use zng_wgt::{ICONS, prelude::*};
+use zng_wgt_access::{AccessRole, access_role};
+use zng_wgt_data::{DATA, DataNoteHandle};
+use zng_wgt_text::{Text, TxtParseValue};
+
+use crate::TextInput;
+
+/// Numeric text input with step buttons.
+///
+/// The [`value`] is parsed from the text and clamped to the [`value_min`] and [`value_max`] range, values out of range
+/// and parse errors are set in [`DATA`] as error notes, the default text input style shows these notes. The value is
+/// also stepped by [`value_step`] on the increment and decrement buttons, on arrow up and down keys and on mouse wheel
+/// while the input is focused.
+///
+/// # Shorthand
+///
+/// The `NumberInput!` macro provides a shorthand init that sets the value, `NumberInput!(var(0i32))`.
+///
+/// [`value`]: fn@value
+/// [`value_min`]: fn@value_min
+/// [`value_max`]: fn@value_max
+/// [`value_step`]: fn@value_step
+#[widget($crate::number_input::NumberInput {
+    ($value:expr) => {
+        value = $value;
+    };
+})]
+pub struct NumberInput(TextInput);
+impl NumberInput {
+    fn widget_intrinsic(&mut self) {
+        widget_set! {
+            self;
+            access_role = AccessRole::SpinButton;
+            zng_wgt_container::child_out_start = step_button(false);
+            zng_wgt_container::child_out_end = step_button(true);
+        }
+    }
+}
+
+command! {
+    /// Increments the number input value by the [`value_step`].
+    ///
+    /// [`value_step`]: fn@value_step
+    pub static INCREMENT_CMD {
+        l10n!: true,
+        name: "Increment",
+    };
+
+    /// Decrements the number input value by the [`value_step`].
+    ///
+    /// [`value_step`]: fn@value_step
+    pub static DECREMENT_CMD {
+        l10n!: true,
+        name: "Decrement",
+    };
+}
+
+context_var! {
+    /// Minimum number input value.
+    pub static VALUE_MIN_VAR: f64 = f64::NEG_INFINITY;
+
+    /// Maximum number input value.
+    pub static VALUE_MAX_VAR: f64 = f64::INFINITY;
+
+    /// Number input increment and decrement amount.
+    pub static VALUE_STEP_VAR: f64 = 1.0;
+}
+
+/// Represents a number type that can be the value of a [`NumberInput!`].
+///
+/// This trait is implemented for all primitive integer and float types.
+///
+/// [`NumberInput!`]: struct@NumberInput
+pub trait NumberValue: TxtParseValue + Copy + PartialOrd {
+    /// Convert to `f64` for range and step computations.
+    fn to_f64(self) -> f64;
+    /// Convert from `f64`, rounds and saturates if the type cannot represent the value.
+    fn from_f64(value: f64) -> Self;
+}
+macro_rules! impl_number_value {
+    (int: $($int:ty),+; float: $($float:ty),+) => {
+        $(
+            impl NumberValue for $int {
+                #[allow(clippy::unnecessary_cast)]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+                fn from_f64(value: f64) -> Self {
+                    value.round() as Self
+                }
+            }
+        )+
+        $(
+            impl NumberValue for $float {
+                #[allow(clippy::unnecessary_cast)]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+                fn from_f64(value: f64) -> Self {
+                    value as Self
+                }
+            }
+        )+
+    };
+}
+impl_number_value! {
+    int: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize;
+    float: f32, f64
+}
+
+/// Number value that is parsed from the text and displayed as the text.
+///
+/// Parse errors and values out of the [`value_min`] and [`value_max`] range are set in [`DATA.invalidate`] and
+/// do not update the `value`, when the widget loses focus with an out of range value the value is clamped.
+///
+/// The value is also stepped by [`INCREMENT_CMD`], [`DECREMENT_CMD`], arrow up and down keys and mouse wheel
+/// while focused.
+///
+/// [`value_min`]: fn@value_min
+/// [`value_max`]: fn@value_max
+/// [`DATA.invalidate`]: zng_wgt_data::DATA::invalidate
+#[property(CHILD, widget_impl(NumberInput))]
+pub fn value<T: NumberValue>(child: impl IntoUiNode, value: impl IntoVar<T>) -> UiNode {
+    let value = value.into_var();
+    let parsed = var(value.get());
+    let child = number_node(child, value, parsed.clone());
+    zng_wgt_text::txt_parse(child, parsed)
+}
+
+/// Minimum value.
+///
+/// Is `f64::NEG_INFINITY` by default.
+///
+/// This property sets the [`VALUE_MIN_VAR`].
+#[property(CONTEXT, default(VALUE_MIN_VAR), widget_impl(NumberInput))]
+pub fn value_min(child: impl IntoUiNode, min: impl IntoVar<f64>) -> UiNode {
+    with_context_var(child, VALUE_MIN_VAR, min)
+}
+
+/// Maximum value.
+///
+/// Is `f64::INFINITY` by default.
+///
+/// This property sets the [`VALUE_MAX_VAR`].
+#[property(CONTEXT, default(VALUE_MAX_VAR), widget_impl(NumberInput))]
+pub fn value_max(child: impl IntoUiNode, max: impl IntoVar<f64>) -> UiNode {
+    with_context_var(child, VALUE_MAX_VAR, max)
+}
+
+/// Increment and decrement amount.
+///
+/// Is `1.0` by default.
+///
+/// This property sets the [`VALUE_STEP_VAR`].
+#[property(CONTEXT, default(VALUE_STEP_VAR), widget_impl(NumberInput))]
+pub fn value_step(child: impl IntoUiNode, step: impl IntoVar<f64>) -> UiNode {
+    with_context_var(child, VALUE_STEP_VAR, step)
+}
+
+fn step_button(increment: bool) -> UiNode {
+    let (cmd, icon) = if increment {
+        (
+            INCREMENT_CMD,
+            ICONS.get_or(["number-input.increment", "material/rounded/add", "add"], || Text!("+")),
+        )
+    } else {
+        (
+            DECREMENT_CMD,
+            ICONS.get_or(["number-input.decrement", "material/rounded/remove", "remove"], || Text!("−")),
+        )
+    };
+    zng_wgt_button::Button! {
+        zng_wgt::corner_radius = 0;
+        style_fn = zng_wgt_button::LightStyle!();
+        child = icon;
+        focusable = false;
+        on_click = hn!(|args| {
+            args.propagation.stop();
+            if let Some(input) = WIDGET.info().parent() {
+                cmd.scoped(input.id()).notify();
+            }
+        });
+    }
+}
+
+fn number_node<T: NumberValue>(child: impl IntoUiNode, value: Var<T>, parsed: Var<T>) -> UiNode {
+    let mut _range_error = DataNoteHandle::dummy();
+    let mut is_out_of_range = false;
+    let mut is_focused = false;
+    let mut _increment_handle = CommandHandle::dummy();
+    let mut _decrement_handle = CommandHandle::dummy();
+
+    let range = || value_range(VALUE_MIN_VAR.get(), VALUE_MAX_VAR.get());
+
+    match_node(child, move |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET
+                .sub_var(&value)
+                .sub_var(&parsed)
+                .sub_var(&VALUE_MIN_VAR)
+                .sub_var(&VALUE_MAX_VAR)
+                .sub_event(&KEY_INPUT_EVENT)
+                .sub_event(&MOUSE_WHEEL_EVENT)
+                .sub_event(&FOCUS_CHANGED_EVENT);
+
+            parsed.set(value.get());
+
+            let id = WIDGET.id();
+            _increment_handle = INCREMENT_CMD.scoped(id).subscribe(true);
+            _decrement_handle = DECREMENT_CMD.scoped(id).subscribe(true);
+        }
+        UiNodeOp::Deinit => {
+            _range_error = DataNoteHandle::dummy();
+            is_out_of_range = false;
+            _increment_handle = CommandHandle::dummy();
+            _decrement_handle = CommandHandle::dummy();
+        }
+        UiNodeOp::Info { info } => {
+            if let Some(mut a) = info.access() {
+                a.set_value(value.get().to_f64());
+                let (min, max) = range();
+                if min.is_finite() {
+                    a.set_value_min(min);
+                }
+                if max.is_finite() {
+                    a.set_value_max(max);
+                }
+            }
+        }
+        UiNodeOp::Update { updates } => {
+            let id = WIDGET.id();
+            let mut step = 0i32;
+
+            // handle before the text edit nodes, arrow keys are caret navigation for them.
+            KEY_INPUT_EVENT.each_update(false, |args| {
+                if args.state == KeyState::Pressed && args.target.contains_enabled(id) {
+                    match &args.key {
+                        Key::ArrowUp => {
+                            args.propagation.stop();
+                            step += 1;
+                        }
+                        Key::ArrowDown => {
+                            args.propagation.stop();
+                            step -= 1;
+                        }
+                        _ => {}
+                    }
+                }
+            });
+
+            c.update(updates);
+
+            INCREMENT_CMD.scoped(id).each_update(true, false, |args| {
+                args.propagation.stop();
+                step += 1;
+            });
+            DECREMENT_CMD.scoped(id).each_update(true, false, |args| {
+                args.propagation.stop();
+                step -= 1;
+            });
+            if is_focused {
+                MOUSE_WHEEL_EVENT.each_update(false, |args| {
+                    if args.target.contains_enabled(id)
+                        && let Some(MouseScrollDelta::LineDelta(_, y) | MouseScrollDelta::PixelDelta(_, y)) = args.scroll_delta(1.fct())
+                        && y != 0.0
+                    {
+                        args.propagation.stop();
+                        step += if y > 0.0 { 1 } else { -1 };
+                    }
+                });
+            }
+
+            let mut clamp = false;
+            FOCUS_CHANGED_EVENT.each_update(true, |args| {
+                if args.is_focus(id) {
+                    is_focused = true;
+                } else if args.is_blur(id) {
+                    is_focused = false;
+                    clamp = true;
+                }
+            });
+
+            let (min, max) = range();
+            if step != 0 {
+                let new = step_value(value.get(), VALUE_STEP_VAR.get(), step, (min, max));
+                value.set(new);
+                parsed.set(new);
+                _range_error = DataNoteHandle::dummy();
+                is_out_of_range = false;
+            } else if let Some(p) = parsed.get_new() {
+                let f = p.to_f64();
+                is_out_of_range = f < min || f > max;
+                if is_out_of_range {
+                    _range_error = DATA.invalidate(if min.is_finite() && max.is_finite() {
+                        formatx!("value must be between {min} and {max}")
+                    } else if min.is_finite() {
+                        formatx!("value must be greater or equal to {min}")
+                    } else {
+                        formatx!("value must be less or equal to {max}")
+                    });
+                } else {
+                    _range_error = DataNoteHandle::dummy();
+                    value.set(p);
+                }
+            } else if let Some(v) = value.get_new() {
+                parsed.set(v);
+            }
+
+            if clamp && is_out_of_range {
+                let f = parsed.get().to_f64().clamp(min, max);
+                let new = T::from_f64(f);
+                value.set(new);
+                parsed.set(new);
+                _range_error = DataNoteHandle::dummy();
+                is_out_of_range = false;
+            }
+
+            if value.is_new() || VALUE_MIN_VAR.is_new() || VALUE_MAX_VAR.is_new() {
+                WIDGET.update_info();
+            }
+        }
+        _ => {}
+    })
+}
+
+/// Gets the `(min, max)` range, `max` is never less than `min`.
+fn value_range(min: f64, max: f64) -> (f64, f64) {
+    (min, max.max(min))
+}
+
+/// Apply `count` steps to `value` and clamp to the `(min, max)` range.
+fn step_value<T: NumberValue>(value: T, step: f64, count: i32, (min, max): (f64, f64)) -> T {
+    let new = value.to_f64() + step * count as f64;
+    T::from_f64(new.clamp(min, max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(i32::from_txt(&Txt::from("-12")), Ok(-12));
+        assert!(i32::from_txt(&Txt::from("1.5")).is_err());
+        assert!(u8::from_txt(&Txt::from("256")).is_err());
+        assert!(u8::from_txt(&Txt::from("")).is_err());
+        assert_eq!(f64::from_txt(&Txt::from("1.5")), Ok(1.5));
+        assert_eq!(TxtParseValue::to_txt(&1.5f32), "1.5");
+    }
+
+    #[test]
+    fn from_f64_round_saturate() {
+        assert_eq!(i32::from_f64(2.4), 2);
+        assert_eq!(i32::from_f64(2.5), 3);
+        assert_eq!(i32::from_f64(-2.5), -3);
+        assert_eq!(u8::from_f64(300.0), 255);
+        assert_eq!(u8::from_f64(-1.0), 0);
+        assert_eq!(f32::from_f64(0.5), 0.5);
+    }
+
+    #[test]
+    fn range() {
+        assert_eq!(value_range(0.0, 10.0), (0.0, 10.0));
+        assert_eq!(value_range(10.0, 0.0), (10.0, 10.0));
+        assert_eq!(value_range(f64::NEG_INFINITY, f64::INFINITY), (f64::NEG_INFINITY, f64::INFINITY));
+    }
+
+    #[test]
+    fn step() {
+        let range = (0.0, 10.0);
+        assert_eq!(step_value(5i32, 1.0, 1, range), 6);
+        assert_eq!(step_value(5i32, 1.0, -1, range), 4);
+        assert_eq!(step_value(5i32, 2.0, 3, range), 10);
+        assert_eq!(step_value(0i32, 1.0, -1, range), 0);
+        assert_eq!(step_value(1.0f64, 0.25, 2, range), 1.5);
+    }
+
+    #[test]
+    fn step_saturate() {
+        let range = value_range(f64::NEG_INFINITY, f64::INFINITY);
+        assert_eq!(step_value(0u8, 1.0, -1, range), 0);
+        assert_eq!(step_value(255u8, 1.0, 1, range), 255);
+        assert_eq!(step_value(i64::MAX, 1.0, 1, range), i64::MAX);
+    }
+}
@@ -0,0 +1,742 @@
+//! Rich text editor widget, commands and properties.
+
+use std::{fmt, ops, sync::Arc};
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use zng_app::event::command;
+use zng_ext_font::{CaretIndex, FontStyle, FontWeight};
+use zng_ext_undo::{UNDO, UndoOp};
+use zng_wgt::prelude::*;
+use zng_wgt_access::{AccessRole, access_role};
+use zng_wgt_style::{Style, StyleMix, impl_style_fn};
+use zng_wgt_text::{
+    FONT_STYLE_VAR, FONT_WEIGHT_VAR, Text, UNDERLINE_STYLE_VAR, UNDERLINE_THICKNESS_VAR, accepts_enter,
+    cmd::{SELECT_CMD, TextSelectOp},
+    node::TEXT,
+    rich_text, txt_editable, txt_selectable,
+};
+use zng_wgt_undo::{UndoMix, undo_limit, undo_scope};
+use zng_wgt_wrap::Wrap;
+
+/// Rich text editor widget.
+///
+/// The [`runs`] variable is the formatted document, each [`TextRun`] is presented by an editable `Text!` inside a rich text
+/// context, so the caret and selection move across the runs. Typing edits the focused run and the formatting commands
+/// ([`TOGGLE_BOLD_CMD`], [`TOGGLE_ITALIC_CMD`] and [`TOGGLE_UNDERLINE_CMD`]) toggle the format over the selection.
+///
+/// The document variable is modified by the widget, so it must be read-write. The [`TextRuns`] type is serializable and
+/// can be converted to markdown for export.
+///
+/// # Undo/Redo
+///
+/// The widget is an undo scope, typing and formatting changes can be undone.
+///
+/// # Shorthand
+///
+/// The `RichTextEdit!` macro provides a shorthand init that sets the document, `RichTextEdit!(runs)`.
+///
+/// [`runs`]: fn@runs
+#[widget($crate::rich_text_edit::RichTextEdit {
+    ($runs:expr) => {
+        runs = $runs;
+    };
+})]
+pub struct RichTextEdit(StyleMix<UndoMix<WidgetBase>>);
+impl RichTextEdit {
+    fn widget_intrinsic(&mut self) {
+        self.style_intrinsic(STYLE_FN_VAR, property_id!(self::style_fn));
+
+        widget_set! {
+            self;
+            access_role = AccessRole::TextInput;
+            rich_text = true;
+            txt_editable = true;
+            txt_selectable = true;
+            accepts_enter = true;
+            undo_scope = true;
+            undo_limit = 100;
+        }
+
+        self.widget_builder().push_build_action(|wgt| {
+            wgt.set_child(node());
+        });
+    }
+}
+impl_style_fn!(RichTextEdit, DefaultStyle);
+
+/// Rich text editor default style.
+#[widget($crate::rich_text_edit::DefaultStyle)]
+pub struct DefaultStyle(Style);
+impl DefaultStyle {
+    fn widget_intrinsic(&mut self) {
+        use zng_wgt::{base_color, border};
+        use zng_wgt_container::*;
+        use zng_wgt_fill::*;
+        use zng_wgt_input::{focus::is_focus_within, *};
+
+        widget_set! {
+            self;
+            replace = true;
+            padding = (7, 10);
+            cursor = CursorIcon::Text;
+            base_color = light_dark(rgb(0.88, 0.88, 0.88), rgb(0.12, 0.12, 0.12));
+            background_color = colors::BASE_COLOR_VAR.rgba();
+            border = {
+                widths: 1,
+                sides: colors::BASE_COLOR_VAR.shade_fct_into(0.20),
+            };
+            zng_wgt_text::selection_color = colors::ACCENT_COLOR_VAR.rgba_map(|c| c.with_alpha(30.pct()));
+
+            when *#is_focus_within {
+                border = {
+                    widths: 1,
+                    sides: colors::ACCENT_COLOR_VAR.rgba_into(),
+                };
+            }
+        }
+    }
+}
+
+/// Inline text format.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TextFormat {
+    /// Bold font weight.
+    Bold,
+    /// Italic font style.
+    Italic,
+    /// Underline.
+    Underline,
+}
+
+/// Text segment with the same format.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct TextRun {
+    /// Run text.
+    pub text: Txt,
+    /// If the text is bold.
+    pub bold: bool,
+    /// If the text is italic.
+    pub italic: bool,
+    /// If the text is underlined.
+    pub underline: bool,
+}
+impl TextRun {
+    /// New run without format.
+    pub fn new(text: impl Into<Txt>) -> Self {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Enable the `format`.
+    pub fn with(mut self, format: TextFormat) -> Self {
+        self.set(format, true);
+        self
+    }
+
+    /// If the `format` is enabled.
+    pub fn has(&self, format: TextFormat) -> bool {
+        match format {
+            TextFormat::Bold => self.bold,
+            TextFormat::Italic => self.italic,
+            TextFormat::Underline => self.underline,
+        }
+    }
+
+    /// Enable or disable the `format`.
+    pub fn set(&mut self, format: TextFormat, enabled: bool) {
+        match format {
+            TextFormat::Bold => self.bold = enabled,
+            TextFormat::Italic => self.italic = enabled,
+            TextFormat::Underline => self.underline = enabled,
+        }
+    }
+
+    /// If the run has the same format as `other`.
+    pub fn same_format(&self, other: &TextRun) -> bool {
+        self.bold == other.bold && self.italic == other.italic && self.underline == other.underline
+    }
+}
+
+/// Formatted text document, a sequence of text runs.
+///
+/// Text ranges are byte ranges in the full text, the concatenation of all runs.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct TextRuns {
+    /// Runs in the text order.
+    pub runs: Vec<TextRun>,
+}
+impl TextRuns {
+    /// New from runs.
+    pub fn new(runs: impl IntoIterator<Item = TextRun>) -> Self {
+        Self {
+            runs: runs.into_iter().collect(),
+        }
+    }
+
+    /// New with a single run without format.
+    pub fn plain(text: impl Into<Txt>) -> Self {
+        Self::new([TextRun::new(text)])
+    }
+
+    /// Full text length in bytes.
+    pub fn len(&self) -> usize {
+        self.runs.iter().map(|r| r.text.len()).sum()
+    }
+
+    /// If the full text is empty.
+    pub fn is_empty(&self) -> bool {
+        self.runs.iter().all(|r| r.text.is_empty())
+    }
+
+    /// Full text without format.
+    pub fn to_txt(&self) -> Txt {
+        let mut txt = String::with_capacity(self.len());
+        for r in &self.runs {
+            txt.push_str(&r.text);
+        }
+        txt.into()
+    }
+
+    /// Full text formatted as markdown.
+    ///
+    /// Bold and italic use the emphasis markers, underline uses the `<u>` HTML tag.
+    pub fn to_markdown(&self) -> Txt {
+        let mut md = String::with_capacity(self.len());
+        for r in &self.runs {
+            let text = escape_markdown(&r.text);
+            let start = text.len() - text.trim_start().len();
+            let end = text.trim_end().len();
+            if start >= end || !(r.bold || r.italic || r.underline) {
+                md.push_str(&text);
+                continue;
+            }
+
+            md.push_str(&text[..start]);
+            if r.underline {
+                md.push_str("<u>");
+            }
+            if r.bold {
+                md.push_str("**");
+            }
+            if r.italic {
+                md.push('*');
+            }
+            md.push_str(&text[start..end]);
+            if r.italic {
+                md.push('*');
+            }
+            if r.bold {
+                md.push_str("**");
+            }
+            if r.underline {
+                md.push_str("</u>");
+            }
+            md.push_str(&text[end..]);
+        }
+        md.into()
+    }
+
+    /// If all text in the `range` has the `format`.
+    ///
+    /// Returns `false` if the range is empty.
+    pub fn has_format(&self, range: ops::Range<usize>, format: TextFormat) -> bool {
+        let mut offset = 0;
+        let mut any = false;
+        for r in &self.runs {
+            let r_range = offset..offset + r.text.len();
+            offset = r_range.end;
+            if r_range.start < range.end && range.start < r_range.end {
+                if !r.has(format) {
+                    return false;
+                }
+                any = true;
+            }
+        }
+        any
+    }
+
+    /// Toggle the `format` over the text `range`.
+    ///
+    /// If all text in the range has the format it is removed, otherwise it is enabled for all text in the range.
+    pub fn toggle_format(&mut self, range: ops::Range<usize>, format: TextFormat) {
+        if range.is_empty() {
+            return;
+        }
+        let enable = !self.has_format(range.clone(), format);
+        let start = self.split_at(range.start);
+        let end = self.split_at(range.end);
+        for r in &mut self.runs[start..end] {
+            r.set(format, enable);
+        }
+        self.normalize();
+    }
+
+    /// Remove empty runs and merge sequential runs with the same format.
+    pub fn normalize(&mut self) {
+        let mut runs: Vec<TextRun> = Vec::with_capacity(self.runs.len());
+        for r in self.runs.drain(..) {
+            if r.text.is_empty() {
+                continue;
+            }
+            if let Some(last) = runs.last_mut()
+                && last.same_format(&r)
+            {
+                last.text.push_str(&r.text);
+            } else {
+                runs.push(r);
+            }
+        }
+        self.runs = runs;
+    }
+
+    /// Split the run that contains the byte `index`, returns the index of the run that starts at `index`.
+    fn split_at(&mut self, index: usize) -> usize {
+        let mut offset = 0;
+        for i in 0..self.runs.len() {
+            let len = self.runs[i].text.len();
+            if index <= offset {
+                return i;
+            }
+            if index < offset + len {
+                let run = &mut self.runs[i];
+                let mut at = index - offset;
+                while !run.text.is_char_boundary(at) {
+                    at -= 1;
+                }
+                if at == 0 {
+                    return i;
+                }
+                let second = TextRun {
+                    text: Txt::from_str(&run.text[at..]),
+                    ..run.clone()
+                };
+                run.text = Txt::from_str(&run.text[..at]);
+                self.runs.insert(i + 1, second);
+                return i + 1;
+            }
+            offset += len;
+        }
+        self.runs.len()
+    }
+
+    /// Run index and local byte index of the full text byte `index`.
+    ///
+    /// If `index` is at a run boundary gets the start of the next run, or the end of the previous run if `prefer_end` is `true`.
+    fn locate(&self, index: usize, prefer_end: bool) -> Option<(usize, usize)> {
+        let mut offset = 0;
+        for (i, r) in self.runs.iter().enumerate() {
+            let end = offset + r.text.len();
+            if index < end || (prefer_end && index == end) {
+                return Some((i, index - offset));
+            }
+            offset = end;
+        }
+        if index == offset {
+            self.runs.len().checked_sub(1).map(|i| (i, self.runs[i].text.len()))
+        } else {
+            None
+        }
+    }
+
+    /// Format of each run, an empty document is a single run without format.
+    fn structure(&self) -> Vec<TextRun> {
+        if self.runs.is_empty() {
+            return vec![TextRun::default()];
+        }
+        self.runs
+            .iter()
+            .map(|r| TextRun {
+                text: Txt::from_static(""),
+                ..r.clone()
+            })
+            .collect()
+    }
+}
+impl From<Txt> for TextRuns {
+    fn from(text: Txt) -> Self {
+        TextRuns::plain(text)
+    }
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut r = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']' | '<' | '>' | '#') {
+            r.push('\\');
+        }
+        r.push(c);
+    }
+    r
+}
+
+context_var! {
+    /// Rich text editor document.
+    pub static RUNS_VAR: TextRuns = TextRuns::default();
+}
+
+/// Rich text editor document.
+///
+/// The variable is modified by typing and formatting, so it must be read-write for that.
+///
+/// This property sets the [`RUNS_VAR`].
+#[property(CONTEXT, default(RUNS_VAR), widget_impl(RichTextEdit))]
+pub fn runs(child: impl IntoUiNode, runs: impl IntoVar<TextRuns>) -> UiNode {
+    with_context_var(child, RUNS_VAR, runs)
+}
+
+command! {
+    /// Toggle bold format over the selected text.
+    pub static TOGGLE_BOLD_CMD {
+        l10n!: true,
+        name: "Bold",
+        info: "Toggle bold format of the selected text",
+        shortcut: shortcut!(CTRL + 'B'),
+        shortcut_filter: ShortcutFilter::FOCUSED | ShortcutFilter::CMD_ENABLED,
+    };
+
+    /// Toggle italic format over the selected text.
+    pub static TOGGLE_ITALIC_CMD {
+        l10n!: true,
+        name: "Italic",
+        info: "Toggle italic format of the selected text",
+        shortcut: shortcut!(CTRL + 'I'),
+        shortcut_filter: ShortcutFilter::FOCUSED | ShortcutFilter::CMD_ENABLED,
+    };
+
+    /// Toggle underline format over the selected text.
+    pub static TOGGLE_UNDERLINE_CMD {
+        l10n!: true,
+        name: "Underline",
+        info: "Toggle underline format of the selected text",
+        shortcut: shortcut!(CTRL + 'U'),
+        shortcut_filter: ShortcutFilter::FOCUSED | ShortcutFilter::CMD_ENABLED,
+    };
+}
+
+/// Rich text editor node.
+///
+/// Generates a `Text!` for each run in the [`RUNS_VAR`] and handles the format commands scoped on the parent widget. The
+/// parent widget must enable [`rich_text`] and [`txt_editable`].
+///
+/// [`rich_text`]: fn@rich_text
+/// [`txt_editable`]: fn@txt_editable
+pub fn node() -> UiNode {
+    let selections = RunSelections::default();
+    let mut structure = vec![];
+    let mut run_ids = vec![];
+    let mut pending_selection = None;
+    let mut restore_selection = None;
+    let mut _cmd_handles = vec![];
+    match_node(UiNode::nil(), move |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_var(&RUNS_VAR);
+
+            structure = RUNS_VAR.with(TextRuns::structure);
+            *c.node() = runs_panel(&structure, &mut run_ids, &selections);
+            c.init();
+
+            let id = WIDGET.id();
+            _cmd_handles = vec![
+                TOGGLE_BOLD_CMD.scoped(id).subscribe(true),
+                TOGGLE_ITALIC_CMD.scoped(id).subscribe(true),
+                TOGGLE_UNDERLINE_CMD.scoped(id).subscribe(true),
+            ];
+        }
+        UiNodeOp::Deinit => {
+            c.deinit();
+            *c.node() = UiNode::nil();
+            _cmd_handles = vec![];
+            pending_selection = None;
+            restore_selection = None;
+        }
+        UiNodeOp::Info { info } => {
+            c.info(info);
+            if let Some(range) = restore_selection.take() {
+                select_range(range, &run_ids);
+            }
+        }
+        UiNodeOp::Update { .. } => {
+            let id = WIDGET.id();
+            for (cmd, format) in [
+                (TOGGLE_BOLD_CMD, TextFormat::Bold),
+                (TOGGLE_ITALIC_CMD, TextFormat::Italic),
+                (TOGGLE_UNDERLINE_CMD, TextFormat::Underline),
+            ] {
+                cmd.scoped(id).each_update(true, false, |args| {
+                    args.propagation.stop();
+                    if let Some(range) = toggle_selection_format(cmd, format, &run_ids, &selections) {
+                        pending_selection = Some(range);
+                    }
+                });
+            }
+
+            if RUNS_VAR.is_new() {
+                let new_structure = RUNS_VAR.with(TextRuns::structure);
+                if new_structure != structure {
+                    structure = new_structure;
+                    c.node().deinit();
+                    *c.node() = runs_panel(&structure, &mut run_ids, &selections);
+                    c.node().init();
+                    restore_selection = pending_selection.take();
+                    WIDGET.update_info().layout().render();
+                }
+            }
+        }
+        _ => {}
+    })
+}
+
+fn runs_panel(structure: &[TextRun], run_ids: &mut Vec<WidgetId>, selections: &RunSelections) -> UiNode {
+    let runs = RUNS_VAR.current_context();
+    // reuse the IDs so that the focused run keeps focus
+    run_ids.truncate(structure.len());
+    while run_ids.len() < structure.len() {
+        run_ids.push(WidgetId::new_unique());
+    }
+    let children: UiVec = structure
+        .iter()
+        .enumerate()
+        .map(|(i, format)| run_text(run_ids[i], i, format, &runs, selections))
+        .collect();
+    Wrap! {
+        rich_text = true;
+        children;
+    }
+}
+
+fn run_text(id: WidgetId, i: usize, format: &TextRun, runs: &Var<TextRuns>, selections: &RunSelections) -> UiNode {
+    let txt = runs.map_bidi_modify(
+        move |r| r.runs.get(i).map(|r| r.text.clone()).unwrap_or_default(),
+        move |t, r| match r.runs.get(i) {
+            Some(run) if &run.text != t => r.runs[i].text = t.clone(),
+            None if r.runs.is_empty() && !t.is_empty() => r.runs.push(TextRun::new(t.clone())),
+            _ => {}
+        },
+    );
+    let bold = format.bold;
+    let italic = format.italic;
+    let underline = format.underline;
+    Text! {
+        id;
+        txt;
+        font_weight = FONT_WEIGHT_VAR.current_context().map(move |w| if bold { FontWeight::BOLD } else { *w });
+        font_style = FONT_STYLE_VAR.current_context().map(move |s| if italic { FontStyle::Italic } else { *s });
+        zng_wgt_text::underline = UNDERLINE_THICKNESS_VAR
+            .current_context()
+            .map(move |t| if underline { Length::from(1) } else { t.clone() }),
+            UNDERLINE_STYLE_VAR
+            .current_context()
+            .map(move |s| if underline { LineStyle::Solid } else { *s });
+        track_selection = selections.clone();
+    }
+}
+
+/// Toggle the format over the selected text, returns the full text range if the document changed.
+///
+/// Does nothing if there is no selection.
+fn toggle_selection_format(
+    cmd: Command,
+    format: TextFormat,
+    run_ids: &[WidgetId],
+    selections: &RunSelections,
+) -> Option<ops::Range<usize>> {
+    let leaves: Vec<WidgetId> = TEXT.rich().selection().map(|w| w.id()).collect();
+    let (first, last) = (leaves.first()?, leaves.last()?);
+
+    let runs = RUNS_VAR.current_context();
+    let old = runs.get();
+    let selections = selections.0.lock();
+    let offset = |id: &WidgetId| {
+        let i = run_ids.iter().position(|r| r == id)?;
+        let offset: usize = old.runs[..i.min(old.runs.len())].iter().map(|r| r.text.len()).sum();
+        let len = old.runs.get(i).map(|r| r.text.len()).unwrap_or(0);
+        Some((offset, len))
+    };
+    let (first_offset, _) = offset(first)?;
+    let (last_offset, last_len) = offset(last)?;
+    let start = first_offset + selections.get(first).map(|r| r.start).unwrap_or(0);
+    let end = last_offset + selections.get(last).map(|r| r.end).unwrap_or(last_len);
+    if start >= end {
+        return None;
+    }
+
+    let mut new = old.clone();
+    new.toggle_format(start..end, format);
+    if new == old {
+        return None;
+    }
+    UNDO.run_op(cmd.name(), move |op| match op {
+        UndoOp::Undo => runs.set(old.clone()),
+        UndoOp::Redo => runs.set(new.clone()),
+    });
+    Some(start..end)
+}
+
+/// Select the full text `range` in the run widgets, the caret is placed at the end.
+fn select_range(range: ops::Range<usize>, run_ids: &[WidgetId]) {
+    let (start, end) = RUNS_VAR.with(|r| (r.locate(range.start, false), r.locate(range.end, true)));
+    let (Some((start_i, start)), Some((end_i, end))) = (start, end) else {
+        return;
+    };
+    let (Some(&start_id), Some(&end_id)) = (run_ids.get(start_i), run_ids.get(end_i)) else {
+        return;
+    };
+    SELECT_CMD.scoped(end_id).notify_param(TextSelectOp::new_rich(
+        move |_| (end_id, end),
+        |index| (CaretIndex { index, line: 0 }, ()),
+        move |_, ()| Some((start_id, start)),
+        |index| Some(CaretIndex { index, line: 0 }),
+    ));
+}
+
+/// Local selection of each run text.
+#[derive(Clone, Default)]
+struct RunSelections(Arc<Mutex<IdMap<WidgetId, ops::Range<usize>>>>);
+impl fmt::Debug for RunSelections {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RunSelections").finish_non_exhaustive()
+    }
+}
+impl PartialEq for RunSelections {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Records the text selection of the run widget.
+#[property(CHILD_LAYOUT)]
+fn track_selection(child: impl IntoUiNode, selections: impl IntoValue<RunSelections>) -> UiNode {
+    let selections = selections.into();
+    let record = move |selections: &RunSelections| {
+        let range = TEXT.resolved().caret.selection_char_range();
+        let mut s = selections.0.lock();
+        match range {
+            Some(r) => {
+                s.insert(WIDGET.id(), r);
+            }
+            None => {
+                s.remove(&WIDGET.id());
+            }
+        }
+    };
+    match_node(child, move |c, op| match op {
+        UiNodeOp::Deinit => {
+            selections.0.lock().remove(&WIDGET.id());
+        }
+        UiNodeOp::Update { updates } => {
+            c.update(updates);
+            record(&selections);
+        }
+        UiNodeOp::Layout { wl, final_size } => {
+            *final_size = c.layout(wl);
+            record(&selections);
+        }
+        _ => {}
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_at() {
+        let mut runs = TextRuns::new([TextRun::new("abc"), TextRun::new("def").with(TextFormat::Bold)]);
+
+        assert_eq!(0, runs.split_at(0));
+        assert_eq!(1, runs.split_at(3));
+        assert_eq!(2, runs.runs.len());
+
+        assert_eq!(2, runs.split_at(4));
+        assert_eq!(
+            runs.runs,
+            vec![
+                TextRun::new("abc"),
+                TextRun::new("d").with(TextFormat::Bold),
+                TextRun::new("ef").with(TextFormat::Bold),
+            ]
+        );
+
+        assert_eq!(3, runs.split_at(6));
+        assert_eq!(3, runs.split_at(100));
+    }
+
+    #[test]
+    fn split_at_char_boundary() {
+        let mut runs = TextRuns::plain("aéb");
+        // 'é' is two bytes, split inside it snaps to the char start
+        assert_eq!(1, runs.split_at(2));
+        assert_eq!(runs.runs, vec![TextRun::new("a"), TextRun::new("éb")]);
+    }
+
+    #[test]
+    fn toggle_format() {
+        let mut runs = TextRuns::plain("hello world");
+
+        runs.toggle_format(6..11, TextFormat::Bold);
+        assert_eq!(
+            runs.runs,
+            vec![TextRun::new("hello "), TextRun::new("world").with(TextFormat::Bold)]
+        );
+
+        // partially bold range enables for all
+        runs.toggle_format(4..11, TextFormat::Bold);
+        assert_eq!(
+            runs.runs,
+            vec![TextRun::new("hell"), TextRun::new("o world").with(TextFormat::Bold)]
+        );
+
+        // fully bold range disables
+        runs.toggle_format(0..11, TextFormat::Bold);
+        runs.toggle_format(0..11, TextFormat::Bold);
+        assert_eq!(runs, TextRuns::plain("hello world"));
+
+        // empty range does nothing
+        runs.toggle_format(3..3, TextFormat::Italic);
+        assert_eq!(runs, TextRuns::plain("hello world"));
+    }
+
+    #[test]
+    fn toggle_format_normalizes() {
+        let mut runs = TextRuns::new([
+            TextRun::new("a").with(TextFormat::Italic),
+            TextRun::new("b"),
+            TextRun::new("c").with(TextFormat::Italic),
+        ]);
+        runs.toggle_format(1..2, TextFormat::Italic);
+        assert_eq!(runs.runs, vec![TextRun::new("abc").with(TextFormat::Italic)]);
+    }
+
+    #[test]
+    fn to_markdown() {
+        let runs = TextRuns::new([
+            TextRun::new("plain "),
+            TextRun::new("bold ").with(TextFormat::Bold),
+            TextRun::new("both").with(TextFormat::Bold).with(TextFormat::Italic),
+            TextRun::new(" "),
+            TextRun::new("under").with(TextFormat::Underline),
+        ]);
+        assert_eq!("plain **bold** ***both*** <u>under</u>", runs.to_markdown());
+    }
+
+    #[test]
+    fn to_markdown_escape() {
+        let runs = TextRuns::new([TextRun::new("a*b_c "), TextRun::new("  ").with(TextFormat::Bold)]);
+        assert_eq!("a\\*b\\_c   ", runs.to_markdown());
+    }
+
+    #[test]
+    fn locate() {
+        let runs = TextRuns::new([TextRun::new("abc"), TextRun::new("de").with(TextFormat::Bold)]);
+        assert_eq!(Some((0, 0)), runs.locate(0, false));
+        assert_eq!(Some((1, 0)), runs.locate(3, false));
+        assert_eq!(Some((0, 3)), runs.locate(3, true));
+        assert_eq!(Some((1, 2)), runs.locate(5, false));
+        assert_eq!(Some((1, 2)), runs.locate(5, true));
+        assert_eq!(None, runs.locate(6, false));
+        assert_eq!(None, TextRuns::default().locate(0, true));
+    }
+}
@@ -641,7 +641,7 @@ impl TextEditOp {
             (op.op)(&mut *op.data, UndoFullOp::Op(UndoOp::Redo));
         }
 
-        if !OBSCURE_TXT_VAR.get() {
+        if !is_obscured() {
             UNDO.register(UndoTextEditOp::new(self));
         }
         true
@@ -777,58 +777,130 @@ impl fmt::Debug for TextSelectOp {
 impl TextSelectOp {
     /// Clear selection and move the caret to the next insert index.
     ///
-    /// This is the `Right` key operation.
+    /// This is the `Right` key operation in left-to-right text, see [`right`] for the bidi aware operation.
+    ///
+    /// [`right`]: Self::right
     pub fn next() -> Self {
         rich_clear_next_prev(true, false)
     }
 
     /// Extend or shrink selection by moving the caret to the next insert index.
     ///
-    /// This is the `SHIFT+Right` key operation.
+    /// This is the `SHIFT+Right` key operation in left-to-right text, see [`select_right`] for the bidi aware operation.
+    ///
+    /// [`select_right`]: Self::select_right
     pub fn select_next() -> Self {
         rich_select_next_prev(true, false)
     }
 
     /// Clear selection and move the caret to the previous insert index.
     ///
-    /// This is the `Left` key operation.
+    /// This is the `Left` key operation in left-to-right text, see [`left`] for the bidi aware operation.
+    ///
+    /// [`left`]: Self::left
     pub fn prev() -> Self {
         rich_clear_next_prev(false, false)
     }
 
     /// Extend or shrink selection by moving the caret to the previous insert index.
     ///
-    /// This is the `SHIFT+Left` key operation.
+    /// This is the `SHIFT+Left` key operation in left-to-right text, see [`select_left`] for the bidi aware operation.
+    ///
+    /// [`select_left`]: Self::select_left
     pub fn select_prev() -> Self {
         rich_select_next_prev(false, false)
     }
 
     /// Clear selection and move the caret to the next word insert index.
     ///
-    /// This is the `CTRL+Right` shortcut operation.
+    /// This is the `CTRL+Right` shortcut operation in left-to-right text.
     pub fn next_word() -> Self {
         rich_clear_next_prev(true, true)
     }
     /// Extend or shrink selection by moving the caret to the next word insert index.
     ///
-    /// This is the `CTRL+SHIFT+Right` shortcut operation.
+    /// This is the `CTRL+SHIFT+Right` shortcut operation in left-to-right text.
     pub fn select_next_word() -> Self {
         rich_select_next_prev(true, true)
     }
     /// Clear selection and move the caret to the previous word insert index.
     ///
-    /// This is the `CTRL+Left` shortcut operation.
+    /// This is the `CTRL+Left` shortcut operation in left-to-right text.
     pub fn prev_word() -> Self {
         rich_clear_next_prev(false, true)
     }
 
     /// Extend or shrink selection by moving the caret to the previous word insert index.
     ///
-    /// This is the `CTRL+SHIFT+Left` shortcut operation.
+    /// This is the `CTRL+SHIFT+Left` shortcut operation in left-to-right text.
     pub fn select_prev_word() -> Self {
         rich_select_next_prev(false, true)
     }
 
+    /// Clear selection and move the caret to the insert index visually to the right.
+    ///
+    /// This is [`next`] if the text at the caret is left-to-right or [`prev`] if it is right-to-left, so the
+    /// caret follows the on-screen direction in bidirectional text. This is the `Right` key operation.
+    ///
+    /// [`next`]: Self::next
+    /// [`prev`]: Self::prev
+    pub fn right() -> Self {
+        visual_next_prev(true, TextSelectOp::next, TextSelectOp::prev)
+    }
+
+    /// Extend or shrink selection by moving the caret to the insert index visually to the right.
+    ///
+    /// This is the `SHIFT+Right` key operation.
+    pub fn select_right() -> Self {
+        visual_next_prev(true, TextSelectOp::select_next, TextSelectOp::select_prev)
+    }
+
+    /// Clear selection and move the caret to the insert index visually to the left.
+    ///
+    /// This is [`prev`] if the text at the caret is left-to-right or [`next`] if it is right-to-left, so the
+    /// caret follows the on-screen direction in bidirectional text. This is the `Left` key operation.
+    ///
+    /// [`next`]: Self::next
+    /// [`prev`]: Self::prev
+    pub fn left() -> Self {
+        visual_next_prev(false, TextSelectOp::next, TextSelectOp::prev)
+    }
+
+    /// Extend or shrink selection by moving the caret to the insert index visually to the left.
+    ///
+    /// This is the `SHIFT+Left` key operation.
+    pub fn select_left() -> Self {
+        visual_next_prev(false, TextSelectOp::select_next, TextSelectOp::select_prev)
+    }
+
+    /// Clear selection and move the caret to the word insert index visually to the right.
+    ///
+    /// This is the `CTRL+Right` shortcut operation.
+    pub fn right_word() -> Self {
+        visual_next_prev(true, TextSelectOp::next_word, TextSelectOp::prev_word)
+    }
+
+    /// Extend or shrink selection by moving the caret to the word insert index visually to the right.
+    ///
+    /// This is the `CTRL+SHIFT+Right` shortcut operation.
+    pub fn select_right_word() -> Self {
+        visual_next_prev(true, TextSelectOp::select_next_word, TextSelectOp::select_prev_word)
+    }
+
+    /// Clear selection and move the caret to the word insert index visually to the left.
+    ///
+    /// This is the `CTRL+Left` shortcut operation.
+    pub fn left_word() -> Self {
+        visual_next_prev(false, TextSelectOp::next_word, TextSelectOp::prev_word)
+    }
+
+    /// Extend or shrink selection by moving the caret to the word insert index visually to the left.
+    ///
+    /// This is the `CTRL+SHIFT+Left` shortcut operation.
+    pub fn select_left_word() -> Self {
+        visual_next_prev(false, TextSelectOp::select_next_word, TextSelectOp::select_prev_word)
+    }
+
     /// Clear selection and move the caret to the nearest insert index on the previous line.
     ///
     /// This is the `Up` key operation.
@@ -1465,6 +1537,34 @@ fn rich_select_op_finish(
     }
 }
 
+fn visual_next_prev(is_right: bool, next: fn() -> TextSelectOp, prev: fn() -> TextSelectOp) -> TextSelectOp {
+    TextSelectOp::new(move || {
+        let is_next = {
+            let ctx = TEXT.resolved();
+            visual_is_next(&ctx.segmented_text, ctx.caret.index.unwrap_or(CaretIndex::ZERO).index, is_right)
+        };
+        if is_next { next() } else { prev() }.call();
+    })
+}
+/// If a right or left caret move from the `index` is a move to the next index, in the direction of the text segment visually affected.
+fn visual_is_next(txt: &SegmentedText, index: usize, is_right: bool) -> bool {
+    // right moves over the char after the caret, left over the char before, at the text edges uses the only char available
+    let char_i = if is_right {
+        if index >= txt.text().len() {
+            index.saturating_sub(1)
+        } else {
+            index
+        }
+    } else {
+        index.saturating_sub(1)
+    };
+    let direction = match txt.segs().get(txt.seg_from_char(char_i)) {
+        Some(seg) => seg.direction(),
+        None => txt.base_direction(),
+    };
+    is_right == (direction == LayoutDirection::LTR)
+}
+
 fn rich_clear_next_prev(is_next: bool, is_word: bool) -> TextSelectOp {
     TextSelectOp::new_rich(
         // get prev/next leaf widget
@@ -2563,3 +2663,48 @@ fn local_select_line_word_nearest_to(replace_selection: bool, select_word: bool,
         caret.clear_selection();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visual_is_next_ltr() {
+        let txt = SegmentedText::new("abc", LayoutDirection::LTR);
+        assert!(visual_is_next(&txt, 1, true));
+        assert!(!visual_is_next(&txt, 1, false));
+        assert!(visual_is_next(&txt, 0, true));
+        assert!(!visual_is_next(&txt, 3, false));
+    }
+
+    #[test]
+    fn visual_is_next_rtl() {
+        // each Hebrew char is 2 bytes
+        let txt = SegmentedText::new("אבג", LayoutDirection::RTL);
+        assert!(!visual_is_next(&txt, 2, true));
+        assert!(visual_is_next(&txt, 2, false));
+        assert!(visual_is_next(&txt, 0, false));
+        assert!(!visual_is_next(&txt, 6, true));
+    }
+
+    #[test]
+    fn visual_is_next_mixed_ltr_base() {
+        // "abc " is 0..4, "אבג" is 4..10
+        let txt = SegmentedText::new("abc אבג", LayoutDirection::LTR);
+        assert!(visual_is_next(&txt, 1, true));
+        assert!(!visual_is_next(&txt, 1, false));
+        assert!(!visual_is_next(&txt, 6, true));
+        assert!(visual_is_next(&txt, 6, false));
+        assert!(!visual_is_next(&txt, 10, true));
+    }
+
+    #[test]
+    fn visual_is_next_mixed_rtl_base() {
+        // "אבג " is 0..7, "abc" is 7..10
+        let txt = SegmentedText::new("אבג abc", LayoutDirection::RTL);
+        assert!(!visual_is_next(&txt, 2, true));
+        assert!(visual_is_next(&txt, 2, false));
+        assert!(visual_is_next(&txt, 8, true));
+        assert!(!visual_is_next(&txt, 8, false));
+    }
+}
@@ -28,6 +28,9 @@ pub use zng_wgt::prelude::formatx as __formatx;
 
 pub mod icon;
 
+mod link;
+pub use link::*;
+
 /// A configured text run.
 ///
 /// # Examples
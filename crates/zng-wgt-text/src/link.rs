@@ -0,0 +1,233 @@
+use zng_ext_font::CaretIndex;
+use zng_ext_input::gesture::CLICK_EVENT;
+use zng_wgt::prelude::*;
+
+use crate::{FONT_COLOR_VAR, TextInspectMix, node::TEXT};
+
+event! {
+    /// Event raised by text links when clicked.
+    ///
+    /// This event is raised by [`txt_auto_link`] texts, markdown links and `AnsiText!` auto links.
+    ///
+    /// [`txt_auto_link`]: fn@txt_auto_link
+    pub static LINK_EVENT: LinkArgs;
+}
+
+event_property! {
+    /// Text link click.
+    ///
+    /// See [`LINK_EVENT`] for more details.
+    #[property(EVENT)]
+    pub fn on_link<on_pre_link>(child: impl IntoUiNode, handler: Handler<LinkArgs>) -> UiNode {
+        const PRE: bool;
+        EventNodeBuilder::new(LINK_EVENT).build::<PRE>(child, handler)
+    }
+}
+
+event_args! {
+    /// Arguments for the [`LINK_EVENT`].
+    pub struct LinkArgs {
+        /// Raw URL.
+        pub url: Txt,
+
+        /// Link widget.
+        pub link: InteractionPath,
+
+        ..
+
+        fn is_in_target(&self, id: WidgetId) -> bool {
+            self.link.contains(id)
+        }
+    }
+}
+
+/// Detect `http://` and `https://` URLs in the text and present them as links.
+///
+/// When enabled the URLs are underlined and a primary click on them notifies [`LINK_EVENT`]. Use [`on_link`] to handle
+/// the event, for example, to open the URL in the system browser.
+///
+/// This property must be set in the text widget.
+///
+/// [`on_link`]: fn@on_link
+#[property(CHILD_LAYOUT+100, default(false), widget_impl(TextInspectMix<P>))]
+pub fn txt_auto_link(child: impl IntoUiNode, enabled: impl IntoVar<bool>) -> UiNode {
+    let enabled = enabled.into_var();
+    let mut click_handle = VarHandle::dummy();
+    match_node(child, move |_, op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_var_render(&enabled);
+            click_handle = CLICK_EVENT.subscribe(UpdateOp::Update, WIDGET.id());
+        }
+        UiNodeOp::Deinit => {
+            click_handle = VarHandle::dummy();
+        }
+        UiNodeOp::Update { .. } => {
+            if enabled.get() {
+                CLICK_EVENT.each_update(false, |args| {
+                    if args.is_primary()
+                        && let Some(pos) = args.position()
+                        && let Some(url) = link_at(pos)
+                    {
+                        args.propagation.stop();
+                        LINK_EVENT.notify(LinkArgs::now(url, WIDGET.info().interaction_path()));
+                    }
+                });
+            }
+        }
+        UiNodeOp::Render { frame } => {
+            if enabled.get() {
+                let l_txt = TEXT.laidout();
+                let r_txt = TEXT.resolved();
+                let txt = r_txt.segmented_text.text();
+                let color = FONT_COLOR_VAR.get();
+
+                for range in find_urls(txt) {
+                    let start = l_txt.shaped_text.snap_caret_line(CaretIndex {
+                        index: range.start,
+                        line: usize::MAX,
+                    });
+                    let end = l_txt.shaped_text.snap_caret_line(CaretIndex { index: range.end, line: 0 });
+                    for r in l_txt.shaped_text.highlight_rects(start..end, txt) {
+                        let thickness = Px((r.height().0 / 16).max(1));
+                        let y = r.max_y() - l_txt.shaped_text.underline();
+                        let line = PxRect::new(PxPoint::new(r.origin.x, y), PxSize::new(r.width(), thickness));
+                        frame.push_color(line, FrameValue::Value(color));
+                    }
+                }
+            }
+        }
+        _ => {}
+    })
+}
+
+/// Gets the URL at the `window_point` in the context text.
+fn link_at(window_point: DipPoint) -> Option<Txt> {
+    let laidout = TEXT.laidout();
+    let pos = laidout
+        .render_info
+        .transform
+        .inverse()
+        .and_then(|t| t.project_point(window_point.to_px(laidout.render_info.scale_factor)))?;
+    let line = laidout.shaped_text.nearest_line(pos.y)?;
+    if pos.y < line.rect().min_y() || pos.y > line.rect().max_y() {
+        return None;
+    }
+    let seg = line.nearest_seg(pos.x)?;
+    if pos.x < seg.rect().min_x() || pos.x > seg.rect().max_x() {
+        return None;
+    }
+    let resolved = TEXT.resolved();
+    let txt = resolved.segmented_text.text();
+    let index = seg.nearest_char_index(pos.x, txt);
+    find_urls(txt).find(|r| r.start <= index && index < r.end).map(|r| txt[r].to_txt())
+}
+
+/// Iterate over `http://` and `https://` URL ranges in `s`.
+///
+/// URLs end at whitespace or quotes, trailing punctuation and unbalanced closing brackets are not included.
+/// This is the detection used by [`txt_auto_link`] and the `AnsiText!` auto link.
+///
+/// [`txt_auto_link`]: fn@txt_auto_link
+pub fn find_urls(s: &str) -> impl Iterator<Item = std::ops::Range<usize>> + '_ {
+    let mut offset = 0;
+    std::iter::from_fn(move || {
+        loop {
+            let rest = &s[offset..];
+            let i = rest.find("http")?;
+            let start = offset + i;
+            let after = &s[start..];
+            let scheme_len = if after.starts_with("https://") {
+                8
+            } else if after.starts_with("http://") {
+                7
+            } else {
+                offset = start + 4;
+                continue;
+            };
+            // must not be inside a word, like "xhttp://"
+            if s[..start].chars().next_back().is_some_and(|c| c.is_alphanumeric()) {
+                offset = start + scheme_len;
+                continue;
+            }
+
+            let mut end = after
+                .find(|c: char| c.is_whitespace() || matches!(c, '"' | '\'' | '<' | '>' | '`'))
+                .map(|e| start + e)
+                .unwrap_or(s.len());
+            loop {
+                let url = &s[start..end];
+                let Some(c) = url.chars().next_back() else { break };
+                let trim = match c {
+                    '.' | ',' | ';' | ':' | '!' | '?' => true,
+                    ')' => url.matches('(').count() < url.matches(')').count(),
+                    ']' => url.matches('[').count() < url.matches(']').count(),
+                    '}' => url.matches('{').count() < url.matches('}').count(),
+                    _ => false,
+                };
+                if trim {
+                    end -= c.len_utf8();
+                } else {
+                    break;
+                }
+            }
+            offset = end.max(start + scheme_len);
+            if end > start + scheme_len {
+                return Some(start..end);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urls(s: &str) -> Vec<&str> {
+        find_urls(s).map(|r| &s[r]).collect()
+    }
+
+    #[test]
+    fn find_urls_plain() {
+        assert_eq!(urls("see https://zng-ui.github.io for docs"), vec!["https://zng-ui.github.io"]);
+        assert_eq!(urls("http://a.b/c?d=e#f"), vec!["http://a.b/c?d=e#f"]);
+        assert_eq!(urls("http://a.b https://c.d"), vec!["http://a.b", "https://c.d"]);
+        assert!(urls("no links here, http or https").is_empty());
+    }
+
+    #[test]
+    fn find_urls_trailing_punctuation() {
+        assert_eq!(urls("go to https://a.b/c."), vec!["https://a.b/c"]);
+        assert_eq!(urls("https://a.b, https://c.d;"), vec!["https://a.b", "https://c.d"]);
+        assert_eq!(urls("is it https://a.b?"), vec!["https://a.b"]);
+    }
+
+    #[test]
+    fn find_urls_brackets() {
+        assert_eq!(urls("(see https://a.b/c)"), vec!["https://a.b/c"]);
+        assert_eq!(urls("https://a.b/wiki/X_(y)"), vec!["https://a.b/wiki/X_(y)"]);
+        assert_eq!(urls("[https://a.b/x_(y)]"), vec!["https://a.b/x_(y)"]);
+    }
+
+    #[test]
+    fn find_urls_delimiters() {
+        assert_eq!(
+            urls("\"https://a.b\" 'http://c.d' <https://e.f>"),
+            vec!["https://a.b", "http://c.d", "https://e.f"]
+        );
+        assert_eq!(urls("`https://a.b`"), vec!["https://a.b"]);
+    }
+
+    #[test]
+    fn find_urls_not_links() {
+        // inside a word
+        assert!(urls("xhttps://a.b").is_empty());
+        // only the scheme
+        assert!(urls("https:// http://").is_empty());
+        assert_eq!(urls("https://."), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn find_urls_unicode() {
+        assert_eq!(urls("olá https://a.b/ção fim"), vec!["https://a.b/ção"]);
+    }
+}
@@ -1,6 +1,6 @@
 //! UI nodes used for building a text widget.
 
-use std::{fmt, num::Wrapping, ops, sync::Arc};
+use std::{fmt, num::Wrapping, ops, sync::Arc, time::Duration};
 
 use super::text_properties::*;
 use atomic::{Atomic, Ordering};
@@ -664,6 +664,14 @@ pub(super) fn get_lines_wrap_count(child: impl IntoUiNode, lines: impl IntoVar<s
         _ => {}
     })
 }
+
+/// New caret opacity animation for the current text widget.
+///
+/// If `typing` the caret stays visible for the [`CARET_TYPING_PAUSE_VAR`] before blinking.
+pub(crate) fn caret_animation(typing: bool) -> Var<Factor> {
+    let pause = if typing { CARET_TYPING_PAUSE_VAR.get() } else { Duration::ZERO };
+    zng_ext_input::keyboard::KEYBOARD.caret_animation_with(CARET_BLINK_INTERVAL_VAR.current_context(), pause)
+}
 // Returns `Some(_)` if the current wrap count changed from `prev`. Only allocates if new count has wrapped lines.
 fn lines_wrap_count(prev: &super::LinesWrapCount, txt: &ShapedText) -> Option<super::LinesWrapCount> {
     match prev {
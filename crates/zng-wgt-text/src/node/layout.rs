@@ -15,7 +15,7 @@ use zng_ext_font::{
 };
 use zng_ext_input::{
     focus::FOCUS,
-    keyboard::{KEY_INPUT_EVENT, KEYBOARD},
+    keyboard::KEY_INPUT_EVENT,
     mouse::{MOUSE, MOUSE_INPUT_EVENT, MOUSE_MOVE_EVENT},
     pointer_capture::{POINTER_CAPTURE, POINTER_CAPTURE_EVENT},
     touch::{TOUCH_INPUT_EVENT, TOUCH_LONG_PRESS_EVENT, TOUCH_TAP_EVENT},
@@ -32,14 +32,15 @@ use zng_wgt::prelude::*;
 use zng_wgt_scroll::{SCROLL, cmd::ScrollToMode};
 
 use crate::{
-    ACCEPTS_ENTER_VAR, AUTO_SELECTION_VAR, AutoSelection, FONT_FAMILY_VAR, FONT_FEATURES_VAR, FONT_SIZE_VAR, FONT_STRETCH_VAR,
-    FONT_STYLE_VAR, FONT_VARIATIONS_VAR, FONT_WEIGHT_VAR, HYPHEN_CHAR_VAR, HYPHENS_VAR, IME_UNDERLINE_THICKNESS_VAR, JUSTIFY_MODE_VAR,
-    LETTER_SPACING_VAR, LINE_BREAK_VAR, LINE_HEIGHT_VAR, LINE_SPACING_VAR, OBSCURE_TXT_VAR, OBSCURING_CHAR_VAR, OVERLINE_THICKNESS_VAR,
-    PARAGRAPH_BREAK_VAR, PARAGRAPH_INDENT_VAR, PARAGRAPH_SPACING_VAR, STRIKETHROUGH_THICKNESS_VAR, TAB_LENGTH_VAR, TEXT_ALIGN_VAR,
-    TEXT_EDITABLE_VAR, TEXT_OVERFLOW_ALIGN_VAR, TEXT_OVERFLOW_VAR, TEXT_SELECTABLE_ALT_ONLY_VAR, TEXT_SELECTABLE_VAR, TEXT_WRAP_VAR,
-    TextOverflow, UNDERLINE_POSITION_VAR, UNDERLINE_SKIP_VAR, UNDERLINE_THICKNESS_VAR, UnderlinePosition, UnderlineSkip, WORD_BREAK_VAR,
-    WORD_SPACING_VAR,
+    ACCEPTS_ENTER_VAR, AUTO_DIRECTION_VAR, AUTO_SELECTION_VAR, AutoSelection, FONT_FAMILY_VAR, FONT_FEATURES_VAR, FONT_PALETTE_VAR,
+    FONT_SIZE_VAR, FONT_STRETCH_VAR, FONT_STYLE_VAR, FONT_VARIATIONS_VAR, FONT_WEIGHT_VAR, HYPHEN_CHAR_VAR, HYPHENS_VAR,
+    IME_UNDERLINE_THICKNESS_VAR, JUSTIFY_MODE_VAR, LETTER_SPACING_VAR, LINE_BREAK_VAR, LINE_HEIGHT_VAR, LINE_SPACING_VAR, OBSCURE_TXT_VAR,
+    OBSCURING_CHAR_VAR, OVERLINE_THICKNESS_VAR, PARAGRAPH_BREAK_VAR, PARAGRAPH_INDENT_VAR, PARAGRAPH_SPACING_VAR, SECURE_TXT_VAR,
+    STRIKETHROUGH_THICKNESS_VAR, TAB_LENGTH_VAR, TEXT_ALIGN_VAR, TEXT_EDITABLE_VAR, TEXT_OVERFLOW_ALIGN_VAR, TEXT_OVERFLOW_VAR,
+    TEXT_SELECTABLE_ALT_ONLY_VAR, TEXT_SELECTABLE_VAR, TEXT_WRAP_VAR, TextOverflow, UNDERLINE_POSITION_VAR, UNDERLINE_SKIP_VAR,
+    UNDERLINE_THICKNESS_VAR, UnderlinePosition, UnderlineSkip, WORD_BREAK_VAR, WORD_SPACING_VAR,
     cmd::{SELECT_ALL_CMD, SELECT_CMD, TextSelectOp},
+    is_obscured,
     node::{RichTextComponent, RichTextWidgetInfoExt, SelectionBy},
 };
 
@@ -96,6 +97,11 @@ fn layout_text_context(child: impl IntoUiNode) -> UiNode {
 
             laidout = None;
         }
+        op @ (UiNodeOp::Measure { .. } | UiNodeOp::Layout { .. }) if AUTO_DIRECTION_VAR.get() => {
+            // layout in the detected text direction, so that alignment is also in the text direction.
+            let direction = TEXT.resolved().segmented_text.base_direction();
+            LAYOUT.with_direction(direction, || LAIDOUT_TEXT.with_context(&mut laidout, || child.op(op)))
+        }
         op => LAIDOUT_TEXT.with_context(&mut laidout, || child.op(op)),
     })
 }
@@ -139,9 +145,12 @@ fn layout_text_layout(child: impl IntoUiNode) -> UiNode {
                 .sub_var_layout(&JUSTIFY_MODE_VAR)
                 .sub_var_layout(&TEXT_OVERFLOW_ALIGN_VAR);
 
-            WIDGET.sub_var(&FONT_FEATURES_VAR);
+            WIDGET.sub_var(&FONT_FEATURES_VAR).sub_var(&FONT_PALETTE_VAR);
 
-            WIDGET.sub_var(&OBSCURE_TXT_VAR).sub_var(&OBSCURING_CHAR_VAR);
+            WIDGET
+                .sub_var(&OBSCURE_TXT_VAR)
+                .sub_var(&SECURE_TXT_VAR)
+                .sub_var(&OBSCURING_CHAR_VAR);
 
             // LANG_VAR already subscribed by resolve_text
 
@@ -153,8 +162,9 @@ fn layout_text_layout(child: impl IntoUiNode) -> UiNode {
             txt.shaping_args.hyphens = HYPHENS_VAR.get();
             txt.shaping_args.hyphen_char = HYPHEN_CHAR_VAR.get();
             txt.shaping_args.font_features = FONT_FEATURES_VAR.with(|f| f.finalize());
+            txt.shaping_args.font_palette = FONT_PALETTE_VAR.get();
 
-            if OBSCURE_TXT_VAR.get() {
+            if is_obscured() {
                 txt.shaping_args.obscuring_char = Some(OBSCURING_CHAR_VAR.get());
             }
         }
@@ -228,12 +238,8 @@ fn layout_text_layout(child: impl IntoUiNode) -> UiNode {
                 }
             }
 
-            if OBSCURE_TXT_VAR.is_new() || OBSCURING_CHAR_VAR.is_new() {
-                let c = if OBSCURE_TXT_VAR.get() {
-                    Some(OBSCURING_CHAR_VAR.get())
-                } else {
-                    None
-                };
+            if OBSCURE_TXT_VAR.is_new() || SECURE_TXT_VAR.is_new() || OBSCURING_CHAR_VAR.is_new() {
+                let c = if is_obscured() { Some(OBSCURING_CHAR_VAR.get()) } else { None };
                 if txt.shaping_args.obscuring_char != c {
                     txt.shaping_args.obscuring_char = c;
                     txt.pending.insert(PendingLayout::RESHAPE);
@@ -257,6 +263,17 @@ fn layout_text_layout(child: impl IntoUiNode) -> UiNode {
                 WIDGET.layout();
             });
 
+            if let Some(p) = FONT_PALETTE_VAR.get_new()
+                && txt.shaping_args.font_palette != p
+            {
+                // COLRv1 glyph images are generated during shaping
+                txt.shaping_args.font_palette = p;
+                if TEXT.try_laidout().map(|t| t.shaped_text.has_images()).unwrap_or(false) {
+                    txt.pending.insert(PendingLayout::RESHAPE);
+                    WIDGET.layout();
+                }
+            }
+
             if FONT_FAMILY_VAR.is_new()
                 || FONT_STYLE_VAR.is_new()
                 || FONT_STRETCH_VAR.is_new()
@@ -913,14 +930,12 @@ fn layout_text_edit(child: impl IntoUiNode) -> UiNode {
                     });
                 }
 
-                if (OBSCURE_TXT_VAR.is_new() || OBSCURING_CHAR_VAR.is_new())
-                    && let Some(obscure) = OBSCURE_TXT_VAR.get_new()
-                {
+                if OBSCURE_TXT_VAR.is_new() || SECURE_TXT_VAR.is_new() {
                     if edit.is_none() && WINDOW.info().access_enabled().is_enabled() {
                         WIDGET.update_info();
                     }
 
-                    if obscure {
+                    if is_obscured() {
                         UNDO.clear();
                     }
                 }
@@ -1064,14 +1079,14 @@ fn layout_text_edit_events(edit: &mut LayoutTextEdit) {
 
                             if select {
                                 if word {
-                                    TextSelectOp::select_next_word()
+                                    TextSelectOp::select_right_word()
                                 } else {
-                                    TextSelectOp::select_next()
+                                    TextSelectOp::select_right()
                                 }
                             } else if word {
-                                TextSelectOp::next_word()
+                                TextSelectOp::right_word()
                             } else {
-                                TextSelectOp::next()
+                                TextSelectOp::right()
                             }
                             .call();
                         }
@@ -1087,14 +1102,14 @@ fn layout_text_edit_events(edit: &mut LayoutTextEdit) {
 
                             if select {
                                 if word {
-                                    TextSelectOp::select_prev_word()
+                                    TextSelectOp::select_left_word()
                                 } else {
-                                    TextSelectOp::select_prev()
+                                    TextSelectOp::select_left()
                                 }
                             } else if word {
-                                TextSelectOp::prev_word()
+                                TextSelectOp::left_word()
                             } else {
-                                TextSelectOp::prev()
+                                TextSelectOp::left()
                             }
                             .call();
                         }
@@ -1489,7 +1504,7 @@ fn layout_text_edit_events(edit: &mut LayoutTextEdit) {
             edit.caret_animation = VarHandle::dummy();
             caret.opacity = var(0.fct()).read_only();
         } else {
-            caret.opacity = KEYBOARD.caret_animation();
+            caret.opacity = super::caret_animation(true);
             edit.caret_animation = caret.opacity.subscribe(UpdateOp::RenderUpdate, widget.id());
         }
         resolve.pending_layout |= PendingLayout::CARET;
@@ -17,7 +17,7 @@ use zng_ext_clipboard::{CLIPBOARD, COPY_CMD, CUT_CMD, PASTE_CMD};
 use zng_ext_font::{CaretIndex, FONT_CHANGED_EVENT, FONTS, FontFaceList, SegmentedText};
 use zng_ext_input::{
     focus::{FOCUS, FOCUS_CHANGED_EVENT, FocusInfoBuilder, WidgetInfoFocusExt as _},
-    keyboard::{KEY_INPUT_EVENT, KEYBOARD},
+    keyboard::KEY_INPUT_EVENT,
 };
 use zng_ext_l10n::LANG_VAR;
 use zng_ext_undo::UNDO;
@@ -27,10 +27,11 @@ use zng_view_api::keyboard::{Key, KeyState};
 use zng_wgt::prelude::*;
 
 use crate::{
-    ACCEPTS_ENTER_VAR, ACCEPTS_TAB_VAR, AUTO_SELECTION_VAR, AutoSelection, FONT_FAMILY_VAR, FONT_STRETCH_VAR, FONT_STYLE_VAR,
-    FONT_SYNTHESIS_VAR, FONT_WEIGHT_VAR, MAX_CHARS_COUNT_VAR, OBSCURE_TXT_VAR, TEXT_EDITABLE_VAR, TEXT_SELECTABLE_VAR, TEXT_TRANSFORM_VAR,
-    WHITE_SPACE_VAR,
+    ACCEPTS_ENTER_VAR, ACCEPTS_TAB_VAR, AUTO_DIRECTION_VAR, AUTO_SELECTION_VAR, AutoSelection, FONT_FAMILY_VAR, FONT_STRETCH_VAR,
+    FONT_STYLE_VAR, FONT_SYNTHESIS_VAR, FONT_WEIGHT_VAR, MAX_CHARS_COUNT_VAR, OBSCURE_TXT_VAR, SECURE_TXT_VAR, TEXT_EDITABLE_VAR,
+    TEXT_SELECTABLE_VAR, TEXT_TRANSFORM_VAR, WHITE_SPACE_VAR,
     cmd::{EDIT_CMD, SELECT_ALL_CMD, SELECT_CMD, TextEditOp, TextSelectOp, UndoTextEditOp},
+    is_obscured,
 };
 
 use super::{CaretInfo, ImePreview, PendingLayout, RESOLVED_TEXT, ResolvedText, RichTextCopyParam, SelectionBy, TEXT};
@@ -188,7 +189,8 @@ fn resolve_text_access(child: impl IntoUiNode) -> UiNode {
                 .sub_var_info(&TEXT.resolved().txt)
                 .sub_var_info(&TEXT_EDITABLE_VAR)
                 .sub_var_info(&TEXT_SELECTABLE_VAR)
-                .sub_var_info(&OBSCURE_TXT_VAR);
+                .sub_var_info(&OBSCURE_TXT_VAR)
+                .sub_var_info(&SECURE_TXT_VAR);
         }
         UiNodeOp::Info { info } => {
             let editable = TEXT_EDITABLE_VAR.get();
@@ -199,7 +201,7 @@ fn resolve_text_access(child: impl IntoUiNode) -> UiNode {
             child.info(info);
 
             if !editable
-                && !OBSCURE_TXT_VAR.get()
+                && !is_obscured()
                 && let Some(mut a) = info.access()
             {
                 a.set_label(TEXT.resolved().segmented_text.text().clone());
@@ -218,6 +220,7 @@ fn resolve_text_segments(child: impl IntoUiNode) -> UiNode {
                     .sub_var(&TEXT_TRANSFORM_VAR)
                     .sub_var(&WHITE_SPACE_VAR)
                     .sub_var(&DIRECTION_VAR)
+                    .sub_var(&AUTO_DIRECTION_VAR)
                     .sub_var(&TEXT_EDITABLE_VAR);
 
                 segment = true;
@@ -227,6 +230,7 @@ fn resolve_text_segments(child: impl IntoUiNode) -> UiNode {
                     || TEXT_TRANSFORM_VAR.is_new()
                     || WHITE_SPACE_VAR.is_new()
                     || DIRECTION_VAR.is_new()
+                    || AUTO_DIRECTION_VAR.is_new()
                     || TEXT_EDITABLE_VAR.is_new();
             }
             _ => {}
@@ -249,7 +253,12 @@ fn resolve_text_segments(child: impl IntoUiNode) -> UiNode {
                 });
             }
 
-            let direction = DIRECTION_VAR.get();
+            let mut direction = DIRECTION_VAR.get();
+            if AUTO_DIRECTION_VAR.get()
+                && let Some(d) = SegmentedText::detect_direction(&txt)
+            {
+                direction = d;
+            }
             if ctx.segmented_text.text() != &txt || ctx.segmented_text.base_direction() != direction {
                 ctx.segmented_text = SegmentedText::new(txt, direction);
 
@@ -297,7 +306,7 @@ fn resolve_text_edit(child: impl IntoUiNode) -> UiNode {
                         resolve_text_edit_or_select_events(edit);
                     }
 
-                    let enable = !OBSCURE_TXT_VAR.get() && TEXT.resolved().caret.selection_range().is_some();
+                    let enable = !is_obscured() && TEXT.resolved().caret.selection_range().is_some();
                     edit.cut.enabled().set(enable);
                     edit.copy.enabled().set(enable);
                 }
@@ -342,7 +351,7 @@ fn resolve_text_edit(child: impl IntoUiNode) -> UiNode {
                 enforce_max_count(&ctx.txt);
 
                 if FOCUS.is_focused(WIDGET.id()).get() {
-                    ctx.caret.opacity = KEYBOARD.caret_animation();
+                    ctx.caret.opacity = super::caret_animation(false);
                     edit.caret_animation = ctx.caret.opacity.subscribe(UpdateOp::Update, WIDGET.id());
                 }
             }
@@ -352,7 +361,7 @@ fn resolve_text_edit(child: impl IntoUiNode) -> UiNode {
 
                 edit.access_selection = ACCESS_SELECTION_EVENT.subscribe(UpdateOp::Update, id);
 
-                let enabled = !OBSCURE_TXT_VAR.get() && TEXT.resolved().caret.selection_range().is_some();
+                let enabled = !is_obscured() && TEXT.resolved().caret.selection_range().is_some();
                 edit.copy = COPY_CMD.scoped(id).subscribe(enabled);
                 if editable {
                     edit.cut = CUT_CMD.scoped(id).subscribe(enabled);
@@ -486,7 +495,7 @@ fn resolve_text_edit_events(edit: &mut ResolveTextEdit) {
                 *caret_index = Some(CaretIndex::ZERO);
             } else {
                 // restore animation when the caret_index did not change
-                caret.opacity = KEYBOARD.caret_animation();
+                caret.opacity = super::caret_animation(false);
                 edit.caret_animation = caret.opacity.subscribe(UpdateOp::RenderUpdate, widget.id());
             }
         } else {
@@ -547,6 +556,9 @@ fn resolve_text_edit_events(edit: &mut ResolveTextEdit) {
     });
 
     CUT_CMD.scoped(widget.id()).each_update(true, false, |args| {
+        if SECURE_TXT_VAR.get() {
+            return;
+        }
         let mut ctx = TEXT.resolve();
         if let Some(range) = ctx.caret.selection_char_range() {
             args.propagation.stop();
@@ -698,7 +710,8 @@ fn resolve_text_edit_events(edit: &mut ResolveTextEdit) {
                 }
                 text.end_mut();
             }
-            ctx.segmented_text = SegmentedText::new(text, DIRECTION_VAR.get());
+            let direction = ctx.segmented_text.base_direction();
+            ctx.segmented_text = SegmentedText::new(text, direction);
 
             ctx.pending_layout |= PendingLayout::RESHAPE;
             WIDGET.layout();
@@ -714,7 +727,7 @@ fn resolve_text_edit_events(edit: &mut ResolveTextEdit) {
             edit.caret_animation = VarHandle::dummy();
             caret.opacity = var(0.fct()).read_only();
         } else {
-            caret.opacity = KEYBOARD.caret_animation();
+            caret.opacity = super::caret_animation(true);
             edit.caret_animation = caret.opacity.subscribe(UpdateOp::RenderUpdate, widget.id());
         }
         ctx.pending_layout |= PendingLayout::CARET;
@@ -725,6 +738,9 @@ fn resolve_text_edit_or_select_events(_: &mut ResolveTextEdit) {
     let widget_id = WIDGET.id();
 
     COPY_CMD.scoped(widget_id).each_update(true, false, |args| {
+        if SECURE_TXT_VAR.get() {
+            return;
+        }
         let ctx = TEXT.resolved();
         if let Some(range) = ctx.caret.selection_char_range() {
             args.propagation.stop();
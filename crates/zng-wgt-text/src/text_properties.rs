@@ -125,6 +125,77 @@ pub fn font_size(child: impl IntoUiNode, size: impl IntoVar<FontSize>) -> UiNode
     with_context_var(child, FONT_SIZE_VAR, size)
 }
 
+/// Shrinks the font size of the text to fit the available space.
+///
+/// The font size is the largest size in between `min` and `max` that fits the layout constraints, if the text does not fit
+/// even at `min` the text is sized at `min` and overflows. The fit is evaluated every measure and layout, so changes to the
+/// text or to the available space are fitted again.
+///
+/// If [`txt_wrap`] is enabled the text can wrap, the font shrinks until all lines fit the available height, otherwise
+/// the font shrinks until the single line fits the available width and height.
+///
+/// Note that this only overrides the font size for the text, properties of the widget like `padding` still use the
+/// contextual [`font_size`] for the [`Length::Em`] unit.
+///
+/// [`txt_wrap`]: fn@txt_wrap
+/// [`font_size`]: fn@font_size
+/// [`Length::Em`]: zng_wgt::prelude::Length::Em
+#[property(CHILD_LAYOUT+100, widget_impl(FontMix<P>))]
+pub fn auto_font_size(child: impl IntoUiNode, min: impl IntoVar<FontSize>, max: impl IntoVar<FontSize>) -> UiNode {
+    let min = min.into_var();
+    let max = max.into_var();
+    match_node(child, move |child, op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_var_layout(&min).sub_var_layout(&max).sub_var_layout(&TEXT_WRAP_VAR);
+        }
+        UiNodeOp::Measure { wm, desired_size } => {
+            let font_size = auto_font_size_fit(&min.get(), &max.get(), || child.measure(wm));
+            *desired_size = LAYOUT.with_font_size(font_size, || child.measure(wm));
+        }
+        UiNodeOp::Layout { wl, final_size } => {
+            let mut wm = wl.to_measure(None);
+            let font_size = auto_font_size_fit(&min.get(), &max.get(), || child.measure(&mut wm));
+            *final_size = LAYOUT.with_font_size(font_size, || child.layout(wl));
+        }
+        _ => {}
+    })
+}
+fn auto_font_size_fit(min: &FontSize, max: &FontSize, mut measure: impl FnMut() -> PxSize) -> Px {
+    let root = LAYOUT.root_font_size();
+    let min = min.layout_dft_x(root).max(Px(1));
+    let max = max.layout_dft_x(root).max(min);
+
+    let constraints = LAYOUT.constraints();
+    let available = PxSize::new(constraints.x.max_or(Px::MAX), constraints.y.max_or(Px::MAX));
+    if available.width == Px::MAX && available.height == Px::MAX {
+        return max;
+    }
+    let measure_constraints = if TEXT_WRAP_VAR.get() {
+        constraints.with_unbounded_y()
+    } else {
+        constraints.with_unbounded()
+    };
+    let mut fits = |font_size| {
+        let size = LAYOUT.with_font_size(font_size, || LAYOUT.with_constraints(measure_constraints, &mut measure));
+        size.width <= available.width && size.height <= available.height
+    };
+
+    if fits(max) {
+        return max;
+    }
+    // binary search the largest size that fits, `min` is used even if it does not fit
+    let (mut fit, mut no_fit) = (min, max);
+    while no_fit - fit > Px(1) {
+        let mid = Px((fit.0 + no_fit.0) / 2);
+        if fits(mid) {
+            fit = mid;
+        } else {
+            no_fit = mid;
+        }
+    }
+    fit
+}
+
 /// Defines the thickness or boldness the preferred font should have.
 ///
 /// This value influences font resolution, the variant within the font family that is closest to this config will be selected.
@@ -171,7 +242,12 @@ pub fn font_synthesis(child: impl IntoUiNode, enabled: impl IntoVar<FontSynthesi
 
 /// Configure the anti-aliasing used to render text glyphs inside the widget.
 ///
-/// Uses the operating system configuration by default.
+/// Uses the operating system configuration by default. The mode is passed to the view-process font instance
+/// of each text run, so it can be overridden per subtree.
+///
+/// Subpixel anti-aliasing can show color fringes on light text over dark backgrounds, set this to
+/// [`FontAntiAliasing::Alpha`] (grayscale) in dark subtrees, or to a mapping of [`COLOR_SCHEME_VAR`],
+/// to avoid the fringes while the rest of the app stays subpixel.
 ///
 /// Sets the [`FONT_AA_VAR`].
 #[property(CONTEXT, default(FONT_AA_VAR), widget_impl(FontMix<P>))]
@@ -1012,11 +1088,36 @@ pub fn direction(child: impl IntoUiNode, direction: impl IntoVar<LayoutDirection
     with_context_var(child, DIRECTION_VAR, direction)
 }
 
+context_var! {
+    /// If [`Text!`] spans select the base direction from the first strong directional character.
+    ///
+    /// [`Text!`]: struct@crate::Text
+    pub static AUTO_DIRECTION_VAR: bool = false;
+}
+
+/// Enables automatic text direction for texts inside the widget.
+///
+/// When enabled the base direction of each text is the direction of the first strong directional character in the text,
+/// so text in a right-to-left script like Arabic or Hebrew is laid out and aligned right-to-left even if the [`lang`]
+/// is a left-to-right language. Texts without a strong directional character use the [`DIRECTION_VAR`].
+///
+/// This is useful for displaying user content in unknown languages, like chat messages.
+///
+/// Sets the [`AUTO_DIRECTION_VAR`].
+///
+/// [`lang`]: fn@lang
+/// [`DIRECTION_VAR`]: zng_wgt::prelude::DIRECTION_VAR
+#[property(CONTEXT, default(AUTO_DIRECTION_VAR), widget_impl(LangMix<P>))]
+pub fn auto_direction(child: impl IntoUiNode, enabled: impl IntoVar<bool>) -> UiNode {
+    with_context_var(child, AUTO_DIRECTION_VAR, enabled)
+}
+
 impl LangMix<()> {
     /// Insert context variables used by properties in this mixin.
     pub fn context_vars_set(set: &mut ContextValueSet) {
         set.insert(&LANG_VAR);
         set.insert(&DIRECTION_VAR);
+        set.insert(&AUTO_DIRECTION_VAR);
     }
 }
 
@@ -1225,6 +1326,59 @@ pub fn font_ea_width(child: impl IntoUiNode, state: impl IntoVar<EastAsianWidth>
     with_font_feature(child, state, |f, s| f.ea_width().set(s))
 }
 
+/// Sets the variable font weight axis (`wght`).
+///
+/// The value is usually in the `1.0..=1000.0` range, `400.0` is regular and `700.0` is bold. The value can animate
+/// smoothly, unlike [`font_weight`] that selects a different font face.
+///
+/// Note that only variable fonts with the axis are affected.
+///
+/// [`font_weight`]: fn@font_weight
+#[property(CONTEXT, default(400.0), widget_impl(FontFeaturesMix<P>))]
+pub fn font_variation_weight(child: impl IntoUiNode, weight: impl IntoVar<f32>) -> UiNode {
+    with_font_variation(child, b"wght".into(), weight)
+}
+
+/// Sets the variable font width axis (`wdth`).
+///
+/// The value is a percentage of the normal width, `100.0` is normal, `75.0` is condensed and `125.0` is expanded.
+///
+/// Note that only variable fonts with the axis are affected.
+#[property(CONTEXT, default(100.0), widget_impl(FontFeaturesMix<P>))]
+pub fn font_variation_width(child: impl IntoUiNode, width: impl IntoVar<f32>) -> UiNode {
+    with_font_variation(child, b"wdth".into(), width)
+}
+
+/// Sets the variable font slant axis (`slnt`).
+///
+/// The value is the slant angle in degrees, `0.0` is upright and negative values slant to the right.
+///
+/// Note that only variable fonts with the axis are affected.
+#[property(CONTEXT, default(0.0), widget_impl(FontFeaturesMix<P>))]
+pub fn font_variation_slant(child: impl IntoUiNode, slant: impl IntoVar<f32>) -> UiNode {
+    with_font_variation(child, b"slnt".into(), slant)
+}
+
+/// Sets the variable font italic axis (`ital`).
+///
+/// The value is in the `0.0..=1.0` range, `0.0` is upright and `1.0` is italic.
+///
+/// Note that only variable fonts with the axis are affected.
+#[property(CONTEXT, default(0.0), widget_impl(FontFeaturesMix<P>))]
+pub fn font_variation_italic(child: impl IntoUiNode, italic: impl IntoVar<f32>) -> UiNode {
+    with_font_variation(child, b"ital".into(), italic)
+}
+
+/// Sets the variable font optical size axis (`opsz`).
+///
+/// The value is the font size in points the glyphs are optimized for, variable fonts usually select this automatically.
+///
+/// Note that only variable fonts with the axis are affected.
+#[property(CONTEXT, default(12.0), widget_impl(FontFeaturesMix<P>))]
+pub fn font_variation_optical_size(child: impl IntoUiNode, size: impl IntoVar<f32>) -> UiNode {
+    with_font_variation(child, b"opsz".into(), size)
+}
+
 /// Text edit properties.
 ///
 /// All properties in this mixin affects [`Text!`] nodes inside the widget where they are set.
@@ -1266,6 +1420,14 @@ context_var! {
     /// Debounce time for change stop.
     pub static CHANGE_STOP_DELAY_VAR: Duration = 1.secs();
 
+    /// Caret blink interval, is the [`KEYBOARD.caret_animation_config`] interval by default.
+    ///
+    /// [`KEYBOARD.caret_animation_config`]: zng_ext_input::keyboard::KEYBOARD::caret_animation_config
+    pub static CARET_BLINK_INTERVAL_VAR: Duration = zng_ext_input::keyboard::KEYBOARD.caret_animation_config().map(|c| c.0);
+
+    /// Duration the caret stays visible after it moves, before it starts blinking again.
+    pub static CARET_TYPING_PAUSE_VAR: Duration = 500.ms();
+
     /// Auto selection on keyboard focus.
     pub static AUTO_SELECTION_VAR: AutoSelection = AutoSelection::default();
 
@@ -1280,6 +1442,9 @@ context_var! {
     /// If text characters are replaced with [`OBSCURING_CHAR_VAR`] for rendering.
     pub static OBSCURE_TXT_VAR: bool = false;
 
+    /// If text is obscured and protected from clipboard and accessibility export.
+    pub static SECURE_TXT_VAR: bool = false;
+
     pub(super) static TXT_PARSE_PENDING_VAR: bool = false;
 }
 
@@ -1296,13 +1461,21 @@ impl TextEditMix<()> {
         set.insert(&SELECTION_COLOR_VAR);
         set.insert(&TXT_PARSE_LIVE_VAR);
         set.insert(&CHANGE_STOP_DELAY_VAR);
+        set.insert(&CARET_BLINK_INTERVAL_VAR);
+        set.insert(&CARET_TYPING_PAUSE_VAR);
         set.insert(&AUTO_SELECTION_VAR);
         set.insert(&MAX_CHARS_COUNT_VAR);
         set.insert(&OBSCURING_CHAR_VAR);
         set.insert(&OBSCURE_TXT_VAR);
+        set.insert(&SECURE_TXT_VAR);
     }
 }
 
+/// If text characters are replaced for rendering, [`OBSCURE_TXT_VAR`] or [`SECURE_TXT_VAR`] is enabled in the context.
+pub(crate) fn is_obscured() -> bool {
+    OBSCURE_TXT_VAR.get() || SECURE_TXT_VAR.get()
+}
+
 /// Defines the position of an interactive caret in relation to the selection.
 ///
 /// See [`interactive_caret_visual`](fn@interactive_caret_visual) for more details.
@@ -1374,12 +1547,18 @@ pub fn txt_editable(child: impl IntoUiNode, enabled: impl IntoVar<bool>) -> UiNo
 
 /// Enable text selection, copy and makes the widget focusable.
 ///
+/// Read-only texts do not show a caret, pointer and keyboard selection gestures select a range of the text that can be
+/// copied with the [`COPY_CMD`] (`Ctrl+C`). The selection is rendered and the [`selection_toolbar`] is shown the same
+/// way as in editable texts.
+///
 /// Note that if the text widget subscribes to mouse or touch events the selection gestures will interfere with those events,
 /// you can enable [`txt_selectable_alt_only`] so that pointer selection gestures only start when the Alt keyboard modifier is pressed.
 ///
 /// Sets the [`TEXT_SELECTABLE_VAR`].
 ///
 /// [`txt_selectable_alt_only`]: fn@txt_selectable_alt_only
+/// [`selection_toolbar`]: fn@selection_toolbar
+/// [`COPY_CMD`]: zng_ext_clipboard::COPY_CMD
 #[property(CONTEXT, default(TEXT_SELECTABLE_VAR), widget_impl(TextEditMix<P>))]
 pub fn txt_selectable(child: impl IntoUiNode, enabled: impl IntoVar<bool>) -> UiNode {
     with_context_var(child, TEXT_SELECTABLE_VAR, enabled)
@@ -1426,6 +1605,29 @@ pub fn caret_color(child: impl IntoUiNode, color: impl IntoVar<Rgba>) -> UiNode
     with_context_var(child, CARET_COLOR_VAR, color)
 }
 
+/// Defines the blink interval of the non-interactive caret.
+///
+/// The caret is visible for the `interval`, then not visible for the `interval`. Is the operating system config
+/// by default, see [`KEYBOARD.caret_animation_config`] for more details. Set to `Duration::MAX` to disable blinking.
+///
+/// Sets the [`CARET_BLINK_INTERVAL_VAR`].
+///
+/// [`KEYBOARD.caret_animation_config`]: zng_ext_input::keyboard::KEYBOARD::caret_animation_config
+#[property(CONTEXT, default(CARET_BLINK_INTERVAL_VAR), widget_impl(TextEditMix<P>))]
+pub fn caret_blink_interval(child: impl IntoUiNode, interval: impl IntoVar<Duration>) -> UiNode {
+    with_context_var(child, CARET_BLINK_INTERVAL_VAR, interval)
+}
+
+/// Defines how long the caret stays visible without blinking after each keystroke or caret move.
+///
+/// Is `500.ms()` by default.
+///
+/// Sets the [`CARET_TYPING_PAUSE_VAR`].
+#[property(CONTEXT, default(CARET_TYPING_PAUSE_VAR), widget_impl(TextEditMix<P>))]
+pub fn caret_typing_pause(child: impl IntoUiNode, pause: impl IntoVar<Duration>) -> UiNode {
+    with_context_var(child, CARET_TYPING_PAUSE_VAR, pause)
+}
+
 /// Defines custom caret visual for interactive caret.
 ///
 /// The `visual` node becomes the content of a [layered widget] at the `ADORNER+1` layer, the text widget context is
@@ -1578,6 +1780,22 @@ pub fn obscure_txt(child: impl IntoUiNode, enabled: impl IntoVar<bool>) -> UiNod
     with_context_var(child, OBSCURE_TXT_VAR, enabled)
 }
 
+/// Enable secure text mode.
+///
+/// When enabled the text is always obscured like [`obscure_txt`], in addition the [`COPY_CMD`] and [`CUT_CMD`] requests
+/// are ignored, even when notified directly or by a rich text copy, and the text is never exported to the accessibility
+/// info. Use this for password fields.
+///
+/// The same memory security note of [`obscure_txt`] applies.
+///
+/// [`obscure_txt`]: fn@obscure_txt
+/// [`COPY_CMD`]: zng_ext_clipboard::COPY_CMD
+/// [`CUT_CMD`]: zng_ext_clipboard::CUT_CMD
+#[property(CONTEXT, default(SECURE_TXT_VAR), widget_impl(TextEditMix<P>))]
+pub fn secure_txt(child: impl IntoUiNode, enabled: impl IntoVar<bool>) -> UiNode {
+    with_context_var(child, SECURE_TXT_VAR, enabled)
+}
+
 bitflags! {
     /// Defines when text is auto-selected on focus change.
     ///
@@ -1970,6 +2188,80 @@ pub fn txt_highlight(child: impl IntoUiNode, range: impl IntoVar<std::ops::Range
     })
 }
 
+/// Highlight multiple text ranges.
+///
+/// This property must be set in the text widget. See also [`txt_highlight`] to highlight a single range.
+#[property(CHILD_LAYOUT+100, widget_impl(TextInspectMix<P>))]
+pub fn txt_highlight_ranges(
+    child: impl IntoUiNode,
+    ranges: impl IntoVar<Vec<std::ops::Range<CaretIndex>>>,
+    color: impl IntoVar<Rgba>,
+) -> UiNode {
+    let ranges = ranges.into_var();
+    let color = color.into_var();
+    let color_key = FrameValueKey::new_unique();
+    match_node(child, move |_, op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_var_render(&ranges).sub_var_render_update(&color);
+        }
+        UiNodeOp::Render { frame } => {
+            let l_txt = super::node::TEXT.laidout();
+            let r_txt = super::node::TEXT.resolved();
+            let r_txt = r_txt.segmented_text.text();
+
+            ranges.with(|ranges| {
+                for range in ranges {
+                    for line_rect in l_txt.shaped_text.highlight_rects(range.clone(), r_txt) {
+                        frame.push_color(line_rect, color_key.bind_var(&color, |c| *c));
+                    }
+                }
+            });
+        }
+        UiNodeOp::RenderUpdate { update } => {
+            if let Some(color_update) = color_key.update_var(&color, |c| *c) {
+                update.update_color(color_update)
+            }
+        }
+        _ => {}
+    })
+}
+
+/// Highlight all matches of the `query` in the text.
+///
+/// The search is case insensitive, the highlights update when the text or query changes. This property must be set in the text widget.
+///
+/// See [`ShapedText::find`] for more details.
+///
+/// [`ShapedText::find`]: zng_ext_font::ShapedText::find
+#[property(CHILD_LAYOUT+100, widget_impl(TextInspectMix<P>))]
+pub fn txt_highlight_find(child: impl IntoUiNode, query: impl IntoVar<Txt>, color: impl IntoVar<Rgba>) -> UiNode {
+    let query = query.into_var();
+    let color = color.into_var();
+    let color_key = FrameValueKey::new_unique();
+    match_node(child, move |_, op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_var_render(&query).sub_var_render_update(&color);
+        }
+        UiNodeOp::Render { frame } => {
+            let l_txt = super::node::TEXT.laidout();
+            let r_txt = super::node::TEXT.resolved();
+            let r_txt = r_txt.segmented_text.text();
+
+            query.with(|q| {
+                for line_rect in l_txt.shaped_text.find(r_txt, q, false) {
+                    frame.push_color(line_rect, color_key.bind_var(&color, |c| *c));
+                }
+            });
+        }
+        UiNodeOp::RenderUpdate { update } => {
+            if let Some(color_update) = color_key.update_var(&color, |c| *c) {
+                update.update_color(color_update)
+            }
+        }
+        _ => {}
+    })
+}
+
 /// Gets a vector of font and ranges.
 ///
 /// This property must be set in the text widget.
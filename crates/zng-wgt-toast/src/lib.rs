@@ -0,0 +1,472 @@
+#![doc(html_favicon_url = "https://zng-ui.github.io/res/zng-logo-icon.png")]
+#![doc(html_logo_url = "https://zng-ui.github.io/res/zng-logo.png")]
+//!
+//! Toast widget and service.
+//!
+//! # Crate
+//!
+#![doc = include_str!(concat!("../", std::env!("CARGO_PKG_README")))]
+#![warn(unused_extern_crates)]
+#![warn(missing_docs)]
+
+zng_wgt::enable_widget_macros!();
+
+use std::{
+    collections::VecDeque,
+    fmt,
+    sync::{
+        Arc, Weak,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use parking_lot::Mutex;
+use zng_ext_input::mouse::MOUSE_HOVERED_EVENT;
+use zng_wgt::{node::VarPresent as _, prelude::*, *};
+use zng_wgt_access::{AccessRole, LiveIndicator, access_role, live};
+use zng_wgt_button::Button;
+use zng_wgt_container::Container;
+use zng_wgt_fill::background_color;
+use zng_wgt_filter::drop_shadow;
+use zng_wgt_layer::{LAYERS, LayerIndex};
+use zng_wgt_stack::{Stack, StackDirection};
+use zng_wgt_style::{Style, StyleMix, impl_style_fn};
+use zng_wgt_text::Text;
+
+/// A transient message overlay.
+///
+/// Toasts are shown by the [`TOASTS`] service, they close after the [`duration`] elapses or when an action
+/// is selected using [`TOASTS.respond`]. The timeout restarts when the pointer leaves the toast.
+///
+/// [`duration`]: fn@duration
+/// [`TOASTS.respond`]: TOASTS::respond
+#[widget($crate::Toast)]
+pub struct Toast(StyleMix<Container>);
+impl Toast {
+    fn widget_intrinsic(&mut self) {
+        self.style_intrinsic(STYLE_FN_VAR, property_id!(self::style_fn));
+
+        self.widget_builder()
+            .push_build_action(|b| b.push_intrinsic(NestGroup::EVENT, "toast-timeout", toast_timeout_node));
+
+        widget_set! {
+            self;
+            access_role = AccessRole::Status;
+        }
+    }
+}
+impl_style_fn!(Toast, DefaultStyle);
+
+/// Toast default style.
+#[widget($crate::DefaultStyle)]
+pub struct DefaultStyle(Style);
+impl DefaultStyle {
+    fn widget_intrinsic(&mut self) {
+        widget_set! {
+            self;
+
+            replace = true;
+
+            background_color = light_dark(rgb(0.2, 0.2, 0.2), rgb(0.85, 0.85, 0.85));
+            zng_wgt_text::font_color = light_dark(colors::WHITE, colors::BLACK);
+            drop_shadow = {
+                offset: 2,
+                blur_radius: 4,
+                color: colors::BLACK.with_alpha(40.pct()),
+            };
+
+            corner_radius = 6;
+            zng_wgt_container::padding = (8, 12);
+            zng_wgt_size_offset::max_width = 400;
+
+            zng_wgt_container::child_out_end = TOAST_ACTIONS_VAR.present(wgt_fn!(|actions: Vec<ToastAction>| {
+                Stack! {
+                    direction = StackDirection::left_to_right();
+                    spacing = 4;
+                    margin = (0, 0, 0, 8);
+                    children = actions.into_iter().map(|a| presenter(a, ACTION_FN_VAR));
+                }
+            }));
+
+            #[easing(150.ms())]
+            zng_wgt_filter::opacity = 0.pct();
+            when *#is_inited {
+                zng_wgt_filter::opacity = 100.pct();
+            }
+        }
+    }
+}
+
+context_var! {
+    /// Toast actions, usually placed as `child_out_end`.
+    pub static TOAST_ACTIONS_VAR: Vec<ToastAction> = vec![];
+    /// Toast action button generator.
+    pub static ACTION_FN_VAR: WidgetFn<ToastAction> = WidgetFn::new(default_action_fn);
+    /// Time the toast stays open.
+    pub static DURATION_VAR: Duration = TOASTS.duration();
+}
+
+/// Default value of [`action_fn`](fn@action_fn).
+pub fn default_action_fn(action: ToastAction) -> UiNode {
+    Button! {
+        style_fn = zng_wgt_button::LightStyle!();
+        child = Text!(action.label.clone());
+        on_click = hn_once!(|a: &zng_wgt_input::gesture::ClickArgs| {
+            a.propagation.stop();
+            TOASTS.respond(ToastResponse::Action(action.name));
+        });
+    }
+}
+
+/// Toast actions.
+///
+/// The actions are presented as buttons, selecting an action closes the toast with [`ToastResponse::Action`].
+///
+/// This property sets the [`TOAST_ACTIONS_VAR`].
+#[property(CONTEXT, default(TOAST_ACTIONS_VAR), widget_impl(Toast))]
+pub fn actions(child: impl IntoUiNode, actions: impl IntoVar<Vec<ToastAction>>) -> UiNode {
+    with_context_var(child, TOAST_ACTIONS_VAR, actions)
+}
+
+/// Toast action button generator.
+///
+/// This property sets the [`ACTION_FN_VAR`].
+#[property(CONTEXT, default(ACTION_FN_VAR), widget_impl(Toast))]
+pub fn action_fn(child: impl IntoUiNode, action: impl IntoVar<WidgetFn<ToastAction>>) -> UiNode {
+    with_context_var(child, ACTION_FN_VAR, action)
+}
+
+/// Time the toast stays open.
+///
+/// Is [`TOASTS.duration`] by default, if set to [`Duration::MAX`] the toast only closes on response.
+///
+/// This property sets the [`DURATION_VAR`].
+///
+/// [`TOASTS.duration`]: TOASTS::duration
+#[property(CONTEXT, default(DURATION_VAR), widget_impl(Toast))]
+pub fn duration(child: impl IntoUiNode, duration: impl IntoVar<Duration>) -> UiNode {
+    with_context_var(child, DURATION_VAR, duration)
+}
+
+fn toast_timeout_node(child: impl IntoUiNode) -> UiNode {
+    let mut deadline = None::<DeadlineVar>;
+    let mut _deadline_handle = VarHandle::dummy();
+    let mut is_hovered = false;
+
+    let start = |deadline: &mut Option<DeadlineVar>, handle: &mut VarHandle| {
+        let d = DURATION_VAR.get();
+        if d == Duration::MAX {
+            *deadline = None;
+            *handle = VarHandle::dummy();
+        } else {
+            let d = TIMERS.deadline(d);
+            *handle = d.subscribe(UpdateOp::Update, WIDGET.id());
+            *deadline = Some(d);
+        }
+    };
+
+    match_node(child, move |_, op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_var(&DURATION_VAR).sub_event(&MOUSE_HOVERED_EVENT);
+            start(&mut deadline, &mut _deadline_handle);
+        }
+        UiNodeOp::Deinit => {
+            deadline = None;
+            _deadline_handle = VarHandle::dummy();
+            is_hovered = false;
+        }
+        UiNodeOp::Update { .. } => {
+            let wgt = (WINDOW.id(), WIDGET.id());
+            let mut restart = DURATION_VAR.is_new();
+            MOUSE_HOVERED_EVENT.each_update(true, |args| {
+                if args.is_mouse_enter(wgt) {
+                    is_hovered = true;
+                } else if args.is_mouse_leave(wgt) {
+                    is_hovered = false;
+                    restart = true;
+                }
+            });
+
+            if is_hovered {
+                deadline = None;
+                _deadline_handle = VarHandle::dummy();
+            } else if restart {
+                start(&mut deadline, &mut _deadline_handle);
+            } else if let Some(d) = &deadline
+                && d.get().has_elapsed()
+            {
+                deadline = None;
+                _deadline_handle = VarHandle::dummy();
+                TOASTS.respond(ToastResponse::Timeout);
+            }
+        }
+        _ => {}
+    })
+}
+
+/// Toast action.
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct ToastAction {
+    /// Action identifying name.
+    pub name: Txt,
+    /// Action button label.
+    pub label: Var<Txt>,
+}
+impl fmt::Debug for ToastAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.name)
+    }
+}
+impl PartialEq for ToastAction {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+impl ToastAction {
+    /// New from name and label.
+    pub fn new(name: impl Into<Txt>, label: impl IntoVar<Txt>) -> Self {
+        Self {
+            name: name.into(),
+            label: label.into_var(),
+        }
+    }
+}
+
+/// Toast response.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ToastResponse {
+    /// An action was selected, contains the action name.
+    Action(Txt),
+    /// The toast closed after the [`duration`](fn@duration) elapsed.
+    Timeout,
+    /// The toast was closed without response, by [`TOASTS.dismiss_all`] or because the window closed.
+    ///
+    /// [`TOASTS.dismiss_all`]: TOASTS::dismiss_all
+    Dismissed,
+}
+
+/// Toasts service.
+///
+/// Toasts are shown stacked in a corner of the window in the [`LayerIndex::TOP_MOST`] layer, the toasts layer is
+/// a polite live region so screen readers announce new messages. Only [`max_visible`] toasts are shown at a time,
+/// more toasts are queued and shown when the visible ones close.
+///
+/// # Panics
+///
+/// The show methods panic if not called inside a window.
+///
+/// [`max_visible`]: Self::max_visible
+pub struct TOASTS;
+impl TOASTS {
+    /// Show a text message toast.
+    pub fn show(&self, msg: impl IntoVar<Txt>) -> ResponseVar<ToastResponse> {
+        self.custom(Toast! {
+            child = Text!(msg);
+        })
+    }
+
+    /// Show a text message toast with action buttons.
+    pub fn show_actions(&self, msg: impl IntoVar<Txt>, actions: impl IntoVar<Vec<ToastAction>>) -> ResponseVar<ToastResponse> {
+        self.custom(Toast! {
+            child = Text!(msg);
+            actions;
+        })
+    }
+
+    /// Show the custom `toast`.
+    ///
+    /// The `toast` is usually a [`Toast!`] widget, but can be any widget that calls [`respond`] to close.
+    ///
+    /// [`Toast!`]: struct@Toast
+    /// [`respond`]: Self::respond
+    pub fn custom(&self, toast: impl IntoUiNode) -> ResponseVar<ToastResponse> {
+        self.show_impl(toast.into_node())
+    }
+
+    /// Close the contextual toast with the `response`.
+    pub fn respond(&self, response: ToastResponse) {
+        let ctx = TOAST_CTX.get();
+        let id = *ctx.toast_id.lock();
+        if let (Some(id), Some(host)) = (id, ctx.host.upgrade()) {
+            ctx.responder.respond(response);
+            host.list.remove(id);
+        } else {
+            tracing::error!("TOASTS.respond called outside of a toast");
+        }
+    }
+
+    /// Close all toasts and clear the queue of the current window.
+    ///
+    /// The toasts respond with [`ToastResponse::Dismissed`].
+    pub fn dismiss_all(&self) {
+        if let Some(host) = WINDOW.with_state(|s| s.get_clone(*TOASTS_HOST_ID)) {
+            host.dismiss_queued();
+            host.list.retain(|_| false);
+        }
+    }
+
+    /// Variable that defines the alignment of the toasts stack in the window.
+    ///
+    /// Is [`Align::BOTTOM_END`] by default. Toasts stack up from bottom alignments and down from other alignments.
+    pub fn align(&self) -> Var<Align> {
+        TOASTS_SV.read().align.clone()
+    }
+
+    /// Variable that defines the default time a toast stays open.
+    ///
+    /// Is 4 seconds by default.
+    pub fn duration(&self) -> Var<Duration> {
+        TOASTS_SV.read().duration.clone()
+    }
+
+    /// Variable that defines the maximum number of toasts visible at the same time in a window.
+    ///
+    /// Is `3` by default.
+    pub fn max_visible(&self) -> Var<usize> {
+        TOASTS_SV.read().max_visible.clone()
+    }
+
+    fn show_impl(&self, toast: UiNode) -> ResponseVar<ToastResponse> {
+        let host = match WINDOW.with_state(|s| s.get_clone(*TOASTS_HOST_ID)) {
+            Some(h) => h,
+            None => {
+                let list = EditableUiVec::new();
+                let host = Arc::new(ToastHost {
+                    list: list.reference(),
+                    queue: Mutex::new(VecDeque::new()),
+                    visible: AtomicUsize::new(0),
+                });
+                WINDOW.set_state(*TOASTS_HOST_ID, host.clone());
+                LAYERS.insert(LayerIndex::TOP_MOST, host_node(list));
+                host
+            }
+        };
+
+        let (responder, response) = response_var();
+        let queued_responder = responder.clone();
+        let mut ctx = Some(Arc::new(ToastCtx {
+            toast_id: Mutex::new(None),
+            responder,
+            host: Arc::downgrade(&host),
+        }));
+        let toast = match_widget(toast, move |c, op| match &op {
+            UiNodeOp::Init => {
+                *ctx.as_ref().unwrap().toast_id.lock() = c.node().as_widget().map(|mut w| w.id());
+                TOAST_CTX.with_context(&mut ctx, || c.op(op));
+                // in case a non-standard toast widget is used
+                *ctx.as_ref().unwrap().toast_id.lock() = c.node().as_widget().map(|mut w| w.id());
+            }
+            UiNodeOp::Deinit => {
+                TOAST_CTX.with_context(&mut ctx, || c.op(op));
+                let ctx = ctx.as_ref().unwrap();
+                if ctx.responder.get().is_waiting() {
+                    ctx.responder.respond(ToastResponse::Dismissed);
+                }
+                if let Some(host) = ctx.host.upgrade() {
+                    host.visible.fetch_sub(1, Ordering::Relaxed);
+                    host.show_queued();
+                }
+            }
+            _ => {
+                TOAST_CTX.with_context(&mut ctx, || c.op(op));
+            }
+        });
+
+        host.queue.lock().push_back(QueuedToast {
+            toast,
+            responder: queued_responder,
+        });
+        host.show_queued();
+
+        response
+    }
+}
+
+fn host_node(list: EditableUiVec) -> UiNode {
+    let align = TOASTS.align();
+    Stack! {
+        direction = align.map(|a| {
+            if a.y() > 0.5.fct() {
+                StackDirection::bottom_to_top()
+            } else {
+                StackDirection::top_to_bottom()
+            }
+        });
+        spacing = 6;
+        margin = 12;
+        zng_wgt::align = align.clone();
+        zng_wgt_stack::children_align = align;
+        live = LiveIndicator::Polite, false, false;
+        children = list;
+    }
+}
+
+struct ToastHost {
+    list: EditableUiVecRef,
+    queue: Mutex<VecDeque<QueuedToast>>,
+    visible: AtomicUsize,
+}
+impl ToastHost {
+    fn show_queued(&self) {
+        let max = TOASTS.max_visible().get().max(1);
+        let mut queue = self.queue.lock();
+        while self.visible.load(Ordering::Relaxed) < max
+            && let Some(q) = queue.pop_front()
+        {
+            self.visible.fetch_add(1, Ordering::Relaxed);
+            self.list.push(q.toast);
+        }
+    }
+
+    fn dismiss_queued(&self) {
+        let queue = std::mem::take(&mut *self.queue.lock());
+        for q in queue {
+            q.responder.respond(ToastResponse::Dismissed);
+        }
+    }
+}
+impl Drop for ToastHost {
+    fn drop(&mut self) {
+        // window closed
+        self.dismiss_queued();
+    }
+}
+
+struct QueuedToast {
+    toast: UiNode,
+    responder: ResponderVar<ToastResponse>,
+}
+
+static_id! {
+    static ref TOASTS_HOST_ID: StateId<Arc<ToastHost>>;
+}
+
+struct ToastCtx {
+    toast_id: Mutex<Option<WidgetId>>,
+    responder: ResponderVar<ToastResponse>,
+    // weak to avoid a cycle, the host owns the queued toasts and the toasts own the context
+    host: Weak<ToastHost>,
+}
+context_local! {
+    static TOAST_CTX: ToastCtx = ToastCtx {
+        toast_id: Mutex::new(None),
+        responder: response_var().0,
+        host: Weak::new(),
+    };
+}
+
+struct ToastsService {
+    align: Var<Align>,
+    duration: Var<Duration>,
+    max_visible: Var<usize>,
+}
+app_local! {
+    static TOASTS_SV: ToastsService = ToastsService {
+        align: var(Align::BOTTOM_END),
+        duration: var(4.secs()),
+        max_visible: var(3),
+    };
+}
@@ -0,0 +1,5 @@
+SELECT_CMD =
+    .name = Select
+
+TOGGLE_CMD =
+    .name = Toggle
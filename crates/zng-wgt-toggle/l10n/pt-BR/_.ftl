@@ -0,0 +1,5 @@
+SELECT_CMD =
+    .name = Selecionar
+
+TOGGLE_CMD =
+    .name = Alternar
@@ -0,0 +1,7 @@
+### Auto generated by `cargo zng l10n`
+
+SELECT_CMD =
+    .name = Select
+
+TOGGLE_CMD =
+    .name = Toggle
@@ -27,7 +27,10 @@ command! {
     /// [`checked_opt`]: fn@super::checked_opt
     /// [`tristate`]: fn@super::tristate
     /// [`value`]: fn@super::value
-    pub static TOGGLE_CMD;
+    pub static TOGGLE_CMD {
+        l10n!: true,
+        name: "Toggle",
+    };
 
     /// Represents the **select** action.
     ///
@@ -39,7 +42,10 @@ command! {
     ///
     /// [`value`]: fn@super::value
     /// [`selector`]: fn@super::selector
-    pub static SELECT_CMD;
+    pub static SELECT_CMD {
+        l10n!: true,
+        name: "Select",
+    };
 }
 
 /// Represents a select operation that can be send to [`selector`] using [`SELECT_CMD`].
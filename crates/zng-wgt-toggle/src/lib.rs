@@ -35,6 +35,7 @@ use zng_wgt_size_offset::{size, x, y};
 use zng_wgt_style::{Style, impl_named_style_fn, impl_style_fn};
 
 pub mod cmd;
+pub mod segmented;
 
 /// A toggle button that flips a `bool` or `Option<bool>` variable on click, or selects a value.
 ///
@@ -0,0 +1,323 @@
+//! Segmented control widget, properties and styles.
+
+use zng_ext_input::focus::{DirectionalNav, TabNav};
+use zng_wgt::{corner_radius, prelude::*};
+use zng_wgt_access::{AccessRole, access_role};
+use zng_wgt_container::{child_align, padding};
+use zng_wgt_fill::background_color;
+use zng_wgt_input::{
+    focus::{directional_nav, focus_scope, tab_nav},
+    is_hovered,
+};
+use zng_wgt_style::{Style, StyleMix, impl_style_fn};
+use zng_wgt_text::Text;
+
+use crate::{Selector, Toggle, is_checked, selector};
+
+/// Connected toggle segments that select one of the options.
+///
+/// The [`segments`] define the segment labels, the [`selected`] variable is the index of the selected segment. All
+/// segments have the same size and a selection indicator slides behind the selected segment when it changes.
+///
+/// The widget is a radio group focus scope, `Tab` moves focus into and out of the group and the arrow keys move between
+/// segments.
+///
+/// # Shorthand
+///
+/// The `SegmentedControl!` macro provides a shorthand init that sets the segments, `SegmentedControl!(segments)`.
+///
+/// [`segments`]: fn@segments
+/// [`selected`]: fn@selected
+#[widget($crate::segmented::SegmentedControl {
+    ($segments:expr) => {
+        segments = $segments;
+    };
+})]
+pub struct SegmentedControl(StyleMix<WidgetBase>);
+impl SegmentedControl {
+    fn widget_intrinsic(&mut self) {
+        self.style_intrinsic(STYLE_FN_VAR, property_id!(self::style_fn));
+
+        widget_set! {
+            self;
+            access_role = AccessRole::RadioGroup;
+            selector = Selector::single(SELECTED_VAR);
+            focus_scope = true;
+            tab_nav = TabNav::Once;
+            directional_nav = DirectionalNav::Contained;
+        }
+
+        self.widget_builder().push_build_action(|wgt| {
+            wgt.set_child(segments_node());
+        });
+    }
+}
+impl_style_fn!(SegmentedControl, DefaultStyle);
+
+/// Default segmented control style.
+#[widget($crate::segmented::DefaultStyle)]
+pub struct DefaultStyle(Style);
+impl DefaultStyle {
+    fn widget_intrinsic(&mut self) {
+        widget_set! {
+            self;
+            replace = true;
+            padding = 2;
+            corner_radius = 6;
+            background_color = zng_wgt_text::FONT_COLOR_VAR.map(|c| c.with_alpha(6.pct()));
+            indicator_color = colors::ACCENT_COLOR_VAR.rgba_map(|c| c.with_alpha(40.pct()));
+        }
+    }
+}
+
+/// Segmented control segment style.
+///
+/// Style a [`Toggle!`] widget to look like a segment, the checked state is shown by the control indicator.
+///
+/// [`Toggle!`]: struct@Toggle
+#[widget($crate::segmented::SegmentStyle)]
+pub struct SegmentStyle(Style);
+impl SegmentStyle {
+    fn widget_intrinsic(&mut self) {
+        widget_set! {
+            self;
+            replace = true;
+            access_role = AccessRole::Radio;
+            padding = (4, 12);
+            corner_radius = 4;
+            child_align = Align::CENTER;
+
+            when *#is_hovered && !*#is_checked {
+                background_color = zng_wgt_text::FONT_COLOR_VAR.map(|c| c.with_alpha(8.pct()));
+            }
+        }
+    }
+}
+
+/// Arguments for the [`segment_fn`].
+///
+/// [`segment_fn`]: fn@segment_fn
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct SegmentArgs {
+    /// Segment index, the toggle value.
+    pub index: usize,
+    /// Segment label.
+    pub label: Txt,
+}
+
+context_var! {
+    /// Segment labels.
+    pub static SEGMENTS_VAR: Vec<Txt> = vec![];
+    /// Selected segment index.
+    pub static SELECTED_VAR: usize = 0;
+    /// Segment widget generator.
+    pub static SEGMENT_FN_VAR: WidgetFn<SegmentArgs> = WidgetFn::new(default_segment_fn);
+    /// Selection indicator color.
+    pub static INDICATOR_COLOR_VAR: Rgba = colors::GRAY.with_alpha(40.pct());
+}
+
+/// Segment labels.
+///
+/// This property sets the [`SEGMENTS_VAR`].
+#[property(CONTEXT, default(SEGMENTS_VAR), widget_impl(SegmentedControl))]
+pub fn segments(child: impl IntoUiNode, segments: impl IntoVar<Vec<Txt>>) -> UiNode {
+    with_context_var(child, SEGMENTS_VAR, segments)
+}
+
+/// Selected segment index.
+///
+/// The variable is set when a segment is clicked, so it must be read-write for that.
+///
+/// This property sets the [`SELECTED_VAR`].
+#[property(CONTEXT, default(SELECTED_VAR), widget_impl(SegmentedControl))]
+pub fn selected(child: impl IntoUiNode, index: impl IntoVar<usize>) -> UiNode {
+    with_context_var(child, SELECTED_VAR, index)
+}
+
+/// Segment widget generator.
+///
+/// The widget must be a [`Toggle!`] with `value::<usize>` set to the segment index.
+///
+/// This property sets the [`SEGMENT_FN_VAR`].
+///
+/// [`Toggle!`]: struct@Toggle
+#[property(CONTEXT, default(SEGMENT_FN_VAR), widget_impl(SegmentedControl))]
+pub fn segment_fn(child: impl IntoUiNode, wgt_fn: impl IntoVar<WidgetFn<SegmentArgs>>) -> UiNode {
+    with_context_var(child, SEGMENT_FN_VAR, wgt_fn)
+}
+
+/// Selection indicator color.
+///
+/// This property sets the [`INDICATOR_COLOR_VAR`].
+#[property(CONTEXT, default(INDICATOR_COLOR_VAR), widget_impl(SegmentedControl))]
+pub fn indicator_color(child: impl IntoUiNode, color: impl IntoVar<Rgba>) -> UiNode {
+    with_context_var(child, INDICATOR_COLOR_VAR, color)
+}
+
+/// Default value of [`segment_fn`].
+///
+/// A [`Toggle!`] with the [`SegmentStyle!`] and the label text.
+///
+/// [`segment_fn`]: fn@segment_fn
+/// [`Toggle!`]: struct@Toggle
+/// [`SegmentStyle!`]: struct@SegmentStyle
+pub fn default_segment_fn(args: SegmentArgs) -> UiNode {
+    Toggle! {
+        style_fn = SegmentStyle!();
+        child = Text!(args.label);
+        value::<usize> = args.index;
+    }
+}
+
+/// Children are the segments, laid out with equal sizes over the sliding indicator.
+fn segments_node() -> UiNode {
+    let indicator = var(0.fct());
+    let mut _animation = AnimationHandle::dummy();
+    let mut segment_size = PxSize::zero();
+    let mut is_rtl = false;
+    let offset_key = FrameValueKey::new_unique();
+    let mut is_pushed = false;
+    // indicator offset transform, if it is visible
+    let indicator_offset = |segment_size: PxSize, is_rtl: bool, i: Factor| {
+        let count = SEGMENTS_VAR.with(|s| s.len());
+        if count > 0 && SELECTED_VAR.get() < count {
+            let i = if is_rtl { (count - 1) as f32 - i.0 } else { i.0 };
+            let x = Px((segment_size.width.0 as f32 * i).round() as i32);
+            Some(PxTransform::from(PxVector::new(x, Px(0))))
+        } else {
+            None
+        }
+    };
+
+    let generate = || {
+        let segment_fn = SEGMENT_FN_VAR.get();
+        let segments = SEGMENTS_VAR.get();
+        PanelList::new(
+            segments
+                .into_iter()
+                .enumerate()
+                .map(|(index, label)| segment_fn(SegmentArgs { index, label }))
+                .collect::<UiVec>(),
+        )
+        .into_node()
+    };
+
+    match_node(UiNode::nil(), move |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET
+                .sub_var(&SEGMENTS_VAR)
+                .sub_var(&SEGMENT_FN_VAR)
+                .sub_var(&SELECTED_VAR)
+                .sub_var_render_update(&indicator)
+                .sub_var_render(&INDICATOR_COLOR_VAR);
+
+            *c.node() = generate();
+            c.init();
+            indicator.set((SELECTED_VAR.get() as f32).fct());
+        }
+        UiNodeOp::Deinit => {
+            c.deinit();
+            *c.node() = UiNode::nil();
+            _animation = AnimationHandle::dummy();
+        }
+        UiNodeOp::Update { updates } => {
+            if SEGMENTS_VAR.is_new() || SEGMENT_FN_VAR.is_new() {
+                c.node().deinit();
+                *c.node() = generate();
+                c.node().init();
+                WIDGET.update_info().layout().render();
+            } else {
+                let mut changed = false;
+                c.update_list(updates, &mut changed);
+                if changed {
+                    WIDGET.layout();
+                }
+            }
+
+            if let Some(i) = SELECTED_VAR.get_new() {
+                if is_pushed != (i < SEGMENTS_VAR.with(|s| s.len())) {
+                    WIDGET.render();
+                }
+                _animation = indicator.ease((i as f32).fct(), 150.ms(), |t| easing::ease_out(easing::quad, t));
+            }
+        }
+        UiNodeOp::Measure { wm, desired_size } => {
+            c.delegated();
+
+            let constraints = LAYOUT.constraints();
+            let child_constraints = constraints.with_new_min(Px(0), Px(0)).with_unbounded_x();
+            let mut size = PxSize::zero();
+            let mut count = 0i32;
+            c.node_impl::<PanelList>().for_each_child(|_, c, _| {
+                size = size.max(LAYOUT.with_constraints(child_constraints, || c.measure(wm)));
+                count += 1;
+            });
+            *desired_size = constraints.clamp_size(PxSize::new(size.width * count, size.height));
+        }
+        UiNodeOp::Layout { wl, final_size } => {
+            c.delegated();
+
+            let constraints = LAYOUT.constraints();
+            let children = c.node_impl::<PanelList>();
+
+            let child_constraints = constraints.with_new_min(Px(0), Px(0)).with_unbounded_x();
+            let mut size = PxSize::zero();
+            let mut count = 0i32;
+            children.for_each_child(|_, c, _| {
+                size = size.max(LAYOUT.with_constraints(child_constraints, || c.measure(&mut wl.to_measure(None))));
+                count += 1;
+            });
+            if count > 0 {
+                let width = constraints.x.fill_or(size.width * count);
+                size.width = width / count;
+            }
+            size.height = constraints.y.fill_or(size.height);
+            segment_size = size;
+            is_rtl = LAYOUT.direction().is_rtl();
+
+            children.layout_list(
+                wl,
+                |i, c, o, wl| {
+                    let (_, define_ref_frame) =
+                        LAYOUT.with_constraints(PxConstraints2d::new_exact_size(size), || wl.with_child(|wl| c.layout(wl)));
+                    let i = if is_rtl { count - 1 - i as i32 } else { i as i32 };
+                    o.child_offset = PxVector::new(size.width * i, Px(0));
+                    o.define_reference_frame = define_ref_frame;
+                    size
+                },
+                |_, _| PxSize::zero(),
+            );
+            children.commit_data().request_render();
+
+            *final_size = constraints.clamp_size(PxSize::new(size.width * count, size.height));
+        }
+        UiNodeOp::Render { frame } => {
+            let offset = indicator_offset(segment_size, is_rtl, indicator.get());
+            is_pushed = offset.is_some();
+            if let Some(offset) = offset {
+                let rect = PxRect::from_size(segment_size);
+                let radius = Dip::new(4).to_px(frame.scale_factor());
+                frame.push_reference_frame(
+                    offset_key.into(),
+                    offset_key.bind(offset, indicator.is_animating()),
+                    true,
+                    false,
+                    |frame| {
+                        frame.push_clip_rounded_rect(rect, PxCornerRadius::new_all(PxSize::splat(radius)), false, false, |frame| {
+                            frame.push_color(rect, FrameValue::Value(INDICATOR_COLOR_VAR.get()));
+                        });
+                    },
+                );
+            }
+            c.render(frame);
+        }
+        UiNodeOp::RenderUpdate { update } => {
+            if is_pushed && let Some(offset) = indicator_offset(segment_size, is_rtl, indicator.get()) {
+                update.update_transform(offset_key.update(offset, indicator.is_animating()), false);
+            }
+            c.render_update(update);
+        }
+        _ => {}
+    })
+}
@@ -0,0 +1,791 @@
+#![doc(html_favicon_url = "https://zng-ui.github.io/res/zng-logo-icon.png")]
+#![doc(html_logo_url = "https://zng-ui.github.io/res/zng-logo.png")]
+//!
+//! Tree view widget and properties.
+//!
+//! # Crate
+//!
+#![doc = include_str!(concat!("../", std::env!("CARGO_PKG_README")))]
+#![warn(unused_extern_crates)]
+#![warn(missing_docs)]
+
+zng_wgt::enable_widget_macros!();
+
+use std::{
+    collections::{HashMap, HashSet},
+    mem,
+    num::NonZeroU32,
+};
+
+use zng_ext_input::keyboard::{KEY_INPUT_EVENT, Key, KeyInputArgs, KeyState};
+use zng_wgt::{Wgt, prelude::*};
+use zng_wgt_access::{AccessRole, access_role};
+use zng_wgt_container::Container;
+use zng_wgt_input::{focus::focusable, gesture::ClickArgs};
+use zng_wgt_scroll::{
+    LazyMode, Scroll, ScrollMode,
+    cmd::{ScrollToMode, scroll_to},
+};
+use zng_wgt_style::{Style, StyleMix, impl_style_fn};
+use zng_wgt_text::Text;
+
+/// Hierarchical items view with expandable rows.
+///
+/// The [`items`] define the tree, the [`expanded`] variable contains the keys of the items that show their children
+/// and the [`selected`] variable contains the key of the selected item. Rows are generated by [`item_fn`] for each
+/// visible item, indented by [`indent`] for each level, with optional indentation guides.
+///
+/// Rows that are not inside the viewport are lazy, only a placeholder of [`row_height`] is inited for them, so large
+/// trees only pay for the visible rows.
+///
+/// The widget is a single focus stop, when focused the arrow keys move the selection, `Right` expands or moves to the first child,
+/// `Left` collapses or moves to the parent, `Home` and `End` move to the first and last row and `Enter` or `Space` toggle expansion.
+/// The [`AccessRole::Tree`] role is set on the widget and the [`AccessRole::TreeItem`] role, level, position and state
+/// is set on each row automatically.
+///
+/// # Shorthand
+///
+/// The `TreeView!` macro provides a shorthand init that sets the items, `TreeView!(items)`.
+///
+/// [`items`]: fn@items
+/// [`expanded`]: fn@expanded
+/// [`selected`]: fn@selected
+/// [`item_fn`]: fn@item_fn
+/// [`indent`]: fn@indent
+/// [`row_height`]: fn@row_height
+#[widget($crate::TreeView {
+    ($items:expr) => {
+        items = $items;
+    };
+})]
+pub struct TreeView(StyleMix<WidgetBase>);
+impl TreeView {
+    fn widget_intrinsic(&mut self) {
+        self.style_intrinsic(STYLE_FN_VAR, property_id!(self::style_fn));
+
+        widget_set! {
+            self;
+            access_role = AccessRole::Tree;
+            focusable = true;
+        }
+
+        self.widget_builder().push_build_action(|wgt| {
+            wgt.set_child(node());
+        });
+    }
+}
+impl_style_fn!(TreeView, DefaultStyle);
+
+/// Default tree view style.
+#[widget($crate::DefaultStyle)]
+pub struct DefaultStyle(Style);
+impl DefaultStyle {
+    fn widget_intrinsic(&mut self) {
+        widget_set! {
+            self;
+            replace = true;
+        }
+    }
+}
+
+/// Tree item descriptor.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct TreeItem {
+    /// Unique item key, identifies the item in the [`expanded`](fn@expanded) and [`selected`](fn@selected) variables.
+    pub key: Txt,
+    /// Item text.
+    pub label: Txt,
+    /// Child items.
+    pub children: Vec<TreeItem>,
+}
+impl TreeItem {
+    /// New leaf item.
+    pub fn new(key: impl Into<Txt>, label: impl Into<Txt>) -> Self {
+        Self {
+            key: key.into(),
+            label: label.into(),
+            children: vec![],
+        }
+    }
+
+    /// Set the child items.
+    pub fn with_children(mut self, children: impl IntoIterator<Item = TreeItem>) -> Self {
+        self.children = children.into_iter().collect();
+        self
+    }
+}
+
+/// Arguments for the [`item_fn`].
+///
+/// [`item_fn`]: fn@item_fn
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct TreeItemArgs {
+    /// Item.
+    pub item: TreeItem,
+    /// Depth of the item, root items are level `0`.
+    pub level: u32,
+    /// If the item children are visible.
+    pub is_expanded: Var<bool>,
+    /// If the item is selected.
+    pub is_selected: Var<bool>,
+}
+
+context_var! {
+    /// Tree root items.
+    pub static ITEMS_VAR: Vec<TreeItem> = vec![];
+    /// Keys of the expanded items.
+    pub static EXPANDED_VAR: HashSet<Txt> = HashSet::new();
+    /// Key of the selected item.
+    pub static SELECTED_VAR: Option<Txt> = None;
+    /// Row content widget generator.
+    pub static ITEM_FN_VAR: WidgetFn<TreeItemArgs> = WidgetFn::new(default_item_fn);
+    /// Indentation width of each level.
+    pub static INDENT_VAR: Length = 16;
+    /// Height of the placeholder of rows that are not inited.
+    pub static ROW_HEIGHT_VAR: Length = 24;
+    /// If indentation guides are rendered.
+    pub static SHOW_GUIDES_VAR: bool = true;
+    /// Indentation guides color.
+    pub static GUIDES_COLOR_VAR: Rgba = colors::GRAY.with_alpha(40.pct());
+}
+
+/// Tree root items.
+///
+/// This property sets the [`ITEMS_VAR`].
+#[property(CONTEXT, default(ITEMS_VAR), widget_impl(TreeView))]
+pub fn items(child: impl IntoUiNode, items: impl IntoVar<Vec<TreeItem>>) -> UiNode {
+    with_context_var(child, ITEMS_VAR, items)
+}
+
+/// Keys of the expanded items.
+///
+/// The variable is modified when a row is expanded or collapsed by the user, so it must be read-write for that.
+///
+/// This property sets the [`EXPANDED_VAR`].
+#[property(CONTEXT, default(EXPANDED_VAR), widget_impl(TreeView))]
+pub fn expanded(child: impl IntoUiNode, keys: impl IntoVar<HashSet<Txt>>) -> UiNode {
+    with_context_var(child, EXPANDED_VAR, keys)
+}
+
+/// Key of the selected item.
+///
+/// The variable is set when a row is clicked or the selection is moved by keyboard.
+///
+/// This property sets the [`SELECTED_VAR`].
+#[property(CONTEXT, default(SELECTED_VAR), widget_impl(TreeView))]
+pub fn selected(child: impl IntoUiNode, key: impl IntoVar<Option<Txt>>) -> UiNode {
+    with_context_var(child, SELECTED_VAR, key)
+}
+
+/// Row content widget generator.
+///
+/// The content is placed after the indentation and expander of the row.
+///
+/// This property sets the [`ITEM_FN_VAR`].
+#[property(CONTEXT, default(ITEM_FN_VAR), widget_impl(TreeView))]
+pub fn item_fn(child: impl IntoUiNode, wgt_fn: impl IntoVar<WidgetFn<TreeItemArgs>>) -> UiNode {
+    with_context_var(child, ITEM_FN_VAR, wgt_fn)
+}
+
+/// Indentation width of each level.
+///
+/// The expander of each row has the same width.
+///
+/// This property sets the [`INDENT_VAR`].
+#[property(CONTEXT, default(INDENT_VAR), widget_impl(TreeView))]
+pub fn indent(child: impl IntoUiNode, indent: impl IntoVar<Length>) -> UiNode {
+    with_context_var(child, INDENT_VAR, indent)
+}
+
+/// Height of the placeholder of rows outside the viewport.
+///
+/// This is also the minimum height of rows, set it to the expected row height to avoid scroll jumps when rows init.
+///
+/// This property sets the [`ROW_HEIGHT_VAR`].
+#[property(CONTEXT, default(ROW_HEIGHT_VAR), widget_impl(TreeView))]
+pub fn row_height(child: impl IntoUiNode, height: impl IntoVar<Length>) -> UiNode {
+    with_context_var(child, ROW_HEIGHT_VAR, height)
+}
+
+/// If indentation guides are rendered.
+///
+/// This property sets the [`SHOW_GUIDES_VAR`].
+#[property(CONTEXT, default(SHOW_GUIDES_VAR), widget_impl(TreeView))]
+pub fn show_guides(child: impl IntoUiNode, show: impl IntoVar<bool>) -> UiNode {
+    with_context_var(child, SHOW_GUIDES_VAR, show)
+}
+
+/// Indentation guides color.
+///
+/// This property sets the [`GUIDES_COLOR_VAR`].
+#[property(CONTEXT, default(GUIDES_COLOR_VAR), widget_impl(TreeView))]
+pub fn guides_color(child: impl IntoUiNode, color: impl IntoVar<Rgba>) -> UiNode {
+    with_context_var(child, GUIDES_COLOR_VAR, color)
+}
+
+/// Default value of [`item_fn`].
+///
+/// A text with the item label.
+///
+/// [`item_fn`]: fn@item_fn
+pub fn default_item_fn(args: TreeItemArgs) -> UiNode {
+    Text!(args.item.label)
+}
+
+/// Tree view node.
+///
+/// Can be used directly to present a tree without declaring a tree view widget, the context variables are configured by
+/// the [`TreeView!`] properties. The node must be the child of a focusable widget to receive keyboard navigation.
+///
+/// [`TreeView!`]: struct@TreeView
+pub fn node() -> UiNode {
+    let row_ids = var(Vec::<(Txt, WidgetId)>::new());
+    let scroll = Scroll! {
+        mode = ScrollMode::VERTICAL;
+        child_align = Align::FILL_TOP;
+        focusable = false;
+        zng_wgt_input::focus::focus_scope = false;
+        child = rows_node(row_ids.clone());
+    };
+    keyboard_node(scroll, row_ids)
+}
+
+fn keyboard_node(child: impl IntoUiNode, row_ids: Var<Vec<(Txt, WidgetId)>>) -> UiNode {
+    match_node(child, move |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET
+                .sub_event(&KEY_INPUT_EVENT)
+                .sub_var_info(&SELECTED_VAR)
+                .sub_var_info(&row_ids);
+        }
+        UiNodeOp::Info { info } => {
+            c.info(info);
+            let id = SELECTED_VAR.with(|s| s.as_ref().and_then(|s| row_id(&row_ids, s)));
+            if let Some(id) = id
+                && let Some(mut a) = info.access()
+            {
+                a.set_active_descendant(id);
+            }
+        }
+        UiNodeOp::Update { updates } => {
+            c.update(updates);
+
+            KEY_INPUT_EVENT.each_update(false, |args| {
+                if args.state != KeyState::Pressed || !args.target.contains_enabled(WIDGET.id()) {
+                    return;
+                }
+                ITEMS_VAR.with(|items| EXPANDED_VAR.with(|expanded| navigate(args, &flatten(items, expanded), expanded, &row_ids)));
+            });
+        }
+        _ => {}
+    })
+}
+
+/// Move the selection or expand and collapse for the navigation keys.
+fn navigate(args: &KeyInputArgs, rows: &[Row], expanded: &HashSet<Txt>, row_ids: &Var<Vec<(Txt, WidgetId)>>) {
+    if rows.is_empty() {
+        return;
+    }
+    let last = rows.len() - 1;
+    let current = SELECTED_VAR.with(|s| s.as_ref().and_then(|s| rows.iter().position(|r| &r.item.key == s)));
+    let is_expanded = |i: usize| expanded.contains(&rows[i].item.key);
+    let (expand_key, collapse_key) = if DIRECTION_VAR.get().is_rtl() {
+        (Key::ArrowLeft, Key::ArrowRight)
+    } else {
+        (Key::ArrowRight, Key::ArrowLeft)
+    };
+
+    let next = match &args.key {
+        Key::ArrowDown => current.map(|i| (i + 1).min(last)).unwrap_or(0),
+        Key::ArrowUp => current.map(|i| i.saturating_sub(1)).unwrap_or(0),
+        Key::Home => 0,
+        Key::End => last,
+        Key::Enter | Key::Space => {
+            args.propagation.stop();
+            if let Some(i) = current
+                && rows[i].has_children()
+            {
+                toggle_expanded(&rows[i].item.key);
+            }
+            return;
+        }
+        k if k == &expand_key => match current {
+            Some(i) if rows[i].has_children() && !is_expanded(i) => {
+                args.propagation.stop();
+                set_expanded(&rows[i].item.key, true);
+                return;
+            }
+            // first child
+            Some(i) if rows[i].has_children() => (i + 1).min(last),
+            Some(i) => i,
+            None => 0,
+        },
+        k if k == &collapse_key => match current {
+            Some(i) if rows[i].has_children() && is_expanded(i) => {
+                args.propagation.stop();
+                set_expanded(&rows[i].item.key, false);
+                return;
+            }
+            Some(i) => rows[i].parent.unwrap_or(i),
+            None => 0,
+        },
+        _ => return,
+    };
+    args.propagation.stop();
+
+    let key = rows[next].item.key.clone();
+    if let Some(id) = row_id(row_ids, &key) {
+        scroll_to(id, ScrollToMode::minimal(0));
+    }
+    SELECTED_VAR.set(Some(key));
+}
+
+/// Children are the visible rows, rows are reused when items expand or collapse.
+fn rows_node(row_ids: Var<Vec<(Txt, WidgetId)>>) -> UiNode {
+    let mut rows = Rows::default();
+
+    match_node(UiNode::nil(), move |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET
+                .sub_var(&ITEMS_VAR)
+                .sub_var(&EXPANDED_VAR)
+                .sub_var(&SELECTED_VAR)
+                .sub_var(&ITEM_FN_VAR);
+
+            *c.node() = PanelList::new(ui_vec![]).into_node();
+            rows.sync(c.node_impl::<PanelList>(), &row_ids);
+        }
+        UiNodeOp::Deinit => {
+            c.deinit();
+            *c.node() = UiNode::nil();
+            rows = Rows::default();
+            row_ids.set(vec![]);
+        }
+        UiNodeOp::Update { updates } => {
+            if ITEM_FN_VAR.is_new() {
+                c.node().deinit();
+                rows = Rows::default();
+                *c.node() = PanelList::new(ui_vec![]).into_node();
+                rows.sync(c.node_impl::<PanelList>(), &row_ids);
+                WIDGET.update_info().layout().render();
+            } else if ITEMS_VAR.is_new() || EXPANDED_VAR.is_new() {
+                c.update(updates);
+                rows.sync(c.node_impl::<PanelList>(), &row_ids);
+                WIDGET.update_info().layout().render();
+            } else {
+                let mut changed = false;
+                c.update_list(updates, &mut changed);
+                if changed {
+                    WIDGET.layout();
+                }
+            }
+
+            if SELECTED_VAR.is_new() {
+                rows.select(SELECTED_VAR.get());
+            }
+        }
+        UiNodeOp::Measure { wm, desired_size } => {
+            c.delegated();
+
+            let constraints = LAYOUT.constraints();
+            let child_constraints = constraints.with_new_min_y(Px(0)).with_unbounded_y();
+            let mut size = PxSize::zero();
+            c.node_impl::<PanelList>().for_each_child(|_, c, _| {
+                let s = LAYOUT.with_constraints(child_constraints, || c.measure(wm));
+                size.width = size.width.max(s.width);
+                size.height += s.height;
+            });
+            *desired_size = constraints.clamp_size(size);
+        }
+        UiNodeOp::Layout { wl, final_size } => {
+            c.delegated();
+
+            let constraints = LAYOUT.constraints();
+            let children = c.node_impl::<PanelList>();
+
+            let child_constraints = constraints.with_new_min_y(Px(0)).with_unbounded_y();
+            let mut width = Px(0);
+            let mut heights = vec![];
+            children.for_each_child(|_, c, _| {
+                let s = LAYOUT.with_constraints(child_constraints, || c.measure(&mut wl.to_measure(None)));
+                width = width.max(s.width);
+                heights.push(s.height);
+            });
+            let width = constraints.x.fill_or(width);
+            let mut offsets = Vec::with_capacity(heights.len());
+            let mut y = Px(0);
+            for h in &heights {
+                offsets.push(y);
+                y += *h;
+            }
+
+            children.layout_list(
+                wl,
+                |i, c, o, wl| {
+                    let child_size = PxSize::new(width, heights[i]);
+                    let (_, define_ref_frame) =
+                        LAYOUT.with_constraints(PxConstraints2d::new_exact_size(child_size), || wl.with_child(|wl| c.layout(wl)));
+                    o.child_offset = PxVector::new(Px(0), offsets[i]);
+                    o.define_reference_frame = define_ref_frame;
+                    child_size
+                },
+                |_, _| PxSize::zero(),
+            );
+            children.commit_data().request_render();
+
+            *final_size = constraints.clamp_size(PxSize::new(width, y));
+        }
+        _ => {}
+    })
+}
+
+/// Flattened visible item.
+#[derive(Clone, Copy, Debug)]
+struct Row<'a> {
+    item: &'a TreeItem,
+    level: u32,
+    /// Parent row index.
+    parent: Option<usize>,
+    /// Index in the parent children.
+    index: usize,
+    /// Count of the parent children.
+    count: usize,
+}
+impl Row<'_> {
+    fn has_children(&self) -> bool {
+        !self.item.children.is_empty()
+    }
+}
+
+/// Items in display order, children of collapsed items are skipped.
+fn flatten<'a>(items: &'a [TreeItem], expanded: &HashSet<Txt>) -> Vec<Row<'a>> {
+    fn visit<'a>(items: &'a [TreeItem], level: u32, parent: Option<usize>, expanded: &HashSet<Txt>, out: &mut Vec<Row<'a>>) {
+        for (index, item) in items.iter().enumerate() {
+            let row = out.len();
+            out.push(Row {
+                item,
+                level,
+                parent,
+                index,
+                count: items.len(),
+            });
+            if !item.children.is_empty() && expanded.contains(&item.key) {
+                visit(&item.children, level + 1, Some(row), expanded, out);
+            }
+        }
+    }
+    let mut out = vec![];
+    visit(items, 0, None, expanded, &mut out);
+    out
+}
+
+/// Generated row.
+struct RowState {
+    item: TreeItem,
+    level: u32,
+    index: usize,
+    count: usize,
+    id: WidgetId,
+    is_expanded: Var<bool>,
+    is_selected: Var<bool>,
+}
+impl RowState {
+    /// If the row widget can be reused for `row`.
+    fn matches(&self, row: &Row) -> bool {
+        self.level == row.level && self.index == row.index && self.count == row.count && &self.item == row.item
+    }
+}
+
+/// Generated rows, in display order.
+#[derive(Default)]
+struct Rows {
+    rows: Vec<RowState>,
+    by_key: HashMap<Txt, usize>,
+    selected: Option<usize>,
+}
+impl Rows {
+    /// Reuse the row nodes of items that did not change, generate and init rows for new items, deinit removed rows.
+    fn sync(&mut self, panel: &mut PanelList, row_ids: &Var<Vec<(Txt, WidgetId)>>) {
+        let item_fn = ITEM_FN_VAR.get();
+        let selected = SELECTED_VAR.get();
+
+        let (mut list, ..) = mem::replace(panel, PanelList::new(ui_vec![])).into_parts();
+        let children = list.downcast_mut::<UiVec>().expect("expected rows `UiVec`");
+
+        let mut old = HashMap::with_capacity(self.rows.len());
+        for (r, n) in mem::take(&mut self.rows).into_iter().zip(children.drain(..)) {
+            if let Some((_, mut duplicate)) = old.insert(r.item.key.clone(), (r, n)) {
+                duplicate.deinit();
+            }
+        }
+
+        self.by_key.clear();
+        self.selected = None;
+        ITEMS_VAR.with(|items| {
+            EXPANDED_VAR.with(|expanded| {
+                for row in flatten(items, expanded) {
+                    let is_expanded = expanded.contains(&row.item.key);
+                    let (state, node) = match old.remove(&row.item.key) {
+                        Some((state, node)) if state.matches(&row) => {
+                            state.is_expanded.set(is_expanded);
+                            (state, node)
+                        }
+                        reused => {
+                            if let Some((_, mut node)) = reused {
+                                node.deinit();
+                            }
+                            let state = RowState {
+                                item: row.item.clone(),
+                                level: row.level,
+                                index: row.index,
+                                count: row.count,
+                                id: WidgetId::new_unique(),
+                                is_expanded: var(is_expanded),
+                                is_selected: var(false),
+                            };
+                            let mut node = row_widget(&state, &item_fn);
+                            node.init();
+                            (state, node)
+                        }
+                    };
+                    let is_selected = selected.as_ref() == Some(&state.item.key);
+                    if is_selected {
+                        self.selected = Some(self.rows.len());
+                    }
+                    state.is_selected.set(is_selected);
+                    self.by_key.entry(state.item.key.clone()).or_insert(self.rows.len());
+                    children.push(node);
+                    self.rows.push(state);
+                }
+            })
+        });
+        for (_, mut node) in old.into_values() {
+            node.deinit();
+        }
+
+        row_ids.set(self.rows.iter().map(|r| (r.item.key.clone(), r.id)).collect::<Vec<_>>());
+        *panel = PanelList::new(list);
+    }
+
+    /// Update the selected row state.
+    fn select(&mut self, key: Option<Txt>) {
+        if let Some(i) = self.selected.take()
+            && let Some(r) = self.rows.get(i)
+        {
+            r.is_selected.set(false);
+        }
+        if let Some(key) = key
+            && let Some(&i) = self.by_key.get(&key)
+        {
+            self.rows[i].is_selected.set(true);
+            self.selected = Some(i);
+        }
+    }
+}
+
+fn row_widget(row: &RowState, item_fn: &WidgetFn<TreeItemArgs>) -> UiNode {
+    let id = row.id;
+    let key = row.item.key.clone();
+    let has_children = !row.item.children.is_empty();
+    let level = row.level;
+    let is_expanded = row.is_expanded.read_only();
+    let is_selected = row.is_selected.read_only();
+
+    let content = item_fn(TreeItemArgs {
+        item: row.item.clone(),
+        level,
+        is_expanded: is_expanded.clone(),
+        is_selected: is_selected.clone(),
+    });
+    let content = if has_children {
+        zng_wgt_access::expanded(content, is_expanded.clone())
+    } else {
+        content
+    };
+
+    Container! {
+        id;
+        access_role = AccessRole::TreeItem;
+        zng_wgt_access::level = NonZeroU32::new(level + 1).unwrap();
+        zng_wgt_access::item_index = row.index;
+        zng_wgt_access::item_count = row.count;
+        zng_wgt_access::selected = is_selected.clone();
+        zng_wgt_scroll::lazy = LazyMode::lazy_vertical(wgt_fn!(|_| Wgt! {
+            zng_wgt_size_offset::height = ROW_HEIGHT_VAR;
+        }));
+        zng_wgt_size_offset::min_height = ROW_HEIGHT_VAR;
+        zng_wgt_container::padding = merge_var!(INDENT_VAR, DIRECTION_VAR, move |indent, d| {
+            let indent = indent.clone() * level as f32;
+            if d.is_rtl() {
+                SideOffsets::new(0, indent, 0, 4)
+            } else {
+                SideOffsets::new(0, 4, 0, indent)
+            }
+        });
+        zng_wgt_container::child_align = Align::START;
+        zng_wgt_container::child_start = expander(key.clone(), has_children, is_expanded);
+        zng_wgt_fill::background = guides_node(level);
+        child = content;
+        zng_wgt_input::gesture::on_click = hn!(|args: &ClickArgs| {
+            args.propagation.stop();
+            if args.is_double() && has_children {
+                toggle_expanded(&key);
+            }
+            SELECTED_VAR.set(Some(key.clone()));
+        });
+
+        when *#zng_wgt_input::is_hovered {
+            zng_wgt_fill::background_color = colors::ACCENT_COLOR_VAR.rgba_map(|c| c.with_alpha(15.pct()));
+        }
+        when *#{is_selected} {
+            zng_wgt_fill::background_color = colors::ACCENT_COLOR_VAR.rgba_map(|c| c.with_alpha(35.pct()));
+        }
+    }
+}
+
+/// Chevron that toggles the item expansion, or an empty space of the same width for leaf items.
+fn expander(key: Txt, has_children: bool, is_expanded: Var<bool>) -> UiNode {
+    if !has_children {
+        return Wgt! {
+            zng_wgt_size_offset::width = INDENT_VAR;
+        };
+    }
+    Text! {
+        txt = merge_var!(is_expanded, DIRECTION_VAR, |&e, d| {
+            Txt::from_static(if e {
+                "▾"
+            } else if d.is_rtl() {
+                "◂"
+            } else {
+                "▸"
+            })
+        });
+        txt_align = Align::CENTER;
+        zng_wgt_size_offset::width = INDENT_VAR;
+        zng_wgt_input::gesture::on_click = hn!(|args: &ClickArgs| {
+            args.propagation.stop();
+            toggle_expanded(&key);
+        });
+    }
+}
+
+/// Vertical lines centered on the expander of each ancestor level.
+fn guides_node(level: u32) -> UiNode {
+    let mut lines = vec![];
+    match_node(UiNode::nil(), move |_, op| match op {
+        UiNodeOp::Init => {
+            WIDGET
+                .sub_var_layout(&INDENT_VAR)
+                .sub_var_render(&SHOW_GUIDES_VAR)
+                .sub_var_render(&GUIDES_COLOR_VAR);
+        }
+        UiNodeOp::Layout { final_size, .. } => {
+            let size = LAYOUT.constraints().fill_size();
+            *final_size = size;
+
+            let indent = INDENT_VAR.layout_x();
+            let width = Dip::new(1).to_px(LAYOUT.scale_factor());
+            let is_rtl = LAYOUT.direction().is_rtl();
+            let new_lines: Vec<_> = (0..level as i32)
+                .map(|i| {
+                    let x = Px(indent.0 * i + indent.0 / 2);
+                    let x = if is_rtl { size.width - x - width } else { x };
+                    PxRect::new(PxPoint::new(x, Px(0)), PxSize::new(width, size.height))
+                })
+                .collect();
+            if lines != new_lines {
+                lines = new_lines;
+                WIDGET.render();
+            }
+        }
+        UiNodeOp::Render { frame } => {
+            if SHOW_GUIDES_VAR.get() {
+                let color = GUIDES_COLOR_VAR.get();
+                for line in &lines {
+                    frame.push_color(*line, FrameValue::Value(color));
+                }
+            }
+        }
+        _ => {}
+    })
+}
+
+fn row_id(row_ids: &Var<Vec<(Txt, WidgetId)>>, key: &Txt) -> Option<WidgetId> {
+    row_ids.with(|ids| ids.iter().find(|(k, _)| k == key).map(|(_, id)| *id))
+}
+
+fn set_expanded(key: &Txt, expanded: bool) {
+    let key = key.clone();
+    EXPANDED_VAR.modify(move |e| {
+        if expanded {
+            e.insert(key);
+        } else {
+            e.remove(&key);
+        }
+    });
+}
+
+fn toggle_expanded(key: &Txt) {
+    let key = key.clone();
+    EXPANDED_VAR.modify(move |e| {
+        if !e.remove(&key) {
+            e.insert(key);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items() -> Vec<TreeItem> {
+        vec![
+            TreeItem::new("a", "A").with_children([
+                TreeItem::new("a1", "A1"),
+                TreeItem::new("a2", "A2").with_children([TreeItem::new("a21", "A21")]),
+            ]),
+            TreeItem::new("b", "B"),
+        ]
+    }
+
+    fn expanded(keys: &[&'static str]) -> HashSet<Txt> {
+        keys.iter().map(|&k| Txt::from_static(k)).collect()
+    }
+
+    fn keys(rows: &[Row]) -> Vec<&str> {
+        rows.iter().map(|r| r.item.key.as_str()).collect()
+    }
+
+    #[test]
+    fn flatten_collapsed() {
+        let items = items();
+        let rows = flatten(&items, &expanded(&[]));
+        assert_eq!(keys(&rows), ["a", "b"]);
+        assert!(rows.iter().all(|r| r.level == 0 && r.parent.is_none() && r.count == 2));
+        assert_eq!(rows[1].index, 1);
+
+        // expanded child of collapsed parent and expanded leaf
+        let rows = flatten(&items, &expanded(&["a2", "b"]));
+        assert_eq!(keys(&rows), ["a", "b"]);
+    }
+
+    #[test]
+    fn flatten_expanded() {
+        let items = items();
+        let rows = flatten(&items, &expanded(&["a"]));
+        assert_eq!(keys(&rows), ["a", "a1", "a2", "b"]);
+        assert_eq!(rows.iter().map(|r| r.level).collect::<Vec<_>>(), [0, 1, 1, 0]);
+        assert_eq!(rows.iter().map(|r| r.parent).collect::<Vec<_>>(), [None, Some(0), Some(0), None]);
+        assert_eq!(rows.iter().map(|r| r.index).collect::<Vec<_>>(), [0, 0, 1, 1]);
+
+        let rows = flatten(&items, &expanded(&["a", "a2"]));
+        assert_eq!(keys(&rows), ["a", "a1", "a2", "a21", "b"]);
+        assert_eq!(rows[3].parent, Some(2));
+        assert_eq!(rows[3].level, 2);
+        assert_eq!(rows[3].count, 1);
+        assert!(rows[2].has_children());
+        assert!(!rows[3].has_children());
+    }
+}
@@ -0,0 +1,2 @@
+VirtualKeyboard =
+    .space = space
@@ -0,0 +1,4 @@
+### Auto generated by `cargo zng l10n`
+
+VirtualKeyboard =
+    .space = space
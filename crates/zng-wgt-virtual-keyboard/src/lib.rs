@@ -0,0 +1,714 @@
+#![doc(html_favicon_url = "https://zng-ui.github.io/res/zng-logo-icon.png")]
+#![doc(html_logo_url = "https://zng-ui.github.io/res/zng-logo.png")]
+//!
+//! On-screen virtual keyboard widget and service.
+//!
+//! # Crate
+//!
+#![doc = include_str!(concat!("../", std::env!("CARGO_PKG_README")))]
+#![warn(unused_extern_crates)]
+#![warn(missing_docs)]
+
+zng_wgt::enable_widget_macros!();
+
+use zng_app::view_process::{
+    raw_device_events::InputDeviceId,
+    raw_events::{RAW_KEY_INPUT_EVENT, RawKeyInputArgs},
+};
+use zng_ext_input::{
+    focus::{FOCUS, FOCUS_CHANGED_EVENT},
+    keyboard::{Key, KeyCode, KeyLocation, KeyState},
+    mouse::MOUSE_INPUT_EVENT,
+    touch::TOUCH_INPUT_EVENT,
+};
+use zng_ext_l10n::{LANG_VAR, Lang, l10n};
+use zng_ext_window::WidgetInfoImeArea as _;
+use zng_wgt::{corner_radius, prelude::*};
+use zng_wgt_button::Button;
+use zng_wgt_container::padding;
+use zng_wgt_fill::background_color;
+use zng_wgt_input::focus::focusable;
+use zng_wgt_layer::{LAYERS, LayerIndex};
+use zng_wgt_size_offset::{min_height, min_width};
+use zng_wgt_stack::{Stack, StackDirection};
+use zng_wgt_style::{Style, StyleMix, impl_style_fn};
+use zng_wgt_text::Text;
+
+/// On-screen keyboard.
+///
+/// The keyboard presents the [`keyboard_layout`] keys as buttons that inject key events for the focused widget
+/// using [`VIRTUAL_KEYBOARD.press`]. The key buttons are not focusable, so the focus stays in the text input.
+///
+/// The keyboard is usually not instantiated directly, the [`VIRTUAL_KEYBOARD`] service shows it in the
+/// [`LayerIndex::TOP_MOST`] layer of windows that enable [`virtual_keyboard_host`].
+///
+/// [`keyboard_layout`]: fn@keyboard_layout
+/// [`VIRTUAL_KEYBOARD.press`]: VIRTUAL_KEYBOARD::press
+/// [`virtual_keyboard_host`]: fn@virtual_keyboard_host
+#[widget($crate::VirtualKeyboard)]
+pub struct VirtualKeyboard(StyleMix<WidgetBase>);
+impl VirtualKeyboard {
+    fn widget_intrinsic(&mut self) {
+        self.style_intrinsic(STYLE_FN_VAR, property_id!(self::style_fn));
+
+        self.widget_builder().push_build_action(|wgt| {
+            wgt.set_child(keyboard_node());
+        });
+    }
+}
+impl_style_fn!(VirtualKeyboard, DefaultStyle);
+
+/// Default virtual keyboard style.
+#[widget($crate::DefaultStyle)]
+pub struct DefaultStyle(Style);
+impl DefaultStyle {
+    fn widget_intrinsic(&mut self) {
+        widget_set! {
+            self;
+            replace = true;
+            padding = 6;
+            background_color = light_dark(rgb(0.88, 0.88, 0.88), rgb(0.12, 0.12, 0.12));
+            zng_wgt::align = Align::BOTTOM;
+        }
+    }
+}
+
+/// Virtual keyboard key style.
+///
+/// Extends the button default style with a compact padding.
+#[widget($crate::KeyStyle)]
+pub struct KeyStyle(zng_wgt_button::DefaultStyle);
+impl KeyStyle {
+    fn widget_intrinsic(&mut self) {
+        widget_set! {
+            self;
+            padding = (8, 4);
+            corner_radius = 4;
+        }
+    }
+}
+
+/// A key in a [`KeyboardLayout`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct VirtualKey {
+    /// Physical key code reported in the key events.
+    pub code: KeyCode,
+    /// Semantic key.
+    pub key: Key,
+    /// Semantic key when shift is active.
+    pub shift_key: Key,
+    /// Width of the key, relative to a normal character key.
+    pub width: f32,
+}
+impl VirtualKey {
+    /// New character key, the shift key is the uppercase character.
+    pub fn char(code: KeyCode, c: char) -> Self {
+        Self::symbol(code, c, c.to_uppercase().next().unwrap_or(c))
+    }
+
+    /// New character key with a custom shift character.
+    pub fn symbol(code: KeyCode, c: char, shift_c: char) -> Self {
+        Self {
+            code,
+            key: Key::Char(c),
+            shift_key: Key::Char(shift_c),
+            width: 1.0,
+        }
+    }
+
+    /// New non-character key, like [`Key::Backspace`].
+    pub fn special(code: KeyCode, key: Key, width: f32) -> Self {
+        Self {
+            code,
+            shift_key: key.clone(),
+            key,
+            width,
+        }
+    }
+
+    /// Semantic key for the shift state.
+    pub fn key(&self, shift: bool) -> &Key {
+        if shift { &self.shift_key } else { &self.key }
+    }
+
+    /// Text typed by the key for the shift state.
+    pub fn text(&self, shift: bool) -> Txt {
+        match self.key(shift) {
+            Key::Char(c) => Txt::from_char(*c),
+            Key::Space => Txt::from_static(" "),
+            Key::Enter => Txt::from_static("\r"),
+            Key::Tab => Txt::from_static("\t"),
+            _ => Txt::from_static(""),
+        }
+    }
+
+    /// Key label for the shift state.
+    ///
+    /// Is the typed character or a symbol for special keys.
+    pub fn label(&self, shift: bool) -> Txt {
+        match self.key(shift) {
+            Key::Char(c) => Txt::from_char(*c),
+            Key::Backspace => Txt::from_static("⌫"),
+            Key::Enter => Txt::from_static("⏎"),
+            Key::Tab => Txt::from_static("⇥"),
+            Key::Shift => Txt::from_static(if shift { "⬆" } else { "⇧" }),
+            _ => Txt::from_static(""),
+        }
+    }
+}
+
+/// Rows of keys of a [`VirtualKeyboard!`].
+///
+/// [`VirtualKeyboard!`]: struct@VirtualKeyboard
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct KeyboardLayout {
+    /// Key rows, top to bottom.
+    pub rows: Vec<Vec<VirtualKey>>,
+}
+impl KeyboardLayout {
+    /// New from rows.
+    pub fn new(rows: Vec<Vec<VirtualKey>>) -> Self {
+        Self { rows }
+    }
+
+    /// Gets the layout commonly used with the `lang`.
+    ///
+    /// French uses [`azerty`], German and central European languages use [`qwertz`], all other languages
+    /// use [`qwerty`].
+    ///
+    /// [`azerty`]: Self::azerty
+    /// [`qwertz`]: Self::qwertz
+    /// [`qwerty`]: Self::qwerty
+    pub fn for_lang(lang: &Lang) -> Self {
+        let region = lang.0.region.as_ref().map(|r| r.as_str());
+        match lang.0.language.as_str() {
+            "fr" if region == Some("CH") => Self::qwertz(),
+            "fr" => Self::azerty(),
+            "de" | "cs" | "sk" | "hu" | "sl" | "hr" | "sq" => Self::qwertz(),
+            _ => Self::qwerty(),
+        }
+    }
+
+    /// US QWERTY layout.
+    pub fn qwerty() -> Self {
+        Self::letters(
+            "!@#$%^&*()",
+            &["qwertyuiop", "asdfghjkl", "zxcvbnm"],
+            &[
+                &[
+                    KeyCode::KeyQ,
+                    KeyCode::KeyW,
+                    KeyCode::KeyE,
+                    KeyCode::KeyR,
+                    KeyCode::KeyT,
+                    KeyCode::KeyY,
+                    KeyCode::KeyU,
+                    KeyCode::KeyI,
+                    KeyCode::KeyO,
+                    KeyCode::KeyP,
+                ],
+                &[
+                    KeyCode::KeyA,
+                    KeyCode::KeyS,
+                    KeyCode::KeyD,
+                    KeyCode::KeyF,
+                    KeyCode::KeyG,
+                    KeyCode::KeyH,
+                    KeyCode::KeyJ,
+                    KeyCode::KeyK,
+                    KeyCode::KeyL,
+                ],
+                &[
+                    KeyCode::KeyZ,
+                    KeyCode::KeyX,
+                    KeyCode::KeyC,
+                    KeyCode::KeyV,
+                    KeyCode::KeyB,
+                    KeyCode::KeyN,
+                    KeyCode::KeyM,
+                ],
+            ],
+        )
+    }
+
+    /// French AZERTY layout.
+    pub fn azerty() -> Self {
+        Self::letters(
+            "&é\"'(-è_çà",
+            &["azertyuiop", "qsdfghjklm", "wxcvbn"],
+            &[
+                &[
+                    KeyCode::KeyQ,
+                    KeyCode::KeyW,
+                    KeyCode::KeyE,
+                    KeyCode::KeyR,
+                    KeyCode::KeyT,
+                    KeyCode::KeyY,
+                    KeyCode::KeyU,
+                    KeyCode::KeyI,
+                    KeyCode::KeyO,
+                    KeyCode::KeyP,
+                ],
+                &[
+                    KeyCode::KeyA,
+                    KeyCode::KeyS,
+                    KeyCode::KeyD,
+                    KeyCode::KeyF,
+                    KeyCode::KeyG,
+                    KeyCode::KeyH,
+                    KeyCode::KeyJ,
+                    KeyCode::KeyK,
+                    KeyCode::KeyL,
+                    KeyCode::Semicolon,
+                ],
+                &[
+                    KeyCode::KeyZ,
+                    KeyCode::KeyX,
+                    KeyCode::KeyC,
+                    KeyCode::KeyV,
+                    KeyCode::KeyB,
+                    KeyCode::KeyN,
+                ],
+            ],
+        )
+    }
+
+    /// German QWERTZ layout.
+    pub fn qwertz() -> Self {
+        Self::letters(
+            "!\"§$%&/()=",
+            &["qwertzuiopü", "asdfghjklöä", "yxcvbnm"],
+            &[
+                &[
+                    KeyCode::KeyQ,
+                    KeyCode::KeyW,
+                    KeyCode::KeyE,
+                    KeyCode::KeyR,
+                    KeyCode::KeyT,
+                    KeyCode::KeyY,
+                    KeyCode::KeyU,
+                    KeyCode::KeyI,
+                    KeyCode::KeyO,
+                    KeyCode::KeyP,
+                    KeyCode::BracketLeft,
+                ],
+                &[
+                    KeyCode::KeyA,
+                    KeyCode::KeyS,
+                    KeyCode::KeyD,
+                    KeyCode::KeyF,
+                    KeyCode::KeyG,
+                    KeyCode::KeyH,
+                    KeyCode::KeyJ,
+                    KeyCode::KeyK,
+                    KeyCode::KeyL,
+                    KeyCode::Semicolon,
+                    KeyCode::Quote,
+                ],
+                &[
+                    KeyCode::KeyZ,
+                    KeyCode::KeyX,
+                    KeyCode::KeyC,
+                    KeyCode::KeyV,
+                    KeyCode::KeyB,
+                    KeyCode::KeyN,
+                    KeyCode::KeyM,
+                ],
+            ],
+        )
+    }
+
+    /// Digits row with the `digit_shift` symbols, three letter rows, shift, backspace and a bottom row with space and enter.
+    fn letters(digit_shift: &str, letters: &[&str; 3], codes: &[&[KeyCode]; 3]) -> Self {
+        const DIGIT_CODES: [KeyCode; 10] = [
+            KeyCode::Digit1,
+            KeyCode::Digit2,
+            KeyCode::Digit3,
+            KeyCode::Digit4,
+            KeyCode::Digit5,
+            KeyCode::Digit6,
+            KeyCode::Digit7,
+            KeyCode::Digit8,
+            KeyCode::Digit9,
+            KeyCode::Digit0,
+        ];
+        let digits = DIGIT_CODES
+            .into_iter()
+            .zip("1234567890".chars().zip(digit_shift.chars()))
+            .map(|(code, (c, s))| VirtualKey::symbol(code, c, s))
+            .collect();
+
+        let mut rows = vec![digits];
+        for (letters, codes) in letters.iter().zip(codes.iter()) {
+            rows.push(
+                codes
+                    .iter()
+                    .zip(letters.chars())
+                    .map(|(code, c)| VirtualKey::char(*code, c))
+                    .collect(),
+            );
+        }
+
+        let last = rows.last_mut().unwrap();
+        last.insert(0, VirtualKey::special(KeyCode::ShiftLeft, Key::Shift, 1.5));
+        last.push(VirtualKey::special(KeyCode::Backspace, Key::Backspace, 1.5));
+
+        rows.push(vec![
+            VirtualKey::symbol(KeyCode::Comma, ',', ';'),
+            VirtualKey::special(KeyCode::Space, Key::Space, 5.0),
+            VirtualKey::symbol(KeyCode::Period, '.', ':'),
+            VirtualKey::special(KeyCode::Enter, Key::Enter, 1.5),
+        ]);
+
+        Self { rows }
+    }
+}
+
+/// Arguments for the [`key_fn`].
+///
+/// [`key_fn`]: fn@key_fn
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct VirtualKeyArgs {
+    /// The key.
+    pub key: VirtualKey,
+}
+
+context_var! {
+    /// Keyboard layout.
+    ///
+    /// Is [`KeyboardLayout::for_lang`] of the best [`LANG_VAR`] language by default.
+    pub static KEYBOARD_LAYOUT_VAR: KeyboardLayout = LANG_VAR.map(|l| KeyboardLayout::for_lang(l.best()));
+    /// Key widget generator.
+    pub static KEY_FN_VAR: WidgetFn<VirtualKeyArgs> = WidgetFn::new(default_key_fn);
+}
+
+/// Keyboard layout.
+///
+/// Is the layout commonly used with the app language by default.
+///
+/// This property sets the [`KEYBOARD_LAYOUT_VAR`].
+#[property(CONTEXT, default(KEYBOARD_LAYOUT_VAR), widget_impl(VirtualKeyboard))]
+pub fn keyboard_layout(child: impl IntoUiNode, layout: impl IntoVar<KeyboardLayout>) -> UiNode {
+    with_context_var(child, KEYBOARD_LAYOUT_VAR, layout)
+}
+
+/// Key widget generator.
+///
+/// The widget must call [`VIRTUAL_KEYBOARD.press`] on click and must not be focusable.
+///
+/// This property sets the [`KEY_FN_VAR`].
+///
+/// [`VIRTUAL_KEYBOARD.press`]: VIRTUAL_KEYBOARD::press
+#[property(CONTEXT, default(KEY_FN_VAR), widget_impl(VirtualKeyboard))]
+pub fn key_fn(child: impl IntoUiNode, wgt_fn: impl IntoVar<WidgetFn<VirtualKeyArgs>>) -> UiNode {
+    with_context_var(child, KEY_FN_VAR, wgt_fn)
+}
+
+/// Default value of [`key_fn`].
+///
+/// A non-focusable [`Button!`] with the [`KeyStyle!`] and the key label.
+///
+/// [`key_fn`]: fn@key_fn
+/// [`Button!`]: struct@Button
+/// [`KeyStyle!`]: struct@KeyStyle
+pub fn default_key_fn(args: VirtualKeyArgs) -> UiNode {
+    let key = args.key;
+    let label = if key.key == Key::Space {
+        l10n!("VirtualKeyboard.space", "space")
+    } else {
+        VIRTUAL_KEYBOARD.shift().map(clmv!(key, |&s| key.label(s)))
+    };
+    Button! {
+        style_fn = KeyStyle!();
+        child = Text!(label);
+        focusable = false;
+        min_width = (key.width * 2.5).em();
+        min_height = 2.5.em();
+        on_click = hn!(|args: &zng_wgt_input::gesture::ClickArgs| {
+            args.propagation.stop();
+            VIRTUAL_KEYBOARD.press(&key);
+        });
+    }
+}
+
+/// Enable the virtual keyboard in the window.
+///
+/// When enabled the [`VIRTUAL_KEYBOARD`] can show in this window. This property must be set on the window widget,
+/// it is not enabled by default, apps that target touch screens must set it on their windows.
+#[property(CONTEXT, default(false))]
+pub fn virtual_keyboard_host(child: impl IntoUiNode, enabled: impl IntoVar<bool>) -> UiNode {
+    let enabled = enabled.into_var();
+    let mut is_touch = false;
+    let mut keyboard_id = None::<WidgetId>;
+    match_node(child, move |_, op| match op {
+        UiNodeOp::Init => {
+            WIDGET
+                .sub_var(&enabled)
+                .sub_var(&VIRTUAL_KEYBOARD_SV.read().open)
+                .sub_event(&FOCUS_CHANGED_EVENT)
+                .sub_event(&MOUSE_INPUT_EVENT)
+                .sub_event(&TOUCH_INPUT_EVENT);
+        }
+        UiNodeOp::Deinit => {
+            keyboard_id = None;
+            is_touch = false;
+        }
+        UiNodeOp::Update { .. } => {
+            let window_id = WINDOW.id();
+
+            MOUSE_INPUT_EVENT.each_update(true, |args| {
+                if args.is_mouse_down() {
+                    is_touch = false;
+                }
+            });
+            TOUCH_INPUT_EVENT.each_update(true, |args| {
+                if args.is_touch_start() {
+                    is_touch = true;
+                }
+            });
+            FOCUS_CHANGED_EVENT.each_update(true, |args| {
+                if !enabled.get() || !VIRTUAL_KEYBOARD.auto_show().get() {
+                    return;
+                }
+                let is_text_input = match &args.new_focus {
+                    Some(p) if p.window_id() == window_id => WINDOW.info().get(p.widget_id()).is_some_and(|w| w.ime_area().is_some()),
+                    _ => false,
+                };
+                let open = &VIRTUAL_KEYBOARD_SV.read().open;
+                if is_text_input {
+                    if is_touch {
+                        open.set(Some(window_id));
+                    }
+                } else if open.get() == Some(window_id) {
+                    open.set(None);
+                }
+            });
+
+            let show = enabled.get() && VIRTUAL_KEYBOARD_SV.read().open.get() == Some(window_id);
+            if show != keyboard_id.is_some() {
+                if show {
+                    let id = WidgetId::new_unique();
+                    LAYERS.insert(LayerIndex::TOP_MOST, VirtualKeyboard! { id; });
+                    keyboard_id = Some(id);
+                } else if let Some(id) = keyboard_id.take() {
+                    LAYERS.remove(id);
+                }
+            }
+        }
+        _ => {}
+    })
+}
+
+/// Virtual keyboard service.
+///
+/// The keyboard shows in windows that enable [`virtual_keyboard_host`], it can be shown on demand using [`show`] or
+/// automatically when a text input is focused by touch if [`auto_show`] is enabled. Automatic show is disabled by
+/// default, so desktop apps are not affected, kiosk and touch-only apps can bind it to a config.
+///
+/// [`virtual_keyboard_host`]: fn@virtual_keyboard_host
+/// [`show`]: Self::show
+/// [`auto_show`]: Self::auto_show
+pub struct VIRTUAL_KEYBOARD;
+impl VIRTUAL_KEYBOARD {
+    /// Variable that defines if the keyboard shows when a text input is focused by touch and hides when the
+    /// focus leaves text inputs.
+    ///
+    /// Is `false` by default.
+    pub fn auto_show(&self) -> Var<bool> {
+        VIRTUAL_KEYBOARD_SV.read().auto_show.clone()
+    }
+
+    /// Variable that is `true` when the keyboard is open in a window.
+    pub fn is_open(&self) -> Var<bool> {
+        VIRTUAL_KEYBOARD_SV.read().open.map(|o| o.is_some())
+    }
+
+    /// Variable that defines if the next character key types the shifted character.
+    ///
+    /// The shift key toggles this, it is disabled after a character key is pressed.
+    pub fn shift(&self) -> Var<bool> {
+        VIRTUAL_KEYBOARD_SV.read().shift.clone()
+    }
+
+    /// Show the keyboard in the focused window.
+    pub fn show(&self) {
+        if let Some(f) = FOCUS.focused().get() {
+            VIRTUAL_KEYBOARD_SV.read().open.set(Some(f.window_id()));
+        }
+    }
+
+    /// Hide the keyboard.
+    pub fn hide(&self) {
+        VIRTUAL_KEYBOARD_SV.read().open.set(None);
+    }
+
+    /// Inject the key press and release events for the `key` in the focused window.
+    ///
+    /// The events are raw key input events from the [`InputDeviceId::virtual_keyboard`] device, the keyboard service
+    /// handles them like any other key input, targeting the focused widget. The [`Key::Shift`] key only toggles [`shift`].
+    ///
+    /// [`shift`]: Self::shift
+    pub fn press(&self, key: &VirtualKey) {
+        let sv = VIRTUAL_KEYBOARD_SV.read();
+        if key.key == Key::Shift {
+            sv.shift.set(!sv.shift.get());
+            return;
+        }
+        let window_id = match FOCUS.focused().get() {
+            Some(f) => f.window_id(),
+            None => return,
+        };
+
+        let shift = sv.shift.get();
+        let key_modified = key.key(shift).clone();
+        let text = key.text(shift);
+        if shift && matches!(key.key, Key::Char(_)) {
+            sv.shift.set(false);
+        }
+
+        for (state, text) in [(KeyState::Pressed, text), (KeyState::Released, Txt::from_static(""))] {
+            RAW_KEY_INPUT_EVENT.notify(RawKeyInputArgs::now(
+                window_id,
+                InputDeviceId::virtual_keyboard(),
+                key.code,
+                KeyLocation::Standard,
+                state,
+                key.key.clone(),
+                key_modified.clone(),
+                text,
+            ));
+        }
+    }
+}
+
+/// Children are the key rows.
+fn keyboard_node() -> UiNode {
+    let generate = || {
+        let layout = KEYBOARD_LAYOUT_VAR.get();
+        let key_fn = KEY_FN_VAR.get();
+        Stack! {
+            direction = StackDirection::top_to_bottom();
+            spacing = 4;
+            children_align = Align::TOP;
+            children = layout
+                .rows
+                .into_iter()
+                .map(|row| {
+                    Stack! {
+                        direction = StackDirection::left_to_right();
+                        spacing = 4;
+                        children = row.into_iter().map(|key| key_fn(VirtualKeyArgs { key })).collect::<UiVec>();
+                    }
+                })
+                .collect::<UiVec>();
+        }
+    };
+
+    match_node(UiNode::nil(), move |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_var(&KEYBOARD_LAYOUT_VAR).sub_var(&KEY_FN_VAR);
+            *c.node() = generate();
+            c.init();
+        }
+        UiNodeOp::Deinit => {
+            c.deinit();
+            *c.node() = UiNode::nil();
+        }
+        UiNodeOp::Update { .. } => {
+            if KEYBOARD_LAYOUT_VAR.is_new() || KEY_FN_VAR.is_new() {
+                c.node().deinit();
+                *c.node() = generate();
+                c.node().init();
+                WIDGET.update_info().layout().render();
+            }
+        }
+        _ => {}
+    })
+}
+
+struct VirtualKeyboardService {
+    auto_show: Var<bool>,
+    open: Var<Option<WindowId>>,
+    shift: Var<bool>,
+}
+app_local! {
+    static VIRTUAL_KEYBOARD_SV: VirtualKeyboardService = VirtualKeyboardService {
+        auto_show: var(false),
+        open: var(None),
+        shift: var(false),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zng_ext_l10n::lang;
+
+    #[test]
+    fn char_key() {
+        let key = VirtualKey::char(KeyCode::KeyA, 'a');
+        assert_eq!(&Key::Char('a'), key.key(false));
+        assert_eq!(&Key::Char('A'), key.key(true));
+        assert_eq!("a", key.text(false));
+        assert_eq!("A", key.text(true));
+        assert_eq!("A", key.label(true));
+    }
+
+    #[test]
+    fn special_key() {
+        let key = VirtualKey::special(KeyCode::Backspace, Key::Backspace, 1.5);
+        assert_eq!(key.key(false), key.key(true));
+        assert_eq!("", key.text(false));
+        assert_eq!("⌫", key.label(false));
+
+        let key = VirtualKey::special(KeyCode::Space, Key::Space, 5.0);
+        assert_eq!(" ", key.text(true));
+
+        let key = VirtualKey::special(KeyCode::ShiftLeft, Key::Shift, 1.5);
+        assert_ne!(key.label(false), key.label(true));
+    }
+
+    #[test]
+    fn layout_for_lang() {
+        assert_eq!(KeyboardLayout::qwerty(), KeyboardLayout::for_lang(&lang!("en-US")));
+        assert_eq!(KeyboardLayout::azerty(), KeyboardLayout::for_lang(&lang!("fr-FR")));
+        assert_eq!(KeyboardLayout::qwertz(), KeyboardLayout::for_lang(&lang!("fr-CH")));
+        assert_eq!(KeyboardLayout::qwertz(), KeyboardLayout::for_lang(&lang!("de")));
+        assert_eq!(KeyboardLayout::qwerty(), KeyboardLayout::for_lang(&lang!("pt-BR")));
+    }
+
+    #[test]
+    fn layout_rows() {
+        for layout in [KeyboardLayout::qwerty(), KeyboardLayout::azerty(), KeyboardLayout::qwertz()] {
+            assert_eq!(5, layout.rows.len());
+            assert_eq!(10, layout.rows[0].len());
+            assert!(layout.rows[0].iter().all(|k| matches!(k.key, Key::Char(c) if c.is_ascii_digit())));
+
+            let shift_row = &layout.rows[3];
+            assert_eq!(Key::Shift, shift_row.first().unwrap().key);
+            assert_eq!(Key::Backspace, shift_row.last().unwrap().key);
+
+            assert!(layout.rows[4].iter().any(|k| k.key == Key::Space));
+            assert!(layout.rows[4].iter().any(|k| k.key == Key::Enter));
+        }
+    }
+
+    #[test]
+    fn layout_letters() {
+        let qwertz = KeyboardLayout::qwertz();
+        let first = &qwertz.rows[1];
+        assert_eq!(Key::Char('z'), first[5].key);
+        assert_eq!(KeyCode::KeyY, first[5].code);
+        assert_eq!(Key::Char('ü'), first[10].key);
+        assert_eq!(Key::Char('Ü'), first[10].shift_key);
+
+        let azerty = KeyboardLayout::azerty();
+        assert_eq!(Key::Char('a'), azerty.rows[1][0].key);
+        assert_eq!(KeyCode::KeyQ, azerty.rows[1][0].code);
+        assert_eq!(Key::Char('1'), azerty.rows[0][0].key);
+        assert_eq!(Key::Char('&'), azerty.rows[0][0].shift_key);
+    }
+}
@@ -9,6 +9,7 @@ use zng_app::widget::base::Parallel;
 use zng_ext_window::{
     AutoSize, MONITORS, MonitorQuery, WINDOW_Ext as _, WINDOW_LOAD_EVENT, WINDOWS, WindowButton, WindowIcon, WindowLoadingHandle,
     WindowState, WindowVars,
+    cmd::{DRAG_MOVE_RESIZE_CMD, OPEN_TITLE_BAR_CONTEXT_MENU_CMD},
 };
 use zng_var::AnyVar;
 use zng_wgt::prelude::*;
@@ -90,6 +91,7 @@ set_properties! {
     accent_color: Option<LightDark>,
 
     enabled_buttons: WindowButton,
+    drag_region: Option<DipRect>,
 
     parallel: Parallel,
 }
@@ -564,3 +566,255 @@ pub fn custom_chrome_adorner_fn(child: impl IntoUiNode, custom_chrome: impl Into
 pub fn custom_chrome_padding_fn(child: impl IntoUiNode, padding: impl IntoVar<SideOffsets>) -> UiNode {
     zng_wgt_container::padding(child, padding)
 }
+
+/// Starts a window move when the widget is pressed with the primary mouse button.
+///
+/// Only presses that target the widget directly start the move, so descendant widgets like buttons are not affected.
+/// This is a hit-test based alternative to the [`drag_region`] window property, use it to implement custom title bars.
+///
+/// The other system title bar interactions are also replicated, a double-click toggles the maximized state and
+/// a right-click opens the system title bar context menu.
+///
+/// [`drag_region`]: fn@drag_region
+#[property(EVENT, default(false))]
+pub fn drag_move_window(child: impl IntoUiNode, enabled: impl IntoVar<bool>) -> UiNode {
+    let enabled = enabled.into_var();
+    let child = zng_wgt_input::mouse::on_mouse_double_click(
+        child,
+        hn!(enabled, |args| {
+            if enabled.get() && args.target.widget_id() == WIDGET.id() {
+                args.propagation.stop();
+                WINDOW.vars().state().modify(|s| {
+                    **s = if **s == WindowState::Maximized {
+                        WindowState::Normal
+                    } else {
+                        WindowState::Maximized
+                    };
+                });
+            }
+        }),
+    );
+    zng_wgt_input::mouse::on_mouse_down(
+        child,
+        hn!(|args| {
+            if enabled.get() && args.target.widget_id() == WIDGET.id() {
+                if args.is_primary() {
+                    DRAG_MOVE_RESIZE_CMD.scoped(WINDOW.id()).notify();
+                } else if args.is_context() {
+                    OPEN_TITLE_BAR_CONTEXT_MENU_CMD.scoped(WINDOW.id()).notify_param(args.position);
+                }
+            }
+        }),
+    )
+}
+
+/// Window open or close animation.
+///
+/// See [`open_transition`] and [`close_transition`] for more details.
+///
+/// [`open_transition`]: fn@open_transition
+/// [`close_transition`]: fn@close_transition
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct WindowTransition {
+    /// Animation duration.
+    pub duration: Duration,
+    /// If the window content fades in on open or fades out on close.
+    pub fade: bool,
+    /// Content scale at the start of the open animation or at the end of the close animation.
+    ///
+    /// Is `1.0` for no scale animation.
+    pub scale: Factor,
+}
+impl Default for WindowTransition {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+impl WindowTransition {
+    /// No animation.
+    pub fn none() -> Self {
+        Self {
+            duration: Duration::ZERO,
+            fade: false,
+            scale: 1.fct(),
+        }
+    }
+
+    /// Fade animation.
+    pub fn fade(duration: Duration) -> Self {
+        Self {
+            duration,
+            fade: true,
+            scale: 1.fct(),
+        }
+    }
+
+    /// Scale animation, `scale` is the content size at the start of open or end of close.
+    pub fn scale(duration: Duration, scale: impl Into<Factor>) -> Self {
+        Self {
+            duration,
+            fade: false,
+            scale: scale.into(),
+        }
+    }
+
+    /// Fade and scale animation.
+    pub fn fade_scale(duration: Duration, scale: impl Into<Factor>) -> Self {
+        Self {
+            duration,
+            fade: true,
+            scale: scale.into(),
+        }
+    }
+
+    /// If the transition does not animate.
+    pub fn is_none(&self) -> bool {
+        self.duration == Duration::ZERO || (!self.fade && self.scale == 1.fct())
+    }
+
+    fn render(&self, progress: Factor, child: &mut UiNode, frame: &mut FrameBuilder) {
+        let render_scale = |frame: &mut FrameBuilder, child: &mut UiNode| {
+            if self.scale != 1.fct() && progress < 1.fct() {
+                let s = self.scale.0 + (1.0 - self.scale.0) * progress.0;
+                let size = WIDGET.bounds().inner_size();
+                let x = size.width.0 as f32 / 2.0;
+                let y = size.height.0 as f32 / 2.0;
+                let transform = PxTransform::translation(-x, -y)
+                    .then(&PxTransform::scale(s, s))
+                    .then_translate(euclid::vec2(x, y));
+                frame.push_inner_transform(&transform, |frame| child.render(frame));
+            } else {
+                child.render(frame);
+            }
+        };
+        if self.fade && progress < 1.fct() {
+            frame.push_inner_opacity(FrameValue::Value(progress.0.max(0.0)), |frame| render_scale(frame, child));
+        } else {
+            render_scale(frame, child);
+        }
+    }
+}
+
+/// Animation played when the window opens.
+///
+/// The window content fades in and/or scales to the normal size. Note that the animation is applied to the window content only,
+/// the system window frame and clear color are not animated, for a better effect set [`allow_transparency`] and
+/// a transparent [`clear_color`].
+///
+/// [`allow_transparency`]: fn@crate::allow_transparency
+/// [`clear_color`]: fn@clear_color
+#[property(CONTEXT, default(WindowTransition::none()), widget_impl(Window))]
+pub fn open_transition(child: impl IntoUiNode, transition: impl IntoVar<WindowTransition>) -> UiNode {
+    let transition = transition.into_var();
+    let mut progress = var(1.fct());
+    let mut _animation = zng_var::animation::AnimationHandle::dummy();
+    match_node(child, move |c, op| match op {
+        UiNodeOp::Init => {
+            let t = transition.get();
+            if !t.is_none() {
+                progress = var(0.fct());
+                _animation = progress.ease(1.fct(), t.duration, |t| easing::ease_out(easing::cubic, t));
+            }
+            WIDGET.sub_var_render(&progress);
+        }
+        UiNodeOp::Deinit => {
+            _animation = zng_var::animation::AnimationHandle::dummy();
+        }
+        UiNodeOp::Render { frame } => {
+            c.delegated();
+            transition.get().render(progress.get(), c.node(), frame);
+        }
+        _ => {}
+    })
+}
+
+/// Animation played when the window closes.
+///
+/// When the window close is requested and not canceled by other handlers the close is canceled, the animation plays and
+/// the window close is requested again after the animation ends. The [`WINDOWS.close`] response is `Cancel` for the first request,
+/// the window is actually closed after the animation.
+///
+/// Group close requests, like [`WINDOWS.close_all`], and close requests caused by [`APP.exit`] are not delayed, the window
+/// closes without animation in these cases.
+///
+/// The window content fades out and/or scales to the transition size, see [`open_transition`] for more details.
+///
+/// [`WINDOWS.close`]: zng_ext_window::WINDOWS::close
+/// [`WINDOWS.close_all`]: zng_ext_window::WINDOWS::close_all
+/// [`APP.exit`]: zng_app::APP::exit
+/// [`open_transition`]: fn@open_transition
+#[property(CONTEXT, default(WindowTransition::none()), widget_impl(Window))]
+pub fn close_transition(child: impl IntoUiNode, transition: impl IntoVar<WindowTransition>) -> UiNode {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    };
+
+    let transition = transition.into_var();
+    let progress = var(1.fct());
+    let close_task = Arc::new(parking_lot::Mutex::new(None::<zng_app::update::OnUpdateHandle>));
+    let mut _close_handle = VarHandle::dummy();
+    let mut _exit_handle = VarHandle::dummy();
+    match_node(child, move |c, op| match op {
+        UiNodeOp::Init => {
+            WIDGET.sub_var_render(&progress);
+
+            // app exit closes all windows, the next close request must not be delayed
+            let exiting = Arc::new(AtomicBool::new(false));
+            _exit_handle = zng_app::EXIT_REQUESTED_EVENT.hook(clmv!(exiting, |_| {
+                exiting.store(true, Ordering::Relaxed);
+                true
+            }));
+
+            let win_id = WINDOW.id();
+            let closing = Arc::new(AtomicBool::new(false));
+            _close_handle = zng_ext_window::WINDOW_CLOSE_REQUESTED_EVENT.on_event(
+                false,
+                hn!(transition, progress, close_task, |args| {
+                    if !args.windows.contains(&win_id) {
+                        return;
+                    }
+                    let is_exit = exiting.swap(false, Ordering::Relaxed);
+                    if closing.load(Ordering::Relaxed) {
+                        if progress.is_animating() {
+                            // already animating close
+                            args.propagation.stop();
+                        }
+                        return;
+                    }
+                    let t = transition.get();
+                    if t.is_none() || is_exit || args.windows.len() > 1 {
+                        return;
+                    }
+                    args.propagation.stop();
+
+                    closing.store(true, Ordering::Relaxed);
+                    let animation = progress.ease(0.fct(), t.duration, |t| easing::ease_in(easing::cubic, t));
+                    let closing = closing.clone();
+                    let progress = progress.clone();
+                    *close_task.lock() = Some(UPDATES.run(async move {
+                        progress.wait_animation().await;
+                        drop(animation);
+                        let r = WINDOWS.close(win_id).wait_rsp().await;
+                        if let zng_ext_window::CloseWindowResult::Cancel = r {
+                            // canceled by other handler after the animation, restore
+                            closing.store(false, Ordering::Relaxed);
+                            progress.set(1.fct());
+                        }
+                    }));
+                }),
+            );
+        }
+        UiNodeOp::Deinit => {
+            _close_handle = VarHandle::dummy();
+            _exit_handle = VarHandle::dummy();
+            close_task.lock().take();
+        }
+        UiNodeOp::Render { frame } => {
+            c.delegated();
+            transition.get().render(progress.get(), c.node(), frame);
+        }
+        _ => {}
+    })
+}
@@ -85,6 +85,11 @@ pub fn spacing(wgt: &mut WidgetBuilding, spacing: impl IntoVar<GridSpacing>) {
 }
 
 /// Children align.
+///
+/// If the align [`is_baseline`] the children in each row are aligned by their baseline, so that texts
+/// of different font sizes share the same baseline. Children that wrap across multiple rows are bottom aligned.
+///
+/// [`is_baseline`]: Align::is_baseline
 #[property(LAYOUT, widget_impl(Wrap))]
 pub fn children_align(wgt: &mut WidgetBuilding, align: impl IntoVar<Align>) {
     let _ = align;
@@ -307,6 +312,8 @@ struct InlineLayout {
     first_wrapped: bool,
     rows: RecycleVec<RowInfo>,
     desired_size: PxSize,
+    // height added to rows by baseline align in the last layout
+    baseline_extra: Px,
 
     // has segments in the opposite direction, requires bidi sorting and positioning.
     has_bidi_inline: bool,
@@ -430,7 +437,11 @@ impl InlineLayout {
             }
         }
 
-        constraints.clamp_size(self.desired_size)
+        let mut desired_size = self.desired_size;
+        if child_align.is_baseline() {
+            desired_size.height += self.baseline_extra;
+        }
+        constraints.clamp_size(desired_size)
     }
 
     pub fn estimate_layout(wl: &mut WidgetLayout, children_len: usize, child_size: PxSize, spacing: PxGridSpacing) -> PxSize {
@@ -525,7 +536,7 @@ impl InlineLayout {
 
             (first, Px(0), last)
         };
-        let panel_height = constraints.y.fill_or(last.origin.y - first.origin.y + last.size.height);
+        let rows_height = last.origin.y - first.origin.y + last.size.height;
 
         let child_constraints = PxConstraints2d::new_unbounded().with_fill_x(true).with_max_x(panel_width);
 
@@ -539,6 +550,11 @@ impl InlineLayout {
             None
         };
 
+        // (child, row, baseline, height) of children that are only in one row
+        let align_baseline = child_align.is_baseline();
+        let is_inline = wl.is_inline();
+        let mut baselines = vec![];
+
         LAYOUT.with_constraints(child_constraints, || {
             let mut row = first;
             let mut row_segs = &self.rows[0].item_segs;
@@ -734,6 +750,15 @@ impl InlineLayout {
                         }
                         o.define_reference_frame = define_ref_frame;
 
+                        if align_baseline && let Some(mut w) = child.as_widget() {
+                            baselines.push((
+                                i,
+                                next_row_i - 1,
+                                w.with_context(WidgetUpdateMode::Ignore, || WIDGET.bounds().final_baseline()),
+                                child_inline.first.height,
+                            ));
+                        }
+
                         row_advance += child_last.size.width + spacing.column;
                     }
                 } else {
@@ -764,6 +789,14 @@ impl InlineLayout {
                         o.child_offset.x = row.origin.x + bidi_x;
                     }
                     o.define_reference_frame = define_ref_frame;
+                    if align_baseline && let Some(mut w) = child.as_widget() {
+                        baselines.push((
+                            i,
+                            next_row_i - 1,
+                            w.with_context(WidgetUpdateMode::Ignore, || WIDGET.bounds().final_baseline()),
+                            size.height,
+                        ));
+                    }
                     row_advance += size.width + spacing.column;
                 }
             });
@@ -774,8 +807,46 @@ impl InlineLayout {
             }
         });
 
+        self.baseline_extra = Px(0);
+        if !baselines.is_empty() {
+            // children are bottom aligned, offset up so that all baselines in a row match the greatest baseline.
+            let mut row_baselines = vec![Px(0); self.rows.len()];
+            for &(_, r, b, _) in &baselines {
+                row_baselines[r] = row_baselines[r].max(b);
+            }
+            // grow rows so that the children offset up do not overlap the previous row,
+            // inlined rows are defined by the parent panel
+            let mut row_extra = vec![Px(0); self.rows.len()];
+            if !is_inline {
+                for &(_, r, b, height) in &baselines {
+                    row_extra[r] = row_extra[r].max(height + row_baselines[r] - b - self.rows[r].size.height);
+                }
+            }
+            self.baseline_extra = row_extra.iter().copied().sum();
+
+            let rows = &self.rows;
+            let mut baselines = baselines.into_iter().peekable();
+            let mut r = 0;
+            let mut row_offset = row_extra[0];
+            children.for_each_child(|i, _, o| {
+                while r + 1 < rows.len() && rows[r + 1].first_child <= i {
+                    r += 1;
+                    row_offset += row_extra[r];
+                }
+                o.child_offset.y += row_offset;
+
+                if let Some(&(child_i, r, b, _)) = baselines.peek()
+                    && child_i == i
+                {
+                    o.child_offset.y -= row_baselines[r] - b;
+                    baselines.next();
+                }
+            });
+        }
+
         children.commit_data().request_render();
 
+        let panel_height = constraints.y.fill_or(rows_height + self.baseline_extra);
         constraints.clamp_size(PxSize::new(panel_width, panel_height))
     }
 
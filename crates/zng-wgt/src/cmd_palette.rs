@@ -0,0 +1,163 @@
+use std::cmp;
+
+use zng_app::event::EVENTS;
+
+use crate::prelude::*;
+
+/// Represents a command in a command palette search result.
+///
+/// See [`command_palette`] for more details.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct CommandPaletteItem {
+    /// The command.
+    pub command: Command,
+    /// Command [`name`] when the search was made.
+    ///
+    /// [`name`]: zng_app::event::CommandNameExt::name
+    pub name: Txt,
+    /// Command [`info`] when the search was made.
+    ///
+    /// [`info`]: zng_app::event::CommandInfoExt::info
+    pub info: Txt,
+    /// Command [`icon`] when the search was made.
+    ///
+    /// [`icon`]: crate::CommandIconExt::icon
+    pub icon: WidgetFn<()>,
+    /// Command [`shortcut`] when the search was made.
+    ///
+    /// [`shortcut`]: zng_app::shortcut::CommandShortcutExt::shortcut
+    pub shortcut: Shortcuts,
+    /// Match score, higher is better.
+    pub score: u32,
+    /// Char indices in `name` that matched the search query.
+    ///
+    /// Can be used to highlight the matched chars.
+    pub name_matches: Vec<usize>,
+}
+
+/// Search the registered commands that are enabled.
+///
+/// Each command [`name`] is fuzzy matched with the `query`, all query chars must be present in the name in the
+/// same order, case insensitive. Results are sorted by best match first, consecutive chars and chars at the
+/// start of words are preferred. If the `query` is empty all enabled commands are returned sorted by name.
+///
+/// Only commands that have handles are registered in [`EVENTS.commands`], commands are usually enabled by a
+/// focused widget, so this function should be called with the app focus in the context the palette will apply to,
+/// before the palette popup takes focus.
+///
+/// [`name`]: zng_app::event::CommandNameExt::name
+/// [`EVENTS.commands`]: EVENTS::commands
+pub fn command_palette(query: &str) -> Vec<CommandPaletteItem> {
+    let mut r: Vec<_> = EVENTS
+        .commands()
+        .into_iter()
+        .filter(|c| c.is_enabled().get())
+        .filter_map(|command| {
+            let name = command.name().get();
+            let (score, name_matches) = fuzzy_match(query, &name)?;
+            Some(CommandPaletteItem {
+                command,
+                info: command.info().get(),
+                icon: command.icon().get(),
+                shortcut: command.shortcut().get(),
+                name,
+                score,
+                name_matches,
+            })
+        })
+        .collect();
+
+    r.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.name.cmp(&b.name)));
+    r
+}
+
+/// Fuzzy match `query` in `candidate`.
+///
+/// Returns the match score and char indices of `candidate` matched, or `None` if not all `query` chars
+/// are present in the `candidate` in the same order. The comparison is case insensitive and whitespace in
+/// the query is ignored. Higher scores are better matches.
+///
+/// This is the matcher used by [`command_palette`].
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<(u32, Vec<usize>)> {
+    let mut query = query.chars().filter(|c| !c.is_whitespace()).flat_map(char::to_lowercase).peekable();
+
+    let mut score = 0u32;
+    let mut matches = vec![];
+    let mut prev_match = None;
+    let mut prev_char = None::<char>;
+
+    for (i, c) in candidate.chars().enumerate() {
+        let Some(&q) = query.peek() else {
+            break;
+        };
+        if c.to_lowercase().eq([q]) {
+            query.next();
+
+            score += 1;
+            if prev_match.is_some_and(|p| p + 1 == i) {
+                // consecutive
+                score += 5;
+            }
+            if prev_char.is_none_or(|p| !p.is_alphanumeric() || (p.is_lowercase() && c.is_uppercase())) {
+                // word start
+                score += 3;
+            }
+
+            matches.push(i);
+            prev_match = Some(i);
+        }
+        prev_char = Some(c);
+    }
+
+    if query.peek().is_some() {
+        return None;
+    }
+
+    // prefer shorter candidates when the score is the same.
+    let len = candidate.chars().count() as u32;
+    score = score.saturating_mul(64).saturating_add(63u32.saturating_sub(cmp::min(len, 63)));
+
+    Some((score, matches))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(query: &str, candidate: &str) -> u32 {
+        fuzzy_match(query, candidate).unwrap().0
+    }
+
+    #[test]
+    fn fuzzy_match_non_match() {
+        assert!(fuzzy_match("xyz", "Copy").is_none());
+        // out of order
+        assert!(fuzzy_match("yc", "Copy").is_none());
+        // query longer than candidate
+        assert!(fuzzy_match("copy all", "Copy").is_none());
+        assert!(fuzzy_match("a", "").is_none());
+    }
+
+    #[test]
+    fn fuzzy_match_indices() {
+        assert_eq!(fuzzy_match("COPY", "copy").unwrap().1, vec![0, 1, 2, 3]);
+        assert_eq!(fuzzy_match("s a", "Save As").unwrap().1, vec![0, 1]);
+        assert_eq!(fuzzy_match("fb", "fooBar").unwrap().1, vec![0, 3]);
+        assert_eq!(fuzzy_match("çã", "Ação Ç").unwrap().1, vec![1, 2]);
+        assert_eq!(fuzzy_match("", "Copy").unwrap().1, Vec::<usize>::new());
+    }
+
+    #[test]
+    fn fuzzy_match_ranking() {
+        // consecutive chars
+        assert!(score("co", "Copy") > score("co", "Cut Other"));
+        // word start
+        assert!(score("o", "Open") > score("o", "Copy"));
+        assert!(score("b", "fooBar") > score("b", "foobar"));
+        // shorter candidate when the match is the same
+        assert!(score("c", "Cut") > score("c", "Cut All"));
+        // match quality before length
+        assert!(score("sa", "Select All Items") > score("sa", "Sxa"));
+    }
+}
@@ -1,5 +1,5 @@
 use zng_color::{
-    COLOR_SCHEME_VAR,
+    COLOR_SCHEME_BLEND_VAR, COLOR_SCHEME_VAR, color_scheme_blend,
     colors::{ACCENT_COLOR_VAR, BASE_COLOR_VAR},
 };
 
@@ -7,9 +7,11 @@ use crate::prelude::*;
 
 /// Defines the preferred color scheme in the widget and descendants.
 ///
-/// Sets the [`COLOR_SCHEME_VAR`].
+/// Sets the [`COLOR_SCHEME_VAR`] and the [`COLOR_SCHEME_BLEND_VAR`] to the exact blend of the scheme.
 #[property(CONTEXT, default(COLOR_SCHEME_VAR))]
 pub fn color_scheme(child: impl IntoUiNode, pref: impl IntoVar<ColorScheme>) -> UiNode {
+    let pref = pref.into_var();
+    let child = with_context_var(child, COLOR_SCHEME_BLEND_VAR, pref.map(|&s| color_scheme_blend(s)));
     with_context_var(child, COLOR_SCHEME_VAR, pref)
 }
 
@@ -108,6 +108,7 @@ pub mod node;
 
 mod border_props;
 mod clip_props;
+mod cmd_palette;
 mod color_props;
 mod func;
 mod hit_test_props;
@@ -121,6 +122,7 @@ mod wgt;
 
 pub use border_props::*;
 pub use clip_props::*;
+pub use cmd_palette::*;
 pub use color_props::*;
 pub use func::*;
 pub use hit_test_props::*;
@@ -153,5 +153,6 @@ pub use zng_wgt_access::{
     multi_selectable, on_access_click, on_access_expander, on_access_increment, on_access_number, on_access_scroll, on_access_selection,
     on_access_text, on_access_tooltip, on_pre_access_click, on_pre_access_expander, on_pre_access_increment, on_pre_access_number,
     on_pre_access_scroll, on_pre_access_selection, on_pre_access_text, on_pre_access_tooltip, orientation, owns, placeholder, popup,
-    read_only, required, row_count, row_index, row_span, scroll_horizontal, scroll_vertical, selected, sort, value, value_max, value_min,
+    read_only, required, role_description, row_count, row_index, row_span, scroll_horizontal, scroll_vertical, selected, sort, value,
+    value_max, value_min,
 };
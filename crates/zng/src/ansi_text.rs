@@ -21,5 +21,6 @@
 //! See [`zng_wgt_ansi_text`] for the full widget API.
 
 pub use zng_wgt_ansi_text::{
-    AnsiColor, AnsiStyle, AnsiText, AnsiTextParser, AnsiTxt, AnsiWeight, LineFnArgs, PageFnArgs, PanelFnArgs, TextFnArgs,
+    AnsiColor, AnsiLine, AnsiStreamParser, AnsiStyle, AnsiText, AnsiTextParser, AnsiTxt, AnsiWeight, LINK_EVENT, LineFnArgs, LinkArgs,
+    PageFnArgs, PanelFnArgs, TextFnArgs, on_link, on_pre_link,
 };
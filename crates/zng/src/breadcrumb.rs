@@ -0,0 +1,32 @@
+#![cfg(feature = "breadcrumb")]
+
+//! Breadcrumb path navigation widget, properties and events.
+//!
+//! The [`Breadcrumb!`](struct@Breadcrumb) widget shows a path as a row of segments separated by a separator widget,
+//! when there is not enough space the middle segments collapse into a *…* button that opens a menu with the hidden segments.
+//!
+//! ```
+//! use zng::{breadcrumb::BreadcrumbSegment, prelude::*};
+//!
+//! # fn example() {
+//! # let _ =
+//! zng::breadcrumb::Breadcrumb! {
+//!     segments = ["Home", "Documents", "Projects", "zng"].map(BreadcrumbSegment::new).to_vec();
+//!     zng::breadcrumb::on_segment_activated = hn!(|args| {
+//!         println!("navigate to segment {}", args.index);
+//!     });
+//! }
+//! # ; }
+//! ```
+//!
+//! Segments can also have a command [`action`](BreadcrumbSegment::action) that is notified on activation.
+//!
+//! # Full API
+//!
+//! See [`zng_wgt_breadcrumb`] for the full widget API.
+
+pub use zng_wgt_breadcrumb::{
+    Breadcrumb, BreadcrumbSegment, DefaultStyle, OVERFLOW_FN_VAR, OverflowFnArgs, SEGMENT_ACTIVATED_EVENT, SEGMENT_FN_VAR, SEGMENTS_VAR,
+    SEPARATOR_FN_VAR, SegmentActivatedArgs, SegmentFnArgs, default_overflow_fn, default_segment_fn, default_separator_fn, node,
+    on_pre_segment_activated, on_segment_activated, overflow_fn, segment_fn, segments, separator_fn, style_fn,
+};
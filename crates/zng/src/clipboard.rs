@@ -105,9 +105,33 @@
 //! # ;}
 //! ```
 //!
+//! # Best Format
+//!
+//! The [`CLIPBOARD.read_best`] method gets the first available format in a preference order, the example below pastes
+//! HTML if the view-process implements an HTML extension and the clipboard has it, otherwise pastes plain text.
+//!
+//! ```
+//! use zng::clipboard::{self, CLIPBOARD, ClipboardContent, ClipboardType};
+//! use zng::prelude::*;
+//!
+//! # fn demo(html: Var<Txt>) { let _ =
+//! Window! {
+//!     clipboard::on_paste = hn!(|_| {
+//!         match CLIPBOARD.read_best(vec![ClipboardType::Extension(Txt::from("text/html")), ClipboardType::Text]) {
+//!             Ok(Some(ClipboardContent::Extension { data, .. })) => html.set(Txt::from_str(&String::from_utf8_lossy(&data[..]))),
+//!             Ok(Some(ClipboardContent::Text(txt))) => html.set(txt),
+//!             _ => {}
+//!         }
+//!     });
+//! }
+//! # ;}
+//! ```
+//!
+//! [`CLIPBOARD.read_best`]: CLIPBOARD::read_best
+//!
 //! # Full API
 //!
 //! See [`zng_ext_clipboard`] for the full clipboard API.
 
-pub use zng_ext_clipboard::{CLIPBOARD, COPY_CMD, CUT_CMD, ClipboardError, ClipboardType, ClipboardTypes, PASTE_CMD};
+pub use zng_ext_clipboard::{CLIPBOARD, COPY_CMD, CUT_CMD, ClipboardContent, ClipboardError, ClipboardType, ClipboardTypes, PASTE_CMD};
 pub use zng_wgt_input::cmd::{can_copy, can_cut, can_paste, on_copy, on_cut, on_paste, on_pre_copy, on_pre_cut, on_pre_paste};
@@ -0,0 +1,29 @@
+#![cfg(feature = "color_picker")]
+
+//! Color picker widget and properties.
+//!
+//! The [`ColorPicker!`](struct@ColorPicker) widget selects a color by dragging on a color area and on hue and alpha
+//! sliders, the color can also be typed as hex or RGB components and copied or pasted as hex text. The area can
+//! present the HSV or the perceptual OKLCH color space.
+//!
+//! ```
+//! use zng::prelude::*;
+//! use zng::color_picker::{ColorPicker, ColorPickerSpace};
+//! # fn example() {
+//!
+//! let color = var(colors::ORANGE);
+//! # let _ =
+//! ColorPicker! {
+//!     color = color.clone();
+//!     color_space = ColorPickerSpace::Oklch;
+//! }
+//! # ; }
+//! ```
+//!
+//! # Full API
+//!
+//! See [`zng_wgt_color_picker`] for the full widget API.
+
+pub use zng_wgt_color_picker::{
+    COLOR_SPACE_VAR, COLOR_VAR, ColorPicker, ColorPickerSpace, DefaultStyle, color, color_space, node, style_fn,
+};
@@ -0,0 +1,38 @@
+#![cfg(feature = "date_picker")]
+
+//! Date picker widget, properties and date types.
+//!
+//! The [`DatePicker!`](struct@DatePicker) widget presents a month grid of days that selects a date or a range of dates,
+//! month and day names are localized and the first day of the week is selected from the language region.
+//!
+//! ```
+//! use zng::prelude::*;
+//! use zng::date_picker::{DatePicker, DateRange, DateSelectionMode, Date};
+//! # fn example() {
+//!
+//! let selection = var(DateRange::single(Date::today()));
+//! # let _ =
+//! DatePicker! {
+//!     selection = selection.clone();
+//!     selection_mode = DateSelectionMode::Range;
+//! }
+//! # ; }
+//! ```
+//!
+//! # Full API
+//!
+//! See [`zng_wgt_date_picker`] for the full widget API.
+
+pub use zng_wgt_date_picker::{
+    CURSOR_DATE_VAR, Date, DatePicker, DateRange, DateSelectionMode, DefaultStyle, FIRST_WEEKDAY_VAR, ParseDateError, SELECTION_MODE_VAR,
+    SELECTION_VAR, Weekday, cursor_date, first_weekday, month_name, select, selection, selection_mode, style_fn, weekday_name,
+};
+
+/// Day widget, properties and styles.
+///
+/// # Full API
+///
+/// See [`zng_wgt_date_picker::day`] for the full widget API.
+pub mod day {
+    pub use zng_wgt_date_picker::day::{DATE_VAR, Day, DefaultStyle, date, is_cursor, is_other_month, is_selected, is_today, style_fn};
+}
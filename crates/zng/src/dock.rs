@@ -0,0 +1,58 @@
+#![cfg(feature = "dock")]
+
+//! Dock area widget, layout and properties.
+//!
+//! The [`DockArea!`](struct@DockArea) widget presents panels docked to the edges of the area, stacked as tabs or
+//! floating in owned windows. The [`DockLayout`] variable defines where each panel is, the panel content is generated
+//! by [`panel_fn`](fn@panel_fn).
+//!
+//! The layout is serializable, the example below persists it in a config and has a "reset layout" button.
+//!
+//! ```
+//! use zng::{
+//!     dock::{DockArea, DockLayout, DockNode, DockPanel, DockPanelArgs},
+//!     split::SplitOrientation,
+//!     prelude::*,
+//! };
+//!
+//! fn default_layout() -> DockLayout {
+//!     DockLayout::new(DockNode::split(
+//!         SplitOrientation::Horizontal,
+//!         25.pct(),
+//!         DockNode::tabs(["files"]),
+//!         DockNode::tabs(["editor", "output"]),
+//!     ))
+//! }
+//!
+//! # fn example() {
+//! let layout = CONFIG.get("workspace.dock", default_layout());
+//! # let _ =
+//! Container! {
+//!     child_top = Button! {
+//!         child = Text!("Reset Layout");
+//!         on_click = hn!(layout, |_| layout.set(default_layout()));
+//!     };
+//!     child = DockArea! {
+//!         dock_layout = layout;
+//!         zng::dock::panels = vec![
+//!             DockPanel::new("files", "Files"),
+//!             DockPanel::new("editor", "Editor"),
+//!             DockPanel::new("output", "Output").with_closable(true),
+//!         ];
+//!         zng::dock::panel_fn = wgt_fn!(|a: DockPanelArgs| Text!("{} content", a.panel.title));
+//!     };
+//! }
+//! # ; }
+//! ```
+//!
+//! The tab headers have a context menu that floats the panel or docks it to an edge, the [`DockLayout`] methods can be
+//! used to do the same programmatically.
+//!
+//! # Full API
+//!
+//! See [`zng_wgt_dock`] for the full widget API.
+
+pub use zng_wgt_dock::{
+    DOCK_LAYOUT_VAR, DefaultStyle, DockArea, DockLayout, DockNode, DockPanel, DockPanelArgs, DockSide, FloatingPanel, PANEL_FN_VAR,
+    PANELS_VAR, dock_layout, node, panel_fn, panels, style_fn,
+};
@@ -36,6 +36,33 @@
 //! # ; }
 //! ```
 //!
+//! # Accept
+//!
+//! The [`drop_accept`](fn@drop_accept) property filters what data types a drop target accepts, the drop effect is set to reject other
+//! types and `on_drop` only notifies for accepted data. The example below accepts text and image drops.
+//!
+//! ```
+//! use zng::{drag_drop::*, prelude::*};
+//! # fn example() {
+//!
+//! let txt = var(Txt::from("drop text or image"));
+//! # let _ =
+//! Container! {
+//!     drop_accept = vec![DragDropType::Text, DragDropType::Image];
+//!     on_drop = hn!(txt, |args| {
+//!         if let Some(t) = args.text() {
+//!             txt.set(t.clone());
+//!         } else if let Some(img) = args.image() {
+//!             txt.set(formatx!("image {img:?}"));
+//!         }
+//!     });
+//!     when *#is_drop_accepted {
+//!         widget::background_color = colors::GREEN.with_alpha(20.pct());
+//!     }
+//!     child = Text!(txt);
+//! }
+//! # ; }
+//! ```
 //!
 //! # Limitations
 //!
@@ -54,10 +81,10 @@
 
 pub use zng_ext_input::drag_drop::{
     DRAG_DROP, DRAG_END_EVENT, DRAG_HOVERED_EVENT, DRAG_MOVE_EVENT, DRAG_START_EVENT, DROP_EVENT, DragDropData, DragDropEffect,
-    DragEndArgs, DragHandle, DragHoveredArgs, DragMoveArgs, DragStartArgs, DropArgs, WeakDragHandle,
+    DragDropType, DragEndArgs, DragHandle, DragHoveredArgs, DragMoveArgs, DragStartArgs, DropArgs, WeakDragHandle,
 };
 
 pub use zng_wgt_input::drag_drop::{
-    draggable, on_drag_end, on_drag_enter, on_drag_hovered, on_drag_leave, on_drag_start, on_drop, on_pre_drag_end, on_pre_drag_enter,
-    on_pre_drag_hovered, on_pre_drag_leave, on_pre_drag_start, on_pre_drop,
+    DROP_ACCEPT_VAR, draggable, drop_accept, is_drop_accepted, on_drag_end, on_drag_enter, on_drag_hovered, on_drag_leave, on_drag_start,
+    on_drop, on_pre_drag_end, on_pre_drag_enter, on_pre_drag_hovered, on_pre_drag_leave, on_pre_drag_start, on_pre_drop,
 };
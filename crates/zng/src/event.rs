@@ -320,3 +320,4 @@ pub use zng_app::event::{
     CommandNameExt, CommandParam, CommandScope, EVENTS, Event, EventArgs, EventPropagationHandle, EventUpdates, command, event, event_args,
 };
 pub use zng_wgt::node::{EventNodeBuilder, VarEventNodeBuilder, command_property, event_property};
+pub use zng_wgt::{CommandPaletteItem, command_palette, fuzzy_match};
@@ -0,0 +1,33 @@
+#![cfg(feature = "expander")]
+
+//! Expander widget, properties and styles.
+//!
+//! The [`Expander!`](struct@Expander) widget is a container with an always visible header and a content child that
+//! expands and collapses with an animated height when the header is clicked.
+//!
+//! ```
+//! use zng::prelude::*;
+//! # fn example() {
+//!
+//! let expanded = var(false);
+//! # let _ =
+//! zng::expander::Expander! {
+//!     header = Text!(expanded.map(|&e| Txt::from_static(if e { "Show less" } else { "Show more" })));
+//!     is_expanded = expanded;
+//!     child = Text!("Details");
+//! }
+//! # ; }
+//! ```
+//!
+//! The animation is instant when [`VARS.animations_enabled`] is `false`, this is usually the case when the system
+//! *reduced motion* setting is enabled.
+//!
+//! [`VARS.animations_enabled`]: crate::var::VARS::animations_enabled
+//!
+//! # Full API
+//!
+//! See [`zng_wgt_expander`] for the full widget API.
+
+pub use zng_wgt_expander::{
+    EXPAND_DURATION_VAR, Expander, HEADER_STYLE_FN_VAR, HeaderStyle, IS_EXPANDED_VAR, expand_duration, header, header_style_fn, is_expanded,
+};
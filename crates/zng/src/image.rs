@@ -73,8 +73,8 @@
 
 pub use zng_ext_image::{
     ColorType, IMAGE_RENDER, IMAGES, ImageCacheMode, ImageDataFormat, ImageDownscaleMode, ImageEntriesMode, ImageEntry, ImageEntryKind,
-    ImageFormat, ImageFormatCapability, ImageHash, ImageHasher, ImageLimits, ImageOptions, ImageRenderArgs, ImageSource, ImageSourceFilter,
-    ImageVar, PathFilter, render_retain,
+    ImageFormat, ImageFormatCapability, ImageHash, ImageHasher, ImageLimits, ImageMmapPath, ImageOptions, ImageRenderArgs, ImageSource,
+    ImageSourceFilter, ImageVar, PathFilter, render_retain,
 };
 
 #[cfg(feature = "http")]
@@ -82,8 +82,8 @@ pub use zng_ext_image::UriFilter;
 
 pub use zng_wgt_image::{
     Image, ImageAutoScale, ImageFit, ImageRepeat, ImgErrorArgs, ImgLoadArgs, ImgLoadingArgs, img_align, img_auto_scale, img_cache,
-    img_crop, img_downscale, img_entries_mode, img_error_fn, img_fit, img_limits, img_loading_fn, img_offset, img_rendering, img_repeat,
-    img_repeat_spacing, img_scale, is_error, is_loaded, on_error, on_load, on_load_layout,
+    img_compressed_texture, img_crop, img_downscale, img_entries_mode, img_error_fn, img_fade_in, img_fit, img_limits, img_loading_fn,
+    img_offset, img_rendering, img_repeat, img_repeat_spacing, img_scale, is_error, is_loaded, on_error, on_load, on_load_layout,
 };
 
 /// Mask image properties.
@@ -28,12 +28,12 @@ pub use zng_app::shortcut::ModifiersState;
 
 pub use zng_ext_input::keyboard::{
     HeadlessAppKeyboardExt, KEY_INPUT_EVENT, KEYBOARD, Key, KeyCode, KeyInputArgs, KeyLocation, KeyRepeatConfig, KeyState,
-    MODIFIERS_CHANGED_EVENT, ModifiersChangedArgs, NativeKeyCode,
+    MODIFIERS_CHANGED_EVENT, ModifiersChangedArgs, NativeKeyCode, WidgetInfoBuilderKeyboardExt, WidgetInfoKeyboardExt,
 };
 
 pub use zng_wgt_input::keyboard::{
-    on_disabled_key_input, on_key_down, on_key_input, on_key_up, on_pre_disabled_key_input, on_pre_key_down, on_pre_key_input,
-    on_pre_key_up,
+    key_repeat_config, on_disabled_key_input, on_key_down, on_key_input, on_key_up, on_pre_disabled_key_input, on_pre_key_down,
+    on_pre_key_input, on_pre_key_up,
 };
 
 /// Raw keyboard hardware events, received independent of what window or widget is focused.
@@ -493,18 +493,23 @@ pub mod access;
 pub mod ansi_text;
 pub mod app;
 pub mod audio;
+pub mod breadcrumb;
 pub mod button;
 pub mod checkerboard;
 pub mod clipboard;
 pub mod color;
+pub mod color_picker;
 pub mod config;
 pub mod container;
 pub mod data_context;
 pub mod data_view;
+pub mod date_picker;
 pub mod dialog;
+pub mod dock;
 pub mod drag_drop;
 pub mod env;
 pub mod event;
+pub mod expander;
 pub mod focus;
 pub mod font;
 pub mod fs_watcher;
@@ -532,21 +537,26 @@ pub mod scroll;
 pub mod selectable;
 pub mod shortcut_text;
 pub mod slider;
+pub mod split;
 pub mod stack;
 pub mod state_map;
 pub mod style;
+pub mod tabs;
 pub mod task;
 pub mod text;
 pub mod text_input;
 pub mod third_party;
 pub mod timer;
 pub mod tip;
+pub mod toast;
 pub mod toggle;
 pub mod touch;
+pub mod tree_view;
 pub mod undo;
 pub mod update;
 pub mod var;
 pub mod view_process;
+pub mod virtual_keyboard;
 pub mod widget;
 pub mod window;
 pub mod wrap;
@@ -721,6 +731,11 @@ mod __prelude {
     #[cfg(feature = "dialog")]
     pub use zng_wgt_dialog::DIALOG;
 
+    #[cfg(feature = "toast")]
+    pub use crate::toast;
+    #[cfg(feature = "toast")]
+    pub use zng_wgt_toast::TOASTS;
+
     #[cfg(all(feature = "fs_watcher", feature = "image"))]
     pub use crate::fs_watcher::IMAGES_Ext as _;
 }
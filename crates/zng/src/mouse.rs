@@ -56,8 +56,11 @@ pub use zng_wgt_input::mouse::{
 
 pub use zng_wgt_input::{
     CursorIcon, CursorSource, click_mode, cursor, is_cap_mouse_pressed, is_mouse_active, is_mouse_pressed, mouse_active_config,
+    repeat_on_hold,
 };
 
+pub use zng_wgt_input::mouse::rubber_band_select;
+
 #[cfg(feature = "image")]
 pub use zng_wgt_input::CursorImg;
 
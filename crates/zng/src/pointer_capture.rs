@@ -43,6 +43,6 @@
 pub use zng_ext_input::pointer_capture::{CaptureInfo, CaptureMode, POINTER_CAPTURE, POINTER_CAPTURE_EVENT, PointerCaptureArgs};
 
 pub use zng_wgt_input::pointer_capture::{
-    capture_pointer, capture_pointer_on_init, on_got_pointer_capture, on_lost_pointer_capture, on_pointer_capture_changed,
-    on_pre_got_pointer_capture, on_pre_lost_pointer_capture, on_pre_pointer_capture_changed,
+    capture_pointer, capture_pointer_on_init, capture_pointer_while_pressed, on_got_pointer_capture, on_lost_pointer_capture,
+    on_pointer_capture_changed, on_pre_got_pointer_capture, on_pre_lost_pointer_capture, on_pre_pointer_capture_changed,
 };
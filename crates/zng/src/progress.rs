@@ -24,6 +24,20 @@
 //! # }
 //! ```
 //!
+//! The [`ProgressBar!`](struct@ProgressBar) and [`Spinner!`](struct@Spinner) widgets are progress views with a fixed style,
+//! the spinner is a circular indicator that is usually indeterminate.
+//!
+//! ```
+//! # fn demo() {
+//! use zng::prelude::*;
+//!
+//! # let _ =
+//! zng::progress::ProgressBar!(0.5.fct());
+//! # let _ =
+//! zng::progress::Spinner!();
+//! # }
+//! ```
+//!
 //! [`task::Progress`]: zng::task::Progress
 //!
 //! # Full API
@@ -31,6 +45,6 @@
 //! See [`zng_wgt_progress`] and [`zng_task::Progress`] for the full widget API.
 
 pub use zng_wgt_progress::{
-    CircularStyle, DefaultStyle, PROGRESS_VAR, ProgressView, SimpleBarStyle, SimpleCircularStyle, circular_style_fn, is_indeterminate,
-    on_complete, on_progress, simple_bar_style_fn, simple_circular_style_fn,
+    CircularStyle, DefaultStyle, PROGRESS_VAR, ProgressBar, ProgressView, SimpleBarStyle, SimpleCircularStyle, Spinner, circular_style_fn,
+    is_indeterminate, on_complete, on_progress, simple_bar_style_fn, simple_circular_style_fn,
 };
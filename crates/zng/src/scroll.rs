@@ -61,11 +61,13 @@
 //! See [`zng_wgt_scroll`] for the full widget API.
 
 pub use zng_wgt_scroll::{
-    LazyMode, SCROLL, Scroll, ScrollBarArgs, ScrollFrom, ScrollInfo, ScrollMode, ScrollUnitsMix, Scrollbar, ScrollbarFnMix,
-    SmoothScrolling, Thumb, WidgetInfoExt, ZoomToFitMode, alt_factor, auto_hide_extra, clip_to_viewport, define_viewport_unit, h_line_unit,
-    h_page_unit, h_scrollbar_fn, h_wheel_unit, lazy, line_units, max_zoom, min_zoom, mode, mouse_pan, overscroll_color, page_units,
-    scroll_to_focused_mode, scrollbar_fn, scrollbar_joiner_fn, smooth_scrolling, v_line_unit, v_page_unit, v_scrollbar_fn, v_wheel_unit,
-    wheel_units, zoom_origin, zoom_size_only, zoom_to_fit_mode, zoom_touch_origin, zoom_wheel_origin, zoom_wheel_unit,
+    LazyMode, OverscrollMode, PullRefreshArgs, SCROLL, Scroll, ScrollBarArgs, ScrollFrom, ScrollInfo, ScrollMode, ScrollSnapMode,
+    ScrollUnitsMix, Scrollbar, ScrollbarFnMix, SmoothScrolling, Thumb, WidgetInfoExt, ZoomToFitMode, alt_factor, auto_hide_extra,
+    clip_to_viewport, define_viewport_unit, h_line_unit, h_page_unit, h_scroll_snap, h_scrollbar_fn, h_wheel_unit, lazy, line_units,
+    max_zoom, min_zoom, mode, mouse_pan, on_pull_refresh, overscroll_color, overscroll_mode, page_units, pull_refresh_indicator,
+    pull_refresh_threshold, scroll_snap, scroll_to_focused_mode, scrollbar_fn, scrollbar_joiner_fn, smooth_scrolling, sticky_header,
+    v_line_unit, v_page_unit, v_scroll_snap, v_scrollbar_fn, v_wheel_unit, wheel_units, zoom_origin, zoom_size_only, zoom_to_fit_mode,
+    zoom_touch_origin, zoom_wheel_origin, zoom_wheel_unit,
 };
 
 /// Scrollbar thumb widget.
@@ -84,6 +86,6 @@ pub mod cmd {
         PAGE_DOWN_CMD, PAGE_LEFT_CMD, PAGE_RIGHT_CMD, PAGE_UP_CMD, SCROLL_DOWN_CMD, SCROLL_LEFT_CMD, SCROLL_RIGHT_CMD,
         SCROLL_TO_BOTTOM_CMD, SCROLL_TO_CMD, SCROLL_TO_LEFTMOST_CMD, SCROLL_TO_RIGHTMOST_CMD, SCROLL_TO_TOP_CMD, SCROLL_UP_CMD,
         ScrollRequest, ScrollToMode, ScrollToRequest, ScrollToTarget, ZOOM_IN_CMD, ZOOM_OUT_CMD, ZOOM_RESET_CMD, ZOOM_TO_FIT_CMD,
-        ZoomToFitRequest, scroll_to, scroll_to_zoom,
+        ZOOM_TO_RECT_CMD, ZoomToFitRequest, ZoomToRectRequest, scroll_to, scroll_to_zoom,
     };
 }
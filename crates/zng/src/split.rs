@@ -0,0 +1,44 @@
+#![cfg(feature = "split")]
+
+//! Split view widget, styles and properties.
+//!
+//! The [`SplitView!`](struct@SplitView) widget places two panes side by side or one above the other with a divider
+//! in between, dragging the divider resizes the panes.
+//!
+//! ```
+//! use zng::prelude::*;
+//! # fn example() {
+//!
+//! let ratio = var(0.3.fct());
+//! # let _ =
+//! zng::split::SplitView! {
+//!     first = Text!("Navigation");
+//!     second = Text!("Content");
+//!     split_orientation = zng::split::SplitOrientation::Horizontal;
+//!     split_ratio = ratio;
+//!     first_min = 100;
+//!     second_min = 30.pct();
+//!     split_snap = vec![0.5.fct()];
+//! }
+//! # ; }
+//! ```
+//!
+//! The ratio variable is set when the divider is dragged, it can be bound to a config to persist the layout.
+//!
+//! # Full API
+//!
+//! See [`zng_wgt_split`] for the full widget API.
+
+pub use zng_wgt_split::{
+    DefaultStyle, FIRST_MIN_VAR, SECOND_MIN_VAR, SPLIT_ORIENTATION_VAR, SPLIT_RATIO_VAR, SPLIT_SNAP_DISTANCE_VAR, SPLIT_SNAP_VAR,
+    SplitOrientation, SplitView, first, first_min, second, second_min, split_orientation, split_ratio, split_snap, split_snap_distance,
+};
+
+/// Split view divider widget and styles.
+///
+/// # Full API
+///
+/// See [`zng_wgt_split::divider`] for the full widget API.
+pub mod divider {
+    pub use zng_wgt_split::divider::{DefaultStyle, Divider};
+}
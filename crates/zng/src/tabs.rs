@@ -0,0 +1,39 @@
+#![cfg(feature = "tabs")]
+
+//! Tab view widget, commands and properties.
+//!
+//! The [`Tabs!`](struct@Tabs) widget shows a bar of tab headers and the content of the selected tab. The tabs are
+//! defined by a list of [`TabItem`], the selected tab key is a variable and the content is generated by [`content_fn`](fn@content_fn).
+//!
+//! ```
+//! use zng::{
+//!     prelude::*,
+//!     tabs::{TabItem, Tabs},
+//! };
+//!
+//! # fn example() {
+//! # let _ =
+//! Tabs! {
+//!     tabs = var(vec![
+//!         TabItem::new("readme", "README.md"),
+//!         TabItem::new("main", "main.rs").with_closable(true),
+//!     ]);
+//!     zng::tabs::selected = var(Txt::from("readme"));
+//!     zng::tabs::content_fn = wgt_fn!(|tab: TabItem| Text!("content of {}", tab.label));
+//! }
+//! # ; }
+//! ```
+//!
+//! Tab headers can be dragged to reorder, closable tabs have a close button, tabs that do not fit the width are
+//! listed in an overflow menu. The [`NEXT_TAB_CMD`], [`PREV_TAB_CMD`] and [`CLOSE_TAB_CMD`] commands are scoped on the
+//! tabs widget, they have the `Ctrl+Tab`, `Ctrl+Shift+Tab` and `Ctrl+F4` shortcuts.
+//!
+//! # Full API
+//!
+//! See [`zng_wgt_tabs`] for the full widget API.
+
+pub use zng_wgt_tabs::{
+    CLOSE_TAB_CMD, CONTENT_FN_VAR, DefaultStyle, HEADER_FN_VAR, NEXT_TAB_CMD, OVERFLOW_FN_VAR, PREV_TAB_CMD, SELECTED_VAR, TABS_VAR,
+    TabHeaderArgs, TabItem, Tabs, TabsOverflowArgs, content_fn, default_header_fn, default_overflow_fn, header_fn, node, overflow_fn,
+    selected, style_fn, tabs,
+};
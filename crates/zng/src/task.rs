@@ -144,14 +144,18 @@
 //! See [`zng_task`] for the full API.
 
 pub use zng_task::{
-    DeadlineError, McWaker, ParallelIteratorExt, ParallelIteratorWithCtx, Progress, ScopeCtx, SignalOnce, TaskPanicError, UiTask, all,
-    all_ok, all_some, any, any_ok, any_some, block_on, deadline, fs, future_fn, io, join, join_context, poll_respond, poll_spawn, respond,
-    run, run_catch, scope, set_spawn_panic_handler, spawn, spawn_wait, wait, wait_catch, wait_respond, with_deadline, yield_now,
+    DeadlineError, InterruptFlag, McWaker, ParallelIteratorExt, ParallelIteratorWithCtx, Progress, ScopeCtx, SignalOnce, TaskPanicError,
+    UiTask, all, all_ok, all_some, any, any_ok, any_some, block_on, deadline, fs, future_fn, io, join, join_context, poll_respond,
+    poll_spawn, respond, run, run_catch, scope, set_spawn_panic_handler, spawn, spawn_wait, wait, wait_catch, wait_interruptible,
+    wait_respond, with_deadline, yield_now,
 };
 
 #[cfg(any(doc, feature = "test_util"))]
 pub use zng_task::{doc_test, spin_on};
 
+#[cfg(feature = "task_metrics")]
+pub use zng_task::{TaskMetrics, metrics, reset_metrics};
+
 /// HTTP client.
 ///
 /// This module provides an HTTP client API that is backend agnostic. By default it uses the system `curl` command
@@ -224,7 +228,7 @@ pub mod http {
 ///
 /// See [`zng_task::channel`] for the full API.
 pub mod channel {
-    pub use zng_task::channel::{ChannelError, Receiver, Sender, bounded, rendezvous, unbounded};
+    pub use zng_task::channel::{ChannelError, Receiver, Sender, bounded, rendezvous, select, select_map, unbounded};
     pub use zng_task::channel::{
         IpcBytes, IpcBytesCast, IpcBytesCastIntoIter, IpcBytesIntoIter, IpcBytesMut, IpcBytesMutCast, IpcBytesWriter,
         IpcBytesWriterBlocking, IpcFileHandle, IpcRead, IpcReadBlocking, IpcReadHandle, IpcReceiver, IpcSender, IpcValue, NamedIpcReceiver,
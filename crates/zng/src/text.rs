@@ -116,21 +116,23 @@
 pub use zng_txt::*;
 
 pub use zng_wgt_text::{
-    AutoSelection, CaretShape, CaretStatus, ChangeStopArgs, ChangeStopCause, Em, FONT_COLOR_VAR, InteractiveCaretMode, LangMix,
-    LinesWrapCount, ParagraphMix, SelectionToolbarArgs, Strong, Text, TextOverflow, TxtParseValue, UnderlinePosition, UnderlineSkip,
-    accepts_enter, accepts_tab, auto_selection, caret_color, change_stop_delay, direction, font_aa, font_annotation, font_caps,
-    font_char_variant, font_cn_variant, font_color, font_common_lig, font_contextual_alt, font_discretionary_lig, font_ea_width,
-    font_family, font_features, font_historical_forms, font_historical_lig, font_jp_variant, font_kerning, font_num_fraction,
-    font_num_spacing, font_numeric, font_ornaments, font_palette, font_palette_colors, font_position, font_size, font_stretch, font_style,
-    font_style_set, font_stylistic, font_swash, font_synthesis, font_variations, font_weight, get_caret_index, get_caret_status,
-    get_chars_count, get_lines_len, get_lines_wrap_count, get_overflow, has_selection, hyphen_char, hyphens, ime_underline,
-    interactive_caret, interactive_caret_visual, is_line_overflown, is_overflown, is_parse_pending, justify_mode, lang, letter_spacing,
-    line_break, line_height, line_spacing, max_chars_count,
+    AutoSelection, CaretShape, CaretStatus, ChangeStopArgs, ChangeStopCause, Em, FONT_COLOR_VAR, InteractiveCaretMode, LINK_EVENT, LangMix,
+    LinesWrapCount, LinkArgs, ParagraphMix, SelectionToolbarArgs, Strong, Text, TextOverflow, TxtParseValue, UnderlinePosition,
+    UnderlineSkip, accepts_enter, accepts_tab, auto_direction, auto_font_size, auto_selection, caret_blink_interval, caret_color,
+    caret_typing_pause, change_stop_delay, direction, find_urls, font_aa, font_annotation, font_caps, font_char_variant, font_cn_variant,
+    font_color, font_common_lig, font_contextual_alt, font_discretionary_lig, font_ea_width, font_family, font_features,
+    font_historical_forms, font_historical_lig, font_jp_variant, font_kerning, font_num_fraction, font_num_spacing, font_numeric,
+    font_ornaments, font_palette, font_palette_colors, font_position, font_size, font_stretch, font_style, font_style_set, font_stylistic,
+    font_swash, font_synthesis, font_variation_italic, font_variation_optical_size, font_variation_slant, font_variation_weight,
+    font_variation_width, font_variations, font_weight, get_caret_index, get_caret_status, get_chars_count, get_lines_len,
+    get_lines_wrap_count, get_overflow, has_selection, hyphen_char, hyphens, ime_underline, interactive_caret, interactive_caret_visual,
+    is_line_overflown, is_overflown, is_parse_pending, justify_mode, lang, letter_spacing, line_break, line_height, line_spacing,
+    max_chars_count,
     node::{TEXT, set_interactive_caret_spot},
-    obscure_txt, obscuring_char, on_change_stop, overline, overline_color, paragraph_spacing, rich_text, selection_color,
-    selection_toolbar, selection_toolbar_anchor, selection_toolbar_fn, strikethrough, strikethrough_color, tab_length, txt, txt_align,
-    txt_editable, txt_overflow, txt_overflow_align, txt_selectable, txt_selectable_alt_only, underline, underline_color, underline_skip,
-    white_space, word_break, word_spacing,
+    obscure_txt, obscuring_char, on_change_stop, on_link, on_pre_link, overline, overline_color, paragraph_spacing, rich_text, secure_txt,
+    selection_color, selection_toolbar, selection_toolbar_anchor, selection_toolbar_fn, strikethrough, strikethrough_color, tab_length,
+    txt, txt_align, txt_auto_link, txt_editable, txt_overflow, txt_overflow_align, txt_selectable, txt_selectable_alt_only, underline,
+    underline_color, underline_skip, white_space, word_break, word_spacing,
 };
 
 /// Commands that controls the editable/selectable text.
@@ -25,7 +25,7 @@
 //!         },
 //!         TextInput! {
 //!             txt = var_from("pass");
-//!             obscure_txt = true;
+//!             secure_txt = true;
 //!         },
 //!     ];
 //!     direction = StackDirection::top_to_bottom();
@@ -35,7 +35,7 @@
 //! ```
 //!
 //! The first input binds directly to a `Txt` read-write variable. The second field binds to an `u32` read-write variable using the
-//! [`txt_parse`](struct@TextInput#method.txt_parse) property. The third field obscures the text. The `FieldStyle!` adds data validation
+//! [`txt_parse`](struct@TextInput#method.txt_parse) property. The third field obscures the text and blocks clipboard copy and screen readers from reading it. The `FieldStyle!` adds data validation
 //! adorners to the `TextInput!`, in the first field a char count is shown, in the second field the [`field_help`](fn@field_help)
 //! or parse errors are shown.
 //!
@@ -47,3 +47,95 @@ pub use zng_wgt_text_input::{
     DefaultStyle, FieldStyle, SearchStyle, TextInput, data_notes_adorner_fn, field_help, field_style_fn, max_chars_count_adorner_fn,
     search_style_fn, style_fn,
 };
+
+/// Combo box widget, properties and commands.
+///
+/// ```
+/// use zng::prelude::*;
+/// # fn example() {
+///
+/// let selected = var(None::<Txt>);
+/// # let _ =
+/// zng::text_input::combo_box::ComboBox! {
+///     txt = var(Txt::from(""));
+///     options = ["Apple", "Apricot", "Banana", "Blueberry", "Cherry"].map(Txt::from_static).to_vec();
+///     zng::text_input::combo_box::selected = selected;
+/// }
+/// # ; }
+/// ```
+///
+/// The options are filtered by fuzzy matching the text as the user types, the `txt` variable is the free text and the
+/// `selected` variable is the option equal to the text, if any.
+///
+/// # Full API
+///
+/// See [`zng_wgt_text_input::combo_box`] for the full widget API.
+pub mod combo_box {
+    pub use zng_wgt_text_input::combo_box::{
+        ComboBox, OPTIONS_MAX_HEIGHT_VAR, OPTIONS_VAR, SELECTED_VAR, TOGGLE_OPTIONS_CMD, options, options_max_height, selected,
+    };
+}
+
+/// Number input widget, properties and commands.
+///
+/// ```
+/// use zng::prelude::*;
+/// # fn example() {
+///
+/// let value = var(5i32);
+/// # let _ =
+/// zng::text_input::number_input::NumberInput! {
+///     value;
+///     value_min = 0.0;
+///     value_max = 10.0;
+/// }
+/// # ; }
+/// ```
+///
+/// # Full API
+///
+/// See [`zng_wgt_text_input::number_input`] for the full widget API.
+pub mod number_input {
+    pub use zng_wgt_text_input::number_input::{
+        DECREMENT_CMD, INCREMENT_CMD, NumberInput, NumberValue, VALUE_MAX_VAR, VALUE_MIN_VAR, VALUE_STEP_VAR, value, value_max, value_min,
+        value_step,
+    };
+}
+
+/// Rich text editor widget, commands and properties.
+///
+/// The document is a [`TextRuns`](rich_text_edit::TextRuns) variable, the format commands toggle bold, italic
+/// and underline over the selected text.
+///
+/// ```
+/// use zng::prelude::*;
+/// use zng::text_input::rich_text_edit::{RichTextEdit, TOGGLE_BOLD_CMD, TextFormat, TextRun, TextRuns};
+/// # fn example() {
+///
+/// let doc = var(TextRuns::new([TextRun::new("Hello "), TextRun::new("World").with(TextFormat::Bold)]));
+/// # let _ =
+/// Stack! {
+///     direction = StackDirection::top_to_bottom();
+///     children = ui_vec![
+///         Button!(TOGGLE_BOLD_CMD.scoped(WidgetId::named("editor"))),
+///         RichTextEdit! {
+///             id = "editor";
+///             runs = doc.clone();
+///         },
+///         Text!(doc.map(|d| d.to_markdown())),
+///     ];
+/// }
+/// # ; }
+/// ```
+///
+/// The document is serializable, [`TextRuns::to_markdown`](rich_text_edit::TextRuns::to_markdown) can be used to export it.
+///
+/// # Full API
+///
+/// See [`zng_wgt_text_input::rich_text_edit`] for the full widget API.
+pub mod rich_text_edit {
+    pub use zng_wgt_text_input::rich_text_edit::{
+        DefaultStyle, RUNS_VAR, RichTextEdit, TOGGLE_BOLD_CMD, TOGGLE_ITALIC_CMD, TOGGLE_UNDERLINE_CMD, TextFormat, TextRun, TextRuns,
+        node, runs, style_fn,
+    };
+}
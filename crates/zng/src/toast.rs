@@ -0,0 +1,42 @@
+#![cfg(feature = "toast")]
+
+//! Toast notifications widget and service.
+//!
+//! The [`TOASTS`] service shows transient messages stacked in a corner of the window, the messages close
+//! automatically after a [`duration`](fn@duration) and can have action buttons.
+//!
+//! ```
+//! use zng::prelude::*;
+//!
+//! # fn example() {
+//! # let _ =
+//! Button! {
+//!     child = Text!("Copy");
+//!     on_click = async_hn!(|_| {
+//!         TOASTS.show("Copied!");
+//!
+//!         let undo = toast::ToastAction::new("undo", "Undo");
+//!         let r = TOASTS.show_actions("File deleted.", vec![undo]).wait_rsp().await;
+//!         if r == toast::ToastResponse::Action(Txt::from("undo")) {
+//!             // ..
+//!         }
+//!     });
+//! }
+//! # ; }
+//! ```
+//!
+//! Toasts are announced by screen readers, the toasts layer is a polite live region. The [`TOASTS.align`] variable
+//! defines the window corner and the [`TOASTS.max_visible`] variable defines how many toasts are visible at a time, other
+//! toasts are queued.
+//!
+//! [`TOASTS.align`]: TOASTS::align
+//! [`TOASTS.max_visible`]: TOASTS::max_visible
+//!
+//! # Full API
+//!
+//! See [`zng_wgt_toast`] for the full widget API.
+
+pub use zng_wgt_toast::{
+    ACTION_FN_VAR, DURATION_VAR, DefaultStyle, TOAST_ACTIONS_VAR, TOASTS, Toast, ToastAction, ToastResponse, action_fn, actions,
+    default_action_fn, duration, style_fn,
+};
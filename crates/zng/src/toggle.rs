@@ -191,6 +191,31 @@ pub use zng_wgt_toggle::{
     scroll_on_select, select_on_init, select_on_new, selector, style_fn, switch_style_fn, tristate,
 };
 
+/// Segmented control widget, properties and styles.
+///
+/// ```
+/// use zng::prelude::*;
+/// # fn example() {
+///
+/// let view_mode = var(0usize);
+/// # let _ =
+/// zng::toggle::segmented::SegmentedControl! {
+///     segments = ["List", "Grid", "Columns"].map(Txt::from_static).to_vec();
+///     zng::toggle::segmented::selected = view_mode;
+/// }
+/// # ; }
+/// ```
+///
+/// # Full API
+///
+/// See [`zng_wgt_toggle::segmented`] for the full widget API.
+pub mod segmented {
+    pub use zng_wgt_toggle::segmented::{
+        DefaultStyle, INDICATOR_COLOR_VAR, SEGMENT_FN_VAR, SEGMENTS_VAR, SELECTED_VAR, SegmentArgs, SegmentStyle, SegmentedControl,
+        default_segment_fn, indicator_color, segment_fn, segments, selected, style_fn,
+    };
+}
+
 /// Toggle commands.
 pub mod cmd {
     pub use zng_wgt_toggle::cmd::{SELECT_CMD, SelectOp, TOGGLE_CMD};
@@ -49,17 +49,18 @@
 //! See [`zng_ext_input::touch`] and [`zng_wgt_input::touch`] for the full touch API.
 
 pub use zng_ext_input::touch::{
-    TOUCH, TOUCH_INPUT_EVENT, TOUCH_LONG_PRESS_EVENT, TOUCH_MOVE_EVENT, TOUCH_TAP_EVENT, TOUCH_TRANSFORM_EVENT, TOUCHED_EVENT, TouchConfig,
-    TouchForce, TouchId, TouchInputArgs, TouchLongPressArgs, TouchMove, TouchMoveArgs, TouchPhase, TouchPosition, TouchTapArgs,
-    TouchTransformArgs, TouchTransformInfo, TouchTransformMode, TouchUpdate, TouchedArgs,
+    SwipeEdge, TOUCH, TOUCH_EDGE_SWIPE_EVENT, TOUCH_INPUT_EVENT, TOUCH_LONG_PRESS_EVENT, TOUCH_MOVE_EVENT, TOUCH_TAP_EVENT,
+    TOUCH_TRANSFORM_EVENT, TOUCHED_EVENT, TouchConfig, TouchEdgeSwipeArgs, TouchForce, TouchId, TouchInputArgs, TouchLongPressArgs,
+    TouchMove, TouchMoveArgs, TouchPhase, TouchPosition, TouchTapArgs, TouchTransformArgs, TouchTransformInfo, TouchTransformMode,
+    TouchUpdate, TouchedArgs,
 };
 
 pub use zng_wgt_input::touch::{
-    on_disabled_touch_input, on_disabled_touch_long_press, on_disabled_touch_tap, on_pre_disabled_touch_input,
-    on_pre_disabled_touch_long_press, on_pre_disabled_touch_tap, on_pre_touch_cancel, on_pre_touch_end, on_pre_touch_enter,
-    on_pre_touch_input, on_pre_touch_leave, on_pre_touch_long_press, on_pre_touch_move, on_pre_touch_start, on_pre_touch_tap,
-    on_pre_touch_transform, on_pre_touched, on_touch_cancel, on_touch_end, on_touch_enter, on_touch_input, on_touch_leave,
-    on_touch_long_press, on_touch_move, on_touch_start, on_touch_tap, on_touch_transform, on_touched,
+    on_disabled_touch_input, on_disabled_touch_long_press, on_disabled_touch_tap, on_edge_swipe, on_pre_disabled_touch_input,
+    on_pre_disabled_touch_long_press, on_pre_disabled_touch_tap, on_pre_edge_swipe, on_pre_touch_cancel, on_pre_touch_end,
+    on_pre_touch_enter, on_pre_touch_input, on_pre_touch_leave, on_pre_touch_long_press, on_pre_touch_move, on_pre_touch_start,
+    on_pre_touch_tap, on_pre_touch_transform, on_pre_touched, on_touch_cancel, on_touch_end, on_touch_enter, on_touch_input,
+    on_touch_leave, on_touch_long_press, on_touch_move, on_touch_start, on_touch_tap, on_touch_transform, on_touched,
 };
 
 pub use zng_wgt_input::{is_cap_touched, is_touch_active, is_touched, is_touched_from_start, touch_active_config, touch_transform};
@@ -0,0 +1,40 @@
+#![cfg(feature = "tree_view")]
+
+//! Tree view widget and properties.
+//!
+//! The [`TreeView!`](struct@TreeView) widget shows a hierarchy of [`TreeItem`] as indented rows that can expand to show
+//! the children. The expanded keys and selected key are variables, rows are generated by [`item_fn`](fn@item_fn).
+//!
+//! ```
+//! use std::collections::HashSet;
+//! use zng::{
+//!     prelude::*,
+//!     tree_view::{TreeItem, TreeView},
+//! };
+//!
+//! # fn example() {
+//! # let _ =
+//! TreeView! {
+//!     items = vec![
+//!         TreeItem::new("src", "src").with_children([TreeItem::new("src/main.rs", "main.rs"), TreeItem::new("src/lib.rs", "lib.rs")]),
+//!         TreeItem::new("Cargo.toml", "Cargo.toml"),
+//!     ];
+//!     zng::tree_view::expanded = var(HashSet::from([Txt::from("src")]));
+//!     zng::tree_view::selected = var(None::<Txt>);
+//! }
+//! # ; }
+//! ```
+//!
+//! The widget is a single focus stop, the arrow keys move the selection and expand or collapse rows. Rows outside the
+//! viewport are lazy, only a placeholder of [`row_height`](fn@row_height) is inited for them. The tree, tree item and level
+//! accessibility info is set automatically.
+//!
+//! # Full API
+//!
+//! See [`zng_wgt_tree_view`] for the full widget API.
+
+pub use zng_wgt_tree_view::{
+    DefaultStyle, EXPANDED_VAR, GUIDES_COLOR_VAR, INDENT_VAR, ITEM_FN_VAR, ITEMS_VAR, ROW_HEIGHT_VAR, SELECTED_VAR, SHOW_GUIDES_VAR,
+    TreeItem, TreeItemArgs, TreeView, default_item_fn, expanded, guides_color, indent, item_fn, items, node, row_height, selected,
+    show_guides, style_fn,
+};
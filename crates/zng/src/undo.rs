@@ -55,9 +55,9 @@
 //! See [`zng_ext_undo`] for the full undo API.
 
 pub use zng_ext_undo::{
-    CLEAR_HISTORY_CMD, CommandUndoExt, REDO_CMD, RedoAction, UNDO, UNDO_CMD, UndoAction, UndoActionMergeArgs, UndoFullOp, UndoInfo, UndoOp,
-    UndoSelect, UndoSelectInterval, UndoSelectLtEq, UndoSelector, UndoStackInfo, UndoTransaction, UndoVarModifyTag, WidgetInfoUndoExt,
-    WidgetUndoScope,
+    CLEAR_HISTORY_CMD, CommandUndoExt, REDO_CMD, RedoAction, UNDO, UNDO_CMD, UndoAction, UndoActionData, UndoActionMergeArgs, UndoFullOp,
+    UndoHistory, UndoHistoryEntry, UndoInfo, UndoMergePredicateArgs, UndoOp, UndoSelect, UndoSelectInterval, UndoSelectLtEq, UndoSelector,
+    UndoStackInfo, UndoTransaction, UndoVarModifyTag, WidgetInfoUndoExt, WidgetUndoScope,
 };
 
 pub use zng_wgt_undo::{UndoMix, undo_enabled, undo_interval, undo_limit, undo_scope};
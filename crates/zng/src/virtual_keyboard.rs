@@ -0,0 +1,41 @@
+#![cfg(feature = "virtual_keyboard")]
+
+//! On-screen virtual keyboard widget and service.
+//!
+//! The [`VIRTUAL_KEYBOARD`] service shows a [`VirtualKeyboard!`](struct@VirtualKeyboard) at the bottom of the window,
+//! the keys inject key events for the focused widget, so the keyboard works with any text input. Windows must enable
+//! [`virtual_keyboard_host`](fn@virtual_keyboard_host) to host the keyboard, in enabled windows the keyboard can be
+//! shown on demand or automatically when a text input is focused by touch.
+//!
+//! Automatic show is disabled by default, the example below enables it from a config, so kiosk deployments can
+//! turn it on without affecting desktop installs.
+//!
+//! ```
+//! use zng::{prelude::*, virtual_keyboard::VIRTUAL_KEYBOARD};
+//!
+//! # fn example() {
+//! CONFIG.get("kiosk.virtual_keyboard", false).set_bind(&VIRTUAL_KEYBOARD.auto_show()).perm();
+//!
+//! # let _ =
+//! Window! {
+//!     zng::virtual_keyboard::virtual_keyboard_host = true;
+//!     child = TextInput! {
+//!         txt = var(Txt::from(""));
+//!         on_double_click = hn!(|_| VIRTUAL_KEYBOARD.show());
+//!     };
+//! }
+//! # ; }
+//! ```
+//!
+//! The keyboard layout is selected for the app language by default, French uses AZERTY, German uses QWERTZ and
+//! most other languages use QWERTY. The keyboard can be themed using the [`style_fn`](fn@style_fn) and
+//! [`key_fn`](fn@key_fn) properties in a window or app style.
+//!
+//! # Full API
+//!
+//! See [`zng_wgt_virtual_keyboard`] for the full widget API.
+
+pub use zng_wgt_virtual_keyboard::{
+    DefaultStyle, KEY_FN_VAR, KEYBOARD_LAYOUT_VAR, KeyStyle, KeyboardLayout, VIRTUAL_KEYBOARD, VirtualKey, VirtualKeyArgs, VirtualKeyboard,
+    default_key_fn, key_fn, keyboard_layout, style_fn, virtual_keyboard_host,
+};
@@ -172,7 +172,12 @@ pub use zng_ext_window::{
 };
 
 #[cfg(feature = "image")]
-pub use zng_ext_window::{FRAME_IMAGE_READY_EVENT, FrameCaptureMode, FrameImageReadyArgs};
+pub use zng_ext_window::{
+    EXPORT_PDF_EVENT, ExportPdfArgs, ExportPdfRequest, FRAME_IMAGE_READY_EVENT, FrameCaptureMode, FrameImageReadyArgs,
+};
+
+#[cfg(all(feature = "image", feature = "test_util"))]
+pub use zng_ext_window::LayoutSnapshot;
 
 /// Window commands.
 pub mod cmd {
@@ -182,7 +187,7 @@ pub mod cmd {
     pub use zng_wgt_inspector::INSPECT_CMD;
 }
 
-pub use zng_wgt_window::{BlockWindowLoad, DefaultStyle, WINDOWS_Ext, Window};
+pub use zng_wgt_window::{BlockWindowLoad, DefaultStyle, WINDOWS_Ext, Window, WindowTransition, drag_move_window};
 
 pub use zng_wgt_window::events::{
     on_ime, on_pre_ime, on_pre_window_changed, on_pre_window_close_requested, on_pre_window_exited_fullscreen, on_pre_window_fullscreen,
@@ -234,7 +239,9 @@ pub use zng_wgt_window::events::{on_frame_image_ready, on_pre_frame_image_ready}
 /// [`INSPECTOR`]: crate::window::inspector::INSPECTOR
 #[cfg(feature = "inspector")]
 pub mod inspector {
-    pub use zng_wgt_inspector::debug::{InspectMode, show_bounds, show_center_points, show_directional_query, show_hit_test, show_rows};
+    pub use zng_wgt_inspector::debug::{
+        FRAME_STATS_CMD, InspectMode, show_bounds, show_center_points, show_directional_query, show_frame_stats, show_hit_test, show_rows,
+    };
 
     pub use zng_wgt_inspector::{INSPECTOR, InspectedInfo, InspectedTree, InspectedWidget, InspectorWatcherBuilder};
 }
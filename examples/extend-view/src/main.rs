@@ -19,6 +19,7 @@ mod using_blob;
 mod using_display_items;
 mod using_gl_overlay;
 mod using_gl_texture;
+mod using_typed_command;
 
 fn app_main() {
     APP.defaults().run_window("main", async {
@@ -40,7 +41,10 @@ fn app_main() {
                 tracing::info!("RAW-WINDOW-HANDLE: {h}");
 
                 let i = get_info::app_side::gl_version(WINDOW.id()).unwrap();
-                tracing::info!("OPEN-GL: {i}")
+                tracing::info!("OPEN-GL: {i}");
+
+                let c = using_typed_command::app_side::frame_count(WINDOW.id(), false).unwrap();
+                tracing::info!("FRAME-COUNT: {c}")
             });
 
             child = Stack! {
@@ -0,0 +1,106 @@
+//! Demo view extension renderer with strongly typed commands.
+
+/// App-process stuff.
+pub mod app_side {
+    use zng::prelude::*;
+    use zng_app::view_process::VIEW_PROCESS;
+    use zng_ext_window::WINDOWS_EXTENSIONS;
+    use zng_view_api::api_extension::ApiExtensionId;
+
+    /// Get the number of frames rendered by the window renderer since the last reset.
+    ///
+    /// This sends a typed command to the renderer extension implemented in `super::view_side`.
+    pub fn frame_count(win_id: WindowId, reset: bool) -> Option<u64> {
+        match WINDOWS_EXTENSIONS.view_render_extension::<_, super::api::Response>(
+            win_id,
+            self::extension_id(),
+            &super::api::Request::FrameCount { reset },
+        ) {
+            Ok(r) => Some(r.frame_count),
+            Err(e) => {
+                tracing::error!("failed to get extension response, {e}");
+                None
+            }
+        }
+    }
+
+    pub fn extension_id() -> ApiExtensionId {
+        VIEW_PROCESS
+            .extension_id(super::api::extension_name())
+            .ok()
+            .flatten()
+            .unwrap_or(ApiExtensionId::INVALID)
+    }
+}
+
+/// View-process stuff, the actual extension.
+pub mod view_side {
+    use zng_view::extensions::{RenderArgs, RendererCommandArgs, RendererExtension, TypedRendererExtension};
+    use zng_view_api::api_extension::ApiExtensionId;
+
+    use super::api::{Request, Response};
+
+    zng_view::view_process_extension!(|exts| {
+        // the registration handles the request/response encoding.
+        exts.renderer_typed::<CustomExtension>(super::api::extension_name());
+    });
+
+    struct CustomExtension {
+        frame_count: u64,
+    }
+    impl RendererExtension for CustomExtension {
+        fn is_init_only(&self) -> bool {
+            false
+        }
+
+        fn render_end(&mut self, _: &mut RenderArgs) {
+            self.frame_count += 1;
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+    impl TypedRendererExtension for CustomExtension {
+        type Request = Request;
+        type Response = Response;
+
+        fn new(_: ApiExtensionId) -> Self {
+            Self { frame_count: 0 }
+        }
+
+        fn typed_command(&mut self, request: Request, _: &mut RendererCommandArgs) -> Response {
+            match request {
+                Request::FrameCount { reset } => {
+                    let frame_count = self.frame_count;
+                    if reset {
+                        self.frame_count = 0;
+                    }
+                    Response { frame_count }
+                }
+            }
+        }
+    }
+}
+
+/// Shared types.
+pub mod api {
+    use zng_view_api::api_extension::ApiExtensionName;
+
+    pub fn extension_name() -> ApiExtensionName {
+        ApiExtensionName::new("zng.examples.extend_renderer.using_typed_command").unwrap()
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub enum Request {
+        FrameCount { reset: bool },
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct Response {
+        pub frame_count: u64,
+    }
+}
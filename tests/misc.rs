@@ -1,6 +1,12 @@
 use std::fmt::Write as _;
 
-use zng::{font::SegmentedText, layout::TextSegmentKind, prelude::*, prelude_wgt::*};
+use zng::{
+    font::SegmentedText,
+    layout::{TextSegmentKind, size},
+    prelude::*,
+    prelude_wgt::*,
+    widget::visibility,
+};
 
 #[test]
 fn emoji_segs() {
@@ -54,3 +60,893 @@ fn headless_clipboard() {
     assert_eq!(rsp.rsp().unwrap(), Err(zng::clipboard::ClipboardError::Disconnected));
     assert_eq!(CLIPBOARD.text(), Err(zng::clipboard::ClipboardError::Disconnected));
 }
+
+#[test]
+fn stack_spacing_collapsed() {
+    let _app = APP.defaults().run_headless(false);
+
+    fn stack_height(spacing_includes_collapsed: bool) -> Px {
+        WINDOW.with_test_context(WidgetUpdateMode::Bubble, || {
+            let mut wgt = Stack! {
+                direction = StackDirection::top_to_bottom();
+                spacing = 5;
+                spacing_includes_collapsed;
+                children = (0..5)
+                    .map(|i| {
+                        Wgt! {
+                            size = (10, 10);
+                            visibility = if i % 2 == 0 { Visibility::Visible } else { Visibility::Collapsed };
+                        }
+                    })
+                    .collect::<UiVec>();
+            };
+            WINDOW.test_init(&mut wgt);
+            WINDOW.test_info(&mut wgt);
+            let (size, _) = WINDOW.test_layout(&mut wgt, Some(PxConstraints2d::new_unbounded()));
+            size.height
+        })
+    }
+
+    // 3 visible items and 2 spacings
+    assert_eq!(Px(3 * 10 + 2 * 5), stack_height(false));
+    // 3 visible items and 4 spacings
+    assert_eq!(Px(3 * 10 + 4 * 5), stack_height(true));
+}
+
+#[test]
+fn layout_snapshot() {
+    let mut app = APP.defaults().run_headless(false);
+
+    let snapshot = app.layout_snapshot(DipSize::new(Dip::new(200), Dip::new(100)), 2.fct(), || {
+        Container! {
+            padding = 10;
+            child_align = Align::TOP_LEFT;
+            child = Wgt! {
+                id = "target";
+                size = (50, 20);
+            };
+        }
+    });
+
+    let target = snapshot.info.get("target").unwrap();
+    assert_eq!(
+        PxRect::new(PxPoint::new(Px(20), Px(20)), PxSize::new(Px(100), Px(40))),
+        target.inner_bounds()
+    );
+    // headless app without renderer
+    assert!(snapshot.image.is_error());
+}
+
+#[test]
+fn wrap_baseline_grows_row() {
+    let mut app = APP.defaults().run_headless(false);
+
+    let snapshot = app.layout_snapshot(DipSize::new(Dip::new(200), Dip::new(100)), 1.fct(), || {
+        Container! {
+            child_align = Align::TOP_LEFT;
+            child = Wrap! {
+                id = "wrap";
+                children_align = Align::BASELINE_LEFT;
+                children = ui_vec![
+                    Wgt! {
+                        id = "a";
+                        size = (20, 40);
+                        zng::layout::baseline = 20;
+                    },
+                    Wgt! {
+                        id = "b";
+                        size = (20, 30);
+                    },
+                ];
+            };
+        }
+    });
+
+    // "b" is offset up by 20 to match the "a" baseline, the row grows by 10 to fit it
+    let wrap = snapshot.info.get("wrap").unwrap();
+    assert_eq!(Px(50), wrap.inner_bounds().height());
+
+    let a = snapshot.info.get("a").unwrap().inner_bounds();
+    let b = snapshot.info.get("b").unwrap().inner_bounds();
+    assert_eq!(Px(10), a.origin.y);
+    assert_eq!(Px(0), b.origin.y);
+    assert_eq!(a.max_y() - Px(20), b.max_y());
+}
+
+#[test]
+fn updates_on_idle() {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    let mut app = APP.defaults().run_headless(false);
+
+    let idle_count = Arc::new(AtomicUsize::new(0));
+    let handle = UPDATES.on_idle(hn!(idle_count, |args| {
+        idle_count.store(args.count, Ordering::Relaxed);
+    }));
+
+    app.update(false).assert_wait();
+    assert_eq!(1, idle_count.load(Ordering::Relaxed));
+
+    drop(handle);
+    app.update(false).assert_wait();
+    assert_eq!(1, idle_count.load(Ordering::Relaxed));
+}
+
+#[test]
+fn key_repeat_config_override() {
+    use std::sync::{Arc, Mutex};
+    use zng::{
+        focus::focusable,
+        keyboard::{HeadlessAppKeyboardExt as _, Key, KeyCode, KeyLocation, KeyRepeatConfig, KeyState, key_repeat_config, on_key_down},
+    };
+
+    let mut app = APP.defaults().run_headless(false);
+
+    let repeats = Arc::new(Mutex::new(vec![]));
+    let win_id = WindowId::new_unique();
+    let input_id = WidgetId::new_unique();
+    let other_id = WidgetId::new_unique();
+    app.open_window(
+        win_id,
+        async_clmv!(repeats, {
+            Window! {
+                child = Stack! {
+                    direction = StackDirection::top_to_bottom();
+                    children = ui_vec![
+                        Wgt! {
+                            id = input_id;
+                            focusable = true;
+                            key_repeat_config = Some(KeyRepeatConfig::new(100.ms(), 50.ms()));
+                            on_key_down = hn!(repeats, |args| {
+                                repeats.lock().unwrap().push(args.repeat_count);
+                            });
+                        },
+                        Wgt! {
+                            id = other_id;
+                            focusable = true;
+                        },
+                    ];
+                };
+            }
+        }),
+    );
+    app.update(false).assert_wait();
+    FOCUS.focus_widget(input_id, true);
+    app.update(false).assert_wait();
+
+    let key = |app: &mut zng::app::HeadlessApp, state| {
+        app.on_keyboard_input(win_id, KeyCode::KeyA, KeyLocation::Standard, Key::Char('a'), state);
+        app.update(false).assert_wait();
+    };
+
+    key(&mut app, KeyState::Pressed);
+    assert_eq!(vec![0], *repeats.lock().unwrap());
+
+    // system repeat is replaced by the override
+    key(&mut app, KeyState::Pressed);
+    assert_eq!(vec![0], *repeats.lock().unwrap());
+
+    app.step(100.ms());
+    assert_eq!(vec![0, 1], *repeats.lock().unwrap());
+    app.step(50.ms());
+    assert_eq!(vec![0, 1, 2], *repeats.lock().unwrap());
+
+    // stops on release
+    key(&mut app, KeyState::Released);
+    app.step(200.ms());
+    assert_eq!(vec![0, 1, 2], *repeats.lock().unwrap());
+
+    // stops on focus loss
+    key(&mut app, KeyState::Pressed);
+    assert_eq!(vec![0, 1, 2, 0], *repeats.lock().unwrap());
+    FOCUS.focus_widget(other_id, true);
+    app.update(false).assert_wait();
+    app.step(200.ms());
+    assert_eq!(vec![0, 1, 2, 0], *repeats.lock().unwrap());
+}
+
+#[test]
+fn repeat_on_hold_clicks() {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    };
+    use zng::mouse::{ButtonRepeatConfig, ButtonState, MouseButton, repeat_on_hold};
+    use zng_app::view_process::{
+        raw_device_events::InputDeviceId,
+        raw_events::{RAW_MOUSE_INPUT_EVENT, RAW_MOUSE_MOVED_EVENT, RawMouseInputArgs, RawMouseMovedArgs},
+    };
+
+    let mut app = APP.defaults().run_headless(false);
+
+    let clicks = Arc::new(AtomicU32::new(0));
+    let win_id = WindowId::new_unique();
+    app.open_window(
+        win_id,
+        async_clmv!(clicks, {
+            Window! {
+                child = Wgt! {
+                    repeat_on_hold = Some(ButtonRepeatConfig::new(100.ms(), 50.ms()));
+                    on_click = hn!(clicks, |_| {
+                        clicks.fetch_add(1, Ordering::Relaxed);
+                    });
+                };
+            }
+        }),
+    );
+    app.update(false).assert_wait();
+
+    let device_id = InputDeviceId::virtual_mouse();
+    let position = DipPoint::new(Dip::new(10), Dip::new(10));
+    RAW_MOUSE_MOVED_EVENT.notify(RawMouseMovedArgs::now(win_id, device_id, vec![position], position));
+    app.update(false).assert_wait();
+
+    let button = |app: &mut zng::app::HeadlessApp, state| {
+        RAW_MOUSE_INPUT_EVENT.notify(RawMouseInputArgs::now(win_id, device_id, state, MouseButton::Left));
+        app.update(false).assert_wait();
+    };
+
+    // click on press
+    button(&mut app, ButtonState::Pressed);
+    assert_eq!(1, clicks.load(Ordering::Relaxed));
+
+    // repeat after the start delay, then every interval
+    app.step(100.ms());
+    assert_eq!(2, clicks.load(Ordering::Relaxed));
+    app.step(50.ms());
+    assert_eq!(3, clicks.load(Ordering::Relaxed));
+
+    // stops on release
+    button(&mut app, ButtonState::Released);
+    app.step(200.ms());
+    assert_eq!(3, clicks.load(Ordering::Relaxed));
+}
+
+#[test]
+fn data_focus_first_note() {
+    use std::sync::{Arc, Mutex};
+    use zng::{
+        data_context::{DATA, DataNoteLevel, data_error, data_warn, has_data_notes},
+        focus::focusable,
+    };
+
+    let mut app = APP.defaults().run_headless(false);
+
+    let request = var(None::<DataNoteLevel>);
+    let results = Arc::new(Mutex::new(vec![]));
+    let win_id = WindowId::new_unique();
+    app.open_window(
+        win_id,
+        async_clmv!(request, results, {
+            let fields = Stack! {
+                direction = StackDirection::top_to_bottom();
+                has_data_notes = var(false);
+                children = ui_vec![
+                    Wgt! {
+                        id = "no-notes";
+                        focusable = true;
+                    },
+                    Wgt! {
+                        id = "warn";
+                        focusable = true;
+                        data_warn = "warn";
+                    },
+                    // error source is not focusable, the field gets focus
+                    Wgt! {
+                        id = "error-field";
+                        focusable = true;
+                        child = Wgt! {
+                            id = "error-source";
+                            data_error = "error";
+                        };
+                    },
+                ];
+            };
+            Window! {
+                child = match_node(fields, move |_, op| match op {
+                    UiNodeOp::Init => {
+                        WIDGET.sub_var(&request);
+                    }
+                    UiNodeOp::Update { .. } => {
+                        if let Some(Some(level)) = request.get_new() {
+                            results.lock().unwrap().push(DATA.focus_first_note(level));
+                        }
+                    }
+                    _ => {}
+                });
+            }
+        }),
+    );
+    app.update(false).assert_wait();
+
+    let focused = || FOCUS.focused().get().map(|p| p.widget_id());
+
+    request.set(Some(DataNoteLevel::ERROR));
+    app.update(false).assert_wait();
+    assert_eq!(vec![Some(WidgetId::named("error-field"))], *results.lock().unwrap());
+    assert_eq!(Some(WidgetId::named("error-field")), focused());
+
+    request.set(Some(DataNoteLevel::WARN));
+    app.update(false).assert_wait();
+    assert_eq!(Some(WidgetId::named("warn")), results.lock().unwrap()[1]);
+    assert_eq!(Some(WidgetId::named("warn")), focused());
+}
+
+#[test]
+fn updates_run_incremental_renders_between_slices() {
+    use std::sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    let mut app = APP.defaults().run_headless(false);
+
+    let renders = Arc::new(AtomicUsize::new(0));
+    let win_id = WindowId::new_unique();
+    app.open_window(
+        win_id,
+        async_clmv!(renders, {
+            Window! {
+                child = match_node(UiNode::nil(), move |_, op| {
+                    if let UiNodeOp::Render { .. } = op {
+                        renders.fetch_add(1, Ordering::Relaxed);
+                    }
+                });
+            }
+        }),
+    );
+    app.update(false).assert_wait();
+
+    // zero budget, each `work` call is a slice
+    let slices = Arc::new(Mutex::new(vec![]));
+    UPDATES
+        .run_incremental(
+            0.ms(),
+            clmv!(renders, slices, || {
+                let mut slices = slices.lock().unwrap();
+                slices.push(renders.load(Ordering::Relaxed));
+                UPDATES.render_window(win_id);
+                slices.len() < 5
+            }),
+        )
+        .perm();
+    app.update(false).assert_wait();
+
+    let slices = slices.lock().unwrap();
+    assert_eq!(5, slices.len());
+    assert!(
+        slices.windows(2).all(|s| s[0] < s[1]),
+        "expected a frame between slices, {slices:?}"
+    );
+}
+
+#[test]
+fn close_transition_app_exit() {
+    let mut app = APP.defaults().run_headless(false);
+    WINDOWS.exit_on_last_close().set(false);
+
+    app.open_window(WindowId::new_unique(), async {
+        Window! {
+            close_transition = zng::window::WindowTransition::fade(10.secs());
+        }
+    });
+    app.update(false).assert_wait();
+
+    // app exit must not be delayed by the close animation
+    let _ = APP.exit();
+    for _ in 0..20 {
+        if let zng::app::AppControlFlow::Exit = app.update(false) {
+            break;
+        }
+    }
+    assert!(app.has_exited());
+}
+
+#[test]
+fn color_scheme_subtree_override() {
+    let mut app = APP.defaults().run_headless(false);
+
+    let color = var(colors::RED);
+    app.open_window(
+        WindowId::new_unique(),
+        async_clmv!(color, {
+            Window! {
+                color::color_scheme = color::ColorScheme::Light;
+                child = Wgt! {
+                    color::color_scheme = color::ColorScheme::Dark;
+                    widget::on_init = hn!(color, |_| {
+                        color.set(light_dark(colors::WHITE, colors::BLACK).rgba().get());
+                    });
+                }
+            }
+        }),
+    );
+    app.update(false).assert_wait();
+
+    assert_eq!(colors::BLACK, color.get());
+}
+
+#[test]
+fn auto_font_size_fit() {
+    let _app = APP.defaults().run_headless(false);
+
+    fn fit(max_width: i32) -> PxSize {
+        WINDOW.with_test_context(WidgetUpdateMode::Bubble, || {
+            // text mock, 5 glyphs sized by the font size
+            fn text_size() -> PxSize {
+                let font_size = LAYOUT.font_size();
+                PxSize::new(Px(font_size.0 * 5), font_size)
+            }
+            let text = match_node_leaf(|op| match op {
+                UiNodeOp::Measure { desired_size, .. } => *desired_size = text_size(),
+                UiNodeOp::Layout { final_size, .. } => *final_size = text_size(),
+                _ => {}
+            });
+            let mut wgt = zng::text::auto_font_size(text, 8, 40);
+            WINDOW.test_init(&mut wgt);
+            let (size, _) = WINDOW.test_layout(&mut wgt, Some(PxConstraints2d::new_unbounded().with_max_x(Px(max_width))));
+            size
+        })
+    }
+
+    // largest size that fits
+    assert_eq!(PxSize::new(Px(100), Px(20)), fit(100));
+    // max size fits
+    assert_eq!(PxSize::new(Px(200), Px(40)), fit(1000));
+    // min size overflows
+    assert_eq!(PxSize::new(Px(40), Px(8)), fit(20));
+}
+
+#[test]
+fn toasts_dismiss_all() {
+    use std::sync::{Arc, Mutex};
+    use zng::toast::{TOASTS, ToastResponse};
+
+    let mut app = APP.defaults().run_headless(false);
+    TOASTS.max_visible().set(1);
+
+    let responses = Arc::new(Mutex::new(vec![]));
+    let dismiss = var(false);
+    app.open_window(
+        WindowId::new_unique(),
+        async_clmv!(responses, dismiss, {
+            Window! {
+                child = Wgt! {
+                    widget::on_init = async_hn!(responses, dismiss, |_| {
+                        {
+                            let mut r = responses.lock().unwrap();
+                            for i in 0..3 {
+                                r.push(TOASTS.show(formatx!("toast {i}")));
+                            }
+                        }
+                        dismiss.wait_match(|&d| d).await;
+                        TOASTS.dismiss_all();
+                    });
+                }
+            }
+        }),
+    );
+    app.update(false).assert_wait();
+
+    // one visible, two queued
+    assert_eq!(3, responses.lock().unwrap().len());
+    assert!(responses.lock().unwrap().iter().all(|r| r.rsp().is_none()));
+
+    dismiss.set(true);
+    for _ in 0..5 {
+        let _ = app.update(false);
+    }
+
+    for r in responses.lock().unwrap().iter() {
+        assert_eq!(Some(ToastResponse::Dismissed), r.rsp());
+    }
+}
+
+#[test]
+fn toasts_window_close_dismiss_queued() {
+    use std::sync::{Arc, Mutex};
+    use zng::toast::{TOASTS, ToastResponse};
+
+    let mut app = APP.defaults().run_headless(false);
+    WINDOWS.exit_on_last_close().set(false);
+    TOASTS.max_visible().set(1);
+
+    let responses = Arc::new(Mutex::new(vec![]));
+    let id = WindowId::new_unique();
+    app.open_window(
+        id,
+        async_clmv!(responses, {
+            Window! {
+                child = Wgt! {
+                    widget::on_init = hn!(responses, |_| {
+                        let mut r = responses.lock().unwrap();
+                        for i in 0..3 {
+                            r.push(TOASTS.show(formatx!("toast {i}")));
+                        }
+                    });
+                }
+            }
+        }),
+    );
+    app.update(false).assert_wait();
+
+    assert!(app.close_window(id));
+    for _ in 0..5 {
+        let _ = app.update(false);
+    }
+
+    // the host and queued toasts are not leaked by a reference cycle
+    for r in responses.lock().unwrap().iter() {
+        assert_eq!(Some(ToastResponse::Dismissed), r.rsp());
+    }
+}
+
+#[test]
+fn tree_view_reuses_rows() {
+    use std::{
+        collections::HashSet,
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+    };
+    use zng::tree_view::{TreeItem, TreeView};
+
+    let mut app = APP.defaults().run_headless(false);
+
+    let items = vec![
+        TreeItem::new("a", "A").with_children([TreeItem::new("a1", "A1"), TreeItem::new("a2", "A2")]),
+        TreeItem::new("b", "B"),
+        TreeItem::new("c", "C"),
+    ];
+    let expanded = var(HashSet::<Txt>::new());
+    let selected = var(None::<Txt>);
+    let generated = Arc::new(AtomicUsize::new(0));
+    app.open_window(
+        WindowId::new_unique(),
+        async_clmv!(expanded, selected, generated, {
+            Window! {
+                child = TreeView! {
+                    items;
+                    expanded;
+                    selected;
+                    item_fn = wgt_fn!(generated, |args: zng::tree_view::TreeItemArgs| {
+                        generated.fetch_add(1, Ordering::Relaxed);
+                        Text!(args.item.label)
+                    });
+                };
+            }
+        }),
+    );
+    app.update(false).assert_wait();
+    assert_eq!(3, generated.load(Ordering::Relaxed));
+
+    // only the new child rows are generated, rows below the expanded item are reused
+    expanded.set(HashSet::from([Txt::from("a")]));
+    app.update(false).assert_wait();
+    assert_eq!(5, generated.load(Ordering::Relaxed));
+
+    expanded.set(HashSet::new());
+    app.update(false).assert_wait();
+    assert_eq!(5, generated.load(Ordering::Relaxed));
+
+    // selection does not regenerate rows
+    selected.set(Some(Txt::from("c")));
+    app.update(false).assert_wait();
+    assert_eq!(5, generated.load(Ordering::Relaxed));
+}
+
+#[test]
+fn scroll_snap_after_idle() {
+    use zng::scroll::{Scroll, ScrollMode, ScrollSnapMode, SmoothScrolling};
+
+    let mut app = APP.defaults().run_headless(false);
+
+    let offset = var(0.fct());
+    app.open_window(
+        WindowId::new_unique(),
+        async_clmv!(offset, {
+            Window! {
+                child = Scroll! {
+                    size = 100;
+                    mode = ScrollMode::VERTICAL;
+                    smooth_scrolling = SmoothScrolling::disabled();
+                    v_scroll_snap = ScrollSnapMode::Mandatory;
+                    vertical_offset = offset;
+                    child = Stack! {
+                        direction = StackDirection::top_to_bottom();
+                        children = (0..4).map(|_| Wgt!(size = 100)).collect::<UiVec>();
+                    };
+                };
+            }
+        }),
+    );
+    app.update(false).assert_wait();
+
+    // content is 400 tall, max offset is 300, 120 is between the second and third items
+    offset.set(0.4.fct());
+    app.update(false).assert_wait();
+    app.step(100.ms());
+    assert_eq!(0.4.fct(), offset.get());
+
+    app.step(100.ms());
+    app.update(false).assert_wait();
+    assert_eq!((100.0f32 / 300.0).fct(), offset.get());
+}
+
+#[test]
+fn rubber_band_select_ends_on_capture_lost() {
+    use zng::mouse::{ButtonState, MouseButton, rubber_band_select};
+    use zng_app::view_process::{
+        raw_device_events::InputDeviceId,
+        raw_events::{RAW_MOUSE_INPUT_EVENT, RAW_MOUSE_MOVED_EVENT, RawMouseInputArgs, RawMouseMovedArgs},
+    };
+
+    let mut app = APP.defaults().run_headless(false);
+
+    let selection = var(Vec::<WidgetId>::new());
+    let win_id = WindowId::new_unique();
+    app.open_window(
+        win_id,
+        async_clmv!(selection, {
+            Window! {
+                child = Stack! {
+                    rubber_band_select = selection;
+                    direction = StackDirection::top_to_bottom();
+                    children_align = Align::TOP_LEFT;
+                    children = ["a", "b", "c"]
+                        .into_iter()
+                        .map(|id| {
+                            Wgt! {
+                                id;
+                                size = 50;
+                            }
+                        })
+                        .collect::<UiVec>();
+                };
+            }
+        }),
+    );
+    app.update(false).assert_wait();
+    app.focus_window(win_id);
+
+    let device_id = InputDeviceId::virtual_mouse();
+    let move_to = |app: &mut zng::app::HeadlessApp, x: i32, y: i32| {
+        let position = DipPoint::new(Dip::new(x), Dip::new(y));
+        RAW_MOUSE_MOVED_EVENT.notify(RawMouseMovedArgs::now(win_id, device_id, vec![position], position));
+        app.update(false).assert_wait();
+    };
+
+    // press on empty space and drag over the first two children
+    move_to(&mut app, 200, 10);
+    RAW_MOUSE_INPUT_EVENT.notify(RawMouseInputArgs::now(win_id, device_id, ButtonState::Pressed, MouseButton::Left));
+    app.update(false).assert_wait();
+    move_to(&mut app, 10, 80);
+    assert_eq!(vec![WidgetId::named("a"), WidgetId::named("b")], selection.get());
+
+    // capture lost ends the band, later moves do not select
+    app.blur_window(win_id);
+    move_to(&mut app, 10, 140);
+    assert_eq!(vec![WidgetId::named("a"), WidgetId::named("b")], selection.get());
+}
+
+#[test]
+fn segmented_control_info() {
+    use zng::{
+        access::AccessRole,
+        toggle::segmented::{SegmentedControl, selected},
+    };
+
+    let mut app = APP.defaults().run_headless(false);
+
+    let sel = var(0usize);
+    let win_id = WindowId::new_unique();
+    app.open_window(
+        win_id,
+        async_clmv!(sel, {
+            WINDOW.enable_access();
+            Window! {
+                child = SegmentedControl! {
+                    id = "seg";
+                    segments = ["A", "Bbbbbb", "C"].map(Txt::from_static).to_vec();
+                    selected = sel;
+                };
+            }
+        }),
+    );
+    app.update(false).assert_wait();
+
+    sel.set(2);
+    app.update(false).assert_wait();
+
+    let tree = WINDOWS.widget_tree(win_id).unwrap();
+    let seg = tree.get("seg").unwrap();
+    assert_eq!(Some(AccessRole::RadioGroup), seg.access().unwrap().role());
+
+    let segments: Vec<_> = seg.children().collect();
+    assert_eq!(3, segments.len());
+    let size = segments[0].inner_bounds().size;
+    assert!(size.width > Px(0));
+    let mut x = segments[0].inner_bounds().origin.x;
+    for (i, s) in segments.iter().enumerate() {
+        assert_eq!(Some(AccessRole::Radio), s.access().unwrap().role());
+
+        // all segments have the size of the largest label
+        let bounds = s.inner_bounds();
+        assert_eq!(size, bounds.size);
+        if i > 0 {
+            assert_eq!(x + size.width, bounds.origin.x);
+            x = bounds.origin.x;
+        }
+    }
+}
+
+#[test]
+fn secure_txt_blocks_copy_cut() {
+    use zng::{
+        clipboard::{COPY_CMD, CUT_CMD},
+        event::{CommandArgs, CommandScope},
+        text::cmd::SELECT_ALL_CMD,
+    };
+
+    let mut app = APP.defaults().run_headless(false);
+
+    let secret = var(Txt::from_static("secret"));
+    let public = var(Txt::from_static("public"));
+    let win_id = WindowId::new_unique();
+    app.open_window(
+        win_id,
+        async_clmv!(secret, public, {
+            Window! {
+                child = Stack! {
+                    direction = StackDirection::top_to_bottom();
+                    children = ui_vec![
+                        TextInput! {
+                            id = "secret";
+                            txt = secret;
+                            secure_txt = true;
+                        },
+                        TextInput! {
+                            id = "public";
+                            txt = public;
+                        },
+                    ];
+                };
+            }
+        }),
+    );
+    app.update(false).assert_wait();
+
+    // the text edit handlers only stop propagation when they write the clipboard
+    let handled = var(Vec::<WidgetId>::new());
+    let observer = hn!(handled, |args: &CommandArgs| {
+        if args.propagation.is_stopped()
+            && let CommandScope::Widget(id) = args.scope
+        {
+            handled.modify(move |h| h.push(id));
+        }
+    })
+    .into_arc();
+    let _copy = COPY_CMD.on_event(true, false, true, observer.clone().into());
+    let _cut = CUT_CMD.on_event(true, false, true, observer.into());
+
+    for id in ["secret", "public"] {
+        SELECT_ALL_CMD.scoped(WidgetId::named(id)).notify();
+    }
+    app.update(false).assert_wait();
+
+    for id in ["secret", "public"] {
+        COPY_CMD.scoped(WidgetId::named(id)).notify();
+    }
+    app.update(false).assert_wait();
+    assert_eq!(vec![WidgetId::named("public")], handled.get());
+
+    handled.set(vec![]);
+    for id in ["secret", "public"] {
+        CUT_CMD.scoped(WidgetId::named(id)).notify();
+    }
+    app.update(false).assert_wait();
+    assert_eq!(vec![WidgetId::named("public")], handled.get());
+    assert_eq!("secret", secret.get());
+    assert_eq!("", public.get());
+}
+
+#[test]
+fn secure_txt_rich_copy_skips_leaf() {
+    use std::sync::{Arc, Mutex};
+    use zng::{
+        clipboard::COPY_CMD,
+        event::{CommandArgs, CommandScope},
+        text::cmd::SELECT_ALL_CMD,
+    };
+    use zng_wgt_text::node::RichTextCopyParam;
+
+    let mut app = APP.defaults().run_headless(false);
+
+    app.open_window(WindowId::new_unique(), async {
+        Window! {
+            child = Stack! {
+                id = "rich";
+                text::rich_text = true;
+                text::txt_selectable = true;
+                direction = StackDirection::top_to_bottom();
+                children = ui_vec![
+                    Text! {
+                        id = "public";
+                        txt = "public";
+                    },
+                    Text! {
+                        id = "secret";
+                        txt = "secret";
+                        text::secure_txt = true;
+                    },
+                ];
+            };
+        }
+    });
+    app.update(false).assert_wait();
+
+    // collect the copy requests the rich text context sends to each leaf
+    let requests = Arc::new(Mutex::new(vec![]));
+    let _copy = COPY_CMD.on_event(
+        true,
+        false,
+        true,
+        hn!(requests, |args: &CommandArgs| {
+            if let Some(p) = args.param::<RichTextCopyParam>()
+                && let CommandScope::Widget(id) = args.scope
+            {
+                requests.lock().unwrap().push((id, p.clone()));
+            }
+        }),
+    );
+
+    SELECT_ALL_CMD.scoped(WidgetId::named("rich")).notify();
+    app.update(false).assert_wait();
+    COPY_CMD.scoped(WidgetId::named("rich")).notify();
+    app.update(false).assert_wait();
+
+    let requests: Vec<_> = requests.lock().unwrap().drain(..).map(|(id, p)| (id, p.into_text())).collect();
+    assert_eq!(
+        vec![
+            (WidgetId::named("public"), Some(Txt::from_static("public"))),
+            (WidgetId::named("secret"), None),
+        ],
+        requests
+    );
+}
+
+#[test]
+fn secure_txt_access_no_label() {
+    let mut app = APP.defaults().run_headless(false);
+
+    let win_id = WindowId::new_unique();
+    app.open_window(win_id, async {
+        WINDOW.enable_access();
+        Window! {
+            child = Stack! {
+                direction = StackDirection::top_to_bottom();
+                children = ui_vec![
+                    Text! {
+                        id = "public";
+                        txt = "public";
+                    },
+                    Text! {
+                        id = "secret";
+                        txt = "secret";
+                        text::secure_txt = true;
+                    },
+                ];
+            };
+        }
+    });
+    app.update(false).assert_wait();
+
+    let tree = WINDOWS.widget_tree(win_id).unwrap();
+    let label = |id: &'static str| tree.get(id).unwrap().access().unwrap().label();
+    assert_eq!(Some(Txt::from_static("public")), label("public"));
+    assert_eq!(None, label("secret"));
+}
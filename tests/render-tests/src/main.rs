@@ -139,6 +139,30 @@ fn run_tests(args: Args, view_process: ViewProcess, mut app: HeadlessApp) {
         }
     }
 
+    let step_test = [("step_anim", tests::step_anim)];
+    for (test_name, test) in step_test {
+        for render_mode in render_mode {
+            let test_name = formatx!("{test_name}({view_process:?}, {render_mode:?})");
+            if !args.include_test(&test_name) {
+                continue;
+            }
+
+            println!(cstr!("\n<bold><green>TEST</> {}</>"), test_name);
+            TEST_NAME.set(test_name);
+
+            let start = Instant::now();
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| test(&mut app, render_mode)));
+            APP.end_manual_time();
+            if result.is_err() {
+                println!(cstr!("<bold><red>FAILED</></>"));
+                FAILED.store(true, Relaxed);
+            } else {
+                println!(cstr!("<bold><green>PASSED</></> in {:?}"), start.elapsed());
+            }
+            zng::image::IMAGES.clean_all();
+        }
+    }
+
     app.exit();
 
     std::thread::spawn(move || {
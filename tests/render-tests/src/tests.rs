@@ -1,4 +1,5 @@
 use zng::{
+    app::HeadlessApp,
     image::{IMAGES, ImageEntry},
     layout::LayoutPassId,
     prelude::*,
@@ -67,6 +68,67 @@ pub async fn bw_rgb(render_mode: RenderMode, scale_factor: Factor) {
     }
 }
 
+/// Captures frames between steps of an animation.
+pub fn step_anim(app: &mut HeadlessApp, render_mode: RenderMode) {
+    let _ = app.step(0.ms());
+
+    let color = var(colors::BLACK);
+    let win_id = WindowId::new_unique();
+    app.open_window(
+        win_id,
+        async_clmv!(color, {
+            Window! {
+                render_mode;
+                headless_monitor = HeadlessMonitor::new_scale(1.fct());
+                size = (10, 10);
+                widget::background_color = color;
+            }
+        }),
+    );
+
+    color.ease(colors::WHITE, 160.ms(), easing::linear).perm();
+    for i in 0..3 {
+        let _ = app.step(16.ms());
+
+        let expected = color.get();
+        assert!(
+            expected != colors::BLACK && expected != colors::WHITE,
+            "expected animating color, found {expected}"
+        );
+
+        let img = app.window_frame_image(win_id, None);
+        let img = app
+            .run_task(async move {
+                while img.with(ImageEntry::is_loading) {
+                    img.wait_update().await;
+                }
+                img.get()
+            })
+            .unwrap();
+
+        if let Some(name) = save_name() {
+            let file = format!("{name}.{i}.png");
+            app.run_task(clmv!(img, file, async move { img.save(file).await }))
+                .unwrap()
+                .unwrap();
+            println!("saved to `{file}`");
+        }
+
+        let (_, p) = img
+            .copy_pixels(PxRect::from_size(PxSize::splat(Px(10))))
+            .unwrap_or_else(|| panic!("expected frame pixels"));
+        let expected = [expected.blue, expected.green, expected.red].map(|c| (c * 255.0).round() as i32);
+        for cc in p.chunks_exact(4) {
+            // BGRA
+            for (c, e) in cc[..3].iter().zip(expected) {
+                assert!((*c as i32 - e).abs() <= 2, "expected {expected:?} (BGR), found {:?}", &cc[..3]);
+            }
+        }
+    }
+
+    app.close_window(win_id);
+}
+
 // async fn save_rect(rect: PxRect, p: &[u8]) {
 //     if let Some(name) = save_name() {
 //         let img = IMAGES.from_data(